@@ -7,9 +7,9 @@ import '../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `basename`, `build_download_filename`, `copy_file_with_progress`, `current_download_concurrency`, `download_author_avatar`, `download_cover`, `download_semaphore`, `map_record`, `progress_sender`, `resolve_download_path`, `resolve_under_data_dir`, `resume_queued_downloads`, `run_download`, `sanitize_filename`, `spawn_download`, `task_controls`, `uniquify_path`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DownloadControl`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `acquire_download_permit`, `as_str`, `available_ranges`, `basename`, `build_download_filename`, `classify`, `content_range_total`, `current_download_concurrency`, `default_max_retries`, `download_author_avatar`, `download_cover`, `download_files`, `download_path_hint`, `download_semaphore`, `downloads_suspended`, `enqueue_new_download`, `ensure_free_space`, `find_source`, `find_source_or_variant`, `from_http_status`, `handle_download_failure`, `is_transient`, `map_record`, `mark_download_corrupt`, `notify_download_changed`, `on_disk_bytes`, `open_download_file`, `open_media_stream`, `parse_status`, `preallocate_enabled`, `progress_sender`, `refresh_source_url`, `request_media`, `resolve_download_path`, `resolve_under_downloads_root`, `resume_queued_downloads`, `resume_suspended_downloads`, `retry_delay_secs`, `run_download`, `schedule_retry`, `space_reserve_bytes`, `spawn_download`, `stop_download_tasks`, `stream_ended_early`, `suspend_downloads`, `task_controls`, `verify_downloaded_file`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DownloadControl`, `DownloadErrorKind`, `InsufficientSpaceError`, `MediaHttpError`, `MediaSource`, `QuotaExceededError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// 添加下载任务
 Future<ApiDownloadTask> addDownload({
@@ -36,6 +36,10 @@ Future<List<ApiDownloadTask>> getAllDownloads() =>
 Future<List<ApiDownloadTask>> getDownloadsByStatus({required String status}) =>
     RustLib.instance.api.crateApiDownloadGetDownloadsByStatus(status: status);
 
+/// 按条件分页查询下载任务
+Future<ApiDownloadPage> queryDownloads({required ApiDownloadQuery query}) =>
+    RustLib.instance.api.crateApiDownloadQueryDownloads(query: query);
+
 /// 暂停下载
 Future<bool> pauseDownload({required String taskId}) =>
     RustLib.instance.api.crateApiDownloadPauseDownload(taskId: taskId);
@@ -44,6 +48,20 @@ Future<bool> pauseDownload({required String taskId}) =>
 Future<bool> resumeDownload({required String taskId}) =>
     RustLib.instance.api.crateApiDownloadResumeDownload(taskId: taskId);
 
+/// 重新校验下载文件（已完成、已标记损坏或文件缺失的任务）
+///
+/// - 校验通过：损坏 / 缺失任务恢复为已完成
+/// - 文件缺失、不完整或结构损坏：标记为 `Corrupt`
+Future<ApiDownloadTask> verifyDownload({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadVerifyDownload(taskId: taskId);
+
+/// 修复损坏的下载
+///
+/// - 文件仅是不完整：保留已下载部分，Range 续传补齐
+/// - 结构损坏或文件缺失：删除文件后重新下载
+Future<bool> repairDownload({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadRepairDownload(taskId: taskId);
+
 /// 取消/删除下载
 Future<bool> deleteDownload({
   required String taskId,
@@ -57,6 +75,7 @@ Future<bool> deleteDownload({
 ///
 /// - 仅导出已下载完成且存在本地文件的任务
 /// - 文件名格式：`[{作者}]{标题}.{ext}`，非法字符替换为 `_`
+/// - 进度事件中的 `job_id` 可用于暂停 / 取消（见 download_export）
 Stream<ApiExportProgress> exportDownloadsToDir({
   required List<String> taskIds,
   required String destDir,
@@ -73,6 +92,19 @@ Future<bool> pauseAllDownloads() =>
 Future<bool> resumeAllDownloads() =>
     RustLib.instance.api.crateApiDownloadResumeAllDownloads();
 
+/// 设置任务的最大自动重试次数（0 表示失败后不自动重试）
+Future<bool> setDownloadMaxRetries({
+  required String taskId,
+  required int maxRetries,
+}) => RustLib.instance.api.crateApiDownloadSetDownloadMaxRetries(
+  taskId: taskId,
+  maxRetries: maxRetries,
+);
+
+/// 获取下载目录空间使用情况（已占用 / 待下载 / 磁盘剩余）
+Future<ApiDownloadStorageInfo> getDownloadStorageInfo() =>
+    RustLib.instance.api.crateApiDownloadGetDownloadStorageInfo();
+
 /// 监听下载进度更新
 Stream<ApiDownloadTask> subscribeDownloadProgress() =>
     RustLib.instance.api.crateApiDownloadSubscribeDownloadProgress();
@@ -80,3 +112,12 @@ Stream<ApiDownloadTask> subscribeDownloadProgress() =>
 /// 获取已下载视频的本地播放路径
 Future<String?> getLocalVideoPath({required String videoId}) =>
     RustLib.instance.api.crateApiDownloadGetLocalVideoPath(videoId: videoId);
+
+/// 获取下载任务已写入磁盘的字节区间（`end` 不包含），用于边下边播时显示缓冲进度
+///
+/// 顺序下载的开头部分之外，还包括播放器拖动后提前下载的区间
+Future<List<ApiByteRange>> getDownloadAvailableRanges({
+  required String taskId,
+}) => RustLib.instance.api.crateApiDownloadGetDownloadAvailableRanges(
+  taskId: taskId,
+);
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `enqueue_cards`
+
+/// 下载整部系列（按识别出的集数顺序，无法识别时按观看页播放列表顺序）
+///
+/// 观看页没有播放列表时仅下载该视频本身
+Future<ApiBatchEnqueueResult> downloadSeries({
+  required String videoId,
+  required String quality,
+  String? folderId,
+}) => RustLib.instance.api.crateApiDownloadBatchDownloadSeries(
+  videoId: videoId,
+  quality: quality,
+  folderId: folderId,
+);
+
+/// 下载播放列表（`/playlist?list=...`）中的全部视频
+Future<ApiBatchEnqueueResult> downloadPlaylist({
+  required String listId,
+  required String quality,
+  String? folderId,
+}) => RustLib.instance.api.crateApiDownloadBatchDownloadPlaylist(
+  listId: listId,
+  quality: quality,
+  folderId: folderId,
+);
+
+/// 下载搜索结果（最多 `max_pages` 页），可按作者名过滤以下载整个作者目录
+Future<ApiBatchEnqueueResult> downloadSearch({
+  required ApiSearchFilters filters,
+  required int maxPages,
+  String? artist,
+  required String quality,
+  String? folderId,
+}) => RustLib.instance.api.crateApiDownloadBatchDownloadSearch(
+  filters: filters,
+  maxPages: maxPages,
+  artist: artist,
+  quality: quality,
+  folderId: folderId,
+);
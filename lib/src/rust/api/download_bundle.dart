@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add_bytes`, `add_file`, `add_image`, `bytes`, `copy_with_progress`, `done`, `extract`, `file_name_of`, `finish`, `finish_item`, `import_bundle`, `import_folders`, `import_image`, `import_one`, `lock`, `new`, `open`, `parse`, `part_path`, `read_manifest`, `safe_name`, `send`, `size_of`, `start_item`, `unique_path`, `write_bundle`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BundleDownload`, `BundleFolder`, `BundleManifest`, `BundleReader`, `BundleReporter`, `BundleSubtitle`, `BundleWriter`, `ConflictPolicy`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 导出已完成的下载为可迁移的打包（通过 StreamSink 发送进度）
+///
+/// - `task_ids` 为空时导出全部已完成的下载
+/// - `as_zip` 为 true 时 `dest_path` 为 zip 文件路径，否则为目录
+Stream<ApiBundleProgress> exportDownloadBundle({
+  required List<String> taskIds,
+  required String destPath,
+  required bool asZip,
+}) => RustLib.instance.api.crateApiDownloadBundleExportDownloadBundle(
+  taskIds: taskIds,
+  destPath: destPath,
+  asZip: asZip,
+);
+
+/// 从打包（目录或 zip 文件）导入下载（通过 StreamSink 发送进度）
+///
+/// - `conflict_policy`：`skip`（默认，保留本地记录）| `replace`（删除本地记录与文件后导入）
+/// - 文件夹按名称合并，不存在时新建
+Stream<ApiBundleProgress> importDownloadBundle({
+  required String srcPath,
+  required String conflictPolicy,
+}) => RustLib.instance.api.crateApiDownloadBundleImportDownloadBundle(
+  srcPath: srcPath,
+  conflictPolicy: conflictPolicy,
+);
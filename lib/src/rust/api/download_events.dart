@@ -0,0 +1,16 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `export_recent_events`, `map_event`, `record`, `source_host`
+
+/// 获取下载任务的事件时间线（按发生顺序）
+Future<List<ApiDownloadEvent>> getDownloadTimeline({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadEventsGetDownloadTimeline(
+      taskId: taskId,
+    );
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `build_nfo`, `build_tvshow_nfo`, `choose_media_path`, `compare_samples`, `copy_file_resumable`, `copy_subtitles`, `done`, `export_jobs`, `files_identical`, `finish_file`, `finish_move`, `folder_names`, `new`, `parse`, `part_path`, `plan_target`, `prefix_samples_equal`, `progress`, `push_tag`, `render`, `run_export`, `run_export_job`, `segment`, `send`, `send_export_control`, `skip_file`, `template_values`, `uniquify_path`, `wait_while_paused`, `with_ext`, `with_image_ext`, `write_jpeg`, `write_sidecars`, `xml_escape`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExportControl`, `ExportPlan`, `ExportReporter`, `ExportTarget`, `GroupBy`, `NameTemplate`, `Sidecars`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// 按选项导出下载文件到指定目录（通过 StreamSink 发送进度）
+Stream<ApiExportProgress> exportDownloadsWithOptions({
+  required List<String> taskIds,
+  required String destDir,
+  required ApiExportOptions options,
+}) => RustLib.instance.api.crateApiDownloadExportExportDownloadsWithOptions(
+  taskIds: taskIds,
+  destDir: destDir,
+  options: options,
+);
+
+/// 获取文件名模板支持的占位符
+Future<List<String>> getExportTemplateTokens() =>
+    RustLib.instance.api.crateApiDownloadExportGetExportTemplateTokens();
+
+/// 校验文件名模板（不合法时返回错误信息）
+Future<bool> validateExportTemplate({required String template}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadExportValidateExportTemplate(template: template);
+
+/// 预览文件名模板的渲染结果（相对导出目录的路径，`/` 分隔）
+///
+/// `task_ids` 为空时使用示例数据
+Future<List<String>> previewExportTemplate({
+  required String template,
+  required List<String> taskIds,
+  int? maxNameBytes,
+}) => RustLib.instance.api.crateApiDownloadExportPreviewExportTemplate(
+  template: template,
+  taskIds: taskIds,
+  maxNameBytes: maxNameBytes,
+);
+
+/// 暂停导出任务（当前文件写入在下一个数据块处暂停，`.part` 保留）
+Future<bool> pauseExportJob({required String jobId}) =>
+    RustLib.instance.api.crateApiDownloadExportPauseExportJob(jobId: jobId);
+
+/// 继续导出任务
+Future<bool> resumeExportJob({required String jobId}) =>
+    RustLib.instance.api.crateApiDownloadExportResumeExportJob(jobId: jobId);
+
+/// 取消导出任务（删除当前文件的 `.part`，已完成的文件保留）
+Future<bool> cancelExportJob({required String jobId}) =>
+    RustLib.instance.api.crateApiDownloadExportCancelExportJob(jobId: jobId);
+
+/// 获取进行中的导出任务 ID
+Future<List<String>> getActiveExportJobs() =>
+    RustLib.instance.api.crateApiDownloadExportGetActiveExportJobs();
@@ -7,16 +7,47 @@ import '../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// 获取所有下载文件夹
+// These functions are ignored because they are not marked as `pub`: `check_parent`, `create_folder`, `descendant_ids`, `ensure_manual_folder`, `find_folder`, `folder_scope`, `from_filter`, `map_folder`, `parse_rule`, `to_filter`
+
+/// 获取所有下载文件夹（按同级排序，界面根据 parent_id 组装树）
 Future<List<ApiDownloadFolder>> getDownloadFolders() =>
     RustLib.instance.api.crateApiDownloadFoldersGetDownloadFolders();
 
-/// 创建下载文件夹
-Future<ApiDownloadFolder> createDownloadFolder({required String name}) =>
-    RustLib.instance.api.crateApiDownloadFoldersCreateDownloadFolder(
-      name: name,
+/// 创建下载文件夹（`parent_id` 为空时创建在顶层）
+Future<ApiDownloadFolder> createDownloadFolder({
+  required String name,
+  String? parentId,
+}) => RustLib.instance.api.crateApiDownloadFoldersCreateDownloadFolder(
+  name: name,
+  parentId: parentId,
+);
+
+/// 创建智能文件夹
+Future<ApiDownloadFolder> createSmartFolder({
+  required String name,
+  String? parentId,
+  required ApiSmartFolderRule rule,
+}) => RustLib.instance.api.crateApiDownloadFoldersCreateSmartFolder(
+  name: name,
+  parentId: parentId,
+  rule: rule,
+);
+
+/// 获取智能文件夹规则
+Future<ApiSmartFolderRule> getSmartFolderRule({required String folderId}) =>
+    RustLib.instance.api.crateApiDownloadFoldersGetSmartFolderRule(
+      folderId: folderId,
     );
 
+/// 更新智能文件夹规则
+Future<bool> updateSmartFolderRule({
+  required String folderId,
+  required ApiSmartFolderRule rule,
+}) => RustLib.instance.api.crateApiDownloadFoldersUpdateSmartFolderRule(
+  folderId: folderId,
+  rule: rule,
+);
+
 /// 重命名下载文件夹
 Future<bool> renameDownloadFolder({
   required String folderId,
@@ -26,13 +57,51 @@ Future<bool> renameDownloadFolder({
   name: name,
 );
 
-/// 删除下载文件夹（视频不会被删除，仅清除视频的文件夹关联）
+/// 删除下载文件夹（视频不会被删除，仅清除视频的文件夹关联；子文件夹移到上一级）
 Future<bool> deleteDownloadFolder({required String folderId}) => RustLib
     .instance
     .api
     .crateApiDownloadFoldersDeleteDownloadFolder(folderId: folderId);
 
-/// 将视频移动到文件夹
+/// 移动文件夹到新的上级（`parent_id` 为空时移到顶层），排在新同级的末尾
+Future<bool> moveDownloadFolder({required String folderId, String? parentId}) =>
+    RustLib.instance.api.crateApiDownloadFoldersMoveDownloadFolder(
+      folderId: folderId,
+      parentId: parentId,
+    );
+
+/// 调整同级文件夹顺序
+///
+/// `folder_ids` 中的文件夹按给定顺序排在前面，未列出的同级文件夹保持原有相对顺序排在后面
+Future<bool> reorderDownloadFolders({
+  String? parentId,
+  required List<String> folderIds,
+}) => RustLib.instance.api.crateApiDownloadFoldersReorderDownloadFolders(
+  parentId: parentId,
+  folderIds: folderIds,
+);
+
+/// 设置文件夹封面（`video_id` 为空时恢复默认）
+Future<bool> setDownloadFolderCover({
+  required String folderId,
+  String? videoId,
+}) => RustLib.instance.api.crateApiDownloadFoldersSetDownloadFolderCover(
+  folderId: folderId,
+  videoId: videoId,
+);
+
+/// 获取文件夹中的视频（智能文件夹按规则求值）
+///
+/// `include_subfolders` 为 true 时同时包含所有子孙文件夹中的视频（去重）
+Future<List<ApiDownloadTask>> getFolderDownloads({
+  required String folderId,
+  required bool includeSubfolders,
+}) => RustLib.instance.api.crateApiDownloadFoldersGetFolderDownloads(
+  folderId: folderId,
+  includeSubfolders: includeSubfolders,
+);
+
+/// 将视频移动到文件夹（智能文件夹不能手动移入）
 Future<bool> moveDownloadsToFolder({
   required List<String> videoIds,
   String? folderId,
@@ -0,0 +1,10 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `assign_file_name`, `clear_progress`, `complete`, `fetch_bytes`, `fetch_playlist`, `fetch_segment`, `fetch_throttled`, `is_hls_source`, `load_media_playlist`, `load_progress`, `mark_corrupt`, `report_progress`, `run_hls_download`, `save_progress`, `set_status`, `video_id`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HlsTarget`
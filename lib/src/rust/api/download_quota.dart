@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `blocked_sender`, `build_plan`, `cleanup_lock`, `ensure_quota`, `execute_plan`, `library_size`, `load`, `pinned_videos`, `rules`, `start_auto_cleanup`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `QuotaPolicy`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 获取配额与清理策略
+Future<ApiDownloadQuotaPolicy> getDownloadQuotaPolicy() =>
+    RustLib.instance.api.crateApiDownloadQuotaGetDownloadQuotaPolicy();
+
+/// 保存配额与清理策略
+Future<bool> setDownloadQuotaPolicy({required ApiDownloadQuotaPolicy policy}) =>
+    RustLib.instance.api.crateApiDownloadQuotaSetDownloadQuotaPolicy(
+      policy: policy,
+    );
+
+/// 预览清理：按当前策略列出将被删除的下载（不做任何修改）
+Future<ApiCleanupPlan> previewDownloadCleanup() =>
+    RustLib.instance.api.crateApiDownloadQuotaPreviewDownloadCleanup();
+
+/// 立即按当前策略清理，返回实际删除的下载
+Future<ApiCleanupPlan> runDownloadCleanup() =>
+    RustLib.instance.api.crateApiDownloadQuotaRunDownloadCleanup();
+
+/// 监听配额阻止下载事件
+Stream<ApiQuotaBlockedEvent> subscribeQuotaBlockedEvents() =>
+    RustLib.instance.api.crateApiDownloadQuotaSubscribeQuotaBlockedEvents();
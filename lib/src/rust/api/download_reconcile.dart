@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `file_exists`, `guess_video_id`, `is_recent`, `map_status`, `plain_file_name`, `referenced_files`, `subtitle_files`, `variant_files`
+
+/// 扫描下载目录与数据库，生成核对报告（只读，不做任何修改）
+Future<ApiReconcileReport> scanDownloadLibrary() =>
+    RustLib.instance.api.crateApiDownloadReconcileScanDownloadLibrary();
+
+/// 将下载记录重新关联到目录中已有的文件
+///
+/// - `kind`：`media` | `cover` | `avatar`
+/// - 文件必须位于对应目录且未被其他记录引用；媒体文件关联后会重新校验
+Future<ApiDownloadTask> relinkDownload({
+  required String videoId,
+  required String kind,
+  required String fileName,
+}) => RustLib.instance.api.crateApiDownloadReconcileRelinkDownload(
+  videoId: videoId,
+  kind: kind,
+  fileName: fileName,
+);
+
+/// 将媒体文件缺失的已完成任务标记为 `Missing`，返回实际标记的数量
+Future<int> markDownloadsMissing({required List<String> videoIds}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadReconcileMarkDownloadsMissing(videoIds: videoIds);
+
+/// 重新下载文件缺失的任务，返回成功加入队列的数量
+Future<int> redownloadMissing({required List<String> videoIds}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadReconcileRedownloadMissing(videoIds: videoIds);
+
+/// 删除孤立文件（`files` 为 `{dir}/{file_name}`），删除前会再次确认无记录引用
+///
+/// 返回释放的字节数
+Future<BigInt> deleteOrphanFiles({required List<String> files}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadReconcileDeleteOrphanFiles(files: files);
@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cleanup_sources`, `copy_verified`, `done`, `move_library`, `new`, `plan_files`, `prepare`, `relocate`, `rollback`, `save_root`, `send`, `stage`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MovedFile`, `PlannedFile`, `RelocateReporter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+
+/// 获取当前下载根目录
+Future<String> getDownloadsRootPath() =>
+    RustLib.instance.api.crateApiDownloadRelocateGetDownloadsRootPath();
+
+/// 迁移下载目录到新的根目录（通过 StreamSink 发送进度）
+///
+/// - `new_root` 为空时迁回数据目录
+/// - 迁移期间进行中的下载会被暂停，结束后自动恢复
+/// - 导出、打包、核对扫描、本地播放、新建下载或删除文件进行中时返回错误，结束后再试
+Stream<ApiRelocateProgress> relocateDownloads({required String newRoot}) =>
+    RustLib.instance.api.crateApiDownloadRelocateRelocateDownloads(
+      newRoot: newRoot,
+    );
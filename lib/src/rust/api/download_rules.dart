@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_interval`, `check_lock`, `ensure_baseline`, `ensure_baseline_logged`, `fetch_candidate`, `map_rule`, `pending_details`, `rule_capped`, `rule_matches`, `run_rule_check`, `start_rule_checker`, `to_record`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Candidate`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+
+/// 获取所有自动下载规则
+Future<List<ApiDownloadRule>> getDownloadRules() =>
+    RustLib.instance.api.crateApiDownloadRulesGetDownloadRules();
+
+/// 创建自动下载规则（忽略传入的 id / created_at）
+Future<ApiDownloadRule> createDownloadRule({required ApiDownloadRule rule}) =>
+    RustLib.instance.api.crateApiDownloadRulesCreateDownloadRule(rule: rule);
+
+/// 更新自动下载规则
+Future<bool> updateDownloadRule({required ApiDownloadRule rule}) =>
+    RustLib.instance.api.crateApiDownloadRulesUpdateDownloadRule(rule: rule);
+
+/// 启用/停用自动下载规则
+Future<bool> setDownloadRuleEnabled({
+  required String ruleId,
+  required bool enabled,
+}) => RustLib.instance.api.crateApiDownloadRulesSetDownloadRuleEnabled(
+  ruleId: ruleId,
+  enabled: enabled,
+);
+
+/// 删除自动下载规则
+Future<bool> deleteDownloadRule({required String ruleId}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadRulesDeleteDownloadRule(ruleId: ruleId);
+
+/// 设置后台检查间隔（分钟，最少 10 分钟）
+Future<bool> setDownloadRulesInterval({required int minutes}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadRulesSetDownloadRulesInterval(minutes: minutes);
+
+/// 立即检查一次订阅更新，返回本次加入队列的视频数
+Future<int> checkDownloadRulesNow() =>
+    RustLib.instance.api.crateApiDownloadRulesCheckDownloadRulesNow();
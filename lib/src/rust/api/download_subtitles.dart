@@ -0,0 +1,17 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `delete_subtitle_files`, `fetch_subtitle`, `fetch_subtitles`, `local_subtitles`, `subtitle_file_name`, `subtitle_path`
+
+/// 获取下载任务的字幕（`file_path` 为空表示尚未下载）
+Future<List<ApiDownloadSubtitle>> getDownloadSubtitles({
+  required String taskId,
+}) => RustLib.instance.api.crateApiDownloadSubtitlesGetDownloadSubtitles(
+  taskId: taskId,
+);
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `load_bandwidth_settings`, `load_settings`
+
+/// 获取下载限速设置
+Future<ApiDownloadBandwidthSettings> getDownloadBandwidthSettings() =>
+    RustLib.instance.api.crateApiDownloadThrottleGetDownloadBandwidthSettings();
+
+/// 保存下载限速设置（立即生效）
+Future<bool> setDownloadBandwidthSettings({
+  required ApiDownloadBandwidthSettings settings,
+}) => RustLib.instance.api.crateApiDownloadThrottleSetDownloadBandwidthSettings(
+  settings: settings,
+);
+
+/// 获取任务单独设置的限速（KB/s，0 表示不限），为空表示使用默认值
+Future<int?> getDownloadTaskBandwidthLimit({required String taskId}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadThrottleGetDownloadTaskBandwidthLimit(taskId: taskId);
+
+/// 设置任务单独的限速（KB/s，0 表示不限），为空时恢复使用默认值（立即生效）
+Future<bool> setDownloadTaskBandwidthLimit({
+  required String taskId,
+  int? kbPerSec,
+}) =>
+    RustLib.instance.api.crateApiDownloadThrottleSetDownloadTaskBandwidthLimit(
+      taskId: taskId,
+      kbPerSec: kbPerSec,
+    );
+
+/// 播放器开始/停止播放在线视频（播放本地文件时无需上报）
+Future<void> setPlayerStreaming({required bool active}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadThrottleSetPlayerStreaming(active: active);
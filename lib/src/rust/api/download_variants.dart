@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cancel_task`, `check_new_quality`, `complete_variant`, `delete_variants_of`, `fetch_source`, `has_variant_tasks`, `map_variant`, `notify_variant`, `open_variant_stream`, `promote`, `remove_variant_file`, `resume_variant_downloads`, `run_variant`, `spawn_variant`, `start_variant`, `stop_variant_tasks`, `variant_controls`, `variant_file_name`, `variant_files`, `variant_sender`
+
+/// 升级已下载视频的画质
+///
+/// - 新画质在后台下载，期间旧文件仍可播放；完成后自动替换主下载
+/// - `keep_previous` 为 true 时保留旧画质为变体，否则删除旧文件
+/// - 该画质已作为变体下载完成时立即替换
+Future<ApiDownloadVariant> upgradeDownload({
+  required String videoId,
+  required String quality,
+  required bool keepPrevious,
+}) => RustLib.instance.api.crateApiDownloadVariantsUpgradeDownload(
+  videoId: videoId,
+  quality: quality,
+  keepPrevious: keepPrevious,
+);
+
+/// 额外下载一个画质，与主下载并存
+Future<ApiDownloadVariant> addDownloadVariant({
+  required String videoId,
+  required String quality,
+}) => RustLib.instance.api.crateApiDownloadVariantsAddDownloadVariant(
+  videoId: videoId,
+  quality: quality,
+);
+
+/// 获取视频的画质变体（不含主下载）
+Future<List<ApiDownloadVariant>> getDownloadVariants({
+  required String videoId,
+}) => RustLib.instance.api.crateApiDownloadVariantsGetDownloadVariants(
+  videoId: videoId,
+);
+
+/// 将已下载完成的变体设为主下载
+///
+/// `keep_previous` 为 true 时原主下载转为变体，否则删除原文件
+Future<bool> setPrimaryDownloadVariant({
+  required PlatformInt64 variantId,
+  required bool keepPrevious,
+}) => RustLib.instance.api.crateApiDownloadVariantsSetPrimaryDownloadVariant(
+  variantId: variantId,
+  keepPrevious: keepPrevious,
+);
+
+/// 暂停变体下载
+Future<bool> pauseDownloadVariant({required PlatformInt64 variantId}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadVariantsPauseDownloadVariant(variantId: variantId);
+
+/// 继续变体下载（暂停或失败后）
+Future<bool> resumeDownloadVariant({required PlatformInt64 variantId}) =>
+    RustLib.instance.api.crateApiDownloadVariantsResumeDownloadVariant(
+      variantId: variantId,
+    );
+
+/// 取消 / 删除变体（进行中的升级会被放弃，主下载不受影响）
+Future<bool> deleteDownloadVariant({
+  required PlatformInt64 variantId,
+  required bool deleteFile,
+}) => RustLib.instance.api.crateApiDownloadVariantsDeleteDownloadVariant(
+  variantId: variantId,
+  deleteFile: deleteFile,
+);
+
+/// 监听变体下载进度
+Stream<ApiDownloadVariant> subscribeDownloadVariantProgress() => RustLib
+    .instance
+    .api
+    .crateApiDownloadVariantsSubscribeDownloadVariantProgress();
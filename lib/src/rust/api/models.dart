@@ -9,7 +9,7 @@ import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'models.freezed.dart';
 
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ApiResult`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// 应用设置
 @freezed
@@ -48,6 +48,67 @@ sealed class ApiBanner with _$ApiBanner {
   }) = _ApiBanner;
 }
 
+/// 批量添加下载的结果
+@freezed
+sealed class ApiBatchEnqueueResult with _$ApiBatchEnqueueResult {
+  const factory ApiBatchEnqueueResult({
+    required int total,
+    required List<String> enqueued,
+    required List<String> skipped,
+    required List<String> failed,
+  }) = _ApiBatchEnqueueResult;
+}
+
+/// 下载库打包（导出 / 导入）进度
+@freezed
+sealed class ApiBundleProgress with _$ApiBundleProgress {
+  const factory ApiBundleProgress({
+    required String stage,
+    required int totalItems,
+    required int doneItems,
+    required int exported,
+    required int imported,
+    required int skipped,
+    required int failed,
+    String? currentItem,
+    required BigInt currentBytes,
+    required BigInt currentTotalBytes,
+    required bool done,
+    String? error,
+  }) = _ApiBundleProgress;
+}
+
+/// 字节区间 [start, end)
+@freezed
+sealed class ApiByteRange with _$ApiByteRange {
+  const factory ApiByteRange({required BigInt start, required BigInt end}) =
+      _ApiByteRange;
+}
+
+/// 清理计划中的单个下载
+@freezed
+sealed class ApiCleanupItem with _$ApiCleanupItem {
+  const factory ApiCleanupItem({
+    required String videoId,
+    required String title,
+    required String reason,
+    required BigInt size,
+    required PlatformInt64 completedAt,
+    PlatformInt64? watchedAt,
+  }) = _ApiCleanupItem;
+}
+
+/// 清理计划 / 结果
+@freezed
+sealed class ApiCleanupPlan with _$ApiCleanupPlan {
+  const factory ApiCleanupPlan({
+    required BigInt usedBytes,
+    BigInt? quotaBytes,
+    required List<ApiCleanupItem> items,
+    required BigInt freedBytes,
+  }) = _ApiCleanupPlan;
+}
+
 /// Cloudflare 验证请求
 @freezed
 sealed class ApiCloudflareChallenge with _$ApiCloudflareChallenge {
@@ -84,6 +145,56 @@ sealed class ApiCommentList with _$ApiCommentList {
   }) = _ApiCommentList;
 }
 
+/// 继续观看（已开始但未看完）
+@freezed
+sealed class ApiContinueWatching with _$ApiContinueWatching {
+  const factory ApiContinueWatching({
+    required String videoId,
+    required String title,
+    required String coverUrl,
+    required double progress,
+    required int position,
+    required int duration,
+    required PlatformInt64 lastPlayedAt,
+    String? seriesTitle,
+    int? episode,
+    required bool isDownloaded,
+  }) = _ApiContinueWatching;
+}
+
+/// 下载限速设置
+@freezed
+sealed class ApiDownloadBandwidthSettings with _$ApiDownloadBandwidthSettings {
+  const ApiDownloadBandwidthSettings._();
+  const factory ApiDownloadBandwidthSettings({
+    required int globalKbPerSec,
+    required int taskKbPerSec,
+    required bool onlyWhileStreaming,
+    required bool playerStreaming,
+  }) = _ApiDownloadBandwidthSettings;
+  static Future<ApiDownloadBandwidthSettings> default_() =>
+      RustLib.instance.api.crateApiModelsApiDownloadBandwidthSettingsDefault();
+}
+
+/// 下载事件（用于查看任务时间线排查问题）
+@freezed
+sealed class ApiDownloadEvent with _$ApiDownloadEvent {
+  const factory ApiDownloadEvent({
+    required PlatformInt64 id,
+    required String kind,
+    String? status,
+    String? message,
+    String? sourceHost,
+    String? quality,
+    String? format,
+    BigInt? rangeStart,
+    BigInt? rangeEnd,
+    int? httpStatus,
+    String? errorKind,
+    required PlatformInt64 createdAt,
+  }) = _ApiDownloadEvent;
+}
+
 /// 下载文件夹（仅用于过滤分类，删除文件夹不影响视频）
 @freezed
 sealed class ApiDownloadFolder with _$ApiDownloadFolder {
@@ -91,9 +202,82 @@ sealed class ApiDownloadFolder with _$ApiDownloadFolder {
     required String id,
     required String name,
     required PlatformInt64 createdAt,
+    String? parentId,
+    required int sortOrder,
+    String? coverVideoId,
+    required bool isSmart,
+    required int videoCount,
   }) = _ApiDownloadFolder;
 }
 
+/// 下载列表分页结果
+@freezed
+sealed class ApiDownloadPage with _$ApiDownloadPage {
+  const factory ApiDownloadPage({
+    required List<ApiDownloadTask> items,
+    required int total,
+  }) = _ApiDownloadPage;
+}
+
+/// 下载列表查询条件（过滤、排序、分页均在数据库中完成）
+///
+/// 列表为空、字段为空表示不限
+@freezed
+sealed class ApiDownloadQuery with _$ApiDownloadQuery {
+  const ApiDownloadQuery._();
+  const factory ApiDownloadQuery({
+    required List<String> statuses,
+    String? folderId,
+    required bool includeSubfolders,
+    required List<String> authors,
+    required List<String> tags,
+    String? text,
+    PlatformInt64? downloadedFrom,
+    PlatformInt64? downloadedTo,
+    BigInt? minBytes,
+    BigInt? maxBytes,
+    String? sortBy,
+    required bool descending,
+    required int offset,
+    required int limit,
+  }) = _ApiDownloadQuery;
+  static Future<ApiDownloadQuery> default_() =>
+      RustLib.instance.api.crateApiModelsApiDownloadQueryDefault();
+}
+
+/// 下载存储配额与自动清理策略
+@freezed
+sealed class ApiDownloadQuotaPolicy with _$ApiDownloadQuotaPolicy {
+  const ApiDownloadQuotaPolicy._();
+  const factory ApiDownloadQuotaPolicy({
+    BigInt? quotaBytes,
+    required bool autoCleanup,
+    int? watchedOlderThanDays,
+    required bool oldestFirst,
+    required List<String> pinnedFolderIds,
+  }) = _ApiDownloadQuotaPolicy;
+  static Future<ApiDownloadQuotaPolicy> default_() =>
+      RustLib.instance.api.crateApiModelsApiDownloadQuotaPolicyDefault();
+}
+
+/// 订阅自动下载规则
+@freezed
+sealed class ApiDownloadRule with _$ApiDownloadRule {
+  const factory ApiDownloadRule({
+    required String id,
+    required String name,
+    required String matchType,
+    required String matchValue,
+    required String quality,
+    String? folderId,
+    int? maxAgeDays,
+    int? dailyCap,
+    required bool enabled,
+    required PlatformInt64 createdAt,
+    PlatformInt64? lastCheckedAt,
+  }) = _ApiDownloadRule;
+}
+
 @freezed
 sealed class ApiDownloadStatus with _$ApiDownloadStatus {
   const ApiDownloadStatus._();
@@ -104,6 +288,39 @@ sealed class ApiDownloadStatus with _$ApiDownloadStatus {
   const factory ApiDownloadStatus.completed() = ApiDownloadStatus_Completed;
   const factory ApiDownloadStatus.failed({required String error}) =
       ApiDownloadStatus_Failed;
+
+  /// 文件完整性校验失败
+  const factory ApiDownloadStatus.corrupt({required String reason}) =
+      ApiDownloadStatus_Corrupt;
+
+  /// 本地文件缺失（可重新关联或重新下载）
+  const factory ApiDownloadStatus.missing() = ApiDownloadStatus_Missing;
+}
+
+/// 下载目录空间使用情况
+@freezed
+sealed class ApiDownloadStorageInfo with _$ApiDownloadStorageInfo {
+  const factory ApiDownloadStorageInfo({
+    required String downloadDir,
+    required BigInt usedBytes,
+    required BigInt pendingBytes,
+    required BigInt freeBytes,
+    required BigInt diskTotalBytes,
+    required BigInt reserveBytes,
+    required bool preallocate,
+  }) = _ApiDownloadStorageInfo;
+}
+
+/// 随下载保存的字幕
+@freezed
+sealed class ApiDownloadSubtitle with _$ApiDownloadSubtitle {
+  const factory ApiDownloadSubtitle({
+    required String language,
+    required String label,
+    required String url,
+    required String format,
+    String? filePath,
+  }) = _ApiDownloadSubtitle;
 }
 
 /// 下载任务
@@ -130,15 +347,57 @@ sealed class ApiDownloadTask with _$ApiDownloadTask {
     required PlatformInt64 createdAt,
     String? filePath,
     String? folderId,
+    String? errorKind,
+    required int retryCount,
+    required int maxRetries,
+    PlatformInt64? nextRetryAt,
   }) = _ApiDownloadTask;
 }
 
+/// 下载视频的画质变体（主下载之外的其他画质）
+@freezed
+sealed class ApiDownloadVariant with _$ApiDownloadVariant {
+  const factory ApiDownloadVariant({
+    required PlatformInt64 id,
+    required String videoId,
+    required String quality,
+    String? sourceQuality,
+    required ApiDownloadStatus status,
+    required double progress,
+    required BigInt downloadedBytes,
+    required BigInt totalBytes,
+    String? filePath,
+    required bool replacePrimary,
+    required bool keepPrevious,
+    String? errorMessage,
+    required PlatformInt64 createdAt,
+    PlatformInt64? completedAt,
+  }) = _ApiDownloadVariant;
+}
+
+/// 导出选项
+@freezed
+sealed class ApiExportOptions with _$ApiExportOptions {
+  const ApiExportOptions._();
+  const factory ApiExportOptions({
+    required bool mediaServerLayout,
+    required String groupBy,
+    String? filenameTemplate,
+    int? maxNameBytes,
+    required bool moveFiles,
+  }) = _ApiExportOptions;
+  static Future<ApiExportOptions> default_() =>
+      RustLib.instance.api.crateApiModelsApiExportOptionsDefault();
+}
+
 /// 导出进度
 @freezed
 sealed class ApiExportProgress with _$ApiExportProgress {
   const factory ApiExportProgress({
+    required String jobId,
     required int totalFiles,
     required int doneFiles,
+    required int skippedFiles,
     String? currentFile,
     required BigInt currentBytes,
     required BigInt currentTotalBytes,
@@ -202,6 +461,21 @@ sealed class ApiHomeSection with _$ApiHomeSection {
   }) = _ApiHomeSection;
 }
 
+/// 文件缺失的下载记录
+@freezed
+sealed class ApiMissingDownload with _$ApiMissingDownload {
+  const factory ApiMissingDownload({
+    required String videoId,
+    required String title,
+    required ApiDownloadStatus status,
+    required bool missingMedia,
+    required bool missingCover,
+    required bool missingAvatar,
+    required BigInt expectedBytes,
+    required List<String> candidateFiles,
+  }) = _ApiMissingDownload;
+}
+
 /// 我的列表信息（收藏、稍后观看等）
 @freezed
 sealed class ApiMyListInfo with _$ApiMyListInfo {
@@ -221,6 +495,34 @@ sealed class ApiMyListItem with _$ApiMyListItem {
   }) = _ApiMyListItem;
 }
 
+/// 下一集推荐（系列中看完一集后的下一集）
+@freezed
+sealed class ApiNextEpisode with _$ApiNextEpisode {
+  const factory ApiNextEpisode({
+    required String videoId,
+    required String title,
+    required String coverUrl,
+    required String seriesId,
+    required String seriesTitle,
+    required int episode,
+    required String previousVideoId,
+    required PlatformInt64 previousPlayedAt,
+    required bool isDownloaded,
+  }) = _ApiNextEpisode;
+}
+
+/// 没有记录引用的文件
+@freezed
+sealed class ApiOrphanFile with _$ApiOrphanFile {
+  const factory ApiOrphanFile({
+    required String dir,
+    required String fileName,
+    required BigInt size,
+    PlatformInt64? modifiedAt,
+    String? videoId,
+  }) = _ApiOrphanFile;
+}
+
 /// 播放历史记录
 @freezed
 sealed class ApiPlayHistory with _$ApiPlayHistory {
@@ -254,6 +556,47 @@ sealed class ApiPlaylistInfo with _$ApiPlaylistInfo {
   }) = _ApiPlaylistInfo;
 }
 
+/// 配额阻止下载事件
+@freezed
+sealed class ApiQuotaBlockedEvent with _$ApiQuotaBlockedEvent {
+  const factory ApiQuotaBlockedEvent({
+    required String videoId,
+    required String title,
+    required BigInt requiredBytes,
+    required BigInt usedBytes,
+    required BigInt quotaBytes,
+    required BigInt freedBytes,
+    required PlatformInt64 blockedAt,
+  }) = _ApiQuotaBlockedEvent;
+}
+
+/// 下载库核对报告（数据库记录 ↔ 本地文件）
+@freezed
+sealed class ApiReconcileReport with _$ApiReconcileReport {
+  const factory ApiReconcileReport({
+    required List<ApiMissingDownload> missing,
+    required List<ApiOrphanFile> orphans,
+    required BigInt missingBytes,
+    required BigInt orphanBytes,
+    required PlatformInt64 scannedAt,
+  }) = _ApiReconcileReport;
+}
+
+/// 下载目录迁移进度
+@freezed
+sealed class ApiRelocateProgress with _$ApiRelocateProgress {
+  const factory ApiRelocateProgress({
+    required String stage,
+    required int totalFiles,
+    required int doneFiles,
+    required BigInt totalBytes,
+    required BigInt doneBytes,
+    String? currentFile,
+    required bool done,
+    String? error,
+  }) = _ApiRelocateProgress;
+}
+
 /// 搜索过滤条件
 @freezed
 sealed class ApiSearchFilters with _$ApiSearchFilters {
@@ -307,6 +650,27 @@ sealed class ApiSeriesVideo with _$ApiSeriesVideo {
   }) = _ApiSeriesVideo;
 }
 
+/// 智能文件夹规则
+///
+/// - 不同条件之间为“且”；`authors` / `qualities` 满足任一即可，`tags` 需全部包含
+/// - 列表为空、字段为空表示不限
+@freezed
+sealed class ApiSmartFolderRule with _$ApiSmartFolderRule {
+  const ApiSmartFolderRule._();
+  const factory ApiSmartFolderRule({
+    required List<String> authors,
+    required List<String> tags,
+    required List<String> qualities,
+    PlatformInt64? downloadedFrom,
+    PlatformInt64? downloadedTo,
+    String? uploadedFrom,
+    String? uploadedTo,
+    bool? watched,
+  }) = _ApiSmartFolderRule;
+  static Future<ApiSmartFolderRule> default_() =>
+      RustLib.instance.api.crateApiModelsApiSmartFolderRuleDefault();
+}
+
 /// 我的订阅页数据
 @freezed
 sealed class ApiSubscriptionsPage with _$ApiSubscriptionsPage {
@@ -318,6 +682,17 @@ sealed class ApiSubscriptionsPage with _$ApiSubscriptionsPage {
   }) = _ApiSubscriptionsPage;
 }
 
+/// 字幕轨道
+@freezed
+sealed class ApiSubtitleTrack with _$ApiSubtitleTrack {
+  const factory ApiSubtitleTrack({
+    required String language,
+    required String label,
+    required String url,
+    required String format,
+  }) = _ApiSubtitleTrack;
+}
+
 /// 标签分组
 @freezed
 sealed class ApiTagGroup with _$ApiTagGroup {
@@ -372,6 +747,7 @@ sealed class ApiVideoDetail with _$ApiVideoDetail {
     ApiAuthorInfo? author,
     required List<String> tags,
     required List<ApiVideoQuality> qualities,
+    required List<ApiSubtitleTrack> subtitles,
     ApiSeriesInfo? series,
     required List<ApiVideoCard> relatedVideos,
     String? formToken,
@@ -389,5 +765,18 @@ sealed class ApiVideoQuality with _$ApiVideoQuality {
   const factory ApiVideoQuality({
     required String quality,
     required String url,
+    int? width,
+    int? height,
+    BigInt? bandwidth,
+    String? codecs,
   }) = _ApiVideoQuality;
 }
+
+/// 「接下来看什么」
+@freezed
+sealed class ApiWatchNext with _$ApiWatchNext {
+  const factory ApiWatchNext({
+    required List<ApiContinueWatching> continueWatching,
+    required List<ApiNextEpisode> nextEpisodes,
+  }) = _ApiWatchNext;
+}
@@ -795,40 +795,40 @@ as String?,
 }
 
 /// @nodoc
-mixin _$ApiCloudflareChallenge {
+mixin _$ApiBatchEnqueueResult {
 
- String get url; String get userAgent;
-/// Create a copy of ApiCloudflareChallenge
+ int get total; List<String> get enqueued; List<String> get skipped; List<String> get failed;
+/// Create a copy of ApiBatchEnqueueResult
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiCloudflareChallengeCopyWith<ApiCloudflareChallenge> get copyWith => _$ApiCloudflareChallengeCopyWithImpl<ApiCloudflareChallenge>(this as ApiCloudflareChallenge, _$identity);
+$ApiBatchEnqueueResultCopyWith<ApiBatchEnqueueResult> get copyWith => _$ApiBatchEnqueueResultCopyWithImpl<ApiBatchEnqueueResult>(this as ApiBatchEnqueueResult, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiCloudflareChallenge&&(identical(other.url, url) || other.url == url)&&(identical(other.userAgent, userAgent) || other.userAgent == userAgent));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiBatchEnqueueResult&&(identical(other.total, total) || other.total == total)&&const DeepCollectionEquality().equals(other.enqueued, enqueued)&&const DeepCollectionEquality().equals(other.skipped, skipped)&&const DeepCollectionEquality().equals(other.failed, failed));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,url,userAgent);
+int get hashCode => Object.hash(runtimeType,total,const DeepCollectionEquality().hash(enqueued),const DeepCollectionEquality().hash(skipped),const DeepCollectionEquality().hash(failed));
 
 @override
 String toString() {
-  return 'ApiCloudflareChallenge(url: $url, userAgent: $userAgent)';
+  return 'ApiBatchEnqueueResult(total: $total, enqueued: $enqueued, skipped: $skipped, failed: $failed)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiCloudflareChallengeCopyWith<$Res>  {
-  factory $ApiCloudflareChallengeCopyWith(ApiCloudflareChallenge value, $Res Function(ApiCloudflareChallenge) _then) = _$ApiCloudflareChallengeCopyWithImpl;
+abstract mixin class $ApiBatchEnqueueResultCopyWith<$Res>  {
+  factory $ApiBatchEnqueueResultCopyWith(ApiBatchEnqueueResult value, $Res Function(ApiBatchEnqueueResult) _then) = _$ApiBatchEnqueueResultCopyWithImpl;
 @useResult
 $Res call({
- String url, String userAgent
+ int total, List<String> enqueued, List<String> skipped, List<String> failed
 });
 
 
@@ -836,28 +836,30 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiCloudflareChallengeCopyWithImpl<$Res>
-    implements $ApiCloudflareChallengeCopyWith<$Res> {
-  _$ApiCloudflareChallengeCopyWithImpl(this._self, this._then);
+class _$ApiBatchEnqueueResultCopyWithImpl<$Res>
+    implements $ApiBatchEnqueueResultCopyWith<$Res> {
+  _$ApiBatchEnqueueResultCopyWithImpl(this._self, this._then);
 
-  final ApiCloudflareChallenge _self;
-  final $Res Function(ApiCloudflareChallenge) _then;
+  final ApiBatchEnqueueResult _self;
+  final $Res Function(ApiBatchEnqueueResult) _then;
 
-/// Create a copy of ApiCloudflareChallenge
+/// Create a copy of ApiBatchEnqueueResult
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? url = null,Object? userAgent = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? total = null,Object? enqueued = null,Object? skipped = null,Object? failed = null,}) {
   return _then(_self.copyWith(
-url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
-as String,userAgent: null == userAgent ? _self.userAgent : userAgent // ignore: cast_nullable_to_non_nullable
-as String,
+total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,enqueued: null == enqueued ? _self.enqueued : enqueued // ignore: cast_nullable_to_non_nullable
+as List<String>,skipped: null == skipped ? _self.skipped : skipped // ignore: cast_nullable_to_non_nullable
+as List<String>,failed: null == failed ? _self.failed : failed // ignore: cast_nullable_to_non_nullable
+as List<String>,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiCloudflareChallenge].
-extension ApiCloudflareChallengePatterns on ApiCloudflareChallenge {
+/// Adds pattern-matching-related methods to [ApiBatchEnqueueResult].
+extension ApiBatchEnqueueResultPatterns on ApiBatchEnqueueResult {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -870,10 +872,10 @@ extension ApiCloudflareChallengePatterns on ApiCloudflareChallenge {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiCloudflareChallenge value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiBatchEnqueueResult value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiCloudflareChallenge() when $default != null:
+case _ApiBatchEnqueueResult() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -892,10 +894,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiCloudflareChallenge value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiBatchEnqueueResult value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiCloudflareChallenge():
+case _ApiBatchEnqueueResult():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -910,10 +912,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiCloudflareChallenge value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiBatchEnqueueResult value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiCloudflareChallenge() when $default != null:
+case _ApiBatchEnqueueResult() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -931,10 +933,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String url,  String userAgent)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int total,  List<String> enqueued,  List<String> skipped,  List<String> failed)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiCloudflareChallenge() when $default != null:
-return $default(_that.url,_that.userAgent);case _:
+case _ApiBatchEnqueueResult() when $default != null:
+return $default(_that.total,_that.enqueued,_that.skipped,_that.failed);case _:
   return orElse();
 
 }
@@ -952,10 +954,10 @@ return $default(_that.url,_that.userAgent);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String url,  String userAgent)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int total,  List<String> enqueued,  List<String> skipped,  List<String> failed)  $default,) {final _that = this;
 switch (_that) {
-case _ApiCloudflareChallenge():
-return $default(_that.url,_that.userAgent);}
+case _ApiBatchEnqueueResult():
+return $default(_that.total,_that.enqueued,_that.skipped,_that.failed);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -969,10 +971,10 @@ return $default(_that.url,_that.userAgent);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String url,  String userAgent)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int total,  List<String> enqueued,  List<String> skipped,  List<String> failed)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiCloudflareChallenge() when $default != null:
-return $default(_that.url,_that.userAgent);case _:
+case _ApiBatchEnqueueResult() when $default != null:
+return $default(_that.total,_that.enqueued,_that.skipped,_that.failed);case _:
   return null;
 
 }
@@ -983,44 +985,64 @@ return $default(_that.url,_that.userAgent);case _:
 /// @nodoc
 
 
-class _ApiCloudflareChallenge implements ApiCloudflareChallenge {
-  const _ApiCloudflareChallenge({required this.url, required this.userAgent});
+class _ApiBatchEnqueueResult implements ApiBatchEnqueueResult {
+  const _ApiBatchEnqueueResult({required this.total, required final  List<String> enqueued, required final  List<String> skipped, required final  List<String> failed}): _enqueued = enqueued,_skipped = skipped,_failed = failed;
   
 
-@override final  String url;
-@override final  String userAgent;
+@override final  int total;
+ final  List<String> _enqueued;
+@override List<String> get enqueued {
+  if (_enqueued is EqualUnmodifiableListView) return _enqueued;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_enqueued);
+}
 
-/// Create a copy of ApiCloudflareChallenge
+ final  List<String> _skipped;
+@override List<String> get skipped {
+  if (_skipped is EqualUnmodifiableListView) return _skipped;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_skipped);
+}
+
+ final  List<String> _failed;
+@override List<String> get failed {
+  if (_failed is EqualUnmodifiableListView) return _failed;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_failed);
+}
+
+
+/// Create a copy of ApiBatchEnqueueResult
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiCloudflareChallengeCopyWith<_ApiCloudflareChallenge> get copyWith => __$ApiCloudflareChallengeCopyWithImpl<_ApiCloudflareChallenge>(this, _$identity);
+_$ApiBatchEnqueueResultCopyWith<_ApiBatchEnqueueResult> get copyWith => __$ApiBatchEnqueueResultCopyWithImpl<_ApiBatchEnqueueResult>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiCloudflareChallenge&&(identical(other.url, url) || other.url == url)&&(identical(other.userAgent, userAgent) || other.userAgent == userAgent));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiBatchEnqueueResult&&(identical(other.total, total) || other.total == total)&&const DeepCollectionEquality().equals(other._enqueued, _enqueued)&&const DeepCollectionEquality().equals(other._skipped, _skipped)&&const DeepCollectionEquality().equals(other._failed, _failed));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,url,userAgent);
+int get hashCode => Object.hash(runtimeType,total,const DeepCollectionEquality().hash(_enqueued),const DeepCollectionEquality().hash(_skipped),const DeepCollectionEquality().hash(_failed));
 
 @override
 String toString() {
-  return 'ApiCloudflareChallenge(url: $url, userAgent: $userAgent)';
+  return 'ApiBatchEnqueueResult(total: $total, enqueued: $enqueued, skipped: $skipped, failed: $failed)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiCloudflareChallengeCopyWith<$Res> implements $ApiCloudflareChallengeCopyWith<$Res> {
-  factory _$ApiCloudflareChallengeCopyWith(_ApiCloudflareChallenge value, $Res Function(_ApiCloudflareChallenge) _then) = __$ApiCloudflareChallengeCopyWithImpl;
+abstract mixin class _$ApiBatchEnqueueResultCopyWith<$Res> implements $ApiBatchEnqueueResultCopyWith<$Res> {
+  factory _$ApiBatchEnqueueResultCopyWith(_ApiBatchEnqueueResult value, $Res Function(_ApiBatchEnqueueResult) _then) = __$ApiBatchEnqueueResultCopyWithImpl;
 @override @useResult
 $Res call({
- String url, String userAgent
+ int total, List<String> enqueued, List<String> skipped, List<String> failed
 });
 
 
@@ -1028,20 +1050,22 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiCloudflareChallengeCopyWithImpl<$Res>
-    implements _$ApiCloudflareChallengeCopyWith<$Res> {
-  __$ApiCloudflareChallengeCopyWithImpl(this._self, this._then);
+class __$ApiBatchEnqueueResultCopyWithImpl<$Res>
+    implements _$ApiBatchEnqueueResultCopyWith<$Res> {
+  __$ApiBatchEnqueueResultCopyWithImpl(this._self, this._then);
 
-  final _ApiCloudflareChallenge _self;
-  final $Res Function(_ApiCloudflareChallenge) _then;
+  final _ApiBatchEnqueueResult _self;
+  final $Res Function(_ApiBatchEnqueueResult) _then;
 
-/// Create a copy of ApiCloudflareChallenge
+/// Create a copy of ApiBatchEnqueueResult
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? url = null,Object? userAgent = null,}) {
-  return _then(_ApiCloudflareChallenge(
-url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
-as String,userAgent: null == userAgent ? _self.userAgent : userAgent // ignore: cast_nullable_to_non_nullable
-as String,
+@override @pragma('vm:prefer-inline') $Res call({Object? total = null,Object? enqueued = null,Object? skipped = null,Object? failed = null,}) {
+  return _then(_ApiBatchEnqueueResult(
+total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,enqueued: null == enqueued ? _self._enqueued : enqueued // ignore: cast_nullable_to_non_nullable
+as List<String>,skipped: null == skipped ? _self._skipped : skipped // ignore: cast_nullable_to_non_nullable
+as List<String>,failed: null == failed ? _self._failed : failed // ignore: cast_nullable_to_non_nullable
+as List<String>,
   ));
 }
 
@@ -1049,40 +1073,40 @@ as String,
 }
 
 /// @nodoc
-mixin _$ApiComment {
+mixin _$ApiBundleProgress {
 
- String get id; String get userName; String? get userAvatar; String get content; String get time; int get likes; int get dislikes; List<ApiComment> get replies; bool get hasMoreReplies;
-/// Create a copy of ApiComment
+ String get stage; int get totalItems; int get doneItems; int get exported; int get imported; int get skipped; int get failed; String? get currentItem; BigInt get currentBytes; BigInt get currentTotalBytes; bool get done; String? get error;
+/// Create a copy of ApiBundleProgress
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiCommentCopyWith<ApiComment> get copyWith => _$ApiCommentCopyWithImpl<ApiComment>(this as ApiComment, _$identity);
+$ApiBundleProgressCopyWith<ApiBundleProgress> get copyWith => _$ApiBundleProgressCopyWithImpl<ApiBundleProgress>(this as ApiBundleProgress, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiComment&&(identical(other.id, id) || other.id == id)&&(identical(other.userName, userName) || other.userName == userName)&&(identical(other.userAvatar, userAvatar) || other.userAvatar == userAvatar)&&(identical(other.content, content) || other.content == content)&&(identical(other.time, time) || other.time == time)&&(identical(other.likes, likes) || other.likes == likes)&&(identical(other.dislikes, dislikes) || other.dislikes == dislikes)&&const DeepCollectionEquality().equals(other.replies, replies)&&(identical(other.hasMoreReplies, hasMoreReplies) || other.hasMoreReplies == hasMoreReplies));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiBundleProgress&&(identical(other.stage, stage) || other.stage == stage)&&(identical(other.totalItems, totalItems) || other.totalItems == totalItems)&&(identical(other.doneItems, doneItems) || other.doneItems == doneItems)&&(identical(other.exported, exported) || other.exported == exported)&&(identical(other.imported, imported) || other.imported == imported)&&(identical(other.skipped, skipped) || other.skipped == skipped)&&(identical(other.failed, failed) || other.failed == failed)&&(identical(other.currentItem, currentItem) || other.currentItem == currentItem)&&(identical(other.currentBytes, currentBytes) || other.currentBytes == currentBytes)&&(identical(other.currentTotalBytes, currentTotalBytes) || other.currentTotalBytes == currentTotalBytes)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,userName,userAvatar,content,time,likes,dislikes,const DeepCollectionEquality().hash(replies),hasMoreReplies);
+int get hashCode => Object.hash(runtimeType,stage,totalItems,doneItems,exported,imported,skipped,failed,currentItem,currentBytes,currentTotalBytes,done,error);
 
 @override
 String toString() {
-  return 'ApiComment(id: $id, userName: $userName, userAvatar: $userAvatar, content: $content, time: $time, likes: $likes, dislikes: $dislikes, replies: $replies, hasMoreReplies: $hasMoreReplies)';
+  return 'ApiBundleProgress(stage: $stage, totalItems: $totalItems, doneItems: $doneItems, exported: $exported, imported: $imported, skipped: $skipped, failed: $failed, currentItem: $currentItem, currentBytes: $currentBytes, currentTotalBytes: $currentTotalBytes, done: $done, error: $error)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiCommentCopyWith<$Res>  {
-  factory $ApiCommentCopyWith(ApiComment value, $Res Function(ApiComment) _then) = _$ApiCommentCopyWithImpl;
+abstract mixin class $ApiBundleProgressCopyWith<$Res>  {
+  factory $ApiBundleProgressCopyWith(ApiBundleProgress value, $Res Function(ApiBundleProgress) _then) = _$ApiBundleProgressCopyWithImpl;
 @useResult
 $Res call({
- String id, String userName, String? userAvatar, String content, String time, int likes, int dislikes, List<ApiComment> replies, bool hasMoreReplies
+ String stage, int totalItems, int doneItems, int exported, int imported, int skipped, int failed, String? currentItem, BigInt currentBytes, BigInt currentTotalBytes, bool done, String? error
 });
 
 
@@ -1090,35 +1114,38 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiCommentCopyWithImpl<$Res>
-    implements $ApiCommentCopyWith<$Res> {
-  _$ApiCommentCopyWithImpl(this._self, this._then);
+class _$ApiBundleProgressCopyWithImpl<$Res>
+    implements $ApiBundleProgressCopyWith<$Res> {
+  _$ApiBundleProgressCopyWithImpl(this._self, this._then);
 
-  final ApiComment _self;
-  final $Res Function(ApiComment) _then;
+  final ApiBundleProgress _self;
+  final $Res Function(ApiBundleProgress) _then;
 
-/// Create a copy of ApiComment
+/// Create a copy of ApiBundleProgress
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? userName = null,Object? userAvatar = freezed,Object? content = null,Object? time = null,Object? likes = null,Object? dislikes = null,Object? replies = null,Object? hasMoreReplies = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? stage = null,Object? totalItems = null,Object? doneItems = null,Object? exported = null,Object? imported = null,Object? skipped = null,Object? failed = null,Object? currentItem = freezed,Object? currentBytes = null,Object? currentTotalBytes = null,Object? done = null,Object? error = freezed,}) {
   return _then(_self.copyWith(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,userName: null == userName ? _self.userName : userName // ignore: cast_nullable_to_non_nullable
-as String,userAvatar: freezed == userAvatar ? _self.userAvatar : userAvatar // ignore: cast_nullable_to_non_nullable
-as String?,content: null == content ? _self.content : content // ignore: cast_nullable_to_non_nullable
-as String,time: null == time ? _self.time : time // ignore: cast_nullable_to_non_nullable
-as String,likes: null == likes ? _self.likes : likes // ignore: cast_nullable_to_non_nullable
-as int,dislikes: null == dislikes ? _self.dislikes : dislikes // ignore: cast_nullable_to_non_nullable
-as int,replies: null == replies ? _self.replies : replies // ignore: cast_nullable_to_non_nullable
-as List<ApiComment>,hasMoreReplies: null == hasMoreReplies ? _self.hasMoreReplies : hasMoreReplies // ignore: cast_nullable_to_non_nullable
-as bool,
+stage: null == stage ? _self.stage : stage // ignore: cast_nullable_to_non_nullable
+as String,totalItems: null == totalItems ? _self.totalItems : totalItems // ignore: cast_nullable_to_non_nullable
+as int,doneItems: null == doneItems ? _self.doneItems : doneItems // ignore: cast_nullable_to_non_nullable
+as int,exported: null == exported ? _self.exported : exported // ignore: cast_nullable_to_non_nullable
+as int,imported: null == imported ? _self.imported : imported // ignore: cast_nullable_to_non_nullable
+as int,skipped: null == skipped ? _self.skipped : skipped // ignore: cast_nullable_to_non_nullable
+as int,failed: null == failed ? _self.failed : failed // ignore: cast_nullable_to_non_nullable
+as int,currentItem: freezed == currentItem ? _self.currentItem : currentItem // ignore: cast_nullable_to_non_nullable
+as String?,currentBytes: null == currentBytes ? _self.currentBytes : currentBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,currentTotalBytes: null == currentTotalBytes ? _self.currentTotalBytes : currentTotalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
+as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiComment].
-extension ApiCommentPatterns on ApiComment {
+/// Adds pattern-matching-related methods to [ApiBundleProgress].
+extension ApiBundleProgressPatterns on ApiBundleProgress {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -1131,10 +1158,10 @@ extension ApiCommentPatterns on ApiComment {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiComment value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiBundleProgress value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiComment() when $default != null:
+case _ApiBundleProgress() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -1153,10 +1180,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiComment value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiBundleProgress value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiComment():
+case _ApiBundleProgress():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -1171,10 +1198,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiComment value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiBundleProgress value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiComment() when $default != null:
+case _ApiBundleProgress() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -1192,10 +1219,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String userName,  String? userAvatar,  String content,  String time,  int likes,  int dislikes,  List<ApiComment> replies,  bool hasMoreReplies)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String stage,  int totalItems,  int doneItems,  int exported,  int imported,  int skipped,  int failed,  String? currentItem,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiComment() when $default != null:
-return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.time,_that.likes,_that.dislikes,_that.replies,_that.hasMoreReplies);case _:
+case _ApiBundleProgress() when $default != null:
+return $default(_that.stage,_that.totalItems,_that.doneItems,_that.exported,_that.imported,_that.skipped,_that.failed,_that.currentItem,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);case _:
   return orElse();
 
 }
@@ -1213,10 +1240,10 @@ return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.tim
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String userName,  String? userAvatar,  String content,  String time,  int likes,  int dislikes,  List<ApiComment> replies,  bool hasMoreReplies)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String stage,  int totalItems,  int doneItems,  int exported,  int imported,  int skipped,  int failed,  String? currentItem,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)  $default,) {final _that = this;
 switch (_that) {
-case _ApiComment():
-return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.time,_that.likes,_that.dislikes,_that.replies,_that.hasMoreReplies);}
+case _ApiBundleProgress():
+return $default(_that.stage,_that.totalItems,_that.doneItems,_that.exported,_that.imported,_that.skipped,_that.failed,_that.currentItem,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -1230,10 +1257,10 @@ return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.tim
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String userName,  String? userAvatar,  String content,  String time,  int likes,  int dislikes,  List<ApiComment> replies,  bool hasMoreReplies)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String stage,  int totalItems,  int doneItems,  int exported,  int imported,  int skipped,  int failed,  String? currentItem,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiComment() when $default != null:
-return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.time,_that.likes,_that.dislikes,_that.replies,_that.hasMoreReplies);case _:
+case _ApiBundleProgress() when $default != null:
+return $default(_that.stage,_that.totalItems,_that.doneItems,_that.exported,_that.imported,_that.skipped,_that.failed,_that.currentItem,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);case _:
   return null;
 
 }
@@ -1244,57 +1271,54 @@ return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.tim
 /// @nodoc
 
 
-class _ApiComment implements ApiComment {
-  const _ApiComment({required this.id, required this.userName, this.userAvatar, required this.content, required this.time, required this.likes, required this.dislikes, required final  List<ApiComment> replies, required this.hasMoreReplies}): _replies = replies;
+class _ApiBundleProgress implements ApiBundleProgress {
+  const _ApiBundleProgress({required this.stage, required this.totalItems, required this.doneItems, required this.exported, required this.imported, required this.skipped, required this.failed, this.currentItem, required this.currentBytes, required this.currentTotalBytes, required this.done, this.error});
   
 
-@override final  String id;
-@override final  String userName;
-@override final  String? userAvatar;
-@override final  String content;
-@override final  String time;
-@override final  int likes;
-@override final  int dislikes;
- final  List<ApiComment> _replies;
-@override List<ApiComment> get replies {
-  if (_replies is EqualUnmodifiableListView) return _replies;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_replies);
-}
-
-@override final  bool hasMoreReplies;
+@override final  String stage;
+@override final  int totalItems;
+@override final  int doneItems;
+@override final  int exported;
+@override final  int imported;
+@override final  int skipped;
+@override final  int failed;
+@override final  String? currentItem;
+@override final  BigInt currentBytes;
+@override final  BigInt currentTotalBytes;
+@override final  bool done;
+@override final  String? error;
 
-/// Create a copy of ApiComment
+/// Create a copy of ApiBundleProgress
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiCommentCopyWith<_ApiComment> get copyWith => __$ApiCommentCopyWithImpl<_ApiComment>(this, _$identity);
+_$ApiBundleProgressCopyWith<_ApiBundleProgress> get copyWith => __$ApiBundleProgressCopyWithImpl<_ApiBundleProgress>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiComment&&(identical(other.id, id) || other.id == id)&&(identical(other.userName, userName) || other.userName == userName)&&(identical(other.userAvatar, userAvatar) || other.userAvatar == userAvatar)&&(identical(other.content, content) || other.content == content)&&(identical(other.time, time) || other.time == time)&&(identical(other.likes, likes) || other.likes == likes)&&(identical(other.dislikes, dislikes) || other.dislikes == dislikes)&&const DeepCollectionEquality().equals(other._replies, _replies)&&(identical(other.hasMoreReplies, hasMoreReplies) || other.hasMoreReplies == hasMoreReplies));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiBundleProgress&&(identical(other.stage, stage) || other.stage == stage)&&(identical(other.totalItems, totalItems) || other.totalItems == totalItems)&&(identical(other.doneItems, doneItems) || other.doneItems == doneItems)&&(identical(other.exported, exported) || other.exported == exported)&&(identical(other.imported, imported) || other.imported == imported)&&(identical(other.skipped, skipped) || other.skipped == skipped)&&(identical(other.failed, failed) || other.failed == failed)&&(identical(other.currentItem, currentItem) || other.currentItem == currentItem)&&(identical(other.currentBytes, currentBytes) || other.currentBytes == currentBytes)&&(identical(other.currentTotalBytes, currentTotalBytes) || other.currentTotalBytes == currentTotalBytes)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,userName,userAvatar,content,time,likes,dislikes,const DeepCollectionEquality().hash(_replies),hasMoreReplies);
+int get hashCode => Object.hash(runtimeType,stage,totalItems,doneItems,exported,imported,skipped,failed,currentItem,currentBytes,currentTotalBytes,done,error);
 
 @override
 String toString() {
-  return 'ApiComment(id: $id, userName: $userName, userAvatar: $userAvatar, content: $content, time: $time, likes: $likes, dislikes: $dislikes, replies: $replies, hasMoreReplies: $hasMoreReplies)';
+  return 'ApiBundleProgress(stage: $stage, totalItems: $totalItems, doneItems: $doneItems, exported: $exported, imported: $imported, skipped: $skipped, failed: $failed, currentItem: $currentItem, currentBytes: $currentBytes, currentTotalBytes: $currentTotalBytes, done: $done, error: $error)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiCommentCopyWith<$Res> implements $ApiCommentCopyWith<$Res> {
-  factory _$ApiCommentCopyWith(_ApiComment value, $Res Function(_ApiComment) _then) = __$ApiCommentCopyWithImpl;
+abstract mixin class _$ApiBundleProgressCopyWith<$Res> implements $ApiBundleProgressCopyWith<$Res> {
+  factory _$ApiBundleProgressCopyWith(_ApiBundleProgress value, $Res Function(_ApiBundleProgress) _then) = __$ApiBundleProgressCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String userName, String? userAvatar, String content, String time, int likes, int dislikes, List<ApiComment> replies, bool hasMoreReplies
+ String stage, int totalItems, int doneItems, int exported, int imported, int skipped, int failed, String? currentItem, BigInt currentBytes, BigInt currentTotalBytes, bool done, String? error
 });
 
 
@@ -1302,27 +1326,30 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiCommentCopyWithImpl<$Res>
-    implements _$ApiCommentCopyWith<$Res> {
-  __$ApiCommentCopyWithImpl(this._self, this._then);
+class __$ApiBundleProgressCopyWithImpl<$Res>
+    implements _$ApiBundleProgressCopyWith<$Res> {
+  __$ApiBundleProgressCopyWithImpl(this._self, this._then);
 
-  final _ApiComment _self;
-  final $Res Function(_ApiComment) _then;
+  final _ApiBundleProgress _self;
+  final $Res Function(_ApiBundleProgress) _then;
 
-/// Create a copy of ApiComment
+/// Create a copy of ApiBundleProgress
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? userName = null,Object? userAvatar = freezed,Object? content = null,Object? time = null,Object? likes = null,Object? dislikes = null,Object? replies = null,Object? hasMoreReplies = null,}) {
-  return _then(_ApiComment(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,userName: null == userName ? _self.userName : userName // ignore: cast_nullable_to_non_nullable
-as String,userAvatar: freezed == userAvatar ? _self.userAvatar : userAvatar // ignore: cast_nullable_to_non_nullable
-as String?,content: null == content ? _self.content : content // ignore: cast_nullable_to_non_nullable
-as String,time: null == time ? _self.time : time // ignore: cast_nullable_to_non_nullable
-as String,likes: null == likes ? _self.likes : likes // ignore: cast_nullable_to_non_nullable
-as int,dislikes: null == dislikes ? _self.dislikes : dislikes // ignore: cast_nullable_to_non_nullable
-as int,replies: null == replies ? _self._replies : replies // ignore: cast_nullable_to_non_nullable
-as List<ApiComment>,hasMoreReplies: null == hasMoreReplies ? _self.hasMoreReplies : hasMoreReplies // ignore: cast_nullable_to_non_nullable
-as bool,
+@override @pragma('vm:prefer-inline') $Res call({Object? stage = null,Object? totalItems = null,Object? doneItems = null,Object? exported = null,Object? imported = null,Object? skipped = null,Object? failed = null,Object? currentItem = freezed,Object? currentBytes = null,Object? currentTotalBytes = null,Object? done = null,Object? error = freezed,}) {
+  return _then(_ApiBundleProgress(
+stage: null == stage ? _self.stage : stage // ignore: cast_nullable_to_non_nullable
+as String,totalItems: null == totalItems ? _self.totalItems : totalItems // ignore: cast_nullable_to_non_nullable
+as int,doneItems: null == doneItems ? _self.doneItems : doneItems // ignore: cast_nullable_to_non_nullable
+as int,exported: null == exported ? _self.exported : exported // ignore: cast_nullable_to_non_nullable
+as int,imported: null == imported ? _self.imported : imported // ignore: cast_nullable_to_non_nullable
+as int,skipped: null == skipped ? _self.skipped : skipped // ignore: cast_nullable_to_non_nullable
+as int,failed: null == failed ? _self.failed : failed // ignore: cast_nullable_to_non_nullable
+as int,currentItem: freezed == currentItem ? _self.currentItem : currentItem // ignore: cast_nullable_to_non_nullable
+as String?,currentBytes: null == currentBytes ? _self.currentBytes : currentBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,currentTotalBytes: null == currentTotalBytes ? _self.currentTotalBytes : currentTotalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
+as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
@@ -1330,40 +1357,40 @@ as bool,
 }
 
 /// @nodoc
-mixin _$ApiCommentList {
+mixin _$ApiByteRange {
 
- List<ApiComment> get comments; int get total; int get page; bool get hasNext;
-/// Create a copy of ApiCommentList
+ BigInt get start; BigInt get end;
+/// Create a copy of ApiByteRange
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiCommentListCopyWith<ApiCommentList> get copyWith => _$ApiCommentListCopyWithImpl<ApiCommentList>(this as ApiCommentList, _$identity);
+$ApiByteRangeCopyWith<ApiByteRange> get copyWith => _$ApiByteRangeCopyWithImpl<ApiByteRange>(this as ApiByteRange, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiCommentList&&const DeepCollectionEquality().equals(other.comments, comments)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiByteRange&&(identical(other.start, start) || other.start == start)&&(identical(other.end, end) || other.end == end));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(comments),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,start,end);
 
 @override
 String toString() {
-  return 'ApiCommentList(comments: $comments, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiByteRange(start: $start, end: $end)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiCommentListCopyWith<$Res>  {
-  factory $ApiCommentListCopyWith(ApiCommentList value, $Res Function(ApiCommentList) _then) = _$ApiCommentListCopyWithImpl;
+abstract mixin class $ApiByteRangeCopyWith<$Res>  {
+  factory $ApiByteRangeCopyWith(ApiByteRange value, $Res Function(ApiByteRange) _then) = _$ApiByteRangeCopyWithImpl;
 @useResult
 $Res call({
- List<ApiComment> comments, int total, int page, bool hasNext
+ BigInt start, BigInt end
 });
 
 
@@ -1371,30 +1398,28 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiCommentListCopyWithImpl<$Res>
-    implements $ApiCommentListCopyWith<$Res> {
-  _$ApiCommentListCopyWithImpl(this._self, this._then);
+class _$ApiByteRangeCopyWithImpl<$Res>
+    implements $ApiByteRangeCopyWith<$Res> {
+  _$ApiByteRangeCopyWithImpl(this._self, this._then);
 
-  final ApiCommentList _self;
-  final $Res Function(ApiCommentList) _then;
+  final ApiByteRange _self;
+  final $Res Function(ApiByteRange) _then;
 
-/// Create a copy of ApiCommentList
+/// Create a copy of ApiByteRange
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? comments = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? start = null,Object? end = null,}) {
   return _then(_self.copyWith(
-comments: null == comments ? _self.comments : comments // ignore: cast_nullable_to_non_nullable
-as List<ApiComment>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+start: null == start ? _self.start : start // ignore: cast_nullable_to_non_nullable
+as BigInt,end: null == end ? _self.end : end // ignore: cast_nullable_to_non_nullable
+as BigInt,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiCommentList].
-extension ApiCommentListPatterns on ApiCommentList {
+/// Adds pattern-matching-related methods to [ApiByteRange].
+extension ApiByteRangePatterns on ApiByteRange {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -1407,10 +1432,10 @@ extension ApiCommentListPatterns on ApiCommentList {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiCommentList value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiByteRange value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiCommentList() when $default != null:
+case _ApiByteRange() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -1429,10 +1454,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiCommentList value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiByteRange value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiCommentList():
+case _ApiByteRange():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -1447,10 +1472,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiCommentList value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiByteRange value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiCommentList() when $default != null:
+case _ApiByteRange() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -1468,10 +1493,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiComment> comments,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt start,  BigInt end)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiCommentList() when $default != null:
-return $default(_that.comments,_that.total,_that.page,_that.hasNext);case _:
+case _ApiByteRange() when $default != null:
+return $default(_that.start,_that.end);case _:
   return orElse();
 
 }
@@ -1489,10 +1514,10 @@ return $default(_that.comments,_that.total,_that.page,_that.hasNext);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiComment> comments,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt start,  BigInt end)  $default,) {final _that = this;
 switch (_that) {
-case _ApiCommentList():
-return $default(_that.comments,_that.total,_that.page,_that.hasNext);}
+case _ApiByteRange():
+return $default(_that.start,_that.end);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -1506,10 +1531,10 @@ return $default(_that.comments,_that.total,_that.page,_that.hasNext);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiComment> comments,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt start,  BigInt end)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiCommentList() when $default != null:
-return $default(_that.comments,_that.total,_that.page,_that.hasNext);case _:
+case _ApiByteRange() when $default != null:
+return $default(_that.start,_that.end);case _:
   return null;
 
 }
@@ -1520,52 +1545,44 @@ return $default(_that.comments,_that.total,_that.page,_that.hasNext);case _:
 /// @nodoc
 
 
-class _ApiCommentList implements ApiCommentList {
-  const _ApiCommentList({required final  List<ApiComment> comments, required this.total, required this.page, required this.hasNext}): _comments = comments;
+class _ApiByteRange implements ApiByteRange {
+  const _ApiByteRange({required this.start, required this.end});
   
 
- final  List<ApiComment> _comments;
-@override List<ApiComment> get comments {
-  if (_comments is EqualUnmodifiableListView) return _comments;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_comments);
-}
-
-@override final  int total;
-@override final  int page;
-@override final  bool hasNext;
+@override final  BigInt start;
+@override final  BigInt end;
 
-/// Create a copy of ApiCommentList
+/// Create a copy of ApiByteRange
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiCommentListCopyWith<_ApiCommentList> get copyWith => __$ApiCommentListCopyWithImpl<_ApiCommentList>(this, _$identity);
+_$ApiByteRangeCopyWith<_ApiByteRange> get copyWith => __$ApiByteRangeCopyWithImpl<_ApiByteRange>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiCommentList&&const DeepCollectionEquality().equals(other._comments, _comments)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiByteRange&&(identical(other.start, start) || other.start == start)&&(identical(other.end, end) || other.end == end));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_comments),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,start,end);
 
 @override
 String toString() {
-  return 'ApiCommentList(comments: $comments, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiByteRange(start: $start, end: $end)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiCommentListCopyWith<$Res> implements $ApiCommentListCopyWith<$Res> {
-  factory _$ApiCommentListCopyWith(_ApiCommentList value, $Res Function(_ApiCommentList) _then) = __$ApiCommentListCopyWithImpl;
+abstract mixin class _$ApiByteRangeCopyWith<$Res> implements $ApiByteRangeCopyWith<$Res> {
+  factory _$ApiByteRangeCopyWith(_ApiByteRange value, $Res Function(_ApiByteRange) _then) = __$ApiByteRangeCopyWithImpl;
 @override @useResult
 $Res call({
- List<ApiComment> comments, int total, int page, bool hasNext
+ BigInt start, BigInt end
 });
 
 
@@ -1573,22 +1590,20 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiCommentListCopyWithImpl<$Res>
-    implements _$ApiCommentListCopyWith<$Res> {
-  __$ApiCommentListCopyWithImpl(this._self, this._then);
+class __$ApiByteRangeCopyWithImpl<$Res>
+    implements _$ApiByteRangeCopyWith<$Res> {
+  __$ApiByteRangeCopyWithImpl(this._self, this._then);
 
-  final _ApiCommentList _self;
-  final $Res Function(_ApiCommentList) _then;
+  final _ApiByteRange _self;
+  final $Res Function(_ApiByteRange) _then;
 
-/// Create a copy of ApiCommentList
+/// Create a copy of ApiByteRange
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? comments = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
-  return _then(_ApiCommentList(
-comments: null == comments ? _self._comments : comments // ignore: cast_nullable_to_non_nullable
-as List<ApiComment>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+@override @pragma('vm:prefer-inline') $Res call({Object? start = null,Object? end = null,}) {
+  return _then(_ApiByteRange(
+start: null == start ? _self.start : start // ignore: cast_nullable_to_non_nullable
+as BigInt,end: null == end ? _self.end : end // ignore: cast_nullable_to_non_nullable
+as BigInt,
   ));
 }
 
@@ -1596,40 +1611,40 @@ as bool,
 }
 
 /// @nodoc
-mixin _$ApiDownloadFolder {
+mixin _$ApiCleanupItem {
 
- String get id; String get name; PlatformInt64 get createdAt;
-/// Create a copy of ApiDownloadFolder
+ String get videoId; String get title; String get reason; BigInt get size; PlatformInt64 get completedAt; PlatformInt64? get watchedAt;
+/// Create a copy of ApiCleanupItem
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiDownloadFolderCopyWith<ApiDownloadFolder> get copyWith => _$ApiDownloadFolderCopyWithImpl<ApiDownloadFolder>(this as ApiDownloadFolder, _$identity);
+$ApiCleanupItemCopyWith<ApiCleanupItem> get copyWith => _$ApiCleanupItemCopyWithImpl<ApiCleanupItem>(this as ApiCleanupItem, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadFolder&&(identical(other.id, id) || other.id == id)&&(identical(other.name, name) || other.name == name)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiCleanupItem&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.reason, reason) || other.reason == reason)&&(identical(other.size, size) || other.size == size)&&(identical(other.completedAt, completedAt) || other.completedAt == completedAt)&&(identical(other.watchedAt, watchedAt) || other.watchedAt == watchedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,name,createdAt);
+int get hashCode => Object.hash(runtimeType,videoId,title,reason,size,completedAt,watchedAt);
 
 @override
 String toString() {
-  return 'ApiDownloadFolder(id: $id, name: $name, createdAt: $createdAt)';
+  return 'ApiCleanupItem(videoId: $videoId, title: $title, reason: $reason, size: $size, completedAt: $completedAt, watchedAt: $watchedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiDownloadFolderCopyWith<$Res>  {
-  factory $ApiDownloadFolderCopyWith(ApiDownloadFolder value, $Res Function(ApiDownloadFolder) _then) = _$ApiDownloadFolderCopyWithImpl;
+abstract mixin class $ApiCleanupItemCopyWith<$Res>  {
+  factory $ApiCleanupItemCopyWith(ApiCleanupItem value, $Res Function(ApiCleanupItem) _then) = _$ApiCleanupItemCopyWithImpl;
 @useResult
 $Res call({
- String id, String name, PlatformInt64 createdAt
+ String videoId, String title, String reason, BigInt size, PlatformInt64 completedAt, PlatformInt64? watchedAt
 });
 
 
@@ -1637,29 +1652,32 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiDownloadFolderCopyWithImpl<$Res>
-    implements $ApiDownloadFolderCopyWith<$Res> {
-  _$ApiDownloadFolderCopyWithImpl(this._self, this._then);
+class _$ApiCleanupItemCopyWithImpl<$Res>
+    implements $ApiCleanupItemCopyWith<$Res> {
+  _$ApiCleanupItemCopyWithImpl(this._self, this._then);
 
-  final ApiDownloadFolder _self;
-  final $Res Function(ApiDownloadFolder) _then;
+  final ApiCleanupItem _self;
+  final $Res Function(ApiCleanupItem) _then;
 
-/// Create a copy of ApiDownloadFolder
+/// Create a copy of ApiCleanupItem
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? name = null,Object? createdAt = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? reason = null,Object? size = null,Object? completedAt = null,Object? watchedAt = freezed,}) {
   return _then(_self.copyWith(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,completedAt: null == completedAt ? _self.completedAt : completedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,watchedAt: freezed == watchedAt ? _self.watchedAt : watchedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiDownloadFolder].
-extension ApiDownloadFolderPatterns on ApiDownloadFolder {
+/// Adds pattern-matching-related methods to [ApiCleanupItem].
+extension ApiCleanupItemPatterns on ApiCleanupItem {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -1672,10 +1690,10 @@ extension ApiDownloadFolderPatterns on ApiDownloadFolder {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadFolder value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiCleanupItem value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiDownloadFolder() when $default != null:
+case _ApiCleanupItem() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -1694,10 +1712,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadFolder value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiCleanupItem value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiDownloadFolder():
+case _ApiCleanupItem():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -1712,10 +1730,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadFolder value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiCleanupItem value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiDownloadFolder() when $default != null:
+case _ApiCleanupItem() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -1733,10 +1751,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String name,  PlatformInt64 createdAt)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  String reason,  BigInt size,  PlatformInt64 completedAt,  PlatformInt64? watchedAt)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiDownloadFolder() when $default != null:
-return $default(_that.id,_that.name,_that.createdAt);case _:
+case _ApiCleanupItem() when $default != null:
+return $default(_that.videoId,_that.title,_that.reason,_that.size,_that.completedAt,_that.watchedAt);case _:
   return orElse();
 
 }
@@ -1754,10 +1772,10 @@ return $default(_that.id,_that.name,_that.createdAt);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String name,  PlatformInt64 createdAt)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  String reason,  BigInt size,  PlatformInt64 completedAt,  PlatformInt64? watchedAt)  $default,) {final _that = this;
 switch (_that) {
-case _ApiDownloadFolder():
-return $default(_that.id,_that.name,_that.createdAt);}
+case _ApiCleanupItem():
+return $default(_that.videoId,_that.title,_that.reason,_that.size,_that.completedAt,_that.watchedAt);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -1771,10 +1789,10 @@ return $default(_that.id,_that.name,_that.createdAt);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String name,  PlatformInt64 createdAt)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  String reason,  BigInt size,  PlatformInt64 completedAt,  PlatformInt64? watchedAt)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiDownloadFolder() when $default != null:
-return $default(_that.id,_that.name,_that.createdAt);case _:
+case _ApiCleanupItem() when $default != null:
+return $default(_that.videoId,_that.title,_that.reason,_that.size,_that.completedAt,_that.watchedAt);case _:
   return null;
 
 }
@@ -1785,45 +1803,48 @@ return $default(_that.id,_that.name,_that.createdAt);case _:
 /// @nodoc
 
 
-class _ApiDownloadFolder implements ApiDownloadFolder {
-  const _ApiDownloadFolder({required this.id, required this.name, required this.createdAt});
+class _ApiCleanupItem implements ApiCleanupItem {
+  const _ApiCleanupItem({required this.videoId, required this.title, required this.reason, required this.size, required this.completedAt, this.watchedAt});
   
 
-@override final  String id;
-@override final  String name;
-@override final  PlatformInt64 createdAt;
+@override final  String videoId;
+@override final  String title;
+@override final  String reason;
+@override final  BigInt size;
+@override final  PlatformInt64 completedAt;
+@override final  PlatformInt64? watchedAt;
 
-/// Create a copy of ApiDownloadFolder
+/// Create a copy of ApiCleanupItem
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiDownloadFolderCopyWith<_ApiDownloadFolder> get copyWith => __$ApiDownloadFolderCopyWithImpl<_ApiDownloadFolder>(this, _$identity);
+_$ApiCleanupItemCopyWith<_ApiCleanupItem> get copyWith => __$ApiCleanupItemCopyWithImpl<_ApiCleanupItem>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadFolder&&(identical(other.id, id) || other.id == id)&&(identical(other.name, name) || other.name == name)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiCleanupItem&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.reason, reason) || other.reason == reason)&&(identical(other.size, size) || other.size == size)&&(identical(other.completedAt, completedAt) || other.completedAt == completedAt)&&(identical(other.watchedAt, watchedAt) || other.watchedAt == watchedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,name,createdAt);
+int get hashCode => Object.hash(runtimeType,videoId,title,reason,size,completedAt,watchedAt);
 
 @override
 String toString() {
-  return 'ApiDownloadFolder(id: $id, name: $name, createdAt: $createdAt)';
+  return 'ApiCleanupItem(videoId: $videoId, title: $title, reason: $reason, size: $size, completedAt: $completedAt, watchedAt: $watchedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiDownloadFolderCopyWith<$Res> implements $ApiDownloadFolderCopyWith<$Res> {
-  factory _$ApiDownloadFolderCopyWith(_ApiDownloadFolder value, $Res Function(_ApiDownloadFolder) _then) = __$ApiDownloadFolderCopyWithImpl;
+abstract mixin class _$ApiCleanupItemCopyWith<$Res> implements $ApiCleanupItemCopyWith<$Res> {
+  factory _$ApiCleanupItemCopyWith(_ApiCleanupItem value, $Res Function(_ApiCleanupItem) _then) = __$ApiCleanupItemCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String name, PlatformInt64 createdAt
+ String videoId, String title, String reason, BigInt size, PlatformInt64 completedAt, PlatformInt64? watchedAt
 });
 
 
@@ -1831,21 +1852,24 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiDownloadFolderCopyWithImpl<$Res>
-    implements _$ApiDownloadFolderCopyWith<$Res> {
-  __$ApiDownloadFolderCopyWithImpl(this._self, this._then);
+class __$ApiCleanupItemCopyWithImpl<$Res>
+    implements _$ApiCleanupItemCopyWith<$Res> {
+  __$ApiCleanupItemCopyWithImpl(this._self, this._then);
 
-  final _ApiDownloadFolder _self;
-  final $Res Function(_ApiDownloadFolder) _then;
+  final _ApiCleanupItem _self;
+  final $Res Function(_ApiCleanupItem) _then;
 
-/// Create a copy of ApiDownloadFolder
+/// Create a copy of ApiCleanupItem
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? name = null,Object? createdAt = null,}) {
-  return _then(_ApiDownloadFolder(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,
+@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? reason = null,Object? size = null,Object? completedAt = null,Object? watchedAt = freezed,}) {
+  return _then(_ApiCleanupItem(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,completedAt: null == completedAt ? _self.completedAt : completedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,watchedAt: freezed == watchedAt ? _self.watchedAt : watchedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
   ));
 }
 
@@ -1853,37 +1877,71 @@ as PlatformInt64,
 }
 
 /// @nodoc
-mixin _$ApiDownloadStatus {
-
+mixin _$ApiCleanupPlan {
 
+ BigInt get usedBytes; BigInt? get quotaBytes; List<ApiCleanupItem> get items; BigInt get freedBytes;
+/// Create a copy of ApiCleanupPlan
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiCleanupPlanCopyWith<ApiCleanupPlan> get copyWith => _$ApiCleanupPlanCopyWithImpl<ApiCleanupPlan>(this as ApiCleanupPlan, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiCleanupPlan&&(identical(other.usedBytes, usedBytes) || other.usedBytes == usedBytes)&&(identical(other.quotaBytes, quotaBytes) || other.quotaBytes == quotaBytes)&&const DeepCollectionEquality().equals(other.items, items)&&(identical(other.freedBytes, freedBytes) || other.freedBytes == freedBytes));
 }
 
 
 @override
-int get hashCode => runtimeType.hashCode;
+int get hashCode => Object.hash(runtimeType,usedBytes,quotaBytes,const DeepCollectionEquality().hash(items),freedBytes);
 
 @override
 String toString() {
-  return 'ApiDownloadStatus()';
+  return 'ApiCleanupPlan(usedBytes: $usedBytes, quotaBytes: $quotaBytes, items: $items, freedBytes: $freedBytes)';
 }
 
 
 }
 
 /// @nodoc
-class $ApiDownloadStatusCopyWith<$Res>  {
-$ApiDownloadStatusCopyWith(ApiDownloadStatus _, $Res Function(ApiDownloadStatus) __);
+abstract mixin class $ApiCleanupPlanCopyWith<$Res>  {
+  factory $ApiCleanupPlanCopyWith(ApiCleanupPlan value, $Res Function(ApiCleanupPlan) _then) = _$ApiCleanupPlanCopyWithImpl;
+@useResult
+$Res call({
+ BigInt usedBytes, BigInt? quotaBytes, List<ApiCleanupItem> items, BigInt freedBytes
+});
+
+
+
+
 }
+/// @nodoc
+class _$ApiCleanupPlanCopyWithImpl<$Res>
+    implements $ApiCleanupPlanCopyWith<$Res> {
+  _$ApiCleanupPlanCopyWithImpl(this._self, this._then);
 
+  final ApiCleanupPlan _self;
+  final $Res Function(ApiCleanupPlan) _then;
 
-/// Adds pattern-matching-related methods to [ApiDownloadStatus].
-extension ApiDownloadStatusPatterns on ApiDownloadStatus {
+/// Create a copy of ApiCleanupPlan
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? usedBytes = null,Object? quotaBytes = freezed,Object? items = null,Object? freedBytes = null,}) {
+  return _then(_self.copyWith(
+usedBytes: null == usedBytes ? _self.usedBytes : usedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,quotaBytes: freezed == quotaBytes ? _self.quotaBytes : quotaBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,items: null == items ? _self.items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiCleanupItem>,freedBytes: null == freedBytes ? _self.freedBytes : freedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiCleanupPlan].
+extension ApiCleanupPlanPatterns on ApiCleanupPlan {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -1896,15 +1954,11 @@ extension ApiDownloadStatusPatterns on ApiDownloadStatus {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ApiDownloadStatus_Pending value)?  pending,TResult Function( ApiDownloadStatus_Downloading value)?  downloading,TResult Function( ApiDownloadStatus_Paused value)?  paused,TResult Function( ApiDownloadStatus_Completed value)?  completed,TResult Function( ApiDownloadStatus_Failed value)?  failed,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiCleanupPlan value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case ApiDownloadStatus_Pending() when pending != null:
-return pending(_that);case ApiDownloadStatus_Downloading() when downloading != null:
-return downloading(_that);case ApiDownloadStatus_Paused() when paused != null:
-return paused(_that);case ApiDownloadStatus_Completed() when completed != null:
-return completed(_that);case ApiDownloadStatus_Failed() when failed != null:
-return failed(_that);case _:
+case _ApiCleanupPlan() when $default != null:
+return $default(_that);case _:
   return orElse();
 
 }
@@ -1922,15 +1976,11 @@ return failed(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ApiDownloadStatus_Pending value)  pending,required TResult Function( ApiDownloadStatus_Downloading value)  downloading,required TResult Function( ApiDownloadStatus_Paused value)  paused,required TResult Function( ApiDownloadStatus_Completed value)  completed,required TResult Function( ApiDownloadStatus_Failed value)  failed,}){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiCleanupPlan value)  $default,){
 final _that = this;
 switch (_that) {
-case ApiDownloadStatus_Pending():
-return pending(_that);case ApiDownloadStatus_Downloading():
-return downloading(_that);case ApiDownloadStatus_Paused():
-return paused(_that);case ApiDownloadStatus_Completed():
-return completed(_that);case ApiDownloadStatus_Failed():
-return failed(_that);}
+case _ApiCleanupPlan():
+return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -1944,15 +1994,11 @@ return failed(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ApiDownloadStatus_Pending value)?  pending,TResult? Function( ApiDownloadStatus_Downloading value)?  downloading,TResult? Function( ApiDownloadStatus_Paused value)?  paused,TResult? Function( ApiDownloadStatus_Completed value)?  completed,TResult? Function( ApiDownloadStatus_Failed value)?  failed,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiCleanupPlan value)?  $default,){
 final _that = this;
 switch (_that) {
-case ApiDownloadStatus_Pending() when pending != null:
-return pending(_that);case ApiDownloadStatus_Downloading() when downloading != null:
-return downloading(_that);case ApiDownloadStatus_Paused() when paused != null:
-return paused(_that);case ApiDownloadStatus_Completed() when completed != null:
-return completed(_that);case ApiDownloadStatus_Failed() when failed != null:
-return failed(_that);case _:
+case _ApiCleanupPlan() when $default != null:
+return $default(_that);case _:
   return null;
 
 }
@@ -1969,14 +2015,10 @@ return failed(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  pending,TResult Function()?  downloading,TResult Function()?  paused,TResult Function()?  completed,TResult Function( String error)?  failed,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt usedBytes,  BigInt? quotaBytes,  List<ApiCleanupItem> items,  BigInt freedBytes)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case ApiDownloadStatus_Pending() when pending != null:
-return pending();case ApiDownloadStatus_Downloading() when downloading != null:
-return downloading();case ApiDownloadStatus_Paused() when paused != null:
-return paused();case ApiDownloadStatus_Completed() when completed != null:
-return completed();case ApiDownloadStatus_Failed() when failed != null:
-return failed(_that.error);case _:
+case _ApiCleanupPlan() when $default != null:
+return $default(_that.usedBytes,_that.quotaBytes,_that.items,_that.freedBytes);case _:
   return orElse();
 
 }
@@ -1994,14 +2036,10 @@ return failed(_that.error);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  pending,required TResult Function()  downloading,required TResult Function()  paused,required TResult Function()  completed,required TResult Function( String error)  failed,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt usedBytes,  BigInt? quotaBytes,  List<ApiCleanupItem> items,  BigInt freedBytes)  $default,) {final _that = this;
 switch (_that) {
-case ApiDownloadStatus_Pending():
-return pending();case ApiDownloadStatus_Downloading():
-return downloading();case ApiDownloadStatus_Paused():
-return paused();case ApiDownloadStatus_Completed():
-return completed();case ApiDownloadStatus_Failed():
-return failed(_that.error);}
+case _ApiCleanupPlan():
+return $default(_that.usedBytes,_that.quotaBytes,_that.items,_that.freedBytes);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -2015,14 +2053,10 @@ return failed(_that.error);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  pending,TResult? Function()?  downloading,TResult? Function()?  paused,TResult? Function()?  completed,TResult? Function( String error)?  failed,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt usedBytes,  BigInt? quotaBytes,  List<ApiCleanupItem> items,  BigInt freedBytes)?  $default,) {final _that = this;
 switch (_that) {
-case ApiDownloadStatus_Pending() when pending != null:
-return pending();case ApiDownloadStatus_Downloading() when downloading != null:
-return downloading();case ApiDownloadStatus_Paused() when paused != null:
-return paused();case ApiDownloadStatus_Completed() when completed != null:
-return completed();case ApiDownloadStatus_Failed() when failed != null:
-return failed(_that.error);case _:
+case _ApiCleanupPlan() when $default != null:
+return $default(_that.usedBytes,_that.quotaBytes,_that.items,_that.freedBytes);case _:
   return null;
 
 }
@@ -2033,171 +2067,116 @@ return failed(_that.error);case _:
 /// @nodoc
 
 
-class ApiDownloadStatus_Pending extends ApiDownloadStatus {
-  const ApiDownloadStatus_Pending(): super._();
+class _ApiCleanupPlan implements ApiCleanupPlan {
+  const _ApiCleanupPlan({required this.usedBytes, this.quotaBytes, required final  List<ApiCleanupItem> items, required this.freedBytes}): _items = items;
   
 
-
-
-
-
-
-@override
-bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Pending);
-}
-
-
-@override
-int get hashCode => runtimeType.hashCode;
-
-@override
-String toString() {
-  return 'ApiDownloadStatus.pending()';
-}
-
-
+@override final  BigInt usedBytes;
+@override final  BigInt? quotaBytes;
+ final  List<ApiCleanupItem> _items;
+@override List<ApiCleanupItem> get items {
+  if (_items is EqualUnmodifiableListView) return _items;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_items);
 }
 
+@override final  BigInt freedBytes;
 
-
-
-/// @nodoc
-
-
-class ApiDownloadStatus_Downloading extends ApiDownloadStatus {
-  const ApiDownloadStatus_Downloading(): super._();
-  
-
-
-
+/// Create a copy of ApiCleanupPlan
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiCleanupPlanCopyWith<_ApiCleanupPlan> get copyWith => __$ApiCleanupPlanCopyWithImpl<_ApiCleanupPlan>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Downloading);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiCleanupPlan&&(identical(other.usedBytes, usedBytes) || other.usedBytes == usedBytes)&&(identical(other.quotaBytes, quotaBytes) || other.quotaBytes == quotaBytes)&&const DeepCollectionEquality().equals(other._items, _items)&&(identical(other.freedBytes, freedBytes) || other.freedBytes == freedBytes));
 }
 
 
 @override
-int get hashCode => runtimeType.hashCode;
+int get hashCode => Object.hash(runtimeType,usedBytes,quotaBytes,const DeepCollectionEquality().hash(_items),freedBytes);
 
 @override
 String toString() {
-  return 'ApiDownloadStatus.downloading()';
+  return 'ApiCleanupPlan(usedBytes: $usedBytes, quotaBytes: $quotaBytes, items: $items, freedBytes: $freedBytes)';
 }
 
 
 }
 
-
-
-
 /// @nodoc
+abstract mixin class _$ApiCleanupPlanCopyWith<$Res> implements $ApiCleanupPlanCopyWith<$Res> {
+  factory _$ApiCleanupPlanCopyWith(_ApiCleanupPlan value, $Res Function(_ApiCleanupPlan) _then) = __$ApiCleanupPlanCopyWithImpl;
+@override @useResult
+$Res call({
+ BigInt usedBytes, BigInt? quotaBytes, List<ApiCleanupItem> items, BigInt freedBytes
+});
 
 
-class ApiDownloadStatus_Paused extends ApiDownloadStatus {
-  const ApiDownloadStatus_Paused(): super._();
-  
-
-
-
-
-
-
-@override
-bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Paused);
-}
-
-
-@override
-int get hashCode => runtimeType.hashCode;
-
-@override
-String toString() {
-  return 'ApiDownloadStatus.paused()';
-}
 
 
 }
-
-
-
-
 /// @nodoc
+class __$ApiCleanupPlanCopyWithImpl<$Res>
+    implements _$ApiCleanupPlanCopyWith<$Res> {
+  __$ApiCleanupPlanCopyWithImpl(this._self, this._then);
 
+  final _ApiCleanupPlan _self;
+  final $Res Function(_ApiCleanupPlan) _then;
 
-class ApiDownloadStatus_Completed extends ApiDownloadStatus {
-  const ApiDownloadStatus_Completed(): super._();
-  
-
-
-
-
-
-
-@override
-bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Completed);
-}
-
-
-@override
-int get hashCode => runtimeType.hashCode;
-
-@override
-String toString() {
-  return 'ApiDownloadStatus.completed()';
+/// Create a copy of ApiCleanupPlan
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? usedBytes = null,Object? quotaBytes = freezed,Object? items = null,Object? freedBytes = null,}) {
+  return _then(_ApiCleanupPlan(
+usedBytes: null == usedBytes ? _self.usedBytes : usedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,quotaBytes: freezed == quotaBytes ? _self.quotaBytes : quotaBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,items: null == items ? _self._items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiCleanupItem>,freedBytes: null == freedBytes ? _self.freedBytes : freedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
 }
 
 
 }
 
-
-
-
 /// @nodoc
+mixin _$ApiCloudflareChallenge {
 
-
-class ApiDownloadStatus_Failed extends ApiDownloadStatus {
-  const ApiDownloadStatus_Failed({required this.error}): super._();
-  
-
- final  String error;
-
-/// Create a copy of ApiDownloadStatus
+ String get url; String get userAgent;
+/// Create a copy of ApiCloudflareChallenge
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiDownloadStatus_FailedCopyWith<ApiDownloadStatus_Failed> get copyWith => _$ApiDownloadStatus_FailedCopyWithImpl<ApiDownloadStatus_Failed>(this, _$identity);
+$ApiCloudflareChallengeCopyWith<ApiCloudflareChallenge> get copyWith => _$ApiCloudflareChallengeCopyWithImpl<ApiCloudflareChallenge>(this as ApiCloudflareChallenge, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Failed&&(identical(other.error, error) || other.error == error));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiCloudflareChallenge&&(identical(other.url, url) || other.url == url)&&(identical(other.userAgent, userAgent) || other.userAgent == userAgent));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,error);
+int get hashCode => Object.hash(runtimeType,url,userAgent);
 
 @override
 String toString() {
-  return 'ApiDownloadStatus.failed(error: $error)';
+  return 'ApiCloudflareChallenge(url: $url, userAgent: $userAgent)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiDownloadStatus_FailedCopyWith<$Res> implements $ApiDownloadStatusCopyWith<$Res> {
-  factory $ApiDownloadStatus_FailedCopyWith(ApiDownloadStatus_Failed value, $Res Function(ApiDownloadStatus_Failed) _then) = _$ApiDownloadStatus_FailedCopyWithImpl;
+abstract mixin class $ApiCloudflareChallengeCopyWith<$Res>  {
+  factory $ApiCloudflareChallengeCopyWith(ApiCloudflareChallenge value, $Res Function(ApiCloudflareChallenge) _then) = _$ApiCloudflareChallengeCopyWithImpl;
 @useResult
 $Res call({
- String error
+ String url, String userAgent
 });
 
 
@@ -2205,102 +2184,6850 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiDownloadStatus_FailedCopyWithImpl<$Res>
-    implements $ApiDownloadStatus_FailedCopyWith<$Res> {
-  _$ApiDownloadStatus_FailedCopyWithImpl(this._self, this._then);
+class _$ApiCloudflareChallengeCopyWithImpl<$Res>
+    implements $ApiCloudflareChallengeCopyWith<$Res> {
+  _$ApiCloudflareChallengeCopyWithImpl(this._self, this._then);
 
-  final ApiDownloadStatus_Failed _self;
-  final $Res Function(ApiDownloadStatus_Failed) _then;
+  final ApiCloudflareChallenge _self;
+  final $Res Function(ApiCloudflareChallenge) _then;
 
-/// Create a copy of ApiDownloadStatus
+/// Create a copy of ApiCloudflareChallenge
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? error = null,}) {
-  return _then(ApiDownloadStatus_Failed(
-error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+@pragma('vm:prefer-inline') @override $Res call({Object? url = null,Object? userAgent = null,}) {
+  return _then(_self.copyWith(
+url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,userAgent: null == userAgent ? _self.userAgent : userAgent // ignore: cast_nullable_to_non_nullable
 as String,
   ));
 }
 
-
 }
 
-/// @nodoc
-mixin _$ApiDownloadTask {
-
- String get id; String get videoId; String get title; String get coverUrl; String? get coverPath; String? get authorId; String? get authorName; String? get authorAvatarUrl; String? get authorAvatarPath; String get quality; String? get description; List<String> get tags; ApiDownloadStatus get status; double get progress; BigInt get downloadedBytes; BigInt get totalBytes; BigInt get speed; PlatformInt64 get createdAt; String? get filePath; String? get folderId;
-/// Create a copy of ApiDownloadTask
-/// with the given fields replaced by the non-null parameter values.
-@JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$ApiDownloadTaskCopyWith<ApiDownloadTask> get copyWith => _$ApiDownloadTaskCopyWithImpl<ApiDownloadTask>(this as ApiDownloadTask, _$identity);
-
-
+
+/// Adds pattern-matching-related methods to [ApiCloudflareChallenge].
+extension ApiCloudflareChallengePatterns on ApiCloudflareChallenge {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiCloudflareChallenge value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiCloudflareChallenge() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiCloudflareChallenge value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiCloudflareChallenge():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiCloudflareChallenge value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiCloudflareChallenge() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String url,  String userAgent)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiCloudflareChallenge() when $default != null:
+return $default(_that.url,_that.userAgent);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String url,  String userAgent)  $default,) {final _that = this;
+switch (_that) {
+case _ApiCloudflareChallenge():
+return $default(_that.url,_that.userAgent);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String url,  String userAgent)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiCloudflareChallenge() when $default != null:
+return $default(_that.url,_that.userAgent);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiCloudflareChallenge implements ApiCloudflareChallenge {
+  const _ApiCloudflareChallenge({required this.url, required this.userAgent});
+  
+
+@override final  String url;
+@override final  String userAgent;
+
+/// Create a copy of ApiCloudflareChallenge
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiCloudflareChallengeCopyWith<_ApiCloudflareChallenge> get copyWith => __$ApiCloudflareChallengeCopyWithImpl<_ApiCloudflareChallenge>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiCloudflareChallenge&&(identical(other.url, url) || other.url == url)&&(identical(other.userAgent, userAgent) || other.userAgent == userAgent));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,url,userAgent);
+
+@override
+String toString() {
+  return 'ApiCloudflareChallenge(url: $url, userAgent: $userAgent)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiCloudflareChallengeCopyWith<$Res> implements $ApiCloudflareChallengeCopyWith<$Res> {
+  factory _$ApiCloudflareChallengeCopyWith(_ApiCloudflareChallenge value, $Res Function(_ApiCloudflareChallenge) _then) = __$ApiCloudflareChallengeCopyWithImpl;
+@override @useResult
+$Res call({
+ String url, String userAgent
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiCloudflareChallengeCopyWithImpl<$Res>
+    implements _$ApiCloudflareChallengeCopyWith<$Res> {
+  __$ApiCloudflareChallengeCopyWithImpl(this._self, this._then);
+
+  final _ApiCloudflareChallenge _self;
+  final $Res Function(_ApiCloudflareChallenge) _then;
+
+/// Create a copy of ApiCloudflareChallenge
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? url = null,Object? userAgent = null,}) {
+  return _then(_ApiCloudflareChallenge(
+url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,userAgent: null == userAgent ? _self.userAgent : userAgent // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiComment {
+
+ String get id; String get userName; String? get userAvatar; String get content; String get time; int get likes; int get dislikes; List<ApiComment> get replies; bool get hasMoreReplies;
+/// Create a copy of ApiComment
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiCommentCopyWith<ApiComment> get copyWith => _$ApiCommentCopyWithImpl<ApiComment>(this as ApiComment, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiComment&&(identical(other.id, id) || other.id == id)&&(identical(other.userName, userName) || other.userName == userName)&&(identical(other.userAvatar, userAvatar) || other.userAvatar == userAvatar)&&(identical(other.content, content) || other.content == content)&&(identical(other.time, time) || other.time == time)&&(identical(other.likes, likes) || other.likes == likes)&&(identical(other.dislikes, dislikes) || other.dislikes == dislikes)&&const DeepCollectionEquality().equals(other.replies, replies)&&(identical(other.hasMoreReplies, hasMoreReplies) || other.hasMoreReplies == hasMoreReplies));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,userName,userAvatar,content,time,likes,dislikes,const DeepCollectionEquality().hash(replies),hasMoreReplies);
+
+@override
+String toString() {
+  return 'ApiComment(id: $id, userName: $userName, userAvatar: $userAvatar, content: $content, time: $time, likes: $likes, dislikes: $dislikes, replies: $replies, hasMoreReplies: $hasMoreReplies)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiCommentCopyWith<$Res>  {
+  factory $ApiCommentCopyWith(ApiComment value, $Res Function(ApiComment) _then) = _$ApiCommentCopyWithImpl;
+@useResult
+$Res call({
+ String id, String userName, String? userAvatar, String content, String time, int likes, int dislikes, List<ApiComment> replies, bool hasMoreReplies
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiCommentCopyWithImpl<$Res>
+    implements $ApiCommentCopyWith<$Res> {
+  _$ApiCommentCopyWithImpl(this._self, this._then);
+
+  final ApiComment _self;
+  final $Res Function(ApiComment) _then;
+
+/// Create a copy of ApiComment
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? userName = null,Object? userAvatar = freezed,Object? content = null,Object? time = null,Object? likes = null,Object? dislikes = null,Object? replies = null,Object? hasMoreReplies = null,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,userName: null == userName ? _self.userName : userName // ignore: cast_nullable_to_non_nullable
+as String,userAvatar: freezed == userAvatar ? _self.userAvatar : userAvatar // ignore: cast_nullable_to_non_nullable
+as String?,content: null == content ? _self.content : content // ignore: cast_nullable_to_non_nullable
+as String,time: null == time ? _self.time : time // ignore: cast_nullable_to_non_nullable
+as String,likes: null == likes ? _self.likes : likes // ignore: cast_nullable_to_non_nullable
+as int,dislikes: null == dislikes ? _self.dislikes : dislikes // ignore: cast_nullable_to_non_nullable
+as int,replies: null == replies ? _self.replies : replies // ignore: cast_nullable_to_non_nullable
+as List<ApiComment>,hasMoreReplies: null == hasMoreReplies ? _self.hasMoreReplies : hasMoreReplies // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiComment].
+extension ApiCommentPatterns on ApiComment {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiComment value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiComment() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiComment value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiComment():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiComment value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiComment() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String userName,  String? userAvatar,  String content,  String time,  int likes,  int dislikes,  List<ApiComment> replies,  bool hasMoreReplies)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiComment() when $default != null:
+return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.time,_that.likes,_that.dislikes,_that.replies,_that.hasMoreReplies);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String userName,  String? userAvatar,  String content,  String time,  int likes,  int dislikes,  List<ApiComment> replies,  bool hasMoreReplies)  $default,) {final _that = this;
+switch (_that) {
+case _ApiComment():
+return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.time,_that.likes,_that.dislikes,_that.replies,_that.hasMoreReplies);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String userName,  String? userAvatar,  String content,  String time,  int likes,  int dislikes,  List<ApiComment> replies,  bool hasMoreReplies)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiComment() when $default != null:
+return $default(_that.id,_that.userName,_that.userAvatar,_that.content,_that.time,_that.likes,_that.dislikes,_that.replies,_that.hasMoreReplies);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiComment implements ApiComment {
+  const _ApiComment({required this.id, required this.userName, this.userAvatar, required this.content, required this.time, required this.likes, required this.dislikes, required final  List<ApiComment> replies, required this.hasMoreReplies}): _replies = replies;
+  
+
+@override final  String id;
+@override final  String userName;
+@override final  String? userAvatar;
+@override final  String content;
+@override final  String time;
+@override final  int likes;
+@override final  int dislikes;
+ final  List<ApiComment> _replies;
+@override List<ApiComment> get replies {
+  if (_replies is EqualUnmodifiableListView) return _replies;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_replies);
+}
+
+@override final  bool hasMoreReplies;
+
+/// Create a copy of ApiComment
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiCommentCopyWith<_ApiComment> get copyWith => __$ApiCommentCopyWithImpl<_ApiComment>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiComment&&(identical(other.id, id) || other.id == id)&&(identical(other.userName, userName) || other.userName == userName)&&(identical(other.userAvatar, userAvatar) || other.userAvatar == userAvatar)&&(identical(other.content, content) || other.content == content)&&(identical(other.time, time) || other.time == time)&&(identical(other.likes, likes) || other.likes == likes)&&(identical(other.dislikes, dislikes) || other.dislikes == dislikes)&&const DeepCollectionEquality().equals(other._replies, _replies)&&(identical(other.hasMoreReplies, hasMoreReplies) || other.hasMoreReplies == hasMoreReplies));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,userName,userAvatar,content,time,likes,dislikes,const DeepCollectionEquality().hash(_replies),hasMoreReplies);
+
+@override
+String toString() {
+  return 'ApiComment(id: $id, userName: $userName, userAvatar: $userAvatar, content: $content, time: $time, likes: $likes, dislikes: $dislikes, replies: $replies, hasMoreReplies: $hasMoreReplies)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiCommentCopyWith<$Res> implements $ApiCommentCopyWith<$Res> {
+  factory _$ApiCommentCopyWith(_ApiComment value, $Res Function(_ApiComment) _then) = __$ApiCommentCopyWithImpl;
+@override @useResult
+$Res call({
+ String id, String userName, String? userAvatar, String content, String time, int likes, int dislikes, List<ApiComment> replies, bool hasMoreReplies
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiCommentCopyWithImpl<$Res>
+    implements _$ApiCommentCopyWith<$Res> {
+  __$ApiCommentCopyWithImpl(this._self, this._then);
+
+  final _ApiComment _self;
+  final $Res Function(_ApiComment) _then;
+
+/// Create a copy of ApiComment
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? userName = null,Object? userAvatar = freezed,Object? content = null,Object? time = null,Object? likes = null,Object? dislikes = null,Object? replies = null,Object? hasMoreReplies = null,}) {
+  return _then(_ApiComment(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,userName: null == userName ? _self.userName : userName // ignore: cast_nullable_to_non_nullable
+as String,userAvatar: freezed == userAvatar ? _self.userAvatar : userAvatar // ignore: cast_nullable_to_non_nullable
+as String?,content: null == content ? _self.content : content // ignore: cast_nullable_to_non_nullable
+as String,time: null == time ? _self.time : time // ignore: cast_nullable_to_non_nullable
+as String,likes: null == likes ? _self.likes : likes // ignore: cast_nullable_to_non_nullable
+as int,dislikes: null == dislikes ? _self.dislikes : dislikes // ignore: cast_nullable_to_non_nullable
+as int,replies: null == replies ? _self._replies : replies // ignore: cast_nullable_to_non_nullable
+as List<ApiComment>,hasMoreReplies: null == hasMoreReplies ? _self.hasMoreReplies : hasMoreReplies // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiCommentList {
+
+ List<ApiComment> get comments; int get total; int get page; bool get hasNext;
+/// Create a copy of ApiCommentList
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiCommentListCopyWith<ApiCommentList> get copyWith => _$ApiCommentListCopyWithImpl<ApiCommentList>(this as ApiCommentList, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiCommentList&&const DeepCollectionEquality().equals(other.comments, comments)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(comments),total,page,hasNext);
+
+@override
+String toString() {
+  return 'ApiCommentList(comments: $comments, total: $total, page: $page, hasNext: $hasNext)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiCommentListCopyWith<$Res>  {
+  factory $ApiCommentListCopyWith(ApiCommentList value, $Res Function(ApiCommentList) _then) = _$ApiCommentListCopyWithImpl;
+@useResult
+$Res call({
+ List<ApiComment> comments, int total, int page, bool hasNext
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiCommentListCopyWithImpl<$Res>
+    implements $ApiCommentListCopyWith<$Res> {
+  _$ApiCommentListCopyWithImpl(this._self, this._then);
+
+  final ApiCommentList _self;
+  final $Res Function(ApiCommentList) _then;
+
+/// Create a copy of ApiCommentList
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? comments = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_self.copyWith(
+comments: null == comments ? _self.comments : comments // ignore: cast_nullable_to_non_nullable
+as List<ApiComment>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiCommentList].
+extension ApiCommentListPatterns on ApiCommentList {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiCommentList value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiCommentList() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiCommentList value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiCommentList():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiCommentList value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiCommentList() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiComment> comments,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiCommentList() when $default != null:
+return $default(_that.comments,_that.total,_that.page,_that.hasNext);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiComment> comments,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
+switch (_that) {
+case _ApiCommentList():
+return $default(_that.comments,_that.total,_that.page,_that.hasNext);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiComment> comments,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiCommentList() when $default != null:
+return $default(_that.comments,_that.total,_that.page,_that.hasNext);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiCommentList implements ApiCommentList {
+  const _ApiCommentList({required final  List<ApiComment> comments, required this.total, required this.page, required this.hasNext}): _comments = comments;
+  
+
+ final  List<ApiComment> _comments;
+@override List<ApiComment> get comments {
+  if (_comments is EqualUnmodifiableListView) return _comments;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_comments);
+}
+
+@override final  int total;
+@override final  int page;
+@override final  bool hasNext;
+
+/// Create a copy of ApiCommentList
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiCommentListCopyWith<_ApiCommentList> get copyWith => __$ApiCommentListCopyWithImpl<_ApiCommentList>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiCommentList&&const DeepCollectionEquality().equals(other._comments, _comments)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_comments),total,page,hasNext);
+
+@override
+String toString() {
+  return 'ApiCommentList(comments: $comments, total: $total, page: $page, hasNext: $hasNext)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiCommentListCopyWith<$Res> implements $ApiCommentListCopyWith<$Res> {
+  factory _$ApiCommentListCopyWith(_ApiCommentList value, $Res Function(_ApiCommentList) _then) = __$ApiCommentListCopyWithImpl;
+@override @useResult
+$Res call({
+ List<ApiComment> comments, int total, int page, bool hasNext
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiCommentListCopyWithImpl<$Res>
+    implements _$ApiCommentListCopyWith<$Res> {
+  __$ApiCommentListCopyWithImpl(this._self, this._then);
+
+  final _ApiCommentList _self;
+  final $Res Function(_ApiCommentList) _then;
+
+/// Create a copy of ApiCommentList
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? comments = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_ApiCommentList(
+comments: null == comments ? _self._comments : comments // ignore: cast_nullable_to_non_nullable
+as List<ApiComment>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiContinueWatching {
+
+ String get videoId; String get title; String get coverUrl; double get progress; int get position; int get duration; PlatformInt64 get lastPlayedAt; String? get seriesTitle; int? get episode; bool get isDownloaded;
+/// Create a copy of ApiContinueWatching
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiContinueWatchingCopyWith<ApiContinueWatching> get copyWith => _$ApiContinueWatchingCopyWithImpl<ApiContinueWatching>(this as ApiContinueWatching, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiContinueWatching&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.position, position) || other.position == position)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.lastPlayedAt, lastPlayedAt) || other.lastPlayedAt == lastPlayedAt)&&(identical(other.seriesTitle, seriesTitle) || other.seriesTitle == seriesTitle)&&(identical(other.episode, episode) || other.episode == episode)&&(identical(other.isDownloaded, isDownloaded) || other.isDownloaded == isDownloaded));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,progress,position,duration,lastPlayedAt,seriesTitle,episode,isDownloaded);
+
+@override
+String toString() {
+  return 'ApiContinueWatching(videoId: $videoId, title: $title, coverUrl: $coverUrl, progress: $progress, position: $position, duration: $duration, lastPlayedAt: $lastPlayedAt, seriesTitle: $seriesTitle, episode: $episode, isDownloaded: $isDownloaded)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiContinueWatchingCopyWith<$Res>  {
+  factory $ApiContinueWatchingCopyWith(ApiContinueWatching value, $Res Function(ApiContinueWatching) _then) = _$ApiContinueWatchingCopyWithImpl;
+@useResult
+$Res call({
+ String videoId, String title, String coverUrl, double progress, int position, int duration, PlatformInt64 lastPlayedAt, String? seriesTitle, int? episode, bool isDownloaded
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiContinueWatchingCopyWithImpl<$Res>
+    implements $ApiContinueWatchingCopyWith<$Res> {
+  _$ApiContinueWatchingCopyWithImpl(this._self, this._then);
+
+  final ApiContinueWatching _self;
+  final $Res Function(ApiContinueWatching) _then;
+
+/// Create a copy of ApiContinueWatching
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? progress = null,Object? position = null,Object? duration = null,Object? lastPlayedAt = null,Object? seriesTitle = freezed,Object? episode = freezed,Object? isDownloaded = null,}) {
+  return _then(_self.copyWith(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,position: null == position ? _self.position : position // ignore: cast_nullable_to_non_nullable
+as int,duration: null == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as int,lastPlayedAt: null == lastPlayedAt ? _self.lastPlayedAt : lastPlayedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,seriesTitle: freezed == seriesTitle ? _self.seriesTitle : seriesTitle // ignore: cast_nullable_to_non_nullable
+as String?,episode: freezed == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
+as int?,isDownloaded: null == isDownloaded ? _self.isDownloaded : isDownloaded // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiContinueWatching].
+extension ApiContinueWatchingPatterns on ApiContinueWatching {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiContinueWatching value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiContinueWatching() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiContinueWatching value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiContinueWatching():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiContinueWatching value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiContinueWatching() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  double progress,  int position,  int duration,  PlatformInt64 lastPlayedAt,  String? seriesTitle,  int? episode,  bool isDownloaded)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiContinueWatching() when $default != null:
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.position,_that.duration,_that.lastPlayedAt,_that.seriesTitle,_that.episode,_that.isDownloaded);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  double progress,  int position,  int duration,  PlatformInt64 lastPlayedAt,  String? seriesTitle,  int? episode,  bool isDownloaded)  $default,) {final _that = this;
+switch (_that) {
+case _ApiContinueWatching():
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.position,_that.duration,_that.lastPlayedAt,_that.seriesTitle,_that.episode,_that.isDownloaded);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  String coverUrl,  double progress,  int position,  int duration,  PlatformInt64 lastPlayedAt,  String? seriesTitle,  int? episode,  bool isDownloaded)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiContinueWatching() when $default != null:
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.position,_that.duration,_that.lastPlayedAt,_that.seriesTitle,_that.episode,_that.isDownloaded);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiContinueWatching implements ApiContinueWatching {
+  const _ApiContinueWatching({required this.videoId, required this.title, required this.coverUrl, required this.progress, required this.position, required this.duration, required this.lastPlayedAt, this.seriesTitle, this.episode, required this.isDownloaded});
+  
+
+@override final  String videoId;
+@override final  String title;
+@override final  String coverUrl;
+@override final  double progress;
+@override final  int position;
+@override final  int duration;
+@override final  PlatformInt64 lastPlayedAt;
+@override final  String? seriesTitle;
+@override final  int? episode;
+@override final  bool isDownloaded;
+
+/// Create a copy of ApiContinueWatching
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiContinueWatchingCopyWith<_ApiContinueWatching> get copyWith => __$ApiContinueWatchingCopyWithImpl<_ApiContinueWatching>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiContinueWatching&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.position, position) || other.position == position)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.lastPlayedAt, lastPlayedAt) || other.lastPlayedAt == lastPlayedAt)&&(identical(other.seriesTitle, seriesTitle) || other.seriesTitle == seriesTitle)&&(identical(other.episode, episode) || other.episode == episode)&&(identical(other.isDownloaded, isDownloaded) || other.isDownloaded == isDownloaded));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,progress,position,duration,lastPlayedAt,seriesTitle,episode,isDownloaded);
+
+@override
+String toString() {
+  return 'ApiContinueWatching(videoId: $videoId, title: $title, coverUrl: $coverUrl, progress: $progress, position: $position, duration: $duration, lastPlayedAt: $lastPlayedAt, seriesTitle: $seriesTitle, episode: $episode, isDownloaded: $isDownloaded)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiContinueWatchingCopyWith<$Res> implements $ApiContinueWatchingCopyWith<$Res> {
+  factory _$ApiContinueWatchingCopyWith(_ApiContinueWatching value, $Res Function(_ApiContinueWatching) _then) = __$ApiContinueWatchingCopyWithImpl;
+@override @useResult
+$Res call({
+ String videoId, String title, String coverUrl, double progress, int position, int duration, PlatformInt64 lastPlayedAt, String? seriesTitle, int? episode, bool isDownloaded
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiContinueWatchingCopyWithImpl<$Res>
+    implements _$ApiContinueWatchingCopyWith<$Res> {
+  __$ApiContinueWatchingCopyWithImpl(this._self, this._then);
+
+  final _ApiContinueWatching _self;
+  final $Res Function(_ApiContinueWatching) _then;
+
+/// Create a copy of ApiContinueWatching
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? progress = null,Object? position = null,Object? duration = null,Object? lastPlayedAt = null,Object? seriesTitle = freezed,Object? episode = freezed,Object? isDownloaded = null,}) {
+  return _then(_ApiContinueWatching(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,position: null == position ? _self.position : position // ignore: cast_nullable_to_non_nullable
+as int,duration: null == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as int,lastPlayedAt: null == lastPlayedAt ? _self.lastPlayedAt : lastPlayedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,seriesTitle: freezed == seriesTitle ? _self.seriesTitle : seriesTitle // ignore: cast_nullable_to_non_nullable
+as String?,episode: freezed == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
+as int?,isDownloaded: null == isDownloaded ? _self.isDownloaded : isDownloaded // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadBandwidthSettings {
+
+ int get globalKbPerSec; int get taskKbPerSec; bool get onlyWhileStreaming; bool get playerStreaming;
+/// Create a copy of ApiDownloadBandwidthSettings
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadBandwidthSettingsCopyWith<ApiDownloadBandwidthSettings> get copyWith => _$ApiDownloadBandwidthSettingsCopyWithImpl<ApiDownloadBandwidthSettings>(this as ApiDownloadBandwidthSettings, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadBandwidthSettings&&(identical(other.globalKbPerSec, globalKbPerSec) || other.globalKbPerSec == globalKbPerSec)&&(identical(other.taskKbPerSec, taskKbPerSec) || other.taskKbPerSec == taskKbPerSec)&&(identical(other.onlyWhileStreaming, onlyWhileStreaming) || other.onlyWhileStreaming == onlyWhileStreaming)&&(identical(other.playerStreaming, playerStreaming) || other.playerStreaming == playerStreaming));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,globalKbPerSec,taskKbPerSec,onlyWhileStreaming,playerStreaming);
+
+@override
+String toString() {
+  return 'ApiDownloadBandwidthSettings(globalKbPerSec: $globalKbPerSec, taskKbPerSec: $taskKbPerSec, onlyWhileStreaming: $onlyWhileStreaming, playerStreaming: $playerStreaming)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadBandwidthSettingsCopyWith<$Res>  {
+  factory $ApiDownloadBandwidthSettingsCopyWith(ApiDownloadBandwidthSettings value, $Res Function(ApiDownloadBandwidthSettings) _then) = _$ApiDownloadBandwidthSettingsCopyWithImpl;
+@useResult
+$Res call({
+ int globalKbPerSec, int taskKbPerSec, bool onlyWhileStreaming, bool playerStreaming
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadBandwidthSettingsCopyWithImpl<$Res>
+    implements $ApiDownloadBandwidthSettingsCopyWith<$Res> {
+  _$ApiDownloadBandwidthSettingsCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadBandwidthSettings _self;
+  final $Res Function(ApiDownloadBandwidthSettings) _then;
+
+/// Create a copy of ApiDownloadBandwidthSettings
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? globalKbPerSec = null,Object? taskKbPerSec = null,Object? onlyWhileStreaming = null,Object? playerStreaming = null,}) {
+  return _then(_self.copyWith(
+globalKbPerSec: null == globalKbPerSec ? _self.globalKbPerSec : globalKbPerSec // ignore: cast_nullable_to_non_nullable
+as int,taskKbPerSec: null == taskKbPerSec ? _self.taskKbPerSec : taskKbPerSec // ignore: cast_nullable_to_non_nullable
+as int,onlyWhileStreaming: null == onlyWhileStreaming ? _self.onlyWhileStreaming : onlyWhileStreaming // ignore: cast_nullable_to_non_nullable
+as bool,playerStreaming: null == playerStreaming ? _self.playerStreaming : playerStreaming // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadBandwidthSettings].
+extension ApiDownloadBandwidthSettingsPatterns on ApiDownloadBandwidthSettings {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadBandwidthSettings value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadBandwidthSettings() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadBandwidthSettings value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadBandwidthSettings():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadBandwidthSettings value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadBandwidthSettings() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int globalKbPerSec,  int taskKbPerSec,  bool onlyWhileStreaming,  bool playerStreaming)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadBandwidthSettings() when $default != null:
+return $default(_that.globalKbPerSec,_that.taskKbPerSec,_that.onlyWhileStreaming,_that.playerStreaming);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int globalKbPerSec,  int taskKbPerSec,  bool onlyWhileStreaming,  bool playerStreaming)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadBandwidthSettings():
+return $default(_that.globalKbPerSec,_that.taskKbPerSec,_that.onlyWhileStreaming,_that.playerStreaming);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int globalKbPerSec,  int taskKbPerSec,  bool onlyWhileStreaming,  bool playerStreaming)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadBandwidthSettings() when $default != null:
+return $default(_that.globalKbPerSec,_that.taskKbPerSec,_that.onlyWhileStreaming,_that.playerStreaming);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadBandwidthSettings extends ApiDownloadBandwidthSettings {
+  const _ApiDownloadBandwidthSettings({required this.globalKbPerSec, required this.taskKbPerSec, required this.onlyWhileStreaming, required this.playerStreaming}): super._();
+  
+
+@override final  int globalKbPerSec;
+@override final  int taskKbPerSec;
+@override final  bool onlyWhileStreaming;
+@override final  bool playerStreaming;
+
+/// Create a copy of ApiDownloadBandwidthSettings
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadBandwidthSettingsCopyWith<_ApiDownloadBandwidthSettings> get copyWith => __$ApiDownloadBandwidthSettingsCopyWithImpl<_ApiDownloadBandwidthSettings>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadBandwidthSettings&&(identical(other.globalKbPerSec, globalKbPerSec) || other.globalKbPerSec == globalKbPerSec)&&(identical(other.taskKbPerSec, taskKbPerSec) || other.taskKbPerSec == taskKbPerSec)&&(identical(other.onlyWhileStreaming, onlyWhileStreaming) || other.onlyWhileStreaming == onlyWhileStreaming)&&(identical(other.playerStreaming, playerStreaming) || other.playerStreaming == playerStreaming));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,globalKbPerSec,taskKbPerSec,onlyWhileStreaming,playerStreaming);
+
+@override
+String toString() {
+  return 'ApiDownloadBandwidthSettings(globalKbPerSec: $globalKbPerSec, taskKbPerSec: $taskKbPerSec, onlyWhileStreaming: $onlyWhileStreaming, playerStreaming: $playerStreaming)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadBandwidthSettingsCopyWith<$Res> implements $ApiDownloadBandwidthSettingsCopyWith<$Res> {
+  factory _$ApiDownloadBandwidthSettingsCopyWith(_ApiDownloadBandwidthSettings value, $Res Function(_ApiDownloadBandwidthSettings) _then) = __$ApiDownloadBandwidthSettingsCopyWithImpl;
+@override @useResult
+$Res call({
+ int globalKbPerSec, int taskKbPerSec, bool onlyWhileStreaming, bool playerStreaming
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadBandwidthSettingsCopyWithImpl<$Res>
+    implements _$ApiDownloadBandwidthSettingsCopyWith<$Res> {
+  __$ApiDownloadBandwidthSettingsCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadBandwidthSettings _self;
+  final $Res Function(_ApiDownloadBandwidthSettings) _then;
+
+/// Create a copy of ApiDownloadBandwidthSettings
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? globalKbPerSec = null,Object? taskKbPerSec = null,Object? onlyWhileStreaming = null,Object? playerStreaming = null,}) {
+  return _then(_ApiDownloadBandwidthSettings(
+globalKbPerSec: null == globalKbPerSec ? _self.globalKbPerSec : globalKbPerSec // ignore: cast_nullable_to_non_nullable
+as int,taskKbPerSec: null == taskKbPerSec ? _self.taskKbPerSec : taskKbPerSec // ignore: cast_nullable_to_non_nullable
+as int,onlyWhileStreaming: null == onlyWhileStreaming ? _self.onlyWhileStreaming : onlyWhileStreaming // ignore: cast_nullable_to_non_nullable
+as bool,playerStreaming: null == playerStreaming ? _self.playerStreaming : playerStreaming // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadEvent {
+
+ PlatformInt64 get id; String get kind; String? get status; String? get message; String? get sourceHost; String? get quality; String? get format; BigInt? get rangeStart; BigInt? get rangeEnd; int? get httpStatus; String? get errorKind; PlatformInt64 get createdAt;
+/// Create a copy of ApiDownloadEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadEventCopyWith<ApiDownloadEvent> get copyWith => _$ApiDownloadEventCopyWithImpl<ApiDownloadEvent>(this as ApiDownloadEvent, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadEvent&&(identical(other.id, id) || other.id == id)&&(identical(other.kind, kind) || other.kind == kind)&&(identical(other.status, status) || other.status == status)&&(identical(other.message, message) || other.message == message)&&(identical(other.sourceHost, sourceHost) || other.sourceHost == sourceHost)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.format, format) || other.format == format)&&(identical(other.rangeStart, rangeStart) || other.rangeStart == rangeStart)&&(identical(other.rangeEnd, rangeEnd) || other.rangeEnd == rangeEnd)&&(identical(other.httpStatus, httpStatus) || other.httpStatus == httpStatus)&&(identical(other.errorKind, errorKind) || other.errorKind == errorKind)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,kind,status,message,sourceHost,quality,format,rangeStart,rangeEnd,httpStatus,errorKind,createdAt);
+
+@override
+String toString() {
+  return 'ApiDownloadEvent(id: $id, kind: $kind, status: $status, message: $message, sourceHost: $sourceHost, quality: $quality, format: $format, rangeStart: $rangeStart, rangeEnd: $rangeEnd, httpStatus: $httpStatus, errorKind: $errorKind, createdAt: $createdAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadEventCopyWith<$Res>  {
+  factory $ApiDownloadEventCopyWith(ApiDownloadEvent value, $Res Function(ApiDownloadEvent) _then) = _$ApiDownloadEventCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 id, String kind, String? status, String? message, String? sourceHost, String? quality, String? format, BigInt? rangeStart, BigInt? rangeEnd, int? httpStatus, String? errorKind, PlatformInt64 createdAt
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadEventCopyWithImpl<$Res>
+    implements $ApiDownloadEventCopyWith<$Res> {
+  _$ApiDownloadEventCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadEvent _self;
+  final $Res Function(ApiDownloadEvent) _then;
+
+/// Create a copy of ApiDownloadEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? kind = null,Object? status = freezed,Object? message = freezed,Object? sourceHost = freezed,Object? quality = freezed,Object? format = freezed,Object? rangeStart = freezed,Object? rangeEnd = freezed,Object? httpStatus = freezed,Object? errorKind = freezed,Object? createdAt = null,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as String,status: freezed == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as String?,message: freezed == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String?,sourceHost: freezed == sourceHost ? _self.sourceHost : sourceHost // ignore: cast_nullable_to_non_nullable
+as String?,quality: freezed == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String?,format: freezed == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as String?,rangeStart: freezed == rangeStart ? _self.rangeStart : rangeStart // ignore: cast_nullable_to_non_nullable
+as BigInt?,rangeEnd: freezed == rangeEnd ? _self.rangeEnd : rangeEnd // ignore: cast_nullable_to_non_nullable
+as BigInt?,httpStatus: freezed == httpStatus ? _self.httpStatus : httpStatus // ignore: cast_nullable_to_non_nullable
+as int?,errorKind: freezed == errorKind ? _self.errorKind : errorKind // ignore: cast_nullable_to_non_nullable
+as String?,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadEvent].
+extension ApiDownloadEventPatterns on ApiDownloadEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadEvent value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadEvent() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadEvent value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadEvent():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadEvent value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadEvent() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( PlatformInt64 id,  String kind,  String? status,  String? message,  String? sourceHost,  String? quality,  String? format,  BigInt? rangeStart,  BigInt? rangeEnd,  int? httpStatus,  String? errorKind,  PlatformInt64 createdAt)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadEvent() when $default != null:
+return $default(_that.id,_that.kind,_that.status,_that.message,_that.sourceHost,_that.quality,_that.format,_that.rangeStart,_that.rangeEnd,_that.httpStatus,_that.errorKind,_that.createdAt);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( PlatformInt64 id,  String kind,  String? status,  String? message,  String? sourceHost,  String? quality,  String? format,  BigInt? rangeStart,  BigInt? rangeEnd,  int? httpStatus,  String? errorKind,  PlatformInt64 createdAt)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadEvent():
+return $default(_that.id,_that.kind,_that.status,_that.message,_that.sourceHost,_that.quality,_that.format,_that.rangeStart,_that.rangeEnd,_that.httpStatus,_that.errorKind,_that.createdAt);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( PlatformInt64 id,  String kind,  String? status,  String? message,  String? sourceHost,  String? quality,  String? format,  BigInt? rangeStart,  BigInt? rangeEnd,  int? httpStatus,  String? errorKind,  PlatformInt64 createdAt)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadEvent() when $default != null:
+return $default(_that.id,_that.kind,_that.status,_that.message,_that.sourceHost,_that.quality,_that.format,_that.rangeStart,_that.rangeEnd,_that.httpStatus,_that.errorKind,_that.createdAt);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadEvent implements ApiDownloadEvent {
+  const _ApiDownloadEvent({required this.id, required this.kind, this.status, this.message, this.sourceHost, this.quality, this.format, this.rangeStart, this.rangeEnd, this.httpStatus, this.errorKind, required this.createdAt});
+  
+
+@override final  PlatformInt64 id;
+@override final  String kind;
+@override final  String? status;
+@override final  String? message;
+@override final  String? sourceHost;
+@override final  String? quality;
+@override final  String? format;
+@override final  BigInt? rangeStart;
+@override final  BigInt? rangeEnd;
+@override final  int? httpStatus;
+@override final  String? errorKind;
+@override final  PlatformInt64 createdAt;
+
+/// Create a copy of ApiDownloadEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadEventCopyWith<_ApiDownloadEvent> get copyWith => __$ApiDownloadEventCopyWithImpl<_ApiDownloadEvent>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadEvent&&(identical(other.id, id) || other.id == id)&&(identical(other.kind, kind) || other.kind == kind)&&(identical(other.status, status) || other.status == status)&&(identical(other.message, message) || other.message == message)&&(identical(other.sourceHost, sourceHost) || other.sourceHost == sourceHost)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.format, format) || other.format == format)&&(identical(other.rangeStart, rangeStart) || other.rangeStart == rangeStart)&&(identical(other.rangeEnd, rangeEnd) || other.rangeEnd == rangeEnd)&&(identical(other.httpStatus, httpStatus) || other.httpStatus == httpStatus)&&(identical(other.errorKind, errorKind) || other.errorKind == errorKind)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,kind,status,message,sourceHost,quality,format,rangeStart,rangeEnd,httpStatus,errorKind,createdAt);
+
+@override
+String toString() {
+  return 'ApiDownloadEvent(id: $id, kind: $kind, status: $status, message: $message, sourceHost: $sourceHost, quality: $quality, format: $format, rangeStart: $rangeStart, rangeEnd: $rangeEnd, httpStatus: $httpStatus, errorKind: $errorKind, createdAt: $createdAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadEventCopyWith<$Res> implements $ApiDownloadEventCopyWith<$Res> {
+  factory _$ApiDownloadEventCopyWith(_ApiDownloadEvent value, $Res Function(_ApiDownloadEvent) _then) = __$ApiDownloadEventCopyWithImpl;
+@override @useResult
+$Res call({
+ PlatformInt64 id, String kind, String? status, String? message, String? sourceHost, String? quality, String? format, BigInt? rangeStart, BigInt? rangeEnd, int? httpStatus, String? errorKind, PlatformInt64 createdAt
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadEventCopyWithImpl<$Res>
+    implements _$ApiDownloadEventCopyWith<$Res> {
+  __$ApiDownloadEventCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadEvent _self;
+  final $Res Function(_ApiDownloadEvent) _then;
+
+/// Create a copy of ApiDownloadEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? kind = null,Object? status = freezed,Object? message = freezed,Object? sourceHost = freezed,Object? quality = freezed,Object? format = freezed,Object? rangeStart = freezed,Object? rangeEnd = freezed,Object? httpStatus = freezed,Object? errorKind = freezed,Object? createdAt = null,}) {
+  return _then(_ApiDownloadEvent(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as String,status: freezed == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as String?,message: freezed == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String?,sourceHost: freezed == sourceHost ? _self.sourceHost : sourceHost // ignore: cast_nullable_to_non_nullable
+as String?,quality: freezed == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String?,format: freezed == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as String?,rangeStart: freezed == rangeStart ? _self.rangeStart : rangeStart // ignore: cast_nullable_to_non_nullable
+as BigInt?,rangeEnd: freezed == rangeEnd ? _self.rangeEnd : rangeEnd // ignore: cast_nullable_to_non_nullable
+as BigInt?,httpStatus: freezed == httpStatus ? _self.httpStatus : httpStatus // ignore: cast_nullable_to_non_nullable
+as int?,errorKind: freezed == errorKind ? _self.errorKind : errorKind // ignore: cast_nullable_to_non_nullable
+as String?,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadFolder {
+
+ String get id; String get name; PlatformInt64 get createdAt; String? get parentId; int get sortOrder; String? get coverVideoId; bool get isSmart; int get videoCount;
+/// Create a copy of ApiDownloadFolder
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadFolderCopyWith<ApiDownloadFolder> get copyWith => _$ApiDownloadFolderCopyWithImpl<ApiDownloadFolder>(this as ApiDownloadFolder, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadFolder&&(identical(other.id, id) || other.id == id)&&(identical(other.name, name) || other.name == name)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.parentId, parentId) || other.parentId == parentId)&&(identical(other.sortOrder, sortOrder) || other.sortOrder == sortOrder)&&(identical(other.coverVideoId, coverVideoId) || other.coverVideoId == coverVideoId)&&(identical(other.isSmart, isSmart) || other.isSmart == isSmart)&&(identical(other.videoCount, videoCount) || other.videoCount == videoCount));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,name,createdAt,parentId,sortOrder,coverVideoId,isSmart,videoCount);
+
+@override
+String toString() {
+  return 'ApiDownloadFolder(id: $id, name: $name, createdAt: $createdAt, parentId: $parentId, sortOrder: $sortOrder, coverVideoId: $coverVideoId, isSmart: $isSmart, videoCount: $videoCount)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadFolderCopyWith<$Res>  {
+  factory $ApiDownloadFolderCopyWith(ApiDownloadFolder value, $Res Function(ApiDownloadFolder) _then) = _$ApiDownloadFolderCopyWithImpl;
+@useResult
+$Res call({
+ String id, String name, PlatformInt64 createdAt, String? parentId, int sortOrder, String? coverVideoId, bool isSmart, int videoCount
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadFolderCopyWithImpl<$Res>
+    implements $ApiDownloadFolderCopyWith<$Res> {
+  _$ApiDownloadFolderCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadFolder _self;
+  final $Res Function(ApiDownloadFolder) _then;
+
+/// Create a copy of ApiDownloadFolder
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? name = null,Object? createdAt = null,Object? parentId = freezed,Object? sortOrder = null,Object? coverVideoId = freezed,Object? isSmart = null,Object? videoCount = null,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,parentId: freezed == parentId ? _self.parentId : parentId // ignore: cast_nullable_to_non_nullable
+as String?,sortOrder: null == sortOrder ? _self.sortOrder : sortOrder // ignore: cast_nullable_to_non_nullable
+as int,coverVideoId: freezed == coverVideoId ? _self.coverVideoId : coverVideoId // ignore: cast_nullable_to_non_nullable
+as String?,isSmart: null == isSmart ? _self.isSmart : isSmart // ignore: cast_nullable_to_non_nullable
+as bool,videoCount: null == videoCount ? _self.videoCount : videoCount // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadFolder].
+extension ApiDownloadFolderPatterns on ApiDownloadFolder {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadFolder value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadFolder() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadFolder value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadFolder():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadFolder value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadFolder() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String name,  PlatformInt64 createdAt,  String? parentId,  int sortOrder,  String? coverVideoId,  bool isSmart,  int videoCount)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadFolder() when $default != null:
+return $default(_that.id,_that.name,_that.createdAt,_that.parentId,_that.sortOrder,_that.coverVideoId,_that.isSmart,_that.videoCount);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String name,  PlatformInt64 createdAt,  String? parentId,  int sortOrder,  String? coverVideoId,  bool isSmart,  int videoCount)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadFolder():
+return $default(_that.id,_that.name,_that.createdAt,_that.parentId,_that.sortOrder,_that.coverVideoId,_that.isSmart,_that.videoCount);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String name,  PlatformInt64 createdAt,  String? parentId,  int sortOrder,  String? coverVideoId,  bool isSmart,  int videoCount)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadFolder() when $default != null:
+return $default(_that.id,_that.name,_that.createdAt,_that.parentId,_that.sortOrder,_that.coverVideoId,_that.isSmart,_that.videoCount);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadFolder implements ApiDownloadFolder {
+  const _ApiDownloadFolder({required this.id, required this.name, required this.createdAt, this.parentId, required this.sortOrder, this.coverVideoId, required this.isSmart, required this.videoCount});
+  
+
+@override final  String id;
+@override final  String name;
+@override final  PlatformInt64 createdAt;
+@override final  String? parentId;
+@override final  int sortOrder;
+@override final  String? coverVideoId;
+@override final  bool isSmart;
+@override final  int videoCount;
+
+/// Create a copy of ApiDownloadFolder
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadFolderCopyWith<_ApiDownloadFolder> get copyWith => __$ApiDownloadFolderCopyWithImpl<_ApiDownloadFolder>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadFolder&&(identical(other.id, id) || other.id == id)&&(identical(other.name, name) || other.name == name)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.parentId, parentId) || other.parentId == parentId)&&(identical(other.sortOrder, sortOrder) || other.sortOrder == sortOrder)&&(identical(other.coverVideoId, coverVideoId) || other.coverVideoId == coverVideoId)&&(identical(other.isSmart, isSmart) || other.isSmart == isSmart)&&(identical(other.videoCount, videoCount) || other.videoCount == videoCount));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,name,createdAt,parentId,sortOrder,coverVideoId,isSmart,videoCount);
+
+@override
+String toString() {
+  return 'ApiDownloadFolder(id: $id, name: $name, createdAt: $createdAt, parentId: $parentId, sortOrder: $sortOrder, coverVideoId: $coverVideoId, isSmart: $isSmart, videoCount: $videoCount)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadFolderCopyWith<$Res> implements $ApiDownloadFolderCopyWith<$Res> {
+  factory _$ApiDownloadFolderCopyWith(_ApiDownloadFolder value, $Res Function(_ApiDownloadFolder) _then) = __$ApiDownloadFolderCopyWithImpl;
+@override @useResult
+$Res call({
+ String id, String name, PlatformInt64 createdAt, String? parentId, int sortOrder, String? coverVideoId, bool isSmart, int videoCount
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadFolderCopyWithImpl<$Res>
+    implements _$ApiDownloadFolderCopyWith<$Res> {
+  __$ApiDownloadFolderCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadFolder _self;
+  final $Res Function(_ApiDownloadFolder) _then;
+
+/// Create a copy of ApiDownloadFolder
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? name = null,Object? createdAt = null,Object? parentId = freezed,Object? sortOrder = null,Object? coverVideoId = freezed,Object? isSmart = null,Object? videoCount = null,}) {
+  return _then(_ApiDownloadFolder(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,parentId: freezed == parentId ? _self.parentId : parentId // ignore: cast_nullable_to_non_nullable
+as String?,sortOrder: null == sortOrder ? _self.sortOrder : sortOrder // ignore: cast_nullable_to_non_nullable
+as int,coverVideoId: freezed == coverVideoId ? _self.coverVideoId : coverVideoId // ignore: cast_nullable_to_non_nullable
+as String?,isSmart: null == isSmart ? _self.isSmart : isSmart // ignore: cast_nullable_to_non_nullable
+as bool,videoCount: null == videoCount ? _self.videoCount : videoCount // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadPage {
+
+ List<ApiDownloadTask> get items; int get total;
+/// Create a copy of ApiDownloadPage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadPageCopyWith<ApiDownloadPage> get copyWith => _$ApiDownloadPageCopyWithImpl<ApiDownloadPage>(this as ApiDownloadPage, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadPage&&const DeepCollectionEquality().equals(other.items, items)&&(identical(other.total, total) || other.total == total));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(items),total);
+
+@override
+String toString() {
+  return 'ApiDownloadPage(items: $items, total: $total)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadPageCopyWith<$Res>  {
+  factory $ApiDownloadPageCopyWith(ApiDownloadPage value, $Res Function(ApiDownloadPage) _then) = _$ApiDownloadPageCopyWithImpl;
+@useResult
+$Res call({
+ List<ApiDownloadTask> items, int total
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadPageCopyWithImpl<$Res>
+    implements $ApiDownloadPageCopyWith<$Res> {
+  _$ApiDownloadPageCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadPage _self;
+  final $Res Function(ApiDownloadPage) _then;
+
+/// Create a copy of ApiDownloadPage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? items = null,Object? total = null,}) {
+  return _then(_self.copyWith(
+items: null == items ? _self.items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiDownloadTask>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadPage].
+extension ApiDownloadPagePatterns on ApiDownloadPage {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadPage value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadPage() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadPage value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadPage():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadPage value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadPage() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiDownloadTask> items,  int total)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadPage() when $default != null:
+return $default(_that.items,_that.total);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiDownloadTask> items,  int total)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadPage():
+return $default(_that.items,_that.total);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiDownloadTask> items,  int total)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadPage() when $default != null:
+return $default(_that.items,_that.total);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadPage implements ApiDownloadPage {
+  const _ApiDownloadPage({required final  List<ApiDownloadTask> items, required this.total}): _items = items;
+  
+
+ final  List<ApiDownloadTask> _items;
+@override List<ApiDownloadTask> get items {
+  if (_items is EqualUnmodifiableListView) return _items;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_items);
+}
+
+@override final  int total;
+
+/// Create a copy of ApiDownloadPage
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadPageCopyWith<_ApiDownloadPage> get copyWith => __$ApiDownloadPageCopyWithImpl<_ApiDownloadPage>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadPage&&const DeepCollectionEquality().equals(other._items, _items)&&(identical(other.total, total) || other.total == total));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_items),total);
+
+@override
+String toString() {
+  return 'ApiDownloadPage(items: $items, total: $total)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadPageCopyWith<$Res> implements $ApiDownloadPageCopyWith<$Res> {
+  factory _$ApiDownloadPageCopyWith(_ApiDownloadPage value, $Res Function(_ApiDownloadPage) _then) = __$ApiDownloadPageCopyWithImpl;
+@override @useResult
+$Res call({
+ List<ApiDownloadTask> items, int total
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadPageCopyWithImpl<$Res>
+    implements _$ApiDownloadPageCopyWith<$Res> {
+  __$ApiDownloadPageCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadPage _self;
+  final $Res Function(_ApiDownloadPage) _then;
+
+/// Create a copy of ApiDownloadPage
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? items = null,Object? total = null,}) {
+  return _then(_ApiDownloadPage(
+items: null == items ? _self._items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiDownloadTask>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadQuery {
+
+ List<String> get statuses; String? get folderId; bool get includeSubfolders; List<String> get authors; List<String> get tags; String? get text; PlatformInt64? get downloadedFrom; PlatformInt64? get downloadedTo; BigInt? get minBytes; BigInt? get maxBytes; String? get sortBy; bool get descending; int get offset; int get limit;
+/// Create a copy of ApiDownloadQuery
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadQueryCopyWith<ApiDownloadQuery> get copyWith => _$ApiDownloadQueryCopyWithImpl<ApiDownloadQuery>(this as ApiDownloadQuery, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadQuery&&const DeepCollectionEquality().equals(other.statuses, statuses)&&(identical(other.folderId, folderId) || other.folderId == folderId)&&(identical(other.includeSubfolders, includeSubfolders) || other.includeSubfolders == includeSubfolders)&&const DeepCollectionEquality().equals(other.authors, authors)&&const DeepCollectionEquality().equals(other.tags, tags)&&(identical(other.text, text) || other.text == text)&&(identical(other.downloadedFrom, downloadedFrom) || other.downloadedFrom == downloadedFrom)&&(identical(other.downloadedTo, downloadedTo) || other.downloadedTo == downloadedTo)&&(identical(other.minBytes, minBytes) || other.minBytes == minBytes)&&(identical(other.maxBytes, maxBytes) || other.maxBytes == maxBytes)&&(identical(other.sortBy, sortBy) || other.sortBy == sortBy)&&(identical(other.descending, descending) || other.descending == descending)&&(identical(other.offset, offset) || other.offset == offset)&&(identical(other.limit, limit) || other.limit == limit));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(statuses),folderId,includeSubfolders,const DeepCollectionEquality().hash(authors),const DeepCollectionEquality().hash(tags),text,downloadedFrom,downloadedTo,minBytes,maxBytes,sortBy,descending,offset,limit);
+
+@override
+String toString() {
+  return 'ApiDownloadQuery(statuses: $statuses, folderId: $folderId, includeSubfolders: $includeSubfolders, authors: $authors, tags: $tags, text: $text, downloadedFrom: $downloadedFrom, downloadedTo: $downloadedTo, minBytes: $minBytes, maxBytes: $maxBytes, sortBy: $sortBy, descending: $descending, offset: $offset, limit: $limit)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadQueryCopyWith<$Res>  {
+  factory $ApiDownloadQueryCopyWith(ApiDownloadQuery value, $Res Function(ApiDownloadQuery) _then) = _$ApiDownloadQueryCopyWithImpl;
+@useResult
+$Res call({
+ List<String> statuses, String? folderId, bool includeSubfolders, List<String> authors, List<String> tags, String? text, PlatformInt64? downloadedFrom, PlatformInt64? downloadedTo, BigInt? minBytes, BigInt? maxBytes, String? sortBy, bool descending, int offset, int limit
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadQueryCopyWithImpl<$Res>
+    implements $ApiDownloadQueryCopyWith<$Res> {
+  _$ApiDownloadQueryCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadQuery _self;
+  final $Res Function(ApiDownloadQuery) _then;
+
+/// Create a copy of ApiDownloadQuery
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? statuses = null,Object? folderId = freezed,Object? includeSubfolders = null,Object? authors = null,Object? tags = null,Object? text = freezed,Object? downloadedFrom = freezed,Object? downloadedTo = freezed,Object? minBytes = freezed,Object? maxBytes = freezed,Object? sortBy = freezed,Object? descending = null,Object? offset = null,Object? limit = null,}) {
+  return _then(_self.copyWith(
+statuses: null == statuses ? _self.statuses : statuses // ignore: cast_nullable_to_non_nullable
+as List<String>,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
+as String?,includeSubfolders: null == includeSubfolders ? _self.includeSubfolders : includeSubfolders // ignore: cast_nullable_to_non_nullable
+as bool,authors: null == authors ? _self.authors : authors // ignore: cast_nullable_to_non_nullable
+as List<String>,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,text: freezed == text ? _self.text : text // ignore: cast_nullable_to_non_nullable
+as String?,downloadedFrom: freezed == downloadedFrom ? _self.downloadedFrom : downloadedFrom // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,downloadedTo: freezed == downloadedTo ? _self.downloadedTo : downloadedTo // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,minBytes: freezed == minBytes ? _self.minBytes : minBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,maxBytes: freezed == maxBytes ? _self.maxBytes : maxBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,sortBy: freezed == sortBy ? _self.sortBy : sortBy // ignore: cast_nullable_to_non_nullable
+as String?,descending: null == descending ? _self.descending : descending // ignore: cast_nullable_to_non_nullable
+as bool,offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as int,limit: null == limit ? _self.limit : limit // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadQuery].
+extension ApiDownloadQueryPatterns on ApiDownloadQuery {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadQuery value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadQuery() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadQuery value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadQuery():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadQuery value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadQuery() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> statuses,  String? folderId,  bool includeSubfolders,  List<String> authors,  List<String> tags,  String? text,  PlatformInt64? downloadedFrom,  PlatformInt64? downloadedTo,  BigInt? minBytes,  BigInt? maxBytes,  String? sortBy,  bool descending,  int offset,  int limit)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadQuery() when $default != null:
+return $default(_that.statuses,_that.folderId,_that.includeSubfolders,_that.authors,_that.tags,_that.text,_that.downloadedFrom,_that.downloadedTo,_that.minBytes,_that.maxBytes,_that.sortBy,_that.descending,_that.offset,_that.limit);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> statuses,  String? folderId,  bool includeSubfolders,  List<String> authors,  List<String> tags,  String? text,  PlatformInt64? downloadedFrom,  PlatformInt64? downloadedTo,  BigInt? minBytes,  BigInt? maxBytes,  String? sortBy,  bool descending,  int offset,  int limit)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadQuery():
+return $default(_that.statuses,_that.folderId,_that.includeSubfolders,_that.authors,_that.tags,_that.text,_that.downloadedFrom,_that.downloadedTo,_that.minBytes,_that.maxBytes,_that.sortBy,_that.descending,_that.offset,_that.limit);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> statuses,  String? folderId,  bool includeSubfolders,  List<String> authors,  List<String> tags,  String? text,  PlatformInt64? downloadedFrom,  PlatformInt64? downloadedTo,  BigInt? minBytes,  BigInt? maxBytes,  String? sortBy,  bool descending,  int offset,  int limit)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadQuery() when $default != null:
+return $default(_that.statuses,_that.folderId,_that.includeSubfolders,_that.authors,_that.tags,_that.text,_that.downloadedFrom,_that.downloadedTo,_that.minBytes,_that.maxBytes,_that.sortBy,_that.descending,_that.offset,_that.limit);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadQuery extends ApiDownloadQuery {
+  const _ApiDownloadQuery({required final  List<String> statuses, this.folderId, required this.includeSubfolders, required final  List<String> authors, required final  List<String> tags, this.text, this.downloadedFrom, this.downloadedTo, this.minBytes, this.maxBytes, this.sortBy, required this.descending, required this.offset, required this.limit}): _statuses = statuses,_authors = authors,_tags = tags,super._();
+  
+
+ final  List<String> _statuses;
+@override List<String> get statuses {
+  if (_statuses is EqualUnmodifiableListView) return _statuses;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_statuses);
+}
+
+@override final  String? folderId;
+@override final  bool includeSubfolders;
+ final  List<String> _authors;
+@override List<String> get authors {
+  if (_authors is EqualUnmodifiableListView) return _authors;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_authors);
+}
+
+ final  List<String> _tags;
+@override List<String> get tags {
+  if (_tags is EqualUnmodifiableListView) return _tags;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_tags);
+}
+
+@override final  String? text;
+@override final  PlatformInt64? downloadedFrom;
+@override final  PlatformInt64? downloadedTo;
+@override final  BigInt? minBytes;
+@override final  BigInt? maxBytes;
+@override final  String? sortBy;
+@override final  bool descending;
+@override final  int offset;
+@override final  int limit;
+
+/// Create a copy of ApiDownloadQuery
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadQueryCopyWith<_ApiDownloadQuery> get copyWith => __$ApiDownloadQueryCopyWithImpl<_ApiDownloadQuery>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadQuery&&const DeepCollectionEquality().equals(other._statuses, _statuses)&&(identical(other.folderId, folderId) || other.folderId == folderId)&&(identical(other.includeSubfolders, includeSubfolders) || other.includeSubfolders == includeSubfolders)&&const DeepCollectionEquality().equals(other._authors, _authors)&&const DeepCollectionEquality().equals(other._tags, _tags)&&(identical(other.text, text) || other.text == text)&&(identical(other.downloadedFrom, downloadedFrom) || other.downloadedFrom == downloadedFrom)&&(identical(other.downloadedTo, downloadedTo) || other.downloadedTo == downloadedTo)&&(identical(other.minBytes, minBytes) || other.minBytes == minBytes)&&(identical(other.maxBytes, maxBytes) || other.maxBytes == maxBytes)&&(identical(other.sortBy, sortBy) || other.sortBy == sortBy)&&(identical(other.descending, descending) || other.descending == descending)&&(identical(other.offset, offset) || other.offset == offset)&&(identical(other.limit, limit) || other.limit == limit));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_statuses),folderId,includeSubfolders,const DeepCollectionEquality().hash(_authors),const DeepCollectionEquality().hash(_tags),text,downloadedFrom,downloadedTo,minBytes,maxBytes,sortBy,descending,offset,limit);
+
+@override
+String toString() {
+  return 'ApiDownloadQuery(statuses: $statuses, folderId: $folderId, includeSubfolders: $includeSubfolders, authors: $authors, tags: $tags, text: $text, downloadedFrom: $downloadedFrom, downloadedTo: $downloadedTo, minBytes: $minBytes, maxBytes: $maxBytes, sortBy: $sortBy, descending: $descending, offset: $offset, limit: $limit)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadQueryCopyWith<$Res> implements $ApiDownloadQueryCopyWith<$Res> {
+  factory _$ApiDownloadQueryCopyWith(_ApiDownloadQuery value, $Res Function(_ApiDownloadQuery) _then) = __$ApiDownloadQueryCopyWithImpl;
+@override @useResult
+$Res call({
+ List<String> statuses, String? folderId, bool includeSubfolders, List<String> authors, List<String> tags, String? text, PlatformInt64? downloadedFrom, PlatformInt64? downloadedTo, BigInt? minBytes, BigInt? maxBytes, String? sortBy, bool descending, int offset, int limit
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadQueryCopyWithImpl<$Res>
+    implements _$ApiDownloadQueryCopyWith<$Res> {
+  __$ApiDownloadQueryCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadQuery _self;
+  final $Res Function(_ApiDownloadQuery) _then;
+
+/// Create a copy of ApiDownloadQuery
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? statuses = null,Object? folderId = freezed,Object? includeSubfolders = null,Object? authors = null,Object? tags = null,Object? text = freezed,Object? downloadedFrom = freezed,Object? downloadedTo = freezed,Object? minBytes = freezed,Object? maxBytes = freezed,Object? sortBy = freezed,Object? descending = null,Object? offset = null,Object? limit = null,}) {
+  return _then(_ApiDownloadQuery(
+statuses: null == statuses ? _self._statuses : statuses // ignore: cast_nullable_to_non_nullable
+as List<String>,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
+as String?,includeSubfolders: null == includeSubfolders ? _self.includeSubfolders : includeSubfolders // ignore: cast_nullable_to_non_nullable
+as bool,authors: null == authors ? _self._authors : authors // ignore: cast_nullable_to_non_nullable
+as List<String>,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,text: freezed == text ? _self.text : text // ignore: cast_nullable_to_non_nullable
+as String?,downloadedFrom: freezed == downloadedFrom ? _self.downloadedFrom : downloadedFrom // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,downloadedTo: freezed == downloadedTo ? _self.downloadedTo : downloadedTo // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,minBytes: freezed == minBytes ? _self.minBytes : minBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,maxBytes: freezed == maxBytes ? _self.maxBytes : maxBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,sortBy: freezed == sortBy ? _self.sortBy : sortBy // ignore: cast_nullable_to_non_nullable
+as String?,descending: null == descending ? _self.descending : descending // ignore: cast_nullable_to_non_nullable
+as bool,offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as int,limit: null == limit ? _self.limit : limit // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadQuotaPolicy {
+
+ BigInt? get quotaBytes; bool get autoCleanup; int? get watchedOlderThanDays; bool get oldestFirst; List<String> get pinnedFolderIds;
+/// Create a copy of ApiDownloadQuotaPolicy
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadQuotaPolicyCopyWith<ApiDownloadQuotaPolicy> get copyWith => _$ApiDownloadQuotaPolicyCopyWithImpl<ApiDownloadQuotaPolicy>(this as ApiDownloadQuotaPolicy, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadQuotaPolicy&&(identical(other.quotaBytes, quotaBytes) || other.quotaBytes == quotaBytes)&&(identical(other.autoCleanup, autoCleanup) || other.autoCleanup == autoCleanup)&&(identical(other.watchedOlderThanDays, watchedOlderThanDays) || other.watchedOlderThanDays == watchedOlderThanDays)&&(identical(other.oldestFirst, oldestFirst) || other.oldestFirst == oldestFirst)&&const DeepCollectionEquality().equals(other.pinnedFolderIds, pinnedFolderIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,quotaBytes,autoCleanup,watchedOlderThanDays,oldestFirst,const DeepCollectionEquality().hash(pinnedFolderIds));
+
+@override
+String toString() {
+  return 'ApiDownloadQuotaPolicy(quotaBytes: $quotaBytes, autoCleanup: $autoCleanup, watchedOlderThanDays: $watchedOlderThanDays, oldestFirst: $oldestFirst, pinnedFolderIds: $pinnedFolderIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadQuotaPolicyCopyWith<$Res>  {
+  factory $ApiDownloadQuotaPolicyCopyWith(ApiDownloadQuotaPolicy value, $Res Function(ApiDownloadQuotaPolicy) _then) = _$ApiDownloadQuotaPolicyCopyWithImpl;
+@useResult
+$Res call({
+ BigInt? quotaBytes, bool autoCleanup, int? watchedOlderThanDays, bool oldestFirst, List<String> pinnedFolderIds
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadQuotaPolicyCopyWithImpl<$Res>
+    implements $ApiDownloadQuotaPolicyCopyWith<$Res> {
+  _$ApiDownloadQuotaPolicyCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadQuotaPolicy _self;
+  final $Res Function(ApiDownloadQuotaPolicy) _then;
+
+/// Create a copy of ApiDownloadQuotaPolicy
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? quotaBytes = freezed,Object? autoCleanup = null,Object? watchedOlderThanDays = freezed,Object? oldestFirst = null,Object? pinnedFolderIds = null,}) {
+  return _then(_self.copyWith(
+quotaBytes: freezed == quotaBytes ? _self.quotaBytes : quotaBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,autoCleanup: null == autoCleanup ? _self.autoCleanup : autoCleanup // ignore: cast_nullable_to_non_nullable
+as bool,watchedOlderThanDays: freezed == watchedOlderThanDays ? _self.watchedOlderThanDays : watchedOlderThanDays // ignore: cast_nullable_to_non_nullable
+as int?,oldestFirst: null == oldestFirst ? _self.oldestFirst : oldestFirst // ignore: cast_nullable_to_non_nullable
+as bool,pinnedFolderIds: null == pinnedFolderIds ? _self.pinnedFolderIds : pinnedFolderIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadQuotaPolicy].
+extension ApiDownloadQuotaPolicyPatterns on ApiDownloadQuotaPolicy {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadQuotaPolicy value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadQuotaPolicy() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadQuotaPolicy value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadQuotaPolicy():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadQuotaPolicy value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadQuotaPolicy() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt? quotaBytes,  bool autoCleanup,  int? watchedOlderThanDays,  bool oldestFirst,  List<String> pinnedFolderIds)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadQuotaPolicy() when $default != null:
+return $default(_that.quotaBytes,_that.autoCleanup,_that.watchedOlderThanDays,_that.oldestFirst,_that.pinnedFolderIds);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt? quotaBytes,  bool autoCleanup,  int? watchedOlderThanDays,  bool oldestFirst,  List<String> pinnedFolderIds)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadQuotaPolicy():
+return $default(_that.quotaBytes,_that.autoCleanup,_that.watchedOlderThanDays,_that.oldestFirst,_that.pinnedFolderIds);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt? quotaBytes,  bool autoCleanup,  int? watchedOlderThanDays,  bool oldestFirst,  List<String> pinnedFolderIds)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadQuotaPolicy() when $default != null:
+return $default(_that.quotaBytes,_that.autoCleanup,_that.watchedOlderThanDays,_that.oldestFirst,_that.pinnedFolderIds);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadQuotaPolicy extends ApiDownloadQuotaPolicy {
+  const _ApiDownloadQuotaPolicy({this.quotaBytes, required this.autoCleanup, this.watchedOlderThanDays, required this.oldestFirst, required final  List<String> pinnedFolderIds}): _pinnedFolderIds = pinnedFolderIds,super._();
+  
+
+@override final  BigInt? quotaBytes;
+@override final  bool autoCleanup;
+@override final  int? watchedOlderThanDays;
+@override final  bool oldestFirst;
+ final  List<String> _pinnedFolderIds;
+@override List<String> get pinnedFolderIds {
+  if (_pinnedFolderIds is EqualUnmodifiableListView) return _pinnedFolderIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_pinnedFolderIds);
+}
+
+
+/// Create a copy of ApiDownloadQuotaPolicy
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadQuotaPolicyCopyWith<_ApiDownloadQuotaPolicy> get copyWith => __$ApiDownloadQuotaPolicyCopyWithImpl<_ApiDownloadQuotaPolicy>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadQuotaPolicy&&(identical(other.quotaBytes, quotaBytes) || other.quotaBytes == quotaBytes)&&(identical(other.autoCleanup, autoCleanup) || other.autoCleanup == autoCleanup)&&(identical(other.watchedOlderThanDays, watchedOlderThanDays) || other.watchedOlderThanDays == watchedOlderThanDays)&&(identical(other.oldestFirst, oldestFirst) || other.oldestFirst == oldestFirst)&&const DeepCollectionEquality().equals(other._pinnedFolderIds, _pinnedFolderIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,quotaBytes,autoCleanup,watchedOlderThanDays,oldestFirst,const DeepCollectionEquality().hash(_pinnedFolderIds));
+
+@override
+String toString() {
+  return 'ApiDownloadQuotaPolicy(quotaBytes: $quotaBytes, autoCleanup: $autoCleanup, watchedOlderThanDays: $watchedOlderThanDays, oldestFirst: $oldestFirst, pinnedFolderIds: $pinnedFolderIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadQuotaPolicyCopyWith<$Res> implements $ApiDownloadQuotaPolicyCopyWith<$Res> {
+  factory _$ApiDownloadQuotaPolicyCopyWith(_ApiDownloadQuotaPolicy value, $Res Function(_ApiDownloadQuotaPolicy) _then) = __$ApiDownloadQuotaPolicyCopyWithImpl;
+@override @useResult
+$Res call({
+ BigInt? quotaBytes, bool autoCleanup, int? watchedOlderThanDays, bool oldestFirst, List<String> pinnedFolderIds
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadQuotaPolicyCopyWithImpl<$Res>
+    implements _$ApiDownloadQuotaPolicyCopyWith<$Res> {
+  __$ApiDownloadQuotaPolicyCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadQuotaPolicy _self;
+  final $Res Function(_ApiDownloadQuotaPolicy) _then;
+
+/// Create a copy of ApiDownloadQuotaPolicy
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? quotaBytes = freezed,Object? autoCleanup = null,Object? watchedOlderThanDays = freezed,Object? oldestFirst = null,Object? pinnedFolderIds = null,}) {
+  return _then(_ApiDownloadQuotaPolicy(
+quotaBytes: freezed == quotaBytes ? _self.quotaBytes : quotaBytes // ignore: cast_nullable_to_non_nullable
+as BigInt?,autoCleanup: null == autoCleanup ? _self.autoCleanup : autoCleanup // ignore: cast_nullable_to_non_nullable
+as bool,watchedOlderThanDays: freezed == watchedOlderThanDays ? _self.watchedOlderThanDays : watchedOlderThanDays // ignore: cast_nullable_to_non_nullable
+as int?,oldestFirst: null == oldestFirst ? _self.oldestFirst : oldestFirst // ignore: cast_nullable_to_non_nullable
+as bool,pinnedFolderIds: null == pinnedFolderIds ? _self._pinnedFolderIds : pinnedFolderIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadRule {
+
+ String get id; String get name; String get matchType; String get matchValue; String get quality; String? get folderId; int? get maxAgeDays; int? get dailyCap; bool get enabled; PlatformInt64 get createdAt; PlatformInt64? get lastCheckedAt;
+/// Create a copy of ApiDownloadRule
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadRuleCopyWith<ApiDownloadRule> get copyWith => _$ApiDownloadRuleCopyWithImpl<ApiDownloadRule>(this as ApiDownloadRule, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadRule&&(identical(other.id, id) || other.id == id)&&(identical(other.name, name) || other.name == name)&&(identical(other.matchType, matchType) || other.matchType == matchType)&&(identical(other.matchValue, matchValue) || other.matchValue == matchValue)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.folderId, folderId) || other.folderId == folderId)&&(identical(other.maxAgeDays, maxAgeDays) || other.maxAgeDays == maxAgeDays)&&(identical(other.dailyCap, dailyCap) || other.dailyCap == dailyCap)&&(identical(other.enabled, enabled) || other.enabled == enabled)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.lastCheckedAt, lastCheckedAt) || other.lastCheckedAt == lastCheckedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,name,matchType,matchValue,quality,folderId,maxAgeDays,dailyCap,enabled,createdAt,lastCheckedAt);
+
+@override
+String toString() {
+  return 'ApiDownloadRule(id: $id, name: $name, matchType: $matchType, matchValue: $matchValue, quality: $quality, folderId: $folderId, maxAgeDays: $maxAgeDays, dailyCap: $dailyCap, enabled: $enabled, createdAt: $createdAt, lastCheckedAt: $lastCheckedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadRuleCopyWith<$Res>  {
+  factory $ApiDownloadRuleCopyWith(ApiDownloadRule value, $Res Function(ApiDownloadRule) _then) = _$ApiDownloadRuleCopyWithImpl;
+@useResult
+$Res call({
+ String id, String name, String matchType, String matchValue, String quality, String? folderId, int? maxAgeDays, int? dailyCap, bool enabled, PlatformInt64 createdAt, PlatformInt64? lastCheckedAt
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadRuleCopyWithImpl<$Res>
+    implements $ApiDownloadRuleCopyWith<$Res> {
+  _$ApiDownloadRuleCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadRule _self;
+  final $Res Function(ApiDownloadRule) _then;
+
+/// Create a copy of ApiDownloadRule
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? name = null,Object? matchType = null,Object? matchValue = null,Object? quality = null,Object? folderId = freezed,Object? maxAgeDays = freezed,Object? dailyCap = freezed,Object? enabled = null,Object? createdAt = null,Object? lastCheckedAt = freezed,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,matchType: null == matchType ? _self.matchType : matchType // ignore: cast_nullable_to_non_nullable
+as String,matchValue: null == matchValue ? _self.matchValue : matchValue // ignore: cast_nullable_to_non_nullable
+as String,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
+as String?,maxAgeDays: freezed == maxAgeDays ? _self.maxAgeDays : maxAgeDays // ignore: cast_nullable_to_non_nullable
+as int?,dailyCap: freezed == dailyCap ? _self.dailyCap : dailyCap // ignore: cast_nullable_to_non_nullable
+as int?,enabled: null == enabled ? _self.enabled : enabled // ignore: cast_nullable_to_non_nullable
+as bool,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,lastCheckedAt: freezed == lastCheckedAt ? _self.lastCheckedAt : lastCheckedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadRule].
+extension ApiDownloadRulePatterns on ApiDownloadRule {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadRule value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadRule() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadRule value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadRule():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadRule value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadRule() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String name,  String matchType,  String matchValue,  String quality,  String? folderId,  int? maxAgeDays,  int? dailyCap,  bool enabled,  PlatformInt64 createdAt,  PlatformInt64? lastCheckedAt)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadRule() when $default != null:
+return $default(_that.id,_that.name,_that.matchType,_that.matchValue,_that.quality,_that.folderId,_that.maxAgeDays,_that.dailyCap,_that.enabled,_that.createdAt,_that.lastCheckedAt);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String name,  String matchType,  String matchValue,  String quality,  String? folderId,  int? maxAgeDays,  int? dailyCap,  bool enabled,  PlatformInt64 createdAt,  PlatformInt64? lastCheckedAt)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadRule():
+return $default(_that.id,_that.name,_that.matchType,_that.matchValue,_that.quality,_that.folderId,_that.maxAgeDays,_that.dailyCap,_that.enabled,_that.createdAt,_that.lastCheckedAt);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String name,  String matchType,  String matchValue,  String quality,  String? folderId,  int? maxAgeDays,  int? dailyCap,  bool enabled,  PlatformInt64 createdAt,  PlatformInt64? lastCheckedAt)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadRule() when $default != null:
+return $default(_that.id,_that.name,_that.matchType,_that.matchValue,_that.quality,_that.folderId,_that.maxAgeDays,_that.dailyCap,_that.enabled,_that.createdAt,_that.lastCheckedAt);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadRule implements ApiDownloadRule {
+  const _ApiDownloadRule({required this.id, required this.name, required this.matchType, required this.matchValue, required this.quality, this.folderId, this.maxAgeDays, this.dailyCap, required this.enabled, required this.createdAt, this.lastCheckedAt});
+  
+
+@override final  String id;
+@override final  String name;
+@override final  String matchType;
+@override final  String matchValue;
+@override final  String quality;
+@override final  String? folderId;
+@override final  int? maxAgeDays;
+@override final  int? dailyCap;
+@override final  bool enabled;
+@override final  PlatformInt64 createdAt;
+@override final  PlatformInt64? lastCheckedAt;
+
+/// Create a copy of ApiDownloadRule
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadRuleCopyWith<_ApiDownloadRule> get copyWith => __$ApiDownloadRuleCopyWithImpl<_ApiDownloadRule>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadRule&&(identical(other.id, id) || other.id == id)&&(identical(other.name, name) || other.name == name)&&(identical(other.matchType, matchType) || other.matchType == matchType)&&(identical(other.matchValue, matchValue) || other.matchValue == matchValue)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.folderId, folderId) || other.folderId == folderId)&&(identical(other.maxAgeDays, maxAgeDays) || other.maxAgeDays == maxAgeDays)&&(identical(other.dailyCap, dailyCap) || other.dailyCap == dailyCap)&&(identical(other.enabled, enabled) || other.enabled == enabled)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.lastCheckedAt, lastCheckedAt) || other.lastCheckedAt == lastCheckedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,name,matchType,matchValue,quality,folderId,maxAgeDays,dailyCap,enabled,createdAt,lastCheckedAt);
+
+@override
+String toString() {
+  return 'ApiDownloadRule(id: $id, name: $name, matchType: $matchType, matchValue: $matchValue, quality: $quality, folderId: $folderId, maxAgeDays: $maxAgeDays, dailyCap: $dailyCap, enabled: $enabled, createdAt: $createdAt, lastCheckedAt: $lastCheckedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadRuleCopyWith<$Res> implements $ApiDownloadRuleCopyWith<$Res> {
+  factory _$ApiDownloadRuleCopyWith(_ApiDownloadRule value, $Res Function(_ApiDownloadRule) _then) = __$ApiDownloadRuleCopyWithImpl;
+@override @useResult
+$Res call({
+ String id, String name, String matchType, String matchValue, String quality, String? folderId, int? maxAgeDays, int? dailyCap, bool enabled, PlatformInt64 createdAt, PlatformInt64? lastCheckedAt
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadRuleCopyWithImpl<$Res>
+    implements _$ApiDownloadRuleCopyWith<$Res> {
+  __$ApiDownloadRuleCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadRule _self;
+  final $Res Function(_ApiDownloadRule) _then;
+
+/// Create a copy of ApiDownloadRule
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? name = null,Object? matchType = null,Object? matchValue = null,Object? quality = null,Object? folderId = freezed,Object? maxAgeDays = freezed,Object? dailyCap = freezed,Object? enabled = null,Object? createdAt = null,Object? lastCheckedAt = freezed,}) {
+  return _then(_ApiDownloadRule(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,matchType: null == matchType ? _self.matchType : matchType // ignore: cast_nullable_to_non_nullable
+as String,matchValue: null == matchValue ? _self.matchValue : matchValue // ignore: cast_nullable_to_non_nullable
+as String,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
+as String?,maxAgeDays: freezed == maxAgeDays ? _self.maxAgeDays : maxAgeDays // ignore: cast_nullable_to_non_nullable
+as int?,dailyCap: freezed == dailyCap ? _self.dailyCap : dailyCap // ignore: cast_nullable_to_non_nullable
+as int?,enabled: null == enabled ? _self.enabled : enabled // ignore: cast_nullable_to_non_nullable
+as bool,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,lastCheckedAt: freezed == lastCheckedAt ? _self.lastCheckedAt : lastCheckedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadStatus {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiDownloadStatus()';
+}
+
+
+}
+
+/// @nodoc
+class $ApiDownloadStatusCopyWith<$Res>  {
+$ApiDownloadStatusCopyWith(ApiDownloadStatus _, $Res Function(ApiDownloadStatus) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadStatus].
+extension ApiDownloadStatusPatterns on ApiDownloadStatus {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ApiDownloadStatus_Pending value)?  pending,TResult Function( ApiDownloadStatus_Downloading value)?  downloading,TResult Function( ApiDownloadStatus_Paused value)?  paused,TResult Function( ApiDownloadStatus_Completed value)?  completed,TResult Function( ApiDownloadStatus_Failed value)?  failed,TResult Function( ApiDownloadStatus_Corrupt value)?  corrupt,TResult Function( ApiDownloadStatus_Missing value)?  missing,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ApiDownloadStatus_Pending() when pending != null:
+return pending(_that);case ApiDownloadStatus_Downloading() when downloading != null:
+return downloading(_that);case ApiDownloadStatus_Paused() when paused != null:
+return paused(_that);case ApiDownloadStatus_Completed() when completed != null:
+return completed(_that);case ApiDownloadStatus_Failed() when failed != null:
+return failed(_that);case ApiDownloadStatus_Corrupt() when corrupt != null:
+return corrupt(_that);case ApiDownloadStatus_Missing() when missing != null:
+return missing(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ApiDownloadStatus_Pending value)  pending,required TResult Function( ApiDownloadStatus_Downloading value)  downloading,required TResult Function( ApiDownloadStatus_Paused value)  paused,required TResult Function( ApiDownloadStatus_Completed value)  completed,required TResult Function( ApiDownloadStatus_Failed value)  failed,required TResult Function( ApiDownloadStatus_Corrupt value)  corrupt,required TResult Function( ApiDownloadStatus_Missing value)  missing,}){
+final _that = this;
+switch (_that) {
+case ApiDownloadStatus_Pending():
+return pending(_that);case ApiDownloadStatus_Downloading():
+return downloading(_that);case ApiDownloadStatus_Paused():
+return paused(_that);case ApiDownloadStatus_Completed():
+return completed(_that);case ApiDownloadStatus_Failed():
+return failed(_that);case ApiDownloadStatus_Corrupt():
+return corrupt(_that);case ApiDownloadStatus_Missing():
+return missing(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ApiDownloadStatus_Pending value)?  pending,TResult? Function( ApiDownloadStatus_Downloading value)?  downloading,TResult? Function( ApiDownloadStatus_Paused value)?  paused,TResult? Function( ApiDownloadStatus_Completed value)?  completed,TResult? Function( ApiDownloadStatus_Failed value)?  failed,TResult? Function( ApiDownloadStatus_Corrupt value)?  corrupt,TResult? Function( ApiDownloadStatus_Missing value)?  missing,}){
+final _that = this;
+switch (_that) {
+case ApiDownloadStatus_Pending() when pending != null:
+return pending(_that);case ApiDownloadStatus_Downloading() when downloading != null:
+return downloading(_that);case ApiDownloadStatus_Paused() when paused != null:
+return paused(_that);case ApiDownloadStatus_Completed() when completed != null:
+return completed(_that);case ApiDownloadStatus_Failed() when failed != null:
+return failed(_that);case ApiDownloadStatus_Corrupt() when corrupt != null:
+return corrupt(_that);case ApiDownloadStatus_Missing() when missing != null:
+return missing(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  pending,TResult Function()?  downloading,TResult Function()?  paused,TResult Function()?  completed,TResult Function( String error)?  failed,TResult Function( String reason)?  corrupt,TResult Function()?  missing,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ApiDownloadStatus_Pending() when pending != null:
+return pending();case ApiDownloadStatus_Downloading() when downloading != null:
+return downloading();case ApiDownloadStatus_Paused() when paused != null:
+return paused();case ApiDownloadStatus_Completed() when completed != null:
+return completed();case ApiDownloadStatus_Failed() when failed != null:
+return failed(_that.error);case ApiDownloadStatus_Corrupt() when corrupt != null:
+return corrupt(_that.reason);case ApiDownloadStatus_Missing() when missing != null:
+return missing();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  pending,required TResult Function()  downloading,required TResult Function()  paused,required TResult Function()  completed,required TResult Function( String error)  failed,required TResult Function( String reason)  corrupt,required TResult Function()  missing,}) {final _that = this;
+switch (_that) {
+case ApiDownloadStatus_Pending():
+return pending();case ApiDownloadStatus_Downloading():
+return downloading();case ApiDownloadStatus_Paused():
+return paused();case ApiDownloadStatus_Completed():
+return completed();case ApiDownloadStatus_Failed():
+return failed(_that.error);case ApiDownloadStatus_Corrupt():
+return corrupt(_that.reason);case ApiDownloadStatus_Missing():
+return missing();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  pending,TResult? Function()?  downloading,TResult? Function()?  paused,TResult? Function()?  completed,TResult? Function( String error)?  failed,TResult? Function( String reason)?  corrupt,TResult? Function()?  missing,}) {final _that = this;
+switch (_that) {
+case ApiDownloadStatus_Pending() when pending != null:
+return pending();case ApiDownloadStatus_Downloading() when downloading != null:
+return downloading();case ApiDownloadStatus_Paused() when paused != null:
+return paused();case ApiDownloadStatus_Completed() when completed != null:
+return completed();case ApiDownloadStatus_Failed() when failed != null:
+return failed(_that.error);case ApiDownloadStatus_Corrupt() when corrupt != null:
+return corrupt(_that.reason);case ApiDownloadStatus_Missing() when missing != null:
+return missing();case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Pending extends ApiDownloadStatus {
+  const ApiDownloadStatus_Pending(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Pending);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.pending()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Downloading extends ApiDownloadStatus {
+  const ApiDownloadStatus_Downloading(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Downloading);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.downloading()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Paused extends ApiDownloadStatus {
+  const ApiDownloadStatus_Paused(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Paused);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.paused()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Completed extends ApiDownloadStatus {
+  const ApiDownloadStatus_Completed(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Completed);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.completed()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Failed extends ApiDownloadStatus {
+  const ApiDownloadStatus_Failed({required this.error}): super._();
+  
+
+ final  String error;
+
+/// Create a copy of ApiDownloadStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadStatus_FailedCopyWith<ApiDownloadStatus_Failed> get copyWith => _$ApiDownloadStatus_FailedCopyWithImpl<ApiDownloadStatus_Failed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Failed&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,error);
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.failed(error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadStatus_FailedCopyWith<$Res> implements $ApiDownloadStatusCopyWith<$Res> {
+  factory $ApiDownloadStatus_FailedCopyWith(ApiDownloadStatus_Failed value, $Res Function(ApiDownloadStatus_Failed) _then) = _$ApiDownloadStatus_FailedCopyWithImpl;
+@useResult
+$Res call({
+ String error
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadStatus_FailedCopyWithImpl<$Res>
+    implements $ApiDownloadStatus_FailedCopyWith<$Res> {
+  _$ApiDownloadStatus_FailedCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadStatus_Failed _self;
+  final $Res Function(ApiDownloadStatus_Failed) _then;
+
+/// Create a copy of ApiDownloadStatus
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? error = null,}) {
+  return _then(ApiDownloadStatus_Failed(
+error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Corrupt extends ApiDownloadStatus {
+  const ApiDownloadStatus_Corrupt({required this.reason}): super._();
+  
+
+ final  String reason;
+
+/// Create a copy of ApiDownloadStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadStatus_CorruptCopyWith<ApiDownloadStatus_Corrupt> get copyWith => _$ApiDownloadStatus_CorruptCopyWithImpl<ApiDownloadStatus_Corrupt>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Corrupt&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.corrupt(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadStatus_CorruptCopyWith<$Res> implements $ApiDownloadStatusCopyWith<$Res> {
+  factory $ApiDownloadStatus_CorruptCopyWith(ApiDownloadStatus_Corrupt value, $Res Function(ApiDownloadStatus_Corrupt) _then) = _$ApiDownloadStatus_CorruptCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadStatus_CorruptCopyWithImpl<$Res>
+    implements $ApiDownloadStatus_CorruptCopyWith<$Res> {
+  _$ApiDownloadStatus_CorruptCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadStatus_Corrupt _self;
+  final $Res Function(ApiDownloadStatus_Corrupt) _then;
+
+/// Create a copy of ApiDownloadStatus
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(ApiDownloadStatus_Corrupt(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiDownloadStatus_Missing extends ApiDownloadStatus {
+  const ApiDownloadStatus_Missing(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStatus_Missing);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiDownloadStatus.missing()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+mixin _$ApiDownloadStorageInfo {
+
+ String get downloadDir; BigInt get usedBytes; BigInt get pendingBytes; BigInt get freeBytes; BigInt get diskTotalBytes; BigInt get reserveBytes; bool get preallocate;
+/// Create a copy of ApiDownloadStorageInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadStorageInfoCopyWith<ApiDownloadStorageInfo> get copyWith => _$ApiDownloadStorageInfoCopyWithImpl<ApiDownloadStorageInfo>(this as ApiDownloadStorageInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadStorageInfo&&(identical(other.downloadDir, downloadDir) || other.downloadDir == downloadDir)&&(identical(other.usedBytes, usedBytes) || other.usedBytes == usedBytes)&&(identical(other.pendingBytes, pendingBytes) || other.pendingBytes == pendingBytes)&&(identical(other.freeBytes, freeBytes) || other.freeBytes == freeBytes)&&(identical(other.diskTotalBytes, diskTotalBytes) || other.diskTotalBytes == diskTotalBytes)&&(identical(other.reserveBytes, reserveBytes) || other.reserveBytes == reserveBytes)&&(identical(other.preallocate, preallocate) || other.preallocate == preallocate));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,downloadDir,usedBytes,pendingBytes,freeBytes,diskTotalBytes,reserveBytes,preallocate);
+
+@override
+String toString() {
+  return 'ApiDownloadStorageInfo(downloadDir: $downloadDir, usedBytes: $usedBytes, pendingBytes: $pendingBytes, freeBytes: $freeBytes, diskTotalBytes: $diskTotalBytes, reserveBytes: $reserveBytes, preallocate: $preallocate)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadStorageInfoCopyWith<$Res>  {
+  factory $ApiDownloadStorageInfoCopyWith(ApiDownloadStorageInfo value, $Res Function(ApiDownloadStorageInfo) _then) = _$ApiDownloadStorageInfoCopyWithImpl;
+@useResult
+$Res call({
+ String downloadDir, BigInt usedBytes, BigInt pendingBytes, BigInt freeBytes, BigInt diskTotalBytes, BigInt reserveBytes, bool preallocate
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadStorageInfoCopyWithImpl<$Res>
+    implements $ApiDownloadStorageInfoCopyWith<$Res> {
+  _$ApiDownloadStorageInfoCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadStorageInfo _self;
+  final $Res Function(ApiDownloadStorageInfo) _then;
+
+/// Create a copy of ApiDownloadStorageInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? downloadDir = null,Object? usedBytes = null,Object? pendingBytes = null,Object? freeBytes = null,Object? diskTotalBytes = null,Object? reserveBytes = null,Object? preallocate = null,}) {
+  return _then(_self.copyWith(
+downloadDir: null == downloadDir ? _self.downloadDir : downloadDir // ignore: cast_nullable_to_non_nullable
+as String,usedBytes: null == usedBytes ? _self.usedBytes : usedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,pendingBytes: null == pendingBytes ? _self.pendingBytes : pendingBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,freeBytes: null == freeBytes ? _self.freeBytes : freeBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,diskTotalBytes: null == diskTotalBytes ? _self.diskTotalBytes : diskTotalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,reserveBytes: null == reserveBytes ? _self.reserveBytes : reserveBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,preallocate: null == preallocate ? _self.preallocate : preallocate // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadStorageInfo].
+extension ApiDownloadStorageInfoPatterns on ApiDownloadStorageInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadStorageInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadStorageInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadStorageInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadStorageInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadStorageInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadStorageInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String downloadDir,  BigInt usedBytes,  BigInt pendingBytes,  BigInt freeBytes,  BigInt diskTotalBytes,  BigInt reserveBytes,  bool preallocate)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadStorageInfo() when $default != null:
+return $default(_that.downloadDir,_that.usedBytes,_that.pendingBytes,_that.freeBytes,_that.diskTotalBytes,_that.reserveBytes,_that.preallocate);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String downloadDir,  BigInt usedBytes,  BigInt pendingBytes,  BigInt freeBytes,  BigInt diskTotalBytes,  BigInt reserveBytes,  bool preallocate)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadStorageInfo():
+return $default(_that.downloadDir,_that.usedBytes,_that.pendingBytes,_that.freeBytes,_that.diskTotalBytes,_that.reserveBytes,_that.preallocate);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String downloadDir,  BigInt usedBytes,  BigInt pendingBytes,  BigInt freeBytes,  BigInt diskTotalBytes,  BigInt reserveBytes,  bool preallocate)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadStorageInfo() when $default != null:
+return $default(_that.downloadDir,_that.usedBytes,_that.pendingBytes,_that.freeBytes,_that.diskTotalBytes,_that.reserveBytes,_that.preallocate);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadStorageInfo implements ApiDownloadStorageInfo {
+  const _ApiDownloadStorageInfo({required this.downloadDir, required this.usedBytes, required this.pendingBytes, required this.freeBytes, required this.diskTotalBytes, required this.reserveBytes, required this.preallocate});
+  
+
+@override final  String downloadDir;
+@override final  BigInt usedBytes;
+@override final  BigInt pendingBytes;
+@override final  BigInt freeBytes;
+@override final  BigInt diskTotalBytes;
+@override final  BigInt reserveBytes;
+@override final  bool preallocate;
+
+/// Create a copy of ApiDownloadStorageInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadStorageInfoCopyWith<_ApiDownloadStorageInfo> get copyWith => __$ApiDownloadStorageInfoCopyWithImpl<_ApiDownloadStorageInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadStorageInfo&&(identical(other.downloadDir, downloadDir) || other.downloadDir == downloadDir)&&(identical(other.usedBytes, usedBytes) || other.usedBytes == usedBytes)&&(identical(other.pendingBytes, pendingBytes) || other.pendingBytes == pendingBytes)&&(identical(other.freeBytes, freeBytes) || other.freeBytes == freeBytes)&&(identical(other.diskTotalBytes, diskTotalBytes) || other.diskTotalBytes == diskTotalBytes)&&(identical(other.reserveBytes, reserveBytes) || other.reserveBytes == reserveBytes)&&(identical(other.preallocate, preallocate) || other.preallocate == preallocate));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,downloadDir,usedBytes,pendingBytes,freeBytes,diskTotalBytes,reserveBytes,preallocate);
+
+@override
+String toString() {
+  return 'ApiDownloadStorageInfo(downloadDir: $downloadDir, usedBytes: $usedBytes, pendingBytes: $pendingBytes, freeBytes: $freeBytes, diskTotalBytes: $diskTotalBytes, reserveBytes: $reserveBytes, preallocate: $preallocate)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadStorageInfoCopyWith<$Res> implements $ApiDownloadStorageInfoCopyWith<$Res> {
+  factory _$ApiDownloadStorageInfoCopyWith(_ApiDownloadStorageInfo value, $Res Function(_ApiDownloadStorageInfo) _then) = __$ApiDownloadStorageInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String downloadDir, BigInt usedBytes, BigInt pendingBytes, BigInt freeBytes, BigInt diskTotalBytes, BigInt reserveBytes, bool preallocate
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadStorageInfoCopyWithImpl<$Res>
+    implements _$ApiDownloadStorageInfoCopyWith<$Res> {
+  __$ApiDownloadStorageInfoCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadStorageInfo _self;
+  final $Res Function(_ApiDownloadStorageInfo) _then;
+
+/// Create a copy of ApiDownloadStorageInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? downloadDir = null,Object? usedBytes = null,Object? pendingBytes = null,Object? freeBytes = null,Object? diskTotalBytes = null,Object? reserveBytes = null,Object? preallocate = null,}) {
+  return _then(_ApiDownloadStorageInfo(
+downloadDir: null == downloadDir ? _self.downloadDir : downloadDir // ignore: cast_nullable_to_non_nullable
+as String,usedBytes: null == usedBytes ? _self.usedBytes : usedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,pendingBytes: null == pendingBytes ? _self.pendingBytes : pendingBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,freeBytes: null == freeBytes ? _self.freeBytes : freeBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,diskTotalBytes: null == diskTotalBytes ? _self.diskTotalBytes : diskTotalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,reserveBytes: null == reserveBytes ? _self.reserveBytes : reserveBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,preallocate: null == preallocate ? _self.preallocate : preallocate // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadSubtitle {
+
+ String get language; String get label; String get url; String get format; String? get filePath;
+/// Create a copy of ApiDownloadSubtitle
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadSubtitleCopyWith<ApiDownloadSubtitle> get copyWith => _$ApiDownloadSubtitleCopyWithImpl<ApiDownloadSubtitle>(this as ApiDownloadSubtitle, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadSubtitle&&(identical(other.language, language) || other.language == language)&&(identical(other.label, label) || other.label == label)&&(identical(other.url, url) || other.url == url)&&(identical(other.format, format) || other.format == format)&&(identical(other.filePath, filePath) || other.filePath == filePath));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,language,label,url,format,filePath);
+
+@override
+String toString() {
+  return 'ApiDownloadSubtitle(language: $language, label: $label, url: $url, format: $format, filePath: $filePath)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadSubtitleCopyWith<$Res>  {
+  factory $ApiDownloadSubtitleCopyWith(ApiDownloadSubtitle value, $Res Function(ApiDownloadSubtitle) _then) = _$ApiDownloadSubtitleCopyWithImpl;
+@useResult
+$Res call({
+ String language, String label, String url, String format, String? filePath
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiDownloadSubtitleCopyWithImpl<$Res>
+    implements $ApiDownloadSubtitleCopyWith<$Res> {
+  _$ApiDownloadSubtitleCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadSubtitle _self;
+  final $Res Function(ApiDownloadSubtitle) _then;
+
+/// Create a copy of ApiDownloadSubtitle
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? language = null,Object? label = null,Object? url = null,Object? format = null,Object? filePath = freezed,}) {
+  return _then(_self.copyWith(
+language: null == language ? _self.language : language // ignore: cast_nullable_to_non_nullable
+as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
+as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,format: null == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as String,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadSubtitle].
+extension ApiDownloadSubtitlePatterns on ApiDownloadSubtitle {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadSubtitle value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadSubtitle() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadSubtitle value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadSubtitle():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadSubtitle value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadSubtitle() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String language,  String label,  String url,  String format,  String? filePath)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadSubtitle() when $default != null:
+return $default(_that.language,_that.label,_that.url,_that.format,_that.filePath);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String language,  String label,  String url,  String format,  String? filePath)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadSubtitle():
+return $default(_that.language,_that.label,_that.url,_that.format,_that.filePath);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String language,  String label,  String url,  String format,  String? filePath)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadSubtitle() when $default != null:
+return $default(_that.language,_that.label,_that.url,_that.format,_that.filePath);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadSubtitle implements ApiDownloadSubtitle {
+  const _ApiDownloadSubtitle({required this.language, required this.label, required this.url, required this.format, this.filePath});
+  
+
+@override final  String language;
+@override final  String label;
+@override final  String url;
+@override final  String format;
+@override final  String? filePath;
+
+/// Create a copy of ApiDownloadSubtitle
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadSubtitleCopyWith<_ApiDownloadSubtitle> get copyWith => __$ApiDownloadSubtitleCopyWithImpl<_ApiDownloadSubtitle>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadSubtitle&&(identical(other.language, language) || other.language == language)&&(identical(other.label, label) || other.label == label)&&(identical(other.url, url) || other.url == url)&&(identical(other.format, format) || other.format == format)&&(identical(other.filePath, filePath) || other.filePath == filePath));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,language,label,url,format,filePath);
+
+@override
+String toString() {
+  return 'ApiDownloadSubtitle(language: $language, label: $label, url: $url, format: $format, filePath: $filePath)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadSubtitleCopyWith<$Res> implements $ApiDownloadSubtitleCopyWith<$Res> {
+  factory _$ApiDownloadSubtitleCopyWith(_ApiDownloadSubtitle value, $Res Function(_ApiDownloadSubtitle) _then) = __$ApiDownloadSubtitleCopyWithImpl;
+@override @useResult
+$Res call({
+ String language, String label, String url, String format, String? filePath
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiDownloadSubtitleCopyWithImpl<$Res>
+    implements _$ApiDownloadSubtitleCopyWith<$Res> {
+  __$ApiDownloadSubtitleCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadSubtitle _self;
+  final $Res Function(_ApiDownloadSubtitle) _then;
+
+/// Create a copy of ApiDownloadSubtitle
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? language = null,Object? label = null,Object? url = null,Object? format = null,Object? filePath = freezed,}) {
+  return _then(_ApiDownloadSubtitle(
+language: null == language ? _self.language : language // ignore: cast_nullable_to_non_nullable
+as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
+as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,format: null == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as String,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiDownloadTask {
+
+ String get id; String get videoId; String get title; String get coverUrl; String? get coverPath; String? get authorId; String? get authorName; String? get authorAvatarUrl; String? get authorAvatarPath; String get quality; String? get description; List<String> get tags; ApiDownloadStatus get status; double get progress; BigInt get downloadedBytes; BigInt get totalBytes; BigInt get speed; PlatformInt64 get createdAt; String? get filePath; String? get folderId; String? get errorKind; int get retryCount; int get maxRetries; PlatformInt64? get nextRetryAt;
+/// Create a copy of ApiDownloadTask
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadTaskCopyWith<ApiDownloadTask> get copyWith => _$ApiDownloadTaskCopyWithImpl<ApiDownloadTask>(this as ApiDownloadTask, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadTask&&(identical(other.id, id) || other.id == id)&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.coverPath, coverPath) || other.coverPath == coverPath)&&(identical(other.authorId, authorId) || other.authorId == authorId)&&(identical(other.authorName, authorName) || other.authorName == authorName)&&(identical(other.authorAvatarUrl, authorAvatarUrl) || other.authorAvatarUrl == authorAvatarUrl)&&(identical(other.authorAvatarPath, authorAvatarPath) || other.authorAvatarPath == authorAvatarPath)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.description, description) || other.description == description)&&const DeepCollectionEquality().equals(other.tags, tags)&&(identical(other.status, status) || other.status == status)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.downloadedBytes, downloadedBytes) || other.downloadedBytes == downloadedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.speed, speed) || other.speed == speed)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.filePath, filePath) || other.filePath == filePath)&&(identical(other.folderId, folderId) || other.folderId == folderId)&&(identical(other.errorKind, errorKind) || other.errorKind == errorKind)&&(identical(other.retryCount, retryCount) || other.retryCount == retryCount)&&(identical(other.maxRetries, maxRetries) || other.maxRetries == maxRetries)&&(identical(other.nextRetryAt, nextRetryAt) || other.nextRetryAt == nextRetryAt));
+}
+
+
+@override
+int get hashCode => Object.hashAll([runtimeType,id,videoId,title,coverUrl,coverPath,authorId,authorName,authorAvatarUrl,authorAvatarPath,quality,description,const DeepCollectionEquality().hash(tags),status,progress,downloadedBytes,totalBytes,speed,createdAt,filePath,folderId,errorKind,retryCount,maxRetries,nextRetryAt]);
+
+@override
+String toString() {
+  return 'ApiDownloadTask(id: $id, videoId: $videoId, title: $title, coverUrl: $coverUrl, coverPath: $coverPath, authorId: $authorId, authorName: $authorName, authorAvatarUrl: $authorAvatarUrl, authorAvatarPath: $authorAvatarPath, quality: $quality, description: $description, tags: $tags, status: $status, progress: $progress, downloadedBytes: $downloadedBytes, totalBytes: $totalBytes, speed: $speed, createdAt: $createdAt, filePath: $filePath, folderId: $folderId, errorKind: $errorKind, retryCount: $retryCount, maxRetries: $maxRetries, nextRetryAt: $nextRetryAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadTaskCopyWith<$Res>  {
+  factory $ApiDownloadTaskCopyWith(ApiDownloadTask value, $Res Function(ApiDownloadTask) _then) = _$ApiDownloadTaskCopyWithImpl;
+@useResult
+$Res call({
+ String id, String videoId, String title, String coverUrl, String? coverPath, String? authorId, String? authorName, String? authorAvatarUrl, String? authorAvatarPath, String quality, String? description, List<String> tags, ApiDownloadStatus status, double progress, BigInt downloadedBytes, BigInt totalBytes, BigInt speed, PlatformInt64 createdAt, String? filePath, String? folderId, String? errorKind, int retryCount, int maxRetries, PlatformInt64? nextRetryAt
+});
+
+
+$ApiDownloadStatusCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class _$ApiDownloadTaskCopyWithImpl<$Res>
+    implements $ApiDownloadTaskCopyWith<$Res> {
+  _$ApiDownloadTaskCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadTask _self;
+  final $Res Function(ApiDownloadTask) _then;
+
+/// Create a copy of ApiDownloadTask
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? coverPath = freezed,Object? authorId = freezed,Object? authorName = freezed,Object? authorAvatarUrl = freezed,Object? authorAvatarPath = freezed,Object? quality = null,Object? description = freezed,Object? tags = null,Object? status = null,Object? progress = null,Object? downloadedBytes = null,Object? totalBytes = null,Object? speed = null,Object? createdAt = null,Object? filePath = freezed,Object? folderId = freezed,Object? errorKind = freezed,Object? retryCount = null,Object? maxRetries = null,Object? nextRetryAt = freezed,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,coverPath: freezed == coverPath ? _self.coverPath : coverPath // ignore: cast_nullable_to_non_nullable
+as String?,authorId: freezed == authorId ? _self.authorId : authorId // ignore: cast_nullable_to_non_nullable
+as String?,authorName: freezed == authorName ? _self.authorName : authorName // ignore: cast_nullable_to_non_nullable
+as String?,authorAvatarUrl: freezed == authorAvatarUrl ? _self.authorAvatarUrl : authorAvatarUrl // ignore: cast_nullable_to_non_nullable
+as String?,authorAvatarPath: freezed == authorAvatarPath ? _self.authorAvatarPath : authorAvatarPath // ignore: cast_nullable_to_non_nullable
+as String?,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String,description: freezed == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
+as String?,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as ApiDownloadStatus,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,downloadedBytes: null == downloadedBytes ? _self.downloadedBytes : downloadedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,speed: null == speed ? _self.speed : speed // ignore: cast_nullable_to_non_nullable
+as BigInt,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
+as String?,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
+as String?,errorKind: freezed == errorKind ? _self.errorKind : errorKind // ignore: cast_nullable_to_non_nullable
+as String?,retryCount: null == retryCount ? _self.retryCount : retryCount // ignore: cast_nullable_to_non_nullable
+as int,maxRetries: null == maxRetries ? _self.maxRetries : maxRetries // ignore: cast_nullable_to_non_nullable
+as int,nextRetryAt: freezed == nextRetryAt ? _self.nextRetryAt : nextRetryAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+/// Create a copy of ApiDownloadTask
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiDownloadStatusCopyWith<$Res> get status {
+  
+  return $ApiDownloadStatusCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadTask].
+extension ApiDownloadTaskPatterns on ApiDownloadTask {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadTask value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadTask() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadTask value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadTask():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadTask value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadTask() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String videoId,  String title,  String coverUrl,  String? coverPath,  String? authorId,  String? authorName,  String? authorAvatarUrl,  String? authorAvatarPath,  String quality,  String? description,  List<String> tags,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  BigInt speed,  PlatformInt64 createdAt,  String? filePath,  String? folderId,  String? errorKind,  int retryCount,  int maxRetries,  PlatformInt64? nextRetryAt)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadTask() when $default != null:
+return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPath,_that.authorId,_that.authorName,_that.authorAvatarUrl,_that.authorAvatarPath,_that.quality,_that.description,_that.tags,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.speed,_that.createdAt,_that.filePath,_that.folderId,_that.errorKind,_that.retryCount,_that.maxRetries,_that.nextRetryAt);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String videoId,  String title,  String coverUrl,  String? coverPath,  String? authorId,  String? authorName,  String? authorAvatarUrl,  String? authorAvatarPath,  String quality,  String? description,  List<String> tags,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  BigInt speed,  PlatformInt64 createdAt,  String? filePath,  String? folderId,  String? errorKind,  int retryCount,  int maxRetries,  PlatformInt64? nextRetryAt)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadTask():
+return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPath,_that.authorId,_that.authorName,_that.authorAvatarUrl,_that.authorAvatarPath,_that.quality,_that.description,_that.tags,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.speed,_that.createdAt,_that.filePath,_that.folderId,_that.errorKind,_that.retryCount,_that.maxRetries,_that.nextRetryAt);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String videoId,  String title,  String coverUrl,  String? coverPath,  String? authorId,  String? authorName,  String? authorAvatarUrl,  String? authorAvatarPath,  String quality,  String? description,  List<String> tags,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  BigInt speed,  PlatformInt64 createdAt,  String? filePath,  String? folderId,  String? errorKind,  int retryCount,  int maxRetries,  PlatformInt64? nextRetryAt)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadTask() when $default != null:
+return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPath,_that.authorId,_that.authorName,_that.authorAvatarUrl,_that.authorAvatarPath,_that.quality,_that.description,_that.tags,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.speed,_that.createdAt,_that.filePath,_that.folderId,_that.errorKind,_that.retryCount,_that.maxRetries,_that.nextRetryAt);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadTask implements ApiDownloadTask {
+  const _ApiDownloadTask({required this.id, required this.videoId, required this.title, required this.coverUrl, this.coverPath, this.authorId, this.authorName, this.authorAvatarUrl, this.authorAvatarPath, required this.quality, this.description, required final  List<String> tags, required this.status, required this.progress, required this.downloadedBytes, required this.totalBytes, required this.speed, required this.createdAt, this.filePath, this.folderId, this.errorKind, required this.retryCount, required this.maxRetries, this.nextRetryAt}): _tags = tags;
+  
+
+@override final  String id;
+@override final  String videoId;
+@override final  String title;
+@override final  String coverUrl;
+@override final  String? coverPath;
+@override final  String? authorId;
+@override final  String? authorName;
+@override final  String? authorAvatarUrl;
+@override final  String? authorAvatarPath;
+@override final  String quality;
+@override final  String? description;
+ final  List<String> _tags;
+@override List<String> get tags {
+  if (_tags is EqualUnmodifiableListView) return _tags;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_tags);
+}
+
+@override final  ApiDownloadStatus status;
+@override final  double progress;
+@override final  BigInt downloadedBytes;
+@override final  BigInt totalBytes;
+@override final  BigInt speed;
+@override final  PlatformInt64 createdAt;
+@override final  String? filePath;
+@override final  String? folderId;
+@override final  String? errorKind;
+@override final  int retryCount;
+@override final  int maxRetries;
+@override final  PlatformInt64? nextRetryAt;
+
+/// Create a copy of ApiDownloadTask
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadTaskCopyWith<_ApiDownloadTask> get copyWith => __$ApiDownloadTaskCopyWithImpl<_ApiDownloadTask>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadTask&&(identical(other.id, id) || other.id == id)&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.coverPath, coverPath) || other.coverPath == coverPath)&&(identical(other.authorId, authorId) || other.authorId == authorId)&&(identical(other.authorName, authorName) || other.authorName == authorName)&&(identical(other.authorAvatarUrl, authorAvatarUrl) || other.authorAvatarUrl == authorAvatarUrl)&&(identical(other.authorAvatarPath, authorAvatarPath) || other.authorAvatarPath == authorAvatarPath)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.description, description) || other.description == description)&&const DeepCollectionEquality().equals(other._tags, _tags)&&(identical(other.status, status) || other.status == status)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.downloadedBytes, downloadedBytes) || other.downloadedBytes == downloadedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.speed, speed) || other.speed == speed)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.filePath, filePath) || other.filePath == filePath)&&(identical(other.folderId, folderId) || other.folderId == folderId)&&(identical(other.errorKind, errorKind) || other.errorKind == errorKind)&&(identical(other.retryCount, retryCount) || other.retryCount == retryCount)&&(identical(other.maxRetries, maxRetries) || other.maxRetries == maxRetries)&&(identical(other.nextRetryAt, nextRetryAt) || other.nextRetryAt == nextRetryAt));
+}
+
+
+@override
+int get hashCode => Object.hashAll([runtimeType,id,videoId,title,coverUrl,coverPath,authorId,authorName,authorAvatarUrl,authorAvatarPath,quality,description,const DeepCollectionEquality().hash(_tags),status,progress,downloadedBytes,totalBytes,speed,createdAt,filePath,folderId,errorKind,retryCount,maxRetries,nextRetryAt]);
+
+@override
+String toString() {
+  return 'ApiDownloadTask(id: $id, videoId: $videoId, title: $title, coverUrl: $coverUrl, coverPath: $coverPath, authorId: $authorId, authorName: $authorName, authorAvatarUrl: $authorAvatarUrl, authorAvatarPath: $authorAvatarPath, quality: $quality, description: $description, tags: $tags, status: $status, progress: $progress, downloadedBytes: $downloadedBytes, totalBytes: $totalBytes, speed: $speed, createdAt: $createdAt, filePath: $filePath, folderId: $folderId, errorKind: $errorKind, retryCount: $retryCount, maxRetries: $maxRetries, nextRetryAt: $nextRetryAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadTaskCopyWith<$Res> implements $ApiDownloadTaskCopyWith<$Res> {
+  factory _$ApiDownloadTaskCopyWith(_ApiDownloadTask value, $Res Function(_ApiDownloadTask) _then) = __$ApiDownloadTaskCopyWithImpl;
+@override @useResult
+$Res call({
+ String id, String videoId, String title, String coverUrl, String? coverPath, String? authorId, String? authorName, String? authorAvatarUrl, String? authorAvatarPath, String quality, String? description, List<String> tags, ApiDownloadStatus status, double progress, BigInt downloadedBytes, BigInt totalBytes, BigInt speed, PlatformInt64 createdAt, String? filePath, String? folderId, String? errorKind, int retryCount, int maxRetries, PlatformInt64? nextRetryAt
+});
+
+
+@override $ApiDownloadStatusCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class __$ApiDownloadTaskCopyWithImpl<$Res>
+    implements _$ApiDownloadTaskCopyWith<$Res> {
+  __$ApiDownloadTaskCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadTask _self;
+  final $Res Function(_ApiDownloadTask) _then;
+
+/// Create a copy of ApiDownloadTask
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? coverPath = freezed,Object? authorId = freezed,Object? authorName = freezed,Object? authorAvatarUrl = freezed,Object? authorAvatarPath = freezed,Object? quality = null,Object? description = freezed,Object? tags = null,Object? status = null,Object? progress = null,Object? downloadedBytes = null,Object? totalBytes = null,Object? speed = null,Object? createdAt = null,Object? filePath = freezed,Object? folderId = freezed,Object? errorKind = freezed,Object? retryCount = null,Object? maxRetries = null,Object? nextRetryAt = freezed,}) {
+  return _then(_ApiDownloadTask(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,coverPath: freezed == coverPath ? _self.coverPath : coverPath // ignore: cast_nullable_to_non_nullable
+as String?,authorId: freezed == authorId ? _self.authorId : authorId // ignore: cast_nullable_to_non_nullable
+as String?,authorName: freezed == authorName ? _self.authorName : authorName // ignore: cast_nullable_to_non_nullable
+as String?,authorAvatarUrl: freezed == authorAvatarUrl ? _self.authorAvatarUrl : authorAvatarUrl // ignore: cast_nullable_to_non_nullable
+as String?,authorAvatarPath: freezed == authorAvatarPath ? _self.authorAvatarPath : authorAvatarPath // ignore: cast_nullable_to_non_nullable
+as String?,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String,description: freezed == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
+as String?,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as ApiDownloadStatus,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,downloadedBytes: null == downloadedBytes ? _self.downloadedBytes : downloadedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,speed: null == speed ? _self.speed : speed // ignore: cast_nullable_to_non_nullable
+as BigInt,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
+as String?,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
+as String?,errorKind: freezed == errorKind ? _self.errorKind : errorKind // ignore: cast_nullable_to_non_nullable
+as String?,retryCount: null == retryCount ? _self.retryCount : retryCount // ignore: cast_nullable_to_non_nullable
+as int,maxRetries: null == maxRetries ? _self.maxRetries : maxRetries // ignore: cast_nullable_to_non_nullable
+as int,nextRetryAt: freezed == nextRetryAt ? _self.nextRetryAt : nextRetryAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+/// Create a copy of ApiDownloadTask
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiDownloadStatusCopyWith<$Res> get status {
+  
+  return $ApiDownloadStatusCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+/// @nodoc
+mixin _$ApiDownloadVariant {
+
+ PlatformInt64 get id; String get videoId; String get quality; String? get sourceQuality; ApiDownloadStatus get status; double get progress; BigInt get downloadedBytes; BigInt get totalBytes; String? get filePath; bool get replacePrimary; bool get keepPrevious; String? get errorMessage; PlatformInt64 get createdAt; PlatformInt64? get completedAt;
+/// Create a copy of ApiDownloadVariant
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiDownloadVariantCopyWith<ApiDownloadVariant> get copyWith => _$ApiDownloadVariantCopyWithImpl<ApiDownloadVariant>(this as ApiDownloadVariant, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadVariant&&(identical(other.id, id) || other.id == id)&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.sourceQuality, sourceQuality) || other.sourceQuality == sourceQuality)&&(identical(other.status, status) || other.status == status)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.downloadedBytes, downloadedBytes) || other.downloadedBytes == downloadedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.filePath, filePath) || other.filePath == filePath)&&(identical(other.replacePrimary, replacePrimary) || other.replacePrimary == replacePrimary)&&(identical(other.keepPrevious, keepPrevious) || other.keepPrevious == keepPrevious)&&(identical(other.errorMessage, errorMessage) || other.errorMessage == errorMessage)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.completedAt, completedAt) || other.completedAt == completedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,videoId,quality,sourceQuality,status,progress,downloadedBytes,totalBytes,filePath,replacePrimary,keepPrevious,errorMessage,createdAt,completedAt);
+
+@override
+String toString() {
+  return 'ApiDownloadVariant(id: $id, videoId: $videoId, quality: $quality, sourceQuality: $sourceQuality, status: $status, progress: $progress, downloadedBytes: $downloadedBytes, totalBytes: $totalBytes, filePath: $filePath, replacePrimary: $replacePrimary, keepPrevious: $keepPrevious, errorMessage: $errorMessage, createdAt: $createdAt, completedAt: $completedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiDownloadVariantCopyWith<$Res>  {
+  factory $ApiDownloadVariantCopyWith(ApiDownloadVariant value, $Res Function(ApiDownloadVariant) _then) = _$ApiDownloadVariantCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 id, String videoId, String quality, String? sourceQuality, ApiDownloadStatus status, double progress, BigInt downloadedBytes, BigInt totalBytes, String? filePath, bool replacePrimary, bool keepPrevious, String? errorMessage, PlatformInt64 createdAt, PlatformInt64? completedAt
+});
+
+
+$ApiDownloadStatusCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class _$ApiDownloadVariantCopyWithImpl<$Res>
+    implements $ApiDownloadVariantCopyWith<$Res> {
+  _$ApiDownloadVariantCopyWithImpl(this._self, this._then);
+
+  final ApiDownloadVariant _self;
+  final $Res Function(ApiDownloadVariant) _then;
+
+/// Create a copy of ApiDownloadVariant
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? videoId = null,Object? quality = null,Object? sourceQuality = freezed,Object? status = null,Object? progress = null,Object? downloadedBytes = null,Object? totalBytes = null,Object? filePath = freezed,Object? replacePrimary = null,Object? keepPrevious = null,Object? errorMessage = freezed,Object? createdAt = null,Object? completedAt = freezed,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String,sourceQuality: freezed == sourceQuality ? _self.sourceQuality : sourceQuality // ignore: cast_nullable_to_non_nullable
+as String?,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as ApiDownloadStatus,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,downloadedBytes: null == downloadedBytes ? _self.downloadedBytes : downloadedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
+as String?,replacePrimary: null == replacePrimary ? _self.replacePrimary : replacePrimary // ignore: cast_nullable_to_non_nullable
+as bool,keepPrevious: null == keepPrevious ? _self.keepPrevious : keepPrevious // ignore: cast_nullable_to_non_nullable
+as bool,errorMessage: freezed == errorMessage ? _self.errorMessage : errorMessage // ignore: cast_nullable_to_non_nullable
+as String?,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,completedAt: freezed == completedAt ? _self.completedAt : completedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+/// Create a copy of ApiDownloadVariant
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiDownloadStatusCopyWith<$Res> get status {
+  
+  return $ApiDownloadStatusCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [ApiDownloadVariant].
+extension ApiDownloadVariantPatterns on ApiDownloadVariant {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadVariant value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiDownloadVariant() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadVariant value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadVariant():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadVariant value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiDownloadVariant() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( PlatformInt64 id,  String videoId,  String quality,  String? sourceQuality,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  String? filePath,  bool replacePrimary,  bool keepPrevious,  String? errorMessage,  PlatformInt64 createdAt,  PlatformInt64? completedAt)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiDownloadVariant() when $default != null:
+return $default(_that.id,_that.videoId,_that.quality,_that.sourceQuality,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.filePath,_that.replacePrimary,_that.keepPrevious,_that.errorMessage,_that.createdAt,_that.completedAt);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( PlatformInt64 id,  String videoId,  String quality,  String? sourceQuality,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  String? filePath,  bool replacePrimary,  bool keepPrevious,  String? errorMessage,  PlatformInt64 createdAt,  PlatformInt64? completedAt)  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadVariant():
+return $default(_that.id,_that.videoId,_that.quality,_that.sourceQuality,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.filePath,_that.replacePrimary,_that.keepPrevious,_that.errorMessage,_that.createdAt,_that.completedAt);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( PlatformInt64 id,  String videoId,  String quality,  String? sourceQuality,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  String? filePath,  bool replacePrimary,  bool keepPrevious,  String? errorMessage,  PlatformInt64 createdAt,  PlatformInt64? completedAt)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiDownloadVariant() when $default != null:
+return $default(_that.id,_that.videoId,_that.quality,_that.sourceQuality,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.filePath,_that.replacePrimary,_that.keepPrevious,_that.errorMessage,_that.createdAt,_that.completedAt);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiDownloadVariant implements ApiDownloadVariant {
+  const _ApiDownloadVariant({required this.id, required this.videoId, required this.quality, this.sourceQuality, required this.status, required this.progress, required this.downloadedBytes, required this.totalBytes, this.filePath, required this.replacePrimary, required this.keepPrevious, this.errorMessage, required this.createdAt, this.completedAt});
+  
+
+@override final  PlatformInt64 id;
+@override final  String videoId;
+@override final  String quality;
+@override final  String? sourceQuality;
+@override final  ApiDownloadStatus status;
+@override final  double progress;
+@override final  BigInt downloadedBytes;
+@override final  BigInt totalBytes;
+@override final  String? filePath;
+@override final  bool replacePrimary;
+@override final  bool keepPrevious;
+@override final  String? errorMessage;
+@override final  PlatformInt64 createdAt;
+@override final  PlatformInt64? completedAt;
+
+/// Create a copy of ApiDownloadVariant
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiDownloadVariantCopyWith<_ApiDownloadVariant> get copyWith => __$ApiDownloadVariantCopyWithImpl<_ApiDownloadVariant>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadVariant&&(identical(other.id, id) || other.id == id)&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.sourceQuality, sourceQuality) || other.sourceQuality == sourceQuality)&&(identical(other.status, status) || other.status == status)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.downloadedBytes, downloadedBytes) || other.downloadedBytes == downloadedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.filePath, filePath) || other.filePath == filePath)&&(identical(other.replacePrimary, replacePrimary) || other.replacePrimary == replacePrimary)&&(identical(other.keepPrevious, keepPrevious) || other.keepPrevious == keepPrevious)&&(identical(other.errorMessage, errorMessage) || other.errorMessage == errorMessage)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.completedAt, completedAt) || other.completedAt == completedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,videoId,quality,sourceQuality,status,progress,downloadedBytes,totalBytes,filePath,replacePrimary,keepPrevious,errorMessage,createdAt,completedAt);
+
+@override
+String toString() {
+  return 'ApiDownloadVariant(id: $id, videoId: $videoId, quality: $quality, sourceQuality: $sourceQuality, status: $status, progress: $progress, downloadedBytes: $downloadedBytes, totalBytes: $totalBytes, filePath: $filePath, replacePrimary: $replacePrimary, keepPrevious: $keepPrevious, errorMessage: $errorMessage, createdAt: $createdAt, completedAt: $completedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiDownloadVariantCopyWith<$Res> implements $ApiDownloadVariantCopyWith<$Res> {
+  factory _$ApiDownloadVariantCopyWith(_ApiDownloadVariant value, $Res Function(_ApiDownloadVariant) _then) = __$ApiDownloadVariantCopyWithImpl;
+@override @useResult
+$Res call({
+ PlatformInt64 id, String videoId, String quality, String? sourceQuality, ApiDownloadStatus status, double progress, BigInt downloadedBytes, BigInt totalBytes, String? filePath, bool replacePrimary, bool keepPrevious, String? errorMessage, PlatformInt64 createdAt, PlatformInt64? completedAt
+});
+
+
+@override $ApiDownloadStatusCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class __$ApiDownloadVariantCopyWithImpl<$Res>
+    implements _$ApiDownloadVariantCopyWith<$Res> {
+  __$ApiDownloadVariantCopyWithImpl(this._self, this._then);
+
+  final _ApiDownloadVariant _self;
+  final $Res Function(_ApiDownloadVariant) _then;
+
+/// Create a copy of ApiDownloadVariant
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? videoId = null,Object? quality = null,Object? sourceQuality = freezed,Object? status = null,Object? progress = null,Object? downloadedBytes = null,Object? totalBytes = null,Object? filePath = freezed,Object? replacePrimary = null,Object? keepPrevious = null,Object? errorMessage = freezed,Object? createdAt = null,Object? completedAt = freezed,}) {
+  return _then(_ApiDownloadVariant(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
+as String,sourceQuality: freezed == sourceQuality ? _self.sourceQuality : sourceQuality // ignore: cast_nullable_to_non_nullable
+as String?,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as ApiDownloadStatus,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,downloadedBytes: null == downloadedBytes ? _self.downloadedBytes : downloadedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
+as String?,replacePrimary: null == replacePrimary ? _self.replacePrimary : replacePrimary // ignore: cast_nullable_to_non_nullable
+as bool,keepPrevious: null == keepPrevious ? _self.keepPrevious : keepPrevious // ignore: cast_nullable_to_non_nullable
+as bool,errorMessage: freezed == errorMessage ? _self.errorMessage : errorMessage // ignore: cast_nullable_to_non_nullable
+as String?,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,completedAt: freezed == completedAt ? _self.completedAt : completedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+/// Create a copy of ApiDownloadVariant
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiDownloadStatusCopyWith<$Res> get status {
+  
+  return $ApiDownloadStatusCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+/// @nodoc
+mixin _$ApiExportOptions {
+
+ bool get mediaServerLayout; String get groupBy; String? get filenameTemplate; int? get maxNameBytes; bool get moveFiles;
+/// Create a copy of ApiExportOptions
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiExportOptionsCopyWith<ApiExportOptions> get copyWith => _$ApiExportOptionsCopyWithImpl<ApiExportOptions>(this as ApiExportOptions, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiExportOptions&&(identical(other.mediaServerLayout, mediaServerLayout) || other.mediaServerLayout == mediaServerLayout)&&(identical(other.groupBy, groupBy) || other.groupBy == groupBy)&&(identical(other.filenameTemplate, filenameTemplate) || other.filenameTemplate == filenameTemplate)&&(identical(other.maxNameBytes, maxNameBytes) || other.maxNameBytes == maxNameBytes)&&(identical(other.moveFiles, moveFiles) || other.moveFiles == moveFiles));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,mediaServerLayout,groupBy,filenameTemplate,maxNameBytes,moveFiles);
+
+@override
+String toString() {
+  return 'ApiExportOptions(mediaServerLayout: $mediaServerLayout, groupBy: $groupBy, filenameTemplate: $filenameTemplate, maxNameBytes: $maxNameBytes, moveFiles: $moveFiles)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiExportOptionsCopyWith<$Res>  {
+  factory $ApiExportOptionsCopyWith(ApiExportOptions value, $Res Function(ApiExportOptions) _then) = _$ApiExportOptionsCopyWithImpl;
+@useResult
+$Res call({
+ bool mediaServerLayout, String groupBy, String? filenameTemplate, int? maxNameBytes, bool moveFiles
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiExportOptionsCopyWithImpl<$Res>
+    implements $ApiExportOptionsCopyWith<$Res> {
+  _$ApiExportOptionsCopyWithImpl(this._self, this._then);
+
+  final ApiExportOptions _self;
+  final $Res Function(ApiExportOptions) _then;
+
+/// Create a copy of ApiExportOptions
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? mediaServerLayout = null,Object? groupBy = null,Object? filenameTemplate = freezed,Object? maxNameBytes = freezed,Object? moveFiles = null,}) {
+  return _then(_self.copyWith(
+mediaServerLayout: null == mediaServerLayout ? _self.mediaServerLayout : mediaServerLayout // ignore: cast_nullable_to_non_nullable
+as bool,groupBy: null == groupBy ? _self.groupBy : groupBy // ignore: cast_nullable_to_non_nullable
+as String,filenameTemplate: freezed == filenameTemplate ? _self.filenameTemplate : filenameTemplate // ignore: cast_nullable_to_non_nullable
+as String?,maxNameBytes: freezed == maxNameBytes ? _self.maxNameBytes : maxNameBytes // ignore: cast_nullable_to_non_nullable
+as int?,moveFiles: null == moveFiles ? _self.moveFiles : moveFiles // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiExportOptions].
+extension ApiExportOptionsPatterns on ApiExportOptions {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiExportOptions value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiExportOptions() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiExportOptions value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiExportOptions():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiExportOptions value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiExportOptions() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool mediaServerLayout,  String groupBy,  String? filenameTemplate,  int? maxNameBytes,  bool moveFiles)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiExportOptions() when $default != null:
+return $default(_that.mediaServerLayout,_that.groupBy,_that.filenameTemplate,_that.maxNameBytes,_that.moveFiles);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool mediaServerLayout,  String groupBy,  String? filenameTemplate,  int? maxNameBytes,  bool moveFiles)  $default,) {final _that = this;
+switch (_that) {
+case _ApiExportOptions():
+return $default(_that.mediaServerLayout,_that.groupBy,_that.filenameTemplate,_that.maxNameBytes,_that.moveFiles);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool mediaServerLayout,  String groupBy,  String? filenameTemplate,  int? maxNameBytes,  bool moveFiles)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiExportOptions() when $default != null:
+return $default(_that.mediaServerLayout,_that.groupBy,_that.filenameTemplate,_that.maxNameBytes,_that.moveFiles);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiExportOptions extends ApiExportOptions {
+  const _ApiExportOptions({required this.mediaServerLayout, required this.groupBy, this.filenameTemplate, this.maxNameBytes, required this.moveFiles}): super._();
+  
+
+@override final  bool mediaServerLayout;
+@override final  String groupBy;
+@override final  String? filenameTemplate;
+@override final  int? maxNameBytes;
+@override final  bool moveFiles;
+
+/// Create a copy of ApiExportOptions
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiExportOptionsCopyWith<_ApiExportOptions> get copyWith => __$ApiExportOptionsCopyWithImpl<_ApiExportOptions>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiExportOptions&&(identical(other.mediaServerLayout, mediaServerLayout) || other.mediaServerLayout == mediaServerLayout)&&(identical(other.groupBy, groupBy) || other.groupBy == groupBy)&&(identical(other.filenameTemplate, filenameTemplate) || other.filenameTemplate == filenameTemplate)&&(identical(other.maxNameBytes, maxNameBytes) || other.maxNameBytes == maxNameBytes)&&(identical(other.moveFiles, moveFiles) || other.moveFiles == moveFiles));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,mediaServerLayout,groupBy,filenameTemplate,maxNameBytes,moveFiles);
+
+@override
+String toString() {
+  return 'ApiExportOptions(mediaServerLayout: $mediaServerLayout, groupBy: $groupBy, filenameTemplate: $filenameTemplate, maxNameBytes: $maxNameBytes, moveFiles: $moveFiles)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiExportOptionsCopyWith<$Res> implements $ApiExportOptionsCopyWith<$Res> {
+  factory _$ApiExportOptionsCopyWith(_ApiExportOptions value, $Res Function(_ApiExportOptions) _then) = __$ApiExportOptionsCopyWithImpl;
+@override @useResult
+$Res call({
+ bool mediaServerLayout, String groupBy, String? filenameTemplate, int? maxNameBytes, bool moveFiles
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiExportOptionsCopyWithImpl<$Res>
+    implements _$ApiExportOptionsCopyWith<$Res> {
+  __$ApiExportOptionsCopyWithImpl(this._self, this._then);
+
+  final _ApiExportOptions _self;
+  final $Res Function(_ApiExportOptions) _then;
+
+/// Create a copy of ApiExportOptions
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? mediaServerLayout = null,Object? groupBy = null,Object? filenameTemplate = freezed,Object? maxNameBytes = freezed,Object? moveFiles = null,}) {
+  return _then(_ApiExportOptions(
+mediaServerLayout: null == mediaServerLayout ? _self.mediaServerLayout : mediaServerLayout // ignore: cast_nullable_to_non_nullable
+as bool,groupBy: null == groupBy ? _self.groupBy : groupBy // ignore: cast_nullable_to_non_nullable
+as String,filenameTemplate: freezed == filenameTemplate ? _self.filenameTemplate : filenameTemplate // ignore: cast_nullable_to_non_nullable
+as String?,maxNameBytes: freezed == maxNameBytes ? _self.maxNameBytes : maxNameBytes // ignore: cast_nullable_to_non_nullable
+as int?,moveFiles: null == moveFiles ? _self.moveFiles : moveFiles // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiExportProgress {
+
+ String get jobId; int get totalFiles; int get doneFiles; int get skippedFiles; String? get currentFile; BigInt get currentBytes; BigInt get currentTotalBytes; bool get done; String? get error;
+/// Create a copy of ApiExportProgress
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiExportProgressCopyWith<ApiExportProgress> get copyWith => _$ApiExportProgressCopyWithImpl<ApiExportProgress>(this as ApiExportProgress, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiExportProgress&&(identical(other.jobId, jobId) || other.jobId == jobId)&&(identical(other.totalFiles, totalFiles) || other.totalFiles == totalFiles)&&(identical(other.doneFiles, doneFiles) || other.doneFiles == doneFiles)&&(identical(other.skippedFiles, skippedFiles) || other.skippedFiles == skippedFiles)&&(identical(other.currentFile, currentFile) || other.currentFile == currentFile)&&(identical(other.currentBytes, currentBytes) || other.currentBytes == currentBytes)&&(identical(other.currentTotalBytes, currentTotalBytes) || other.currentTotalBytes == currentTotalBytes)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,jobId,totalFiles,doneFiles,skippedFiles,currentFile,currentBytes,currentTotalBytes,done,error);
+
+@override
+String toString() {
+  return 'ApiExportProgress(jobId: $jobId, totalFiles: $totalFiles, doneFiles: $doneFiles, skippedFiles: $skippedFiles, currentFile: $currentFile, currentBytes: $currentBytes, currentTotalBytes: $currentTotalBytes, done: $done, error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiExportProgressCopyWith<$Res>  {
+  factory $ApiExportProgressCopyWith(ApiExportProgress value, $Res Function(ApiExportProgress) _then) = _$ApiExportProgressCopyWithImpl;
+@useResult
+$Res call({
+ String jobId, int totalFiles, int doneFiles, int skippedFiles, String? currentFile, BigInt currentBytes, BigInt currentTotalBytes, bool done, String? error
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiExportProgressCopyWithImpl<$Res>
+    implements $ApiExportProgressCopyWith<$Res> {
+  _$ApiExportProgressCopyWithImpl(this._self, this._then);
+
+  final ApiExportProgress _self;
+  final $Res Function(ApiExportProgress) _then;
+
+/// Create a copy of ApiExportProgress
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? jobId = null,Object? totalFiles = null,Object? doneFiles = null,Object? skippedFiles = null,Object? currentFile = freezed,Object? currentBytes = null,Object? currentTotalBytes = null,Object? done = null,Object? error = freezed,}) {
+  return _then(_self.copyWith(
+jobId: null == jobId ? _self.jobId : jobId // ignore: cast_nullable_to_non_nullable
+as String,totalFiles: null == totalFiles ? _self.totalFiles : totalFiles // ignore: cast_nullable_to_non_nullable
+as int,doneFiles: null == doneFiles ? _self.doneFiles : doneFiles // ignore: cast_nullable_to_non_nullable
+as int,skippedFiles: null == skippedFiles ? _self.skippedFiles : skippedFiles // ignore: cast_nullable_to_non_nullable
+as int,currentFile: freezed == currentFile ? _self.currentFile : currentFile // ignore: cast_nullable_to_non_nullable
+as String?,currentBytes: null == currentBytes ? _self.currentBytes : currentBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,currentTotalBytes: null == currentTotalBytes ? _self.currentTotalBytes : currentTotalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
+as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiExportProgress].
+extension ApiExportProgressPatterns on ApiExportProgress {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiExportProgress value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiExportProgress() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiExportProgress value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiExportProgress():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiExportProgress value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiExportProgress() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String jobId,  int totalFiles,  int doneFiles,  int skippedFiles,  String? currentFile,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiExportProgress() when $default != null:
+return $default(_that.jobId,_that.totalFiles,_that.doneFiles,_that.skippedFiles,_that.currentFile,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String jobId,  int totalFiles,  int doneFiles,  int skippedFiles,  String? currentFile,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)  $default,) {final _that = this;
+switch (_that) {
+case _ApiExportProgress():
+return $default(_that.jobId,_that.totalFiles,_that.doneFiles,_that.skippedFiles,_that.currentFile,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String jobId,  int totalFiles,  int doneFiles,  int skippedFiles,  String? currentFile,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiExportProgress() when $default != null:
+return $default(_that.jobId,_that.totalFiles,_that.doneFiles,_that.skippedFiles,_that.currentFile,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiExportProgress implements ApiExportProgress {
+  const _ApiExportProgress({required this.jobId, required this.totalFiles, required this.doneFiles, required this.skippedFiles, this.currentFile, required this.currentBytes, required this.currentTotalBytes, required this.done, this.error});
+  
+
+@override final  String jobId;
+@override final  int totalFiles;
+@override final  int doneFiles;
+@override final  int skippedFiles;
+@override final  String? currentFile;
+@override final  BigInt currentBytes;
+@override final  BigInt currentTotalBytes;
+@override final  bool done;
+@override final  String? error;
+
+/// Create a copy of ApiExportProgress
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiExportProgressCopyWith<_ApiExportProgress> get copyWith => __$ApiExportProgressCopyWithImpl<_ApiExportProgress>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiExportProgress&&(identical(other.jobId, jobId) || other.jobId == jobId)&&(identical(other.totalFiles, totalFiles) || other.totalFiles == totalFiles)&&(identical(other.doneFiles, doneFiles) || other.doneFiles == doneFiles)&&(identical(other.skippedFiles, skippedFiles) || other.skippedFiles == skippedFiles)&&(identical(other.currentFile, currentFile) || other.currentFile == currentFile)&&(identical(other.currentBytes, currentBytes) || other.currentBytes == currentBytes)&&(identical(other.currentTotalBytes, currentTotalBytes) || other.currentTotalBytes == currentTotalBytes)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,jobId,totalFiles,doneFiles,skippedFiles,currentFile,currentBytes,currentTotalBytes,done,error);
+
+@override
+String toString() {
+  return 'ApiExportProgress(jobId: $jobId, totalFiles: $totalFiles, doneFiles: $doneFiles, skippedFiles: $skippedFiles, currentFile: $currentFile, currentBytes: $currentBytes, currentTotalBytes: $currentTotalBytes, done: $done, error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiExportProgressCopyWith<$Res> implements $ApiExportProgressCopyWith<$Res> {
+  factory _$ApiExportProgressCopyWith(_ApiExportProgress value, $Res Function(_ApiExportProgress) _then) = __$ApiExportProgressCopyWithImpl;
+@override @useResult
+$Res call({
+ String jobId, int totalFiles, int doneFiles, int skippedFiles, String? currentFile, BigInt currentBytes, BigInt currentTotalBytes, bool done, String? error
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiExportProgressCopyWithImpl<$Res>
+    implements _$ApiExportProgressCopyWith<$Res> {
+  __$ApiExportProgressCopyWithImpl(this._self, this._then);
+
+  final _ApiExportProgress _self;
+  final $Res Function(_ApiExportProgress) _then;
+
+/// Create a copy of ApiExportProgress
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? jobId = null,Object? totalFiles = null,Object? doneFiles = null,Object? skippedFiles = null,Object? currentFile = freezed,Object? currentBytes = null,Object? currentTotalBytes = null,Object? done = null,Object? error = freezed,}) {
+  return _then(_ApiExportProgress(
+jobId: null == jobId ? _self.jobId : jobId // ignore: cast_nullable_to_non_nullable
+as String,totalFiles: null == totalFiles ? _self.totalFiles : totalFiles // ignore: cast_nullable_to_non_nullable
+as int,doneFiles: null == doneFiles ? _self.doneFiles : doneFiles // ignore: cast_nullable_to_non_nullable
+as int,skippedFiles: null == skippedFiles ? _self.skippedFiles : skippedFiles // ignore: cast_nullable_to_non_nullable
+as int,currentFile: freezed == currentFile ? _self.currentFile : currentFile // ignore: cast_nullable_to_non_nullable
+as String?,currentBytes: null == currentBytes ? _self.currentBytes : currentBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,currentTotalBytes: null == currentTotalBytes ? _self.currentTotalBytes : currentTotalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
+as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiFavoriteList {
+
+ List<ApiVideoCard> get videos; int get total; int get page; bool get hasNext;
+/// Create a copy of ApiFavoriteList
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiFavoriteListCopyWith<ApiFavoriteList> get copyWith => _$ApiFavoriteListCopyWithImpl<ApiFavoriteList>(this as ApiFavoriteList, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiFavoriteList&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(videos),total,page,hasNext);
+
+@override
+String toString() {
+  return 'ApiFavoriteList(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiFavoriteListCopyWith<$Res>  {
+  factory $ApiFavoriteListCopyWith(ApiFavoriteList value, $Res Function(ApiFavoriteList) _then) = _$ApiFavoriteListCopyWithImpl;
+@useResult
+$Res call({
+ List<ApiVideoCard> videos, int total, int page, bool hasNext
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiFavoriteListCopyWithImpl<$Res>
+    implements $ApiFavoriteListCopyWith<$Res> {
+  _$ApiFavoriteListCopyWithImpl(this._self, this._then);
+
+  final ApiFavoriteList _self;
+  final $Res Function(ApiFavoriteList) _then;
+
+/// Create a copy of ApiFavoriteList
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_self.copyWith(
+videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiFavoriteList].
+extension ApiFavoriteListPatterns on ApiFavoriteList {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiFavoriteList value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiFavoriteList() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiFavoriteList value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiFavoriteList():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiFavoriteList value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiFavoriteList() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiFavoriteList() when $default != null:
+return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
+switch (_that) {
+case _ApiFavoriteList():
+return $default(_that.videos,_that.total,_that.page,_that.hasNext);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiFavoriteList() when $default != null:
+return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiFavoriteList implements ApiFavoriteList {
+  const _ApiFavoriteList({required final  List<ApiVideoCard> videos, required this.total, required this.page, required this.hasNext}): _videos = videos;
+  
+
+ final  List<ApiVideoCard> _videos;
+@override List<ApiVideoCard> get videos {
+  if (_videos is EqualUnmodifiableListView) return _videos;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_videos);
+}
+
+@override final  int total;
+@override final  int page;
+@override final  bool hasNext;
+
+/// Create a copy of ApiFavoriteList
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiFavoriteListCopyWith<_ApiFavoriteList> get copyWith => __$ApiFavoriteListCopyWithImpl<_ApiFavoriteList>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiFavoriteList&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_videos),total,page,hasNext);
+
+@override
+String toString() {
+  return 'ApiFavoriteList(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiFavoriteListCopyWith<$Res> implements $ApiFavoriteListCopyWith<$Res> {
+  factory _$ApiFavoriteListCopyWith(_ApiFavoriteList value, $Res Function(_ApiFavoriteList) _then) = __$ApiFavoriteListCopyWithImpl;
+@override @useResult
+$Res call({
+ List<ApiVideoCard> videos, int total, int page, bool hasNext
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiFavoriteListCopyWithImpl<$Res>
+    implements _$ApiFavoriteListCopyWith<$Res> {
+  __$ApiFavoriteListCopyWithImpl(this._self, this._then);
+
+  final _ApiFavoriteList _self;
+  final $Res Function(_ApiFavoriteList) _then;
+
+/// Create a copy of ApiFavoriteList
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_ApiFavoriteList(
+videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiFilterOption {
+
+ String get value; String get label;
+/// Create a copy of ApiFilterOption
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiFilterOptionCopyWith<ApiFilterOption> get copyWith => _$ApiFilterOptionCopyWithImpl<ApiFilterOption>(this as ApiFilterOption, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiFilterOption&&(identical(other.value, value) || other.value == value)&&(identical(other.label, label) || other.label == label));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,value,label);
+
+@override
+String toString() {
+  return 'ApiFilterOption(value: $value, label: $label)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiFilterOptionCopyWith<$Res>  {
+  factory $ApiFilterOptionCopyWith(ApiFilterOption value, $Res Function(ApiFilterOption) _then) = _$ApiFilterOptionCopyWithImpl;
+@useResult
+$Res call({
+ String value, String label
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiFilterOptionCopyWithImpl<$Res>
+    implements $ApiFilterOptionCopyWith<$Res> {
+  _$ApiFilterOptionCopyWithImpl(this._self, this._then);
+
+  final ApiFilterOption _self;
+  final $Res Function(ApiFilterOption) _then;
+
+/// Create a copy of ApiFilterOption
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? value = null,Object? label = null,}) {
+  return _then(_self.copyWith(
+value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiFilterOption].
+extension ApiFilterOptionPatterns on ApiFilterOption {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiFilterOption value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiFilterOption() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiFilterOption value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiFilterOption():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiFilterOption value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiFilterOption() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String value,  String label)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiFilterOption() when $default != null:
+return $default(_that.value,_that.label);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String value,  String label)  $default,) {final _that = this;
+switch (_that) {
+case _ApiFilterOption():
+return $default(_that.value,_that.label);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String value,  String label)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiFilterOption() when $default != null:
+return $default(_that.value,_that.label);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiFilterOption implements ApiFilterOption {
+  const _ApiFilterOption({required this.value, required this.label});
+  
+
+@override final  String value;
+@override final  String label;
+
+/// Create a copy of ApiFilterOption
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiFilterOptionCopyWith<_ApiFilterOption> get copyWith => __$ApiFilterOptionCopyWithImpl<_ApiFilterOption>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiFilterOption&&(identical(other.value, value) || other.value == value)&&(identical(other.label, label) || other.label == label));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,value,label);
+
+@override
+String toString() {
+  return 'ApiFilterOption(value: $value, label: $label)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiFilterOptionCopyWith<$Res> implements $ApiFilterOptionCopyWith<$Res> {
+  factory _$ApiFilterOptionCopyWith(_ApiFilterOption value, $Res Function(_ApiFilterOption) _then) = __$ApiFilterOptionCopyWithImpl;
+@override @useResult
+$Res call({
+ String value, String label
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiFilterOptionCopyWithImpl<$Res>
+    implements _$ApiFilterOptionCopyWith<$Res> {
+  __$ApiFilterOptionCopyWithImpl(this._self, this._then);
+
+  final _ApiFilterOption _self;
+  final $Res Function(_ApiFilterOption) _then;
+
+/// Create a copy of ApiFilterOption
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? value = null,Object? label = null,}) {
+  return _then(_ApiFilterOption(
+value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiFilterOptions {
+
+ List<ApiFilterOption> get genres; List<ApiTagGroup> get tags; List<ApiFilterOption> get sorts; List<ApiFilterOption> get years; List<ApiFilterOption> get durations;
+/// Create a copy of ApiFilterOptions
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiFilterOptionsCopyWith<ApiFilterOptions> get copyWith => _$ApiFilterOptionsCopyWithImpl<ApiFilterOptions>(this as ApiFilterOptions, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiFilterOptions&&const DeepCollectionEquality().equals(other.genres, genres)&&const DeepCollectionEquality().equals(other.tags, tags)&&const DeepCollectionEquality().equals(other.sorts, sorts)&&const DeepCollectionEquality().equals(other.years, years)&&const DeepCollectionEquality().equals(other.durations, durations));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(genres),const DeepCollectionEquality().hash(tags),const DeepCollectionEquality().hash(sorts),const DeepCollectionEquality().hash(years),const DeepCollectionEquality().hash(durations));
+
+@override
+String toString() {
+  return 'ApiFilterOptions(genres: $genres, tags: $tags, sorts: $sorts, years: $years, durations: $durations)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiFilterOptionsCopyWith<$Res>  {
+  factory $ApiFilterOptionsCopyWith(ApiFilterOptions value, $Res Function(ApiFilterOptions) _then) = _$ApiFilterOptionsCopyWithImpl;
+@useResult
+$Res call({
+ List<ApiFilterOption> genres, List<ApiTagGroup> tags, List<ApiFilterOption> sorts, List<ApiFilterOption> years, List<ApiFilterOption> durations
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiFilterOptionsCopyWithImpl<$Res>
+    implements $ApiFilterOptionsCopyWith<$Res> {
+  _$ApiFilterOptionsCopyWithImpl(this._self, this._then);
+
+  final ApiFilterOptions _self;
+  final $Res Function(ApiFilterOptions) _then;
+
+/// Create a copy of ApiFilterOptions
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? genres = null,Object? tags = null,Object? sorts = null,Object? years = null,Object? durations = null,}) {
+  return _then(_self.copyWith(
+genres: null == genres ? _self.genres : genres // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
+as List<ApiTagGroup>,sorts: null == sorts ? _self.sorts : sorts // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,years: null == years ? _self.years : years // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,durations: null == durations ? _self.durations : durations // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiFilterOptions].
+extension ApiFilterOptionsPatterns on ApiFilterOptions {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiFilterOptions value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiFilterOptions() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiFilterOptions value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiFilterOptions():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiFilterOptions value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiFilterOptions() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiFilterOption> genres,  List<ApiTagGroup> tags,  List<ApiFilterOption> sorts,  List<ApiFilterOption> years,  List<ApiFilterOption> durations)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiFilterOptions() when $default != null:
+return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiFilterOption> genres,  List<ApiTagGroup> tags,  List<ApiFilterOption> sorts,  List<ApiFilterOption> years,  List<ApiFilterOption> durations)  $default,) {final _that = this;
+switch (_that) {
+case _ApiFilterOptions():
+return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiFilterOption> genres,  List<ApiTagGroup> tags,  List<ApiFilterOption> sorts,  List<ApiFilterOption> years,  List<ApiFilterOption> durations)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiFilterOptions() when $default != null:
+return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiFilterOptions implements ApiFilterOptions {
+  const _ApiFilterOptions({required final  List<ApiFilterOption> genres, required final  List<ApiTagGroup> tags, required final  List<ApiFilterOption> sorts, required final  List<ApiFilterOption> years, required final  List<ApiFilterOption> durations}): _genres = genres,_tags = tags,_sorts = sorts,_years = years,_durations = durations;
+  
+
+ final  List<ApiFilterOption> _genres;
+@override List<ApiFilterOption> get genres {
+  if (_genres is EqualUnmodifiableListView) return _genres;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_genres);
+}
+
+ final  List<ApiTagGroup> _tags;
+@override List<ApiTagGroup> get tags {
+  if (_tags is EqualUnmodifiableListView) return _tags;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_tags);
+}
+
+ final  List<ApiFilterOption> _sorts;
+@override List<ApiFilterOption> get sorts {
+  if (_sorts is EqualUnmodifiableListView) return _sorts;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_sorts);
+}
+
+ final  List<ApiFilterOption> _years;
+@override List<ApiFilterOption> get years {
+  if (_years is EqualUnmodifiableListView) return _years;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_years);
+}
+
+ final  List<ApiFilterOption> _durations;
+@override List<ApiFilterOption> get durations {
+  if (_durations is EqualUnmodifiableListView) return _durations;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_durations);
+}
+
+
+/// Create a copy of ApiFilterOptions
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiFilterOptionsCopyWith<_ApiFilterOptions> get copyWith => __$ApiFilterOptionsCopyWithImpl<_ApiFilterOptions>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiFilterOptions&&const DeepCollectionEquality().equals(other._genres, _genres)&&const DeepCollectionEquality().equals(other._tags, _tags)&&const DeepCollectionEquality().equals(other._sorts, _sorts)&&const DeepCollectionEquality().equals(other._years, _years)&&const DeepCollectionEquality().equals(other._durations, _durations));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_genres),const DeepCollectionEquality().hash(_tags),const DeepCollectionEquality().hash(_sorts),const DeepCollectionEquality().hash(_years),const DeepCollectionEquality().hash(_durations));
+
+@override
+String toString() {
+  return 'ApiFilterOptions(genres: $genres, tags: $tags, sorts: $sorts, years: $years, durations: $durations)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiFilterOptionsCopyWith<$Res> implements $ApiFilterOptionsCopyWith<$Res> {
+  factory _$ApiFilterOptionsCopyWith(_ApiFilterOptions value, $Res Function(_ApiFilterOptions) _then) = __$ApiFilterOptionsCopyWithImpl;
+@override @useResult
+$Res call({
+ List<ApiFilterOption> genres, List<ApiTagGroup> tags, List<ApiFilterOption> sorts, List<ApiFilterOption> years, List<ApiFilterOption> durations
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiFilterOptionsCopyWithImpl<$Res>
+    implements _$ApiFilterOptionsCopyWith<$Res> {
+  __$ApiFilterOptionsCopyWithImpl(this._self, this._then);
+
+  final _ApiFilterOptions _self;
+  final $Res Function(_ApiFilterOptions) _then;
+
+/// Create a copy of ApiFilterOptions
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? genres = null,Object? tags = null,Object? sorts = null,Object? years = null,Object? durations = null,}) {
+  return _then(_ApiFilterOptions(
+genres: null == genres ? _self._genres : genres // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
+as List<ApiTagGroup>,sorts: null == sorts ? _self._sorts : sorts // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,years: null == years ? _self._years : years // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,durations: null == durations ? _self._durations : durations // ignore: cast_nullable_to_non_nullable
+as List<ApiFilterOption>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiHomePage {
+
+ String? get formToken; String? get avatarUrl; String? get username; ApiBanner? get banner; List<ApiVideoCard> get latestRelease; List<ApiVideoCard> get latestUpload; List<ApiHomeSection> get sections;
+/// Create a copy of ApiHomePage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiHomePageCopyWith<ApiHomePage> get copyWith => _$ApiHomePageCopyWithImpl<ApiHomePage>(this as ApiHomePage, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiHomePage&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.avatarUrl, avatarUrl) || other.avatarUrl == avatarUrl)&&(identical(other.username, username) || other.username == username)&&(identical(other.banner, banner) || other.banner == banner)&&const DeepCollectionEquality().equals(other.latestRelease, latestRelease)&&const DeepCollectionEquality().equals(other.latestUpload, latestUpload)&&const DeepCollectionEquality().equals(other.sections, sections));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,formToken,avatarUrl,username,banner,const DeepCollectionEquality().hash(latestRelease),const DeepCollectionEquality().hash(latestUpload),const DeepCollectionEquality().hash(sections));
+
+@override
+String toString() {
+  return 'ApiHomePage(formToken: $formToken, avatarUrl: $avatarUrl, username: $username, banner: $banner, latestRelease: $latestRelease, latestUpload: $latestUpload, sections: $sections)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiHomePageCopyWith<$Res>  {
+  factory $ApiHomePageCopyWith(ApiHomePage value, $Res Function(ApiHomePage) _then) = _$ApiHomePageCopyWithImpl;
+@useResult
+$Res call({
+ String? formToken, String? avatarUrl, String? username, ApiBanner? banner, List<ApiVideoCard> latestRelease, List<ApiVideoCard> latestUpload, List<ApiHomeSection> sections
+});
+
+
+$ApiBannerCopyWith<$Res>? get banner;
+
+}
+/// @nodoc
+class _$ApiHomePageCopyWithImpl<$Res>
+    implements $ApiHomePageCopyWith<$Res> {
+  _$ApiHomePageCopyWithImpl(this._self, this._then);
+
+  final ApiHomePage _self;
+  final $Res Function(ApiHomePage) _then;
+
+/// Create a copy of ApiHomePage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? formToken = freezed,Object? avatarUrl = freezed,Object? username = freezed,Object? banner = freezed,Object? latestRelease = null,Object? latestUpload = null,Object? sections = null,}) {
+  return _then(_self.copyWith(
+formToken: freezed == formToken ? _self.formToken : formToken // ignore: cast_nullable_to_non_nullable
+as String?,avatarUrl: freezed == avatarUrl ? _self.avatarUrl : avatarUrl // ignore: cast_nullable_to_non_nullable
+as String?,username: freezed == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String?,banner: freezed == banner ? _self.banner : banner // ignore: cast_nullable_to_non_nullable
+as ApiBanner?,latestRelease: null == latestRelease ? _self.latestRelease : latestRelease // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,latestUpload: null == latestUpload ? _self.latestUpload : latestUpload // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,sections: null == sections ? _self.sections : sections // ignore: cast_nullable_to_non_nullable
+as List<ApiHomeSection>,
+  ));
+}
+/// Create a copy of ApiHomePage
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiBannerCopyWith<$Res>? get banner {
+    if (_self.banner == null) {
+    return null;
+  }
+
+  return $ApiBannerCopyWith<$Res>(_self.banner!, (value) {
+    return _then(_self.copyWith(banner: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [ApiHomePage].
+extension ApiHomePagePatterns on ApiHomePage {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiHomePage value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiHomePage() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiHomePage value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiHomePage():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiHomePage value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiHomePage() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? formToken,  String? avatarUrl,  String? username,  ApiBanner? banner,  List<ApiVideoCard> latestRelease,  List<ApiVideoCard> latestUpload,  List<ApiHomeSection> sections)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiHomePage() when $default != null:
+return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_that.latestRelease,_that.latestUpload,_that.sections);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? formToken,  String? avatarUrl,  String? username,  ApiBanner? banner,  List<ApiVideoCard> latestRelease,  List<ApiVideoCard> latestUpload,  List<ApiHomeSection> sections)  $default,) {final _that = this;
+switch (_that) {
+case _ApiHomePage():
+return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_that.latestRelease,_that.latestUpload,_that.sections);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? formToken,  String? avatarUrl,  String? username,  ApiBanner? banner,  List<ApiVideoCard> latestRelease,  List<ApiVideoCard> latestUpload,  List<ApiHomeSection> sections)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiHomePage() when $default != null:
+return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_that.latestRelease,_that.latestUpload,_that.sections);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiHomePage implements ApiHomePage {
+  const _ApiHomePage({this.formToken, this.avatarUrl, this.username, this.banner, required final  List<ApiVideoCard> latestRelease, required final  List<ApiVideoCard> latestUpload, required final  List<ApiHomeSection> sections}): _latestRelease = latestRelease,_latestUpload = latestUpload,_sections = sections;
+  
+
+@override final  String? formToken;
+@override final  String? avatarUrl;
+@override final  String? username;
+@override final  ApiBanner? banner;
+ final  List<ApiVideoCard> _latestRelease;
+@override List<ApiVideoCard> get latestRelease {
+  if (_latestRelease is EqualUnmodifiableListView) return _latestRelease;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_latestRelease);
+}
+
+ final  List<ApiVideoCard> _latestUpload;
+@override List<ApiVideoCard> get latestUpload {
+  if (_latestUpload is EqualUnmodifiableListView) return _latestUpload;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_latestUpload);
+}
+
+ final  List<ApiHomeSection> _sections;
+@override List<ApiHomeSection> get sections {
+  if (_sections is EqualUnmodifiableListView) return _sections;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_sections);
+}
+
+
+/// Create a copy of ApiHomePage
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiHomePageCopyWith<_ApiHomePage> get copyWith => __$ApiHomePageCopyWithImpl<_ApiHomePage>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiHomePage&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.avatarUrl, avatarUrl) || other.avatarUrl == avatarUrl)&&(identical(other.username, username) || other.username == username)&&(identical(other.banner, banner) || other.banner == banner)&&const DeepCollectionEquality().equals(other._latestRelease, _latestRelease)&&const DeepCollectionEquality().equals(other._latestUpload, _latestUpload)&&const DeepCollectionEquality().equals(other._sections, _sections));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,formToken,avatarUrl,username,banner,const DeepCollectionEquality().hash(_latestRelease),const DeepCollectionEquality().hash(_latestUpload),const DeepCollectionEquality().hash(_sections));
+
+@override
+String toString() {
+  return 'ApiHomePage(formToken: $formToken, avatarUrl: $avatarUrl, username: $username, banner: $banner, latestRelease: $latestRelease, latestUpload: $latestUpload, sections: $sections)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiHomePageCopyWith<$Res> implements $ApiHomePageCopyWith<$Res> {
+  factory _$ApiHomePageCopyWith(_ApiHomePage value, $Res Function(_ApiHomePage) _then) = __$ApiHomePageCopyWithImpl;
+@override @useResult
+$Res call({
+ String? formToken, String? avatarUrl, String? username, ApiBanner? banner, List<ApiVideoCard> latestRelease, List<ApiVideoCard> latestUpload, List<ApiHomeSection> sections
+});
+
+
+@override $ApiBannerCopyWith<$Res>? get banner;
+
+}
+/// @nodoc
+class __$ApiHomePageCopyWithImpl<$Res>
+    implements _$ApiHomePageCopyWith<$Res> {
+  __$ApiHomePageCopyWithImpl(this._self, this._then);
+
+  final _ApiHomePage _self;
+  final $Res Function(_ApiHomePage) _then;
+
+/// Create a copy of ApiHomePage
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? formToken = freezed,Object? avatarUrl = freezed,Object? username = freezed,Object? banner = freezed,Object? latestRelease = null,Object? latestUpload = null,Object? sections = null,}) {
+  return _then(_ApiHomePage(
+formToken: freezed == formToken ? _self.formToken : formToken // ignore: cast_nullable_to_non_nullable
+as String?,avatarUrl: freezed == avatarUrl ? _self.avatarUrl : avatarUrl // ignore: cast_nullable_to_non_nullable
+as String?,username: freezed == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String?,banner: freezed == banner ? _self.banner : banner // ignore: cast_nullable_to_non_nullable
+as ApiBanner?,latestRelease: null == latestRelease ? _self._latestRelease : latestRelease // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,latestUpload: null == latestUpload ? _self._latestUpload : latestUpload // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,sections: null == sections ? _self._sections : sections // ignore: cast_nullable_to_non_nullable
+as List<ApiHomeSection>,
+  ));
+}
+
+/// Create a copy of ApiHomePage
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiBannerCopyWith<$Res>? get banner {
+    if (_self.banner == null) {
+    return null;
+  }
+
+  return $ApiBannerCopyWith<$Res>(_self.banner!, (value) {
+    return _then(_self.copyWith(banner: value));
+  });
+}
+}
+
+/// @nodoc
+mixin _$ApiHomeSection {
+
+ String get name; List<ApiVideoCard> get videos;
+/// Create a copy of ApiHomeSection
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiHomeSectionCopyWith<ApiHomeSection> get copyWith => _$ApiHomeSectionCopyWithImpl<ApiHomeSection>(this as ApiHomeSection, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiHomeSection&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other.videos, videos));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,const DeepCollectionEquality().hash(videos));
+
+@override
+String toString() {
+  return 'ApiHomeSection(name: $name, videos: $videos)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiHomeSectionCopyWith<$Res>  {
+  factory $ApiHomeSectionCopyWith(ApiHomeSection value, $Res Function(ApiHomeSection) _then) = _$ApiHomeSectionCopyWithImpl;
+@useResult
+$Res call({
+ String name, List<ApiVideoCard> videos
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiHomeSectionCopyWithImpl<$Res>
+    implements $ApiHomeSectionCopyWith<$Res> {
+  _$ApiHomeSectionCopyWithImpl(this._self, this._then);
+
+  final ApiHomeSection _self;
+  final $Res Function(ApiHomeSection) _then;
+
+/// Create a copy of ApiHomeSection
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? name = null,Object? videos = null,}) {
+  return _then(_self.copyWith(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiHomeSection].
+extension ApiHomeSectionPatterns on ApiHomeSection {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiHomeSection value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiHomeSection() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiHomeSection value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiHomeSection():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiHomeSection value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiHomeSection() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String name,  List<ApiVideoCard> videos)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiHomeSection() when $default != null:
+return $default(_that.name,_that.videos);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String name,  List<ApiVideoCard> videos)  $default,) {final _that = this;
+switch (_that) {
+case _ApiHomeSection():
+return $default(_that.name,_that.videos);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String name,  List<ApiVideoCard> videos)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiHomeSection() when $default != null:
+return $default(_that.name,_that.videos);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiHomeSection implements ApiHomeSection {
+  const _ApiHomeSection({required this.name, required final  List<ApiVideoCard> videos}): _videos = videos;
+  
+
+@override final  String name;
+ final  List<ApiVideoCard> _videos;
+@override List<ApiVideoCard> get videos {
+  if (_videos is EqualUnmodifiableListView) return _videos;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_videos);
+}
+
+
+/// Create a copy of ApiHomeSection
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiHomeSectionCopyWith<_ApiHomeSection> get copyWith => __$ApiHomeSectionCopyWithImpl<_ApiHomeSection>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiHomeSection&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other._videos, _videos));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,const DeepCollectionEquality().hash(_videos));
+
+@override
+String toString() {
+  return 'ApiHomeSection(name: $name, videos: $videos)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiHomeSectionCopyWith<$Res> implements $ApiHomeSectionCopyWith<$Res> {
+  factory _$ApiHomeSectionCopyWith(_ApiHomeSection value, $Res Function(_ApiHomeSection) _then) = __$ApiHomeSectionCopyWithImpl;
+@override @useResult
+$Res call({
+ String name, List<ApiVideoCard> videos
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiHomeSectionCopyWithImpl<$Res>
+    implements _$ApiHomeSectionCopyWith<$Res> {
+  __$ApiHomeSectionCopyWithImpl(this._self, this._then);
+
+  final _ApiHomeSection _self;
+  final $Res Function(_ApiHomeSection) _then;
+
+/// Create a copy of ApiHomeSection
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? name = null,Object? videos = null,}) {
+  return _then(_ApiHomeSection(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiMissingDownload {
+
+ String get videoId; String get title; ApiDownloadStatus get status; bool get missingMedia; bool get missingCover; bool get missingAvatar; BigInt get expectedBytes; List<String> get candidateFiles;
+/// Create a copy of ApiMissingDownload
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiMissingDownloadCopyWith<ApiMissingDownload> get copyWith => _$ApiMissingDownloadCopyWithImpl<ApiMissingDownload>(this as ApiMissingDownload, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiMissingDownload&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.status, status) || other.status == status)&&(identical(other.missingMedia, missingMedia) || other.missingMedia == missingMedia)&&(identical(other.missingCover, missingCover) || other.missingCover == missingCover)&&(identical(other.missingAvatar, missingAvatar) || other.missingAvatar == missingAvatar)&&(identical(other.expectedBytes, expectedBytes) || other.expectedBytes == expectedBytes)&&const DeepCollectionEquality().equals(other.candidateFiles, candidateFiles));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,videoId,title,status,missingMedia,missingCover,missingAvatar,expectedBytes,const DeepCollectionEquality().hash(candidateFiles));
+
+@override
+String toString() {
+  return 'ApiMissingDownload(videoId: $videoId, title: $title, status: $status, missingMedia: $missingMedia, missingCover: $missingCover, missingAvatar: $missingAvatar, expectedBytes: $expectedBytes, candidateFiles: $candidateFiles)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiMissingDownloadCopyWith<$Res>  {
+  factory $ApiMissingDownloadCopyWith(ApiMissingDownload value, $Res Function(ApiMissingDownload) _then) = _$ApiMissingDownloadCopyWithImpl;
+@useResult
+$Res call({
+ String videoId, String title, ApiDownloadStatus status, bool missingMedia, bool missingCover, bool missingAvatar, BigInt expectedBytes, List<String> candidateFiles
+});
+
+
+$ApiDownloadStatusCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class _$ApiMissingDownloadCopyWithImpl<$Res>
+    implements $ApiMissingDownloadCopyWith<$Res> {
+  _$ApiMissingDownloadCopyWithImpl(this._self, this._then);
+
+  final ApiMissingDownload _self;
+  final $Res Function(ApiMissingDownload) _then;
+
+/// Create a copy of ApiMissingDownload
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? status = null,Object? missingMedia = null,Object? missingCover = null,Object? missingAvatar = null,Object? expectedBytes = null,Object? candidateFiles = null,}) {
+  return _then(_self.copyWith(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as ApiDownloadStatus,missingMedia: null == missingMedia ? _self.missingMedia : missingMedia // ignore: cast_nullable_to_non_nullable
+as bool,missingCover: null == missingCover ? _self.missingCover : missingCover // ignore: cast_nullable_to_non_nullable
+as bool,missingAvatar: null == missingAvatar ? _self.missingAvatar : missingAvatar // ignore: cast_nullable_to_non_nullable
+as bool,expectedBytes: null == expectedBytes ? _self.expectedBytes : expectedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,candidateFiles: null == candidateFiles ? _self.candidateFiles : candidateFiles // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+/// Create a copy of ApiMissingDownload
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ApiDownloadStatusCopyWith<$Res> get status {
+  
+  return $ApiDownloadStatusCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [ApiMissingDownload].
+extension ApiMissingDownloadPatterns on ApiMissingDownload {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiMissingDownload value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiMissingDownload() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiMissingDownload value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiMissingDownload():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiMissingDownload value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiMissingDownload() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  ApiDownloadStatus status,  bool missingMedia,  bool missingCover,  bool missingAvatar,  BigInt expectedBytes,  List<String> candidateFiles)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiMissingDownload() when $default != null:
+return $default(_that.videoId,_that.title,_that.status,_that.missingMedia,_that.missingCover,_that.missingAvatar,_that.expectedBytes,_that.candidateFiles);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  ApiDownloadStatus status,  bool missingMedia,  bool missingCover,  bool missingAvatar,  BigInt expectedBytes,  List<String> candidateFiles)  $default,) {final _that = this;
+switch (_that) {
+case _ApiMissingDownload():
+return $default(_that.videoId,_that.title,_that.status,_that.missingMedia,_that.missingCover,_that.missingAvatar,_that.expectedBytes,_that.candidateFiles);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  ApiDownloadStatus status,  bool missingMedia,  bool missingCover,  bool missingAvatar,  BigInt expectedBytes,  List<String> candidateFiles)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiMissingDownload() when $default != null:
+return $default(_that.videoId,_that.title,_that.status,_that.missingMedia,_that.missingCover,_that.missingAvatar,_that.expectedBytes,_that.candidateFiles);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiMissingDownload implements ApiMissingDownload {
+  const _ApiMissingDownload({required this.videoId, required this.title, required this.status, required this.missingMedia, required this.missingCover, required this.missingAvatar, required this.expectedBytes, required final  List<String> candidateFiles}): _candidateFiles = candidateFiles;
+  
+
+@override final  String videoId;
+@override final  String title;
+@override final  ApiDownloadStatus status;
+@override final  bool missingMedia;
+@override final  bool missingCover;
+@override final  bool missingAvatar;
+@override final  BigInt expectedBytes;
+ final  List<String> _candidateFiles;
+@override List<String> get candidateFiles {
+  if (_candidateFiles is EqualUnmodifiableListView) return _candidateFiles;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_candidateFiles);
+}
+
+
+/// Create a copy of ApiMissingDownload
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiMissingDownloadCopyWith<_ApiMissingDownload> get copyWith => __$ApiMissingDownloadCopyWithImpl<_ApiMissingDownload>(this, _$identity);
+
+
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiDownloadTask&&(identical(other.id, id) || other.id == id)&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.coverPath, coverPath) || other.coverPath == coverPath)&&(identical(other.authorId, authorId) || other.authorId == authorId)&&(identical(other.authorName, authorName) || other.authorName == authorName)&&(identical(other.authorAvatarUrl, authorAvatarUrl) || other.authorAvatarUrl == authorAvatarUrl)&&(identical(other.authorAvatarPath, authorAvatarPath) || other.authorAvatarPath == authorAvatarPath)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.description, description) || other.description == description)&&const DeepCollectionEquality().equals(other.tags, tags)&&(identical(other.status, status) || other.status == status)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.downloadedBytes, downloadedBytes) || other.downloadedBytes == downloadedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.speed, speed) || other.speed == speed)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.filePath, filePath) || other.filePath == filePath)&&(identical(other.folderId, folderId) || other.folderId == folderId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiMissingDownload&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.status, status) || other.status == status)&&(identical(other.missingMedia, missingMedia) || other.missingMedia == missingMedia)&&(identical(other.missingCover, missingCover) || other.missingCover == missingCover)&&(identical(other.missingAvatar, missingAvatar) || other.missingAvatar == missingAvatar)&&(identical(other.expectedBytes, expectedBytes) || other.expectedBytes == expectedBytes)&&const DeepCollectionEquality().equals(other._candidateFiles, _candidateFiles));
 }
 
 
 @override
-int get hashCode => Object.hashAll([runtimeType,id,videoId,title,coverUrl,coverPath,authorId,authorName,authorAvatarUrl,authorAvatarPath,quality,description,const DeepCollectionEquality().hash(tags),status,progress,downloadedBytes,totalBytes,speed,createdAt,filePath,folderId]);
+int get hashCode => Object.hash(runtimeType,videoId,title,status,missingMedia,missingCover,missingAvatar,expectedBytes,const DeepCollectionEquality().hash(_candidateFiles));
 
 @override
 String toString() {
-  return 'ApiDownloadTask(id: $id, videoId: $videoId, title: $title, coverUrl: $coverUrl, coverPath: $coverPath, authorId: $authorId, authorName: $authorName, authorAvatarUrl: $authorAvatarUrl, authorAvatarPath: $authorAvatarPath, quality: $quality, description: $description, tags: $tags, status: $status, progress: $progress, downloadedBytes: $downloadedBytes, totalBytes: $totalBytes, speed: $speed, createdAt: $createdAt, filePath: $filePath, folderId: $folderId)';
+  return 'ApiMissingDownload(videoId: $videoId, title: $title, status: $status, missingMedia: $missingMedia, missingCover: $missingCover, missingAvatar: $missingAvatar, expectedBytes: $expectedBytes, candidateFiles: $candidateFiles)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiDownloadTaskCopyWith<$Res>  {
-  factory $ApiDownloadTaskCopyWith(ApiDownloadTask value, $Res Function(ApiDownloadTask) _then) = _$ApiDownloadTaskCopyWithImpl;
-@useResult
+abstract mixin class _$ApiMissingDownloadCopyWith<$Res> implements $ApiMissingDownloadCopyWith<$Res> {
+  factory _$ApiMissingDownloadCopyWith(_ApiMissingDownload value, $Res Function(_ApiMissingDownload) _then) = __$ApiMissingDownloadCopyWithImpl;
+@override @useResult
 $Res call({
- String id, String videoId, String title, String coverUrl, String? coverPath, String? authorId, String? authorName, String? authorAvatarUrl, String? authorAvatarPath, String quality, String? description, List<String> tags, ApiDownloadStatus status, double progress, BigInt downloadedBytes, BigInt totalBytes, BigInt speed, PlatformInt64 createdAt, String? filePath, String? folderId
+ String videoId, String title, ApiDownloadStatus status, bool missingMedia, bool missingCover, bool missingAvatar, BigInt expectedBytes, List<String> candidateFiles
 });
 
 
-$ApiDownloadStatusCopyWith<$Res> get status;
+@override $ApiDownloadStatusCopyWith<$Res> get status;
 
 }
 /// @nodoc
-class _$ApiDownloadTaskCopyWithImpl<$Res>
-    implements $ApiDownloadTaskCopyWith<$Res> {
-  _$ApiDownloadTaskCopyWithImpl(this._self, this._then);
+class __$ApiMissingDownloadCopyWithImpl<$Res>
+    implements _$ApiMissingDownloadCopyWith<$Res> {
+  __$ApiMissingDownloadCopyWithImpl(this._self, this._then);
 
-  final ApiDownloadTask _self;
-  final $Res Function(ApiDownloadTask) _then;
+  final _ApiMissingDownload _self;
+  final $Res Function(_ApiMissingDownload) _then;
 
-/// Create a copy of ApiDownloadTask
+/// Create a copy of ApiMissingDownload
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? coverPath = freezed,Object? authorId = freezed,Object? authorName = freezed,Object? authorAvatarUrl = freezed,Object? authorAvatarPath = freezed,Object? quality = null,Object? description = freezed,Object? tags = null,Object? status = null,Object? progress = null,Object? downloadedBytes = null,Object? totalBytes = null,Object? speed = null,Object? createdAt = null,Object? filePath = freezed,Object? folderId = freezed,}) {
-  return _then(_self.copyWith(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? status = null,Object? missingMedia = null,Object? missingCover = null,Object? missingAvatar = null,Object? expectedBytes = null,Object? candidateFiles = null,}) {
+  return _then(_ApiMissingDownload(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
 as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
-as String,coverPath: freezed == coverPath ? _self.coverPath : coverPath // ignore: cast_nullable_to_non_nullable
-as String?,authorId: freezed == authorId ? _self.authorId : authorId // ignore: cast_nullable_to_non_nullable
-as String?,authorName: freezed == authorName ? _self.authorName : authorName // ignore: cast_nullable_to_non_nullable
-as String?,authorAvatarUrl: freezed == authorAvatarUrl ? _self.authorAvatarUrl : authorAvatarUrl // ignore: cast_nullable_to_non_nullable
-as String?,authorAvatarPath: freezed == authorAvatarPath ? _self.authorAvatarPath : authorAvatarPath // ignore: cast_nullable_to_non_nullable
-as String?,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
-as String,description: freezed == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
-as String?,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
-as List<String>,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
-as ApiDownloadStatus,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
-as double,downloadedBytes: null == downloadedBytes ? _self.downloadedBytes : downloadedBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,speed: null == speed ? _self.speed : speed // ignore: cast_nullable_to_non_nullable
-as BigInt,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
-as String?,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
-as String?,
+as String,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as ApiDownloadStatus,missingMedia: null == missingMedia ? _self.missingMedia : missingMedia // ignore: cast_nullable_to_non_nullable
+as bool,missingCover: null == missingCover ? _self.missingCover : missingCover // ignore: cast_nullable_to_non_nullable
+as bool,missingAvatar: null == missingAvatar ? _self.missingAvatar : missingAvatar // ignore: cast_nullable_to_non_nullable
+as bool,expectedBytes: null == expectedBytes ? _self.expectedBytes : expectedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,candidateFiles: null == candidateFiles ? _self._candidateFiles : candidateFiles // ignore: cast_nullable_to_non_nullable
+as List<String>,
   ));
 }
-/// Create a copy of ApiDownloadTask
+
+/// Create a copy of ApiMissingDownload
 /// with the given fields replaced by the non-null parameter values.
 @override
 @pragma('vm:prefer-inline')
@@ -2312,9 +9039,70 @@ $ApiDownloadStatusCopyWith<$Res> get status {
 }
 }
 
+/// @nodoc
+mixin _$ApiMyListInfo {
+
+ bool get isWatchLater; List<ApiMyListItem> get items;
+/// Create a copy of ApiMyListInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiMyListInfoCopyWith<ApiMyListInfo> get copyWith => _$ApiMyListInfoCopyWithImpl<ApiMyListInfo>(this as ApiMyListInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiMyListInfo&&(identical(other.isWatchLater, isWatchLater) || other.isWatchLater == isWatchLater)&&const DeepCollectionEquality().equals(other.items, items));
+}
+
 
-/// Adds pattern-matching-related methods to [ApiDownloadTask].
-extension ApiDownloadTaskPatterns on ApiDownloadTask {
+@override
+int get hashCode => Object.hash(runtimeType,isWatchLater,const DeepCollectionEquality().hash(items));
+
+@override
+String toString() {
+  return 'ApiMyListInfo(isWatchLater: $isWatchLater, items: $items)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiMyListInfoCopyWith<$Res>  {
+  factory $ApiMyListInfoCopyWith(ApiMyListInfo value, $Res Function(ApiMyListInfo) _then) = _$ApiMyListInfoCopyWithImpl;
+@useResult
+$Res call({
+ bool isWatchLater, List<ApiMyListItem> items
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiMyListInfoCopyWithImpl<$Res>
+    implements $ApiMyListInfoCopyWith<$Res> {
+  _$ApiMyListInfoCopyWithImpl(this._self, this._then);
+
+  final ApiMyListInfo _self;
+  final $Res Function(ApiMyListInfo) _then;
+
+/// Create a copy of ApiMyListInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? isWatchLater = null,Object? items = null,}) {
+  return _then(_self.copyWith(
+isWatchLater: null == isWatchLater ? _self.isWatchLater : isWatchLater // ignore: cast_nullable_to_non_nullable
+as bool,items: null == items ? _self.items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiMyListItem>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiMyListInfo].
+extension ApiMyListInfoPatterns on ApiMyListInfo {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -2327,10 +9115,10 @@ extension ApiDownloadTaskPatterns on ApiDownloadTask {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiDownloadTask value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiMyListInfo value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiDownloadTask() when $default != null:
+case _ApiMyListInfo() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -2349,10 +9137,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiDownloadTask value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiMyListInfo value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiDownloadTask():
+case _ApiMyListInfo():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -2367,10 +9155,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiDownloadTask value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiMyListInfo value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiDownloadTask() when $default != null:
+case _ApiMyListInfo() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -2388,10 +9176,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String videoId,  String title,  String coverUrl,  String? coverPath,  String? authorId,  String? authorName,  String? authorAvatarUrl,  String? authorAvatarPath,  String quality,  String? description,  List<String> tags,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  BigInt speed,  PlatformInt64 createdAt,  String? filePath,  String? folderId)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool isWatchLater,  List<ApiMyListItem> items)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiDownloadTask() when $default != null:
-return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPath,_that.authorId,_that.authorName,_that.authorAvatarUrl,_that.authorAvatarPath,_that.quality,_that.description,_that.tags,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.speed,_that.createdAt,_that.filePath,_that.folderId);case _:
+case _ApiMyListInfo() when $default != null:
+return $default(_that.isWatchLater,_that.items);case _:
   return orElse();
 
 }
@@ -2409,10 +9197,10 @@ return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPat
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String videoId,  String title,  String coverUrl,  String? coverPath,  String? authorId,  String? authorName,  String? authorAvatarUrl,  String? authorAvatarPath,  String quality,  String? description,  List<String> tags,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  BigInt speed,  PlatformInt64 createdAt,  String? filePath,  String? folderId)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool isWatchLater,  List<ApiMyListItem> items)  $default,) {final _that = this;
 switch (_that) {
-case _ApiDownloadTask():
-return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPath,_that.authorId,_that.authorName,_that.authorAvatarUrl,_that.authorAvatarPath,_that.quality,_that.description,_that.tags,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.speed,_that.createdAt,_that.filePath,_that.folderId);}
+case _ApiMyListInfo():
+return $default(_that.isWatchLater,_that.items);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -2426,10 +9214,10 @@ return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPat
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String videoId,  String title,  String coverUrl,  String? coverPath,  String? authorId,  String? authorName,  String? authorAvatarUrl,  String? authorAvatarPath,  String quality,  String? description,  List<String> tags,  ApiDownloadStatus status,  double progress,  BigInt downloadedBytes,  BigInt totalBytes,  BigInt speed,  PlatformInt64 createdAt,  String? filePath,  String? folderId)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool isWatchLater,  List<ApiMyListItem> items)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiDownloadTask() when $default != null:
-return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPath,_that.authorId,_that.authorName,_that.authorAvatarUrl,_that.authorAvatarPath,_that.quality,_that.description,_that.tags,_that.status,_that.progress,_that.downloadedBytes,_that.totalBytes,_that.speed,_that.createdAt,_that.filePath,_that.folderId);case _:
+case _ApiMyListInfo() when $default != null:
+return $default(_that.isWatchLater,_that.items);case _:
   return null;
 
 }
@@ -2440,157 +9228,112 @@ return $default(_that.id,_that.videoId,_that.title,_that.coverUrl,_that.coverPat
 /// @nodoc
 
 
-class _ApiDownloadTask implements ApiDownloadTask {
-  const _ApiDownloadTask({required this.id, required this.videoId, required this.title, required this.coverUrl, this.coverPath, this.authorId, this.authorName, this.authorAvatarUrl, this.authorAvatarPath, required this.quality, this.description, required final  List<String> tags, required this.status, required this.progress, required this.downloadedBytes, required this.totalBytes, required this.speed, required this.createdAt, this.filePath, this.folderId}): _tags = tags;
+class _ApiMyListInfo implements ApiMyListInfo {
+  const _ApiMyListInfo({required this.isWatchLater, required final  List<ApiMyListItem> items}): _items = items;
   
 
-@override final  String id;
-@override final  String videoId;
-@override final  String title;
-@override final  String coverUrl;
-@override final  String? coverPath;
-@override final  String? authorId;
-@override final  String? authorName;
-@override final  String? authorAvatarUrl;
-@override final  String? authorAvatarPath;
-@override final  String quality;
-@override final  String? description;
- final  List<String> _tags;
-@override List<String> get tags {
-  if (_tags is EqualUnmodifiableListView) return _tags;
+@override final  bool isWatchLater;
+ final  List<ApiMyListItem> _items;
+@override List<ApiMyListItem> get items {
+  if (_items is EqualUnmodifiableListView) return _items;
   // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_tags);
+  return EqualUnmodifiableListView(_items);
 }
 
-@override final  ApiDownloadStatus status;
-@override final  double progress;
-@override final  BigInt downloadedBytes;
-@override final  BigInt totalBytes;
-@override final  BigInt speed;
-@override final  PlatformInt64 createdAt;
-@override final  String? filePath;
-@override final  String? folderId;
 
-/// Create a copy of ApiDownloadTask
+/// Create a copy of ApiMyListInfo
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiDownloadTaskCopyWith<_ApiDownloadTask> get copyWith => __$ApiDownloadTaskCopyWithImpl<_ApiDownloadTask>(this, _$identity);
+_$ApiMyListInfoCopyWith<_ApiMyListInfo> get copyWith => __$ApiMyListInfoCopyWithImpl<_ApiMyListInfo>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiDownloadTask&&(identical(other.id, id) || other.id == id)&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.coverPath, coverPath) || other.coverPath == coverPath)&&(identical(other.authorId, authorId) || other.authorId == authorId)&&(identical(other.authorName, authorName) || other.authorName == authorName)&&(identical(other.authorAvatarUrl, authorAvatarUrl) || other.authorAvatarUrl == authorAvatarUrl)&&(identical(other.authorAvatarPath, authorAvatarPath) || other.authorAvatarPath == authorAvatarPath)&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.description, description) || other.description == description)&&const DeepCollectionEquality().equals(other._tags, _tags)&&(identical(other.status, status) || other.status == status)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.downloadedBytes, downloadedBytes) || other.downloadedBytes == downloadedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.speed, speed) || other.speed == speed)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.filePath, filePath) || other.filePath == filePath)&&(identical(other.folderId, folderId) || other.folderId == folderId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiMyListInfo&&(identical(other.isWatchLater, isWatchLater) || other.isWatchLater == isWatchLater)&&const DeepCollectionEquality().equals(other._items, _items));
 }
 
 
 @override
-int get hashCode => Object.hashAll([runtimeType,id,videoId,title,coverUrl,coverPath,authorId,authorName,authorAvatarUrl,authorAvatarPath,quality,description,const DeepCollectionEquality().hash(_tags),status,progress,downloadedBytes,totalBytes,speed,createdAt,filePath,folderId]);
+int get hashCode => Object.hash(runtimeType,isWatchLater,const DeepCollectionEquality().hash(_items));
 
 @override
 String toString() {
-  return 'ApiDownloadTask(id: $id, videoId: $videoId, title: $title, coverUrl: $coverUrl, coverPath: $coverPath, authorId: $authorId, authorName: $authorName, authorAvatarUrl: $authorAvatarUrl, authorAvatarPath: $authorAvatarPath, quality: $quality, description: $description, tags: $tags, status: $status, progress: $progress, downloadedBytes: $downloadedBytes, totalBytes: $totalBytes, speed: $speed, createdAt: $createdAt, filePath: $filePath, folderId: $folderId)';
+  return 'ApiMyListInfo(isWatchLater: $isWatchLater, items: $items)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiDownloadTaskCopyWith<$Res> implements $ApiDownloadTaskCopyWith<$Res> {
-  factory _$ApiDownloadTaskCopyWith(_ApiDownloadTask value, $Res Function(_ApiDownloadTask) _then) = __$ApiDownloadTaskCopyWithImpl;
+abstract mixin class _$ApiMyListInfoCopyWith<$Res> implements $ApiMyListInfoCopyWith<$Res> {
+  factory _$ApiMyListInfoCopyWith(_ApiMyListInfo value, $Res Function(_ApiMyListInfo) _then) = __$ApiMyListInfoCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String videoId, String title, String coverUrl, String? coverPath, String? authorId, String? authorName, String? authorAvatarUrl, String? authorAvatarPath, String quality, String? description, List<String> tags, ApiDownloadStatus status, double progress, BigInt downloadedBytes, BigInt totalBytes, BigInt speed, PlatformInt64 createdAt, String? filePath, String? folderId
+ bool isWatchLater, List<ApiMyListItem> items
 });
 
 
-@override $ApiDownloadStatusCopyWith<$Res> get status;
+
 
 }
 /// @nodoc
-class __$ApiDownloadTaskCopyWithImpl<$Res>
-    implements _$ApiDownloadTaskCopyWith<$Res> {
-  __$ApiDownloadTaskCopyWithImpl(this._self, this._then);
+class __$ApiMyListInfoCopyWithImpl<$Res>
+    implements _$ApiMyListInfoCopyWith<$Res> {
+  __$ApiMyListInfoCopyWithImpl(this._self, this._then);
 
-  final _ApiDownloadTask _self;
-  final $Res Function(_ApiDownloadTask) _then;
+  final _ApiMyListInfo _self;
+  final $Res Function(_ApiMyListInfo) _then;
 
-/// Create a copy of ApiDownloadTask
+/// Create a copy of ApiMyListInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? coverPath = freezed,Object? authorId = freezed,Object? authorName = freezed,Object? authorAvatarUrl = freezed,Object? authorAvatarPath = freezed,Object? quality = null,Object? description = freezed,Object? tags = null,Object? status = null,Object? progress = null,Object? downloadedBytes = null,Object? totalBytes = null,Object? speed = null,Object? createdAt = null,Object? filePath = freezed,Object? folderId = freezed,}) {
-  return _then(_ApiDownloadTask(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
-as String,coverPath: freezed == coverPath ? _self.coverPath : coverPath // ignore: cast_nullable_to_non_nullable
-as String?,authorId: freezed == authorId ? _self.authorId : authorId // ignore: cast_nullable_to_non_nullable
-as String?,authorName: freezed == authorName ? _self.authorName : authorName // ignore: cast_nullable_to_non_nullable
-as String?,authorAvatarUrl: freezed == authorAvatarUrl ? _self.authorAvatarUrl : authorAvatarUrl // ignore: cast_nullable_to_non_nullable
-as String?,authorAvatarPath: freezed == authorAvatarPath ? _self.authorAvatarPath : authorAvatarPath // ignore: cast_nullable_to_non_nullable
-as String?,quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
-as String,description: freezed == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
-as String?,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
-as List<String>,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
-as ApiDownloadStatus,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
-as double,downloadedBytes: null == downloadedBytes ? _self.downloadedBytes : downloadedBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,speed: null == speed ? _self.speed : speed // ignore: cast_nullable_to_non_nullable
-as BigInt,createdAt: null == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,filePath: freezed == filePath ? _self.filePath : filePath // ignore: cast_nullable_to_non_nullable
-as String?,folderId: freezed == folderId ? _self.folderId : folderId // ignore: cast_nullable_to_non_nullable
-as String?,
+@override @pragma('vm:prefer-inline') $Res call({Object? isWatchLater = null,Object? items = null,}) {
+  return _then(_ApiMyListInfo(
+isWatchLater: null == isWatchLater ? _self.isWatchLater : isWatchLater // ignore: cast_nullable_to_non_nullable
+as bool,items: null == items ? _self._items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiMyListItem>,
   ));
 }
 
-/// Create a copy of ApiDownloadTask
-/// with the given fields replaced by the non-null parameter values.
-@override
-@pragma('vm:prefer-inline')
-$ApiDownloadStatusCopyWith<$Res> get status {
-  
-  return $ApiDownloadStatusCopyWith<$Res>(_self.status, (value) {
-    return _then(_self.copyWith(status: value));
-  });
-}
+
 }
 
 /// @nodoc
-mixin _$ApiExportProgress {
+mixin _$ApiMyListItem {
 
- int get totalFiles; int get doneFiles; String? get currentFile; BigInt get currentBytes; BigInt get currentTotalBytes; bool get done; String? get error;
-/// Create a copy of ApiExportProgress
+ String get code; String get title; bool get isSelected;
+/// Create a copy of ApiMyListItem
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiExportProgressCopyWith<ApiExportProgress> get copyWith => _$ApiExportProgressCopyWithImpl<ApiExportProgress>(this as ApiExportProgress, _$identity);
+$ApiMyListItemCopyWith<ApiMyListItem> get copyWith => _$ApiMyListItemCopyWithImpl<ApiMyListItem>(this as ApiMyListItem, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiExportProgress&&(identical(other.totalFiles, totalFiles) || other.totalFiles == totalFiles)&&(identical(other.doneFiles, doneFiles) || other.doneFiles == doneFiles)&&(identical(other.currentFile, currentFile) || other.currentFile == currentFile)&&(identical(other.currentBytes, currentBytes) || other.currentBytes == currentBytes)&&(identical(other.currentTotalBytes, currentTotalBytes) || other.currentTotalBytes == currentTotalBytes)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiMyListItem&&(identical(other.code, code) || other.code == code)&&(identical(other.title, title) || other.title == title)&&(identical(other.isSelected, isSelected) || other.isSelected == isSelected));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,totalFiles,doneFiles,currentFile,currentBytes,currentTotalBytes,done,error);
+int get hashCode => Object.hash(runtimeType,code,title,isSelected);
 
 @override
 String toString() {
-  return 'ApiExportProgress(totalFiles: $totalFiles, doneFiles: $doneFiles, currentFile: $currentFile, currentBytes: $currentBytes, currentTotalBytes: $currentTotalBytes, done: $done, error: $error)';
+  return 'ApiMyListItem(code: $code, title: $title, isSelected: $isSelected)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiExportProgressCopyWith<$Res>  {
-  factory $ApiExportProgressCopyWith(ApiExportProgress value, $Res Function(ApiExportProgress) _then) = _$ApiExportProgressCopyWithImpl;
+abstract mixin class $ApiMyListItemCopyWith<$Res>  {
+  factory $ApiMyListItemCopyWith(ApiMyListItem value, $Res Function(ApiMyListItem) _then) = _$ApiMyListItemCopyWithImpl;
 @useResult
 $Res call({
- int totalFiles, int doneFiles, String? currentFile, BigInt currentBytes, BigInt currentTotalBytes, bool done, String? error
+ String code, String title, bool isSelected
 });
 
 
@@ -2598,33 +9341,29 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiExportProgressCopyWithImpl<$Res>
-    implements $ApiExportProgressCopyWith<$Res> {
-  _$ApiExportProgressCopyWithImpl(this._self, this._then);
+class _$ApiMyListItemCopyWithImpl<$Res>
+    implements $ApiMyListItemCopyWith<$Res> {
+  _$ApiMyListItemCopyWithImpl(this._self, this._then);
 
-  final ApiExportProgress _self;
-  final $Res Function(ApiExportProgress) _then;
+  final ApiMyListItem _self;
+  final $Res Function(ApiMyListItem) _then;
 
-/// Create a copy of ApiExportProgress
+/// Create a copy of ApiMyListItem
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? totalFiles = null,Object? doneFiles = null,Object? currentFile = freezed,Object? currentBytes = null,Object? currentTotalBytes = null,Object? done = null,Object? error = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? code = null,Object? title = null,Object? isSelected = null,}) {
   return _then(_self.copyWith(
-totalFiles: null == totalFiles ? _self.totalFiles : totalFiles // ignore: cast_nullable_to_non_nullable
-as int,doneFiles: null == doneFiles ? _self.doneFiles : doneFiles // ignore: cast_nullable_to_non_nullable
-as int,currentFile: freezed == currentFile ? _self.currentFile : currentFile // ignore: cast_nullable_to_non_nullable
-as String?,currentBytes: null == currentBytes ? _self.currentBytes : currentBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,currentTotalBytes: null == currentTotalBytes ? _self.currentTotalBytes : currentTotalBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
-as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
-as String?,
+code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,isSelected: null == isSelected ? _self.isSelected : isSelected // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiExportProgress].
-extension ApiExportProgressPatterns on ApiExportProgress {
+/// Adds pattern-matching-related methods to [ApiMyListItem].
+extension ApiMyListItemPatterns on ApiMyListItem {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -2637,10 +9376,10 @@ extension ApiExportProgressPatterns on ApiExportProgress {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiExportProgress value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiMyListItem value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiExportProgress() when $default != null:
+case _ApiMyListItem() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -2659,10 +9398,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiExportProgress value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiMyListItem value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiExportProgress():
+case _ApiMyListItem():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -2677,10 +9416,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiExportProgress value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiMyListItem value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiExportProgress() when $default != null:
+case _ApiMyListItem() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -2698,10 +9437,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int totalFiles,  int doneFiles,  String? currentFile,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String code,  String title,  bool isSelected)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiExportProgress() when $default != null:
-return $default(_that.totalFiles,_that.doneFiles,_that.currentFile,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);case _:
+case _ApiMyListItem() when $default != null:
+return $default(_that.code,_that.title,_that.isSelected);case _:
   return orElse();
 
 }
@@ -2719,10 +9458,10 @@ return $default(_that.totalFiles,_that.doneFiles,_that.currentFile,_that.current
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int totalFiles,  int doneFiles,  String? currentFile,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String code,  String title,  bool isSelected)  $default,) {final _that = this;
 switch (_that) {
-case _ApiExportProgress():
-return $default(_that.totalFiles,_that.doneFiles,_that.currentFile,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);}
+case _ApiMyListItem():
+return $default(_that.code,_that.title,_that.isSelected);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -2736,10 +9475,10 @@ return $default(_that.totalFiles,_that.doneFiles,_that.currentFile,_that.current
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int totalFiles,  int doneFiles,  String? currentFile,  BigInt currentBytes,  BigInt currentTotalBytes,  bool done,  String? error)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String code,  String title,  bool isSelected)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiExportProgress() when $default != null:
-return $default(_that.totalFiles,_that.doneFiles,_that.currentFile,_that.currentBytes,_that.currentTotalBytes,_that.done,_that.error);case _:
+case _ApiMyListItem() when $default != null:
+return $default(_that.code,_that.title,_that.isSelected);case _:
   return null;
 
 }
@@ -2750,49 +9489,45 @@ return $default(_that.totalFiles,_that.doneFiles,_that.currentFile,_that.current
 /// @nodoc
 
 
-class _ApiExportProgress implements ApiExportProgress {
-  const _ApiExportProgress({required this.totalFiles, required this.doneFiles, this.currentFile, required this.currentBytes, required this.currentTotalBytes, required this.done, this.error});
+class _ApiMyListItem implements ApiMyListItem {
+  const _ApiMyListItem({required this.code, required this.title, required this.isSelected});
   
 
-@override final  int totalFiles;
-@override final  int doneFiles;
-@override final  String? currentFile;
-@override final  BigInt currentBytes;
-@override final  BigInt currentTotalBytes;
-@override final  bool done;
-@override final  String? error;
+@override final  String code;
+@override final  String title;
+@override final  bool isSelected;
 
-/// Create a copy of ApiExportProgress
+/// Create a copy of ApiMyListItem
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiExportProgressCopyWith<_ApiExportProgress> get copyWith => __$ApiExportProgressCopyWithImpl<_ApiExportProgress>(this, _$identity);
+_$ApiMyListItemCopyWith<_ApiMyListItem> get copyWith => __$ApiMyListItemCopyWithImpl<_ApiMyListItem>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiExportProgress&&(identical(other.totalFiles, totalFiles) || other.totalFiles == totalFiles)&&(identical(other.doneFiles, doneFiles) || other.doneFiles == doneFiles)&&(identical(other.currentFile, currentFile) || other.currentFile == currentFile)&&(identical(other.currentBytes, currentBytes) || other.currentBytes == currentBytes)&&(identical(other.currentTotalBytes, currentTotalBytes) || other.currentTotalBytes == currentTotalBytes)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiMyListItem&&(identical(other.code, code) || other.code == code)&&(identical(other.title, title) || other.title == title)&&(identical(other.isSelected, isSelected) || other.isSelected == isSelected));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,totalFiles,doneFiles,currentFile,currentBytes,currentTotalBytes,done,error);
+int get hashCode => Object.hash(runtimeType,code,title,isSelected);
 
 @override
 String toString() {
-  return 'ApiExportProgress(totalFiles: $totalFiles, doneFiles: $doneFiles, currentFile: $currentFile, currentBytes: $currentBytes, currentTotalBytes: $currentTotalBytes, done: $done, error: $error)';
+  return 'ApiMyListItem(code: $code, title: $title, isSelected: $isSelected)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiExportProgressCopyWith<$Res> implements $ApiExportProgressCopyWith<$Res> {
-  factory _$ApiExportProgressCopyWith(_ApiExportProgress value, $Res Function(_ApiExportProgress) _then) = __$ApiExportProgressCopyWithImpl;
+abstract mixin class _$ApiMyListItemCopyWith<$Res> implements $ApiMyListItemCopyWith<$Res> {
+  factory _$ApiMyListItemCopyWith(_ApiMyListItem value, $Res Function(_ApiMyListItem) _then) = __$ApiMyListItemCopyWithImpl;
 @override @useResult
 $Res call({
- int totalFiles, int doneFiles, String? currentFile, BigInt currentBytes, BigInt currentTotalBytes, bool done, String? error
+ String code, String title, bool isSelected
 });
 
 
@@ -2800,25 +9535,21 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiExportProgressCopyWithImpl<$Res>
-    implements _$ApiExportProgressCopyWith<$Res> {
-  __$ApiExportProgressCopyWithImpl(this._self, this._then);
+class __$ApiMyListItemCopyWithImpl<$Res>
+    implements _$ApiMyListItemCopyWith<$Res> {
+  __$ApiMyListItemCopyWithImpl(this._self, this._then);
 
-  final _ApiExportProgress _self;
-  final $Res Function(_ApiExportProgress) _then;
+  final _ApiMyListItem _self;
+  final $Res Function(_ApiMyListItem) _then;
 
-/// Create a copy of ApiExportProgress
+/// Create a copy of ApiMyListItem
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? totalFiles = null,Object? doneFiles = null,Object? currentFile = freezed,Object? currentBytes = null,Object? currentTotalBytes = null,Object? done = null,Object? error = freezed,}) {
-  return _then(_ApiExportProgress(
-totalFiles: null == totalFiles ? _self.totalFiles : totalFiles // ignore: cast_nullable_to_non_nullable
-as int,doneFiles: null == doneFiles ? _self.doneFiles : doneFiles // ignore: cast_nullable_to_non_nullable
-as int,currentFile: freezed == currentFile ? _self.currentFile : currentFile // ignore: cast_nullable_to_non_nullable
-as String?,currentBytes: null == currentBytes ? _self.currentBytes : currentBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,currentTotalBytes: null == currentTotalBytes ? _self.currentTotalBytes : currentTotalBytes // ignore: cast_nullable_to_non_nullable
-as BigInt,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
-as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
-as String?,
+@override @pragma('vm:prefer-inline') $Res call({Object? code = null,Object? title = null,Object? isSelected = null,}) {
+  return _then(_ApiMyListItem(
+code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,isSelected: null == isSelected ? _self.isSelected : isSelected // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -2826,40 +9557,40 @@ as String?,
 }
 
 /// @nodoc
-mixin _$ApiFavoriteList {
+mixin _$ApiNextEpisode {
 
- List<ApiVideoCard> get videos; int get total; int get page; bool get hasNext;
-/// Create a copy of ApiFavoriteList
+ String get videoId; String get title; String get coverUrl; String get seriesId; String get seriesTitle; int get episode; String get previousVideoId; PlatformInt64 get previousPlayedAt; bool get isDownloaded;
+/// Create a copy of ApiNextEpisode
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiFavoriteListCopyWith<ApiFavoriteList> get copyWith => _$ApiFavoriteListCopyWithImpl<ApiFavoriteList>(this as ApiFavoriteList, _$identity);
+$ApiNextEpisodeCopyWith<ApiNextEpisode> get copyWith => _$ApiNextEpisodeCopyWithImpl<ApiNextEpisode>(this as ApiNextEpisode, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiFavoriteList&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiNextEpisode&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.seriesId, seriesId) || other.seriesId == seriesId)&&(identical(other.seriesTitle, seriesTitle) || other.seriesTitle == seriesTitle)&&(identical(other.episode, episode) || other.episode == episode)&&(identical(other.previousVideoId, previousVideoId) || other.previousVideoId == previousVideoId)&&(identical(other.previousPlayedAt, previousPlayedAt) || other.previousPlayedAt == previousPlayedAt)&&(identical(other.isDownloaded, isDownloaded) || other.isDownloaded == isDownloaded));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(videos),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,seriesId,seriesTitle,episode,previousVideoId,previousPlayedAt,isDownloaded);
 
 @override
 String toString() {
-  return 'ApiFavoriteList(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiNextEpisode(videoId: $videoId, title: $title, coverUrl: $coverUrl, seriesId: $seriesId, seriesTitle: $seriesTitle, episode: $episode, previousVideoId: $previousVideoId, previousPlayedAt: $previousPlayedAt, isDownloaded: $isDownloaded)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiFavoriteListCopyWith<$Res>  {
-  factory $ApiFavoriteListCopyWith(ApiFavoriteList value, $Res Function(ApiFavoriteList) _then) = _$ApiFavoriteListCopyWithImpl;
+abstract mixin class $ApiNextEpisodeCopyWith<$Res>  {
+  factory $ApiNextEpisodeCopyWith(ApiNextEpisode value, $Res Function(ApiNextEpisode) _then) = _$ApiNextEpisodeCopyWithImpl;
 @useResult
 $Res call({
- List<ApiVideoCard> videos, int total, int page, bool hasNext
+ String videoId, String title, String coverUrl, String seriesId, String seriesTitle, int episode, String previousVideoId, PlatformInt64 previousPlayedAt, bool isDownloaded
 });
 
 
@@ -2867,21 +9598,26 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiFavoriteListCopyWithImpl<$Res>
-    implements $ApiFavoriteListCopyWith<$Res> {
-  _$ApiFavoriteListCopyWithImpl(this._self, this._then);
+class _$ApiNextEpisodeCopyWithImpl<$Res>
+    implements $ApiNextEpisodeCopyWith<$Res> {
+  _$ApiNextEpisodeCopyWithImpl(this._self, this._then);
 
-  final ApiFavoriteList _self;
-  final $Res Function(ApiFavoriteList) _then;
+  final ApiNextEpisode _self;
+  final $Res Function(ApiNextEpisode) _then;
 
-/// Create a copy of ApiFavoriteList
+/// Create a copy of ApiNextEpisode
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? seriesId = null,Object? seriesTitle = null,Object? episode = null,Object? previousVideoId = null,Object? previousPlayedAt = null,Object? isDownloaded = null,}) {
   return _then(_self.copyWith(
-videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,seriesId: null == seriesId ? _self.seriesId : seriesId // ignore: cast_nullable_to_non_nullable
+as String,seriesTitle: null == seriesTitle ? _self.seriesTitle : seriesTitle // ignore: cast_nullable_to_non_nullable
+as String,episode: null == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
+as int,previousVideoId: null == previousVideoId ? _self.previousVideoId : previousVideoId // ignore: cast_nullable_to_non_nullable
+as String,previousPlayedAt: null == previousPlayedAt ? _self.previousPlayedAt : previousPlayedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,isDownloaded: null == isDownloaded ? _self.isDownloaded : isDownloaded // ignore: cast_nullable_to_non_nullable
 as bool,
   ));
 }
@@ -2889,8 +9625,8 @@ as bool,
 }
 
 
-/// Adds pattern-matching-related methods to [ApiFavoriteList].
-extension ApiFavoriteListPatterns on ApiFavoriteList {
+/// Adds pattern-matching-related methods to [ApiNextEpisode].
+extension ApiNextEpisodePatterns on ApiNextEpisode {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -2903,10 +9639,10 @@ extension ApiFavoriteListPatterns on ApiFavoriteList {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiFavoriteList value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiNextEpisode value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiFavoriteList() when $default != null:
+case _ApiNextEpisode() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -2925,10 +9661,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiFavoriteList value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiNextEpisode value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiFavoriteList():
+case _ApiNextEpisode():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -2943,10 +9679,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiFavoriteList value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiNextEpisode value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiFavoriteList() when $default != null:
+case _ApiNextEpisode() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -2964,10 +9700,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  String seriesId,  String seriesTitle,  int episode,  String previousVideoId,  PlatformInt64 previousPlayedAt,  bool isDownloaded)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiFavoriteList() when $default != null:
-return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
+case _ApiNextEpisode() when $default != null:
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.seriesId,_that.seriesTitle,_that.episode,_that.previousVideoId,_that.previousPlayedAt,_that.isDownloaded);case _:
   return orElse();
 
 }
@@ -2985,10 +9721,10 @@ return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  String seriesId,  String seriesTitle,  int episode,  String previousVideoId,  PlatformInt64 previousPlayedAt,  bool isDownloaded)  $default,) {final _that = this;
 switch (_that) {
-case _ApiFavoriteList():
-return $default(_that.videos,_that.total,_that.page,_that.hasNext);}
+case _ApiNextEpisode():
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.seriesId,_that.seriesTitle,_that.episode,_that.previousVideoId,_that.previousPlayedAt,_that.isDownloaded);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -3002,10 +9738,10 @@ return $default(_that.videos,_that.total,_that.page,_that.hasNext);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  String coverUrl,  String seriesId,  String seriesTitle,  int episode,  String previousVideoId,  PlatformInt64 previousPlayedAt,  bool isDownloaded)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiFavoriteList() when $default != null:
-return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
+case _ApiNextEpisode() when $default != null:
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.seriesId,_that.seriesTitle,_that.episode,_that.previousVideoId,_that.previousPlayedAt,_that.isDownloaded);case _:
   return null;
 
 }
@@ -3016,52 +9752,51 @@ return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
 /// @nodoc
 
 
-class _ApiFavoriteList implements ApiFavoriteList {
-  const _ApiFavoriteList({required final  List<ApiVideoCard> videos, required this.total, required this.page, required this.hasNext}): _videos = videos;
+class _ApiNextEpisode implements ApiNextEpisode {
+  const _ApiNextEpisode({required this.videoId, required this.title, required this.coverUrl, required this.seriesId, required this.seriesTitle, required this.episode, required this.previousVideoId, required this.previousPlayedAt, required this.isDownloaded});
   
 
- final  List<ApiVideoCard> _videos;
-@override List<ApiVideoCard> get videos {
-  if (_videos is EqualUnmodifiableListView) return _videos;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_videos);
-}
-
-@override final  int total;
-@override final  int page;
-@override final  bool hasNext;
+@override final  String videoId;
+@override final  String title;
+@override final  String coverUrl;
+@override final  String seriesId;
+@override final  String seriesTitle;
+@override final  int episode;
+@override final  String previousVideoId;
+@override final  PlatformInt64 previousPlayedAt;
+@override final  bool isDownloaded;
 
-/// Create a copy of ApiFavoriteList
+/// Create a copy of ApiNextEpisode
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiFavoriteListCopyWith<_ApiFavoriteList> get copyWith => __$ApiFavoriteListCopyWithImpl<_ApiFavoriteList>(this, _$identity);
+_$ApiNextEpisodeCopyWith<_ApiNextEpisode> get copyWith => __$ApiNextEpisodeCopyWithImpl<_ApiNextEpisode>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiFavoriteList&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiNextEpisode&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.seriesId, seriesId) || other.seriesId == seriesId)&&(identical(other.seriesTitle, seriesTitle) || other.seriesTitle == seriesTitle)&&(identical(other.episode, episode) || other.episode == episode)&&(identical(other.previousVideoId, previousVideoId) || other.previousVideoId == previousVideoId)&&(identical(other.previousPlayedAt, previousPlayedAt) || other.previousPlayedAt == previousPlayedAt)&&(identical(other.isDownloaded, isDownloaded) || other.isDownloaded == isDownloaded));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_videos),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,seriesId,seriesTitle,episode,previousVideoId,previousPlayedAt,isDownloaded);
 
 @override
 String toString() {
-  return 'ApiFavoriteList(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiNextEpisode(videoId: $videoId, title: $title, coverUrl: $coverUrl, seriesId: $seriesId, seriesTitle: $seriesTitle, episode: $episode, previousVideoId: $previousVideoId, previousPlayedAt: $previousPlayedAt, isDownloaded: $isDownloaded)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiFavoriteListCopyWith<$Res> implements $ApiFavoriteListCopyWith<$Res> {
-  factory _$ApiFavoriteListCopyWith(_ApiFavoriteList value, $Res Function(_ApiFavoriteList) _then) = __$ApiFavoriteListCopyWithImpl;
+abstract mixin class _$ApiNextEpisodeCopyWith<$Res> implements $ApiNextEpisodeCopyWith<$Res> {
+  factory _$ApiNextEpisodeCopyWith(_ApiNextEpisode value, $Res Function(_ApiNextEpisode) _then) = __$ApiNextEpisodeCopyWithImpl;
 @override @useResult
 $Res call({
- List<ApiVideoCard> videos, int total, int page, bool hasNext
+ String videoId, String title, String coverUrl, String seriesId, String seriesTitle, int episode, String previousVideoId, PlatformInt64 previousPlayedAt, bool isDownloaded
 });
 
 
@@ -3069,21 +9804,26 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiFavoriteListCopyWithImpl<$Res>
-    implements _$ApiFavoriteListCopyWith<$Res> {
-  __$ApiFavoriteListCopyWithImpl(this._self, this._then);
+class __$ApiNextEpisodeCopyWithImpl<$Res>
+    implements _$ApiNextEpisodeCopyWith<$Res> {
+  __$ApiNextEpisodeCopyWithImpl(this._self, this._then);
 
-  final _ApiFavoriteList _self;
-  final $Res Function(_ApiFavoriteList) _then;
+  final _ApiNextEpisode _self;
+  final $Res Function(_ApiNextEpisode) _then;
 
-/// Create a copy of ApiFavoriteList
+/// Create a copy of ApiNextEpisode
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
-  return _then(_ApiFavoriteList(
-videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? seriesId = null,Object? seriesTitle = null,Object? episode = null,Object? previousVideoId = null,Object? previousPlayedAt = null,Object? isDownloaded = null,}) {
+  return _then(_ApiNextEpisode(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,seriesId: null == seriesId ? _self.seriesId : seriesId // ignore: cast_nullable_to_non_nullable
+as String,seriesTitle: null == seriesTitle ? _self.seriesTitle : seriesTitle // ignore: cast_nullable_to_non_nullable
+as String,episode: null == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
+as int,previousVideoId: null == previousVideoId ? _self.previousVideoId : previousVideoId // ignore: cast_nullable_to_non_nullable
+as String,previousPlayedAt: null == previousPlayedAt ? _self.previousPlayedAt : previousPlayedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,isDownloaded: null == isDownloaded ? _self.isDownloaded : isDownloaded // ignore: cast_nullable_to_non_nullable
 as bool,
   ));
 }
@@ -3092,40 +9832,40 @@ as bool,
 }
 
 /// @nodoc
-mixin _$ApiFilterOption {
+mixin _$ApiOrphanFile {
 
- String get value; String get label;
-/// Create a copy of ApiFilterOption
+ String get dir; String get fileName; BigInt get size; PlatformInt64? get modifiedAt; String? get videoId;
+/// Create a copy of ApiOrphanFile
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiFilterOptionCopyWith<ApiFilterOption> get copyWith => _$ApiFilterOptionCopyWithImpl<ApiFilterOption>(this as ApiFilterOption, _$identity);
+$ApiOrphanFileCopyWith<ApiOrphanFile> get copyWith => _$ApiOrphanFileCopyWithImpl<ApiOrphanFile>(this as ApiOrphanFile, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiFilterOption&&(identical(other.value, value) || other.value == value)&&(identical(other.label, label) || other.label == label));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiOrphanFile&&(identical(other.dir, dir) || other.dir == dir)&&(identical(other.fileName, fileName) || other.fileName == fileName)&&(identical(other.size, size) || other.size == size)&&(identical(other.modifiedAt, modifiedAt) || other.modifiedAt == modifiedAt)&&(identical(other.videoId, videoId) || other.videoId == videoId));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,value,label);
+int get hashCode => Object.hash(runtimeType,dir,fileName,size,modifiedAt,videoId);
 
 @override
 String toString() {
-  return 'ApiFilterOption(value: $value, label: $label)';
+  return 'ApiOrphanFile(dir: $dir, fileName: $fileName, size: $size, modifiedAt: $modifiedAt, videoId: $videoId)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiFilterOptionCopyWith<$Res>  {
-  factory $ApiFilterOptionCopyWith(ApiFilterOption value, $Res Function(ApiFilterOption) _then) = _$ApiFilterOptionCopyWithImpl;
+abstract mixin class $ApiOrphanFileCopyWith<$Res>  {
+  factory $ApiOrphanFileCopyWith(ApiOrphanFile value, $Res Function(ApiOrphanFile) _then) = _$ApiOrphanFileCopyWithImpl;
 @useResult
 $Res call({
- String value, String label
+ String dir, String fileName, BigInt size, PlatformInt64? modifiedAt, String? videoId
 });
 
 
@@ -3133,28 +9873,31 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiFilterOptionCopyWithImpl<$Res>
-    implements $ApiFilterOptionCopyWith<$Res> {
-  _$ApiFilterOptionCopyWithImpl(this._self, this._then);
+class _$ApiOrphanFileCopyWithImpl<$Res>
+    implements $ApiOrphanFileCopyWith<$Res> {
+  _$ApiOrphanFileCopyWithImpl(this._self, this._then);
 
-  final ApiFilterOption _self;
-  final $Res Function(ApiFilterOption) _then;
+  final ApiOrphanFile _self;
+  final $Res Function(ApiOrphanFile) _then;
 
-/// Create a copy of ApiFilterOption
+/// Create a copy of ApiOrphanFile
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? value = null,Object? label = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? dir = null,Object? fileName = null,Object? size = null,Object? modifiedAt = freezed,Object? videoId = freezed,}) {
   return _then(_self.copyWith(
-value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
-as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
-as String,
+dir: null == dir ? _self.dir : dir // ignore: cast_nullable_to_non_nullable
+as String,fileName: null == fileName ? _self.fileName : fileName // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,modifiedAt: freezed == modifiedAt ? _self.modifiedAt : modifiedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,videoId: freezed == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiFilterOption].
-extension ApiFilterOptionPatterns on ApiFilterOption {
+/// Adds pattern-matching-related methods to [ApiOrphanFile].
+extension ApiOrphanFilePatterns on ApiOrphanFile {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -3167,10 +9910,10 @@ extension ApiFilterOptionPatterns on ApiFilterOption {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiFilterOption value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiOrphanFile value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiFilterOption() when $default != null:
+case _ApiOrphanFile() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -3189,10 +9932,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiFilterOption value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiOrphanFile value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiFilterOption():
+case _ApiOrphanFile():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -3207,10 +9950,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiFilterOption value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiOrphanFile value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiFilterOption() when $default != null:
+case _ApiOrphanFile() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -3228,10 +9971,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String value,  String label)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String dir,  String fileName,  BigInt size,  PlatformInt64? modifiedAt,  String? videoId)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiFilterOption() when $default != null:
-return $default(_that.value,_that.label);case _:
+case _ApiOrphanFile() when $default != null:
+return $default(_that.dir,_that.fileName,_that.size,_that.modifiedAt,_that.videoId);case _:
   return orElse();
 
 }
@@ -3249,10 +9992,10 @@ return $default(_that.value,_that.label);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String value,  String label)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String dir,  String fileName,  BigInt size,  PlatformInt64? modifiedAt,  String? videoId)  $default,) {final _that = this;
 switch (_that) {
-case _ApiFilterOption():
-return $default(_that.value,_that.label);}
+case _ApiOrphanFile():
+return $default(_that.dir,_that.fileName,_that.size,_that.modifiedAt,_that.videoId);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -3266,10 +10009,10 @@ return $default(_that.value,_that.label);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String value,  String label)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String dir,  String fileName,  BigInt size,  PlatformInt64? modifiedAt,  String? videoId)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiFilterOption() when $default != null:
-return $default(_that.value,_that.label);case _:
+case _ApiOrphanFile() when $default != null:
+return $default(_that.dir,_that.fileName,_that.size,_that.modifiedAt,_that.videoId);case _:
   return null;
 
 }
@@ -3280,44 +10023,47 @@ return $default(_that.value,_that.label);case _:
 /// @nodoc
 
 
-class _ApiFilterOption implements ApiFilterOption {
-  const _ApiFilterOption({required this.value, required this.label});
+class _ApiOrphanFile implements ApiOrphanFile {
+  const _ApiOrphanFile({required this.dir, required this.fileName, required this.size, this.modifiedAt, this.videoId});
   
 
-@override final  String value;
-@override final  String label;
+@override final  String dir;
+@override final  String fileName;
+@override final  BigInt size;
+@override final  PlatformInt64? modifiedAt;
+@override final  String? videoId;
 
-/// Create a copy of ApiFilterOption
+/// Create a copy of ApiOrphanFile
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiFilterOptionCopyWith<_ApiFilterOption> get copyWith => __$ApiFilterOptionCopyWithImpl<_ApiFilterOption>(this, _$identity);
+_$ApiOrphanFileCopyWith<_ApiOrphanFile> get copyWith => __$ApiOrphanFileCopyWithImpl<_ApiOrphanFile>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiFilterOption&&(identical(other.value, value) || other.value == value)&&(identical(other.label, label) || other.label == label));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiOrphanFile&&(identical(other.dir, dir) || other.dir == dir)&&(identical(other.fileName, fileName) || other.fileName == fileName)&&(identical(other.size, size) || other.size == size)&&(identical(other.modifiedAt, modifiedAt) || other.modifiedAt == modifiedAt)&&(identical(other.videoId, videoId) || other.videoId == videoId));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,value,label);
+int get hashCode => Object.hash(runtimeType,dir,fileName,size,modifiedAt,videoId);
 
 @override
 String toString() {
-  return 'ApiFilterOption(value: $value, label: $label)';
+  return 'ApiOrphanFile(dir: $dir, fileName: $fileName, size: $size, modifiedAt: $modifiedAt, videoId: $videoId)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiFilterOptionCopyWith<$Res> implements $ApiFilterOptionCopyWith<$Res> {
-  factory _$ApiFilterOptionCopyWith(_ApiFilterOption value, $Res Function(_ApiFilterOption) _then) = __$ApiFilterOptionCopyWithImpl;
+abstract mixin class _$ApiOrphanFileCopyWith<$Res> implements $ApiOrphanFileCopyWith<$Res> {
+  factory _$ApiOrphanFileCopyWith(_ApiOrphanFile value, $Res Function(_ApiOrphanFile) _then) = __$ApiOrphanFileCopyWithImpl;
 @override @useResult
 $Res call({
- String value, String label
+ String dir, String fileName, BigInt size, PlatformInt64? modifiedAt, String? videoId
 });
 
 
@@ -3325,20 +10071,23 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiFilterOptionCopyWithImpl<$Res>
-    implements _$ApiFilterOptionCopyWith<$Res> {
-  __$ApiFilterOptionCopyWithImpl(this._self, this._then);
+class __$ApiOrphanFileCopyWithImpl<$Res>
+    implements _$ApiOrphanFileCopyWith<$Res> {
+  __$ApiOrphanFileCopyWithImpl(this._self, this._then);
 
-  final _ApiFilterOption _self;
-  final $Res Function(_ApiFilterOption) _then;
+  final _ApiOrphanFile _self;
+  final $Res Function(_ApiOrphanFile) _then;
 
-/// Create a copy of ApiFilterOption
+/// Create a copy of ApiOrphanFile
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? value = null,Object? label = null,}) {
-  return _then(_ApiFilterOption(
-value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
-as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
-as String,
+@override @pragma('vm:prefer-inline') $Res call({Object? dir = null,Object? fileName = null,Object? size = null,Object? modifiedAt = freezed,Object? videoId = freezed,}) {
+  return _then(_ApiOrphanFile(
+dir: null == dir ? _self.dir : dir // ignore: cast_nullable_to_non_nullable
+as String,fileName: null == fileName ? _self.fileName : fileName // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,modifiedAt: freezed == modifiedAt ? _self.modifiedAt : modifiedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,videoId: freezed == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
@@ -3346,40 +10095,40 @@ as String,
 }
 
 /// @nodoc
-mixin _$ApiFilterOptions {
+mixin _$ApiPlayHistory {
 
- List<ApiFilterOption> get genres; List<ApiTagGroup> get tags; List<ApiFilterOption> get sorts; List<ApiFilterOption> get years; List<ApiFilterOption> get durations;
-/// Create a copy of ApiFilterOptions
+ String get videoId; String get title; String get coverUrl; double get progress; int get duration; PlatformInt64 get lastPlayedAt;
+/// Create a copy of ApiPlayHistory
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiFilterOptionsCopyWith<ApiFilterOptions> get copyWith => _$ApiFilterOptionsCopyWithImpl<ApiFilterOptions>(this as ApiFilterOptions, _$identity);
+$ApiPlayHistoryCopyWith<ApiPlayHistory> get copyWith => _$ApiPlayHistoryCopyWithImpl<ApiPlayHistory>(this as ApiPlayHistory, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiFilterOptions&&const DeepCollectionEquality().equals(other.genres, genres)&&const DeepCollectionEquality().equals(other.tags, tags)&&const DeepCollectionEquality().equals(other.sorts, sorts)&&const DeepCollectionEquality().equals(other.years, years)&&const DeepCollectionEquality().equals(other.durations, durations));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiPlayHistory&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.lastPlayedAt, lastPlayedAt) || other.lastPlayedAt == lastPlayedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(genres),const DeepCollectionEquality().hash(tags),const DeepCollectionEquality().hash(sorts),const DeepCollectionEquality().hash(years),const DeepCollectionEquality().hash(durations));
+int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,progress,duration,lastPlayedAt);
 
 @override
 String toString() {
-  return 'ApiFilterOptions(genres: $genres, tags: $tags, sorts: $sorts, years: $years, durations: $durations)';
+  return 'ApiPlayHistory(videoId: $videoId, title: $title, coverUrl: $coverUrl, progress: $progress, duration: $duration, lastPlayedAt: $lastPlayedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiFilterOptionsCopyWith<$Res>  {
-  factory $ApiFilterOptionsCopyWith(ApiFilterOptions value, $Res Function(ApiFilterOptions) _then) = _$ApiFilterOptionsCopyWithImpl;
+abstract mixin class $ApiPlayHistoryCopyWith<$Res>  {
+  factory $ApiPlayHistoryCopyWith(ApiPlayHistory value, $Res Function(ApiPlayHistory) _then) = _$ApiPlayHistoryCopyWithImpl;
 @useResult
 $Res call({
- List<ApiFilterOption> genres, List<ApiTagGroup> tags, List<ApiFilterOption> sorts, List<ApiFilterOption> years, List<ApiFilterOption> durations
+ String videoId, String title, String coverUrl, double progress, int duration, PlatformInt64 lastPlayedAt
 });
 
 
@@ -3387,31 +10136,32 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiFilterOptionsCopyWithImpl<$Res>
-    implements $ApiFilterOptionsCopyWith<$Res> {
-  _$ApiFilterOptionsCopyWithImpl(this._self, this._then);
+class _$ApiPlayHistoryCopyWithImpl<$Res>
+    implements $ApiPlayHistoryCopyWith<$Res> {
+  _$ApiPlayHistoryCopyWithImpl(this._self, this._then);
 
-  final ApiFilterOptions _self;
-  final $Res Function(ApiFilterOptions) _then;
+  final ApiPlayHistory _self;
+  final $Res Function(ApiPlayHistory) _then;
 
-/// Create a copy of ApiFilterOptions
+/// Create a copy of ApiPlayHistory
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? genres = null,Object? tags = null,Object? sorts = null,Object? years = null,Object? durations = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? progress = null,Object? duration = null,Object? lastPlayedAt = null,}) {
   return _then(_self.copyWith(
-genres: null == genres ? _self.genres : genres // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
-as List<ApiTagGroup>,sorts: null == sorts ? _self.sorts : sorts // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,years: null == years ? _self.years : years // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,durations: null == durations ? _self.durations : durations // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,duration: null == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as int,lastPlayedAt: null == lastPlayedAt ? _self.lastPlayedAt : lastPlayedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiFilterOptions].
-extension ApiFilterOptionsPatterns on ApiFilterOptions {
+/// Adds pattern-matching-related methods to [ApiPlayHistory].
+extension ApiPlayHistoryPatterns on ApiPlayHistory {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -3424,10 +10174,10 @@ extension ApiFilterOptionsPatterns on ApiFilterOptions {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiFilterOptions value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiPlayHistory value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiFilterOptions() when $default != null:
+case _ApiPlayHistory() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -3446,10 +10196,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiFilterOptions value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiPlayHistory value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiFilterOptions():
+case _ApiPlayHistory():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -3464,10 +10214,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiFilterOptions value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiPlayHistory value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiFilterOptions() when $default != null:
+case _ApiPlayHistory() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -3485,10 +10235,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiFilterOption> genres,  List<ApiTagGroup> tags,  List<ApiFilterOption> sorts,  List<ApiFilterOption> years,  List<ApiFilterOption> durations)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  double progress,  int duration,  PlatformInt64 lastPlayedAt)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiFilterOptions() when $default != null:
-return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations);case _:
+case _ApiPlayHistory() when $default != null:
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.duration,_that.lastPlayedAt);case _:
   return orElse();
 
 }
@@ -3506,10 +10256,10 @@ return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations)
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiFilterOption> genres,  List<ApiTagGroup> tags,  List<ApiFilterOption> sorts,  List<ApiFilterOption> years,  List<ApiFilterOption> durations)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  double progress,  int duration,  PlatformInt64 lastPlayedAt)  $default,) {final _that = this;
 switch (_that) {
-case _ApiFilterOptions():
-return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations);}
+case _ApiPlayHistory():
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.duration,_that.lastPlayedAt);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -3523,10 +10273,10 @@ return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations)
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiFilterOption> genres,  List<ApiTagGroup> tags,  List<ApiFilterOption> sorts,  List<ApiFilterOption> years,  List<ApiFilterOption> durations)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  String coverUrl,  double progress,  int duration,  PlatformInt64 lastPlayedAt)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiFilterOptions() when $default != null:
-return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations);case _:
+case _ApiPlayHistory() when $default != null:
+return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.duration,_that.lastPlayedAt);case _:
   return null;
 
 }
@@ -3537,77 +10287,48 @@ return $default(_that.genres,_that.tags,_that.sorts,_that.years,_that.durations)
 /// @nodoc
 
 
-class _ApiFilterOptions implements ApiFilterOptions {
-  const _ApiFilterOptions({required final  List<ApiFilterOption> genres, required final  List<ApiTagGroup> tags, required final  List<ApiFilterOption> sorts, required final  List<ApiFilterOption> years, required final  List<ApiFilterOption> durations}): _genres = genres,_tags = tags,_sorts = sorts,_years = years,_durations = durations;
+class _ApiPlayHistory implements ApiPlayHistory {
+  const _ApiPlayHistory({required this.videoId, required this.title, required this.coverUrl, required this.progress, required this.duration, required this.lastPlayedAt});
   
 
- final  List<ApiFilterOption> _genres;
-@override List<ApiFilterOption> get genres {
-  if (_genres is EqualUnmodifiableListView) return _genres;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_genres);
-}
-
- final  List<ApiTagGroup> _tags;
-@override List<ApiTagGroup> get tags {
-  if (_tags is EqualUnmodifiableListView) return _tags;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_tags);
-}
-
- final  List<ApiFilterOption> _sorts;
-@override List<ApiFilterOption> get sorts {
-  if (_sorts is EqualUnmodifiableListView) return _sorts;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_sorts);
-}
-
- final  List<ApiFilterOption> _years;
-@override List<ApiFilterOption> get years {
-  if (_years is EqualUnmodifiableListView) return _years;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_years);
-}
-
- final  List<ApiFilterOption> _durations;
-@override List<ApiFilterOption> get durations {
-  if (_durations is EqualUnmodifiableListView) return _durations;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_durations);
-}
-
+@override final  String videoId;
+@override final  String title;
+@override final  String coverUrl;
+@override final  double progress;
+@override final  int duration;
+@override final  PlatformInt64 lastPlayedAt;
 
-/// Create a copy of ApiFilterOptions
+/// Create a copy of ApiPlayHistory
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiFilterOptionsCopyWith<_ApiFilterOptions> get copyWith => __$ApiFilterOptionsCopyWithImpl<_ApiFilterOptions>(this, _$identity);
+_$ApiPlayHistoryCopyWith<_ApiPlayHistory> get copyWith => __$ApiPlayHistoryCopyWithImpl<_ApiPlayHistory>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiFilterOptions&&const DeepCollectionEquality().equals(other._genres, _genres)&&const DeepCollectionEquality().equals(other._tags, _tags)&&const DeepCollectionEquality().equals(other._sorts, _sorts)&&const DeepCollectionEquality().equals(other._years, _years)&&const DeepCollectionEquality().equals(other._durations, _durations));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiPlayHistory&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.lastPlayedAt, lastPlayedAt) || other.lastPlayedAt == lastPlayedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_genres),const DeepCollectionEquality().hash(_tags),const DeepCollectionEquality().hash(_sorts),const DeepCollectionEquality().hash(_years),const DeepCollectionEquality().hash(_durations));
+int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,progress,duration,lastPlayedAt);
 
 @override
 String toString() {
-  return 'ApiFilterOptions(genres: $genres, tags: $tags, sorts: $sorts, years: $years, durations: $durations)';
+  return 'ApiPlayHistory(videoId: $videoId, title: $title, coverUrl: $coverUrl, progress: $progress, duration: $duration, lastPlayedAt: $lastPlayedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiFilterOptionsCopyWith<$Res> implements $ApiFilterOptionsCopyWith<$Res> {
-  factory _$ApiFilterOptionsCopyWith(_ApiFilterOptions value, $Res Function(_ApiFilterOptions) _then) = __$ApiFilterOptionsCopyWithImpl;
+abstract mixin class _$ApiPlayHistoryCopyWith<$Res> implements $ApiPlayHistoryCopyWith<$Res> {
+  factory _$ApiPlayHistoryCopyWith(_ApiPlayHistory value, $Res Function(_ApiPlayHistory) _then) = __$ApiPlayHistoryCopyWithImpl;
 @override @useResult
 $Res call({
- List<ApiFilterOption> genres, List<ApiTagGroup> tags, List<ApiFilterOption> sorts, List<ApiFilterOption> years, List<ApiFilterOption> durations
+ String videoId, String title, String coverUrl, double progress, int duration, PlatformInt64 lastPlayedAt
 });
 
 
@@ -3615,23 +10336,24 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiFilterOptionsCopyWithImpl<$Res>
-    implements _$ApiFilterOptionsCopyWith<$Res> {
-  __$ApiFilterOptionsCopyWithImpl(this._self, this._then);
+class __$ApiPlayHistoryCopyWithImpl<$Res>
+    implements _$ApiPlayHistoryCopyWith<$Res> {
+  __$ApiPlayHistoryCopyWithImpl(this._self, this._then);
 
-  final _ApiFilterOptions _self;
-  final $Res Function(_ApiFilterOptions) _then;
+  final _ApiPlayHistory _self;
+  final $Res Function(_ApiPlayHistory) _then;
 
-/// Create a copy of ApiFilterOptions
+/// Create a copy of ApiPlayHistory
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? genres = null,Object? tags = null,Object? sorts = null,Object? years = null,Object? durations = null,}) {
-  return _then(_ApiFilterOptions(
-genres: null == genres ? _self._genres : genres // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
-as List<ApiTagGroup>,sorts: null == sorts ? _self._sorts : sorts // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,years: null == years ? _self._years : years // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,durations: null == durations ? _self._durations : durations // ignore: cast_nullable_to_non_nullable
-as List<ApiFilterOption>,
+@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? progress = null,Object? duration = null,Object? lastPlayedAt = null,}) {
+  return _then(_ApiPlayHistory(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double,duration: null == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as int,lastPlayedAt: null == lastPlayedAt ? _self.lastPlayedAt : lastPlayedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
   ));
 }
 
@@ -3639,86 +10361,71 @@ as List<ApiFilterOption>,
 }
 
 /// @nodoc
-mixin _$ApiHomePage {
+mixin _$ApiPlayHistoryList {
 
- String? get formToken; String? get avatarUrl; String? get username; ApiBanner? get banner; List<ApiVideoCard> get latestRelease; List<ApiVideoCard> get latestUpload; List<ApiHomeSection> get sections;
-/// Create a copy of ApiHomePage
+ List<ApiPlayHistory> get items; int get total; int get page; bool get hasNext;
+/// Create a copy of ApiPlayHistoryList
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiHomePageCopyWith<ApiHomePage> get copyWith => _$ApiHomePageCopyWithImpl<ApiHomePage>(this as ApiHomePage, _$identity);
+$ApiPlayHistoryListCopyWith<ApiPlayHistoryList> get copyWith => _$ApiPlayHistoryListCopyWithImpl<ApiPlayHistoryList>(this as ApiPlayHistoryList, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiHomePage&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.avatarUrl, avatarUrl) || other.avatarUrl == avatarUrl)&&(identical(other.username, username) || other.username == username)&&(identical(other.banner, banner) || other.banner == banner)&&const DeepCollectionEquality().equals(other.latestRelease, latestRelease)&&const DeepCollectionEquality().equals(other.latestUpload, latestUpload)&&const DeepCollectionEquality().equals(other.sections, sections));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiPlayHistoryList&&const DeepCollectionEquality().equals(other.items, items)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,formToken,avatarUrl,username,banner,const DeepCollectionEquality().hash(latestRelease),const DeepCollectionEquality().hash(latestUpload),const DeepCollectionEquality().hash(sections));
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(items),total,page,hasNext);
 
 @override
 String toString() {
-  return 'ApiHomePage(formToken: $formToken, avatarUrl: $avatarUrl, username: $username, banner: $banner, latestRelease: $latestRelease, latestUpload: $latestUpload, sections: $sections)';
+  return 'ApiPlayHistoryList(items: $items, total: $total, page: $page, hasNext: $hasNext)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiHomePageCopyWith<$Res>  {
-  factory $ApiHomePageCopyWith(ApiHomePage value, $Res Function(ApiHomePage) _then) = _$ApiHomePageCopyWithImpl;
+abstract mixin class $ApiPlayHistoryListCopyWith<$Res>  {
+  factory $ApiPlayHistoryListCopyWith(ApiPlayHistoryList value, $Res Function(ApiPlayHistoryList) _then) = _$ApiPlayHistoryListCopyWithImpl;
 @useResult
 $Res call({
- String? formToken, String? avatarUrl, String? username, ApiBanner? banner, List<ApiVideoCard> latestRelease, List<ApiVideoCard> latestUpload, List<ApiHomeSection> sections
+ List<ApiPlayHistory> items, int total, int page, bool hasNext
 });
 
 
-$ApiBannerCopyWith<$Res>? get banner;
+
 
 }
 /// @nodoc
-class _$ApiHomePageCopyWithImpl<$Res>
-    implements $ApiHomePageCopyWith<$Res> {
-  _$ApiHomePageCopyWithImpl(this._self, this._then);
+class _$ApiPlayHistoryListCopyWithImpl<$Res>
+    implements $ApiPlayHistoryListCopyWith<$Res> {
+  _$ApiPlayHistoryListCopyWithImpl(this._self, this._then);
 
-  final ApiHomePage _self;
-  final $Res Function(ApiHomePage) _then;
+  final ApiPlayHistoryList _self;
+  final $Res Function(ApiPlayHistoryList) _then;
 
-/// Create a copy of ApiHomePage
+/// Create a copy of ApiPlayHistoryList
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? formToken = freezed,Object? avatarUrl = freezed,Object? username = freezed,Object? banner = freezed,Object? latestRelease = null,Object? latestUpload = null,Object? sections = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? items = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
   return _then(_self.copyWith(
-formToken: freezed == formToken ? _self.formToken : formToken // ignore: cast_nullable_to_non_nullable
-as String?,avatarUrl: freezed == avatarUrl ? _self.avatarUrl : avatarUrl // ignore: cast_nullable_to_non_nullable
-as String?,username: freezed == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
-as String?,banner: freezed == banner ? _self.banner : banner // ignore: cast_nullable_to_non_nullable
-as ApiBanner?,latestRelease: null == latestRelease ? _self.latestRelease : latestRelease // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,latestUpload: null == latestUpload ? _self.latestUpload : latestUpload // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,sections: null == sections ? _self.sections : sections // ignore: cast_nullable_to_non_nullable
-as List<ApiHomeSection>,
+items: null == items ? _self.items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiPlayHistory>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
-/// Create a copy of ApiHomePage
-/// with the given fields replaced by the non-null parameter values.
-@override
-@pragma('vm:prefer-inline')
-$ApiBannerCopyWith<$Res>? get banner {
-    if (_self.banner == null) {
-    return null;
-  }
 
-  return $ApiBannerCopyWith<$Res>(_self.banner!, (value) {
-    return _then(_self.copyWith(banner: value));
-  });
-}
 }
 
 
-/// Adds pattern-matching-related methods to [ApiHomePage].
-extension ApiHomePagePatterns on ApiHomePage {
+/// Adds pattern-matching-related methods to [ApiPlayHistoryList].
+extension ApiPlayHistoryListPatterns on ApiPlayHistoryList {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -3731,10 +10438,10 @@ extension ApiHomePagePatterns on ApiHomePage {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiHomePage value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiPlayHistoryList value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiHomePage() when $default != null:
+case _ApiPlayHistoryList() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -3753,10 +10460,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiHomePage value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiPlayHistoryList value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiHomePage():
+case _ApiPlayHistoryList():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -3771,10 +10478,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiHomePage value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiPlayHistoryList value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiHomePage() when $default != null:
+case _ApiPlayHistoryList() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -3792,10 +10499,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? formToken,  String? avatarUrl,  String? username,  ApiBanner? banner,  List<ApiVideoCard> latestRelease,  List<ApiVideoCard> latestUpload,  List<ApiHomeSection> sections)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiPlayHistory> items,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiHomePage() when $default != null:
-return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_that.latestRelease,_that.latestUpload,_that.sections);case _:
+case _ApiPlayHistoryList() when $default != null:
+return $default(_that.items,_that.total,_that.page,_that.hasNext);case _:
   return orElse();
 
 }
@@ -3813,10 +10520,10 @@ return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_tha
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? formToken,  String? avatarUrl,  String? username,  ApiBanner? banner,  List<ApiVideoCard> latestRelease,  List<ApiVideoCard> latestUpload,  List<ApiHomeSection> sections)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiPlayHistory> items,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
 switch (_that) {
-case _ApiHomePage():
-return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_that.latestRelease,_that.latestUpload,_that.sections);}
+case _ApiPlayHistoryList():
+return $default(_that.items,_that.total,_that.page,_that.hasNext);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -3830,10 +10537,10 @@ return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_tha
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? formToken,  String? avatarUrl,  String? username,  ApiBanner? banner,  List<ApiVideoCard> latestRelease,  List<ApiVideoCard> latestUpload,  List<ApiHomeSection> sections)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiPlayHistory> items,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiHomePage() when $default != null:
-return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_that.latestRelease,_that.latestUpload,_that.sections);case _:
+case _ApiPlayHistoryList() when $default != null:
+return $default(_that.items,_that.total,_that.page,_that.hasNext);case _:
   return null;
 
 }
@@ -3844,126 +10551,96 @@ return $default(_that.formToken,_that.avatarUrl,_that.username,_that.banner,_tha
 /// @nodoc
 
 
-class _ApiHomePage implements ApiHomePage {
-  const _ApiHomePage({this.formToken, this.avatarUrl, this.username, this.banner, required final  List<ApiVideoCard> latestRelease, required final  List<ApiVideoCard> latestUpload, required final  List<ApiHomeSection> sections}): _latestRelease = latestRelease,_latestUpload = latestUpload,_sections = sections;
+class _ApiPlayHistoryList implements ApiPlayHistoryList {
+  const _ApiPlayHistoryList({required final  List<ApiPlayHistory> items, required this.total, required this.page, required this.hasNext}): _items = items;
   
 
-@override final  String? formToken;
-@override final  String? avatarUrl;
-@override final  String? username;
-@override final  ApiBanner? banner;
- final  List<ApiVideoCard> _latestRelease;
-@override List<ApiVideoCard> get latestRelease {
-  if (_latestRelease is EqualUnmodifiableListView) return _latestRelease;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_latestRelease);
-}
-
- final  List<ApiVideoCard> _latestUpload;
-@override List<ApiVideoCard> get latestUpload {
-  if (_latestUpload is EqualUnmodifiableListView) return _latestUpload;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_latestUpload);
-}
-
- final  List<ApiHomeSection> _sections;
-@override List<ApiHomeSection> get sections {
-  if (_sections is EqualUnmodifiableListView) return _sections;
+ final  List<ApiPlayHistory> _items;
+@override List<ApiPlayHistory> get items {
+  if (_items is EqualUnmodifiableListView) return _items;
   // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_sections);
+  return EqualUnmodifiableListView(_items);
 }
 
+@override final  int total;
+@override final  int page;
+@override final  bool hasNext;
 
-/// Create a copy of ApiHomePage
+/// Create a copy of ApiPlayHistoryList
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiHomePageCopyWith<_ApiHomePage> get copyWith => __$ApiHomePageCopyWithImpl<_ApiHomePage>(this, _$identity);
+_$ApiPlayHistoryListCopyWith<_ApiPlayHistoryList> get copyWith => __$ApiPlayHistoryListCopyWithImpl<_ApiPlayHistoryList>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiHomePage&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.avatarUrl, avatarUrl) || other.avatarUrl == avatarUrl)&&(identical(other.username, username) || other.username == username)&&(identical(other.banner, banner) || other.banner == banner)&&const DeepCollectionEquality().equals(other._latestRelease, _latestRelease)&&const DeepCollectionEquality().equals(other._latestUpload, _latestUpload)&&const DeepCollectionEquality().equals(other._sections, _sections));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiPlayHistoryList&&const DeepCollectionEquality().equals(other._items, _items)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,formToken,avatarUrl,username,banner,const DeepCollectionEquality().hash(_latestRelease),const DeepCollectionEquality().hash(_latestUpload),const DeepCollectionEquality().hash(_sections));
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_items),total,page,hasNext);
 
 @override
 String toString() {
-  return 'ApiHomePage(formToken: $formToken, avatarUrl: $avatarUrl, username: $username, banner: $banner, latestRelease: $latestRelease, latestUpload: $latestUpload, sections: $sections)';
+  return 'ApiPlayHistoryList(items: $items, total: $total, page: $page, hasNext: $hasNext)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiHomePageCopyWith<$Res> implements $ApiHomePageCopyWith<$Res> {
-  factory _$ApiHomePageCopyWith(_ApiHomePage value, $Res Function(_ApiHomePage) _then) = __$ApiHomePageCopyWithImpl;
+abstract mixin class _$ApiPlayHistoryListCopyWith<$Res> implements $ApiPlayHistoryListCopyWith<$Res> {
+  factory _$ApiPlayHistoryListCopyWith(_ApiPlayHistoryList value, $Res Function(_ApiPlayHistoryList) _then) = __$ApiPlayHistoryListCopyWithImpl;
 @override @useResult
 $Res call({
- String? formToken, String? avatarUrl, String? username, ApiBanner? banner, List<ApiVideoCard> latestRelease, List<ApiVideoCard> latestUpload, List<ApiHomeSection> sections
+ List<ApiPlayHistory> items, int total, int page, bool hasNext
 });
 
 
-@override $ApiBannerCopyWith<$Res>? get banner;
+
 
 }
 /// @nodoc
-class __$ApiHomePageCopyWithImpl<$Res>
-    implements _$ApiHomePageCopyWith<$Res> {
-  __$ApiHomePageCopyWithImpl(this._self, this._then);
+class __$ApiPlayHistoryListCopyWithImpl<$Res>
+    implements _$ApiPlayHistoryListCopyWith<$Res> {
+  __$ApiPlayHistoryListCopyWithImpl(this._self, this._then);
 
-  final _ApiHomePage _self;
-  final $Res Function(_ApiHomePage) _then;
+  final _ApiPlayHistoryList _self;
+  final $Res Function(_ApiPlayHistoryList) _then;
 
-/// Create a copy of ApiHomePage
+/// Create a copy of ApiPlayHistoryList
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? formToken = freezed,Object? avatarUrl = freezed,Object? username = freezed,Object? banner = freezed,Object? latestRelease = null,Object? latestUpload = null,Object? sections = null,}) {
-  return _then(_ApiHomePage(
-formToken: freezed == formToken ? _self.formToken : formToken // ignore: cast_nullable_to_non_nullable
-as String?,avatarUrl: freezed == avatarUrl ? _self.avatarUrl : avatarUrl // ignore: cast_nullable_to_non_nullable
-as String?,username: freezed == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
-as String?,banner: freezed == banner ? _self.banner : banner // ignore: cast_nullable_to_non_nullable
-as ApiBanner?,latestRelease: null == latestRelease ? _self._latestRelease : latestRelease // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,latestUpload: null == latestUpload ? _self._latestUpload : latestUpload // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,sections: null == sections ? _self._sections : sections // ignore: cast_nullable_to_non_nullable
-as List<ApiHomeSection>,
+@override @pragma('vm:prefer-inline') $Res call({Object? items = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_ApiPlayHistoryList(
+items: null == items ? _self._items : items // ignore: cast_nullable_to_non_nullable
+as List<ApiPlayHistory>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
-/// Create a copy of ApiHomePage
-/// with the given fields replaced by the non-null parameter values.
-@override
-@pragma('vm:prefer-inline')
-$ApiBannerCopyWith<$Res>? get banner {
-    if (_self.banner == null) {
-    return null;
-  }
 
-  return $ApiBannerCopyWith<$Res>(_self.banner!, (value) {
-    return _then(_self.copyWith(banner: value));
-  });
-}
 }
 
 /// @nodoc
-mixin _$ApiHomeSection {
+mixin _$ApiPlaylistInfo {
 
- String get name; List<ApiVideoCard> get videos;
-/// Create a copy of ApiHomeSection
+ String? get name; List<ApiVideoCard> get videos;
+/// Create a copy of ApiPlaylistInfo
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiHomeSectionCopyWith<ApiHomeSection> get copyWith => _$ApiHomeSectionCopyWithImpl<ApiHomeSection>(this as ApiHomeSection, _$identity);
+$ApiPlaylistInfoCopyWith<ApiPlaylistInfo> get copyWith => _$ApiPlaylistInfoCopyWithImpl<ApiPlaylistInfo>(this as ApiPlaylistInfo, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiHomeSection&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other.videos, videos));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiPlaylistInfo&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other.videos, videos));
 }
 
 
@@ -3972,18 +10649,18 @@ int get hashCode => Object.hash(runtimeType,name,const DeepCollectionEquality().
 
 @override
 String toString() {
-  return 'ApiHomeSection(name: $name, videos: $videos)';
+  return 'ApiPlaylistInfo(name: $name, videos: $videos)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiHomeSectionCopyWith<$Res>  {
-  factory $ApiHomeSectionCopyWith(ApiHomeSection value, $Res Function(ApiHomeSection) _then) = _$ApiHomeSectionCopyWithImpl;
+abstract mixin class $ApiPlaylistInfoCopyWith<$Res>  {
+  factory $ApiPlaylistInfoCopyWith(ApiPlaylistInfo value, $Res Function(ApiPlaylistInfo) _then) = _$ApiPlaylistInfoCopyWithImpl;
 @useResult
 $Res call({
- String name, List<ApiVideoCard> videos
+ String? name, List<ApiVideoCard> videos
 });
 
 
@@ -3991,19 +10668,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiHomeSectionCopyWithImpl<$Res>
-    implements $ApiHomeSectionCopyWith<$Res> {
-  _$ApiHomeSectionCopyWithImpl(this._self, this._then);
+class _$ApiPlaylistInfoCopyWithImpl<$Res>
+    implements $ApiPlaylistInfoCopyWith<$Res> {
+  _$ApiPlaylistInfoCopyWithImpl(this._self, this._then);
 
-  final ApiHomeSection _self;
-  final $Res Function(ApiHomeSection) _then;
+  final ApiPlaylistInfo _self;
+  final $Res Function(ApiPlaylistInfo) _then;
 
-/// Create a copy of ApiHomeSection
+/// Create a copy of ApiPlaylistInfo
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? name = null,Object? videos = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? name = freezed,Object? videos = null,}) {
   return _then(_self.copyWith(
-name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
+name: freezed == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String?,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
 as List<ApiVideoCard>,
   ));
 }
@@ -4011,8 +10688,8 @@ as List<ApiVideoCard>,
 }
 
 
-/// Adds pattern-matching-related methods to [ApiHomeSection].
-extension ApiHomeSectionPatterns on ApiHomeSection {
+/// Adds pattern-matching-related methods to [ApiPlaylistInfo].
+extension ApiPlaylistInfoPatterns on ApiPlaylistInfo {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -4025,10 +10702,10 @@ extension ApiHomeSectionPatterns on ApiHomeSection {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiHomeSection value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiPlaylistInfo value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiHomeSection() when $default != null:
+case _ApiPlaylistInfo() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -4047,10 +10724,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiHomeSection value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiPlaylistInfo value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiHomeSection():
+case _ApiPlaylistInfo():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -4065,10 +10742,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiHomeSection value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiPlaylistInfo value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiHomeSection() when $default != null:
+case _ApiPlaylistInfo() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -4086,9 +10763,9 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String name,  List<ApiVideoCard> videos)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? name,  List<ApiVideoCard> videos)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiHomeSection() when $default != null:
+case _ApiPlaylistInfo() when $default != null:
 return $default(_that.name,_that.videos);case _:
   return orElse();
 
@@ -4107,9 +10784,9 @@ return $default(_that.name,_that.videos);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String name,  List<ApiVideoCard> videos)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? name,  List<ApiVideoCard> videos)  $default,) {final _that = this;
 switch (_that) {
-case _ApiHomeSection():
+case _ApiPlaylistInfo():
 return $default(_that.name,_that.videos);}
 }
 /// A variant of `when` that fallback to returning `null`
@@ -4124,9 +10801,9 @@ return $default(_that.name,_that.videos);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String name,  List<ApiVideoCard> videos)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? name,  List<ApiVideoCard> videos)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiHomeSection() when $default != null:
+case _ApiPlaylistInfo() when $default != null:
 return $default(_that.name,_that.videos);case _:
   return null;
 
@@ -4138,11 +10815,11 @@ return $default(_that.name,_that.videos);case _:
 /// @nodoc
 
 
-class _ApiHomeSection implements ApiHomeSection {
-  const _ApiHomeSection({required this.name, required final  List<ApiVideoCard> videos}): _videos = videos;
+class _ApiPlaylistInfo implements ApiPlaylistInfo {
+  const _ApiPlaylistInfo({this.name, required final  List<ApiVideoCard> videos}): _videos = videos;
   
 
-@override final  String name;
+@override final  String? name;
  final  List<ApiVideoCard> _videos;
 @override List<ApiVideoCard> get videos {
   if (_videos is EqualUnmodifiableListView) return _videos;
@@ -4151,17 +10828,17 @@ class _ApiHomeSection implements ApiHomeSection {
 }
 
 
-/// Create a copy of ApiHomeSection
+/// Create a copy of ApiPlaylistInfo
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiHomeSectionCopyWith<_ApiHomeSection> get copyWith => __$ApiHomeSectionCopyWithImpl<_ApiHomeSection>(this, _$identity);
+_$ApiPlaylistInfoCopyWith<_ApiPlaylistInfo> get copyWith => __$ApiPlaylistInfoCopyWithImpl<_ApiPlaylistInfo>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiHomeSection&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other._videos, _videos));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiPlaylistInfo&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other._videos, _videos));
 }
 
 
@@ -4170,18 +10847,18 @@ int get hashCode => Object.hash(runtimeType,name,const DeepCollectionEquality().
 
 @override
 String toString() {
-  return 'ApiHomeSection(name: $name, videos: $videos)';
+  return 'ApiPlaylistInfo(name: $name, videos: $videos)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiHomeSectionCopyWith<$Res> implements $ApiHomeSectionCopyWith<$Res> {
-  factory _$ApiHomeSectionCopyWith(_ApiHomeSection value, $Res Function(_ApiHomeSection) _then) = __$ApiHomeSectionCopyWithImpl;
+abstract mixin class _$ApiPlaylistInfoCopyWith<$Res> implements $ApiPlaylistInfoCopyWith<$Res> {
+  factory _$ApiPlaylistInfoCopyWith(_ApiPlaylistInfo value, $Res Function(_ApiPlaylistInfo) _then) = __$ApiPlaylistInfoCopyWithImpl;
 @override @useResult
 $Res call({
- String name, List<ApiVideoCard> videos
+ String? name, List<ApiVideoCard> videos
 });
 
 
@@ -4189,19 +10866,19 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiHomeSectionCopyWithImpl<$Res>
-    implements _$ApiHomeSectionCopyWith<$Res> {
-  __$ApiHomeSectionCopyWithImpl(this._self, this._then);
+class __$ApiPlaylistInfoCopyWithImpl<$Res>
+    implements _$ApiPlaylistInfoCopyWith<$Res> {
+  __$ApiPlaylistInfoCopyWithImpl(this._self, this._then);
 
-  final _ApiHomeSection _self;
-  final $Res Function(_ApiHomeSection) _then;
+  final _ApiPlaylistInfo _self;
+  final $Res Function(_ApiPlaylistInfo) _then;
 
-/// Create a copy of ApiHomeSection
+/// Create a copy of ApiPlaylistInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? name = null,Object? videos = null,}) {
-  return _then(_ApiHomeSection(
-name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? name = freezed,Object? videos = null,}) {
+  return _then(_ApiPlaylistInfo(
+name: freezed == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String?,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
 as List<ApiVideoCard>,
   ));
 }
@@ -4210,40 +10887,40 @@ as List<ApiVideoCard>,
 }
 
 /// @nodoc
-mixin _$ApiMyListInfo {
+mixin _$ApiQuotaBlockedEvent {
 
- bool get isWatchLater; List<ApiMyListItem> get items;
-/// Create a copy of ApiMyListInfo
+ String get videoId; String get title; BigInt get requiredBytes; BigInt get usedBytes; BigInt get quotaBytes; BigInt get freedBytes; PlatformInt64 get blockedAt;
+/// Create a copy of ApiQuotaBlockedEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiMyListInfoCopyWith<ApiMyListInfo> get copyWith => _$ApiMyListInfoCopyWithImpl<ApiMyListInfo>(this as ApiMyListInfo, _$identity);
+$ApiQuotaBlockedEventCopyWith<ApiQuotaBlockedEvent> get copyWith => _$ApiQuotaBlockedEventCopyWithImpl<ApiQuotaBlockedEvent>(this as ApiQuotaBlockedEvent, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiMyListInfo&&(identical(other.isWatchLater, isWatchLater) || other.isWatchLater == isWatchLater)&&const DeepCollectionEquality().equals(other.items, items));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiQuotaBlockedEvent&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.requiredBytes, requiredBytes) || other.requiredBytes == requiredBytes)&&(identical(other.usedBytes, usedBytes) || other.usedBytes == usedBytes)&&(identical(other.quotaBytes, quotaBytes) || other.quotaBytes == quotaBytes)&&(identical(other.freedBytes, freedBytes) || other.freedBytes == freedBytes)&&(identical(other.blockedAt, blockedAt) || other.blockedAt == blockedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,isWatchLater,const DeepCollectionEquality().hash(items));
+int get hashCode => Object.hash(runtimeType,videoId,title,requiredBytes,usedBytes,quotaBytes,freedBytes,blockedAt);
 
 @override
 String toString() {
-  return 'ApiMyListInfo(isWatchLater: $isWatchLater, items: $items)';
+  return 'ApiQuotaBlockedEvent(videoId: $videoId, title: $title, requiredBytes: $requiredBytes, usedBytes: $usedBytes, quotaBytes: $quotaBytes, freedBytes: $freedBytes, blockedAt: $blockedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiMyListInfoCopyWith<$Res>  {
-  factory $ApiMyListInfoCopyWith(ApiMyListInfo value, $Res Function(ApiMyListInfo) _then) = _$ApiMyListInfoCopyWithImpl;
+abstract mixin class $ApiQuotaBlockedEventCopyWith<$Res>  {
+  factory $ApiQuotaBlockedEventCopyWith(ApiQuotaBlockedEvent value, $Res Function(ApiQuotaBlockedEvent) _then) = _$ApiQuotaBlockedEventCopyWithImpl;
 @useResult
 $Res call({
- bool isWatchLater, List<ApiMyListItem> items
+ String videoId, String title, BigInt requiredBytes, BigInt usedBytes, BigInt quotaBytes, BigInt freedBytes, PlatformInt64 blockedAt
 });
 
 
@@ -4251,28 +10928,33 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiMyListInfoCopyWithImpl<$Res>
-    implements $ApiMyListInfoCopyWith<$Res> {
-  _$ApiMyListInfoCopyWithImpl(this._self, this._then);
+class _$ApiQuotaBlockedEventCopyWithImpl<$Res>
+    implements $ApiQuotaBlockedEventCopyWith<$Res> {
+  _$ApiQuotaBlockedEventCopyWithImpl(this._self, this._then);
 
-  final ApiMyListInfo _self;
-  final $Res Function(ApiMyListInfo) _then;
+  final ApiQuotaBlockedEvent _self;
+  final $Res Function(ApiQuotaBlockedEvent) _then;
 
-/// Create a copy of ApiMyListInfo
+/// Create a copy of ApiQuotaBlockedEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? isWatchLater = null,Object? items = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? requiredBytes = null,Object? usedBytes = null,Object? quotaBytes = null,Object? freedBytes = null,Object? blockedAt = null,}) {
   return _then(_self.copyWith(
-isWatchLater: null == isWatchLater ? _self.isWatchLater : isWatchLater // ignore: cast_nullable_to_non_nullable
-as bool,items: null == items ? _self.items : items // ignore: cast_nullable_to_non_nullable
-as List<ApiMyListItem>,
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,requiredBytes: null == requiredBytes ? _self.requiredBytes : requiredBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,usedBytes: null == usedBytes ? _self.usedBytes : usedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,quotaBytes: null == quotaBytes ? _self.quotaBytes : quotaBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,freedBytes: null == freedBytes ? _self.freedBytes : freedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,blockedAt: null == blockedAt ? _self.blockedAt : blockedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiMyListInfo].
-extension ApiMyListInfoPatterns on ApiMyListInfo {
+/// Adds pattern-matching-related methods to [ApiQuotaBlockedEvent].
+extension ApiQuotaBlockedEventPatterns on ApiQuotaBlockedEvent {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -4285,10 +10967,10 @@ extension ApiMyListInfoPatterns on ApiMyListInfo {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiMyListInfo value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiQuotaBlockedEvent value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiMyListInfo() when $default != null:
+case _ApiQuotaBlockedEvent() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -4307,10 +10989,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiMyListInfo value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiQuotaBlockedEvent value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiMyListInfo():
+case _ApiQuotaBlockedEvent():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -4325,10 +11007,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiMyListInfo value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiQuotaBlockedEvent value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiMyListInfo() when $default != null:
+case _ApiQuotaBlockedEvent() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -4346,10 +11028,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool isWatchLater,  List<ApiMyListItem> items)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  BigInt requiredBytes,  BigInt usedBytes,  BigInt quotaBytes,  BigInt freedBytes,  PlatformInt64 blockedAt)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiMyListInfo() when $default != null:
-return $default(_that.isWatchLater,_that.items);case _:
+case _ApiQuotaBlockedEvent() when $default != null:
+return $default(_that.videoId,_that.title,_that.requiredBytes,_that.usedBytes,_that.quotaBytes,_that.freedBytes,_that.blockedAt);case _:
   return orElse();
 
 }
@@ -4367,10 +11049,10 @@ return $default(_that.isWatchLater,_that.items);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool isWatchLater,  List<ApiMyListItem> items)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  BigInt requiredBytes,  BigInt usedBytes,  BigInt quotaBytes,  BigInt freedBytes,  PlatformInt64 blockedAt)  $default,) {final _that = this;
 switch (_that) {
-case _ApiMyListInfo():
-return $default(_that.isWatchLater,_that.items);}
+case _ApiQuotaBlockedEvent():
+return $default(_that.videoId,_that.title,_that.requiredBytes,_that.usedBytes,_that.quotaBytes,_that.freedBytes,_that.blockedAt);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -4384,10 +11066,10 @@ return $default(_that.isWatchLater,_that.items);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool isWatchLater,  List<ApiMyListItem> items)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  BigInt requiredBytes,  BigInt usedBytes,  BigInt quotaBytes,  BigInt freedBytes,  PlatformInt64 blockedAt)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiMyListInfo() when $default != null:
-return $default(_that.isWatchLater,_that.items);case _:
+case _ApiQuotaBlockedEvent() when $default != null:
+return $default(_that.videoId,_that.title,_that.requiredBytes,_that.usedBytes,_that.quotaBytes,_that.freedBytes,_that.blockedAt);case _:
   return null;
 
 }
@@ -4395,53 +11077,52 @@ return $default(_that.isWatchLater,_that.items);case _:
 
 }
 
-/// @nodoc
-
-
-class _ApiMyListInfo implements ApiMyListInfo {
-  const _ApiMyListInfo({required this.isWatchLater, required final  List<ApiMyListItem> items}): _items = items;
-  
-
-@override final  bool isWatchLater;
- final  List<ApiMyListItem> _items;
-@override List<ApiMyListItem> get items {
-  if (_items is EqualUnmodifiableListView) return _items;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_items);
-}
+/// @nodoc
 
 
-/// Create a copy of ApiMyListInfo
+class _ApiQuotaBlockedEvent implements ApiQuotaBlockedEvent {
+  const _ApiQuotaBlockedEvent({required this.videoId, required this.title, required this.requiredBytes, required this.usedBytes, required this.quotaBytes, required this.freedBytes, required this.blockedAt});
+  
+
+@override final  String videoId;
+@override final  String title;
+@override final  BigInt requiredBytes;
+@override final  BigInt usedBytes;
+@override final  BigInt quotaBytes;
+@override final  BigInt freedBytes;
+@override final  PlatformInt64 blockedAt;
+
+/// Create a copy of ApiQuotaBlockedEvent
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiMyListInfoCopyWith<_ApiMyListInfo> get copyWith => __$ApiMyListInfoCopyWithImpl<_ApiMyListInfo>(this, _$identity);
+_$ApiQuotaBlockedEventCopyWith<_ApiQuotaBlockedEvent> get copyWith => __$ApiQuotaBlockedEventCopyWithImpl<_ApiQuotaBlockedEvent>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiMyListInfo&&(identical(other.isWatchLater, isWatchLater) || other.isWatchLater == isWatchLater)&&const DeepCollectionEquality().equals(other._items, _items));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiQuotaBlockedEvent&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.requiredBytes, requiredBytes) || other.requiredBytes == requiredBytes)&&(identical(other.usedBytes, usedBytes) || other.usedBytes == usedBytes)&&(identical(other.quotaBytes, quotaBytes) || other.quotaBytes == quotaBytes)&&(identical(other.freedBytes, freedBytes) || other.freedBytes == freedBytes)&&(identical(other.blockedAt, blockedAt) || other.blockedAt == blockedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,isWatchLater,const DeepCollectionEquality().hash(_items));
+int get hashCode => Object.hash(runtimeType,videoId,title,requiredBytes,usedBytes,quotaBytes,freedBytes,blockedAt);
 
 @override
 String toString() {
-  return 'ApiMyListInfo(isWatchLater: $isWatchLater, items: $items)';
+  return 'ApiQuotaBlockedEvent(videoId: $videoId, title: $title, requiredBytes: $requiredBytes, usedBytes: $usedBytes, quotaBytes: $quotaBytes, freedBytes: $freedBytes, blockedAt: $blockedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiMyListInfoCopyWith<$Res> implements $ApiMyListInfoCopyWith<$Res> {
-  factory _$ApiMyListInfoCopyWith(_ApiMyListInfo value, $Res Function(_ApiMyListInfo) _then) = __$ApiMyListInfoCopyWithImpl;
+abstract mixin class _$ApiQuotaBlockedEventCopyWith<$Res> implements $ApiQuotaBlockedEventCopyWith<$Res> {
+  factory _$ApiQuotaBlockedEventCopyWith(_ApiQuotaBlockedEvent value, $Res Function(_ApiQuotaBlockedEvent) _then) = __$ApiQuotaBlockedEventCopyWithImpl;
 @override @useResult
 $Res call({
- bool isWatchLater, List<ApiMyListItem> items
+ String videoId, String title, BigInt requiredBytes, BigInt usedBytes, BigInt quotaBytes, BigInt freedBytes, PlatformInt64 blockedAt
 });
 
 
@@ -4449,20 +11130,25 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiMyListInfoCopyWithImpl<$Res>
-    implements _$ApiMyListInfoCopyWith<$Res> {
-  __$ApiMyListInfoCopyWithImpl(this._self, this._then);
+class __$ApiQuotaBlockedEventCopyWithImpl<$Res>
+    implements _$ApiQuotaBlockedEventCopyWith<$Res> {
+  __$ApiQuotaBlockedEventCopyWithImpl(this._self, this._then);
 
-  final _ApiMyListInfo _self;
-  final $Res Function(_ApiMyListInfo) _then;
+  final _ApiQuotaBlockedEvent _self;
+  final $Res Function(_ApiQuotaBlockedEvent) _then;
 
-/// Create a copy of ApiMyListInfo
+/// Create a copy of ApiQuotaBlockedEvent
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? isWatchLater = null,Object? items = null,}) {
-  return _then(_ApiMyListInfo(
-isWatchLater: null == isWatchLater ? _self.isWatchLater : isWatchLater // ignore: cast_nullable_to_non_nullable
-as bool,items: null == items ? _self._items : items // ignore: cast_nullable_to_non_nullable
-as List<ApiMyListItem>,
+@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? requiredBytes = null,Object? usedBytes = null,Object? quotaBytes = null,Object? freedBytes = null,Object? blockedAt = null,}) {
+  return _then(_ApiQuotaBlockedEvent(
+videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,requiredBytes: null == requiredBytes ? _self.requiredBytes : requiredBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,usedBytes: null == usedBytes ? _self.usedBytes : usedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,quotaBytes: null == quotaBytes ? _self.quotaBytes : quotaBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,freedBytes: null == freedBytes ? _self.freedBytes : freedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,blockedAt: null == blockedAt ? _self.blockedAt : blockedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
   ));
 }
 
@@ -4470,40 +11156,40 @@ as List<ApiMyListItem>,
 }
 
 /// @nodoc
-mixin _$ApiMyListItem {
+mixin _$ApiReconcileReport {
 
- String get code; String get title; bool get isSelected;
-/// Create a copy of ApiMyListItem
+ List<ApiMissingDownload> get missing; List<ApiOrphanFile> get orphans; BigInt get missingBytes; BigInt get orphanBytes; PlatformInt64 get scannedAt;
+/// Create a copy of ApiReconcileReport
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiMyListItemCopyWith<ApiMyListItem> get copyWith => _$ApiMyListItemCopyWithImpl<ApiMyListItem>(this as ApiMyListItem, _$identity);
+$ApiReconcileReportCopyWith<ApiReconcileReport> get copyWith => _$ApiReconcileReportCopyWithImpl<ApiReconcileReport>(this as ApiReconcileReport, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiMyListItem&&(identical(other.code, code) || other.code == code)&&(identical(other.title, title) || other.title == title)&&(identical(other.isSelected, isSelected) || other.isSelected == isSelected));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiReconcileReport&&const DeepCollectionEquality().equals(other.missing, missing)&&const DeepCollectionEquality().equals(other.orphans, orphans)&&(identical(other.missingBytes, missingBytes) || other.missingBytes == missingBytes)&&(identical(other.orphanBytes, orphanBytes) || other.orphanBytes == orphanBytes)&&(identical(other.scannedAt, scannedAt) || other.scannedAt == scannedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,code,title,isSelected);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(missing),const DeepCollectionEquality().hash(orphans),missingBytes,orphanBytes,scannedAt);
 
 @override
 String toString() {
-  return 'ApiMyListItem(code: $code, title: $title, isSelected: $isSelected)';
+  return 'ApiReconcileReport(missing: $missing, orphans: $orphans, missingBytes: $missingBytes, orphanBytes: $orphanBytes, scannedAt: $scannedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiMyListItemCopyWith<$Res>  {
-  factory $ApiMyListItemCopyWith(ApiMyListItem value, $Res Function(ApiMyListItem) _then) = _$ApiMyListItemCopyWithImpl;
+abstract mixin class $ApiReconcileReportCopyWith<$Res>  {
+  factory $ApiReconcileReportCopyWith(ApiReconcileReport value, $Res Function(ApiReconcileReport) _then) = _$ApiReconcileReportCopyWithImpl;
 @useResult
 $Res call({
- String code, String title, bool isSelected
+ List<ApiMissingDownload> missing, List<ApiOrphanFile> orphans, BigInt missingBytes, BigInt orphanBytes, PlatformInt64 scannedAt
 });
 
 
@@ -4511,29 +11197,31 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiMyListItemCopyWithImpl<$Res>
-    implements $ApiMyListItemCopyWith<$Res> {
-  _$ApiMyListItemCopyWithImpl(this._self, this._then);
+class _$ApiReconcileReportCopyWithImpl<$Res>
+    implements $ApiReconcileReportCopyWith<$Res> {
+  _$ApiReconcileReportCopyWithImpl(this._self, this._then);
 
-  final ApiMyListItem _self;
-  final $Res Function(ApiMyListItem) _then;
+  final ApiReconcileReport _self;
+  final $Res Function(ApiReconcileReport) _then;
 
-/// Create a copy of ApiMyListItem
+/// Create a copy of ApiReconcileReport
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? code = null,Object? title = null,Object? isSelected = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? missing = null,Object? orphans = null,Object? missingBytes = null,Object? orphanBytes = null,Object? scannedAt = null,}) {
   return _then(_self.copyWith(
-code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,isSelected: null == isSelected ? _self.isSelected : isSelected // ignore: cast_nullable_to_non_nullable
-as bool,
+missing: null == missing ? _self.missing : missing // ignore: cast_nullable_to_non_nullable
+as List<ApiMissingDownload>,orphans: null == orphans ? _self.orphans : orphans // ignore: cast_nullable_to_non_nullable
+as List<ApiOrphanFile>,missingBytes: null == missingBytes ? _self.missingBytes : missingBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,orphanBytes: null == orphanBytes ? _self.orphanBytes : orphanBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,scannedAt: null == scannedAt ? _self.scannedAt : scannedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiMyListItem].
-extension ApiMyListItemPatterns on ApiMyListItem {
+/// Adds pattern-matching-related methods to [ApiReconcileReport].
+extension ApiReconcileReportPatterns on ApiReconcileReport {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -4546,10 +11234,10 @@ extension ApiMyListItemPatterns on ApiMyListItem {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiMyListItem value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiReconcileReport value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiMyListItem() when $default != null:
+case _ApiReconcileReport() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -4568,10 +11256,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiMyListItem value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiReconcileReport value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiMyListItem():
+case _ApiReconcileReport():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -4586,10 +11274,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiMyListItem value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiReconcileReport value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiMyListItem() when $default != null:
+case _ApiReconcileReport() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -4607,10 +11295,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String code,  String title,  bool isSelected)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiMissingDownload> missing,  List<ApiOrphanFile> orphans,  BigInt missingBytes,  BigInt orphanBytes,  PlatformInt64 scannedAt)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiMyListItem() when $default != null:
-return $default(_that.code,_that.title,_that.isSelected);case _:
+case _ApiReconcileReport() when $default != null:
+return $default(_that.missing,_that.orphans,_that.missingBytes,_that.orphanBytes,_that.scannedAt);case _:
   return orElse();
 
 }
@@ -4628,10 +11316,10 @@ return $default(_that.code,_that.title,_that.isSelected);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String code,  String title,  bool isSelected)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiMissingDownload> missing,  List<ApiOrphanFile> orphans,  BigInt missingBytes,  BigInt orphanBytes,  PlatformInt64 scannedAt)  $default,) {final _that = this;
 switch (_that) {
-case _ApiMyListItem():
-return $default(_that.code,_that.title,_that.isSelected);}
+case _ApiReconcileReport():
+return $default(_that.missing,_that.orphans,_that.missingBytes,_that.orphanBytes,_that.scannedAt);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -4645,10 +11333,10 @@ return $default(_that.code,_that.title,_that.isSelected);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String code,  String title,  bool isSelected)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiMissingDownload> missing,  List<ApiOrphanFile> orphans,  BigInt missingBytes,  BigInt orphanBytes,  PlatformInt64 scannedAt)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiMyListItem() when $default != null:
-return $default(_that.code,_that.title,_that.isSelected);case _:
+case _ApiReconcileReport() when $default != null:
+return $default(_that.missing,_that.orphans,_that.missingBytes,_that.orphanBytes,_that.scannedAt);case _:
   return null;
 
 }
@@ -4659,45 +11347,59 @@ return $default(_that.code,_that.title,_that.isSelected);case _:
 /// @nodoc
 
 
-class _ApiMyListItem implements ApiMyListItem {
-  const _ApiMyListItem({required this.code, required this.title, required this.isSelected});
+class _ApiReconcileReport implements ApiReconcileReport {
+  const _ApiReconcileReport({required final  List<ApiMissingDownload> missing, required final  List<ApiOrphanFile> orphans, required this.missingBytes, required this.orphanBytes, required this.scannedAt}): _missing = missing,_orphans = orphans;
   
 
-@override final  String code;
-@override final  String title;
-@override final  bool isSelected;
+ final  List<ApiMissingDownload> _missing;
+@override List<ApiMissingDownload> get missing {
+  if (_missing is EqualUnmodifiableListView) return _missing;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_missing);
+}
 
-/// Create a copy of ApiMyListItem
+ final  List<ApiOrphanFile> _orphans;
+@override List<ApiOrphanFile> get orphans {
+  if (_orphans is EqualUnmodifiableListView) return _orphans;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_orphans);
+}
+
+@override final  BigInt missingBytes;
+@override final  BigInt orphanBytes;
+@override final  PlatformInt64 scannedAt;
+
+/// Create a copy of ApiReconcileReport
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiMyListItemCopyWith<_ApiMyListItem> get copyWith => __$ApiMyListItemCopyWithImpl<_ApiMyListItem>(this, _$identity);
+_$ApiReconcileReportCopyWith<_ApiReconcileReport> get copyWith => __$ApiReconcileReportCopyWithImpl<_ApiReconcileReport>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiMyListItem&&(identical(other.code, code) || other.code == code)&&(identical(other.title, title) || other.title == title)&&(identical(other.isSelected, isSelected) || other.isSelected == isSelected));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiReconcileReport&&const DeepCollectionEquality().equals(other._missing, _missing)&&const DeepCollectionEquality().equals(other._orphans, _orphans)&&(identical(other.missingBytes, missingBytes) || other.missingBytes == missingBytes)&&(identical(other.orphanBytes, orphanBytes) || other.orphanBytes == orphanBytes)&&(identical(other.scannedAt, scannedAt) || other.scannedAt == scannedAt));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,code,title,isSelected);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_missing),const DeepCollectionEquality().hash(_orphans),missingBytes,orphanBytes,scannedAt);
 
 @override
 String toString() {
-  return 'ApiMyListItem(code: $code, title: $title, isSelected: $isSelected)';
+  return 'ApiReconcileReport(missing: $missing, orphans: $orphans, missingBytes: $missingBytes, orphanBytes: $orphanBytes, scannedAt: $scannedAt)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiMyListItemCopyWith<$Res> implements $ApiMyListItemCopyWith<$Res> {
-  factory _$ApiMyListItemCopyWith(_ApiMyListItem value, $Res Function(_ApiMyListItem) _then) = __$ApiMyListItemCopyWithImpl;
+abstract mixin class _$ApiReconcileReportCopyWith<$Res> implements $ApiReconcileReportCopyWith<$Res> {
+  factory _$ApiReconcileReportCopyWith(_ApiReconcileReport value, $Res Function(_ApiReconcileReport) _then) = __$ApiReconcileReportCopyWithImpl;
 @override @useResult
 $Res call({
- String code, String title, bool isSelected
+ List<ApiMissingDownload> missing, List<ApiOrphanFile> orphans, BigInt missingBytes, BigInt orphanBytes, PlatformInt64 scannedAt
 });
 
 
@@ -4705,21 +11407,23 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiMyListItemCopyWithImpl<$Res>
-    implements _$ApiMyListItemCopyWith<$Res> {
-  __$ApiMyListItemCopyWithImpl(this._self, this._then);
+class __$ApiReconcileReportCopyWithImpl<$Res>
+    implements _$ApiReconcileReportCopyWith<$Res> {
+  __$ApiReconcileReportCopyWithImpl(this._self, this._then);
 
-  final _ApiMyListItem _self;
-  final $Res Function(_ApiMyListItem) _then;
+  final _ApiReconcileReport _self;
+  final $Res Function(_ApiReconcileReport) _then;
 
-/// Create a copy of ApiMyListItem
+/// Create a copy of ApiReconcileReport
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? code = null,Object? title = null,Object? isSelected = null,}) {
-  return _then(_ApiMyListItem(
-code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,isSelected: null == isSelected ? _self.isSelected : isSelected // ignore: cast_nullable_to_non_nullable
-as bool,
+@override @pragma('vm:prefer-inline') $Res call({Object? missing = null,Object? orphans = null,Object? missingBytes = null,Object? orphanBytes = null,Object? scannedAt = null,}) {
+  return _then(_ApiReconcileReport(
+missing: null == missing ? _self._missing : missing // ignore: cast_nullable_to_non_nullable
+as List<ApiMissingDownload>,orphans: null == orphans ? _self._orphans : orphans // ignore: cast_nullable_to_non_nullable
+as List<ApiOrphanFile>,missingBytes: null == missingBytes ? _self.missingBytes : missingBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,orphanBytes: null == orphanBytes ? _self.orphanBytes : orphanBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,scannedAt: null == scannedAt ? _self.scannedAt : scannedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
   ));
 }
 
@@ -4727,40 +11431,40 @@ as bool,
 }
 
 /// @nodoc
-mixin _$ApiPlayHistory {
+mixin _$ApiRelocateProgress {
 
- String get videoId; String get title; String get coverUrl; double get progress; int get duration; PlatformInt64 get lastPlayedAt;
-/// Create a copy of ApiPlayHistory
+ String get stage; int get totalFiles; int get doneFiles; BigInt get totalBytes; BigInt get doneBytes; String? get currentFile; bool get done; String? get error;
+/// Create a copy of ApiRelocateProgress
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiPlayHistoryCopyWith<ApiPlayHistory> get copyWith => _$ApiPlayHistoryCopyWithImpl<ApiPlayHistory>(this as ApiPlayHistory, _$identity);
+$ApiRelocateProgressCopyWith<ApiRelocateProgress> get copyWith => _$ApiRelocateProgressCopyWithImpl<ApiRelocateProgress>(this as ApiRelocateProgress, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiPlayHistory&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.lastPlayedAt, lastPlayedAt) || other.lastPlayedAt == lastPlayedAt));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiRelocateProgress&&(identical(other.stage, stage) || other.stage == stage)&&(identical(other.totalFiles, totalFiles) || other.totalFiles == totalFiles)&&(identical(other.doneFiles, doneFiles) || other.doneFiles == doneFiles)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.doneBytes, doneBytes) || other.doneBytes == doneBytes)&&(identical(other.currentFile, currentFile) || other.currentFile == currentFile)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,progress,duration,lastPlayedAt);
+int get hashCode => Object.hash(runtimeType,stage,totalFiles,doneFiles,totalBytes,doneBytes,currentFile,done,error);
 
 @override
 String toString() {
-  return 'ApiPlayHistory(videoId: $videoId, title: $title, coverUrl: $coverUrl, progress: $progress, duration: $duration, lastPlayedAt: $lastPlayedAt)';
+  return 'ApiRelocateProgress(stage: $stage, totalFiles: $totalFiles, doneFiles: $doneFiles, totalBytes: $totalBytes, doneBytes: $doneBytes, currentFile: $currentFile, done: $done, error: $error)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiPlayHistoryCopyWith<$Res>  {
-  factory $ApiPlayHistoryCopyWith(ApiPlayHistory value, $Res Function(ApiPlayHistory) _then) = _$ApiPlayHistoryCopyWithImpl;
+abstract mixin class $ApiRelocateProgressCopyWith<$Res>  {
+  factory $ApiRelocateProgressCopyWith(ApiRelocateProgress value, $Res Function(ApiRelocateProgress) _then) = _$ApiRelocateProgressCopyWithImpl;
 @useResult
 $Res call({
- String videoId, String title, String coverUrl, double progress, int duration, PlatformInt64 lastPlayedAt
+ String stage, int totalFiles, int doneFiles, BigInt totalBytes, BigInt doneBytes, String? currentFile, bool done, String? error
 });
 
 
@@ -4768,32 +11472,34 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiPlayHistoryCopyWithImpl<$Res>
-    implements $ApiPlayHistoryCopyWith<$Res> {
-  _$ApiPlayHistoryCopyWithImpl(this._self, this._then);
+class _$ApiRelocateProgressCopyWithImpl<$Res>
+    implements $ApiRelocateProgressCopyWith<$Res> {
+  _$ApiRelocateProgressCopyWithImpl(this._self, this._then);
 
-  final ApiPlayHistory _self;
-  final $Res Function(ApiPlayHistory) _then;
+  final ApiRelocateProgress _self;
+  final $Res Function(ApiRelocateProgress) _then;
 
-/// Create a copy of ApiPlayHistory
+/// Create a copy of ApiRelocateProgress
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? progress = null,Object? duration = null,Object? lastPlayedAt = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? stage = null,Object? totalFiles = null,Object? doneFiles = null,Object? totalBytes = null,Object? doneBytes = null,Object? currentFile = freezed,Object? done = null,Object? error = freezed,}) {
   return _then(_self.copyWith(
-videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
-as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
-as double,duration: null == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
-as int,lastPlayedAt: null == lastPlayedAt ? _self.lastPlayedAt : lastPlayedAt // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,
+stage: null == stage ? _self.stage : stage // ignore: cast_nullable_to_non_nullable
+as String,totalFiles: null == totalFiles ? _self.totalFiles : totalFiles // ignore: cast_nullable_to_non_nullable
+as int,doneFiles: null == doneFiles ? _self.doneFiles : doneFiles // ignore: cast_nullable_to_non_nullable
+as int,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,doneBytes: null == doneBytes ? _self.doneBytes : doneBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,currentFile: freezed == currentFile ? _self.currentFile : currentFile // ignore: cast_nullable_to_non_nullable
+as String?,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
+as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiPlayHistory].
-extension ApiPlayHistoryPatterns on ApiPlayHistory {
+/// Adds pattern-matching-related methods to [ApiRelocateProgress].
+extension ApiRelocateProgressPatterns on ApiRelocateProgress {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -4806,10 +11512,10 @@ extension ApiPlayHistoryPatterns on ApiPlayHistory {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiPlayHistory value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiRelocateProgress value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiPlayHistory() when $default != null:
+case _ApiRelocateProgress() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -4828,10 +11534,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiPlayHistory value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiRelocateProgress value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiPlayHistory():
+case _ApiRelocateProgress():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -4846,10 +11552,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiPlayHistory value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiRelocateProgress value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiPlayHistory() when $default != null:
+case _ApiRelocateProgress() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -4867,10 +11573,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  double progress,  int duration,  PlatformInt64 lastPlayedAt)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String stage,  int totalFiles,  int doneFiles,  BigInt totalBytes,  BigInt doneBytes,  String? currentFile,  bool done,  String? error)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiPlayHistory() when $default != null:
-return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.duration,_that.lastPlayedAt);case _:
+case _ApiRelocateProgress() when $default != null:
+return $default(_that.stage,_that.totalFiles,_that.doneFiles,_that.totalBytes,_that.doneBytes,_that.currentFile,_that.done,_that.error);case _:
   return orElse();
 
 }
@@ -4888,10 +11594,10 @@ return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.du
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String videoId,  String title,  String coverUrl,  double progress,  int duration,  PlatformInt64 lastPlayedAt)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String stage,  int totalFiles,  int doneFiles,  BigInt totalBytes,  BigInt doneBytes,  String? currentFile,  bool done,  String? error)  $default,) {final _that = this;
 switch (_that) {
-case _ApiPlayHistory():
-return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.duration,_that.lastPlayedAt);}
+case _ApiRelocateProgress():
+return $default(_that.stage,_that.totalFiles,_that.doneFiles,_that.totalBytes,_that.doneBytes,_that.currentFile,_that.done,_that.error);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -4905,10 +11611,10 @@ return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.du
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String videoId,  String title,  String coverUrl,  double progress,  int duration,  PlatformInt64 lastPlayedAt)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String stage,  int totalFiles,  int doneFiles,  BigInt totalBytes,  BigInt doneBytes,  String? currentFile,  bool done,  String? error)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiPlayHistory() when $default != null:
-return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.duration,_that.lastPlayedAt);case _:
+case _ApiRelocateProgress() when $default != null:
+return $default(_that.stage,_that.totalFiles,_that.doneFiles,_that.totalBytes,_that.doneBytes,_that.currentFile,_that.done,_that.error);case _:
   return null;
 
 }
@@ -4919,48 +11625,50 @@ return $default(_that.videoId,_that.title,_that.coverUrl,_that.progress,_that.du
 /// @nodoc
 
 
-class _ApiPlayHistory implements ApiPlayHistory {
-  const _ApiPlayHistory({required this.videoId, required this.title, required this.coverUrl, required this.progress, required this.duration, required this.lastPlayedAt});
+class _ApiRelocateProgress implements ApiRelocateProgress {
+  const _ApiRelocateProgress({required this.stage, required this.totalFiles, required this.doneFiles, required this.totalBytes, required this.doneBytes, this.currentFile, required this.done, this.error});
   
 
-@override final  String videoId;
-@override final  String title;
-@override final  String coverUrl;
-@override final  double progress;
-@override final  int duration;
-@override final  PlatformInt64 lastPlayedAt;
+@override final  String stage;
+@override final  int totalFiles;
+@override final  int doneFiles;
+@override final  BigInt totalBytes;
+@override final  BigInt doneBytes;
+@override final  String? currentFile;
+@override final  bool done;
+@override final  String? error;
 
-/// Create a copy of ApiPlayHistory
+/// Create a copy of ApiRelocateProgress
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiPlayHistoryCopyWith<_ApiPlayHistory> get copyWith => __$ApiPlayHistoryCopyWithImpl<_ApiPlayHistory>(this, _$identity);
+_$ApiRelocateProgressCopyWith<_ApiRelocateProgress> get copyWith => __$ApiRelocateProgressCopyWithImpl<_ApiRelocateProgress>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiPlayHistory&&(identical(other.videoId, videoId) || other.videoId == videoId)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.progress, progress) || other.progress == progress)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.lastPlayedAt, lastPlayedAt) || other.lastPlayedAt == lastPlayedAt));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiRelocateProgress&&(identical(other.stage, stage) || other.stage == stage)&&(identical(other.totalFiles, totalFiles) || other.totalFiles == totalFiles)&&(identical(other.doneFiles, doneFiles) || other.doneFiles == doneFiles)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.doneBytes, doneBytes) || other.doneBytes == doneBytes)&&(identical(other.currentFile, currentFile) || other.currentFile == currentFile)&&(identical(other.done, done) || other.done == done)&&(identical(other.error, error) || other.error == error));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,videoId,title,coverUrl,progress,duration,lastPlayedAt);
+int get hashCode => Object.hash(runtimeType,stage,totalFiles,doneFiles,totalBytes,doneBytes,currentFile,done,error);
 
 @override
 String toString() {
-  return 'ApiPlayHistory(videoId: $videoId, title: $title, coverUrl: $coverUrl, progress: $progress, duration: $duration, lastPlayedAt: $lastPlayedAt)';
+  return 'ApiRelocateProgress(stage: $stage, totalFiles: $totalFiles, doneFiles: $doneFiles, totalBytes: $totalBytes, doneBytes: $doneBytes, currentFile: $currentFile, done: $done, error: $error)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiPlayHistoryCopyWith<$Res> implements $ApiPlayHistoryCopyWith<$Res> {
-  factory _$ApiPlayHistoryCopyWith(_ApiPlayHistory value, $Res Function(_ApiPlayHistory) _then) = __$ApiPlayHistoryCopyWithImpl;
+abstract mixin class _$ApiRelocateProgressCopyWith<$Res> implements $ApiRelocateProgressCopyWith<$Res> {
+  factory _$ApiRelocateProgressCopyWith(_ApiRelocateProgress value, $Res Function(_ApiRelocateProgress) _then) = __$ApiRelocateProgressCopyWithImpl;
 @override @useResult
 $Res call({
- String videoId, String title, String coverUrl, double progress, int duration, PlatformInt64 lastPlayedAt
+ String stage, int totalFiles, int doneFiles, BigInt totalBytes, BigInt doneBytes, String? currentFile, bool done, String? error
 });
 
 
@@ -4968,24 +11676,26 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiPlayHistoryCopyWithImpl<$Res>
-    implements _$ApiPlayHistoryCopyWith<$Res> {
-  __$ApiPlayHistoryCopyWithImpl(this._self, this._then);
+class __$ApiRelocateProgressCopyWithImpl<$Res>
+    implements _$ApiRelocateProgressCopyWith<$Res> {
+  __$ApiRelocateProgressCopyWithImpl(this._self, this._then);
 
-  final _ApiPlayHistory _self;
-  final $Res Function(_ApiPlayHistory) _then;
+  final _ApiRelocateProgress _self;
+  final $Res Function(_ApiRelocateProgress) _then;
 
-/// Create a copy of ApiPlayHistory
+/// Create a copy of ApiRelocateProgress
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? videoId = null,Object? title = null,Object? coverUrl = null,Object? progress = null,Object? duration = null,Object? lastPlayedAt = null,}) {
-  return _then(_ApiPlayHistory(
-videoId: null == videoId ? _self.videoId : videoId // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
-as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
-as double,duration: null == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
-as int,lastPlayedAt: null == lastPlayedAt ? _self.lastPlayedAt : lastPlayedAt // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,
+@override @pragma('vm:prefer-inline') $Res call({Object? stage = null,Object? totalFiles = null,Object? doneFiles = null,Object? totalBytes = null,Object? doneBytes = null,Object? currentFile = freezed,Object? done = null,Object? error = freezed,}) {
+  return _then(_ApiRelocateProgress(
+stage: null == stage ? _self.stage : stage // ignore: cast_nullable_to_non_nullable
+as String,totalFiles: null == totalFiles ? _self.totalFiles : totalFiles // ignore: cast_nullable_to_non_nullable
+as int,doneFiles: null == doneFiles ? _self.doneFiles : doneFiles // ignore: cast_nullable_to_non_nullable
+as int,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,doneBytes: null == doneBytes ? _self.doneBytes : doneBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,currentFile: freezed == currentFile ? _self.currentFile : currentFile // ignore: cast_nullable_to_non_nullable
+as String?,done: null == done ? _self.done : done // ignore: cast_nullable_to_non_nullable
+as bool,error: freezed == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
@@ -4993,40 +11703,40 @@ as PlatformInt64,
 }
 
 /// @nodoc
-mixin _$ApiPlayHistoryList {
+mixin _$ApiSearchFilters {
 
- List<ApiPlayHistory> get items; int get total; int get page; bool get hasNext;
-/// Create a copy of ApiPlayHistoryList
+ String? get query; String? get genre; List<String> get tags; bool get broadMatch; String? get sort; String? get year; String? get month; String? get date; String? get duration; int get page;
+/// Create a copy of ApiSearchFilters
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiPlayHistoryListCopyWith<ApiPlayHistoryList> get copyWith => _$ApiPlayHistoryListCopyWithImpl<ApiPlayHistoryList>(this as ApiPlayHistoryList, _$identity);
+$ApiSearchFiltersCopyWith<ApiSearchFilters> get copyWith => _$ApiSearchFiltersCopyWithImpl<ApiSearchFilters>(this as ApiSearchFilters, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiPlayHistoryList&&const DeepCollectionEquality().equals(other.items, items)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSearchFilters&&(identical(other.query, query) || other.query == query)&&(identical(other.genre, genre) || other.genre == genre)&&const DeepCollectionEquality().equals(other.tags, tags)&&(identical(other.broadMatch, broadMatch) || other.broadMatch == broadMatch)&&(identical(other.sort, sort) || other.sort == sort)&&(identical(other.year, year) || other.year == year)&&(identical(other.month, month) || other.month == month)&&(identical(other.date, date) || other.date == date)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.page, page) || other.page == page));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(items),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,query,genre,const DeepCollectionEquality().hash(tags),broadMatch,sort,year,month,date,duration,page);
 
 @override
 String toString() {
-  return 'ApiPlayHistoryList(items: $items, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiSearchFilters(query: $query, genre: $genre, tags: $tags, broadMatch: $broadMatch, sort: $sort, year: $year, month: $month, date: $date, duration: $duration, page: $page)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiPlayHistoryListCopyWith<$Res>  {
-  factory $ApiPlayHistoryListCopyWith(ApiPlayHistoryList value, $Res Function(ApiPlayHistoryList) _then) = _$ApiPlayHistoryListCopyWithImpl;
+abstract mixin class $ApiSearchFiltersCopyWith<$Res>  {
+  factory $ApiSearchFiltersCopyWith(ApiSearchFilters value, $Res Function(ApiSearchFilters) _then) = _$ApiSearchFiltersCopyWithImpl;
 @useResult
 $Res call({
- List<ApiPlayHistory> items, int total, int page, bool hasNext
+ String? query, String? genre, List<String> tags, bool broadMatch, String? sort, String? year, String? month, String? date, String? duration, int page
 });
 
 
@@ -5034,30 +11744,36 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiPlayHistoryListCopyWithImpl<$Res>
-    implements $ApiPlayHistoryListCopyWith<$Res> {
-  _$ApiPlayHistoryListCopyWithImpl(this._self, this._then);
+class _$ApiSearchFiltersCopyWithImpl<$Res>
+    implements $ApiSearchFiltersCopyWith<$Res> {
+  _$ApiSearchFiltersCopyWithImpl(this._self, this._then);
 
-  final ApiPlayHistoryList _self;
-  final $Res Function(ApiPlayHistoryList) _then;
+  final ApiSearchFilters _self;
+  final $Res Function(ApiSearchFilters) _then;
 
-/// Create a copy of ApiPlayHistoryList
+/// Create a copy of ApiSearchFilters
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? items = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? query = freezed,Object? genre = freezed,Object? tags = null,Object? broadMatch = null,Object? sort = freezed,Object? year = freezed,Object? month = freezed,Object? date = freezed,Object? duration = freezed,Object? page = null,}) {
   return _then(_self.copyWith(
-items: null == items ? _self.items : items // ignore: cast_nullable_to_non_nullable
-as List<ApiPlayHistory>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+query: freezed == query ? _self.query : query // ignore: cast_nullable_to_non_nullable
+as String?,genre: freezed == genre ? _self.genre : genre // ignore: cast_nullable_to_non_nullable
+as String?,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,broadMatch: null == broadMatch ? _self.broadMatch : broadMatch // ignore: cast_nullable_to_non_nullable
+as bool,sort: freezed == sort ? _self.sort : sort // ignore: cast_nullable_to_non_nullable
+as String?,year: freezed == year ? _self.year : year // ignore: cast_nullable_to_non_nullable
+as String?,month: freezed == month ? _self.month : month // ignore: cast_nullable_to_non_nullable
+as String?,date: freezed == date ? _self.date : date // ignore: cast_nullable_to_non_nullable
+as String?,duration: freezed == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as String?,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiPlayHistoryList].
-extension ApiPlayHistoryListPatterns on ApiPlayHistoryList {
+/// Adds pattern-matching-related methods to [ApiSearchFilters].
+extension ApiSearchFiltersPatterns on ApiSearchFilters {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -5070,10 +11786,10 @@ extension ApiPlayHistoryListPatterns on ApiPlayHistoryList {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiPlayHistoryList value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSearchFilters value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiPlayHistoryList() when $default != null:
+case _ApiSearchFilters() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -5092,10 +11808,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiPlayHistoryList value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSearchFilters value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiPlayHistoryList():
+case _ApiSearchFilters():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -5110,10 +11826,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiPlayHistoryList value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSearchFilters value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiPlayHistoryList() when $default != null:
+case _ApiSearchFilters() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -5131,10 +11847,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiPlayHistory> items,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? query,  String? genre,  List<String> tags,  bool broadMatch,  String? sort,  String? year,  String? month,  String? date,  String? duration,  int page)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiPlayHistoryList() when $default != null:
-return $default(_that.items,_that.total,_that.page,_that.hasNext);case _:
+case _ApiSearchFilters() when $default != null:
+return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_that.year,_that.month,_that.date,_that.duration,_that.page);case _:
   return orElse();
 
 }
@@ -5152,10 +11868,10 @@ return $default(_that.items,_that.total,_that.page,_that.hasNext);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiPlayHistory> items,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? query,  String? genre,  List<String> tags,  bool broadMatch,  String? sort,  String? year,  String? month,  String? date,  String? duration,  int page)  $default,) {final _that = this;
 switch (_that) {
-case _ApiPlayHistoryList():
-return $default(_that.items,_that.total,_that.page,_that.hasNext);}
+case _ApiSearchFilters():
+return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_that.year,_that.month,_that.date,_that.duration,_that.page);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -5169,10 +11885,10 @@ return $default(_that.items,_that.total,_that.page,_that.hasNext);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiPlayHistory> items,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? query,  String? genre,  List<String> tags,  bool broadMatch,  String? sort,  String? year,  String? month,  String? date,  String? duration,  int page)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiPlayHistoryList() when $default != null:
-return $default(_that.items,_that.total,_that.page,_that.hasNext);case _:
+case _ApiSearchFilters() when $default != null:
+return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_that.year,_that.month,_that.date,_that.duration,_that.page);case _:
   return null;
 
 }
@@ -5183,52 +11899,58 @@ return $default(_that.items,_that.total,_that.page,_that.hasNext);case _:
 /// @nodoc
 
 
-class _ApiPlayHistoryList implements ApiPlayHistoryList {
-  const _ApiPlayHistoryList({required final  List<ApiPlayHistory> items, required this.total, required this.page, required this.hasNext}): _items = items;
+class _ApiSearchFilters extends ApiSearchFilters {
+  const _ApiSearchFilters({this.query, this.genre, required final  List<String> tags, required this.broadMatch, this.sort, this.year, this.month, this.date, this.duration, required this.page}): _tags = tags,super._();
   
 
- final  List<ApiPlayHistory> _items;
-@override List<ApiPlayHistory> get items {
-  if (_items is EqualUnmodifiableListView) return _items;
+@override final  String? query;
+@override final  String? genre;
+ final  List<String> _tags;
+@override List<String> get tags {
+  if (_tags is EqualUnmodifiableListView) return _tags;
   // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_items);
+  return EqualUnmodifiableListView(_tags);
 }
 
-@override final  int total;
+@override final  bool broadMatch;
+@override final  String? sort;
+@override final  String? year;
+@override final  String? month;
+@override final  String? date;
+@override final  String? duration;
 @override final  int page;
-@override final  bool hasNext;
 
-/// Create a copy of ApiPlayHistoryList
+/// Create a copy of ApiSearchFilters
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiPlayHistoryListCopyWith<_ApiPlayHistoryList> get copyWith => __$ApiPlayHistoryListCopyWithImpl<_ApiPlayHistoryList>(this, _$identity);
+_$ApiSearchFiltersCopyWith<_ApiSearchFilters> get copyWith => __$ApiSearchFiltersCopyWithImpl<_ApiSearchFilters>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiPlayHistoryList&&const DeepCollectionEquality().equals(other._items, _items)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSearchFilters&&(identical(other.query, query) || other.query == query)&&(identical(other.genre, genre) || other.genre == genre)&&const DeepCollectionEquality().equals(other._tags, _tags)&&(identical(other.broadMatch, broadMatch) || other.broadMatch == broadMatch)&&(identical(other.sort, sort) || other.sort == sort)&&(identical(other.year, year) || other.year == year)&&(identical(other.month, month) || other.month == month)&&(identical(other.date, date) || other.date == date)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.page, page) || other.page == page));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_items),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,query,genre,const DeepCollectionEquality().hash(_tags),broadMatch,sort,year,month,date,duration,page);
 
 @override
 String toString() {
-  return 'ApiPlayHistoryList(items: $items, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiSearchFilters(query: $query, genre: $genre, tags: $tags, broadMatch: $broadMatch, sort: $sort, year: $year, month: $month, date: $date, duration: $duration, page: $page)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiPlayHistoryListCopyWith<$Res> implements $ApiPlayHistoryListCopyWith<$Res> {
-  factory _$ApiPlayHistoryListCopyWith(_ApiPlayHistoryList value, $Res Function(_ApiPlayHistoryList) _then) = __$ApiPlayHistoryListCopyWithImpl;
+abstract mixin class _$ApiSearchFiltersCopyWith<$Res> implements $ApiSearchFiltersCopyWith<$Res> {
+  factory _$ApiSearchFiltersCopyWith(_ApiSearchFilters value, $Res Function(_ApiSearchFilters) _then) = __$ApiSearchFiltersCopyWithImpl;
 @override @useResult
 $Res call({
- List<ApiPlayHistory> items, int total, int page, bool hasNext
+ String? query, String? genre, List<String> tags, bool broadMatch, String? sort, String? year, String? month, String? date, String? duration, int page
 });
 
 
@@ -5236,22 +11958,28 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiPlayHistoryListCopyWithImpl<$Res>
-    implements _$ApiPlayHistoryListCopyWith<$Res> {
-  __$ApiPlayHistoryListCopyWithImpl(this._self, this._then);
+class __$ApiSearchFiltersCopyWithImpl<$Res>
+    implements _$ApiSearchFiltersCopyWith<$Res> {
+  __$ApiSearchFiltersCopyWithImpl(this._self, this._then);
 
-  final _ApiPlayHistoryList _self;
-  final $Res Function(_ApiPlayHistoryList) _then;
+  final _ApiSearchFilters _self;
+  final $Res Function(_ApiSearchFilters) _then;
 
-/// Create a copy of ApiPlayHistoryList
+/// Create a copy of ApiSearchFilters
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? items = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
-  return _then(_ApiPlayHistoryList(
-items: null == items ? _self._items : items // ignore: cast_nullable_to_non_nullable
-as List<ApiPlayHistory>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+@override @pragma('vm:prefer-inline') $Res call({Object? query = freezed,Object? genre = freezed,Object? tags = null,Object? broadMatch = null,Object? sort = freezed,Object? year = freezed,Object? month = freezed,Object? date = freezed,Object? duration = freezed,Object? page = null,}) {
+  return _then(_ApiSearchFilters(
+query: freezed == query ? _self.query : query // ignore: cast_nullable_to_non_nullable
+as String?,genre: freezed == genre ? _self.genre : genre // ignore: cast_nullable_to_non_nullable
+as String?,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,broadMatch: null == broadMatch ? _self.broadMatch : broadMatch // ignore: cast_nullable_to_non_nullable
+as bool,sort: freezed == sort ? _self.sort : sort // ignore: cast_nullable_to_non_nullable
+as String?,year: freezed == year ? _self.year : year // ignore: cast_nullable_to_non_nullable
+as String?,month: freezed == month ? _self.month : month // ignore: cast_nullable_to_non_nullable
+as String?,date: freezed == date ? _self.date : date // ignore: cast_nullable_to_non_nullable
+as String?,duration: freezed == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as String?,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
@@ -5259,40 +11987,40 @@ as bool,
 }
 
 /// @nodoc
-mixin _$ApiPlaylistInfo {
+mixin _$ApiSearchResult {
 
- String? get name; List<ApiVideoCard> get videos;
-/// Create a copy of ApiPlaylistInfo
+ List<ApiVideoCard> get videos; int get total; int get page; bool get hasNext;
+/// Create a copy of ApiSearchResult
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiPlaylistInfoCopyWith<ApiPlaylistInfo> get copyWith => _$ApiPlaylistInfoCopyWithImpl<ApiPlaylistInfo>(this as ApiPlaylistInfo, _$identity);
+$ApiSearchResultCopyWith<ApiSearchResult> get copyWith => _$ApiSearchResultCopyWithImpl<ApiSearchResult>(this as ApiSearchResult, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiPlaylistInfo&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other.videos, videos));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSearchResult&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,name,const DeepCollectionEquality().hash(videos));
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(videos),total,page,hasNext);
 
 @override
 String toString() {
-  return 'ApiPlaylistInfo(name: $name, videos: $videos)';
+  return 'ApiSearchResult(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiPlaylistInfoCopyWith<$Res>  {
-  factory $ApiPlaylistInfoCopyWith(ApiPlaylistInfo value, $Res Function(ApiPlaylistInfo) _then) = _$ApiPlaylistInfoCopyWithImpl;
+abstract mixin class $ApiSearchResultCopyWith<$Res>  {
+  factory $ApiSearchResultCopyWith(ApiSearchResult value, $Res Function(ApiSearchResult) _then) = _$ApiSearchResultCopyWithImpl;
 @useResult
 $Res call({
- String? name, List<ApiVideoCard> videos
+ List<ApiVideoCard> videos, int total, int page, bool hasNext
 });
 
 
@@ -5300,28 +12028,30 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiPlaylistInfoCopyWithImpl<$Res>
-    implements $ApiPlaylistInfoCopyWith<$Res> {
-  _$ApiPlaylistInfoCopyWithImpl(this._self, this._then);
+class _$ApiSearchResultCopyWithImpl<$Res>
+    implements $ApiSearchResultCopyWith<$Res> {
+  _$ApiSearchResultCopyWithImpl(this._self, this._then);
 
-  final ApiPlaylistInfo _self;
-  final $Res Function(ApiPlaylistInfo) _then;
+  final ApiSearchResult _self;
+  final $Res Function(ApiSearchResult) _then;
 
-/// Create a copy of ApiPlaylistInfo
+/// Create a copy of ApiSearchResult
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? name = freezed,Object? videos = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
   return _then(_self.copyWith(
-name: freezed == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String?,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,
+videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiPlaylistInfo].
-extension ApiPlaylistInfoPatterns on ApiPlaylistInfo {
+/// Adds pattern-matching-related methods to [ApiSearchResult].
+extension ApiSearchResultPatterns on ApiSearchResult {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -5334,10 +12064,10 @@ extension ApiPlaylistInfoPatterns on ApiPlaylistInfo {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiPlaylistInfo value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSearchResult value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiPlaylistInfo() when $default != null:
+case _ApiSearchResult() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -5356,10 +12086,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiPlaylistInfo value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSearchResult value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiPlaylistInfo():
+case _ApiSearchResult():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -5374,10 +12104,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiPlaylistInfo value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSearchResult value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiPlaylistInfo() when $default != null:
+case _ApiSearchResult() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -5395,10 +12125,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? name,  List<ApiVideoCard> videos)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiPlaylistInfo() when $default != null:
-return $default(_that.name,_that.videos);case _:
+case _ApiSearchResult() when $default != null:
+return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
   return orElse();
 
 }
@@ -5416,10 +12146,10 @@ return $default(_that.name,_that.videos);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? name,  List<ApiVideoCard> videos)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
 switch (_that) {
-case _ApiPlaylistInfo():
-return $default(_that.name,_that.videos);}
+case _ApiSearchResult():
+return $default(_that.videos,_that.total,_that.page,_that.hasNext);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -5433,10 +12163,10 @@ return $default(_that.name,_that.videos);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? name,  List<ApiVideoCard> videos)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiPlaylistInfo() when $default != null:
-return $default(_that.name,_that.videos);case _:
+case _ApiSearchResult() when $default != null:
+return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
   return null;
 
 }
@@ -5447,11 +12177,10 @@ return $default(_that.name,_that.videos);case _:
 /// @nodoc
 
 
-class _ApiPlaylistInfo implements ApiPlaylistInfo {
-  const _ApiPlaylistInfo({this.name, required final  List<ApiVideoCard> videos}): _videos = videos;
+class _ApiSearchResult implements ApiSearchResult {
+  const _ApiSearchResult({required final  List<ApiVideoCard> videos, required this.total, required this.page, required this.hasNext}): _videos = videos;
   
 
-@override final  String? name;
  final  List<ApiVideoCard> _videos;
 @override List<ApiVideoCard> get videos {
   if (_videos is EqualUnmodifiableListView) return _videos;
@@ -5459,38 +12188,41 @@ class _ApiPlaylistInfo implements ApiPlaylistInfo {
   return EqualUnmodifiableListView(_videos);
 }
 
+@override final  int total;
+@override final  int page;
+@override final  bool hasNext;
 
-/// Create a copy of ApiPlaylistInfo
+/// Create a copy of ApiSearchResult
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiPlaylistInfoCopyWith<_ApiPlaylistInfo> get copyWith => __$ApiPlaylistInfoCopyWithImpl<_ApiPlaylistInfo>(this, _$identity);
+_$ApiSearchResultCopyWith<_ApiSearchResult> get copyWith => __$ApiSearchResultCopyWithImpl<_ApiSearchResult>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiPlaylistInfo&&(identical(other.name, name) || other.name == name)&&const DeepCollectionEquality().equals(other._videos, _videos));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSearchResult&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,name,const DeepCollectionEquality().hash(_videos));
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_videos),total,page,hasNext);
 
 @override
 String toString() {
-  return 'ApiPlaylistInfo(name: $name, videos: $videos)';
+  return 'ApiSearchResult(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiPlaylistInfoCopyWith<$Res> implements $ApiPlaylistInfoCopyWith<$Res> {
-  factory _$ApiPlaylistInfoCopyWith(_ApiPlaylistInfo value, $Res Function(_ApiPlaylistInfo) _then) = __$ApiPlaylistInfoCopyWithImpl;
+abstract mixin class _$ApiSearchResultCopyWith<$Res> implements $ApiSearchResultCopyWith<$Res> {
+  factory _$ApiSearchResultCopyWith(_ApiSearchResult value, $Res Function(_ApiSearchResult) _then) = __$ApiSearchResultCopyWithImpl;
 @override @useResult
 $Res call({
- String? name, List<ApiVideoCard> videos
+ List<ApiVideoCard> videos, int total, int page, bool hasNext
 });
 
 
@@ -5498,20 +12230,22 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiPlaylistInfoCopyWithImpl<$Res>
-    implements _$ApiPlaylistInfoCopyWith<$Res> {
-  __$ApiPlaylistInfoCopyWithImpl(this._self, this._then);
+class __$ApiSearchResultCopyWithImpl<$Res>
+    implements _$ApiSearchResultCopyWith<$Res> {
+  __$ApiSearchResultCopyWithImpl(this._self, this._then);
 
-  final _ApiPlaylistInfo _self;
-  final $Res Function(_ApiPlaylistInfo) _then;
+  final _ApiSearchResult _self;
+  final $Res Function(_ApiSearchResult) _then;
 
-/// Create a copy of ApiPlaylistInfo
+/// Create a copy of ApiSearchResult
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? name = freezed,Object? videos = null,}) {
-  return _then(_ApiPlaylistInfo(
-name: freezed == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String?,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,
+@override @pragma('vm:prefer-inline') $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_ApiSearchResult(
+videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -5519,40 +12253,40 @@ as List<ApiVideoCard>,
 }
 
 /// @nodoc
-mixin _$ApiSearchFilters {
+mixin _$ApiSeriesInfo {
 
- String? get query; String? get genre; List<String> get tags; bool get broadMatch; String? get sort; String? get year; String? get month; String? get date; String? get duration; int get page;
-/// Create a copy of ApiSearchFilters
+ String get id; String get title; List<ApiSeriesVideo> get videos; int get currentIndex;
+/// Create a copy of ApiSeriesInfo
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiSearchFiltersCopyWith<ApiSearchFilters> get copyWith => _$ApiSearchFiltersCopyWithImpl<ApiSearchFilters>(this as ApiSearchFilters, _$identity);
+$ApiSeriesInfoCopyWith<ApiSeriesInfo> get copyWith => _$ApiSeriesInfoCopyWithImpl<ApiSeriesInfo>(this as ApiSeriesInfo, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSearchFilters&&(identical(other.query, query) || other.query == query)&&(identical(other.genre, genre) || other.genre == genre)&&const DeepCollectionEquality().equals(other.tags, tags)&&(identical(other.broadMatch, broadMatch) || other.broadMatch == broadMatch)&&(identical(other.sort, sort) || other.sort == sort)&&(identical(other.year, year) || other.year == year)&&(identical(other.month, month) || other.month == month)&&(identical(other.date, date) || other.date == date)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.page, page) || other.page == page));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSeriesInfo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.currentIndex, currentIndex) || other.currentIndex == currentIndex));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,query,genre,const DeepCollectionEquality().hash(tags),broadMatch,sort,year,month,date,duration,page);
+int get hashCode => Object.hash(runtimeType,id,title,const DeepCollectionEquality().hash(videos),currentIndex);
 
 @override
 String toString() {
-  return 'ApiSearchFilters(query: $query, genre: $genre, tags: $tags, broadMatch: $broadMatch, sort: $sort, year: $year, month: $month, date: $date, duration: $duration, page: $page)';
+  return 'ApiSeriesInfo(id: $id, title: $title, videos: $videos, currentIndex: $currentIndex)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiSearchFiltersCopyWith<$Res>  {
-  factory $ApiSearchFiltersCopyWith(ApiSearchFilters value, $Res Function(ApiSearchFilters) _then) = _$ApiSearchFiltersCopyWithImpl;
+abstract mixin class $ApiSeriesInfoCopyWith<$Res>  {
+  factory $ApiSeriesInfoCopyWith(ApiSeriesInfo value, $Res Function(ApiSeriesInfo) _then) = _$ApiSeriesInfoCopyWithImpl;
 @useResult
 $Res call({
- String? query, String? genre, List<String> tags, bool broadMatch, String? sort, String? year, String? month, String? date, String? duration, int page
+ String id, String title, List<ApiSeriesVideo> videos, int currentIndex
 });
 
 
@@ -5560,36 +12294,30 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiSearchFiltersCopyWithImpl<$Res>
-    implements $ApiSearchFiltersCopyWith<$Res> {
-  _$ApiSearchFiltersCopyWithImpl(this._self, this._then);
+class _$ApiSeriesInfoCopyWithImpl<$Res>
+    implements $ApiSeriesInfoCopyWith<$Res> {
+  _$ApiSeriesInfoCopyWithImpl(this._self, this._then);
 
-  final ApiSearchFilters _self;
-  final $Res Function(ApiSearchFilters) _then;
+  final ApiSeriesInfo _self;
+  final $Res Function(ApiSeriesInfo) _then;
 
-/// Create a copy of ApiSearchFilters
+/// Create a copy of ApiSeriesInfo
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? query = freezed,Object? genre = freezed,Object? tags = null,Object? broadMatch = null,Object? sort = freezed,Object? year = freezed,Object? month = freezed,Object? date = freezed,Object? duration = freezed,Object? page = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? videos = null,Object? currentIndex = null,}) {
   return _then(_self.copyWith(
-query: freezed == query ? _self.query : query // ignore: cast_nullable_to_non_nullable
-as String?,genre: freezed == genre ? _self.genre : genre // ignore: cast_nullable_to_non_nullable
-as String?,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
-as List<String>,broadMatch: null == broadMatch ? _self.broadMatch : broadMatch // ignore: cast_nullable_to_non_nullable
-as bool,sort: freezed == sort ? _self.sort : sort // ignore: cast_nullable_to_non_nullable
-as String?,year: freezed == year ? _self.year : year // ignore: cast_nullable_to_non_nullable
-as String?,month: freezed == month ? _self.month : month // ignore: cast_nullable_to_non_nullable
-as String?,date: freezed == date ? _self.date : date // ignore: cast_nullable_to_non_nullable
-as String?,duration: freezed == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
-as String?,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiSeriesVideo>,currentIndex: null == currentIndex ? _self.currentIndex : currentIndex // ignore: cast_nullable_to_non_nullable
 as int,
   ));
 }
 
 }
 
-
-/// Adds pattern-matching-related methods to [ApiSearchFilters].
-extension ApiSearchFiltersPatterns on ApiSearchFilters {
+
+/// Adds pattern-matching-related methods to [ApiSeriesInfo].
+extension ApiSeriesInfoPatterns on ApiSeriesInfo {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -5602,10 +12330,10 @@ extension ApiSearchFiltersPatterns on ApiSearchFilters {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSearchFilters value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSeriesInfo value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiSearchFilters() when $default != null:
+case _ApiSeriesInfo() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -5624,10 +12352,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSearchFilters value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSeriesInfo value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSearchFilters():
+case _ApiSeriesInfo():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -5642,10 +12370,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSearchFilters value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSeriesInfo value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSearchFilters() when $default != null:
+case _ApiSeriesInfo() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -5663,10 +12391,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? query,  String? genre,  List<String> tags,  bool broadMatch,  String? sort,  String? year,  String? month,  String? date,  String? duration,  int page)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  List<ApiSeriesVideo> videos,  int currentIndex)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiSearchFilters() when $default != null:
-return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_that.year,_that.month,_that.date,_that.duration,_that.page);case _:
+case _ApiSeriesInfo() when $default != null:
+return $default(_that.id,_that.title,_that.videos,_that.currentIndex);case _:
   return orElse();
 
 }
@@ -5684,10 +12412,10 @@ return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? query,  String? genre,  List<String> tags,  bool broadMatch,  String? sort,  String? year,  String? month,  String? date,  String? duration,  int page)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  List<ApiSeriesVideo> videos,  int currentIndex)  $default,) {final _that = this;
 switch (_that) {
-case _ApiSearchFilters():
-return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_that.year,_that.month,_that.date,_that.duration,_that.page);}
+case _ApiSeriesInfo():
+return $default(_that.id,_that.title,_that.videos,_that.currentIndex);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -5701,10 +12429,10 @@ return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? query,  String? genre,  List<String> tags,  bool broadMatch,  String? sort,  String? year,  String? month,  String? date,  String? duration,  int page)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  List<ApiSeriesVideo> videos,  int currentIndex)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiSearchFilters() when $default != null:
-return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_that.year,_that.month,_that.date,_that.duration,_that.page);case _:
+case _ApiSeriesInfo() when $default != null:
+return $default(_that.id,_that.title,_that.videos,_that.currentIndex);case _:
   return null;
 
 }
@@ -5715,58 +12443,52 @@ return $default(_that.query,_that.genre,_that.tags,_that.broadMatch,_that.sort,_
 /// @nodoc
 
 
-class _ApiSearchFilters extends ApiSearchFilters {
-  const _ApiSearchFilters({this.query, this.genre, required final  List<String> tags, required this.broadMatch, this.sort, this.year, this.month, this.date, this.duration, required this.page}): _tags = tags,super._();
+class _ApiSeriesInfo implements ApiSeriesInfo {
+  const _ApiSeriesInfo({required this.id, required this.title, required final  List<ApiSeriesVideo> videos, required this.currentIndex}): _videos = videos;
   
 
-@override final  String? query;
-@override final  String? genre;
- final  List<String> _tags;
-@override List<String> get tags {
-  if (_tags is EqualUnmodifiableListView) return _tags;
+@override final  String id;
+@override final  String title;
+ final  List<ApiSeriesVideo> _videos;
+@override List<ApiSeriesVideo> get videos {
+  if (_videos is EqualUnmodifiableListView) return _videos;
   // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_tags);
+  return EqualUnmodifiableListView(_videos);
 }
 
-@override final  bool broadMatch;
-@override final  String? sort;
-@override final  String? year;
-@override final  String? month;
-@override final  String? date;
-@override final  String? duration;
-@override final  int page;
+@override final  int currentIndex;
 
-/// Create a copy of ApiSearchFilters
+/// Create a copy of ApiSeriesInfo
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiSearchFiltersCopyWith<_ApiSearchFilters> get copyWith => __$ApiSearchFiltersCopyWithImpl<_ApiSearchFilters>(this, _$identity);
+_$ApiSeriesInfoCopyWith<_ApiSeriesInfo> get copyWith => __$ApiSeriesInfoCopyWithImpl<_ApiSeriesInfo>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSearchFilters&&(identical(other.query, query) || other.query == query)&&(identical(other.genre, genre) || other.genre == genre)&&const DeepCollectionEquality().equals(other._tags, _tags)&&(identical(other.broadMatch, broadMatch) || other.broadMatch == broadMatch)&&(identical(other.sort, sort) || other.sort == sort)&&(identical(other.year, year) || other.year == year)&&(identical(other.month, month) || other.month == month)&&(identical(other.date, date) || other.date == date)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.page, page) || other.page == page));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSeriesInfo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.currentIndex, currentIndex) || other.currentIndex == currentIndex));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,query,genre,const DeepCollectionEquality().hash(_tags),broadMatch,sort,year,month,date,duration,page);
+int get hashCode => Object.hash(runtimeType,id,title,const DeepCollectionEquality().hash(_videos),currentIndex);
 
 @override
 String toString() {
-  return 'ApiSearchFilters(query: $query, genre: $genre, tags: $tags, broadMatch: $broadMatch, sort: $sort, year: $year, month: $month, date: $date, duration: $duration, page: $page)';
+  return 'ApiSeriesInfo(id: $id, title: $title, videos: $videos, currentIndex: $currentIndex)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiSearchFiltersCopyWith<$Res> implements $ApiSearchFiltersCopyWith<$Res> {
-  factory _$ApiSearchFiltersCopyWith(_ApiSearchFilters value, $Res Function(_ApiSearchFilters) _then) = __$ApiSearchFiltersCopyWithImpl;
+abstract mixin class _$ApiSeriesInfoCopyWith<$Res> implements $ApiSeriesInfoCopyWith<$Res> {
+  factory _$ApiSeriesInfoCopyWith(_ApiSeriesInfo value, $Res Function(_ApiSeriesInfo) _then) = __$ApiSeriesInfoCopyWithImpl;
 @override @useResult
 $Res call({
- String? query, String? genre, List<String> tags, bool broadMatch, String? sort, String? year, String? month, String? date, String? duration, int page
+ String id, String title, List<ApiSeriesVideo> videos, int currentIndex
 });
 
 
@@ -5774,27 +12496,21 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiSearchFiltersCopyWithImpl<$Res>
-    implements _$ApiSearchFiltersCopyWith<$Res> {
-  __$ApiSearchFiltersCopyWithImpl(this._self, this._then);
+class __$ApiSeriesInfoCopyWithImpl<$Res>
+    implements _$ApiSeriesInfoCopyWith<$Res> {
+  __$ApiSeriesInfoCopyWithImpl(this._self, this._then);
 
-  final _ApiSearchFilters _self;
-  final $Res Function(_ApiSearchFilters) _then;
+  final _ApiSeriesInfo _self;
+  final $Res Function(_ApiSeriesInfo) _then;
 
-/// Create a copy of ApiSearchFilters
+/// Create a copy of ApiSeriesInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? query = freezed,Object? genre = freezed,Object? tags = null,Object? broadMatch = null,Object? sort = freezed,Object? year = freezed,Object? month = freezed,Object? date = freezed,Object? duration = freezed,Object? page = null,}) {
-  return _then(_ApiSearchFilters(
-query: freezed == query ? _self.query : query // ignore: cast_nullable_to_non_nullable
-as String?,genre: freezed == genre ? _self.genre : genre // ignore: cast_nullable_to_non_nullable
-as String?,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
-as List<String>,broadMatch: null == broadMatch ? _self.broadMatch : broadMatch // ignore: cast_nullable_to_non_nullable
-as bool,sort: freezed == sort ? _self.sort : sort // ignore: cast_nullable_to_non_nullable
-as String?,year: freezed == year ? _self.year : year // ignore: cast_nullable_to_non_nullable
-as String?,month: freezed == month ? _self.month : month // ignore: cast_nullable_to_non_nullable
-as String?,date: freezed == date ? _self.date : date // ignore: cast_nullable_to_non_nullable
-as String?,duration: freezed == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
-as String?,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? videos = null,Object? currentIndex = null,}) {
+  return _then(_ApiSeriesInfo(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiSeriesVideo>,currentIndex: null == currentIndex ? _self.currentIndex : currentIndex // ignore: cast_nullable_to_non_nullable
 as int,
   ));
 }
@@ -5803,40 +12519,40 @@ as int,
 }
 
 /// @nodoc
-mixin _$ApiSearchResult {
+mixin _$ApiSeriesVideo {
 
- List<ApiVideoCard> get videos; int get total; int get page; bool get hasNext;
-/// Create a copy of ApiSearchResult
+ String get id; String get title; String get coverUrl; String get episode;
+/// Create a copy of ApiSeriesVideo
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiSearchResultCopyWith<ApiSearchResult> get copyWith => _$ApiSearchResultCopyWithImpl<ApiSearchResult>(this as ApiSearchResult, _$identity);
+$ApiSeriesVideoCopyWith<ApiSeriesVideo> get copyWith => _$ApiSeriesVideoCopyWithImpl<ApiSeriesVideo>(this as ApiSeriesVideo, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSearchResult&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSeriesVideo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.episode, episode) || other.episode == episode));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(videos),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,id,title,coverUrl,episode);
 
 @override
 String toString() {
-  return 'ApiSearchResult(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiSeriesVideo(id: $id, title: $title, coverUrl: $coverUrl, episode: $episode)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiSearchResultCopyWith<$Res>  {
-  factory $ApiSearchResultCopyWith(ApiSearchResult value, $Res Function(ApiSearchResult) _then) = _$ApiSearchResultCopyWithImpl;
+abstract mixin class $ApiSeriesVideoCopyWith<$Res>  {
+  factory $ApiSeriesVideoCopyWith(ApiSeriesVideo value, $Res Function(ApiSeriesVideo) _then) = _$ApiSeriesVideoCopyWithImpl;
 @useResult
 $Res call({
- List<ApiVideoCard> videos, int total, int page, bool hasNext
+ String id, String title, String coverUrl, String episode
 });
 
 
@@ -5844,30 +12560,30 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiSearchResultCopyWithImpl<$Res>
-    implements $ApiSearchResultCopyWith<$Res> {
-  _$ApiSearchResultCopyWithImpl(this._self, this._then);
+class _$ApiSeriesVideoCopyWithImpl<$Res>
+    implements $ApiSeriesVideoCopyWith<$Res> {
+  _$ApiSeriesVideoCopyWithImpl(this._self, this._then);
 
-  final ApiSearchResult _self;
-  final $Res Function(ApiSearchResult) _then;
+  final ApiSeriesVideo _self;
+  final $Res Function(ApiSeriesVideo) _then;
 
-/// Create a copy of ApiSearchResult
+/// Create a copy of ApiSeriesVideo
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? coverUrl = null,Object? episode = null,}) {
   return _then(_self.copyWith(
-videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,episode: null == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiSearchResult].
-extension ApiSearchResultPatterns on ApiSearchResult {
+/// Adds pattern-matching-related methods to [ApiSeriesVideo].
+extension ApiSeriesVideoPatterns on ApiSeriesVideo {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -5880,10 +12596,10 @@ extension ApiSearchResultPatterns on ApiSearchResult {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSearchResult value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSeriesVideo value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiSearchResult() when $default != null:
+case _ApiSeriesVideo() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -5902,10 +12618,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSearchResult value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSeriesVideo value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSearchResult():
+case _ApiSeriesVideo():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -5920,10 +12636,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSearchResult value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSeriesVideo value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSearchResult() when $default != null:
+case _ApiSeriesVideo() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -5941,10 +12657,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  String coverUrl,  String episode)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiSearchResult() when $default != null:
-return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
+case _ApiSeriesVideo() when $default != null:
+return $default(_that.id,_that.title,_that.coverUrl,_that.episode);case _:
   return orElse();
 
 }
@@ -5962,10 +12678,10 @@ return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  String coverUrl,  String episode)  $default,) {final _that = this;
 switch (_that) {
-case _ApiSearchResult():
-return $default(_that.videos,_that.total,_that.page,_that.hasNext);}
+case _ApiSeriesVideo():
+return $default(_that.id,_that.title,_that.coverUrl,_that.episode);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -5979,10 +12695,10 @@ return $default(_that.videos,_that.total,_that.page,_that.hasNext);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiVideoCard> videos,  int total,  int page,  bool hasNext)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  String coverUrl,  String episode)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiSearchResult() when $default != null:
-return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
+case _ApiSeriesVideo() when $default != null:
+return $default(_that.id,_that.title,_that.coverUrl,_that.episode);case _:
   return null;
 
 }
@@ -5993,52 +12709,46 @@ return $default(_that.videos,_that.total,_that.page,_that.hasNext);case _:
 /// @nodoc
 
 
-class _ApiSearchResult implements ApiSearchResult {
-  const _ApiSearchResult({required final  List<ApiVideoCard> videos, required this.total, required this.page, required this.hasNext}): _videos = videos;
+class _ApiSeriesVideo implements ApiSeriesVideo {
+  const _ApiSeriesVideo({required this.id, required this.title, required this.coverUrl, required this.episode});
   
 
- final  List<ApiVideoCard> _videos;
-@override List<ApiVideoCard> get videos {
-  if (_videos is EqualUnmodifiableListView) return _videos;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_videos);
-}
-
-@override final  int total;
-@override final  int page;
-@override final  bool hasNext;
+@override final  String id;
+@override final  String title;
+@override final  String coverUrl;
+@override final  String episode;
 
-/// Create a copy of ApiSearchResult
+/// Create a copy of ApiSeriesVideo
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiSearchResultCopyWith<_ApiSearchResult> get copyWith => __$ApiSearchResultCopyWithImpl<_ApiSearchResult>(this, _$identity);
+_$ApiSeriesVideoCopyWith<_ApiSeriesVideo> get copyWith => __$ApiSeriesVideoCopyWithImpl<_ApiSeriesVideo>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSearchResult&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.total, total) || other.total == total)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSeriesVideo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.episode, episode) || other.episode == episode));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_videos),total,page,hasNext);
+int get hashCode => Object.hash(runtimeType,id,title,coverUrl,episode);
 
 @override
 String toString() {
-  return 'ApiSearchResult(videos: $videos, total: $total, page: $page, hasNext: $hasNext)';
+  return 'ApiSeriesVideo(id: $id, title: $title, coverUrl: $coverUrl, episode: $episode)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiSearchResultCopyWith<$Res> implements $ApiSearchResultCopyWith<$Res> {
-  factory _$ApiSearchResultCopyWith(_ApiSearchResult value, $Res Function(_ApiSearchResult) _then) = __$ApiSearchResultCopyWithImpl;
+abstract mixin class _$ApiSeriesVideoCopyWith<$Res> implements $ApiSeriesVideoCopyWith<$Res> {
+  factory _$ApiSeriesVideoCopyWith(_ApiSeriesVideo value, $Res Function(_ApiSeriesVideo) _then) = __$ApiSeriesVideoCopyWithImpl;
 @override @useResult
 $Res call({
- List<ApiVideoCard> videos, int total, int page, bool hasNext
+ String id, String title, String coverUrl, String episode
 });
 
 
@@ -6046,22 +12756,22 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiSearchResultCopyWithImpl<$Res>
-    implements _$ApiSearchResultCopyWith<$Res> {
-  __$ApiSearchResultCopyWithImpl(this._self, this._then);
+class __$ApiSeriesVideoCopyWithImpl<$Res>
+    implements _$ApiSeriesVideoCopyWith<$Res> {
+  __$ApiSeriesVideoCopyWithImpl(this._self, this._then);
 
-  final _ApiSearchResult _self;
-  final $Res Function(_ApiSearchResult) _then;
+  final _ApiSeriesVideo _self;
+  final $Res Function(_ApiSeriesVideo) _then;
 
-/// Create a copy of ApiSearchResult
+/// Create a copy of ApiSeriesVideo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? videos = null,Object? total = null,Object? page = null,Object? hasNext = null,}) {
-  return _then(_ApiSearchResult(
-videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
-as int,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? coverUrl = null,Object? episode = null,}) {
+  return _then(_ApiSeriesVideo(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
+as String,episode: null == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -6069,40 +12779,40 @@ as bool,
 }
 
 /// @nodoc
-mixin _$ApiSeriesInfo {
+mixin _$ApiSmartFolderRule {
 
- String get id; String get title; List<ApiSeriesVideo> get videos; int get currentIndex;
-/// Create a copy of ApiSeriesInfo
+ List<String> get authors; List<String> get tags; List<String> get qualities; PlatformInt64? get downloadedFrom; PlatformInt64? get downloadedTo; String? get uploadedFrom; String? get uploadedTo; bool? get watched;
+/// Create a copy of ApiSmartFolderRule
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiSeriesInfoCopyWith<ApiSeriesInfo> get copyWith => _$ApiSeriesInfoCopyWithImpl<ApiSeriesInfo>(this as ApiSeriesInfo, _$identity);
+$ApiSmartFolderRuleCopyWith<ApiSmartFolderRule> get copyWith => _$ApiSmartFolderRuleCopyWithImpl<ApiSmartFolderRule>(this as ApiSmartFolderRule, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSeriesInfo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.currentIndex, currentIndex) || other.currentIndex == currentIndex));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSmartFolderRule&&const DeepCollectionEquality().equals(other.authors, authors)&&const DeepCollectionEquality().equals(other.tags, tags)&&const DeepCollectionEquality().equals(other.qualities, qualities)&&(identical(other.downloadedFrom, downloadedFrom) || other.downloadedFrom == downloadedFrom)&&(identical(other.downloadedTo, downloadedTo) || other.downloadedTo == downloadedTo)&&(identical(other.uploadedFrom, uploadedFrom) || other.uploadedFrom == uploadedFrom)&&(identical(other.uploadedTo, uploadedTo) || other.uploadedTo == uploadedTo)&&(identical(other.watched, watched) || other.watched == watched));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,title,const DeepCollectionEquality().hash(videos),currentIndex);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(authors),const DeepCollectionEquality().hash(tags),const DeepCollectionEquality().hash(qualities),downloadedFrom,downloadedTo,uploadedFrom,uploadedTo,watched);
 
 @override
 String toString() {
-  return 'ApiSeriesInfo(id: $id, title: $title, videos: $videos, currentIndex: $currentIndex)';
+  return 'ApiSmartFolderRule(authors: $authors, tags: $tags, qualities: $qualities, downloadedFrom: $downloadedFrom, downloadedTo: $downloadedTo, uploadedFrom: $uploadedFrom, uploadedTo: $uploadedTo, watched: $watched)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiSeriesInfoCopyWith<$Res>  {
-  factory $ApiSeriesInfoCopyWith(ApiSeriesInfo value, $Res Function(ApiSeriesInfo) _then) = _$ApiSeriesInfoCopyWithImpl;
+abstract mixin class $ApiSmartFolderRuleCopyWith<$Res>  {
+  factory $ApiSmartFolderRuleCopyWith(ApiSmartFolderRule value, $Res Function(ApiSmartFolderRule) _then) = _$ApiSmartFolderRuleCopyWithImpl;
 @useResult
 $Res call({
- String id, String title, List<ApiSeriesVideo> videos, int currentIndex
+ List<String> authors, List<String> tags, List<String> qualities, PlatformInt64? downloadedFrom, PlatformInt64? downloadedTo, String? uploadedFrom, String? uploadedTo, bool? watched
 });
 
 
@@ -6110,30 +12820,34 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiSeriesInfoCopyWithImpl<$Res>
-    implements $ApiSeriesInfoCopyWith<$Res> {
-  _$ApiSeriesInfoCopyWithImpl(this._self, this._then);
+class _$ApiSmartFolderRuleCopyWithImpl<$Res>
+    implements $ApiSmartFolderRuleCopyWith<$Res> {
+  _$ApiSmartFolderRuleCopyWithImpl(this._self, this._then);
 
-  final ApiSeriesInfo _self;
-  final $Res Function(ApiSeriesInfo) _then;
+  final ApiSmartFolderRule _self;
+  final $Res Function(ApiSmartFolderRule) _then;
 
-/// Create a copy of ApiSeriesInfo
+/// Create a copy of ApiSmartFolderRule
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? videos = null,Object? currentIndex = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? authors = null,Object? tags = null,Object? qualities = null,Object? downloadedFrom = freezed,Object? downloadedTo = freezed,Object? uploadedFrom = freezed,Object? uploadedTo = freezed,Object? watched = freezed,}) {
   return _then(_self.copyWith(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiSeriesVideo>,currentIndex: null == currentIndex ? _self.currentIndex : currentIndex // ignore: cast_nullable_to_non_nullable
-as int,
+authors: null == authors ? _self.authors : authors // ignore: cast_nullable_to_non_nullable
+as List<String>,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,qualities: null == qualities ? _self.qualities : qualities // ignore: cast_nullable_to_non_nullable
+as List<String>,downloadedFrom: freezed == downloadedFrom ? _self.downloadedFrom : downloadedFrom // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,downloadedTo: freezed == downloadedTo ? _self.downloadedTo : downloadedTo // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,uploadedFrom: freezed == uploadedFrom ? _self.uploadedFrom : uploadedFrom // ignore: cast_nullable_to_non_nullable
+as String?,uploadedTo: freezed == uploadedTo ? _self.uploadedTo : uploadedTo // ignore: cast_nullable_to_non_nullable
+as String?,watched: freezed == watched ? _self.watched : watched // ignore: cast_nullable_to_non_nullable
+as bool?,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiSeriesInfo].
-extension ApiSeriesInfoPatterns on ApiSeriesInfo {
+/// Adds pattern-matching-related methods to [ApiSmartFolderRule].
+extension ApiSmartFolderRulePatterns on ApiSmartFolderRule {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -6146,10 +12860,10 @@ extension ApiSeriesInfoPatterns on ApiSeriesInfo {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSeriesInfo value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSmartFolderRule value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiSeriesInfo() when $default != null:
+case _ApiSmartFolderRule() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -6168,10 +12882,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSeriesInfo value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSmartFolderRule value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSeriesInfo():
+case _ApiSmartFolderRule():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -6186,10 +12900,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSeriesInfo value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSmartFolderRule value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSeriesInfo() when $default != null:
+case _ApiSmartFolderRule() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -6207,10 +12921,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  List<ApiSeriesVideo> videos,  int currentIndex)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> authors,  List<String> tags,  List<String> qualities,  PlatformInt64? downloadedFrom,  PlatformInt64? downloadedTo,  String? uploadedFrom,  String? uploadedTo,  bool? watched)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiSeriesInfo() when $default != null:
-return $default(_that.id,_that.title,_that.videos,_that.currentIndex);case _:
+case _ApiSmartFolderRule() when $default != null:
+return $default(_that.authors,_that.tags,_that.qualities,_that.downloadedFrom,_that.downloadedTo,_that.uploadedFrom,_that.uploadedTo,_that.watched);case _:
   return orElse();
 
 }
@@ -6228,10 +12942,10 @@ return $default(_that.id,_that.title,_that.videos,_that.currentIndex);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  List<ApiSeriesVideo> videos,  int currentIndex)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> authors,  List<String> tags,  List<String> qualities,  PlatformInt64? downloadedFrom,  PlatformInt64? downloadedTo,  String? uploadedFrom,  String? uploadedTo,  bool? watched)  $default,) {final _that = this;
 switch (_that) {
-case _ApiSeriesInfo():
-return $default(_that.id,_that.title,_that.videos,_that.currentIndex);}
+case _ApiSmartFolderRule():
+return $default(_that.authors,_that.tags,_that.qualities,_that.downloadedFrom,_that.downloadedTo,_that.uploadedFrom,_that.uploadedTo,_that.watched);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -6245,10 +12959,10 @@ return $default(_that.id,_that.title,_that.videos,_that.currentIndex);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  List<ApiSeriesVideo> videos,  int currentIndex)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> authors,  List<String> tags,  List<String> qualities,  PlatformInt64? downloadedFrom,  PlatformInt64? downloadedTo,  String? uploadedFrom,  String? uploadedTo,  bool? watched)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiSeriesInfo() when $default != null:
-return $default(_that.id,_that.title,_that.videos,_that.currentIndex);case _:
+case _ApiSmartFolderRule() when $default != null:
+return $default(_that.authors,_that.tags,_that.qualities,_that.downloadedFrom,_that.downloadedTo,_that.uploadedFrom,_that.uploadedTo,_that.watched);case _:
   return null;
 
 }
@@ -6259,52 +12973,68 @@ return $default(_that.id,_that.title,_that.videos,_that.currentIndex);case _:
 /// @nodoc
 
 
-class _ApiSeriesInfo implements ApiSeriesInfo {
-  const _ApiSeriesInfo({required this.id, required this.title, required final  List<ApiSeriesVideo> videos, required this.currentIndex}): _videos = videos;
+class _ApiSmartFolderRule extends ApiSmartFolderRule {
+  const _ApiSmartFolderRule({required final  List<String> authors, required final  List<String> tags, required final  List<String> qualities, this.downloadedFrom, this.downloadedTo, this.uploadedFrom, this.uploadedTo, this.watched}): _authors = authors,_tags = tags,_qualities = qualities,super._();
   
 
-@override final  String id;
-@override final  String title;
- final  List<ApiSeriesVideo> _videos;
-@override List<ApiSeriesVideo> get videos {
-  if (_videos is EqualUnmodifiableListView) return _videos;
+ final  List<String> _authors;
+@override List<String> get authors {
+  if (_authors is EqualUnmodifiableListView) return _authors;
   // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_videos);
+  return EqualUnmodifiableListView(_authors);
 }
 
-@override final  int currentIndex;
+ final  List<String> _tags;
+@override List<String> get tags {
+  if (_tags is EqualUnmodifiableListView) return _tags;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_tags);
+}
 
-/// Create a copy of ApiSeriesInfo
+ final  List<String> _qualities;
+@override List<String> get qualities {
+  if (_qualities is EqualUnmodifiableListView) return _qualities;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_qualities);
+}
+
+@override final  PlatformInt64? downloadedFrom;
+@override final  PlatformInt64? downloadedTo;
+@override final  String? uploadedFrom;
+@override final  String? uploadedTo;
+@override final  bool? watched;
+
+/// Create a copy of ApiSmartFolderRule
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiSeriesInfoCopyWith<_ApiSeriesInfo> get copyWith => __$ApiSeriesInfoCopyWithImpl<_ApiSeriesInfo>(this, _$identity);
+_$ApiSmartFolderRuleCopyWith<_ApiSmartFolderRule> get copyWith => __$ApiSmartFolderRuleCopyWithImpl<_ApiSmartFolderRule>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSeriesInfo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.currentIndex, currentIndex) || other.currentIndex == currentIndex));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSmartFolderRule&&const DeepCollectionEquality().equals(other._authors, _authors)&&const DeepCollectionEquality().equals(other._tags, _tags)&&const DeepCollectionEquality().equals(other._qualities, _qualities)&&(identical(other.downloadedFrom, downloadedFrom) || other.downloadedFrom == downloadedFrom)&&(identical(other.downloadedTo, downloadedTo) || other.downloadedTo == downloadedTo)&&(identical(other.uploadedFrom, uploadedFrom) || other.uploadedFrom == uploadedFrom)&&(identical(other.uploadedTo, uploadedTo) || other.uploadedTo == uploadedTo)&&(identical(other.watched, watched) || other.watched == watched));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,title,const DeepCollectionEquality().hash(_videos),currentIndex);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_authors),const DeepCollectionEquality().hash(_tags),const DeepCollectionEquality().hash(_qualities),downloadedFrom,downloadedTo,uploadedFrom,uploadedTo,watched);
 
 @override
 String toString() {
-  return 'ApiSeriesInfo(id: $id, title: $title, videos: $videos, currentIndex: $currentIndex)';
+  return 'ApiSmartFolderRule(authors: $authors, tags: $tags, qualities: $qualities, downloadedFrom: $downloadedFrom, downloadedTo: $downloadedTo, uploadedFrom: $uploadedFrom, uploadedTo: $uploadedTo, watched: $watched)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiSeriesInfoCopyWith<$Res> implements $ApiSeriesInfoCopyWith<$Res> {
-  factory _$ApiSeriesInfoCopyWith(_ApiSeriesInfo value, $Res Function(_ApiSeriesInfo) _then) = __$ApiSeriesInfoCopyWithImpl;
+abstract mixin class _$ApiSmartFolderRuleCopyWith<$Res> implements $ApiSmartFolderRuleCopyWith<$Res> {
+  factory _$ApiSmartFolderRuleCopyWith(_ApiSmartFolderRule value, $Res Function(_ApiSmartFolderRule) _then) = __$ApiSmartFolderRuleCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String title, List<ApiSeriesVideo> videos, int currentIndex
+ List<String> authors, List<String> tags, List<String> qualities, PlatformInt64? downloadedFrom, PlatformInt64? downloadedTo, String? uploadedFrom, String? uploadedTo, bool? watched
 });
 
 
@@ -6312,22 +13042,26 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiSeriesInfoCopyWithImpl<$Res>
-    implements _$ApiSeriesInfoCopyWith<$Res> {
-  __$ApiSeriesInfoCopyWithImpl(this._self, this._then);
+class __$ApiSmartFolderRuleCopyWithImpl<$Res>
+    implements _$ApiSmartFolderRuleCopyWith<$Res> {
+  __$ApiSmartFolderRuleCopyWithImpl(this._self, this._then);
 
-  final _ApiSeriesInfo _self;
-  final $Res Function(_ApiSeriesInfo) _then;
+  final _ApiSmartFolderRule _self;
+  final $Res Function(_ApiSmartFolderRule) _then;
 
-/// Create a copy of ApiSeriesInfo
+/// Create a copy of ApiSmartFolderRule
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? videos = null,Object? currentIndex = null,}) {
-  return _then(_ApiSeriesInfo(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiSeriesVideo>,currentIndex: null == currentIndex ? _self.currentIndex : currentIndex // ignore: cast_nullable_to_non_nullable
-as int,
+@override @pragma('vm:prefer-inline') $Res call({Object? authors = null,Object? tags = null,Object? qualities = null,Object? downloadedFrom = freezed,Object? downloadedTo = freezed,Object? uploadedFrom = freezed,Object? uploadedTo = freezed,Object? watched = freezed,}) {
+  return _then(_ApiSmartFolderRule(
+authors: null == authors ? _self._authors : authors // ignore: cast_nullable_to_non_nullable
+as List<String>,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
+as List<String>,qualities: null == qualities ? _self._qualities : qualities // ignore: cast_nullable_to_non_nullable
+as List<String>,downloadedFrom: freezed == downloadedFrom ? _self.downloadedFrom : downloadedFrom // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,downloadedTo: freezed == downloadedTo ? _self.downloadedTo : downloadedTo // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,uploadedFrom: freezed == uploadedFrom ? _self.uploadedFrom : uploadedFrom // ignore: cast_nullable_to_non_nullable
+as String?,uploadedTo: freezed == uploadedTo ? _self.uploadedTo : uploadedTo // ignore: cast_nullable_to_non_nullable
+as String?,watched: freezed == watched ? _self.watched : watched // ignore: cast_nullable_to_non_nullable
+as bool?,
   ));
 }
 
@@ -6335,40 +13069,40 @@ as int,
 }
 
 /// @nodoc
-mixin _$ApiSeriesVideo {
+mixin _$ApiSubscriptionsPage {
 
- String get id; String get title; String get coverUrl; String get episode;
-/// Create a copy of ApiSeriesVideo
+ List<ApiAuthorInfo> get authors; List<ApiVideoCard> get videos; int get page; bool get hasNext;
+/// Create a copy of ApiSubscriptionsPage
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiSeriesVideoCopyWith<ApiSeriesVideo> get copyWith => _$ApiSeriesVideoCopyWithImpl<ApiSeriesVideo>(this as ApiSeriesVideo, _$identity);
+$ApiSubscriptionsPageCopyWith<ApiSubscriptionsPage> get copyWith => _$ApiSubscriptionsPageCopyWithImpl<ApiSubscriptionsPage>(this as ApiSubscriptionsPage, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSeriesVideo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.episode, episode) || other.episode == episode));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSubscriptionsPage&&const DeepCollectionEquality().equals(other.authors, authors)&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,title,coverUrl,episode);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(authors),const DeepCollectionEquality().hash(videos),page,hasNext);
 
 @override
 String toString() {
-  return 'ApiSeriesVideo(id: $id, title: $title, coverUrl: $coverUrl, episode: $episode)';
+  return 'ApiSubscriptionsPage(authors: $authors, videos: $videos, page: $page, hasNext: $hasNext)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiSeriesVideoCopyWith<$Res>  {
-  factory $ApiSeriesVideoCopyWith(ApiSeriesVideo value, $Res Function(ApiSeriesVideo) _then) = _$ApiSeriesVideoCopyWithImpl;
+abstract mixin class $ApiSubscriptionsPageCopyWith<$Res>  {
+  factory $ApiSubscriptionsPageCopyWith(ApiSubscriptionsPage value, $Res Function(ApiSubscriptionsPage) _then) = _$ApiSubscriptionsPageCopyWithImpl;
 @useResult
 $Res call({
- String id, String title, String coverUrl, String episode
+ List<ApiAuthorInfo> authors, List<ApiVideoCard> videos, int page, bool hasNext
 });
 
 
@@ -6376,30 +13110,30 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiSeriesVideoCopyWithImpl<$Res>
-    implements $ApiSeriesVideoCopyWith<$Res> {
-  _$ApiSeriesVideoCopyWithImpl(this._self, this._then);
+class _$ApiSubscriptionsPageCopyWithImpl<$Res>
+    implements $ApiSubscriptionsPageCopyWith<$Res> {
+  _$ApiSubscriptionsPageCopyWithImpl(this._self, this._then);
 
-  final ApiSeriesVideo _self;
-  final $Res Function(ApiSeriesVideo) _then;
+  final ApiSubscriptionsPage _self;
+  final $Res Function(ApiSubscriptionsPage) _then;
 
-/// Create a copy of ApiSeriesVideo
+/// Create a copy of ApiSubscriptionsPage
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? coverUrl = null,Object? episode = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? authors = null,Object? videos = null,Object? page = null,Object? hasNext = null,}) {
   return _then(_self.copyWith(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
-as String,episode: null == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
-as String,
+authors: null == authors ? _self.authors : authors // ignore: cast_nullable_to_non_nullable
+as List<ApiAuthorInfo>,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiSeriesVideo].
-extension ApiSeriesVideoPatterns on ApiSeriesVideo {
+/// Adds pattern-matching-related methods to [ApiSubscriptionsPage].
+extension ApiSubscriptionsPagePatterns on ApiSubscriptionsPage {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -6412,10 +13146,10 @@ extension ApiSeriesVideoPatterns on ApiSeriesVideo {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSeriesVideo value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSubscriptionsPage value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiSeriesVideo() when $default != null:
+case _ApiSubscriptionsPage() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -6434,10 +13168,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSeriesVideo value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSubscriptionsPage value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSeriesVideo():
+case _ApiSubscriptionsPage():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -6452,10 +13186,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSeriesVideo value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSubscriptionsPage value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSeriesVideo() when $default != null:
+case _ApiSubscriptionsPage() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -6473,10 +13207,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  String coverUrl,  String episode)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiAuthorInfo> authors,  List<ApiVideoCard> videos,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiSeriesVideo() when $default != null:
-return $default(_that.id,_that.title,_that.coverUrl,_that.episode);case _:
+case _ApiSubscriptionsPage() when $default != null:
+return $default(_that.authors,_that.videos,_that.page,_that.hasNext);case _:
   return orElse();
 
 }
@@ -6494,10 +13228,10 @@ return $default(_that.id,_that.title,_that.coverUrl,_that.episode);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  String coverUrl,  String episode)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiAuthorInfo> authors,  List<ApiVideoCard> videos,  int page,  bool hasNext)  $default,) {final _that = this;
 switch (_that) {
-case _ApiSeriesVideo():
-return $default(_that.id,_that.title,_that.coverUrl,_that.episode);}
+case _ApiSubscriptionsPage():
+return $default(_that.authors,_that.videos,_that.page,_that.hasNext);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -6511,10 +13245,10 @@ return $default(_that.id,_that.title,_that.coverUrl,_that.episode);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  String coverUrl,  String episode)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiAuthorInfo> authors,  List<ApiVideoCard> videos,  int page,  bool hasNext)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiSeriesVideo() when $default != null:
-return $default(_that.id,_that.title,_that.coverUrl,_that.episode);case _:
+case _ApiSubscriptionsPage() when $default != null:
+return $default(_that.authors,_that.videos,_that.page,_that.hasNext);case _:
   return null;
 
 }
@@ -6525,46 +13259,58 @@ return $default(_that.id,_that.title,_that.coverUrl,_that.episode);case _:
 /// @nodoc
 
 
-class _ApiSeriesVideo implements ApiSeriesVideo {
-  const _ApiSeriesVideo({required this.id, required this.title, required this.coverUrl, required this.episode});
+class _ApiSubscriptionsPage implements ApiSubscriptionsPage {
+  const _ApiSubscriptionsPage({required final  List<ApiAuthorInfo> authors, required final  List<ApiVideoCard> videos, required this.page, required this.hasNext}): _authors = authors,_videos = videos;
   
 
-@override final  String id;
-@override final  String title;
-@override final  String coverUrl;
-@override final  String episode;
+ final  List<ApiAuthorInfo> _authors;
+@override List<ApiAuthorInfo> get authors {
+  if (_authors is EqualUnmodifiableListView) return _authors;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_authors);
+}
 
-/// Create a copy of ApiSeriesVideo
+ final  List<ApiVideoCard> _videos;
+@override List<ApiVideoCard> get videos {
+  if (_videos is EqualUnmodifiableListView) return _videos;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_videos);
+}
+
+@override final  int page;
+@override final  bool hasNext;
+
+/// Create a copy of ApiSubscriptionsPage
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiSeriesVideoCopyWith<_ApiSeriesVideo> get copyWith => __$ApiSeriesVideoCopyWithImpl<_ApiSeriesVideo>(this, _$identity);
+_$ApiSubscriptionsPageCopyWith<_ApiSubscriptionsPage> get copyWith => __$ApiSubscriptionsPageCopyWithImpl<_ApiSubscriptionsPage>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSeriesVideo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.episode, episode) || other.episode == episode));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSubscriptionsPage&&const DeepCollectionEquality().equals(other._authors, _authors)&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,title,coverUrl,episode);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_authors),const DeepCollectionEquality().hash(_videos),page,hasNext);
 
 @override
 String toString() {
-  return 'ApiSeriesVideo(id: $id, title: $title, coverUrl: $coverUrl, episode: $episode)';
+  return 'ApiSubscriptionsPage(authors: $authors, videos: $videos, page: $page, hasNext: $hasNext)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiSeriesVideoCopyWith<$Res> implements $ApiSeriesVideoCopyWith<$Res> {
-  factory _$ApiSeriesVideoCopyWith(_ApiSeriesVideo value, $Res Function(_ApiSeriesVideo) _then) = __$ApiSeriesVideoCopyWithImpl;
+abstract mixin class _$ApiSubscriptionsPageCopyWith<$Res> implements $ApiSubscriptionsPageCopyWith<$Res> {
+  factory _$ApiSubscriptionsPageCopyWith(_ApiSubscriptionsPage value, $Res Function(_ApiSubscriptionsPage) _then) = __$ApiSubscriptionsPageCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String title, String coverUrl, String episode
+ List<ApiAuthorInfo> authors, List<ApiVideoCard> videos, int page, bool hasNext
 });
 
 
@@ -6572,22 +13318,22 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiSeriesVideoCopyWithImpl<$Res>
-    implements _$ApiSeriesVideoCopyWith<$Res> {
-  __$ApiSeriesVideoCopyWithImpl(this._self, this._then);
+class __$ApiSubscriptionsPageCopyWithImpl<$Res>
+    implements _$ApiSubscriptionsPageCopyWith<$Res> {
+  __$ApiSubscriptionsPageCopyWithImpl(this._self, this._then);
 
-  final _ApiSeriesVideo _self;
-  final $Res Function(_ApiSeriesVideo) _then;
+  final _ApiSubscriptionsPage _self;
+  final $Res Function(_ApiSubscriptionsPage) _then;
 
-/// Create a copy of ApiSeriesVideo
+/// Create a copy of ApiSubscriptionsPage
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? coverUrl = null,Object? episode = null,}) {
-  return _then(_ApiSeriesVideo(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
-as String,coverUrl: null == coverUrl ? _self.coverUrl : coverUrl // ignore: cast_nullable_to_non_nullable
-as String,episode: null == episode ? _self.episode : episode // ignore: cast_nullable_to_non_nullable
-as String,
+@override @pragma('vm:prefer-inline') $Res call({Object? authors = null,Object? videos = null,Object? page = null,Object? hasNext = null,}) {
+  return _then(_ApiSubscriptionsPage(
+authors: null == authors ? _self._authors : authors // ignore: cast_nullable_to_non_nullable
+as List<ApiAuthorInfo>,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoCard>,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -6595,40 +13341,40 @@ as String,
 }
 
 /// @nodoc
-mixin _$ApiSubscriptionsPage {
+mixin _$ApiSubtitleTrack {
 
- List<ApiAuthorInfo> get authors; List<ApiVideoCard> get videos; int get page; bool get hasNext;
-/// Create a copy of ApiSubscriptionsPage
+ String get language; String get label; String get url; String get format;
+/// Create a copy of ApiSubtitleTrack
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ApiSubscriptionsPageCopyWith<ApiSubscriptionsPage> get copyWith => _$ApiSubscriptionsPageCopyWithImpl<ApiSubscriptionsPage>(this as ApiSubscriptionsPage, _$identity);
+$ApiSubtitleTrackCopyWith<ApiSubtitleTrack> get copyWith => _$ApiSubtitleTrackCopyWithImpl<ApiSubtitleTrack>(this as ApiSubtitleTrack, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSubscriptionsPage&&const DeepCollectionEquality().equals(other.authors, authors)&&const DeepCollectionEquality().equals(other.videos, videos)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiSubtitleTrack&&(identical(other.language, language) || other.language == language)&&(identical(other.label, label) || other.label == label)&&(identical(other.url, url) || other.url == url)&&(identical(other.format, format) || other.format == format));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(authors),const DeepCollectionEquality().hash(videos),page,hasNext);
+int get hashCode => Object.hash(runtimeType,language,label,url,format);
 
 @override
 String toString() {
-  return 'ApiSubscriptionsPage(authors: $authors, videos: $videos, page: $page, hasNext: $hasNext)';
+  return 'ApiSubtitleTrack(language: $language, label: $label, url: $url, format: $format)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ApiSubscriptionsPageCopyWith<$Res>  {
-  factory $ApiSubscriptionsPageCopyWith(ApiSubscriptionsPage value, $Res Function(ApiSubscriptionsPage) _then) = _$ApiSubscriptionsPageCopyWithImpl;
+abstract mixin class $ApiSubtitleTrackCopyWith<$Res>  {
+  factory $ApiSubtitleTrackCopyWith(ApiSubtitleTrack value, $Res Function(ApiSubtitleTrack) _then) = _$ApiSubtitleTrackCopyWithImpl;
 @useResult
 $Res call({
- List<ApiAuthorInfo> authors, List<ApiVideoCard> videos, int page, bool hasNext
+ String language, String label, String url, String format
 });
 
 
@@ -6636,30 +13382,30 @@ $Res call({
 
 }
 /// @nodoc
-class _$ApiSubscriptionsPageCopyWithImpl<$Res>
-    implements $ApiSubscriptionsPageCopyWith<$Res> {
-  _$ApiSubscriptionsPageCopyWithImpl(this._self, this._then);
+class _$ApiSubtitleTrackCopyWithImpl<$Res>
+    implements $ApiSubtitleTrackCopyWith<$Res> {
+  _$ApiSubtitleTrackCopyWithImpl(this._self, this._then);
 
-  final ApiSubscriptionsPage _self;
-  final $Res Function(ApiSubscriptionsPage) _then;
+  final ApiSubtitleTrack _self;
+  final $Res Function(ApiSubtitleTrack) _then;
 
-/// Create a copy of ApiSubscriptionsPage
+/// Create a copy of ApiSubtitleTrack
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? authors = null,Object? videos = null,Object? page = null,Object? hasNext = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? language = null,Object? label = null,Object? url = null,Object? format = null,}) {
   return _then(_self.copyWith(
-authors: null == authors ? _self.authors : authors // ignore: cast_nullable_to_non_nullable
-as List<ApiAuthorInfo>,videos: null == videos ? _self.videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+language: null == language ? _self.language : language // ignore: cast_nullable_to_non_nullable
+as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
+as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,format: null == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [ApiSubscriptionsPage].
-extension ApiSubscriptionsPagePatterns on ApiSubscriptionsPage {
+/// Adds pattern-matching-related methods to [ApiSubtitleTrack].
+extension ApiSubtitleTrackPatterns on ApiSubtitleTrack {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -6672,10 +13418,10 @@ extension ApiSubscriptionsPagePatterns on ApiSubscriptionsPage {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSubscriptionsPage value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiSubtitleTrack value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ApiSubscriptionsPage() when $default != null:
+case _ApiSubtitleTrack() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -6694,10 +13440,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSubscriptionsPage value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiSubtitleTrack value)  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSubscriptionsPage():
+case _ApiSubtitleTrack():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -6712,10 +13458,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSubscriptionsPage value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiSubtitleTrack value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ApiSubscriptionsPage() when $default != null:
+case _ApiSubtitleTrack() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -6733,10 +13479,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiAuthorInfo> authors,  List<ApiVideoCard> videos,  int page,  bool hasNext)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String language,  String label,  String url,  String format)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ApiSubscriptionsPage() when $default != null:
-return $default(_that.authors,_that.videos,_that.page,_that.hasNext);case _:
+case _ApiSubtitleTrack() when $default != null:
+return $default(_that.language,_that.label,_that.url,_that.format);case _:
   return orElse();
 
 }
@@ -6754,10 +13500,10 @@ return $default(_that.authors,_that.videos,_that.page,_that.hasNext);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiAuthorInfo> authors,  List<ApiVideoCard> videos,  int page,  bool hasNext)  $default,) {final _that = this;
-switch (_that) {
-case _ApiSubscriptionsPage():
-return $default(_that.authors,_that.videos,_that.page,_that.hasNext);}
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String language,  String label,  String url,  String format)  $default,) {final _that = this;
+switch (_that) {
+case _ApiSubtitleTrack():
+return $default(_that.language,_that.label,_that.url,_that.format);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -6771,10 +13517,10 @@ return $default(_that.authors,_that.videos,_that.page,_that.hasNext);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiAuthorInfo> authors,  List<ApiVideoCard> videos,  int page,  bool hasNext)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String language,  String label,  String url,  String format)?  $default,) {final _that = this;
 switch (_that) {
-case _ApiSubscriptionsPage() when $default != null:
-return $default(_that.authors,_that.videos,_that.page,_that.hasNext);case _:
+case _ApiSubtitleTrack() when $default != null:
+return $default(_that.language,_that.label,_that.url,_that.format);case _:
   return null;
 
 }
@@ -6785,58 +13531,46 @@ return $default(_that.authors,_that.videos,_that.page,_that.hasNext);case _:
 /// @nodoc
 
 
-class _ApiSubscriptionsPage implements ApiSubscriptionsPage {
-  const _ApiSubscriptionsPage({required final  List<ApiAuthorInfo> authors, required final  List<ApiVideoCard> videos, required this.page, required this.hasNext}): _authors = authors,_videos = videos;
+class _ApiSubtitleTrack implements ApiSubtitleTrack {
+  const _ApiSubtitleTrack({required this.language, required this.label, required this.url, required this.format});
   
 
- final  List<ApiAuthorInfo> _authors;
-@override List<ApiAuthorInfo> get authors {
-  if (_authors is EqualUnmodifiableListView) return _authors;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_authors);
-}
-
- final  List<ApiVideoCard> _videos;
-@override List<ApiVideoCard> get videos {
-  if (_videos is EqualUnmodifiableListView) return _videos;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_videos);
-}
-
-@override final  int page;
-@override final  bool hasNext;
+@override final  String language;
+@override final  String label;
+@override final  String url;
+@override final  String format;
 
-/// Create a copy of ApiSubscriptionsPage
+/// Create a copy of ApiSubtitleTrack
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ApiSubscriptionsPageCopyWith<_ApiSubscriptionsPage> get copyWith => __$ApiSubscriptionsPageCopyWithImpl<_ApiSubscriptionsPage>(this, _$identity);
+_$ApiSubtitleTrackCopyWith<_ApiSubtitleTrack> get copyWith => __$ApiSubtitleTrackCopyWithImpl<_ApiSubtitleTrack>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSubscriptionsPage&&const DeepCollectionEquality().equals(other._authors, _authors)&&const DeepCollectionEquality().equals(other._videos, _videos)&&(identical(other.page, page) || other.page == page)&&(identical(other.hasNext, hasNext) || other.hasNext == hasNext));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiSubtitleTrack&&(identical(other.language, language) || other.language == language)&&(identical(other.label, label) || other.label == label)&&(identical(other.url, url) || other.url == url)&&(identical(other.format, format) || other.format == format));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_authors),const DeepCollectionEquality().hash(_videos),page,hasNext);
+int get hashCode => Object.hash(runtimeType,language,label,url,format);
 
 @override
 String toString() {
-  return 'ApiSubscriptionsPage(authors: $authors, videos: $videos, page: $page, hasNext: $hasNext)';
+  return 'ApiSubtitleTrack(language: $language, label: $label, url: $url, format: $format)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ApiSubscriptionsPageCopyWith<$Res> implements $ApiSubscriptionsPageCopyWith<$Res> {
-  factory _$ApiSubscriptionsPageCopyWith(_ApiSubscriptionsPage value, $Res Function(_ApiSubscriptionsPage) _then) = __$ApiSubscriptionsPageCopyWithImpl;
+abstract mixin class _$ApiSubtitleTrackCopyWith<$Res> implements $ApiSubtitleTrackCopyWith<$Res> {
+  factory _$ApiSubtitleTrackCopyWith(_ApiSubtitleTrack value, $Res Function(_ApiSubtitleTrack) _then) = __$ApiSubtitleTrackCopyWithImpl;
 @override @useResult
 $Res call({
- List<ApiAuthorInfo> authors, List<ApiVideoCard> videos, int page, bool hasNext
+ String language, String label, String url, String format
 });
 
 
@@ -6844,22 +13578,22 @@ $Res call({
 
 }
 /// @nodoc
-class __$ApiSubscriptionsPageCopyWithImpl<$Res>
-    implements _$ApiSubscriptionsPageCopyWith<$Res> {
-  __$ApiSubscriptionsPageCopyWithImpl(this._self, this._then);
+class __$ApiSubtitleTrackCopyWithImpl<$Res>
+    implements _$ApiSubtitleTrackCopyWith<$Res> {
+  __$ApiSubtitleTrackCopyWithImpl(this._self, this._then);
 
-  final _ApiSubscriptionsPage _self;
-  final $Res Function(_ApiSubscriptionsPage) _then;
+  final _ApiSubtitleTrack _self;
+  final $Res Function(_ApiSubtitleTrack) _then;
 
-/// Create a copy of ApiSubscriptionsPage
+/// Create a copy of ApiSubtitleTrack
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? authors = null,Object? videos = null,Object? page = null,Object? hasNext = null,}) {
-  return _then(_ApiSubscriptionsPage(
-authors: null == authors ? _self._authors : authors // ignore: cast_nullable_to_non_nullable
-as List<ApiAuthorInfo>,videos: null == videos ? _self._videos : videos // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoCard>,page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
-as int,hasNext: null == hasNext ? _self.hasNext : hasNext // ignore: cast_nullable_to_non_nullable
-as bool,
+@override @pragma('vm:prefer-inline') $Res call({Object? language = null,Object? label = null,Object? url = null,Object? format = null,}) {
+  return _then(_ApiSubtitleTrack(
+language: null == language ? _self.language : language // ignore: cast_nullable_to_non_nullable
+as String,label: null == label ? _self.label : label // ignore: cast_nullable_to_non_nullable
+as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,format: null == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -7667,7 +14401,7 @@ as List<String>,
 /// @nodoc
 mixin _$ApiVideoDetail {
 
- String get id; String get title; String? get chineseTitle; String get coverUrl; String? get description; String? get duration; String? get views; int? get likePercent; int? get dislikePercent; int? get likesCount; int? get dislikesCount; String? get uploadDate; ApiAuthorInfo? get author; List<String> get tags; List<ApiVideoQuality> get qualities; ApiSeriesInfo? get series; List<ApiVideoCard> get relatedVideos; String? get formToken; String? get currentUserId; bool get isFav; int? get favTimes; ApiPlaylistInfo? get playlist; ApiMyListInfo? get myList;
+ String get id; String get title; String? get chineseTitle; String get coverUrl; String? get description; String? get duration; String? get views; int? get likePercent; int? get dislikePercent; int? get likesCount; int? get dislikesCount; String? get uploadDate; ApiAuthorInfo? get author; List<String> get tags; List<ApiVideoQuality> get qualities; List<ApiSubtitleTrack> get subtitles; ApiSeriesInfo? get series; List<ApiVideoCard> get relatedVideos; String? get formToken; String? get currentUserId; bool get isFav; int? get favTimes; ApiPlaylistInfo? get playlist; ApiMyListInfo? get myList;
 /// Create a copy of ApiVideoDetail
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
@@ -7678,16 +14412,16 @@ $ApiVideoDetailCopyWith<ApiVideoDetail> get copyWith => _$ApiVideoDetailCopyWith
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiVideoDetail&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.chineseTitle, chineseTitle) || other.chineseTitle == chineseTitle)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.description, description) || other.description == description)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.views, views) || other.views == views)&&(identical(other.likePercent, likePercent) || other.likePercent == likePercent)&&(identical(other.dislikePercent, dislikePercent) || other.dislikePercent == dislikePercent)&&(identical(other.likesCount, likesCount) || other.likesCount == likesCount)&&(identical(other.dislikesCount, dislikesCount) || other.dislikesCount == dislikesCount)&&(identical(other.uploadDate, uploadDate) || other.uploadDate == uploadDate)&&(identical(other.author, author) || other.author == author)&&const DeepCollectionEquality().equals(other.tags, tags)&&const DeepCollectionEquality().equals(other.qualities, qualities)&&(identical(other.series, series) || other.series == series)&&const DeepCollectionEquality().equals(other.relatedVideos, relatedVideos)&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.currentUserId, currentUserId) || other.currentUserId == currentUserId)&&(identical(other.isFav, isFav) || other.isFav == isFav)&&(identical(other.favTimes, favTimes) || other.favTimes == favTimes)&&(identical(other.playlist, playlist) || other.playlist == playlist)&&(identical(other.myList, myList) || other.myList == myList));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiVideoDetail&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.chineseTitle, chineseTitle) || other.chineseTitle == chineseTitle)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.description, description) || other.description == description)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.views, views) || other.views == views)&&(identical(other.likePercent, likePercent) || other.likePercent == likePercent)&&(identical(other.dislikePercent, dislikePercent) || other.dislikePercent == dislikePercent)&&(identical(other.likesCount, likesCount) || other.likesCount == likesCount)&&(identical(other.dislikesCount, dislikesCount) || other.dislikesCount == dislikesCount)&&(identical(other.uploadDate, uploadDate) || other.uploadDate == uploadDate)&&(identical(other.author, author) || other.author == author)&&const DeepCollectionEquality().equals(other.tags, tags)&&const DeepCollectionEquality().equals(other.qualities, qualities)&&const DeepCollectionEquality().equals(other.subtitles, subtitles)&&(identical(other.series, series) || other.series == series)&&const DeepCollectionEquality().equals(other.relatedVideos, relatedVideos)&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.currentUserId, currentUserId) || other.currentUserId == currentUserId)&&(identical(other.isFav, isFav) || other.isFav == isFav)&&(identical(other.favTimes, favTimes) || other.favTimes == favTimes)&&(identical(other.playlist, playlist) || other.playlist == playlist)&&(identical(other.myList, myList) || other.myList == myList));
 }
 
 
 @override
-int get hashCode => Object.hashAll([runtimeType,id,title,chineseTitle,coverUrl,description,duration,views,likePercent,dislikePercent,likesCount,dislikesCount,uploadDate,author,const DeepCollectionEquality().hash(tags),const DeepCollectionEquality().hash(qualities),series,const DeepCollectionEquality().hash(relatedVideos),formToken,currentUserId,isFav,favTimes,playlist,myList]);
+int get hashCode => Object.hashAll([runtimeType,id,title,chineseTitle,coverUrl,description,duration,views,likePercent,dislikePercent,likesCount,dislikesCount,uploadDate,author,const DeepCollectionEquality().hash(tags),const DeepCollectionEquality().hash(qualities),const DeepCollectionEquality().hash(subtitles),series,const DeepCollectionEquality().hash(relatedVideos),formToken,currentUserId,isFav,favTimes,playlist,myList]);
 
 @override
 String toString() {
-  return 'ApiVideoDetail(id: $id, title: $title, chineseTitle: $chineseTitle, coverUrl: $coverUrl, description: $description, duration: $duration, views: $views, likePercent: $likePercent, dislikePercent: $dislikePercent, likesCount: $likesCount, dislikesCount: $dislikesCount, uploadDate: $uploadDate, author: $author, tags: $tags, qualities: $qualities, series: $series, relatedVideos: $relatedVideos, formToken: $formToken, currentUserId: $currentUserId, isFav: $isFav, favTimes: $favTimes, playlist: $playlist, myList: $myList)';
+  return 'ApiVideoDetail(id: $id, title: $title, chineseTitle: $chineseTitle, coverUrl: $coverUrl, description: $description, duration: $duration, views: $views, likePercent: $likePercent, dislikePercent: $dislikePercent, likesCount: $likesCount, dislikesCount: $dislikesCount, uploadDate: $uploadDate, author: $author, tags: $tags, qualities: $qualities, subtitles: $subtitles, series: $series, relatedVideos: $relatedVideos, formToken: $formToken, currentUserId: $currentUserId, isFav: $isFav, favTimes: $favTimes, playlist: $playlist, myList: $myList)';
 }
 
 
@@ -7698,7 +14432,7 @@ abstract mixin class $ApiVideoDetailCopyWith<$Res>  {
   factory $ApiVideoDetailCopyWith(ApiVideoDetail value, $Res Function(ApiVideoDetail) _then) = _$ApiVideoDetailCopyWithImpl;
 @useResult
 $Res call({
- String id, String title, String? chineseTitle, String coverUrl, String? description, String? duration, String? views, int? likePercent, int? dislikePercent, int? likesCount, int? dislikesCount, String? uploadDate, ApiAuthorInfo? author, List<String> tags, List<ApiVideoQuality> qualities, ApiSeriesInfo? series, List<ApiVideoCard> relatedVideos, String? formToken, String? currentUserId, bool isFav, int? favTimes, ApiPlaylistInfo? playlist, ApiMyListInfo? myList
+ String id, String title, String? chineseTitle, String coverUrl, String? description, String? duration, String? views, int? likePercent, int? dislikePercent, int? likesCount, int? dislikesCount, String? uploadDate, ApiAuthorInfo? author, List<String> tags, List<ApiVideoQuality> qualities, List<ApiSubtitleTrack> subtitles, ApiSeriesInfo? series, List<ApiVideoCard> relatedVideos, String? formToken, String? currentUserId, bool isFav, int? favTimes, ApiPlaylistInfo? playlist, ApiMyListInfo? myList
 });
 
 
@@ -7715,7 +14449,7 @@ class _$ApiVideoDetailCopyWithImpl<$Res>
 
 /// Create a copy of ApiVideoDetail
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? chineseTitle = freezed,Object? coverUrl = null,Object? description = freezed,Object? duration = freezed,Object? views = freezed,Object? likePercent = freezed,Object? dislikePercent = freezed,Object? likesCount = freezed,Object? dislikesCount = freezed,Object? uploadDate = freezed,Object? author = freezed,Object? tags = null,Object? qualities = null,Object? series = freezed,Object? relatedVideos = null,Object? formToken = freezed,Object? currentUserId = freezed,Object? isFav = null,Object? favTimes = freezed,Object? playlist = freezed,Object? myList = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? chineseTitle = freezed,Object? coverUrl = null,Object? description = freezed,Object? duration = freezed,Object? views = freezed,Object? likePercent = freezed,Object? dislikePercent = freezed,Object? likesCount = freezed,Object? dislikesCount = freezed,Object? uploadDate = freezed,Object? author = freezed,Object? tags = null,Object? qualities = null,Object? subtitles = null,Object? series = freezed,Object? relatedVideos = null,Object? formToken = freezed,Object? currentUserId = freezed,Object? isFav = null,Object? favTimes = freezed,Object? playlist = freezed,Object? myList = freezed,}) {
   return _then(_self.copyWith(
 id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
 as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
@@ -7732,7 +14466,8 @@ as int?,uploadDate: freezed == uploadDate ? _self.uploadDate : uploadDate // ign
 as String?,author: freezed == author ? _self.author : author // ignore: cast_nullable_to_non_nullable
 as ApiAuthorInfo?,tags: null == tags ? _self.tags : tags // ignore: cast_nullable_to_non_nullable
 as List<String>,qualities: null == qualities ? _self.qualities : qualities // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoQuality>,series: freezed == series ? _self.series : series // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoQuality>,subtitles: null == subtitles ? _self.subtitles : subtitles // ignore: cast_nullable_to_non_nullable
+as List<ApiSubtitleTrack>,series: freezed == series ? _self.series : series // ignore: cast_nullable_to_non_nullable
 as ApiSeriesInfo?,relatedVideos: null == relatedVideos ? _self.relatedVideos : relatedVideos // ignore: cast_nullable_to_non_nullable
 as List<ApiVideoCard>,formToken: freezed == formToken ? _self.formToken : formToken // ignore: cast_nullable_to_non_nullable
 as String?,currentUserId: freezed == currentUserId ? _self.currentUserId : currentUserId // ignore: cast_nullable_to_non_nullable
@@ -7870,10 +14605,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  String? chineseTitle,  String coverUrl,  String? description,  String? duration,  String? views,  int? likePercent,  int? dislikePercent,  int? likesCount,  int? dislikesCount,  String? uploadDate,  ApiAuthorInfo? author,  List<String> tags,  List<ApiVideoQuality> qualities,  ApiSeriesInfo? series,  List<ApiVideoCard> relatedVideos,  String? formToken,  String? currentUserId,  bool isFav,  int? favTimes,  ApiPlaylistInfo? playlist,  ApiMyListInfo? myList)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  String? chineseTitle,  String coverUrl,  String? description,  String? duration,  String? views,  int? likePercent,  int? dislikePercent,  int? likesCount,  int? dislikesCount,  String? uploadDate,  ApiAuthorInfo? author,  List<String> tags,  List<ApiVideoQuality> qualities,  List<ApiSubtitleTrack> subtitles,  ApiSeriesInfo? series,  List<ApiVideoCard> relatedVideos,  String? formToken,  String? currentUserId,  bool isFav,  int? favTimes,  ApiPlaylistInfo? playlist,  ApiMyListInfo? myList)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case _ApiVideoDetail() when $default != null:
-return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.description,_that.duration,_that.views,_that.likePercent,_that.dislikePercent,_that.likesCount,_that.dislikesCount,_that.uploadDate,_that.author,_that.tags,_that.qualities,_that.series,_that.relatedVideos,_that.formToken,_that.currentUserId,_that.isFav,_that.favTimes,_that.playlist,_that.myList);case _:
+return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.description,_that.duration,_that.views,_that.likePercent,_that.dislikePercent,_that.likesCount,_that.dislikesCount,_that.uploadDate,_that.author,_that.tags,_that.qualities,_that.subtitles,_that.series,_that.relatedVideos,_that.formToken,_that.currentUserId,_that.isFav,_that.favTimes,_that.playlist,_that.myList);case _:
   return orElse();
 
 }
@@ -7891,10 +14626,10 @@ return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.des
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  String? chineseTitle,  String coverUrl,  String? description,  String? duration,  String? views,  int? likePercent,  int? dislikePercent,  int? likesCount,  int? dislikesCount,  String? uploadDate,  ApiAuthorInfo? author,  List<String> tags,  List<ApiVideoQuality> qualities,  ApiSeriesInfo? series,  List<ApiVideoCard> relatedVideos,  String? formToken,  String? currentUserId,  bool isFav,  int? favTimes,  ApiPlaylistInfo? playlist,  ApiMyListInfo? myList)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  String? chineseTitle,  String coverUrl,  String? description,  String? duration,  String? views,  int? likePercent,  int? dislikePercent,  int? likesCount,  int? dislikesCount,  String? uploadDate,  ApiAuthorInfo? author,  List<String> tags,  List<ApiVideoQuality> qualities,  List<ApiSubtitleTrack> subtitles,  ApiSeriesInfo? series,  List<ApiVideoCard> relatedVideos,  String? formToken,  String? currentUserId,  bool isFav,  int? favTimes,  ApiPlaylistInfo? playlist,  ApiMyListInfo? myList)  $default,) {final _that = this;
 switch (_that) {
 case _ApiVideoDetail():
-return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.description,_that.duration,_that.views,_that.likePercent,_that.dislikePercent,_that.likesCount,_that.dislikesCount,_that.uploadDate,_that.author,_that.tags,_that.qualities,_that.series,_that.relatedVideos,_that.formToken,_that.currentUserId,_that.isFav,_that.favTimes,_that.playlist,_that.myList);}
+return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.description,_that.duration,_that.views,_that.likePercent,_that.dislikePercent,_that.likesCount,_that.dislikesCount,_that.uploadDate,_that.author,_that.tags,_that.qualities,_that.subtitles,_that.series,_that.relatedVideos,_that.formToken,_that.currentUserId,_that.isFav,_that.favTimes,_that.playlist,_that.myList);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -7908,10 +14643,10 @@ return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.des
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  String? chineseTitle,  String coverUrl,  String? description,  String? duration,  String? views,  int? likePercent,  int? dislikePercent,  int? likesCount,  int? dislikesCount,  String? uploadDate,  ApiAuthorInfo? author,  List<String> tags,  List<ApiVideoQuality> qualities,  ApiSeriesInfo? series,  List<ApiVideoCard> relatedVideos,  String? formToken,  String? currentUserId,  bool isFav,  int? favTimes,  ApiPlaylistInfo? playlist,  ApiMyListInfo? myList)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  String? chineseTitle,  String coverUrl,  String? description,  String? duration,  String? views,  int? likePercent,  int? dislikePercent,  int? likesCount,  int? dislikesCount,  String? uploadDate,  ApiAuthorInfo? author,  List<String> tags,  List<ApiVideoQuality> qualities,  List<ApiSubtitleTrack> subtitles,  ApiSeriesInfo? series,  List<ApiVideoCard> relatedVideos,  String? formToken,  String? currentUserId,  bool isFav,  int? favTimes,  ApiPlaylistInfo? playlist,  ApiMyListInfo? myList)?  $default,) {final _that = this;
 switch (_that) {
 case _ApiVideoDetail() when $default != null:
-return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.description,_that.duration,_that.views,_that.likePercent,_that.dislikePercent,_that.likesCount,_that.dislikesCount,_that.uploadDate,_that.author,_that.tags,_that.qualities,_that.series,_that.relatedVideos,_that.formToken,_that.currentUserId,_that.isFav,_that.favTimes,_that.playlist,_that.myList);case _:
+return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.description,_that.duration,_that.views,_that.likePercent,_that.dislikePercent,_that.likesCount,_that.dislikesCount,_that.uploadDate,_that.author,_that.tags,_that.qualities,_that.subtitles,_that.series,_that.relatedVideos,_that.formToken,_that.currentUserId,_that.isFav,_that.favTimes,_that.playlist,_that.myList);case _:
   return null;
 
 }
@@ -7923,7 +14658,7 @@ return $default(_that.id,_that.title,_that.chineseTitle,_that.coverUrl,_that.des
 
 
 class _ApiVideoDetail implements ApiVideoDetail {
-  const _ApiVideoDetail({required this.id, required this.title, this.chineseTitle, required this.coverUrl, this.description, this.duration, this.views, this.likePercent, this.dislikePercent, this.likesCount, this.dislikesCount, this.uploadDate, this.author, required final  List<String> tags, required final  List<ApiVideoQuality> qualities, this.series, required final  List<ApiVideoCard> relatedVideos, this.formToken, this.currentUserId, required this.isFav, this.favTimes, this.playlist, this.myList}): _tags = tags,_qualities = qualities,_relatedVideos = relatedVideos;
+  const _ApiVideoDetail({required this.id, required this.title, this.chineseTitle, required this.coverUrl, this.description, this.duration, this.views, this.likePercent, this.dislikePercent, this.likesCount, this.dislikesCount, this.uploadDate, this.author, required final  List<String> tags, required final  List<ApiVideoQuality> qualities, required final  List<ApiSubtitleTrack> subtitles, this.series, required final  List<ApiVideoCard> relatedVideos, this.formToken, this.currentUserId, required this.isFav, this.favTimes, this.playlist, this.myList}): _tags = tags,_qualities = qualities,_subtitles = subtitles,_relatedVideos = relatedVideos;
   
 
 @override final  String id;
@@ -7953,6 +14688,13 @@ class _ApiVideoDetail implements ApiVideoDetail {
   return EqualUnmodifiableListView(_qualities);
 }
 
+ final  List<ApiSubtitleTrack> _subtitles;
+@override List<ApiSubtitleTrack> get subtitles {
+  if (_subtitles is EqualUnmodifiableListView) return _subtitles;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_subtitles);
+}
+
 @override final  ApiSeriesInfo? series;
  final  List<ApiVideoCard> _relatedVideos;
 @override List<ApiVideoCard> get relatedVideos {
@@ -7978,16 +14720,16 @@ _$ApiVideoDetailCopyWith<_ApiVideoDetail> get copyWith => __$ApiVideoDetailCopyW
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiVideoDetail&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.chineseTitle, chineseTitle) || other.chineseTitle == chineseTitle)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.description, description) || other.description == description)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.views, views) || other.views == views)&&(identical(other.likePercent, likePercent) || other.likePercent == likePercent)&&(identical(other.dislikePercent, dislikePercent) || other.dislikePercent == dislikePercent)&&(identical(other.likesCount, likesCount) || other.likesCount == likesCount)&&(identical(other.dislikesCount, dislikesCount) || other.dislikesCount == dislikesCount)&&(identical(other.uploadDate, uploadDate) || other.uploadDate == uploadDate)&&(identical(other.author, author) || other.author == author)&&const DeepCollectionEquality().equals(other._tags, _tags)&&const DeepCollectionEquality().equals(other._qualities, _qualities)&&(identical(other.series, series) || other.series == series)&&const DeepCollectionEquality().equals(other._relatedVideos, _relatedVideos)&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.currentUserId, currentUserId) || other.currentUserId == currentUserId)&&(identical(other.isFav, isFav) || other.isFav == isFav)&&(identical(other.favTimes, favTimes) || other.favTimes == favTimes)&&(identical(other.playlist, playlist) || other.playlist == playlist)&&(identical(other.myList, myList) || other.myList == myList));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiVideoDetail&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.chineseTitle, chineseTitle) || other.chineseTitle == chineseTitle)&&(identical(other.coverUrl, coverUrl) || other.coverUrl == coverUrl)&&(identical(other.description, description) || other.description == description)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.views, views) || other.views == views)&&(identical(other.likePercent, likePercent) || other.likePercent == likePercent)&&(identical(other.dislikePercent, dislikePercent) || other.dislikePercent == dislikePercent)&&(identical(other.likesCount, likesCount) || other.likesCount == likesCount)&&(identical(other.dislikesCount, dislikesCount) || other.dislikesCount == dislikesCount)&&(identical(other.uploadDate, uploadDate) || other.uploadDate == uploadDate)&&(identical(other.author, author) || other.author == author)&&const DeepCollectionEquality().equals(other._tags, _tags)&&const DeepCollectionEquality().equals(other._qualities, _qualities)&&const DeepCollectionEquality().equals(other._subtitles, _subtitles)&&(identical(other.series, series) || other.series == series)&&const DeepCollectionEquality().equals(other._relatedVideos, _relatedVideos)&&(identical(other.formToken, formToken) || other.formToken == formToken)&&(identical(other.currentUserId, currentUserId) || other.currentUserId == currentUserId)&&(identical(other.isFav, isFav) || other.isFav == isFav)&&(identical(other.favTimes, favTimes) || other.favTimes == favTimes)&&(identical(other.playlist, playlist) || other.playlist == playlist)&&(identical(other.myList, myList) || other.myList == myList));
 }
 
 
 @override
-int get hashCode => Object.hashAll([runtimeType,id,title,chineseTitle,coverUrl,description,duration,views,likePercent,dislikePercent,likesCount,dislikesCount,uploadDate,author,const DeepCollectionEquality().hash(_tags),const DeepCollectionEquality().hash(_qualities),series,const DeepCollectionEquality().hash(_relatedVideos),formToken,currentUserId,isFav,favTimes,playlist,myList]);
+int get hashCode => Object.hashAll([runtimeType,id,title,chineseTitle,coverUrl,description,duration,views,likePercent,dislikePercent,likesCount,dislikesCount,uploadDate,author,const DeepCollectionEquality().hash(_tags),const DeepCollectionEquality().hash(_qualities),const DeepCollectionEquality().hash(_subtitles),series,const DeepCollectionEquality().hash(_relatedVideos),formToken,currentUserId,isFav,favTimes,playlist,myList]);
 
 @override
 String toString() {
-  return 'ApiVideoDetail(id: $id, title: $title, chineseTitle: $chineseTitle, coverUrl: $coverUrl, description: $description, duration: $duration, views: $views, likePercent: $likePercent, dislikePercent: $dislikePercent, likesCount: $likesCount, dislikesCount: $dislikesCount, uploadDate: $uploadDate, author: $author, tags: $tags, qualities: $qualities, series: $series, relatedVideos: $relatedVideos, formToken: $formToken, currentUserId: $currentUserId, isFav: $isFav, favTimes: $favTimes, playlist: $playlist, myList: $myList)';
+  return 'ApiVideoDetail(id: $id, title: $title, chineseTitle: $chineseTitle, coverUrl: $coverUrl, description: $description, duration: $duration, views: $views, likePercent: $likePercent, dislikePercent: $dislikePercent, likesCount: $likesCount, dislikesCount: $dislikesCount, uploadDate: $uploadDate, author: $author, tags: $tags, qualities: $qualities, subtitles: $subtitles, series: $series, relatedVideos: $relatedVideos, formToken: $formToken, currentUserId: $currentUserId, isFav: $isFav, favTimes: $favTimes, playlist: $playlist, myList: $myList)';
 }
 
 
@@ -7998,7 +14740,7 @@ abstract mixin class _$ApiVideoDetailCopyWith<$Res> implements $ApiVideoDetailCo
   factory _$ApiVideoDetailCopyWith(_ApiVideoDetail value, $Res Function(_ApiVideoDetail) _then) = __$ApiVideoDetailCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String title, String? chineseTitle, String coverUrl, String? description, String? duration, String? views, int? likePercent, int? dislikePercent, int? likesCount, int? dislikesCount, String? uploadDate, ApiAuthorInfo? author, List<String> tags, List<ApiVideoQuality> qualities, ApiSeriesInfo? series, List<ApiVideoCard> relatedVideos, String? formToken, String? currentUserId, bool isFav, int? favTimes, ApiPlaylistInfo? playlist, ApiMyListInfo? myList
+ String id, String title, String? chineseTitle, String coverUrl, String? description, String? duration, String? views, int? likePercent, int? dislikePercent, int? likesCount, int? dislikesCount, String? uploadDate, ApiAuthorInfo? author, List<String> tags, List<ApiVideoQuality> qualities, List<ApiSubtitleTrack> subtitles, ApiSeriesInfo? series, List<ApiVideoCard> relatedVideos, String? formToken, String? currentUserId, bool isFav, int? favTimes, ApiPlaylistInfo? playlist, ApiMyListInfo? myList
 });
 
 
@@ -8015,7 +14757,7 @@ class __$ApiVideoDetailCopyWithImpl<$Res>
 
 /// Create a copy of ApiVideoDetail
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? chineseTitle = freezed,Object? coverUrl = null,Object? description = freezed,Object? duration = freezed,Object? views = freezed,Object? likePercent = freezed,Object? dislikePercent = freezed,Object? likesCount = freezed,Object? dislikesCount = freezed,Object? uploadDate = freezed,Object? author = freezed,Object? tags = null,Object? qualities = null,Object? series = freezed,Object? relatedVideos = null,Object? formToken = freezed,Object? currentUserId = freezed,Object? isFav = null,Object? favTimes = freezed,Object? playlist = freezed,Object? myList = freezed,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? chineseTitle = freezed,Object? coverUrl = null,Object? description = freezed,Object? duration = freezed,Object? views = freezed,Object? likePercent = freezed,Object? dislikePercent = freezed,Object? likesCount = freezed,Object? dislikesCount = freezed,Object? uploadDate = freezed,Object? author = freezed,Object? tags = null,Object? qualities = null,Object? subtitles = null,Object? series = freezed,Object? relatedVideos = null,Object? formToken = freezed,Object? currentUserId = freezed,Object? isFav = null,Object? favTimes = freezed,Object? playlist = freezed,Object? myList = freezed,}) {
   return _then(_ApiVideoDetail(
 id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
 as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
@@ -8032,7 +14774,8 @@ as int?,uploadDate: freezed == uploadDate ? _self.uploadDate : uploadDate // ign
 as String?,author: freezed == author ? _self.author : author // ignore: cast_nullable_to_non_nullable
 as ApiAuthorInfo?,tags: null == tags ? _self._tags : tags // ignore: cast_nullable_to_non_nullable
 as List<String>,qualities: null == qualities ? _self._qualities : qualities // ignore: cast_nullable_to_non_nullable
-as List<ApiVideoQuality>,series: freezed == series ? _self.series : series // ignore: cast_nullable_to_non_nullable
+as List<ApiVideoQuality>,subtitles: null == subtitles ? _self._subtitles : subtitles // ignore: cast_nullable_to_non_nullable
+as List<ApiSubtitleTrack>,series: freezed == series ? _self.series : series // ignore: cast_nullable_to_non_nullable
 as ApiSeriesInfo?,relatedVideos: null == relatedVideos ? _self._relatedVideos : relatedVideos // ignore: cast_nullable_to_non_nullable
 as List<ApiVideoCard>,formToken: freezed == formToken ? _self.formToken : formToken // ignore: cast_nullable_to_non_nullable
 as String?,currentUserId: freezed == currentUserId ? _self.currentUserId : currentUserId // ignore: cast_nullable_to_non_nullable
@@ -8098,7 +14841,7 @@ $ApiMyListInfoCopyWith<$Res>? get myList {
 /// @nodoc
 mixin _$ApiVideoQuality {
 
- String get quality; String get url;
+ String get quality; String get url; int? get width; int? get height; BigInt? get bandwidth; String? get codecs;
 /// Create a copy of ApiVideoQuality
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
@@ -8109,16 +14852,16 @@ $ApiVideoQualityCopyWith<ApiVideoQuality> get copyWith => _$ApiVideoQualityCopyW
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiVideoQuality&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.url, url) || other.url == url));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiVideoQuality&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.url, url) || other.url == url)&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height)&&(identical(other.bandwidth, bandwidth) || other.bandwidth == bandwidth)&&(identical(other.codecs, codecs) || other.codecs == codecs));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,quality,url);
+int get hashCode => Object.hash(runtimeType,quality,url,width,height,bandwidth,codecs);
 
 @override
 String toString() {
-  return 'ApiVideoQuality(quality: $quality, url: $url)';
+  return 'ApiVideoQuality(quality: $quality, url: $url, width: $width, height: $height, bandwidth: $bandwidth, codecs: $codecs)';
 }
 
 
@@ -8129,7 +14872,7 @@ abstract mixin class $ApiVideoQualityCopyWith<$Res>  {
   factory $ApiVideoQualityCopyWith(ApiVideoQuality value, $Res Function(ApiVideoQuality) _then) = _$ApiVideoQualityCopyWithImpl;
 @useResult
 $Res call({
- String quality, String url
+ String quality, String url, int? width, int? height, BigInt? bandwidth, String? codecs
 });
 
 
@@ -8146,11 +14889,15 @@ class _$ApiVideoQualityCopyWithImpl<$Res>
 
 /// Create a copy of ApiVideoQuality
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? quality = null,Object? url = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? quality = null,Object? url = null,Object? width = freezed,Object? height = freezed,Object? bandwidth = freezed,Object? codecs = freezed,}) {
   return _then(_self.copyWith(
 quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
 as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
-as String,
+as String,width: freezed == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as int?,height: freezed == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as int?,bandwidth: freezed == bandwidth ? _self.bandwidth : bandwidth // ignore: cast_nullable_to_non_nullable
+as BigInt?,codecs: freezed == codecs ? _self.codecs : codecs // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
@@ -8232,10 +14979,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String quality,  String url)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String quality,  String url,  int? width,  int? height,  BigInt? bandwidth,  String? codecs)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case _ApiVideoQuality() when $default != null:
-return $default(_that.quality,_that.url);case _:
+return $default(_that.quality,_that.url,_that.width,_that.height,_that.bandwidth,_that.codecs);case _:
   return orElse();
 
 }
@@ -8253,10 +15000,10 @@ return $default(_that.quality,_that.url);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String quality,  String url)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String quality,  String url,  int? width,  int? height,  BigInt? bandwidth,  String? codecs)  $default,) {final _that = this;
 switch (_that) {
 case _ApiVideoQuality():
-return $default(_that.quality,_that.url);}
+return $default(_that.quality,_that.url,_that.width,_that.height,_that.bandwidth,_that.codecs);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -8270,10 +15017,10 @@ return $default(_that.quality,_that.url);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String quality,  String url)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String quality,  String url,  int? width,  int? height,  BigInt? bandwidth,  String? codecs)?  $default,) {final _that = this;
 switch (_that) {
 case _ApiVideoQuality() when $default != null:
-return $default(_that.quality,_that.url);case _:
+return $default(_that.quality,_that.url,_that.width,_that.height,_that.bandwidth,_that.codecs);case _:
   return null;
 
 }
@@ -8285,11 +15032,15 @@ return $default(_that.quality,_that.url);case _:
 
 
 class _ApiVideoQuality implements ApiVideoQuality {
-  const _ApiVideoQuality({required this.quality, required this.url});
+  const _ApiVideoQuality({required this.quality, required this.url, this.width, this.height, this.bandwidth, this.codecs});
   
 
 @override final  String quality;
 @override final  String url;
+@override final  int? width;
+@override final  int? height;
+@override final  BigInt? bandwidth;
+@override final  String? codecs;
 
 /// Create a copy of ApiVideoQuality
 /// with the given fields replaced by the non-null parameter values.
@@ -8301,16 +15052,16 @@ _$ApiVideoQualityCopyWith<_ApiVideoQuality> get copyWith => __$ApiVideoQualityCo
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiVideoQuality&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.url, url) || other.url == url));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiVideoQuality&&(identical(other.quality, quality) || other.quality == quality)&&(identical(other.url, url) || other.url == url)&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height)&&(identical(other.bandwidth, bandwidth) || other.bandwidth == bandwidth)&&(identical(other.codecs, codecs) || other.codecs == codecs));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,quality,url);
+int get hashCode => Object.hash(runtimeType,quality,url,width,height,bandwidth,codecs);
 
 @override
 String toString() {
-  return 'ApiVideoQuality(quality: $quality, url: $url)';
+  return 'ApiVideoQuality(quality: $quality, url: $url, width: $width, height: $height, bandwidth: $bandwidth, codecs: $codecs)';
 }
 
 
@@ -8321,7 +15072,7 @@ abstract mixin class _$ApiVideoQualityCopyWith<$Res> implements $ApiVideoQuality
   factory _$ApiVideoQualityCopyWith(_ApiVideoQuality value, $Res Function(_ApiVideoQuality) _then) = __$ApiVideoQualityCopyWithImpl;
 @override @useResult
 $Res call({
- String quality, String url
+ String quality, String url, int? width, int? height, BigInt? bandwidth, String? codecs
 });
 
 
@@ -8338,11 +15089,281 @@ class __$ApiVideoQualityCopyWithImpl<$Res>
 
 /// Create a copy of ApiVideoQuality
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? quality = null,Object? url = null,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? quality = null,Object? url = null,Object? width = freezed,Object? height = freezed,Object? bandwidth = freezed,Object? codecs = freezed,}) {
   return _then(_ApiVideoQuality(
 quality: null == quality ? _self.quality : quality // ignore: cast_nullable_to_non_nullable
 as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
-as String,
+as String,width: freezed == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as int?,height: freezed == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as int?,bandwidth: freezed == bandwidth ? _self.bandwidth : bandwidth // ignore: cast_nullable_to_non_nullable
+as BigInt?,codecs: freezed == codecs ? _self.codecs : codecs // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiWatchNext {
+
+ List<ApiContinueWatching> get continueWatching; List<ApiNextEpisode> get nextEpisodes;
+/// Create a copy of ApiWatchNext
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiWatchNextCopyWith<ApiWatchNext> get copyWith => _$ApiWatchNextCopyWithImpl<ApiWatchNext>(this as ApiWatchNext, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatchNext&&const DeepCollectionEquality().equals(other.continueWatching, continueWatching)&&const DeepCollectionEquality().equals(other.nextEpisodes, nextEpisodes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(continueWatching),const DeepCollectionEquality().hash(nextEpisodes));
+
+@override
+String toString() {
+  return 'ApiWatchNext(continueWatching: $continueWatching, nextEpisodes: $nextEpisodes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiWatchNextCopyWith<$Res>  {
+  factory $ApiWatchNextCopyWith(ApiWatchNext value, $Res Function(ApiWatchNext) _then) = _$ApiWatchNextCopyWithImpl;
+@useResult
+$Res call({
+ List<ApiContinueWatching> continueWatching, List<ApiNextEpisode> nextEpisodes
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiWatchNextCopyWithImpl<$Res>
+    implements $ApiWatchNextCopyWith<$Res> {
+  _$ApiWatchNextCopyWithImpl(this._self, this._then);
+
+  final ApiWatchNext _self;
+  final $Res Function(ApiWatchNext) _then;
+
+/// Create a copy of ApiWatchNext
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? continueWatching = null,Object? nextEpisodes = null,}) {
+  return _then(_self.copyWith(
+continueWatching: null == continueWatching ? _self.continueWatching : continueWatching // ignore: cast_nullable_to_non_nullable
+as List<ApiContinueWatching>,nextEpisodes: null == nextEpisodes ? _self.nextEpisodes : nextEpisodes // ignore: cast_nullable_to_non_nullable
+as List<ApiNextEpisode>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ApiWatchNext].
+extension ApiWatchNextPatterns on ApiWatchNext {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ApiWatchNext value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ApiWatchNext() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ApiWatchNext value)  $default,){
+final _that = this;
+switch (_that) {
+case _ApiWatchNext():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ApiWatchNext value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ApiWatchNext() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ApiContinueWatching> continueWatching,  List<ApiNextEpisode> nextEpisodes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ApiWatchNext() when $default != null:
+return $default(_that.continueWatching,_that.nextEpisodes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ApiContinueWatching> continueWatching,  List<ApiNextEpisode> nextEpisodes)  $default,) {final _that = this;
+switch (_that) {
+case _ApiWatchNext():
+return $default(_that.continueWatching,_that.nextEpisodes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ApiContinueWatching> continueWatching,  List<ApiNextEpisode> nextEpisodes)?  $default,) {final _that = this;
+switch (_that) {
+case _ApiWatchNext() when $default != null:
+return $default(_that.continueWatching,_that.nextEpisodes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ApiWatchNext implements ApiWatchNext {
+  const _ApiWatchNext({required final  List<ApiContinueWatching> continueWatching, required final  List<ApiNextEpisode> nextEpisodes}): _continueWatching = continueWatching,_nextEpisodes = nextEpisodes;
+  
+
+ final  List<ApiContinueWatching> _continueWatching;
+@override List<ApiContinueWatching> get continueWatching {
+  if (_continueWatching is EqualUnmodifiableListView) return _continueWatching;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_continueWatching);
+}
+
+ final  List<ApiNextEpisode> _nextEpisodes;
+@override List<ApiNextEpisode> get nextEpisodes {
+  if (_nextEpisodes is EqualUnmodifiableListView) return _nextEpisodes;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_nextEpisodes);
+}
+
+
+/// Create a copy of ApiWatchNext
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ApiWatchNextCopyWith<_ApiWatchNext> get copyWith => __$ApiWatchNextCopyWithImpl<_ApiWatchNext>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ApiWatchNext&&const DeepCollectionEquality().equals(other._continueWatching, _continueWatching)&&const DeepCollectionEquality().equals(other._nextEpisodes, _nextEpisodes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_continueWatching),const DeepCollectionEquality().hash(_nextEpisodes));
+
+@override
+String toString() {
+  return 'ApiWatchNext(continueWatching: $continueWatching, nextEpisodes: $nextEpisodes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ApiWatchNextCopyWith<$Res> implements $ApiWatchNextCopyWith<$Res> {
+  factory _$ApiWatchNextCopyWith(_ApiWatchNext value, $Res Function(_ApiWatchNext) _then) = __$ApiWatchNextCopyWithImpl;
+@override @useResult
+$Res call({
+ List<ApiContinueWatching> continueWatching, List<ApiNextEpisode> nextEpisodes
+});
+
+
+
+
+}
+/// @nodoc
+class __$ApiWatchNextCopyWithImpl<$Res>
+    implements _$ApiWatchNextCopyWith<$Res> {
+  __$ApiWatchNextCopyWithImpl(this._self, this._then);
+
+  final _ApiWatchNext _self;
+  final $Res Function(_ApiWatchNext) _then;
+
+/// Create a copy of ApiWatchNext
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? continueWatching = null,Object? nextEpisodes = null,}) {
+  return _then(_ApiWatchNext(
+continueWatching: null == continueWatching ? _self._continueWatching : continueWatching // ignore: cast_nullable_to_non_nullable
+as List<ApiContinueWatching>,nextEpisodes: null == nextEpisodes ? _self._nextEpisodes : nextEpisodes // ignore: cast_nullable_to_non_nullable
+as List<ApiNextEpisode>,
   ));
 }
 
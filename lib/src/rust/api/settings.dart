@@ -28,6 +28,18 @@ Future<bool> setDefaultQuality({required String quality}) =>
 Future<bool> setDownloadConcurrent({required int count}) =>
     RustLib.instance.api.crateApiSettingsSetDownloadConcurrent(count: count);
 
+/// 设置下载预留空间（MB）：剩余空间低于「待下载大小 + 预留」时不开始下载
+Future<bool> setDownloadSpaceReserve({required int reserveMb}) => RustLib
+    .instance
+    .api
+    .crateApiSettingsSetDownloadSpaceReserve(reserveMb: reserveMb);
+
+/// 设置是否在开始下载时预分配完整文件大小
+Future<bool> setDownloadPreallocate({required bool enabled}) => RustLib
+    .instance
+    .api
+    .crateApiSettingsSetDownloadPreallocate(enabled: enabled);
+
 /// 设置代理
 Future<bool> setProxy({String? proxyUrl}) =>
     RustLib.instance.api.crateApiSettingsSetProxy(proxyUrl: proxyUrl);
@@ -0,0 +1,17 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `resolve_local`
+
+/// 获取已下载或下载中视频的本地播放地址（边下边播），没有本地文件时返回空
+Future<String?> getLocalStreamUrl({required String videoId}) =>
+    RustLib.instance.api.crateApiStreamGetLocalStreamUrl(videoId: videoId);
+
+/// 获取远程媒体（mp4 / m3u8）经本地代理后的播放地址
+Future<String> getProxiedStreamUrl({required String url}) =>
+    RustLib.instance.api.crateApiStreamGetProxiedStreamUrl(url: url);
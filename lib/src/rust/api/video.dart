@@ -7,10 +7,26 @@ import '../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `expand_video_sources`, `fetch_master_variants`, `fetch_video_sources`, `plain_sources`, `resolve_video_sources`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ResolvedSource`, `ResolvedSources`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
 /// 获取视频详情
 Future<ApiVideoDetail> getVideoDetail({required String videoId}) =>
     RustLib.instance.api.crateApiVideoGetVideoDetail(videoId: videoId);
 
+/// 获取视频详情
+///
+/// - `expand_hls_variants`：请求并解析 HLS master 播放列表，把各画质变体（分辨率、带宽、编码）
+///   加入 `qualities`；网页已直接提供的清晰度保持不变
+Future<ApiVideoDetail> getVideoDetailWithOptions({
+  required String videoId,
+  required bool expandHlsVariants,
+}) => RustLib.instance.api.crateApiVideoGetVideoDetailWithOptions(
+  videoId: videoId,
+  expandHlsVariants: expandHlsVariants,
+);
+
 /// 获取视频评论
 Future<ApiCommentList> getVideoComments({
   required String videoId,
@@ -25,6 +41,10 @@ Future<List<ApiComment>> getCommentReplies({required String commentId}) =>
     RustLib.instance.api.crateApiVideoGetCommentReplies(commentId: commentId);
 
 /// 获取视频播放地址
+///
+/// - 已下载完成时优先返回本地播放地址
+/// - 否则按清晰度选择：完全匹配 → 低于目标的最接近清晰度 → auto（HLS master 播放列表会展开为各画质）
+/// - 解析结果连同地址过期时间一起缓存，重复调用不会再次抓取 watch 页
 Future<String> getVideoUrl({
   required String videoId,
   required String quality,
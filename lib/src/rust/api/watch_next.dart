@@ -0,0 +1,14 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_downloaded`, `remember_series`
+
+/// 获取继续观看与下一集推荐（每类最多 `limit` 条）
+Future<ApiWatchNext> getWatchNext({required int limit}) =>
+    RustLib.instance.api.crateApiWatchNextGetWatchNext(limit: limit);
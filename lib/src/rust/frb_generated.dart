@@ -5,15 +5,30 @@
 
 import 'api/cache.dart';
 import 'api/download.dart';
+import 'api/download_batch.dart';
+import 'api/download_bundle.dart';
+import 'api/download_events.dart';
+import 'api/download_export.dart';
 import 'api/download_folders.dart';
+import 'api/download_hls.dart';
+import 'api/download_quota.dart';
+import 'api/download_reconcile.dart';
+import 'api/download_relocate.dart';
+import 'api/download_rules.dart';
+import 'api/download_seek.dart';
+import 'api/download_subtitles.dart';
+import 'api/download_throttle.dart';
+import 'api/download_variants.dart';
 import 'api/init.dart';
 import 'api/models.dart';
 import 'api/search.dart';
 import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/stream.dart';
 import 'api/sync.dart';
 import 'api/user.dart';
 import 'api/video.dart';
+import 'api/watch_next.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -76,7 +91,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -304094941;
+  int get rustContentHash => 1749195758;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -96,6 +111,11 @@ abstract class RustLibApi extends BaseApi {
     required List<String> tags,
   });
 
+  Future<ApiDownloadVariant> crateApiDownloadVariantsAddDownloadVariant({
+    required String videoId,
+    required String quality,
+  });
+
   Future<bool> crateApiUserAddToFavorites({
     required String videoCode,
     required String formToken,
@@ -107,8 +127,19 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ApiAppSettings> crateApiModelsApiAppSettingsDefault();
 
+  Future<ApiDownloadBandwidthSettings>
+  crateApiModelsApiDownloadBandwidthSettingsDefault();
+
+  Future<ApiDownloadQuery> crateApiModelsApiDownloadQueryDefault();
+
+  Future<ApiDownloadQuotaPolicy> crateApiModelsApiDownloadQuotaPolicyDefault();
+
+  Future<ApiExportOptions> crateApiModelsApiExportOptionsDefault();
+
   Future<ApiSearchFilters> crateApiModelsApiSearchFiltersDefault();
 
+  Future<ApiSmartFolderRule> crateApiModelsApiSmartFolderRuleDefault();
+
   Future<ApiWebDavSettings> crateApiSyncApiWebDavSettingsDefault();
 
   Future<void> crateApiCacheAutoCleanCache({
@@ -118,8 +149,12 @@ abstract class RustLibApi extends BaseApi {
 
   Future<CacheSize> crateApiCacheCacheSizeDefault();
 
+  Future<bool> crateApiDownloadExportCancelExportJob({required String jobId});
+
   Future<bool> crateApiInitCheckCloudflare();
 
+  Future<int> crateApiDownloadRulesCheckDownloadRulesNow();
+
   Future<bool> crateApiInitCheckNetwork();
 
   Future<PlatformInt64> crateApiSyncCleanupExpiredHistory();
@@ -143,6 +178,17 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ApiDownloadFolder> crateApiDownloadFoldersCreateDownloadFolder({
     required String name,
+    String? parentId,
+  });
+
+  Future<ApiDownloadRule> crateApiDownloadRulesCreateDownloadRule({
+    required ApiDownloadRule rule,
+  });
+
+  Future<ApiDownloadFolder> crateApiDownloadFoldersCreateSmartFolder({
+    required String name,
+    String? parentId,
+    required ApiSmartFolderRule rule,
   });
 
   Future<bool> crateApiDownloadDeleteDownload({
@@ -154,6 +200,15 @@ abstract class RustLibApi extends BaseApi {
     required String folderId,
   });
 
+  Future<bool> crateApiDownloadRulesDeleteDownloadRule({
+    required String ruleId,
+  });
+
+  Future<bool> crateApiDownloadVariantsDeleteDownloadVariant({
+    required PlatformInt64 variantId,
+    required bool deleteFile,
+  });
+
   Future<bool> crateApiUserDeleteFromList({
     required String listType,
     required String videoCode,
@@ -163,17 +218,55 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiSettingsDeleteKv({required String key});
 
+  Future<BigInt> crateApiDownloadReconcileDeleteOrphanFiles({
+    required List<String> files,
+  });
+
   Future<bool> crateApiUserDeletePlayHistory({required String videoId});
 
+  Future<ApiBatchEnqueueResult> crateApiDownloadBatchDownloadPlaylist({
+    required String listId,
+    required String quality,
+    String? folderId,
+  });
+
+  Future<ApiBatchEnqueueResult> crateApiDownloadBatchDownloadSearch({
+    required ApiSearchFilters filters,
+    required int maxPages,
+    String? artist,
+    required String quality,
+    String? folderId,
+  });
+
+  Future<ApiBatchEnqueueResult> crateApiDownloadBatchDownloadSeries({
+    required String videoId,
+    required String quality,
+    String? folderId,
+  });
+
+  Stream<ApiBundleProgress> crateApiDownloadBundleExportDownloadBundle({
+    required List<String> taskIds,
+    required String destPath,
+    required bool asZip,
+  });
+
   Stream<ApiExportProgress> crateApiDownloadExportDownloadsToDir({
     required List<String> taskIds,
     required String destDir,
   });
 
+  Stream<ApiExportProgress> crateApiDownloadExportExportDownloadsWithOptions({
+    required List<String> taskIds,
+    required String destDir,
+    required ApiExportOptions options,
+  });
+
   Future<String> crateApiInitExportLogsZip();
 
   Future<ApiSyncStatus> crateApiSyncForceUploadHistory();
 
+  Future<List<String>> crateApiDownloadExportGetActiveExportJobs();
+
   Future<List<ApiDownloadTask>> crateApiDownloadGetAllDownloads();
 
   Future<String> crateApiSettingsGetAppVersion();
@@ -192,18 +285,55 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiSettingsGetDataDirPath();
 
+  Future<List<ApiByteRange>> crateApiDownloadGetDownloadAvailableRanges({
+    required String taskId,
+  });
+
+  Future<ApiDownloadBandwidthSettings>
+  crateApiDownloadThrottleGetDownloadBandwidthSettings();
+
   Future<List<ApiDownloadFolder>> crateApiDownloadFoldersGetDownloadFolders();
 
+  Future<ApiDownloadQuotaPolicy> crateApiDownloadQuotaGetDownloadQuotaPolicy();
+
+  Future<List<ApiDownloadRule>> crateApiDownloadRulesGetDownloadRules();
+
+  Future<ApiDownloadStorageInfo> crateApiDownloadGetDownloadStorageInfo();
+
+  Future<List<ApiDownloadSubtitle>>
+  crateApiDownloadSubtitlesGetDownloadSubtitles({required String taskId});
+
+  Future<int?> crateApiDownloadThrottleGetDownloadTaskBandwidthLimit({
+    required String taskId,
+  });
+
+  Future<List<ApiDownloadEvent>> crateApiDownloadEventsGetDownloadTimeline({
+    required String taskId,
+  });
+
+  Future<List<ApiDownloadVariant>> crateApiDownloadVariantsGetDownloadVariants({
+    required String videoId,
+  });
+
   Future<List<ApiDownloadTask>> crateApiDownloadGetDownloadsByStatus({
     required String status,
   });
 
+  Future<String> crateApiDownloadRelocateGetDownloadsRootPath();
+
+  Future<List<String>> crateApiDownloadExportGetExportTemplateTokens();
+
   Future<ApiFavoriteList> crateApiUserGetFavorites({required int page});
 
   Future<ApiFilterOptions> crateApiSearchGetFilterOptions();
 
   Future<String?> crateApiSettingsGetFlutterSettings();
 
+  Future<List<ApiDownloadTask>> crateApiDownloadFoldersGetFolderDownloads({
+    required String folderId,
+    required bool includeSubfolders,
+  });
+
   Future<ApiSearchResult> crateApiSearchGetHomeVideos({required int page});
 
   Future<ApiHomePage> crateApiSearchGetHomepage();
@@ -212,6 +342,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<PlatformInt64?> crateApiSyncGetLastSyncTime();
 
+  Future<String?> crateApiStreamGetLocalStreamUrl({required String videoId});
+
   Future<String?> crateApiDownloadGetLocalVideoPath({required String videoId});
 
   Future<String> crateApiUserGetLoginFormToken();
@@ -231,8 +363,14 @@ abstract class RustLibApi extends BaseApi {
     required int pageSize,
   });
 
+  Future<String> crateApiStreamGetProxiedStreamUrl({required String url});
+
   Future<ApiAppSettings> crateApiSettingsGetSettings();
 
+  Future<ApiSmartFolderRule> crateApiDownloadFoldersGetSmartFolderRule({
+    required String folderId,
+  });
+
   Future<List<ApiAuthorInfo>> crateApiUserGetSubscribedAuthors({
     required int page,
   });
@@ -246,6 +384,11 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ApiVideoDetail> crateApiVideoGetVideoDetail({required String videoId});
 
+  Future<ApiVideoDetail> crateApiVideoGetVideoDetailWithOptions({
+    required String videoId,
+    required bool expandHlsVariants,
+  });
+
   Future<ApiPlayHistory?> crateApiUserGetVideoProgress({
     required String videoId,
   });
@@ -255,10 +398,17 @@ abstract class RustLibApi extends BaseApi {
     required String quality,
   });
 
+  Future<ApiWatchNext> crateApiWatchNextGetWatchNext({required int limit});
+
   Future<ApiWebDavSettings> crateApiSyncGetWebdavSettings();
 
   String crateApiSimpleGreet({required String name});
 
+  Stream<ApiBundleProgress> crateApiDownloadBundleImportDownloadBundle({
+    required String srcPath,
+    required String conflictPolicy,
+  });
+
   Future<void> crateApiInitInitApp({required String dataPath});
 
   Future<bool> crateApiSettingsInitApp({
@@ -283,6 +433,15 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiUserLogout();
 
+  Future<int> crateApiDownloadReconcileMarkDownloadsMissing({
+    required List<String> videoIds,
+  });
+
+  Future<bool> crateApiDownloadFoldersMoveDownloadFolder({
+    required String folderId,
+    String? parentId,
+  });
+
   Future<bool> crateApiDownloadFoldersMoveDownloadsToFolder({
     required List<String> videoIds,
     String? folderId,
@@ -292,6 +451,12 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiDownloadPauseDownload({required String taskId});
 
+  Future<bool> crateApiDownloadVariantsPauseDownloadVariant({
+    required PlatformInt64 variantId,
+  });
+
+  Future<bool> crateApiDownloadExportPauseExportJob({required String jobId});
+
   Future<ApiComment> crateApiVideoPostComment({
     required String videoId,
     required String content,
@@ -300,8 +465,34 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<String>> crateApiInitPrepareLogsForSharing();
 
+  Future<ApiCleanupPlan> crateApiDownloadQuotaPreviewDownloadCleanup();
+
+  Future<List<String>> crateApiDownloadExportPreviewExportTemplate({
+    required String template,
+    required List<String> taskIds,
+    int? maxNameBytes,
+  });
+
+  Future<ApiDownloadPage> crateApiDownloadQueryDownloads({
+    required ApiDownloadQuery query,
+  });
+
+  Future<int> crateApiDownloadReconcileRedownloadMissing({
+    required List<String> videoIds,
+  });
+
+  Future<ApiDownloadTask> crateApiDownloadReconcileRelinkDownload({
+    required String videoId,
+    required String kind,
+    required String fileName,
+  });
+
   Future<String> crateApiUserReloadUserAgent();
 
+  Stream<ApiRelocateProgress> crateApiDownloadRelocateRelocateDownloads({
+    required String newRoot,
+  });
+
   Future<bool> crateApiUserRemoveFromFavorites({
     required String videoCode,
     required String formToken,
@@ -316,6 +507,13 @@ abstract class RustLibApi extends BaseApi {
     required String name,
   });
 
+  Future<bool> crateApiDownloadFoldersReorderDownloadFolders({
+    String? parentId,
+    required List<String> folderIds,
+  });
+
+  Future<bool> crateApiDownloadRepairDownload({required String taskId});
+
   Future<void> crateApiInitReportFlutterError({
     required String message,
     String? stack,
@@ -332,6 +530,14 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiDownloadResumeDownload({required String taskId});
 
+  Future<bool> crateApiDownloadVariantsResumeDownloadVariant({
+    required PlatformInt64 variantId,
+  });
+
+  Future<bool> crateApiDownloadExportResumeExportJob({required String jobId});
+
+  Future<ApiCleanupPlan> crateApiDownloadQuotaRunDownloadCleanup();
+
   Future<bool> crateApiSettingsSaveFlutterSettings({required String json});
 
   Future<bool> crateApiSettingsSaveSettings({required ApiAppSettings settings});
@@ -340,6 +546,8 @@ abstract class RustLibApi extends BaseApi {
     required ApiWebDavSettings settings,
   });
 
+  Future<ApiReconcileReport> crateApiDownloadReconcileScanDownloadLibrary();
+
   Future<ApiSearchResult> crateApiSearchSearch({
     required ApiSearchFilters filters,
   });
@@ -357,13 +565,60 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiSettingsSetDefaultQuality({required String quality});
 
+  Future<bool> crateApiDownloadThrottleSetDownloadBandwidthSettings({
+    required ApiDownloadBandwidthSettings settings,
+  });
+
   Future<bool> crateApiSettingsSetDownloadConcurrent({required int count});
 
+  Future<bool> crateApiDownloadFoldersSetDownloadFolderCover({
+    required String folderId,
+    String? videoId,
+  });
+
+  Future<bool> crateApiDownloadSetDownloadMaxRetries({
+    required String taskId,
+    required int maxRetries,
+  });
+
+  Future<bool> crateApiSettingsSetDownloadPreallocate({required bool enabled});
+
+  Future<bool> crateApiDownloadQuotaSetDownloadQuotaPolicy({
+    required ApiDownloadQuotaPolicy policy,
+  });
+
+  Future<bool> crateApiDownloadRulesSetDownloadRuleEnabled({
+    required String ruleId,
+    required bool enabled,
+  });
+
+  Future<bool> crateApiDownloadRulesSetDownloadRulesInterval({
+    required int minutes,
+  });
+
+  Future<bool> crateApiSettingsSetDownloadSpaceReserve({
+    required int reserveMb,
+  });
+
+  Future<bool> crateApiDownloadThrottleSetDownloadTaskBandwidthLimit({
+    required String taskId,
+    int? kbPerSec,
+  });
+
   Future<bool> crateApiSettingsSetKv({
     required String key,
     required String value,
   });
 
+  Future<void> crateApiDownloadThrottleSetPlayerStreaming({
+    required bool active,
+  });
+
+  Future<bool> crateApiDownloadVariantsSetPrimaryDownloadVariant({
+    required PlatformInt64 variantId,
+    required bool keepPrevious,
+  });
+
   Future<bool> crateApiSettingsSetProxy({String? proxyUrl});
 
   Future<bool> crateApiSyncShouldAutoSync();
@@ -377,6 +632,12 @@ abstract class RustLibApi extends BaseApi {
 
   Stream<ApiDownloadTask> crateApiDownloadSubscribeDownloadProgress();
 
+  Stream<ApiDownloadVariant>
+  crateApiDownloadVariantsSubscribeDownloadVariantProgress();
+
+  Stream<ApiQuotaBlockedEvent>
+  crateApiDownloadQuotaSubscribeQuotaBlockedEvents();
+
   Future<ApiSyncStatus> crateApiSyncSyncHistory({required bool forceUpload});
 
   Future<bool> crateApiSyncTestWebdavConnection({
@@ -392,6 +653,10 @@ abstract class RustLibApi extends BaseApi {
     required String xCsrfToken,
   });
 
+  Future<bool> crateApiDownloadRulesUpdateDownloadRule({
+    required ApiDownloadRule rule,
+  });
+
   Future<bool> crateApiSyncUpdateLastSyncTime();
 
   Future<bool> crateApiUserUpdatePlayHistory({
@@ -402,7 +667,26 @@ abstract class RustLibApi extends BaseApi {
     required int duration,
   });
 
+  Future<bool> crateApiDownloadFoldersUpdateSmartFolderRule({
+    required String folderId,
+    required ApiSmartFolderRule rule,
+  });
+
+  Future<ApiDownloadVariant> crateApiDownloadVariantsUpgradeDownload({
+    required String videoId,
+    required String quality,
+    required bool keepPrevious,
+  });
+
   Future<void> crateApiCacheVacuumDatabase();
+
+  Future<bool> crateApiDownloadExportValidateExportTemplate({
+    required String template,
+  });
+
+  Future<ApiDownloadTask> crateApiDownloadVerifyDownload({
+    required String taskId,
+  });
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -463,6 +747,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ],
       );
 
+  @override
+  Future<ApiDownloadVariant> crateApiDownloadVariantsAddDownloadVariant({
+    required String videoId,
+    required String quality,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_String(quality, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_download_variant,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadVariantsAddDownloadVariantConstMeta,
+        argValues: [videoId, quality],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadVariantsAddDownloadVariantConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_download_variant",
+        argNames: ["videoId", "quality"],
+      );
+
   @override
   Future<bool> crateApiUserAddToFavorites({
     required String videoCode,
@@ -481,7 +800,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 3,
             port: port_,
           );
         },
@@ -511,7 +830,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 4,
             port: port_,
           );
         },
@@ -538,7 +857,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 5,
             port: port_,
           );
         },
@@ -557,7 +876,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "api_app_settings_default", argNames: []);
 
   @override
-  Future<ApiSearchFilters> crateApiModelsApiSearchFiltersDefault() {
+  Future<ApiDownloadBandwidthSettings>
+  crateApiModelsApiDownloadBandwidthSettingsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -565,29 +885,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 6,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_search_filters,
+          decodeSuccessData: sse_decode_api_download_bandwidth_settings,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiModelsApiSearchFiltersDefaultConstMeta,
+        constMeta: kCrateApiModelsApiDownloadBandwidthSettingsDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModelsApiSearchFiltersDefaultConstMeta =>
+  TaskConstMeta get kCrateApiModelsApiDownloadBandwidthSettingsDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "api_search_filters_default",
+        debugName: "api_download_bandwidth_settings_default",
         argNames: [],
       );
 
   @override
-  Future<ApiWebDavSettings> crateApiSyncApiWebDavSettingsDefault() {
+  Future<ApiDownloadQuery> crateApiModelsApiDownloadQueryDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -595,64 +915,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 7,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_web_dav_settings,
+          decodeSuccessData: sse_decode_api_download_query,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSyncApiWebDavSettingsDefaultConstMeta,
+        constMeta: kCrateApiModelsApiDownloadQueryDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSyncApiWebDavSettingsDefaultConstMeta =>
+  TaskConstMeta get kCrateApiModelsApiDownloadQueryDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "api_web_dav_settings_default",
+        debugName: "api_download_query_default",
         argNames: [],
       );
 
   @override
-  Future<void> crateApiCacheAutoCleanCache({
-    PlatformInt64? webExpireMs,
-    PlatformInt64? imageExpireMs,
-  }) {
+  Future<ApiDownloadQuotaPolicy> crateApiModelsApiDownloadQuotaPolicyDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_box_autoadd_i_64(webExpireMs, serializer);
-          sse_encode_opt_box_autoadd_i_64(imageExpireMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 8,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_api_download_quota_policy,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCacheAutoCleanCacheConstMeta,
-        argValues: [webExpireMs, imageExpireMs],
+        constMeta: kCrateApiModelsApiDownloadQuotaPolicyDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCacheAutoCleanCacheConstMeta =>
+  TaskConstMeta get kCrateApiModelsApiDownloadQuotaPolicyDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "auto_clean_cache",
-        argNames: ["webExpireMs", "imageExpireMs"],
+        debugName: "api_download_quota_policy_default",
+        argNames: [],
       );
 
   @override
-  Future<CacheSize> crateApiCacheCacheSizeDefault() {
+  Future<ApiExportOptions> crateApiModelsApiExportOptionsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -660,26 +975,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 9,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cache_size,
+          decodeSuccessData: sse_decode_api_export_options,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCacheCacheSizeDefaultConstMeta,
+        constMeta: kCrateApiModelsApiExportOptionsDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCacheCacheSizeDefaultConstMeta =>
-      const TaskConstMeta(debugName: "cache_size_default", argNames: []);
+  TaskConstMeta get kCrateApiModelsApiExportOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_export_options_default",
+        argNames: [],
+      );
 
   @override
-  Future<bool> crateApiInitCheckCloudflare() {
+  Future<ApiSearchFilters> crateApiModelsApiSearchFiltersDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -687,26 +1005,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 10,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_api_search_filters,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitCheckCloudflareConstMeta,
+        constMeta: kCrateApiModelsApiSearchFiltersDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitCheckCloudflareConstMeta =>
-      const TaskConstMeta(debugName: "check_cloudflare", argNames: []);
+  TaskConstMeta get kCrateApiModelsApiSearchFiltersDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_search_filters_default",
+        argNames: [],
+      );
 
   @override
-  Future<bool> crateApiInitCheckNetwork() {
+  Future<ApiSmartFolderRule> crateApiModelsApiSmartFolderRuleDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -714,26 +1035,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 11,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_api_smart_folder_rule,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitCheckNetworkConstMeta,
+        constMeta: kCrateApiModelsApiSmartFolderRuleDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitCheckNetworkConstMeta =>
-      const TaskConstMeta(debugName: "check_network", argNames: []);
+  TaskConstMeta get kCrateApiModelsApiSmartFolderRuleDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_smart_folder_rule_default",
+        argNames: [],
+      );
 
   @override
-  Future<PlatformInt64> crateApiSyncCleanupExpiredHistory() {
+  Future<ApiWebDavSettings> crateApiSyncApiWebDavSettingsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -741,7 +1065,208 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_web_dav_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSyncApiWebDavSettingsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncApiWebDavSettingsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_web_dav_settings_default",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiCacheAutoCleanCache({
+    PlatformInt64? webExpireMs,
+    PlatformInt64? imageExpireMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_i_64(webExpireMs, serializer);
+          sse_encode_opt_box_autoadd_i_64(imageExpireMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCacheAutoCleanCacheConstMeta,
+        argValues: [webExpireMs, imageExpireMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCacheAutoCleanCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "auto_clean_cache",
+        argNames: ["webExpireMs", "imageExpireMs"],
+      );
+
+  @override
+  Future<CacheSize> crateApiCacheCacheSizeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cache_size,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCacheCacheSizeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCacheCacheSizeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "cache_size_default", argNames: []);
+
+  @override
+  Future<bool> crateApiDownloadExportCancelExportJob({required String jobId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadExportCancelExportJobConstMeta,
+        argValues: [jobId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadExportCancelExportJobConstMeta =>
+      const TaskConstMeta(debugName: "cancel_export_job", argNames: ["jobId"]);
+
+  @override
+  Future<bool> crateApiInitCheckCloudflare() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitCheckCloudflareConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitCheckCloudflareConstMeta =>
+      const TaskConstMeta(debugName: "check_cloudflare", argNames: []);
+
+  @override
+  Future<int> crateApiDownloadRulesCheckDownloadRulesNow() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadRulesCheckDownloadRulesNowConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRulesCheckDownloadRulesNowConstMeta =>
+      const TaskConstMeta(debugName: "check_download_rules_now", argNames: []);
+
+  @override
+  Future<bool> crateApiInitCheckNetwork() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitCheckNetworkConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitCheckNetworkConstMeta =>
+      const TaskConstMeta(debugName: "check_network", argNames: []);
+
+  @override
+  Future<PlatformInt64> crateApiSyncCleanupExpiredHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
             port: port_,
           );
         },
@@ -777,7 +1302,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 20,
             port: port_,
           );
         },
@@ -806,7 +1331,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 21,
             port: port_,
           );
         },
@@ -833,7 +1358,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 22,
             port: port_,
           );
         },
@@ -860,7 +1385,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 23,
             port: port_,
           );
         },
@@ -887,7 +1412,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 24,
             port: port_,
           );
         },
@@ -914,7 +1439,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 25,
             port: port_,
           );
         },
@@ -935,16 +1460,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @override
   Future<ApiDownloadFolder> crateApiDownloadFoldersCreateDownloadFolder({
     required String name,
+    String? parentId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
+          sse_encode_opt_String(parentId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 26,
             port: port_,
           );
         },
@@ -953,7 +1480,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_AnyhowException,
         ),
         constMeta: kCrateApiDownloadFoldersCreateDownloadFolderConstMeta,
-        argValues: [name],
+        argValues: [name, parentId],
         apiImpl: this,
       ),
     );
@@ -962,96 +1489,94 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiDownloadFoldersCreateDownloadFolderConstMeta =>
       const TaskConstMeta(
         debugName: "create_download_folder",
-        argNames: ["name"],
+        argNames: ["name", "parentId"],
       );
 
   @override
-  Future<bool> crateApiDownloadDeleteDownload({
-    required String taskId,
-    required bool deleteFile,
+  Future<ApiDownloadRule> crateApiDownloadRulesCreateDownloadRule({
+    required ApiDownloadRule rule,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
-          sse_encode_bool(deleteFile, serializer);
+          sse_encode_box_autoadd_api_download_rule(rule, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_api_download_rule,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadDeleteDownloadConstMeta,
-        argValues: [taskId, deleteFile],
+        constMeta: kCrateApiDownloadRulesCreateDownloadRuleConstMeta,
+        argValues: [rule],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadDeleteDownloadConstMeta =>
+  TaskConstMeta get kCrateApiDownloadRulesCreateDownloadRuleConstMeta =>
       const TaskConstMeta(
-        debugName: "delete_download",
-        argNames: ["taskId", "deleteFile"],
+        debugName: "create_download_rule",
+        argNames: ["rule"],
       );
 
   @override
-  Future<bool> crateApiDownloadFoldersDeleteDownloadFolder({
-    required String folderId,
+  Future<ApiDownloadFolder> crateApiDownloadFoldersCreateSmartFolder({
+    required String name,
+    String? parentId,
+    required ApiSmartFolderRule rule,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(folderId, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_box_autoadd_api_smart_folder_rule(rule, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_api_download_folder,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadFoldersDeleteDownloadFolderConstMeta,
-        argValues: [folderId],
+        constMeta: kCrateApiDownloadFoldersCreateSmartFolderConstMeta,
+        argValues: [name, parentId, rule],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadFoldersDeleteDownloadFolderConstMeta =>
+  TaskConstMeta get kCrateApiDownloadFoldersCreateSmartFolderConstMeta =>
       const TaskConstMeta(
-        debugName: "delete_download_folder",
-        argNames: ["folderId"],
+        debugName: "create_smart_folder",
+        argNames: ["name", "parentId", "rule"],
       );
 
   @override
-  Future<bool> crateApiUserDeleteFromList({
-    required String listType,
-    required String videoCode,
-    required String formToken,
-    required String xCsrfToken,
+  Future<bool> crateApiDownloadDeleteDownload({
+    required String taskId,
+    required bool deleteFile,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(listType, serializer);
-          sse_encode_String(videoCode, serializer);
-          sse_encode_String(formToken, serializer);
-          sse_encode_String(xCsrfToken, serializer);
+          sse_encode_String(taskId, serializer);
+          sse_encode_bool(deleteFile, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 29,
             port: port_,
           );
         },
@@ -1059,15 +1584,155 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserDeleteFromListConstMeta,
-        argValues: [listType, videoCode, formToken, xCsrfToken],
+        constMeta: kCrateApiDownloadDeleteDownloadConstMeta,
+        argValues: [taskId, deleteFile],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserDeleteFromListConstMeta => const TaskConstMeta(
-    debugName: "delete_from_list",
+  TaskConstMeta get kCrateApiDownloadDeleteDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_download",
+        argNames: ["taskId", "deleteFile"],
+      );
+
+  @override
+  Future<bool> crateApiDownloadFoldersDeleteDownloadFolder({
+    required String folderId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(folderId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadFoldersDeleteDownloadFolderConstMeta,
+        argValues: [folderId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadFoldersDeleteDownloadFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_download_folder",
+        argNames: ["folderId"],
+      );
+
+  @override
+  Future<bool> crateApiDownloadRulesDeleteDownloadRule({
+    required String ruleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ruleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadRulesDeleteDownloadRuleConstMeta,
+        argValues: [ruleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRulesDeleteDownloadRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_download_rule",
+        argNames: ["ruleId"],
+      );
+
+  @override
+  Future<bool> crateApiDownloadVariantsDeleteDownloadVariant({
+    required PlatformInt64 variantId,
+    required bool deleteFile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(variantId, serializer);
+          sse_encode_bool(deleteFile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadVariantsDeleteDownloadVariantConstMeta,
+        argValues: [variantId, deleteFile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadVariantsDeleteDownloadVariantConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_download_variant",
+        argNames: ["variantId", "deleteFile"],
+      );
+
+  @override
+  Future<bool> crateApiUserDeleteFromList({
+    required String listType,
+    required String videoCode,
+    required String formToken,
+    required String xCsrfToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(listType, serializer);
+          sse_encode_String(videoCode, serializer);
+          sse_encode_String(formToken, serializer);
+          sse_encode_String(xCsrfToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserDeleteFromListConstMeta,
+        argValues: [listType, videoCode, formToken, xCsrfToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserDeleteFromListConstMeta => const TaskConstMeta(
+    debugName: "delete_from_list",
     argNames: ["listType", "videoCode", "formToken", "xCsrfToken"],
   );
 
@@ -1081,7 +1746,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 34,
             port: port_,
           );
         },
@@ -1099,6 +1764,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSettingsDeleteKvConstMeta =>
       const TaskConstMeta(debugName: "delete_kv", argNames: ["key"]);
 
+  @override
+  Future<BigInt> crateApiDownloadReconcileDeleteOrphanFiles({
+    required List<String> files,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(files, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadReconcileDeleteOrphanFilesConstMeta,
+        argValues: [files],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadReconcileDeleteOrphanFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_orphan_files",
+        argNames: ["files"],
+      );
+
   @override
   Future<bool> crateApiUserDeletePlayHistory({required String videoId}) {
     return handler.executeNormal(
@@ -1109,7 +1807,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 36,
             port: port_,
           );
         },
@@ -1131,23 +1829,140 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Stream<ApiExportProgress> crateApiDownloadExportDownloadsToDir({
+  Future<ApiBatchEnqueueResult> crateApiDownloadBatchDownloadPlaylist({
+    required String listId,
+    required String quality,
+    String? folderId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(listId, serializer);
+          sse_encode_String(quality, serializer);
+          sse_encode_opt_String(folderId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_batch_enqueue_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadBatchDownloadPlaylistConstMeta,
+        argValues: [listId, quality, folderId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadBatchDownloadPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "download_playlist",
+        argNames: ["listId", "quality", "folderId"],
+      );
+
+  @override
+  Future<ApiBatchEnqueueResult> crateApiDownloadBatchDownloadSearch({
+    required ApiSearchFilters filters,
+    required int maxPages,
+    String? artist,
+    required String quality,
+    String? folderId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_api_search_filters(filters, serializer);
+          sse_encode_u_32(maxPages, serializer);
+          sse_encode_opt_String(artist, serializer);
+          sse_encode_String(quality, serializer);
+          sse_encode_opt_String(folderId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_batch_enqueue_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadBatchDownloadSearchConstMeta,
+        argValues: [filters, maxPages, artist, quality, folderId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadBatchDownloadSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "download_search",
+        argNames: ["filters", "maxPages", "artist", "quality", "folderId"],
+      );
+
+  @override
+  Future<ApiBatchEnqueueResult> crateApiDownloadBatchDownloadSeries({
+    required String videoId,
+    required String quality,
+    String? folderId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_String(quality, serializer);
+          sse_encode_opt_String(folderId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_batch_enqueue_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadBatchDownloadSeriesConstMeta,
+        argValues: [videoId, quality, folderId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadBatchDownloadSeriesConstMeta =>
+      const TaskConstMeta(
+        debugName: "download_series",
+        argNames: ["videoId", "quality", "folderId"],
+      );
+
+  @override
+  Stream<ApiBundleProgress> crateApiDownloadBundleExportDownloadBundle({
     required List<String> taskIds,
-    required String destDir,
+    required String destPath,
+    required bool asZip,
   }) {
-    final sink = RustStreamSink<ApiExportProgress>();
+    final sink = RustStreamSink<ApiBundleProgress>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
             sse_encode_list_String(taskIds, serializer);
-            sse_encode_String(destDir, serializer);
-            sse_encode_StreamSink_api_export_progress_Sse(sink, serializer);
+            sse_encode_String(destPath, serializer);
+            sse_encode_bool(asZip, serializer);
+            sse_encode_StreamSink_api_bundle_progress_Sse(sink, serializer);
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 24,
+              funcId: 40,
               port: port_,
             );
           },
@@ -1155,8 +1970,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             decodeSuccessData: sse_decode_unit,
             decodeErrorData: null,
           ),
-          constMeta: kCrateApiDownloadExportDownloadsToDirConstMeta,
-          argValues: [taskIds, destDir, sink],
+          constMeta: kCrateApiDownloadBundleExportDownloadBundleConstMeta,
+          argValues: [taskIds, destPath, asZip, sink],
           apiImpl: this,
         ),
       ),
@@ -1164,22 +1979,104 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiDownloadExportDownloadsToDirConstMeta =>
+  TaskConstMeta get kCrateApiDownloadBundleExportDownloadBundleConstMeta =>
       const TaskConstMeta(
-        debugName: "export_downloads_to_dir",
-        argNames: ["taskIds", "destDir", "sink"],
+        debugName: "export_download_bundle",
+        argNames: ["taskIds", "destPath", "asZip", "sink"],
       );
 
   @override
-  Future<String> crateApiInitExportLogsZip() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  Stream<ApiExportProgress> crateApiDownloadExportDownloadsToDir({
+    required List<String> taskIds,
+    required String destDir,
+  }) {
+    final sink = RustStreamSink<ApiExportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(taskIds, serializer);
+            sse_encode_String(destDir, serializer);
+            sse_encode_StreamSink_api_export_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 41,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDownloadExportDownloadsToDirConstMeta,
+          argValues: [taskIds, destDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadExportDownloadsToDirConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_downloads_to_dir",
+        argNames: ["taskIds", "destDir", "sink"],
+      );
+
+  @override
+  Stream<ApiExportProgress> crateApiDownloadExportExportDownloadsWithOptions({
+    required List<String> taskIds,
+    required String destDir,
+    required ApiExportOptions options,
+  }) {
+    final sink = RustStreamSink<ApiExportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(taskIds, serializer);
+            sse_encode_String(destDir, serializer);
+            sse_encode_box_autoadd_api_export_options(options, serializer);
+            sse_encode_StreamSink_api_export_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 42,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDownloadExportExportDownloadsWithOptionsConstMeta,
+          argValues: [taskIds, destDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadExportExportDownloadsWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_downloads_with_options",
+        argNames: ["taskIds", "destDir", "options", "sink"],
+      );
+
+  @override
+  Future<String> crateApiInitExportLogsZip() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 43,
             port: port_,
           );
         },
@@ -1198,115 +2095,1447 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "export_logs_zip", argNames: []);
 
   @override
-  Future<ApiSyncStatus> crateApiSyncForceUploadHistory() {
+  Future<ApiSyncStatus> crateApiSyncForceUploadHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_sync_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncForceUploadHistoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncForceUploadHistoryConstMeta =>
+      const TaskConstMeta(debugName: "force_upload_history", argNames: []);
+
+  @override
+  Future<List<String>> crateApiDownloadExportGetActiveExportJobs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadExportGetActiveExportJobsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadExportGetActiveExportJobsConstMeta =>
+      const TaskConstMeta(debugName: "get_active_export_jobs", argNames: []);
+
+  @override
+  Future<List<ApiDownloadTask>> crateApiDownloadGetAllDownloads() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_task,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadGetAllDownloadsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadGetAllDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "get_all_downloads", argNames: []);
+
+  @override
+  Future<String> crateApiSettingsGetAppVersion() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsGetAppVersionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetAppVersionConstMeta =>
+      const TaskConstMeta(debugName: "get_app_version", argNames: []);
+
+  @override
+  Future<CacheSize> crateApiCacheGetCacheSize() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cache_size,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCacheGetCacheSizeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCacheGetCacheSizeConstMeta =>
+      const TaskConstMeta(debugName: "get_cache_size", argNames: []);
+
+  @override
+  Future<CacheInfo> crateApiSettingsGetCacheSize() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cache_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsGetCacheSizeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetCacheSizeConstMeta =>
+      const TaskConstMeta(debugName: "get_cache_size", argNames: []);
+
+  @override
+  Future<ApiCloudflareChallenge?> crateApiUserGetCloudflareChallengeInfo() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_api_cloudflare_challenge,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetCloudflareChallengeInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetCloudflareChallengeInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cloudflare_challenge_info",
+        argNames: [],
+      );
+
+  @override
+  Future<List<ApiComment>> crateApiVideoGetCommentReplies({
+    required String commentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(commentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_comment,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVideoGetCommentRepliesConstMeta,
+        argValues: [commentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVideoGetCommentRepliesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_comment_replies",
+        argNames: ["commentId"],
+      );
+
+  @override
+  Future<ApiUserInfo?> crateApiUserGetCurrentUser() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_api_user_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetCurrentUserConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetCurrentUserConstMeta =>
+      const TaskConstMeta(debugName: "get_current_user", argNames: []);
+
+  @override
+  Future<String> crateApiSettingsGetDataDirPath() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsGetDataDirPathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetDataDirPathConstMeta =>
+      const TaskConstMeta(debugName: "get_data_dir_path", argNames: []);
+
+  @override
+  Future<List<ApiByteRange>> crateApiDownloadGetDownloadAvailableRanges({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_byte_range,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadGetDownloadAvailableRangesConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadGetDownloadAvailableRangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_available_ranges",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<ApiDownloadBandwidthSettings>
+  crateApiDownloadThrottleGetDownloadBandwidthSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_download_bandwidth_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiDownloadThrottleGetDownloadBandwidthSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadThrottleGetDownloadBandwidthSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_bandwidth_settings",
+        argNames: [],
+      );
+
+  @override
+  Future<List<ApiDownloadFolder>> crateApiDownloadFoldersGetDownloadFolders() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_folder,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadFoldersGetDownloadFoldersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadFoldersGetDownloadFoldersConstMeta =>
+      const TaskConstMeta(debugName: "get_download_folders", argNames: []);
+
+  @override
+  Future<ApiDownloadQuotaPolicy> crateApiDownloadQuotaGetDownloadQuotaPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_download_quota_policy,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadQuotaGetDownloadQuotaPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadQuotaGetDownloadQuotaPolicyConstMeta =>
+      const TaskConstMeta(debugName: "get_download_quota_policy", argNames: []);
+
+  @override
+  Future<List<ApiDownloadRule>> crateApiDownloadRulesGetDownloadRules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_rule,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadRulesGetDownloadRulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRulesGetDownloadRulesConstMeta =>
+      const TaskConstMeta(debugName: "get_download_rules", argNames: []);
+
+  @override
+  Future<ApiDownloadStorageInfo> crateApiDownloadGetDownloadStorageInfo() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_download_storage_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadGetDownloadStorageInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadGetDownloadStorageInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_download_storage_info", argNames: []);
+
+  @override
+  Future<List<ApiDownloadSubtitle>>
+  crateApiDownloadSubtitlesGetDownloadSubtitles({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_subtitle,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadSubtitlesGetDownloadSubtitlesConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadSubtitlesGetDownloadSubtitlesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_subtitles",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<int?> crateApiDownloadThrottleGetDownloadTaskBandwidthLimit({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiDownloadThrottleGetDownloadTaskBandwidthLimitConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadThrottleGetDownloadTaskBandwidthLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_task_bandwidth_limit",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<List<ApiDownloadEvent>> crateApiDownloadEventsGetDownloadTimeline({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadEventsGetDownloadTimelineConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadEventsGetDownloadTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_timeline",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<List<ApiDownloadVariant>> crateApiDownloadVariantsGetDownloadVariants({
+    required String videoId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_variant,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadVariantsGetDownloadVariantsConstMeta,
+        argValues: [videoId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadVariantsGetDownloadVariantsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_variants",
+        argNames: ["videoId"],
+      );
+
+  @override
+  Future<List<ApiDownloadTask>> crateApiDownloadGetDownloadsByStatus({
+    required String status,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(status, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_task,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadGetDownloadsByStatusConstMeta,
+        argValues: [status],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadGetDownloadsByStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_downloads_by_status",
+        argNames: ["status"],
+      );
+
+  @override
+  Future<String> crateApiDownloadRelocateGetDownloadsRootPath() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadRelocateGetDownloadsRootPathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRelocateGetDownloadsRootPathConstMeta =>
+      const TaskConstMeta(debugName: "get_downloads_root_path", argNames: []);
+
+  @override
+  Future<List<String>> crateApiDownloadExportGetExportTemplateTokens() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloadExportGetExportTemplateTokensConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadExportGetExportTemplateTokensConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_export_template_tokens",
+        argNames: [],
+      );
+
+  @override
+  Future<ApiFavoriteList> crateApiUserGetFavorites({required int page}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_favorite_list,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetFavoritesConstMeta,
+        argValues: [page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetFavoritesConstMeta =>
+      const TaskConstMeta(debugName: "get_favorites", argNames: ["page"]);
+
+  @override
+  Future<ApiFilterOptions> crateApiSearchGetFilterOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_filter_options,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSearchGetFilterOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchGetFilterOptionsConstMeta =>
+      const TaskConstMeta(debugName: "get_filter_options", argNames: []);
+
+  @override
+  Future<String?> crateApiSettingsGetFlutterSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsGetFlutterSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetFlutterSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_flutter_settings", argNames: []);
+
+  @override
+  Future<List<ApiDownloadTask>> crateApiDownloadFoldersGetFolderDownloads({
+    required String folderId,
+    required bool includeSubfolders,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(folderId, serializer);
+          sse_encode_bool(includeSubfolders, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_download_task,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadFoldersGetFolderDownloadsConstMeta,
+        argValues: [folderId, includeSubfolders],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadFoldersGetFolderDownloadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_folder_downloads",
+        argNames: ["folderId", "includeSubfolders"],
+      );
+
+  @override
+  Future<ApiSearchResult> crateApiSearchGetHomeVideos({required int page}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSearchGetHomeVideosConstMeta,
+        argValues: [page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchGetHomeVideosConstMeta =>
+      const TaskConstMeta(debugName: "get_home_videos", argNames: ["page"]);
+
+  @override
+  Future<ApiHomePage> crateApiSearchGetHomepage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_home_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSearchGetHomepageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchGetHomepageConstMeta =>
+      const TaskConstMeta(debugName: "get_homepage", argNames: []);
+
+  @override
+  Future<String?> crateApiSettingsGetKv({required String key}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsGetKvConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetKvConstMeta =>
+      const TaskConstMeta(debugName: "get_kv", argNames: ["key"]);
+
+  @override
+  Future<PlatformInt64?> crateApiSyncGetLastSyncTime() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncGetLastSyncTimeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncGetLastSyncTimeConstMeta =>
+      const TaskConstMeta(debugName: "get_last_sync_time", argNames: []);
+
+  @override
+  Future<String?> crateApiStreamGetLocalStreamUrl({required String videoId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStreamGetLocalStreamUrlConstMeta,
+        argValues: [videoId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamGetLocalStreamUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_local_stream_url",
+        argNames: ["videoId"],
+      );
+
+  @override
+  Future<String?> crateApiDownloadGetLocalVideoPath({required String videoId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadGetLocalVideoPathConstMeta,
+        argValues: [videoId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadGetLocalVideoPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_local_video_path",
+        argNames: ["videoId"],
+      );
+
+  @override
+  Future<String> crateApiUserGetLoginFormToken() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetLoginFormTokenConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetLoginFormTokenConstMeta =>
+      const TaskConstMeta(debugName: "get_login_form_token", argNames: []);
+
+  @override
+  Future<ApiFavoriteList> crateApiUserGetMyList({
+    required String listType,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(listType, serializer);
+          sse_encode_u_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_favorite_list,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetMyListConstMeta,
+        argValues: [listType, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetMyListConstMeta => const TaskConstMeta(
+    debugName: "get_my_list",
+    argNames: ["listType", "page"],
+  );
+
+  @override
+  Future<ApiSubscriptionsPage> crateApiUserGetMySubscriptions({
+    required int page,
+    String? query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          sse_encode_opt_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_subscriptions_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetMySubscriptionsConstMeta,
+        argValues: [page, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetMySubscriptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_my_subscriptions",
+        argNames: ["page", "query"],
+      );
+
+  @override
+  Future<ApiPlayHistoryList> crateApiUserGetPlayHistory({
+    required int page,
+    required int pageSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(pageSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_play_history_list,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetPlayHistoryConstMeta,
+        argValues: [page, pageSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetPlayHistoryConstMeta => const TaskConstMeta(
+    debugName: "get_play_history",
+    argNames: ["page", "pageSize"],
+  );
+
+  @override
+  Future<String> crateApiStreamGetProxiedStreamUrl({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStreamGetProxiedStreamUrlConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamGetProxiedStreamUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_proxied_stream_url",
+        argNames: ["url"],
+      );
+
+  @override
+  Future<ApiAppSettings> crateApiSettingsGetSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_app_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsGetSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_settings", argNames: []);
+
+  @override
+  Future<ApiSmartFolderRule> crateApiDownloadFoldersGetSmartFolderRule({
+    required String folderId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(folderId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_smart_folder_rule,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadFoldersGetSmartFolderRuleConstMeta,
+        argValues: [folderId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadFoldersGetSmartFolderRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_smart_folder_rule",
+        argNames: ["folderId"],
+      );
+
+  @override
+  Future<List<ApiAuthorInfo>> crateApiUserGetSubscribedAuthors({
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_author_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserGetSubscribedAuthorsConstMeta,
+        argValues: [page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserGetSubscribedAuthorsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_subscribed_authors",
+        argNames: ["page"],
+      );
+
+  @override
+  Future<String> crateApiInitGetVersion() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitGetVersionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitGetVersionConstMeta =>
+      const TaskConstMeta(debugName: "get_version", argNames: []);
+
+  @override
+  Future<ApiCommentList> crateApiVideoGetVideoComments({
+    required String videoId,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_u_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_comment_list,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVideoGetVideoCommentsConstMeta,
+        argValues: [videoId, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVideoGetVideoCommentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_video_comments",
+        argNames: ["videoId", "page"],
+      );
+
+  @override
+  Future<ApiVideoDetail> crateApiVideoGetVideoDetail({
+    required String videoId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_video_detail,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVideoGetVideoDetailConstMeta,
+        argValues: [videoId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVideoGetVideoDetailConstMeta =>
+      const TaskConstMeta(debugName: "get_video_detail", argNames: ["videoId"]);
+
+  @override
+  Future<ApiVideoDetail> crateApiVideoGetVideoDetailWithOptions({
+    required String videoId,
+    required bool expandHlsVariants,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_bool(expandHlsVariants, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 88,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_sync_status,
+          decodeSuccessData: sse_decode_api_video_detail,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSyncForceUploadHistoryConstMeta,
-        argValues: [],
+        constMeta: kCrateApiVideoGetVideoDetailWithOptionsConstMeta,
+        argValues: [videoId, expandHlsVariants],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSyncForceUploadHistoryConstMeta =>
-      const TaskConstMeta(debugName: "force_upload_history", argNames: []);
+  TaskConstMeta get kCrateApiVideoGetVideoDetailWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_video_detail_with_options",
+        argNames: ["videoId", "expandHlsVariants"],
+      );
 
   @override
-  Future<List<ApiDownloadTask>> crateApiDownloadGetAllDownloads() {
+  Future<ApiPlayHistory?> crateApiUserGetVideoProgress({
+    required String videoId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 89,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_download_task,
+          decodeSuccessData: sse_decode_opt_box_autoadd_api_play_history,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadGetAllDownloadsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiUserGetVideoProgressConstMeta,
+        argValues: [videoId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadGetAllDownloadsConstMeta =>
-      const TaskConstMeta(debugName: "get_all_downloads", argNames: []);
+  TaskConstMeta get kCrateApiUserGetVideoProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_video_progress",
+        argNames: ["videoId"],
+      );
 
   @override
-  Future<String> crateApiSettingsGetAppVersion() {
+  Future<String> crateApiVideoGetVideoUrl({
+    required String videoId,
+    required String quality,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_String(quality, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 90,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsGetAppVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiVideoGetVideoUrlConstMeta,
+        argValues: [videoId, quality],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetAppVersionConstMeta =>
-      const TaskConstMeta(debugName: "get_app_version", argNames: []);
+  TaskConstMeta get kCrateApiVideoGetVideoUrlConstMeta => const TaskConstMeta(
+    debugName: "get_video_url",
+    argNames: ["videoId", "quality"],
+  );
 
   @override
-  Future<CacheSize> crateApiCacheGetCacheSize() {
+  Future<ApiWatchNext> crateApiWatchNextGetWatchNext({required int limit}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 91,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cache_size,
+          decodeSuccessData: sse_decode_api_watch_next,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCacheGetCacheSizeConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWatchNextGetWatchNextConstMeta,
+        argValues: [limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCacheGetCacheSizeConstMeta =>
-      const TaskConstMeta(debugName: "get_cache_size", argNames: []);
+  TaskConstMeta get kCrateApiWatchNextGetWatchNextConstMeta =>
+      const TaskConstMeta(debugName: "get_watch_next", argNames: ["limit"]);
 
   @override
-  Future<CacheInfo> crateApiSettingsGetCacheSize() {
+  Future<ApiWebDavSettings> crateApiSyncGetWebdavSettings() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1314,117 +3543,151 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 92,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cache_info,
+          decodeSuccessData: sse_decode_api_web_dav_settings,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsGetCacheSizeConstMeta,
+        constMeta: kCrateApiSyncGetWebdavSettingsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetCacheSizeConstMeta =>
-      const TaskConstMeta(debugName: "get_cache_size", argNames: []);
+  TaskConstMeta get kCrateApiSyncGetWebdavSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_webdav_settings", argNames: []);
 
   @override
-  Future<ApiCloudflareChallenge?> crateApiUserGetCloudflareChallengeInfo() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_api_cloudflare_challenge,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiUserGetCloudflareChallengeInfoConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetCloudflareChallengeInfoConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Stream<ApiBundleProgress> crateApiDownloadBundleImportDownloadBundle({
+    required String srcPath,
+    required String conflictPolicy,
+  }) {
+    final sink = RustStreamSink<ApiBundleProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(srcPath, serializer);
+            sse_encode_String(conflictPolicy, serializer);
+            sse_encode_StreamSink_api_bundle_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 94,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDownloadBundleImportDownloadBundleConstMeta,
+          argValues: [srcPath, conflictPolicy, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadBundleImportDownloadBundleConstMeta =>
       const TaskConstMeta(
-        debugName: "get_cloudflare_challenge_info",
-        argNames: [],
+        debugName: "import_download_bundle",
+        argNames: ["srcPath", "conflictPolicy", "sink"],
       );
 
   @override
-  Future<List<ApiComment>> crateApiVideoGetCommentReplies({
-    required String commentId,
-  }) {
+  Future<void> crateApiInitInitApp({required String dataPath}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(commentId, serializer);
+          sse_encode_String(dataPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 95,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_comment,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoGetCommentRepliesConstMeta,
-        argValues: [commentId],
+        constMeta: kCrateApiInitInitAppConstMeta,
+        argValues: [dataPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoGetCommentRepliesConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_comment_replies",
-        argNames: ["commentId"],
-      );
+  TaskConstMeta get kCrateApiInitInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: ["dataPath"]);
 
   @override
-  Future<ApiUserInfo?> crateApiUserGetCurrentUser() {
+  Future<bool> crateApiSettingsInitApp({
+    required String dataDir,
+    required String cacheDir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(cacheDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 96,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_api_user_info,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetCurrentUserConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSettingsInitAppConstMeta,
+        argValues: [dataDir, cacheDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetCurrentUserConstMeta =>
-      const TaskConstMeta(debugName: "get_current_user", argNames: []);
+  TaskConstMeta get kCrateApiSettingsInitAppConstMeta => const TaskConstMeta(
+    debugName: "init_app",
+    argNames: ["dataDir", "cacheDir"],
+  );
 
   @override
-  Future<String> crateApiSettingsGetDataDirPath() {
+  Future<void> crateApiSimpleInitApp() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1432,26 +3695,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 97,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSettingsGetDataDirPathConstMeta,
+        constMeta: kCrateApiSimpleInitAppConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetDataDirPathConstMeta =>
-      const TaskConstMeta(debugName: "get_data_dir_path", argNames: []);
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
 
   @override
-  Future<List<ApiDownloadFolder>> crateApiDownloadFoldersGetDownloadFolders() {
+  Future<bool> crateApiUserIsLoggedIn() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1459,114 +3722,120 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 98,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_download_folder,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadFoldersGetDownloadFoldersConstMeta,
+        constMeta: kCrateApiUserIsLoggedInConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadFoldersGetDownloadFoldersConstMeta =>
-      const TaskConstMeta(debugName: "get_download_folders", argNames: []);
+  TaskConstMeta get kCrateApiUserIsLoggedInConstMeta =>
+      const TaskConstMeta(debugName: "is_logged_in", argNames: []);
 
   @override
-  Future<List<ApiDownloadTask>> crateApiDownloadGetDownloadsByStatus({
-    required String status,
-  }) {
+  Future<bool> crateApiVideoLikeComment({required String commentId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(status, serializer);
+          sse_encode_String(commentId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 99,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_download_task,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadGetDownloadsByStatusConstMeta,
-        argValues: [status],
+        constMeta: kCrateApiVideoLikeCommentConstMeta,
+        argValues: [commentId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadGetDownloadsByStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_downloads_by_status",
-        argNames: ["status"],
-      );
+  TaskConstMeta get kCrateApiVideoLikeCommentConstMeta =>
+      const TaskConstMeta(debugName: "like_comment", argNames: ["commentId"]);
 
   @override
-  Future<ApiFavoriteList> crateApiUserGetFavorites({required int page}) {
+  Future<String> crateApiCacheLoadCachedImage({required String url}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(page, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 100,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_favorite_list,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetFavoritesConstMeta,
-        argValues: [page],
+        constMeta: kCrateApiCacheLoadCachedImageConstMeta,
+        argValues: [url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetFavoritesConstMeta =>
-      const TaskConstMeta(debugName: "get_favorites", argNames: ["page"]);
+  TaskConstMeta get kCrateApiCacheLoadCachedImageConstMeta =>
+      const TaskConstMeta(debugName: "load_cached_image", argNames: ["url"]);
 
   @override
-  Future<ApiFilterOptions> crateApiSearchGetFilterOptions() {
+  Future<bool> crateApiUserLogin({
+    required String email,
+    required String password,
+    required String formToken,
+    required String xCsrfToken,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(email, serializer);
+          sse_encode_String(password, serializer);
+          sse_encode_String(formToken, serializer);
+          sse_encode_String(xCsrfToken, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 101,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_filter_options,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSearchGetFilterOptionsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiUserLoginConstMeta,
+        argValues: [email, password, formToken, xCsrfToken],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSearchGetFilterOptionsConstMeta =>
-      const TaskConstMeta(debugName: "get_filter_options", argNames: []);
+  TaskConstMeta get kCrateApiUserLoginConstMeta => const TaskConstMeta(
+    debugName: "login",
+    argNames: ["email", "password", "formToken", "xCsrfToken"],
+  );
 
   @override
-  Future<String?> crateApiSettingsGetFlutterSettings() {
+  Future<bool> crateApiUserLogout() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1574,109 +3843,129 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 102,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsGetFlutterSettingsConstMeta,
+        constMeta: kCrateApiUserLogoutConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetFlutterSettingsConstMeta =>
-      const TaskConstMeta(debugName: "get_flutter_settings", argNames: []);
+  TaskConstMeta get kCrateApiUserLogoutConstMeta =>
+      const TaskConstMeta(debugName: "logout", argNames: []);
 
   @override
-  Future<ApiSearchResult> crateApiSearchGetHomeVideos({required int page}) {
+  Future<int> crateApiDownloadReconcileMarkDownloadsMissing({
+    required List<String> videoIds,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(page, serializer);
+          sse_encode_list_String(videoIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 103,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_search_result,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSearchGetHomeVideosConstMeta,
-        argValues: [page],
+        constMeta: kCrateApiDownloadReconcileMarkDownloadsMissingConstMeta,
+        argValues: [videoIds],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSearchGetHomeVideosConstMeta =>
-      const TaskConstMeta(debugName: "get_home_videos", argNames: ["page"]);
+  TaskConstMeta get kCrateApiDownloadReconcileMarkDownloadsMissingConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_downloads_missing",
+        argNames: ["videoIds"],
+      );
 
   @override
-  Future<ApiHomePage> crateApiSearchGetHomepage() {
+  Future<bool> crateApiDownloadFoldersMoveDownloadFolder({
+    required String folderId,
+    String? parentId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(folderId, serializer);
+          sse_encode_opt_String(parentId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 104,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_home_page,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSearchGetHomepageConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDownloadFoldersMoveDownloadFolderConstMeta,
+        argValues: [folderId, parentId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSearchGetHomepageConstMeta =>
-      const TaskConstMeta(debugName: "get_homepage", argNames: []);
+  TaskConstMeta get kCrateApiDownloadFoldersMoveDownloadFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_download_folder",
+        argNames: ["folderId", "parentId"],
+      );
 
   @override
-  Future<String?> crateApiSettingsGetKv({required String key}) {
+  Future<bool> crateApiDownloadFoldersMoveDownloadsToFolder({
+    required List<String> videoIds,
+    String? folderId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
+          sse_encode_list_String(videoIds, serializer);
+          sse_encode_opt_String(folderId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 105,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsGetKvConstMeta,
-        argValues: [key],
+        constMeta: kCrateApiDownloadFoldersMoveDownloadsToFolderConstMeta,
+        argValues: [videoIds, folderId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetKvConstMeta =>
-      const TaskConstMeta(debugName: "get_kv", argNames: ["key"]);
+  TaskConstMeta get kCrateApiDownloadFoldersMoveDownloadsToFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_downloads_to_folder",
+        argNames: ["videoIds", "folderId"],
+      );
 
   @override
-  Future<PlatformInt64?> crateApiSyncGetLastSyncTime() {
+  Future<bool> crateApiDownloadPauseAllDownloads() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1684,187 +3973,178 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 106,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSyncGetLastSyncTimeConstMeta,
+        constMeta: kCrateApiDownloadPauseAllDownloadsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSyncGetLastSyncTimeConstMeta =>
-      const TaskConstMeta(debugName: "get_last_sync_time", argNames: []);
+  TaskConstMeta get kCrateApiDownloadPauseAllDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "pause_all_downloads", argNames: []);
 
   @override
-  Future<String?> crateApiDownloadGetLocalVideoPath({required String videoId}) {
+  Future<bool> crateApiDownloadPauseDownload({required String taskId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
+          sse_encode_String(taskId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 107,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadGetLocalVideoPathConstMeta,
-        argValues: [videoId],
+        constMeta: kCrateApiDownloadPauseDownloadConstMeta,
+        argValues: [taskId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadGetLocalVideoPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_local_video_path",
-        argNames: ["videoId"],
-      );
+  TaskConstMeta get kCrateApiDownloadPauseDownloadConstMeta =>
+      const TaskConstMeta(debugName: "pause_download", argNames: ["taskId"]);
 
   @override
-  Future<String> crateApiUserGetLoginFormToken() {
+  Future<bool> crateApiDownloadVariantsPauseDownloadVariant({
+    required PlatformInt64 variantId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(variantId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 108,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetLoginFormTokenConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDownloadVariantsPauseDownloadVariantConstMeta,
+        argValues: [variantId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetLoginFormTokenConstMeta =>
-      const TaskConstMeta(debugName: "get_login_form_token", argNames: []);
+  TaskConstMeta get kCrateApiDownloadVariantsPauseDownloadVariantConstMeta =>
+      const TaskConstMeta(
+        debugName: "pause_download_variant",
+        argNames: ["variantId"],
+      );
 
   @override
-  Future<ApiFavoriteList> crateApiUserGetMyList({
-    required String listType,
-    required int page,
-  }) {
+  Future<bool> crateApiDownloadExportPauseExportJob({required String jobId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(listType, serializer);
-          sse_encode_u_32(page, serializer);
+          sse_encode_String(jobId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 109,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_favorite_list,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetMyListConstMeta,
-        argValues: [listType, page],
+        constMeta: kCrateApiDownloadExportPauseExportJobConstMeta,
+        argValues: [jobId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetMyListConstMeta => const TaskConstMeta(
-    debugName: "get_my_list",
-    argNames: ["listType", "page"],
-  );
+  TaskConstMeta get kCrateApiDownloadExportPauseExportJobConstMeta =>
+      const TaskConstMeta(debugName: "pause_export_job", argNames: ["jobId"]);
 
   @override
-  Future<ApiSubscriptionsPage> crateApiUserGetMySubscriptions({
-    required int page,
-    String? query,
+  Future<ApiComment> crateApiVideoPostComment({
+    required String videoId,
+    required String content,
+    String? replyTo,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(page, serializer);
-          sse_encode_opt_String(query, serializer);
+          sse_encode_String(videoId, serializer);
+          sse_encode_String(content, serializer);
+          sse_encode_opt_String(replyTo, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 110,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_subscriptions_page,
+          decodeSuccessData: sse_decode_api_comment,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetMySubscriptionsConstMeta,
-        argValues: [page, query],
+        constMeta: kCrateApiVideoPostCommentConstMeta,
+        argValues: [videoId, content, replyTo],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetMySubscriptionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_my_subscriptions",
-        argNames: ["page", "query"],
-      );
+  TaskConstMeta get kCrateApiVideoPostCommentConstMeta => const TaskConstMeta(
+    debugName: "post_comment",
+    argNames: ["videoId", "content", "replyTo"],
+  );
 
   @override
-  Future<ApiPlayHistoryList> crateApiUserGetPlayHistory({
-    required int page,
-    required int pageSize,
-  }) {
+  Future<List<String>> crateApiInitPrepareLogsForSharing() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(page, serializer);
-          sse_encode_u_32(pageSize, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 111,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_play_history_list,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetPlayHistoryConstMeta,
-        argValues: [page, pageSize],
+        constMeta: kCrateApiInitPrepareLogsForSharingConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetPlayHistoryConstMeta => const TaskConstMeta(
-    debugName: "get_play_history",
-    argNames: ["page", "pageSize"],
-  );
+  TaskConstMeta get kCrateApiInitPrepareLogsForSharingConstMeta =>
+      const TaskConstMeta(debugName: "prepare_logs_for_sharing", argNames: []);
 
   @override
-  Future<ApiAppSettings> crateApiSettingsGetSettings() {
+  Future<ApiCleanupPlan> crateApiDownloadQuotaPreviewDownloadCleanup() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1872,309 +4152,377 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 112,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_app_settings,
+          decodeSuccessData: sse_decode_api_cleanup_plan,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsGetSettingsConstMeta,
+        constMeta: kCrateApiDownloadQuotaPreviewDownloadCleanupConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetSettingsConstMeta =>
-      const TaskConstMeta(debugName: "get_settings", argNames: []);
+  TaskConstMeta get kCrateApiDownloadQuotaPreviewDownloadCleanupConstMeta =>
+      const TaskConstMeta(debugName: "preview_download_cleanup", argNames: []);
 
   @override
-  Future<List<ApiAuthorInfo>> crateApiUserGetSubscribedAuthors({
-    required int page,
+  Future<List<String>> crateApiDownloadExportPreviewExportTemplate({
+    required String template,
+    required List<String> taskIds,
+    int? maxNameBytes,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(page, serializer);
+          sse_encode_String(template, serializer);
+          sse_encode_list_String(taskIds, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxNameBytes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 113,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_author_info,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetSubscribedAuthorsConstMeta,
-        argValues: [page],
+        constMeta: kCrateApiDownloadExportPreviewExportTemplateConstMeta,
+        argValues: [template, taskIds, maxNameBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetSubscribedAuthorsConstMeta =>
+  TaskConstMeta get kCrateApiDownloadExportPreviewExportTemplateConstMeta =>
       const TaskConstMeta(
-        debugName: "get_subscribed_authors",
-        argNames: ["page"],
+        debugName: "preview_export_template",
+        argNames: ["template", "taskIds", "maxNameBytes"],
       );
 
   @override
-  Future<String> crateApiInitGetVersion() {
+  Future<ApiDownloadPage> crateApiDownloadQueryDownloads({
+    required ApiDownloadQuery query,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_api_download_query(query, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 114,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_api_download_page,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitGetVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDownloadQueryDownloadsConstMeta,
+        argValues: [query],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGetVersionConstMeta =>
-      const TaskConstMeta(debugName: "get_version", argNames: []);
+  TaskConstMeta get kCrateApiDownloadQueryDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "query_downloads", argNames: ["query"]);
 
   @override
-  Future<ApiCommentList> crateApiVideoGetVideoComments({
-    required String videoId,
-    required int page,
+  Future<int> crateApiDownloadReconcileRedownloadMissing({
+    required List<String> videoIds,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
-          sse_encode_u_32(page, serializer);
+          sse_encode_list_String(videoIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 115,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_comment_list,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoGetVideoCommentsConstMeta,
-        argValues: [videoId, page],
+        constMeta: kCrateApiDownloadReconcileRedownloadMissingConstMeta,
+        argValues: [videoIds],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoGetVideoCommentsConstMeta =>
+  TaskConstMeta get kCrateApiDownloadReconcileRedownloadMissingConstMeta =>
       const TaskConstMeta(
-        debugName: "get_video_comments",
-        argNames: ["videoId", "page"],
+        debugName: "redownload_missing",
+        argNames: ["videoIds"],
       );
 
   @override
-  Future<ApiVideoDetail> crateApiVideoGetVideoDetail({
+  Future<ApiDownloadTask> crateApiDownloadReconcileRelinkDownload({
     required String videoId,
+    required String kind,
+    required String fileName,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(videoId, serializer);
+          sse_encode_String(kind, serializer);
+          sse_encode_String(fileName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 116,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_video_detail,
+          decodeSuccessData: sse_decode_api_download_task,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoGetVideoDetailConstMeta,
-        argValues: [videoId],
+        constMeta: kCrateApiDownloadReconcileRelinkDownloadConstMeta,
+        argValues: [videoId, kind, fileName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoGetVideoDetailConstMeta =>
-      const TaskConstMeta(debugName: "get_video_detail", argNames: ["videoId"]);
+  TaskConstMeta get kCrateApiDownloadReconcileRelinkDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "relink_download",
+        argNames: ["videoId", "kind", "fileName"],
+      );
 
   @override
-  Future<ApiPlayHistory?> crateApiUserGetVideoProgress({
-    required String videoId,
-  }) {
+  Future<String> crateApiUserReloadUserAgent() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 117,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_api_play_history,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserGetVideoProgressConstMeta,
-        argValues: [videoId],
+        constMeta: kCrateApiUserReloadUserAgentConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserGetVideoProgressConstMeta =>
+  TaskConstMeta get kCrateApiUserReloadUserAgentConstMeta =>
+      const TaskConstMeta(debugName: "reload_user_agent", argNames: []);
+
+  @override
+  Stream<ApiRelocateProgress> crateApiDownloadRelocateRelocateDownloads({
+    required String newRoot,
+  }) {
+    final sink = RustStreamSink<ApiRelocateProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(newRoot, serializer);
+            sse_encode_StreamSink_api_relocate_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 118,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDownloadRelocateRelocateDownloadsConstMeta,
+          argValues: [newRoot, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadRelocateRelocateDownloadsConstMeta =>
       const TaskConstMeta(
-        debugName: "get_video_progress",
-        argNames: ["videoId"],
+        debugName: "relocate_downloads",
+        argNames: ["newRoot", "sink"],
       );
 
   @override
-  Future<String> crateApiVideoGetVideoUrl({
-    required String videoId,
-    required String quality,
+  Future<bool> crateApiUserRemoveFromFavorites({
+    required String videoCode,
+    required String formToken,
+    required String xCsrfToken,
+    required String userId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
-          sse_encode_String(quality, serializer);
+          sse_encode_String(videoCode, serializer);
+          sse_encode_String(formToken, serializer);
+          sse_encode_String(xCsrfToken, serializer);
+          sse_encode_String(userId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 119,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoGetVideoUrlConstMeta,
-        argValues: [videoId, quality],
+        constMeta: kCrateApiUserRemoveFromFavoritesConstMeta,
+        argValues: [videoCode, formToken, xCsrfToken, userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoGetVideoUrlConstMeta => const TaskConstMeta(
-    debugName: "get_video_url",
-    argNames: ["videoId", "quality"],
-  );
+  TaskConstMeta get kCrateApiUserRemoveFromFavoritesConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_from_favorites",
+        argNames: ["videoCode", "formToken", "xCsrfToken", "userId"],
+      );
 
   @override
-  Future<ApiWebDavSettings> crateApiSyncGetWebdavSettings() {
+  Future<bool> crateApiVideoRemoveFromFavorites({required String videoId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 120,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_web_dav_settings,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSyncGetWebdavSettingsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiVideoRemoveFromFavoritesConstMeta,
+        argValues: [videoId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSyncGetWebdavSettingsConstMeta =>
-      const TaskConstMeta(debugName: "get_webdav_settings", argNames: []);
+  TaskConstMeta get kCrateApiVideoRemoveFromFavoritesConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_from_favorites",
+        argNames: ["videoId"],
+      );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<bool> crateApiDownloadFoldersRenameDownloadFolder({
+    required String folderId,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(folderId, serializer);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiDownloadFoldersRenameDownloadFolderConstMeta,
+        argValues: [folderId, name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiDownloadFoldersRenameDownloadFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "rename_download_folder",
+        argNames: ["folderId", "name"],
+      );
 
   @override
-  Future<void> crateApiInitInitApp({required String dataPath}) {
+  Future<bool> crateApiDownloadFoldersReorderDownloadFolders({
+    String? parentId,
+    required List<String> folderIds,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataPath, serializer);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_list_String(folderIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 122,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitInitAppConstMeta,
-        argValues: [dataPath],
+        constMeta: kCrateApiDownloadFoldersReorderDownloadFoldersConstMeta,
+        argValues: [parentId, folderIds],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: ["dataPath"]);
+  TaskConstMeta get kCrateApiDownloadFoldersReorderDownloadFoldersConstMeta =>
+      const TaskConstMeta(
+        debugName: "reorder_download_folders",
+        argNames: ["parentId", "folderIds"],
+      );
 
   @override
-  Future<bool> crateApiSettingsInitApp({
-    required String dataDir,
-    required String cacheDir,
-  }) {
+  Future<bool> crateApiDownloadRepairDownload({required String taskId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
-          sse_encode_String(cacheDir, serializer);
+          sse_encode_String(taskId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 123,
             port: port_,
           );
         },
@@ -2182,28 +4530,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsInitAppConstMeta,
-        argValues: [dataDir, cacheDir],
+        constMeta: kCrateApiDownloadRepairDownloadConstMeta,
+        argValues: [taskId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsInitAppConstMeta => const TaskConstMeta(
-    debugName: "init_app",
-    argNames: ["dataDir", "cacheDir"],
-  );
+  TaskConstMeta get kCrateApiDownloadRepairDownloadConstMeta =>
+      const TaskConstMeta(debugName: "repair_download", argNames: ["taskId"]);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiInitReportFlutterError({
+    required String message,
+    String? stack,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          sse_encode_opt_String(stack, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 124,
             port: port_,
           );
         },
@@ -2211,54 +4562,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiInitReportFlutterErrorConstMeta,
+        argValues: [message, stack],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiInitReportFlutterErrorConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_flutter_error",
+        argNames: ["message", "stack"],
+      );
 
   @override
-  Future<bool> crateApiUserIsLoggedIn() {
+  Future<void> crateApiInitReportFlutterLog({
+    required String level,
+    required String message,
+    String? tag,
+    String? stack,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          sse_encode_String(message, serializer);
+          sse_encode_opt_String(tag, serializer);
+          sse_encode_opt_String(stack, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 125,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiUserIsLoggedInConstMeta,
-        argValues: [],
+        constMeta: kCrateApiInitReportFlutterLogConstMeta,
+        argValues: [level, message, tag, stack],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserIsLoggedInConstMeta =>
-      const TaskConstMeta(debugName: "is_logged_in", argNames: []);
+  TaskConstMeta get kCrateApiInitReportFlutterLogConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_flutter_log",
+        argNames: ["level", "message", "tag", "stack"],
+      );
 
   @override
-  Future<bool> crateApiVideoLikeComment({required String commentId}) {
+  Future<bool> crateApiDownloadResumeAllDownloads() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(commentId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 126,
             port: port_,
           );
         },
@@ -2266,63 +4631,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoLikeCommentConstMeta,
-        argValues: [commentId],
+        constMeta: kCrateApiDownloadResumeAllDownloadsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoLikeCommentConstMeta =>
-      const TaskConstMeta(debugName: "like_comment", argNames: ["commentId"]);
+  TaskConstMeta get kCrateApiDownloadResumeAllDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "resume_all_downloads", argNames: []);
 
   @override
-  Future<String> crateApiCacheLoadCachedImage({required String url}) {
+  Future<bool> crateApiDownloadResumeDownload({required String taskId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_String(taskId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 127,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCacheLoadCachedImageConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiDownloadResumeDownloadConstMeta,
+        argValues: [taskId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCacheLoadCachedImageConstMeta =>
-      const TaskConstMeta(debugName: "load_cached_image", argNames: ["url"]);
+  TaskConstMeta get kCrateApiDownloadResumeDownloadConstMeta =>
+      const TaskConstMeta(debugName: "resume_download", argNames: ["taskId"]);
 
   @override
-  Future<bool> crateApiUserLogin({
-    required String email,
-    required String password,
-    required String formToken,
-    required String xCsrfToken,
+  Future<bool> crateApiDownloadVariantsResumeDownloadVariant({
+    required PlatformInt64 variantId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(email, serializer);
-          sse_encode_String(password, serializer);
-          sse_encode_String(formToken, serializer);
-          sse_encode_String(xCsrfToken, serializer);
+          sse_encode_i_64(variantId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 128,
             port: port_,
           );
         },
@@ -2330,28 +4689,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserLoginConstMeta,
-        argValues: [email, password, formToken, xCsrfToken],
+        constMeta: kCrateApiDownloadVariantsResumeDownloadVariantConstMeta,
+        argValues: [variantId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserLoginConstMeta => const TaskConstMeta(
-    debugName: "login",
-    argNames: ["email", "password", "formToken", "xCsrfToken"],
-  );
+  TaskConstMeta get kCrateApiDownloadVariantsResumeDownloadVariantConstMeta =>
+      const TaskConstMeta(
+        debugName: "resume_download_variant",
+        argNames: ["variantId"],
+      );
 
   @override
-  Future<bool> crateApiUserLogout() {
+  Future<bool> crateApiDownloadExportResumeExportJob({required String jobId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 129,
             port: port_,
           );
         },
@@ -2359,61 +4720,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserLogoutConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDownloadExportResumeExportJobConstMeta,
+        argValues: [jobId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserLogoutConstMeta =>
-      const TaskConstMeta(debugName: "logout", argNames: []);
+  TaskConstMeta get kCrateApiDownloadExportResumeExportJobConstMeta =>
+      const TaskConstMeta(debugName: "resume_export_job", argNames: ["jobId"]);
 
   @override
-  Future<bool> crateApiDownloadFoldersMoveDownloadsToFolder({
-    required List<String> videoIds,
-    String? folderId,
-  }) {
+  Future<ApiCleanupPlan> crateApiDownloadQuotaRunDownloadCleanup() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(videoIds, serializer);
-          sse_encode_opt_String(folderId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 130,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_api_cleanup_plan,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadFoldersMoveDownloadsToFolderConstMeta,
-        argValues: [videoIds, folderId],
+        constMeta: kCrateApiDownloadQuotaRunDownloadCleanupConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadFoldersMoveDownloadsToFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: "move_downloads_to_folder",
-        argNames: ["videoIds", "folderId"],
-      );
+  TaskConstMeta get kCrateApiDownloadQuotaRunDownloadCleanupConstMeta =>
+      const TaskConstMeta(debugName: "run_download_cleanup", argNames: []);
 
   @override
-  Future<bool> crateApiDownloadPauseAllDownloads() {
+  Future<bool> crateApiSettingsSaveFlutterSettings({required String json}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 67,
+            funcId: 131,
             port: port_,
           );
         },
@@ -2421,27 +4775,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadPauseAllDownloadsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSettingsSaveFlutterSettingsConstMeta,
+        argValues: [json],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadPauseAllDownloadsConstMeta =>
-      const TaskConstMeta(debugName: "pause_all_downloads", argNames: []);
+  TaskConstMeta get kCrateApiSettingsSaveFlutterSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_flutter_settings",
+        argNames: ["json"],
+      );
 
   @override
-  Future<bool> crateApiDownloadPauseDownload({required String taskId}) {
+  Future<bool> crateApiSettingsSaveSettings({
+    required ApiAppSettings settings,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
+          sse_encode_box_autoadd_api_app_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 68,
+            funcId: 132,
             port: port_,
           );
         },
@@ -2449,54 +4808,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadPauseDownloadConstMeta,
-        argValues: [taskId],
+        constMeta: kCrateApiSettingsSaveSettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadPauseDownloadConstMeta =>
-      const TaskConstMeta(debugName: "pause_download", argNames: ["taskId"]);
+  TaskConstMeta get kCrateApiSettingsSaveSettingsConstMeta =>
+      const TaskConstMeta(debugName: "save_settings", argNames: ["settings"]);
 
   @override
-  Future<ApiComment> crateApiVideoPostComment({
-    required String videoId,
-    required String content,
-    String? replyTo,
+  Future<bool> crateApiSyncSaveWebdavSettings({
+    required ApiWebDavSettings settings,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
-          sse_encode_String(content, serializer);
-          sse_encode_opt_String(replyTo, serializer);
+          sse_encode_box_autoadd_api_web_dav_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
+            funcId: 133,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_comment,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoPostCommentConstMeta,
-        argValues: [videoId, content, replyTo],
+        constMeta: kCrateApiSyncSaveWebdavSettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoPostCommentConstMeta => const TaskConstMeta(
-    debugName: "post_comment",
-    argNames: ["videoId", "content", "replyTo"],
-  );
+  TaskConstMeta get kCrateApiSyncSaveWebdavSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_webdav_settings",
+        argNames: ["settings"],
+      );
 
   @override
-  Future<List<String>> crateApiInitPrepareLogsForSharing() {
+  Future<ApiReconcileReport> crateApiDownloadReconcileScanDownloadLibrary() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2504,70 +4860,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 70,
+            funcId: 134,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_api_reconcile_report,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitPrepareLogsForSharingConstMeta,
+        constMeta: kCrateApiDownloadReconcileScanDownloadLibraryConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitPrepareLogsForSharingConstMeta =>
-      const TaskConstMeta(debugName: "prepare_logs_for_sharing", argNames: []);
+  TaskConstMeta get kCrateApiDownloadReconcileScanDownloadLibraryConstMeta =>
+      const TaskConstMeta(debugName: "scan_download_library", argNames: []);
 
   @override
-  Future<String> crateApiUserReloadUserAgent() {
+  Future<ApiSearchResult> crateApiSearchSearch({
+    required ApiSearchFilters filters,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_api_search_filters(filters, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
+            funcId: 135,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_api_search_result,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserReloadUserAgentConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSearchSearchConstMeta,
+        argValues: [filters],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserReloadUserAgentConstMeta =>
-      const TaskConstMeta(debugName: "reload_user_agent", argNames: []);
+  TaskConstMeta get kCrateApiSearchSearchConstMeta =>
+      const TaskConstMeta(debugName: "search", argNames: ["filters"]);
 
   @override
-  Future<bool> crateApiUserRemoveFromFavorites({
-    required String videoCode,
-    required String formToken,
-    required String xCsrfToken,
-    required String userId,
-  }) {
+  Future<bool> crateApiUserSetCfClearance({required String cookieValue}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoCode, serializer);
-          sse_encode_String(formToken, serializer);
-          sse_encode_String(xCsrfToken, serializer);
-          sse_encode_String(userId, serializer);
+          sse_encode_String(cookieValue, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 72,
+            funcId: 136,
             port: port_,
           );
         },
@@ -2575,65 +4926,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserRemoveFromFavoritesConstMeta,
-        argValues: [videoCode, formToken, xCsrfToken, userId],
+        constMeta: kCrateApiUserSetCfClearanceConstMeta,
+        argValues: [cookieValue],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserRemoveFromFavoritesConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_from_favorites",
-        argNames: ["videoCode", "formToken", "xCsrfToken", "userId"],
-      );
+  TaskConstMeta get kCrateApiUserSetCfClearanceConstMeta => const TaskConstMeta(
+    debugName: "set_cf_clearance",
+    argNames: ["cookieValue"],
+  );
 
   @override
-  Future<bool> crateApiVideoRemoveFromFavorites({required String videoId}) {
+  Future<void> crateApiInitSetCookies({
+    required String cookieString,
+    String? domain,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
+          sse_encode_String(cookieString, serializer);
+          sse_encode_opt_String(domain, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 73,
+            funcId: 137,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiVideoRemoveFromFavoritesConstMeta,
-        argValues: [videoId],
+        constMeta: kCrateApiInitSetCookiesConstMeta,
+        argValues: [cookieString, domain],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiVideoRemoveFromFavoritesConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_from_favorites",
-        argNames: ["videoId"],
-      );
+  TaskConstMeta get kCrateApiInitSetCookiesConstMeta => const TaskConstMeta(
+    debugName: "set_cookies",
+    argNames: ["cookieString", "domain"],
+  );
 
   @override
-  Future<bool> crateApiDownloadFoldersRenameDownloadFolder({
-    required String folderId,
-    required String name,
+  Future<bool> crateApiUserSetCookies({
+    required List<(String, String)> cookies,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(folderId, serializer);
-          sse_encode_String(name, serializer);
+          sse_encode_list_record_string_string(cookies, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 74,
+            funcId: 138,
             port: port_,
           );
         },
@@ -2641,103 +4992,95 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadFoldersRenameDownloadFolderConstMeta,
-        argValues: [folderId, name],
+        constMeta: kCrateApiUserSetCookiesConstMeta,
+        argValues: [cookies],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadFoldersRenameDownloadFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: "rename_download_folder",
-        argNames: ["folderId", "name"],
-      );
+  TaskConstMeta get kCrateApiUserSetCookiesConstMeta =>
+      const TaskConstMeta(debugName: "set_cookies", argNames: ["cookies"]);
 
   @override
-  Future<void> crateApiInitReportFlutterError({
-    required String message,
-    String? stack,
-  }) {
+  Future<bool> crateApiSettingsSetDefaultQuality({required String quality}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
-          sse_encode_opt_String(stack, serializer);
+          sse_encode_String(quality, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 75,
+            funcId: 139,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitReportFlutterErrorConstMeta,
-        argValues: [message, stack],
+        constMeta: kCrateApiSettingsSetDefaultQualityConstMeta,
+        argValues: [quality],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitReportFlutterErrorConstMeta =>
+  TaskConstMeta get kCrateApiSettingsSetDefaultQualityConstMeta =>
       const TaskConstMeta(
-        debugName: "report_flutter_error",
-        argNames: ["message", "stack"],
+        debugName: "set_default_quality",
+        argNames: ["quality"],
       );
 
   @override
-  Future<void> crateApiInitReportFlutterLog({
-    required String level,
-    required String message,
-    String? tag,
-    String? stack,
+  Future<bool> crateApiDownloadThrottleSetDownloadBandwidthSettings({
+    required ApiDownloadBandwidthSettings settings,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(level, serializer);
-          sse_encode_String(message, serializer);
-          sse_encode_opt_String(tag, serializer);
-          sse_encode_opt_String(stack, serializer);
+          sse_encode_box_autoadd_api_download_bandwidth_settings(
+            settings,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 76,
+            funcId: 140,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitReportFlutterLogConstMeta,
-        argValues: [level, message, tag, stack],
+        constMeta:
+            kCrateApiDownloadThrottleSetDownloadBandwidthSettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitReportFlutterLogConstMeta =>
+  TaskConstMeta get kCrateApiDownloadThrottleSetDownloadBandwidthSettingsConstMeta =>
       const TaskConstMeta(
-        debugName: "report_flutter_log",
-        argNames: ["level", "message", "tag", "stack"],
+        debugName: "set_download_bandwidth_settings",
+        argNames: ["settings"],
       );
 
   @override
-  Future<bool> crateApiDownloadResumeAllDownloads() {
+  Future<bool> crateApiSettingsSetDownloadConcurrent({required int count}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(count, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 77,
+            funcId: 141,
             port: port_,
           );
         },
@@ -2745,27 +5088,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadResumeAllDownloadsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSettingsSetDownloadConcurrentConstMeta,
+        argValues: [count],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadResumeAllDownloadsConstMeta =>
-      const TaskConstMeta(debugName: "resume_all_downloads", argNames: []);
+  TaskConstMeta get kCrateApiSettingsSetDownloadConcurrentConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_concurrent",
+        argNames: ["count"],
+      );
 
   @override
-  Future<bool> crateApiDownloadResumeDownload({required String taskId}) {
+  Future<bool> crateApiDownloadFoldersSetDownloadFolderCover({
+    required String folderId,
+    String? videoId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
+          sse_encode_String(folderId, serializer);
+          sse_encode_opt_String(videoId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 78,
+            funcId: 142,
             port: port_,
           );
         },
@@ -2773,27 +5123,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDownloadResumeDownloadConstMeta,
-        argValues: [taskId],
+        constMeta: kCrateApiDownloadFoldersSetDownloadFolderCoverConstMeta,
+        argValues: [folderId, videoId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadResumeDownloadConstMeta =>
-      const TaskConstMeta(debugName: "resume_download", argNames: ["taskId"]);
+  TaskConstMeta get kCrateApiDownloadFoldersSetDownloadFolderCoverConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_folder_cover",
+        argNames: ["folderId", "videoId"],
+      );
 
   @override
-  Future<bool> crateApiSettingsSaveFlutterSettings({required String json}) {
+  Future<bool> crateApiDownloadSetDownloadMaxRetries({
+    required String taskId,
+    required int maxRetries,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(json, serializer);
+          sse_encode_String(taskId, serializer);
+          sse_encode_u_32(maxRetries, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 79,
+            funcId: 143,
             port: port_,
           );
         },
@@ -2801,32 +5158,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsSaveFlutterSettingsConstMeta,
-        argValues: [json],
+        constMeta: kCrateApiDownloadSetDownloadMaxRetriesConstMeta,
+        argValues: [taskId, maxRetries],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsSaveFlutterSettingsConstMeta =>
+  TaskConstMeta get kCrateApiDownloadSetDownloadMaxRetriesConstMeta =>
       const TaskConstMeta(
-        debugName: "save_flutter_settings",
-        argNames: ["json"],
+        debugName: "set_download_max_retries",
+        argNames: ["taskId", "maxRetries"],
       );
 
   @override
-  Future<bool> crateApiSettingsSaveSettings({
-    required ApiAppSettings settings,
-  }) {
+  Future<bool> crateApiSettingsSetDownloadPreallocate({required bool enabled}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_api_app_settings(settings, serializer);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 80,
+            funcId: 144,
             port: port_,
           );
         },
@@ -2834,29 +5189,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsSaveSettingsConstMeta,
-        argValues: [settings],
+        constMeta: kCrateApiSettingsSetDownloadPreallocateConstMeta,
+        argValues: [enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsSaveSettingsConstMeta =>
-      const TaskConstMeta(debugName: "save_settings", argNames: ["settings"]);
+  TaskConstMeta get kCrateApiSettingsSetDownloadPreallocateConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_preallocate",
+        argNames: ["enabled"],
+      );
 
   @override
-  Future<bool> crateApiSyncSaveWebdavSettings({
-    required ApiWebDavSettings settings,
+  Future<bool> crateApiDownloadQuotaSetDownloadQuotaPolicy({
+    required ApiDownloadQuotaPolicy policy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_api_web_dav_settings(settings, serializer);
+          sse_encode_box_autoadd_api_download_quota_policy(policy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 81,
+            funcId: 145,
             port: port_,
           );
         },
@@ -2864,60 +5222,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSyncSaveWebdavSettingsConstMeta,
-        argValues: [settings],
+        constMeta: kCrateApiDownloadQuotaSetDownloadQuotaPolicyConstMeta,
+        argValues: [policy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSyncSaveWebdavSettingsConstMeta =>
+  TaskConstMeta get kCrateApiDownloadQuotaSetDownloadQuotaPolicyConstMeta =>
       const TaskConstMeta(
-        debugName: "save_webdav_settings",
-        argNames: ["settings"],
+        debugName: "set_download_quota_policy",
+        argNames: ["policy"],
       );
 
   @override
-  Future<ApiSearchResult> crateApiSearchSearch({
-    required ApiSearchFilters filters,
+  Future<bool> crateApiDownloadRulesSetDownloadRuleEnabled({
+    required String ruleId,
+    required bool enabled,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_api_search_filters(filters, serializer);
+          sse_encode_String(ruleId, serializer);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 82,
+            funcId: 146,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_api_search_result,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSearchSearchConstMeta,
-        argValues: [filters],
+        constMeta: kCrateApiDownloadRulesSetDownloadRuleEnabledConstMeta,
+        argValues: [ruleId, enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSearchSearchConstMeta =>
-      const TaskConstMeta(debugName: "search", argNames: ["filters"]);
+  TaskConstMeta get kCrateApiDownloadRulesSetDownloadRuleEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_rule_enabled",
+        argNames: ["ruleId", "enabled"],
+      );
 
   @override
-  Future<bool> crateApiUserSetCfClearance({required String cookieValue}) {
+  Future<bool> crateApiDownloadRulesSetDownloadRulesInterval({
+    required int minutes,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cookieValue, serializer);
+          sse_encode_u_32(minutes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 83,
+            funcId: 147,
             port: port_,
           );
         },
@@ -2925,65 +5290,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserSetCfClearanceConstMeta,
-        argValues: [cookieValue],
+        constMeta: kCrateApiDownloadRulesSetDownloadRulesIntervalConstMeta,
+        argValues: [minutes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserSetCfClearanceConstMeta => const TaskConstMeta(
-    debugName: "set_cf_clearance",
-    argNames: ["cookieValue"],
-  );
+  TaskConstMeta get kCrateApiDownloadRulesSetDownloadRulesIntervalConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_rules_interval",
+        argNames: ["minutes"],
+      );
 
   @override
-  Future<void> crateApiInitSetCookies({
-    required String cookieString,
-    String? domain,
+  Future<bool> crateApiSettingsSetDownloadSpaceReserve({
+    required int reserveMb,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cookieString, serializer);
-          sse_encode_opt_String(domain, serializer);
+          sse_encode_u_32(reserveMb, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 84,
+            funcId: 148,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitSetCookiesConstMeta,
-        argValues: [cookieString, domain],
+        constMeta: kCrateApiSettingsSetDownloadSpaceReserveConstMeta,
+        argValues: [reserveMb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitSetCookiesConstMeta => const TaskConstMeta(
-    debugName: "set_cookies",
-    argNames: ["cookieString", "domain"],
-  );
+  TaskConstMeta get kCrateApiSettingsSetDownloadSpaceReserveConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_space_reserve",
+        argNames: ["reserveMb"],
+      );
 
   @override
-  Future<bool> crateApiUserSetCookies({
-    required List<(String, String)> cookies,
+  Future<bool> crateApiDownloadThrottleSetDownloadTaskBandwidthLimit({
+    required String taskId,
+    int? kbPerSec,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_record_string_string(cookies, serializer);
+          sse_encode_String(taskId, serializer);
+          sse_encode_opt_box_autoadd_u_32(kbPerSec, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 85,
+            funcId: 149,
             port: port_,
           );
         },
@@ -2991,27 +5358,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserSetCookiesConstMeta,
-        argValues: [cookies],
+        constMeta:
+            kCrateApiDownloadThrottleSetDownloadTaskBandwidthLimitConstMeta,
+        argValues: [taskId, kbPerSec],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserSetCookiesConstMeta =>
-      const TaskConstMeta(debugName: "set_cookies", argNames: ["cookies"]);
+  TaskConstMeta get kCrateApiDownloadThrottleSetDownloadTaskBandwidthLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_task_bandwidth_limit",
+        argNames: ["taskId", "kbPerSec"],
+      );
 
   @override
-  Future<bool> crateApiSettingsSetDefaultQuality({required String quality}) {
+  Future<bool> crateApiSettingsSetKv({
+    required String key,
+    required String value,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(quality, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 86,
+            funcId: 150,
             port: port_,
           );
         },
@@ -3019,65 +5394,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsSetDefaultQualityConstMeta,
-        argValues: [quality],
+        constMeta: kCrateApiSettingsSetKvConstMeta,
+        argValues: [key, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsSetDefaultQualityConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_default_quality",
-        argNames: ["quality"],
-      );
+  TaskConstMeta get kCrateApiSettingsSetKvConstMeta =>
+      const TaskConstMeta(debugName: "set_kv", argNames: ["key", "value"]);
 
   @override
-  Future<bool> crateApiSettingsSetDownloadConcurrent({required int count}) {
+  Future<void> crateApiDownloadThrottleSetPlayerStreaming({
+    required bool active,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(count, serializer);
+          sse_encode_bool(active, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 87,
+            funcId: 151,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSettingsSetDownloadConcurrentConstMeta,
-        argValues: [count],
+        constMeta: kCrateApiDownloadThrottleSetPlayerStreamingConstMeta,
+        argValues: [active],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsSetDownloadConcurrentConstMeta =>
+  TaskConstMeta get kCrateApiDownloadThrottleSetPlayerStreamingConstMeta =>
       const TaskConstMeta(
-        debugName: "set_download_concurrent",
-        argNames: ["count"],
+        debugName: "set_player_streaming",
+        argNames: ["active"],
       );
 
   @override
-  Future<bool> crateApiSettingsSetKv({
-    required String key,
-    required String value,
+  Future<bool> crateApiDownloadVariantsSetPrimaryDownloadVariant({
+    required PlatformInt64 variantId,
+    required bool keepPrevious,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
+          sse_encode_i_64(variantId, serializer);
+          sse_encode_bool(keepPrevious, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 88,
+            funcId: 152,
             port: port_,
           );
         },
@@ -3085,15 +5459,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSettingsSetKvConstMeta,
-        argValues: [key, value],
+        constMeta: kCrateApiDownloadVariantsSetPrimaryDownloadVariantConstMeta,
+        argValues: [variantId, keepPrevious],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsSetKvConstMeta =>
-      const TaskConstMeta(debugName: "set_kv", argNames: ["key", "value"]);
+  TaskConstMeta get kCrateApiDownloadVariantsSetPrimaryDownloadVariantConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_primary_download_variant",
+        argNames: ["variantId", "keepPrevious"],
+      );
 
   @override
   Future<bool> crateApiSettingsSetProxy({String? proxyUrl}) {
@@ -3105,7 +5482,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 89,
+            funcId: 153,
             port: port_,
           );
         },
@@ -3132,7 +5509,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 90,
+            funcId: 154,
             port: port_,
           );
         },
@@ -3168,7 +5545,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 91,
+            funcId: 155,
             port: port_,
           );
         },
@@ -3201,7 +5578,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 92,
+              funcId: 156,
               port: port_,
             );
           },
@@ -3224,6 +5601,79 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink"],
       );
 
+  @override
+  Stream<ApiDownloadVariant>
+  crateApiDownloadVariantsSubscribeDownloadVariantProgress() {
+    final sink = RustStreamSink<ApiDownloadVariant>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_api_download_variant_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 157,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta:
+              kCrateApiDownloadVariantsSubscribeDownloadVariantProgressConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadVariantsSubscribeDownloadVariantProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_download_variant_progress",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<ApiQuotaBlockedEvent>
+  crateApiDownloadQuotaSubscribeQuotaBlockedEvents() {
+    final sink = RustStreamSink<ApiQuotaBlockedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_api_quota_blocked_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 158,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDownloadQuotaSubscribeQuotaBlockedEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadQuotaSubscribeQuotaBlockedEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_quota_blocked_events",
+        argNames: ["sink"],
+      );
+
   @override
   Future<ApiSyncStatus> crateApiSyncSyncHistory({required bool forceUpload}) {
     return handler.executeNormal(
@@ -3234,7 +5684,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 93,
+            funcId: 159,
             port: port_,
           );
         },
@@ -3268,7 +5718,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 94,
+            funcId: 160,
             port: port_,
           );
         },
@@ -3307,7 +5757,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 95,
+            funcId: 161,
             port: port_,
           );
         },
@@ -3328,6 +5778,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["artistId", "userId", "formToken", "xCsrfToken"],
       );
 
+  @override
+  Future<bool> crateApiDownloadRulesUpdateDownloadRule({
+    required ApiDownloadRule rule,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_api_download_rule(rule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadRulesUpdateDownloadRuleConstMeta,
+        argValues: [rule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRulesUpdateDownloadRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_download_rule",
+        argNames: ["rule"],
+      );
+
   @override
   Future<bool> crateApiSyncUpdateLastSyncTime() {
     return handler.executeNormal(
@@ -3337,7 +5820,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 96,
+            funcId: 163,
             port: port_,
           );
         },
@@ -3352,30 +5835,162 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  TaskConstMeta get kCrateApiSyncUpdateLastSyncTimeConstMeta =>
-      const TaskConstMeta(debugName: "update_last_sync_time", argNames: []);
+  TaskConstMeta get kCrateApiSyncUpdateLastSyncTimeConstMeta =>
+      const TaskConstMeta(debugName: "update_last_sync_time", argNames: []);
+
+  @override
+  Future<bool> crateApiUserUpdatePlayHistory({
+    required String videoId,
+    required String title,
+    required String coverUrl,
+    required double progress,
+    required int duration,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_String(coverUrl, serializer);
+          sse_encode_f_32(progress, serializer);
+          sse_encode_u_32(duration, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUserUpdatePlayHistoryConstMeta,
+        argValues: [videoId, title, coverUrl, progress, duration],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUserUpdatePlayHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_play_history",
+        argNames: ["videoId", "title", "coverUrl", "progress", "duration"],
+      );
+
+  @override
+  Future<bool> crateApiDownloadFoldersUpdateSmartFolderRule({
+    required String folderId,
+    required ApiSmartFolderRule rule,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(folderId, serializer);
+          sse_encode_box_autoadd_api_smart_folder_rule(rule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadFoldersUpdateSmartFolderRuleConstMeta,
+        argValues: [folderId, rule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadFoldersUpdateSmartFolderRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_smart_folder_rule",
+        argNames: ["folderId", "rule"],
+      );
+
+  @override
+  Future<ApiDownloadVariant> crateApiDownloadVariantsUpgradeDownload({
+    required String videoId,
+    required String quality,
+    required bool keepPrevious,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(videoId, serializer);
+          sse_encode_String(quality, serializer);
+          sse_encode_bool(keepPrevious, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_download_variant,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadVariantsUpgradeDownloadConstMeta,
+        argValues: [videoId, quality, keepPrevious],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadVariantsUpgradeDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "upgrade_download",
+        argNames: ["videoId", "quality", "keepPrevious"],
+      );
+
+  @override
+  Future<void> crateApiCacheVacuumDatabase() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCacheVacuumDatabaseConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCacheVacuumDatabaseConstMeta =>
+      const TaskConstMeta(debugName: "vacuum_database", argNames: []);
 
   @override
-  Future<bool> crateApiUserUpdatePlayHistory({
-    required String videoId,
-    required String title,
-    required String coverUrl,
-    required double progress,
-    required int duration,
+  Future<bool> crateApiDownloadExportValidateExportTemplate({
+    required String template,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(videoId, serializer);
-          sse_encode_String(title, serializer);
-          sse_encode_String(coverUrl, serializer);
-          sse_encode_f_32(progress, serializer);
-          sse_encode_u_32(duration, serializer);
+          sse_encode_String(template, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 97,
+            funcId: 168,
             port: port_,
           );
         },
@@ -3383,45 +5998,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUserUpdatePlayHistoryConstMeta,
-        argValues: [videoId, title, coverUrl, progress, duration],
+        constMeta: kCrateApiDownloadExportValidateExportTemplateConstMeta,
+        argValues: [template],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUserUpdatePlayHistoryConstMeta =>
+  TaskConstMeta get kCrateApiDownloadExportValidateExportTemplateConstMeta =>
       const TaskConstMeta(
-        debugName: "update_play_history",
-        argNames: ["videoId", "title", "coverUrl", "progress", "duration"],
+        debugName: "validate_export_template",
+        argNames: ["template"],
       );
 
   @override
-  Future<void> crateApiCacheVacuumDatabase() {
+  Future<ApiDownloadTask> crateApiDownloadVerifyDownload({
+    required String taskId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 98,
+            funcId: 169,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_api_download_task,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCacheVacuumDatabaseConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDownloadVerifyDownloadConstMeta,
+        argValues: [taskId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCacheVacuumDatabaseConstMeta =>
-      const TaskConstMeta(debugName: "vacuum_database", argNames: []);
+  TaskConstMeta get kCrateApiDownloadVerifyDownloadConstMeta =>
+      const TaskConstMeta(debugName: "verify_download", argNames: ["taskId"]);
 
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
@@ -3429,6 +6047,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(raw as String);
   }
 
+  @protected
+  RustStreamSink<ApiBundleProgress>
+  dco_decode_StreamSink_api_bundle_progress_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<ApiDownloadTask> dco_decode_StreamSink_api_download_task_Sse(
     dynamic raw,
@@ -3437,6 +6062,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<ApiDownloadVariant>
+  dco_decode_StreamSink_api_download_variant_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<ApiExportProgress>
   dco_decode_StreamSink_api_export_progress_Sse(dynamic raw) {
@@ -3444,6 +6076,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<ApiQuotaBlockedEvent>
+  dco_decode_StreamSink_api_quota_blocked_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ApiRelocateProgress>
+  dco_decode_StreamSink_api_relocate_progress_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3493,6 +6139,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiBatchEnqueueResult dco_decode_api_batch_enqueue_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ApiBatchEnqueueResult(
+      total: dco_decode_u_32(arr[0]),
+      enqueued: dco_decode_list_String(arr[1]),
+      skipped: dco_decode_list_String(arr[2]),
+      failed: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  ApiBundleProgress dco_decode_api_bundle_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return ApiBundleProgress(
+      stage: dco_decode_String(arr[0]),
+      totalItems: dco_decode_u_32(arr[1]),
+      doneItems: dco_decode_u_32(arr[2]),
+      exported: dco_decode_u_32(arr[3]),
+      imported: dco_decode_u_32(arr[4]),
+      skipped: dco_decode_u_32(arr[5]),
+      failed: dco_decode_u_32(arr[6]),
+      currentItem: dco_decode_opt_String(arr[7]),
+      currentBytes: dco_decode_u_64(arr[8]),
+      currentTotalBytes: dco_decode_u_64(arr[9]),
+      done: dco_decode_bool(arr[10]),
+      error: dco_decode_opt_String(arr[11]),
+    );
+  }
+
+  @protected
+  ApiByteRange dco_decode_api_byte_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiByteRange(
+      start: dco_decode_u_64(arr[0]),
+      end: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ApiCleanupItem dco_decode_api_cleanup_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ApiCleanupItem(
+      videoId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      reason: dco_decode_String(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+      completedAt: dco_decode_i_64(arr[4]),
+      watchedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  ApiCleanupPlan dco_decode_api_cleanup_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ApiCleanupPlan(
+      usedBytes: dco_decode_u_64(arr[0]),
+      quotaBytes: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      items: dco_decode_list_api_cleanup_item(arr[2]),
+      freedBytes: dco_decode_u_64(arr[3]),
+    );
+  }
+
   @protected
   ApiCloudflareChallenge dco_decode_api_cloudflare_challenge(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3538,16 +6262,151 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiContinueWatching dco_decode_api_continue_watching(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ApiContinueWatching(
+      videoId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      coverUrl: dco_decode_String(arr[2]),
+      progress: dco_decode_f_32(arr[3]),
+      position: dco_decode_u_32(arr[4]),
+      duration: dco_decode_u_32(arr[5]),
+      lastPlayedAt: dco_decode_i_64(arr[6]),
+      seriesTitle: dco_decode_opt_String(arr[7]),
+      episode: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      isDownloaded: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  ApiDownloadBandwidthSettings dco_decode_api_download_bandwidth_settings(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ApiDownloadBandwidthSettings(
+      globalKbPerSec: dco_decode_u_32(arr[0]),
+      taskKbPerSec: dco_decode_u_32(arr[1]),
+      onlyWhileStreaming: dco_decode_bool(arr[2]),
+      playerStreaming: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  ApiDownloadEvent dco_decode_api_download_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return ApiDownloadEvent(
+      id: dco_decode_i_64(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      status: dco_decode_opt_String(arr[2]),
+      message: dco_decode_opt_String(arr[3]),
+      sourceHost: dco_decode_opt_String(arr[4]),
+      quality: dco_decode_opt_String(arr[5]),
+      format: dco_decode_opt_String(arr[6]),
+      rangeStart: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      rangeEnd: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      httpStatus: dco_decode_opt_box_autoadd_u_16(arr[9]),
+      errorKind: dco_decode_opt_String(arr[10]),
+      createdAt: dco_decode_i_64(arr[11]),
+    );
+  }
+
   @protected
   ApiDownloadFolder dco_decode_api_download_folder(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return ApiDownloadFolder(
       id: dco_decode_String(arr[0]),
       name: dco_decode_String(arr[1]),
       createdAt: dco_decode_i_64(arr[2]),
+      parentId: dco_decode_opt_String(arr[3]),
+      sortOrder: dco_decode_i_32(arr[4]),
+      coverVideoId: dco_decode_opt_String(arr[5]),
+      isSmart: dco_decode_bool(arr[6]),
+      videoCount: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  ApiDownloadPage dco_decode_api_download_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiDownloadPage(
+      items: dco_decode_list_api_download_task(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ApiDownloadQuery dco_decode_api_download_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return ApiDownloadQuery(
+      statuses: dco_decode_list_String(arr[0]),
+      folderId: dco_decode_opt_String(arr[1]),
+      includeSubfolders: dco_decode_bool(arr[2]),
+      authors: dco_decode_list_String(arr[3]),
+      tags: dco_decode_list_String(arr[4]),
+      text: dco_decode_opt_String(arr[5]),
+      downloadedFrom: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      downloadedTo: dco_decode_opt_box_autoadd_i_64(arr[7]),
+      minBytes: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      maxBytes: dco_decode_opt_box_autoadd_u_64(arr[9]),
+      sortBy: dco_decode_opt_String(arr[10]),
+      descending: dco_decode_bool(arr[11]),
+      offset: dco_decode_u_32(arr[12]),
+      limit: dco_decode_u_32(arr[13]),
+    );
+  }
+
+  @protected
+  ApiDownloadQuotaPolicy dco_decode_api_download_quota_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiDownloadQuotaPolicy(
+      quotaBytes: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      autoCleanup: dco_decode_bool(arr[1]),
+      watchedOlderThanDays: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      oldestFirst: dco_decode_bool(arr[3]),
+      pinnedFolderIds: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  ApiDownloadRule dco_decode_api_download_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ApiDownloadRule(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      matchType: dco_decode_String(arr[2]),
+      matchValue: dco_decode_String(arr[3]),
+      quality: dco_decode_String(arr[4]),
+      folderId: dco_decode_opt_String(arr[5]),
+      maxAgeDays: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      dailyCap: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      enabled: dco_decode_bool(arr[8]),
+      createdAt: dco_decode_i_64(arr[9]),
+      lastCheckedAt: dco_decode_opt_box_autoadd_i_64(arr[10]),
     );
   }
 
@@ -3565,17 +6424,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return ApiDownloadStatus_Completed();
       case 4:
         return ApiDownloadStatus_Failed(error: dco_decode_String(raw[1]));
+      case 5:
+        return ApiDownloadStatus_Corrupt(reason: dco_decode_String(raw[1]));
+      case 6:
+        return ApiDownloadStatus_Missing();
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  ApiDownloadStorageInfo dco_decode_api_download_storage_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ApiDownloadStorageInfo(
+      downloadDir: dco_decode_String(arr[0]),
+      usedBytes: dco_decode_u_64(arr[1]),
+      pendingBytes: dco_decode_u_64(arr[2]),
+      freeBytes: dco_decode_u_64(arr[3]),
+      diskTotalBytes: dco_decode_u_64(arr[4]),
+      reserveBytes: dco_decode_u_64(arr[5]),
+      preallocate: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  ApiDownloadSubtitle dco_decode_api_download_subtitle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiDownloadSubtitle(
+      language: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+      format: dco_decode_String(arr[3]),
+      filePath: dco_decode_opt_String(arr[4]),
+    );
+  }
+
   @protected
   ApiDownloadTask dco_decode_api_download_task(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 20)
-      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    if (arr.length != 24)
+      throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
     return ApiDownloadTask(
       id: dco_decode_String(arr[0]),
       videoId: dco_decode_String(arr[1]),
@@ -3597,6 +6492,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       createdAt: dco_decode_i_64(arr[17]),
       filePath: dco_decode_opt_String(arr[18]),
       folderId: dco_decode_opt_String(arr[19]),
+      errorKind: dco_decode_opt_String(arr[20]),
+      retryCount: dco_decode_u_32(arr[21]),
+      maxRetries: dco_decode_u_32(arr[22]),
+      nextRetryAt: dco_decode_opt_box_autoadd_i_64(arr[23]),
+    );
+  }
+
+  @protected
+  ApiDownloadVariant dco_decode_api_download_variant(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return ApiDownloadVariant(
+      id: dco_decode_i_64(arr[0]),
+      videoId: dco_decode_String(arr[1]),
+      quality: dco_decode_String(arr[2]),
+      sourceQuality: dco_decode_opt_String(arr[3]),
+      status: dco_decode_api_download_status(arr[4]),
+      progress: dco_decode_f_32(arr[5]),
+      downloadedBytes: dco_decode_u_64(arr[6]),
+      totalBytes: dco_decode_u_64(arr[7]),
+      filePath: dco_decode_opt_String(arr[8]),
+      replacePrimary: dco_decode_bool(arr[9]),
+      keepPrevious: dco_decode_bool(arr[10]),
+      errorMessage: dco_decode_opt_String(arr[11]),
+      createdAt: dco_decode_i_64(arr[12]),
+      completedAt: dco_decode_opt_box_autoadd_i_64(arr[13]),
+    );
+  }
+
+  @protected
+  ApiExportOptions dco_decode_api_export_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiExportOptions(
+      mediaServerLayout: dco_decode_bool(arr[0]),
+      groupBy: dco_decode_String(arr[1]),
+      filenameTemplate: dco_decode_opt_String(arr[2]),
+      maxNameBytes: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      moveFiles: dco_decode_bool(arr[4]),
     );
   }
 
@@ -3604,16 +6542,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ApiExportProgress dco_decode_api_export_progress(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return ApiExportProgress(
-      totalFiles: dco_decode_u_32(arr[0]),
-      doneFiles: dco_decode_u_32(arr[1]),
-      currentFile: dco_decode_opt_String(arr[2]),
-      currentBytes: dco_decode_u_64(arr[3]),
-      currentTotalBytes: dco_decode_u_64(arr[4]),
-      done: dco_decode_bool(arr[5]),
-      error: dco_decode_opt_String(arr[6]),
+      jobId: dco_decode_String(arr[0]),
+      totalFiles: dco_decode_u_32(arr[1]),
+      doneFiles: dco_decode_u_32(arr[2]),
+      skippedFiles: dco_decode_u_32(arr[3]),
+      currentFile: dco_decode_opt_String(arr[4]),
+      currentBytes: dco_decode_u_64(arr[5]),
+      currentTotalBytes: dco_decode_u_64(arr[6]),
+      done: dco_decode_bool(arr[7]),
+      error: dco_decode_opt_String(arr[8]),
     );
   }
 
@@ -3687,6 +6627,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiMissingDownload dco_decode_api_missing_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ApiMissingDownload(
+      videoId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      status: dco_decode_api_download_status(arr[2]),
+      missingMedia: dco_decode_bool(arr[3]),
+      missingCover: dco_decode_bool(arr[4]),
+      missingAvatar: dco_decode_bool(arr[5]),
+      expectedBytes: dco_decode_u_64(arr[6]),
+      candidateFiles: dco_decode_list_String(arr[7]),
+    );
+  }
+
   @protected
   ApiMyListInfo dco_decode_api_my_list_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3712,6 +6670,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiNextEpisode dco_decode_api_next_episode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ApiNextEpisode(
+      videoId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      coverUrl: dco_decode_String(arr[2]),
+      seriesId: dco_decode_String(arr[3]),
+      seriesTitle: dco_decode_String(arr[4]),
+      episode: dco_decode_u_32(arr[5]),
+      previousVideoId: dco_decode_String(arr[6]),
+      previousPlayedAt: dco_decode_i_64(arr[7]),
+      isDownloaded: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  ApiOrphanFile dco_decode_api_orphan_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiOrphanFile(
+      dir: dco_decode_String(arr[0]),
+      fileName: dco_decode_String(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+      modifiedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      videoId: dco_decode_opt_String(arr[4]),
+    );
+  }
+
   @protected
   ApiPlayHistory dco_decode_api_play_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3754,6 +6746,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiQuotaBlockedEvent dco_decode_api_quota_blocked_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ApiQuotaBlockedEvent(
+      videoId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      requiredBytes: dco_decode_u_64(arr[2]),
+      usedBytes: dco_decode_u_64(arr[3]),
+      quotaBytes: dco_decode_u_64(arr[4]),
+      freedBytes: dco_decode_u_64(arr[5]),
+      blockedAt: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  ApiReconcileReport dco_decode_api_reconcile_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiReconcileReport(
+      missing: dco_decode_list_api_missing_download(arr[0]),
+      orphans: dco_decode_list_api_orphan_file(arr[1]),
+      missingBytes: dco_decode_u_64(arr[2]),
+      orphanBytes: dco_decode_u_64(arr[3]),
+      scannedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  ApiRelocateProgress dco_decode_api_relocate_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ApiRelocateProgress(
+      stage: dco_decode_String(arr[0]),
+      totalFiles: dco_decode_u_32(arr[1]),
+      doneFiles: dco_decode_u_32(arr[2]),
+      totalBytes: dco_decode_u_64(arr[3]),
+      doneBytes: dco_decode_u_64(arr[4]),
+      currentFile: dco_decode_opt_String(arr[5]),
+      done: dco_decode_bool(arr[6]),
+      error: dco_decode_opt_String(arr[7]),
+    );
+  }
+
   @protected
   ApiSearchFilters dco_decode_api_search_filters(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3816,6 +6858,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiSmartFolderRule dco_decode_api_smart_folder_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ApiSmartFolderRule(
+      authors: dco_decode_list_String(arr[0]),
+      tags: dco_decode_list_String(arr[1]),
+      qualities: dco_decode_list_String(arr[2]),
+      downloadedFrom: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      downloadedTo: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      uploadedFrom: dco_decode_opt_String(arr[5]),
+      uploadedTo: dco_decode_opt_String(arr[6]),
+      watched: dco_decode_opt_box_autoadd_bool(arr[7]),
+    );
+  }
+
   @protected
   ApiSubscriptionsPage dco_decode_api_subscriptions_page(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3830,6 +6890,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiSubtitleTrack dco_decode_api_subtitle_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ApiSubtitleTrack(
+      language: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+      format: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   ApiSyncStatus dco_decode_api_sync_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3900,8 +6974,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ApiVideoDetail dco_decode_api_video_detail(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 23)
-      throw Exception('unexpected arr length: expect 23 but see ${arr.length}');
+    if (arr.length != 24)
+      throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
     return ApiVideoDetail(
       id: dco_decode_String(arr[0]),
       title: dco_decode_String(arr[1]),
@@ -3918,14 +6992,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       author: dco_decode_opt_box_autoadd_api_author_info(arr[12]),
       tags: dco_decode_list_String(arr[13]),
       qualities: dco_decode_list_api_video_quality(arr[14]),
-      series: dco_decode_opt_box_autoadd_api_series_info(arr[15]),
-      relatedVideos: dco_decode_list_api_video_card(arr[16]),
-      formToken: dco_decode_opt_String(arr[17]),
-      currentUserId: dco_decode_opt_String(arr[18]),
-      isFav: dco_decode_bool(arr[19]),
-      favTimes: dco_decode_opt_box_autoadd_i_32(arr[20]),
-      playlist: dco_decode_opt_box_autoadd_api_playlist_info(arr[21]),
-      myList: dco_decode_opt_box_autoadd_api_my_list_info(arr[22]),
+      subtitles: dco_decode_list_api_subtitle_track(arr[15]),
+      series: dco_decode_opt_box_autoadd_api_series_info(arr[16]),
+      relatedVideos: dco_decode_list_api_video_card(arr[17]),
+      formToken: dco_decode_opt_String(arr[18]),
+      currentUserId: dco_decode_opt_String(arr[19]),
+      isFav: dco_decode_bool(arr[20]),
+      favTimes: dco_decode_opt_box_autoadd_i_32(arr[21]),
+      playlist: dco_decode_opt_box_autoadd_api_playlist_info(arr[22]),
+      myList: dco_decode_opt_box_autoadd_api_my_list_info(arr[23]),
     );
   }
 
@@ -3933,11 +7008,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ApiVideoQuality dco_decode_api_video_quality(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return ApiVideoQuality(
       quality: dco_decode_String(arr[0]),
       url: dco_decode_String(arr[1]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      bandwidth: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      codecs: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  ApiWatchNext dco_decode_api_watch_next(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiWatchNext(
+      continueWatching: dco_decode_list_api_continue_watching(arr[0]),
+      nextEpisodes: dco_decode_list_api_next_episode(arr[1]),
     );
   }
 
@@ -3989,6 +7080,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_api_cloudflare_challenge(raw);
   }
 
+  @protected
+  ApiDownloadBandwidthSettings
+  dco_decode_box_autoadd_api_download_bandwidth_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_download_bandwidth_settings(raw);
+  }
+
+  @protected
+  ApiDownloadQuery dco_decode_box_autoadd_api_download_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_download_query(raw);
+  }
+
+  @protected
+  ApiDownloadQuotaPolicy dco_decode_box_autoadd_api_download_quota_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_download_quota_policy(raw);
+  }
+
+  @protected
+  ApiDownloadRule dco_decode_box_autoadd_api_download_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_download_rule(raw);
+  }
+
+  @protected
+  ApiExportOptions dco_decode_box_autoadd_api_export_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_export_options(raw);
+  }
+
   @protected
   ApiMyListInfo dco_decode_box_autoadd_api_my_list_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4019,6 +7143,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_api_series_info(raw);
   }
 
+  @protected
+  ApiSmartFolderRule dco_decode_box_autoadd_api_smart_folder_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_smart_folder_rule(raw);
+  }
+
   @protected
   ApiUserInfo dco_decode_box_autoadd_api_user_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4031,6 +7161,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_api_web_dav_settings(raw);
   }
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4043,6 +7179,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_i_64(raw);
   }
 
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4111,24 +7253,70 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_api_author_info).toList();
   }
 
+  @protected
+  List<ApiByteRange> dco_decode_list_api_byte_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_byte_range).toList();
+  }
+
+  @protected
+  List<ApiCleanupItem> dco_decode_list_api_cleanup_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_cleanup_item).toList();
+  }
+
   @protected
   List<ApiComment> dco_decode_list_api_comment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_api_comment).toList();
   }
 
+  @protected
+  List<ApiContinueWatching> dco_decode_list_api_continue_watching(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_api_continue_watching)
+        .toList();
+  }
+
+  @protected
+  List<ApiDownloadEvent> dco_decode_list_api_download_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_download_event).toList();
+  }
+
   @protected
   List<ApiDownloadFolder> dco_decode_list_api_download_folder(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_api_download_folder).toList();
   }
 
+  @protected
+  List<ApiDownloadRule> dco_decode_list_api_download_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_download_rule).toList();
+  }
+
+  @protected
+  List<ApiDownloadSubtitle> dco_decode_list_api_download_subtitle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_api_download_subtitle)
+        .toList();
+  }
+
   @protected
   List<ApiDownloadTask> dco_decode_list_api_download_task(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_api_download_task).toList();
   }
 
+  @protected
+  List<ApiDownloadVariant> dco_decode_list_api_download_variant(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_download_variant).toList();
+  }
+
   @protected
   List<ApiFilterOption> dco_decode_list_api_filter_option(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4141,12 +7329,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_api_home_section).toList();
   }
 
+  @protected
+  List<ApiMissingDownload> dco_decode_list_api_missing_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_missing_download).toList();
+  }
+
   @protected
   List<ApiMyListItem> dco_decode_list_api_my_list_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_api_my_list_item).toList();
   }
 
+  @protected
+  List<ApiNextEpisode> dco_decode_list_api_next_episode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_next_episode).toList();
+  }
+
+  @protected
+  List<ApiOrphanFile> dco_decode_list_api_orphan_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_orphan_file).toList();
+  }
+
   @protected
   List<ApiPlayHistory> dco_decode_list_api_play_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4159,6 +7365,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_api_series_video).toList();
   }
 
+  @protected
+  List<ApiSubtitleTrack> dco_decode_list_api_subtitle_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_subtitle_track).toList();
+  }
+
   @protected
   List<ApiTagGroup> dco_decode_list_api_tag_group(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4247,6 +7459,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_api_user_info(raw);
   }
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4259,6 +7477,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4281,6 +7505,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
   }
 
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4312,6 +7542,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(inner);
   }
 
+  @protected
+  RustStreamSink<ApiBundleProgress>
+  sse_decode_StreamSink_api_bundle_progress_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<ApiDownloadTask> sse_decode_StreamSink_api_download_task_Sse(
     SseDeserializer deserializer,
@@ -4320,6 +7557,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<ApiDownloadVariant>
+  sse_decode_StreamSink_api_download_variant_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<ApiExportProgress>
   sse_decode_StreamSink_api_export_progress_Sse(SseDeserializer deserializer) {
@@ -4327,6 +7571,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<ApiQuotaBlockedEvent>
+  sse_decode_StreamSink_api_quota_blocked_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ApiRelocateProgress>
+  sse_decode_StreamSink_api_relocate_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4381,6 +7643,98 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiBatchEnqueueResult sse_decode_api_batch_enqueue_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_total = sse_decode_u_32(deserializer);
+    var var_enqueued = sse_decode_list_String(deserializer);
+    var var_skipped = sse_decode_list_String(deserializer);
+    var var_failed = sse_decode_list_String(deserializer);
+    return ApiBatchEnqueueResult(
+      total: var_total,
+      enqueued: var_enqueued,
+      skipped: var_skipped,
+      failed: var_failed,
+    );
+  }
+
+  @protected
+  ApiBundleProgress sse_decode_api_bundle_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stage = sse_decode_String(deserializer);
+    var var_totalItems = sse_decode_u_32(deserializer);
+    var var_doneItems = sse_decode_u_32(deserializer);
+    var var_exported = sse_decode_u_32(deserializer);
+    var var_imported = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_u_32(deserializer);
+    var var_currentItem = sse_decode_opt_String(deserializer);
+    var var_currentBytes = sse_decode_u_64(deserializer);
+    var var_currentTotalBytes = sse_decode_u_64(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ApiBundleProgress(
+      stage: var_stage,
+      totalItems: var_totalItems,
+      doneItems: var_doneItems,
+      exported: var_exported,
+      imported: var_imported,
+      skipped: var_skipped,
+      failed: var_failed,
+      currentItem: var_currentItem,
+      currentBytes: var_currentBytes,
+      currentTotalBytes: var_currentTotalBytes,
+      done: var_done,
+      error: var_error,
+    );
+  }
+
+  @protected
+  ApiByteRange sse_decode_api_byte_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_64(deserializer);
+    var var_end = sse_decode_u_64(deserializer);
+    return ApiByteRange(start: var_start, end: var_end);
+  }
+
+  @protected
+  ApiCleanupItem sse_decode_api_cleanup_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_videoId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_completedAt = sse_decode_i_64(deserializer);
+    var var_watchedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return ApiCleanupItem(
+      videoId: var_videoId,
+      title: var_title,
+      reason: var_reason,
+      size: var_size,
+      completedAt: var_completedAt,
+      watchedAt: var_watchedAt,
+    );
+  }
+
+  @protected
+  ApiCleanupPlan sse_decode_api_cleanup_plan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_usedBytes = sse_decode_u_64(deserializer);
+    var var_quotaBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_items = sse_decode_list_api_cleanup_item(deserializer);
+    var var_freedBytes = sse_decode_u_64(deserializer);
+    return ApiCleanupPlan(
+      usedBytes: var_usedBytes,
+      quotaBytes: var_quotaBytes,
+      items: var_items,
+      freedBytes: var_freedBytes,
+    );
+  }
+
   @protected
   ApiCloudflareChallenge sse_decode_api_cloudflare_challenge(
     SseDeserializer deserializer,
@@ -4431,6 +7785,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiContinueWatching sse_decode_api_continue_watching(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_videoId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_coverUrl = sse_decode_String(deserializer);
+    var var_progress = sse_decode_f_32(deserializer);
+    var var_position = sse_decode_u_32(deserializer);
+    var var_duration = sse_decode_u_32(deserializer);
+    var var_lastPlayedAt = sse_decode_i_64(deserializer);
+    var var_seriesTitle = sse_decode_opt_String(deserializer);
+    var var_episode = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_isDownloaded = sse_decode_bool(deserializer);
+    return ApiContinueWatching(
+      videoId: var_videoId,
+      title: var_title,
+      coverUrl: var_coverUrl,
+      progress: var_progress,
+      position: var_position,
+      duration: var_duration,
+      lastPlayedAt: var_lastPlayedAt,
+      seriesTitle: var_seriesTitle,
+      episode: var_episode,
+      isDownloaded: var_isDownloaded,
+    );
+  }
+
+  @protected
+  ApiDownloadBandwidthSettings sse_decode_api_download_bandwidth_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_globalKbPerSec = sse_decode_u_32(deserializer);
+    var var_taskKbPerSec = sse_decode_u_32(deserializer);
+    var var_onlyWhileStreaming = sse_decode_bool(deserializer);
+    var var_playerStreaming = sse_decode_bool(deserializer);
+    return ApiDownloadBandwidthSettings(
+      globalKbPerSec: var_globalKbPerSec,
+      taskKbPerSec: var_taskKbPerSec,
+      onlyWhileStreaming: var_onlyWhileStreaming,
+      playerStreaming: var_playerStreaming,
+    );
+  }
+
+  @protected
+  ApiDownloadEvent sse_decode_api_download_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_status = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    var var_sourceHost = sse_decode_opt_String(deserializer);
+    var var_quality = sse_decode_opt_String(deserializer);
+    var var_format = sse_decode_opt_String(deserializer);
+    var var_rangeStart = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_rangeEnd = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_httpStatus = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_errorKind = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return ApiDownloadEvent(
+      id: var_id,
+      kind: var_kind,
+      status: var_status,
+      message: var_message,
+      sourceHost: var_sourceHost,
+      quality: var_quality,
+      format: var_format,
+      rangeStart: var_rangeStart,
+      rangeEnd: var_rangeEnd,
+      httpStatus: var_httpStatus,
+      errorKind: var_errorKind,
+      createdAt: var_createdAt,
+    );
+  }
+
   @protected
   ApiDownloadFolder sse_decode_api_download_folder(
     SseDeserializer deserializer,
@@ -4439,10 +7870,113 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_id = sse_decode_String(deserializer);
     var var_name = sse_decode_String(deserializer);
     var var_createdAt = sse_decode_i_64(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_sortOrder = sse_decode_i_32(deserializer);
+    var var_coverVideoId = sse_decode_opt_String(deserializer);
+    var var_isSmart = sse_decode_bool(deserializer);
+    var var_videoCount = sse_decode_u_32(deserializer);
     return ApiDownloadFolder(
       id: var_id,
       name: var_name,
       createdAt: var_createdAt,
+      parentId: var_parentId,
+      sortOrder: var_sortOrder,
+      coverVideoId: var_coverVideoId,
+      isSmart: var_isSmart,
+      videoCount: var_videoCount,
+    );
+  }
+
+  @protected
+  ApiDownloadPage sse_decode_api_download_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_items = sse_decode_list_api_download_task(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return ApiDownloadPage(items: var_items, total: var_total);
+  }
+
+  @protected
+  ApiDownloadQuery sse_decode_api_download_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_statuses = sse_decode_list_String(deserializer);
+    var var_folderId = sse_decode_opt_String(deserializer);
+    var var_includeSubfolders = sse_decode_bool(deserializer);
+    var var_authors = sse_decode_list_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_text = sse_decode_opt_String(deserializer);
+    var var_downloadedFrom = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_downloadedTo = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_minBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_sortBy = sse_decode_opt_String(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_limit = sse_decode_u_32(deserializer);
+    return ApiDownloadQuery(
+      statuses: var_statuses,
+      folderId: var_folderId,
+      includeSubfolders: var_includeSubfolders,
+      authors: var_authors,
+      tags: var_tags,
+      text: var_text,
+      downloadedFrom: var_downloadedFrom,
+      downloadedTo: var_downloadedTo,
+      minBytes: var_minBytes,
+      maxBytes: var_maxBytes,
+      sortBy: var_sortBy,
+      descending: var_descending,
+      offset: var_offset,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  ApiDownloadQuotaPolicy sse_decode_api_download_quota_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_quotaBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_autoCleanup = sse_decode_bool(deserializer);
+    var var_watchedOlderThanDays = sse_decode_opt_box_autoadd_u_32(
+      deserializer,
+    );
+    var var_oldestFirst = sse_decode_bool(deserializer);
+    var var_pinnedFolderIds = sse_decode_list_String(deserializer);
+    return ApiDownloadQuotaPolicy(
+      quotaBytes: var_quotaBytes,
+      autoCleanup: var_autoCleanup,
+      watchedOlderThanDays: var_watchedOlderThanDays,
+      oldestFirst: var_oldestFirst,
+      pinnedFolderIds: var_pinnedFolderIds,
+    );
+  }
+
+  @protected
+  ApiDownloadRule sse_decode_api_download_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_matchType = sse_decode_String(deserializer);
+    var var_matchValue = sse_decode_String(deserializer);
+    var var_quality = sse_decode_String(deserializer);
+    var var_folderId = sse_decode_opt_String(deserializer);
+    var var_maxAgeDays = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_dailyCap = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_lastCheckedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return ApiDownloadRule(
+      id: var_id,
+      name: var_name,
+      matchType: var_matchType,
+      matchValue: var_matchValue,
+      quality: var_quality,
+      folderId: var_folderId,
+      maxAgeDays: var_maxAgeDays,
+      dailyCap: var_dailyCap,
+      enabled: var_enabled,
+      createdAt: var_createdAt,
+      lastCheckedAt: var_lastCheckedAt,
     );
   }
 
@@ -4465,11 +7999,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 4:
         var var_error = sse_decode_String(deserializer);
         return ApiDownloadStatus_Failed(error: var_error);
+      case 5:
+        var var_reason = sse_decode_String(deserializer);
+        return ApiDownloadStatus_Corrupt(reason: var_reason);
+      case 6:
+        return ApiDownloadStatus_Missing();
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  ApiDownloadStorageInfo sse_decode_api_download_storage_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_downloadDir = sse_decode_String(deserializer);
+    var var_usedBytes = sse_decode_u_64(deserializer);
+    var var_pendingBytes = sse_decode_u_64(deserializer);
+    var var_freeBytes = sse_decode_u_64(deserializer);
+    var var_diskTotalBytes = sse_decode_u_64(deserializer);
+    var var_reserveBytes = sse_decode_u_64(deserializer);
+    var var_preallocate = sse_decode_bool(deserializer);
+    return ApiDownloadStorageInfo(
+      downloadDir: var_downloadDir,
+      usedBytes: var_usedBytes,
+      pendingBytes: var_pendingBytes,
+      freeBytes: var_freeBytes,
+      diskTotalBytes: var_diskTotalBytes,
+      reserveBytes: var_reserveBytes,
+      preallocate: var_preallocate,
+    );
+  }
+
+  @protected
+  ApiDownloadSubtitle sse_decode_api_download_subtitle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_language = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    var var_filePath = sse_decode_opt_String(deserializer);
+    return ApiDownloadSubtitle(
+      language: var_language,
+      label: var_label,
+      url: var_url,
+      format: var_format,
+      filePath: var_filePath,
+    );
+  }
+
   @protected
   ApiDownloadTask sse_decode_api_download_task(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4493,6 +8074,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_createdAt = sse_decode_i_64(deserializer);
     var var_filePath = sse_decode_opt_String(deserializer);
     var var_folderId = sse_decode_opt_String(deserializer);
+    var var_errorKind = sse_decode_opt_String(deserializer);
+    var var_retryCount = sse_decode_u_32(deserializer);
+    var var_maxRetries = sse_decode_u_32(deserializer);
+    var var_nextRetryAt = sse_decode_opt_box_autoadd_i_64(deserializer);
     return ApiDownloadTask(
       id: var_id,
       videoId: var_videoId,
@@ -4514,6 +8099,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       createdAt: var_createdAt,
       filePath: var_filePath,
       folderId: var_folderId,
+      errorKind: var_errorKind,
+      retryCount: var_retryCount,
+      maxRetries: var_maxRetries,
+      nextRetryAt: var_nextRetryAt,
+    );
+  }
+
+  @protected
+  ApiDownloadVariant sse_decode_api_download_variant(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_videoId = sse_decode_String(deserializer);
+    var var_quality = sse_decode_String(deserializer);
+    var var_sourceQuality = sse_decode_opt_String(deserializer);
+    var var_status = sse_decode_api_download_status(deserializer);
+    var var_progress = sse_decode_f_32(deserializer);
+    var var_downloadedBytes = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_filePath = sse_decode_opt_String(deserializer);
+    var var_replacePrimary = sse_decode_bool(deserializer);
+    var var_keepPrevious = sse_decode_bool(deserializer);
+    var var_errorMessage = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return ApiDownloadVariant(
+      id: var_id,
+      videoId: var_videoId,
+      quality: var_quality,
+      sourceQuality: var_sourceQuality,
+      status: var_status,
+      progress: var_progress,
+      downloadedBytes: var_downloadedBytes,
+      totalBytes: var_totalBytes,
+      filePath: var_filePath,
+      replacePrimary: var_replacePrimary,
+      keepPrevious: var_keepPrevious,
+      errorMessage: var_errorMessage,
+      createdAt: var_createdAt,
+      completedAt: var_completedAt,
+    );
+  }
+
+  @protected
+  ApiExportOptions sse_decode_api_export_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mediaServerLayout = sse_decode_bool(deserializer);
+    var var_groupBy = sse_decode_String(deserializer);
+    var var_filenameTemplate = sse_decode_opt_String(deserializer);
+    var var_maxNameBytes = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_moveFiles = sse_decode_bool(deserializer);
+    return ApiExportOptions(
+      mediaServerLayout: var_mediaServerLayout,
+      groupBy: var_groupBy,
+      filenameTemplate: var_filenameTemplate,
+      maxNameBytes: var_maxNameBytes,
+      moveFiles: var_moveFiles,
     );
   }
 
@@ -4522,16 +8165,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_jobId = sse_decode_String(deserializer);
     var var_totalFiles = sse_decode_u_32(deserializer);
     var var_doneFiles = sse_decode_u_32(deserializer);
+    var var_skippedFiles = sse_decode_u_32(deserializer);
     var var_currentFile = sse_decode_opt_String(deserializer);
     var var_currentBytes = sse_decode_u_64(deserializer);
     var var_currentTotalBytes = sse_decode_u_64(deserializer);
     var var_done = sse_decode_bool(deserializer);
     var var_error = sse_decode_opt_String(deserializer);
     return ApiExportProgress(
+      jobId: var_jobId,
       totalFiles: var_totalFiles,
       doneFiles: var_doneFiles,
+      skippedFiles: var_skippedFiles,
       currentFile: var_currentFile,
       currentBytes: var_currentBytes,
       currentTotalBytes: var_currentTotalBytes,
@@ -4609,6 +8256,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiHomeSection(name: var_name, videos: var_videos);
   }
 
+  @protected
+  ApiMissingDownload sse_decode_api_missing_download(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_videoId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_status = sse_decode_api_download_status(deserializer);
+    var var_missingMedia = sse_decode_bool(deserializer);
+    var var_missingCover = sse_decode_bool(deserializer);
+    var var_missingAvatar = sse_decode_bool(deserializer);
+    var var_expectedBytes = sse_decode_u_64(deserializer);
+    var var_candidateFiles = sse_decode_list_String(deserializer);
+    return ApiMissingDownload(
+      videoId: var_videoId,
+      title: var_title,
+      status: var_status,
+      missingMedia: var_missingMedia,
+      missingCover: var_missingCover,
+      missingAvatar: var_missingAvatar,
+      expectedBytes: var_expectedBytes,
+      candidateFiles: var_candidateFiles,
+    );
+  }
+
   @protected
   ApiMyListInfo sse_decode_api_my_list_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4630,6 +8302,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiNextEpisode sse_decode_api_next_episode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_videoId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_coverUrl = sse_decode_String(deserializer);
+    var var_seriesId = sse_decode_String(deserializer);
+    var var_seriesTitle = sse_decode_String(deserializer);
+    var var_episode = sse_decode_u_32(deserializer);
+    var var_previousVideoId = sse_decode_String(deserializer);
+    var var_previousPlayedAt = sse_decode_i_64(deserializer);
+    var var_isDownloaded = sse_decode_bool(deserializer);
+    return ApiNextEpisode(
+      videoId: var_videoId,
+      title: var_title,
+      coverUrl: var_coverUrl,
+      seriesId: var_seriesId,
+      seriesTitle: var_seriesTitle,
+      episode: var_episode,
+      previousVideoId: var_previousVideoId,
+      previousPlayedAt: var_previousPlayedAt,
+      isDownloaded: var_isDownloaded,
+    );
+  }
+
+  @protected
+  ApiOrphanFile sse_decode_api_orphan_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dir = sse_decode_String(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_modifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_videoId = sse_decode_opt_String(deserializer);
+    return ApiOrphanFile(
+      dir: var_dir,
+      fileName: var_fileName,
+      size: var_size,
+      modifiedAt: var_modifiedAt,
+      videoId: var_videoId,
+    );
+  }
+
   @protected
   ApiPlayHistory sse_decode_api_play_history(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4674,6 +8388,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiPlaylistInfo(name: var_name, videos: var_videos);
   }
 
+  @protected
+  ApiQuotaBlockedEvent sse_decode_api_quota_blocked_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_videoId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_requiredBytes = sse_decode_u_64(deserializer);
+    var var_usedBytes = sse_decode_u_64(deserializer);
+    var var_quotaBytes = sse_decode_u_64(deserializer);
+    var var_freedBytes = sse_decode_u_64(deserializer);
+    var var_blockedAt = sse_decode_i_64(deserializer);
+    return ApiQuotaBlockedEvent(
+      videoId: var_videoId,
+      title: var_title,
+      requiredBytes: var_requiredBytes,
+      usedBytes: var_usedBytes,
+      quotaBytes: var_quotaBytes,
+      freedBytes: var_freedBytes,
+      blockedAt: var_blockedAt,
+    );
+  }
+
+  @protected
+  ApiReconcileReport sse_decode_api_reconcile_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_missing = sse_decode_list_api_missing_download(deserializer);
+    var var_orphans = sse_decode_list_api_orphan_file(deserializer);
+    var var_missingBytes = sse_decode_u_64(deserializer);
+    var var_orphanBytes = sse_decode_u_64(deserializer);
+    var var_scannedAt = sse_decode_i_64(deserializer);
+    return ApiReconcileReport(
+      missing: var_missing,
+      orphans: var_orphans,
+      missingBytes: var_missingBytes,
+      orphanBytes: var_orphanBytes,
+      scannedAt: var_scannedAt,
+    );
+  }
+
+  @protected
+  ApiRelocateProgress sse_decode_api_relocate_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stage = sse_decode_String(deserializer);
+    var var_totalFiles = sse_decode_u_32(deserializer);
+    var var_doneFiles = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_doneBytes = sse_decode_u_64(deserializer);
+    var var_currentFile = sse_decode_opt_String(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ApiRelocateProgress(
+      stage: var_stage,
+      totalFiles: var_totalFiles,
+      doneFiles: var_doneFiles,
+      totalBytes: var_totalBytes,
+      doneBytes: var_doneBytes,
+      currentFile: var_currentFile,
+      done: var_done,
+      error: var_error,
+    );
+  }
+
   @protected
   ApiSearchFilters sse_decode_api_search_filters(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4746,6 +8527,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiSmartFolderRule sse_decode_api_smart_folder_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_authors = sse_decode_list_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_qualities = sse_decode_list_String(deserializer);
+    var var_downloadedFrom = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_downloadedTo = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_uploadedFrom = sse_decode_opt_String(deserializer);
+    var var_uploadedTo = sse_decode_opt_String(deserializer);
+    var var_watched = sse_decode_opt_box_autoadd_bool(deserializer);
+    return ApiSmartFolderRule(
+      authors: var_authors,
+      tags: var_tags,
+      qualities: var_qualities,
+      downloadedFrom: var_downloadedFrom,
+      downloadedTo: var_downloadedTo,
+      uploadedFrom: var_uploadedFrom,
+      uploadedTo: var_uploadedTo,
+      watched: var_watched,
+    );
+  }
+
   @protected
   ApiSubscriptionsPage sse_decode_api_subscriptions_page(
     SseDeserializer deserializer,
@@ -4763,6 +8569,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiSubtitleTrack sse_decode_api_subtitle_track(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_language = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    return ApiSubtitleTrack(
+      language: var_language,
+      label: var_label,
+      url: var_url,
+      format: var_format,
+    );
+  }
+
   @protected
   ApiSyncStatus sse_decode_api_sync_status(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4854,6 +8675,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_author = sse_decode_opt_box_autoadd_api_author_info(deserializer);
     var var_tags = sse_decode_list_String(deserializer);
     var var_qualities = sse_decode_list_api_video_quality(deserializer);
+    var var_subtitles = sse_decode_list_api_subtitle_track(deserializer);
     var var_series = sse_decode_opt_box_autoadd_api_series_info(deserializer);
     var var_relatedVideos = sse_decode_list_api_video_card(deserializer);
     var var_formToken = sse_decode_opt_String(deserializer);
@@ -4880,6 +8702,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       author: var_author,
       tags: var_tags,
       qualities: var_qualities,
+      subtitles: var_subtitles,
       series: var_series,
       relatedVideos: var_relatedVideos,
       formToken: var_formToken,
@@ -4896,7 +8719,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_quality = sse_decode_String(deserializer);
     var var_url = sse_decode_String(deserializer);
-    return ApiVideoQuality(quality: var_quality, url: var_url);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bandwidth = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_codecs = sse_decode_opt_String(deserializer);
+    return ApiVideoQuality(
+      quality: var_quality,
+      url: var_url,
+      width: var_width,
+      height: var_height,
+      bandwidth: var_bandwidth,
+      codecs: var_codecs,
+    );
+  }
+
+  @protected
+  ApiWatchNext sse_decode_api_watch_next(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_continueWatching = sse_decode_list_api_continue_watching(
+      deserializer,
+    );
+    var var_nextEpisodes = sse_decode_list_api_next_episode(deserializer);
+    return ApiWatchNext(
+      continueWatching: var_continueWatching,
+      nextEpisodes: var_nextEpisodes,
+    );
   }
 
   @protected
@@ -4956,6 +8803,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_api_cloudflare_challenge(deserializer));
   }
 
+  @protected
+  ApiDownloadBandwidthSettings
+  sse_decode_box_autoadd_api_download_bandwidth_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_download_bandwidth_settings(deserializer));
+  }
+
+  @protected
+  ApiDownloadQuery sse_decode_box_autoadd_api_download_query(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_download_query(deserializer));
+  }
+
+  @protected
+  ApiDownloadQuotaPolicy sse_decode_box_autoadd_api_download_quota_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_download_quota_policy(deserializer));
+  }
+
+  @protected
+  ApiDownloadRule sse_decode_box_autoadd_api_download_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_download_rule(deserializer));
+  }
+
+  @protected
+  ApiExportOptions sse_decode_box_autoadd_api_export_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_export_options(deserializer));
+  }
+
   @protected
   ApiMyListInfo sse_decode_box_autoadd_api_my_list_info(
     SseDeserializer deserializer,
@@ -4996,6 +8884,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_api_series_info(deserializer));
   }
 
+  @protected
+  ApiSmartFolderRule sse_decode_box_autoadd_api_smart_folder_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_smart_folder_rule(deserializer));
+  }
+
   @protected
   ApiUserInfo sse_decode_box_autoadd_api_user_info(
     SseDeserializer deserializer,
@@ -5012,6 +8908,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_api_web_dav_settings(deserializer));
   }
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5024,6 +8926,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_i_64(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5085,49 +8993,133 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiAuthorInfo> sse_decode_list_api_author_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiAuthorInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_author_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiByteRange> sse_decode_list_api_byte_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiByteRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_byte_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiCleanupItem> sse_decode_list_api_cleanup_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiCleanupItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_cleanup_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiComment> sse_decode_list_api_comment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiComment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_comment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiContinueWatching> sse_decode_list_api_continue_watching(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiContinueWatching>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_continue_watching(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiDownloadEvent> sse_decode_list_api_download_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiDownloadEvent>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+      ans_.add(sse_decode_api_download_event(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<ApiAuthorInfo> sse_decode_list_api_author_info(
+  List<ApiDownloadFolder> sse_decode_list_api_download_folder(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiAuthorInfo>[];
+    var ans_ = <ApiDownloadFolder>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_author_info(deserializer));
+      ans_.add(sse_decode_api_download_folder(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<ApiComment> sse_decode_list_api_comment(SseDeserializer deserializer) {
+  List<ApiDownloadRule> sse_decode_list_api_download_rule(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiComment>[];
+    var ans_ = <ApiDownloadRule>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_comment(deserializer));
+      ans_.add(sse_decode_api_download_rule(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<ApiDownloadFolder> sse_decode_list_api_download_folder(
+  List<ApiDownloadSubtitle> sse_decode_list_api_download_subtitle(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiDownloadFolder>[];
+    var ans_ = <ApiDownloadSubtitle>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_download_folder(deserializer));
+      ans_.add(sse_decode_api_download_subtitle(deserializer));
     }
     return ans_;
   }
@@ -5146,6 +9138,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ApiDownloadVariant> sse_decode_list_api_download_variant(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiDownloadVariant>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_download_variant(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ApiFilterOption> sse_decode_list_api_filter_option(
     SseDeserializer deserializer,
@@ -5174,6 +9180,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ApiMissingDownload> sse_decode_list_api_missing_download(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiMissingDownload>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_missing_download(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ApiMyListItem> sse_decode_list_api_my_list_item(
     SseDeserializer deserializer,
@@ -5188,6 +9208,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ApiNextEpisode> sse_decode_list_api_next_episode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiNextEpisode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_next_episode(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiOrphanFile> sse_decode_list_api_orphan_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiOrphanFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_orphan_file(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ApiPlayHistory> sse_decode_list_api_play_history(
     SseDeserializer deserializer,
@@ -5216,6 +9264,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ApiSubtitleTrack> sse_decode_list_api_subtitle_track(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiSubtitleTrack>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_subtitle_track(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ApiTagGroup> sse_decode_list_api_tag_group(
     SseDeserializer deserializer,
@@ -5394,6 +9456,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5416,6 +9489,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5448,6 +9532,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5480,6 +9570,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_api_bundle_progress_Sse(
+    RustStreamSink<ApiBundleProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_bundle_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_api_download_task_Sse(
     RustStreamSink<ApiDownloadTask> self,
@@ -5497,6 +9604,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_api_download_variant_Sse(
+    RustStreamSink<ApiDownloadVariant> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_download_variant,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_api_export_progress_Sse(
     RustStreamSink<ApiExportProgress> self,
@@ -5514,6 +9638,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_api_quota_blocked_event_Sse(
+    RustStreamSink<ApiQuotaBlockedEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_quota_blocked_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_api_relocate_progress_Sse(
+    RustStreamSink<ApiRelocateProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_relocate_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5554,6 +9712,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.videoCode, serializer);
   }
 
+  @protected
+  void sse_encode_api_batch_enqueue_result(
+    ApiBatchEnqueueResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_list_String(self.enqueued, serializer);
+    sse_encode_list_String(self.skipped, serializer);
+    sse_encode_list_String(self.failed, serializer);
+  }
+
+  @protected
+  void sse_encode_api_bundle_progress(
+    ApiBundleProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.stage, serializer);
+    sse_encode_u_32(self.totalItems, serializer);
+    sse_encode_u_32(self.doneItems, serializer);
+    sse_encode_u_32(self.exported, serializer);
+    sse_encode_u_32(self.imported, serializer);
+    sse_encode_u_32(self.skipped, serializer);
+    sse_encode_u_32(self.failed, serializer);
+    sse_encode_opt_String(self.currentItem, serializer);
+    sse_encode_u_64(self.currentBytes, serializer);
+    sse_encode_u_64(self.currentTotalBytes, serializer);
+    sse_encode_bool(self.done, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_api_byte_range(ApiByteRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.start, serializer);
+    sse_encode_u_64(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_api_cleanup_item(
+    ApiCleanupItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.videoId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.reason, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_i_64(self.completedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.watchedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_api_cleanup_plan(
+    ApiCleanupPlan self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.usedBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.quotaBytes, serializer);
+    sse_encode_list_api_cleanup_item(self.items, serializer);
+    sse_encode_u_64(self.freedBytes, serializer);
+  }
+
   @protected
   void sse_encode_api_cloudflare_challenge(
     ApiCloudflareChallenge self,
@@ -5590,6 +9813,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.hasNext, serializer);
   }
 
+  @protected
+  void sse_encode_api_continue_watching(
+    ApiContinueWatching self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.videoId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.coverUrl, serializer);
+    sse_encode_f_32(self.progress, serializer);
+    sse_encode_u_32(self.position, serializer);
+    sse_encode_u_32(self.duration, serializer);
+    sse_encode_i_64(self.lastPlayedAt, serializer);
+    sse_encode_opt_String(self.seriesTitle, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.episode, serializer);
+    sse_encode_bool(self.isDownloaded, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_bandwidth_settings(
+    ApiDownloadBandwidthSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.globalKbPerSec, serializer);
+    sse_encode_u_32(self.taskKbPerSec, serializer);
+    sse_encode_bool(self.onlyWhileStreaming, serializer);
+    sse_encode_bool(self.playerStreaming, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_event(
+    ApiDownloadEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_opt_String(self.status, serializer);
+    sse_encode_opt_String(self.message, serializer);
+    sse_encode_opt_String(self.sourceHost, serializer);
+    sse_encode_opt_String(self.quality, serializer);
+    sse_encode_opt_String(self.format, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.rangeStart, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.rangeEnd, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.httpStatus, serializer);
+    sse_encode_opt_String(self.errorKind, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+  }
+
   @protected
   void sse_encode_api_download_folder(
     ApiDownloadFolder self,
@@ -5599,6 +9872,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.id, serializer);
     sse_encode_String(self.name, serializer);
     sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_i_32(self.sortOrder, serializer);
+    sse_encode_opt_String(self.coverVideoId, serializer);
+    sse_encode_bool(self.isSmart, serializer);
+    sse_encode_u_32(self.videoCount, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_page(
+    ApiDownloadPage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_api_download_task(self.items, serializer);
+    sse_encode_u_32(self.total, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_query(
+    ApiDownloadQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.statuses, serializer);
+    sse_encode_opt_String(self.folderId, serializer);
+    sse_encode_bool(self.includeSubfolders, serializer);
+    sse_encode_list_String(self.authors, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.text, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.downloadedFrom, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.downloadedTo, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.minBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxBytes, serializer);
+    sse_encode_opt_String(self.sortBy, serializer);
+    sse_encode_bool(self.descending, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_u_32(self.limit, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_quota_policy(
+    ApiDownloadQuotaPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.quotaBytes, serializer);
+    sse_encode_bool(self.autoCleanup, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.watchedOlderThanDays, serializer);
+    sse_encode_bool(self.oldestFirst, serializer);
+    sse_encode_list_String(self.pinnedFolderIds, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_rule(
+    ApiDownloadRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.matchType, serializer);
+    sse_encode_String(self.matchValue, serializer);
+    sse_encode_String(self.quality, serializer);
+    sse_encode_opt_String(self.folderId, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxAgeDays, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.dailyCap, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastCheckedAt, serializer);
   }
 
   @protected
@@ -5619,9 +9961,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case ApiDownloadStatus_Failed(error: final error):
         sse_encode_i_32(4, serializer);
         sse_encode_String(error, serializer);
+      case ApiDownloadStatus_Corrupt(reason: final reason):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(reason, serializer);
+      case ApiDownloadStatus_Missing():
+        sse_encode_i_32(6, serializer);
     }
   }
 
+  @protected
+  void sse_encode_api_download_storage_info(
+    ApiDownloadStorageInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.downloadDir, serializer);
+    sse_encode_u_64(self.usedBytes, serializer);
+    sse_encode_u_64(self.pendingBytes, serializer);
+    sse_encode_u_64(self.freeBytes, serializer);
+    sse_encode_u_64(self.diskTotalBytes, serializer);
+    sse_encode_u_64(self.reserveBytes, serializer);
+    sse_encode_bool(self.preallocate, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_subtitle(
+    ApiDownloadSubtitle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.language, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_opt_String(self.filePath, serializer);
+  }
+
   @protected
   void sse_encode_api_download_task(
     ApiDownloadTask self,
@@ -5648,6 +10023,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.createdAt, serializer);
     sse_encode_opt_String(self.filePath, serializer);
     sse_encode_opt_String(self.folderId, serializer);
+    sse_encode_opt_String(self.errorKind, serializer);
+    sse_encode_u_32(self.retryCount, serializer);
+    sse_encode_u_32(self.maxRetries, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextRetryAt, serializer);
+  }
+
+  @protected
+  void sse_encode_api_download_variant(
+    ApiDownloadVariant self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.videoId, serializer);
+    sse_encode_String(self.quality, serializer);
+    sse_encode_opt_String(self.sourceQuality, serializer);
+    sse_encode_api_download_status(self.status, serializer);
+    sse_encode_f_32(self.progress, serializer);
+    sse_encode_u_64(self.downloadedBytes, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_opt_String(self.filePath, serializer);
+    sse_encode_bool(self.replacePrimary, serializer);
+    sse_encode_bool(self.keepPrevious, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_api_export_options(
+    ApiExportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.mediaServerLayout, serializer);
+    sse_encode_String(self.groupBy, serializer);
+    sse_encode_opt_String(self.filenameTemplate, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxNameBytes, serializer);
+    sse_encode_bool(self.moveFiles, serializer);
   }
 
   @protected
@@ -5656,8 +10070,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.jobId, serializer);
     sse_encode_u_32(self.totalFiles, serializer);
     sse_encode_u_32(self.doneFiles, serializer);
+    sse_encode_u_32(self.skippedFiles, serializer);
     sse_encode_opt_String(self.currentFile, serializer);
     sse_encode_u_64(self.currentBytes, serializer);
     sse_encode_u_64(self.currentTotalBytes, serializer);
@@ -5722,6 +10138,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_api_video_card(self.videos, serializer);
   }
 
+  @protected
+  void sse_encode_api_missing_download(
+    ApiMissingDownload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.videoId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_api_download_status(self.status, serializer);
+    sse_encode_bool(self.missingMedia, serializer);
+    sse_encode_bool(self.missingCover, serializer);
+    sse_encode_bool(self.missingAvatar, serializer);
+    sse_encode_u_64(self.expectedBytes, serializer);
+    sse_encode_list_String(self.candidateFiles, serializer);
+  }
+
   @protected
   void sse_encode_api_my_list_info(
     ApiMyListInfo self,
@@ -5743,6 +10175,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isSelected, serializer);
   }
 
+  @protected
+  void sse_encode_api_next_episode(
+    ApiNextEpisode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.videoId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.coverUrl, serializer);
+    sse_encode_String(self.seriesId, serializer);
+    sse_encode_String(self.seriesTitle, serializer);
+    sse_encode_u_32(self.episode, serializer);
+    sse_encode_String(self.previousVideoId, serializer);
+    sse_encode_i_64(self.previousPlayedAt, serializer);
+    sse_encode_bool(self.isDownloaded, serializer);
+  }
+
+  @protected
+  void sse_encode_api_orphan_file(
+    ApiOrphanFile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.dir, serializer);
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modifiedAt, serializer);
+    sse_encode_opt_String(self.videoId, serializer);
+  }
+
   @protected
   void sse_encode_api_play_history(
     ApiPlayHistory self,
@@ -5779,6 +10241,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_api_video_card(self.videos, serializer);
   }
 
+  @protected
+  void sse_encode_api_quota_blocked_event(
+    ApiQuotaBlockedEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.videoId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_64(self.requiredBytes, serializer);
+    sse_encode_u_64(self.usedBytes, serializer);
+    sse_encode_u_64(self.quotaBytes, serializer);
+    sse_encode_u_64(self.freedBytes, serializer);
+    sse_encode_i_64(self.blockedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_api_reconcile_report(
+    ApiReconcileReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_api_missing_download(self.missing, serializer);
+    sse_encode_list_api_orphan_file(self.orphans, serializer);
+    sse_encode_u_64(self.missingBytes, serializer);
+    sse_encode_u_64(self.orphanBytes, serializer);
+    sse_encode_i_64(self.scannedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_api_relocate_progress(
+    ApiRelocateProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.stage, serializer);
+    sse_encode_u_32(self.totalFiles, serializer);
+    sse_encode_u_32(self.doneFiles, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_u_64(self.doneBytes, serializer);
+    sse_encode_opt_String(self.currentFile, serializer);
+    sse_encode_bool(self.done, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_api_search_filters(
     ApiSearchFilters self,
@@ -5833,6 +10339,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.episode, serializer);
   }
 
+  @protected
+  void sse_encode_api_smart_folder_rule(
+    ApiSmartFolderRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.authors, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_list_String(self.qualities, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.downloadedFrom, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.downloadedTo, serializer);
+    sse_encode_opt_String(self.uploadedFrom, serializer);
+    sse_encode_opt_String(self.uploadedTo, serializer);
+    sse_encode_opt_box_autoadd_bool(self.watched, serializer);
+  }
+
   @protected
   void sse_encode_api_subscriptions_page(
     ApiSubscriptionsPage self,
@@ -5845,6 +10367,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.hasNext, serializer);
   }
 
+  @protected
+  void sse_encode_api_subtitle_track(
+    ApiSubtitleTrack self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.language, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.format, serializer);
+  }
+
   @protected
   void sse_encode_api_sync_status(
     ApiSyncStatus self,
@@ -5921,6 +10455,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_api_author_info(self.author, serializer);
     sse_encode_list_String(self.tags, serializer);
     sse_encode_list_api_video_quality(self.qualities, serializer);
+    sse_encode_list_api_subtitle_track(self.subtitles, serializer);
     sse_encode_opt_box_autoadd_api_series_info(self.series, serializer);
     sse_encode_list_api_video_card(self.relatedVideos, serializer);
     sse_encode_opt_String(self.formToken, serializer);
@@ -5939,6 +10474,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.quality, serializer);
     sse_encode_String(self.url, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.bandwidth, serializer);
+    sse_encode_opt_String(self.codecs, serializer);
+  }
+
+  @protected
+  void sse_encode_api_watch_next(ApiWatchNext self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_api_continue_watching(self.continueWatching, serializer);
+    sse_encode_list_api_next_episode(self.nextEpisodes, serializer);
   }
 
   @protected
@@ -5997,6 +10543,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_api_cloudflare_challenge(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_api_download_bandwidth_settings(
+    ApiDownloadBandwidthSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_download_bandwidth_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_download_query(
+    ApiDownloadQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_download_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_download_quota_policy(
+    ApiDownloadQuotaPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_download_quota_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_download_rule(
+    ApiDownloadRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_download_rule(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_export_options(
+    ApiExportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_export_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_api_my_list_info(
     ApiMyListInfo self,
@@ -6042,6 +10633,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_api_series_info(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_api_smart_folder_rule(
+    ApiSmartFolderRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_smart_folder_rule(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_api_user_info(
     ApiUserInfo self,
@@ -6060,6 +10660,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_api_web_dav_settings(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6075,6 +10681,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6142,6 +10754,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_byte_range(
+    List<ApiByteRange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_byte_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_cleanup_item(
+    List<ApiCleanupItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_cleanup_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_comment(
     List<ApiComment> self,
@@ -6154,6 +10790,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_continue_watching(
+    List<ApiContinueWatching> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_continue_watching(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_download_event(
+    List<ApiDownloadEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_download_event(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_download_folder(
     List<ApiDownloadFolder> self,
@@ -6166,6 +10826,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_download_rule(
+    List<ApiDownloadRule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_download_rule(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_download_subtitle(
+    List<ApiDownloadSubtitle> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_download_subtitle(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_download_task(
     List<ApiDownloadTask> self,
@@ -6178,6 +10862,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_download_variant(
+    List<ApiDownloadVariant> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_download_variant(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_filter_option(
     List<ApiFilterOption> self,
@@ -6202,6 +10898,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_missing_download(
+    List<ApiMissingDownload> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_missing_download(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_my_list_item(
     List<ApiMyListItem> self,
@@ -6214,6 +10922,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_next_episode(
+    List<ApiNextEpisode> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_next_episode(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_orphan_file(
+    List<ApiOrphanFile> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_orphan_file(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_play_history(
     List<ApiPlayHistory> self,
@@ -6238,6 +10970,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_api_subtitle_track(
+    List<ApiSubtitleTrack> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_subtitle_track(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_api_tag_group(
     List<ApiTagGroup> self,
@@ -6410,6 +11154,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6433,6 +11187,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6463,6 +11227,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5,15 +5,30 @@
 
 import 'api/cache.dart';
 import 'api/download.dart';
+import 'api/download_batch.dart';
+import 'api/download_bundle.dart';
+import 'api/download_events.dart';
+import 'api/download_export.dart';
 import 'api/download_folders.dart';
+import 'api/download_hls.dart';
+import 'api/download_quota.dart';
+import 'api/download_reconcile.dart';
+import 'api/download_relocate.dart';
+import 'api/download_rules.dart';
+import 'api/download_seek.dart';
+import 'api/download_subtitles.dart';
+import 'api/download_throttle.dart';
+import 'api/download_variants.dart';
 import 'api/init.dart';
 import 'api/models.dart';
 import 'api/search.dart';
 import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/stream.dart';
 import 'api/sync.dart';
 import 'api/user.dart';
 import 'api/video.dart';
+import 'api/watch_next.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -31,15 +46,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<ApiBundleProgress>
+  dco_decode_StreamSink_api_bundle_progress_Sse(dynamic raw);
+
   @protected
   RustStreamSink<ApiDownloadTask> dco_decode_StreamSink_api_download_task_Sse(
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<ApiDownloadVariant>
+  dco_decode_StreamSink_api_download_variant_Sse(dynamic raw);
+
   @protected
   RustStreamSink<ApiExportProgress>
   dco_decode_StreamSink_api_export_progress_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<ApiQuotaBlockedEvent>
+  dco_decode_StreamSink_api_quota_blocked_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<ApiRelocateProgress>
+  dco_decode_StreamSink_api_relocate_progress_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
@@ -52,6 +83,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiBanner dco_decode_api_banner(dynamic raw);
 
+  @protected
+  ApiBatchEnqueueResult dco_decode_api_batch_enqueue_result(dynamic raw);
+
+  @protected
+  ApiBundleProgress dco_decode_api_bundle_progress(dynamic raw);
+
+  @protected
+  ApiByteRange dco_decode_api_byte_range(dynamic raw);
+
+  @protected
+  ApiCleanupItem dco_decode_api_cleanup_item(dynamic raw);
+
+  @protected
+  ApiCleanupPlan dco_decode_api_cleanup_plan(dynamic raw);
+
   @protected
   ApiCloudflareChallenge dco_decode_api_cloudflare_challenge(dynamic raw);
 
@@ -61,15 +107,50 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiCommentList dco_decode_api_comment_list(dynamic raw);
 
+  @protected
+  ApiContinueWatching dco_decode_api_continue_watching(dynamic raw);
+
+  @protected
+  ApiDownloadBandwidthSettings dco_decode_api_download_bandwidth_settings(
+    dynamic raw,
+  );
+
+  @protected
+  ApiDownloadEvent dco_decode_api_download_event(dynamic raw);
+
   @protected
   ApiDownloadFolder dco_decode_api_download_folder(dynamic raw);
 
+  @protected
+  ApiDownloadPage dco_decode_api_download_page(dynamic raw);
+
+  @protected
+  ApiDownloadQuery dco_decode_api_download_query(dynamic raw);
+
+  @protected
+  ApiDownloadQuotaPolicy dco_decode_api_download_quota_policy(dynamic raw);
+
+  @protected
+  ApiDownloadRule dco_decode_api_download_rule(dynamic raw);
+
   @protected
   ApiDownloadStatus dco_decode_api_download_status(dynamic raw);
 
+  @protected
+  ApiDownloadStorageInfo dco_decode_api_download_storage_info(dynamic raw);
+
+  @protected
+  ApiDownloadSubtitle dco_decode_api_download_subtitle(dynamic raw);
+
   @protected
   ApiDownloadTask dco_decode_api_download_task(dynamic raw);
 
+  @protected
+  ApiDownloadVariant dco_decode_api_download_variant(dynamic raw);
+
+  @protected
+  ApiExportOptions dco_decode_api_export_options(dynamic raw);
+
   @protected
   ApiExportProgress dco_decode_api_export_progress(dynamic raw);
 
@@ -88,12 +169,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiHomeSection dco_decode_api_home_section(dynamic raw);
 
+  @protected
+  ApiMissingDownload dco_decode_api_missing_download(dynamic raw);
+
   @protected
   ApiMyListInfo dco_decode_api_my_list_info(dynamic raw);
 
   @protected
   ApiMyListItem dco_decode_api_my_list_item(dynamic raw);
 
+  @protected
+  ApiNextEpisode dco_decode_api_next_episode(dynamic raw);
+
+  @protected
+  ApiOrphanFile dco_decode_api_orphan_file(dynamic raw);
+
   @protected
   ApiPlayHistory dco_decode_api_play_history(dynamic raw);
 
@@ -103,6 +193,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiPlaylistInfo dco_decode_api_playlist_info(dynamic raw);
 
+  @protected
+  ApiQuotaBlockedEvent dco_decode_api_quota_blocked_event(dynamic raw);
+
+  @protected
+  ApiReconcileReport dco_decode_api_reconcile_report(dynamic raw);
+
+  @protected
+  ApiRelocateProgress dco_decode_api_relocate_progress(dynamic raw);
+
   @protected
   ApiSearchFilters dco_decode_api_search_filters(dynamic raw);
 
@@ -115,9 +214,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiSeriesVideo dco_decode_api_series_video(dynamic raw);
 
+  @protected
+  ApiSmartFolderRule dco_decode_api_smart_folder_rule(dynamic raw);
+
   @protected
   ApiSubscriptionsPage dco_decode_api_subscriptions_page(dynamic raw);
 
+  @protected
+  ApiSubtitleTrack dco_decode_api_subtitle_track(dynamic raw);
+
   @protected
   ApiSyncStatus dco_decode_api_sync_status(dynamic raw);
 
@@ -136,6 +241,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiVideoQuality dco_decode_api_video_quality(dynamic raw);
 
+  @protected
+  ApiWatchNext dco_decode_api_watch_next(dynamic raw);
+
   @protected
   ApiWebDavSettings dco_decode_api_web_dav_settings(dynamic raw);
 
@@ -156,6 +264,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ApiDownloadBandwidthSettings
+  dco_decode_box_autoadd_api_download_bandwidth_settings(dynamic raw);
+
+  @protected
+  ApiDownloadQuery dco_decode_box_autoadd_api_download_query(dynamic raw);
+
+  @protected
+  ApiDownloadQuotaPolicy dco_decode_box_autoadd_api_download_quota_policy(
+    dynamic raw,
+  );
+
+  @protected
+  ApiDownloadRule dco_decode_box_autoadd_api_download_rule(dynamic raw);
+
+  @protected
+  ApiExportOptions dco_decode_box_autoadd_api_export_options(dynamic raw);
+
   @protected
   ApiMyListInfo dco_decode_box_autoadd_api_my_list_info(dynamic raw);
 
@@ -171,18 +297,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiSeriesInfo dco_decode_box_autoadd_api_series_info(dynamic raw);
 
+  @protected
+  ApiSmartFolderRule dco_decode_box_autoadd_api_smart_folder_rule(dynamic raw);
+
   @protected
   ApiUserInfo dco_decode_box_autoadd_api_user_info(dynamic raw);
 
   @protected
   ApiWebDavSettings dco_decode_box_autoadd_api_web_dav_settings(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw);
 
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
@@ -210,30 +345,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<ApiAuthorInfo> dco_decode_list_api_author_info(dynamic raw);
 
+  @protected
+  List<ApiByteRange> dco_decode_list_api_byte_range(dynamic raw);
+
+  @protected
+  List<ApiCleanupItem> dco_decode_list_api_cleanup_item(dynamic raw);
+
   @protected
   List<ApiComment> dco_decode_list_api_comment(dynamic raw);
 
+  @protected
+  List<ApiContinueWatching> dco_decode_list_api_continue_watching(dynamic raw);
+
+  @protected
+  List<ApiDownloadEvent> dco_decode_list_api_download_event(dynamic raw);
+
   @protected
   List<ApiDownloadFolder> dco_decode_list_api_download_folder(dynamic raw);
 
+  @protected
+  List<ApiDownloadRule> dco_decode_list_api_download_rule(dynamic raw);
+
+  @protected
+  List<ApiDownloadSubtitle> dco_decode_list_api_download_subtitle(dynamic raw);
+
   @protected
   List<ApiDownloadTask> dco_decode_list_api_download_task(dynamic raw);
 
+  @protected
+  List<ApiDownloadVariant> dco_decode_list_api_download_variant(dynamic raw);
+
   @protected
   List<ApiFilterOption> dco_decode_list_api_filter_option(dynamic raw);
 
   @protected
   List<ApiHomeSection> dco_decode_list_api_home_section(dynamic raw);
 
+  @protected
+  List<ApiMissingDownload> dco_decode_list_api_missing_download(dynamic raw);
+
   @protected
   List<ApiMyListItem> dco_decode_list_api_my_list_item(dynamic raw);
 
+  @protected
+  List<ApiNextEpisode> dco_decode_list_api_next_episode(dynamic raw);
+
+  @protected
+  List<ApiOrphanFile> dco_decode_list_api_orphan_file(dynamic raw);
+
   @protected
   List<ApiPlayHistory> dco_decode_list_api_play_history(dynamic raw);
 
   @protected
   List<ApiSeriesVideo> dco_decode_list_api_series_video(dynamic raw);
 
+  @protected
+  List<ApiSubtitleTrack> dco_decode_list_api_subtitle_track(dynamic raw);
+
   @protected
   List<ApiTagGroup> dco_decode_list_api_tag_group(dynamic raw);
 
@@ -278,12 +446,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiUserInfo? dco_decode_opt_box_autoadd_api_user_info(dynamic raw);
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
@@ -293,6 +467,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -308,15 +485,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<ApiBundleProgress>
+  sse_decode_StreamSink_api_bundle_progress_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<ApiDownloadTask> sse_decode_StreamSink_api_download_task_Sse(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<ApiDownloadVariant>
+  sse_decode_StreamSink_api_download_variant_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<ApiExportProgress>
   sse_decode_StreamSink_api_export_progress_Sse(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<ApiQuotaBlockedEvent>
+  sse_decode_StreamSink_api_quota_blocked_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ApiRelocateProgress>
+  sse_decode_StreamSink_api_relocate_progress_Sse(SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
@@ -329,6 +524,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiBanner sse_decode_api_banner(SseDeserializer deserializer);
 
+  @protected
+  ApiBatchEnqueueResult sse_decode_api_batch_enqueue_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiBundleProgress sse_decode_api_bundle_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiByteRange sse_decode_api_byte_range(SseDeserializer deserializer);
+
+  @protected
+  ApiCleanupItem sse_decode_api_cleanup_item(SseDeserializer deserializer);
+
+  @protected
+  ApiCleanupPlan sse_decode_api_cleanup_plan(SseDeserializer deserializer);
+
   @protected
   ApiCloudflareChallenge sse_decode_api_cloudflare_challenge(
     SseDeserializer deserializer,
@@ -340,19 +554,64 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiCommentList sse_decode_api_comment_list(SseDeserializer deserializer);
 
+  @protected
+  ApiContinueWatching sse_decode_api_continue_watching(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadBandwidthSettings sse_decode_api_download_bandwidth_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadEvent sse_decode_api_download_event(SseDeserializer deserializer);
+
   @protected
   ApiDownloadFolder sse_decode_api_download_folder(
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiDownloadPage sse_decode_api_download_page(SseDeserializer deserializer);
+
+  @protected
+  ApiDownloadQuery sse_decode_api_download_query(SseDeserializer deserializer);
+
+  @protected
+  ApiDownloadQuotaPolicy sse_decode_api_download_quota_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadRule sse_decode_api_download_rule(SseDeserializer deserializer);
+
   @protected
   ApiDownloadStatus sse_decode_api_download_status(
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiDownloadStorageInfo sse_decode_api_download_storage_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadSubtitle sse_decode_api_download_subtitle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiDownloadTask sse_decode_api_download_task(SseDeserializer deserializer);
 
+  @protected
+  ApiDownloadVariant sse_decode_api_download_variant(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiExportOptions sse_decode_api_export_options(SseDeserializer deserializer);
+
   @protected
   ApiExportProgress sse_decode_api_export_progress(
     SseDeserializer deserializer,
@@ -373,12 +632,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiHomeSection sse_decode_api_home_section(SseDeserializer deserializer);
 
+  @protected
+  ApiMissingDownload sse_decode_api_missing_download(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiMyListInfo sse_decode_api_my_list_info(SseDeserializer deserializer);
 
   @protected
   ApiMyListItem sse_decode_api_my_list_item(SseDeserializer deserializer);
 
+  @protected
+  ApiNextEpisode sse_decode_api_next_episode(SseDeserializer deserializer);
+
+  @protected
+  ApiOrphanFile sse_decode_api_orphan_file(SseDeserializer deserializer);
+
   @protected
   ApiPlayHistory sse_decode_api_play_history(SseDeserializer deserializer);
 
@@ -390,6 +660,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiPlaylistInfo sse_decode_api_playlist_info(SseDeserializer deserializer);
 
+  @protected
+  ApiQuotaBlockedEvent sse_decode_api_quota_blocked_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiReconcileReport sse_decode_api_reconcile_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiRelocateProgress sse_decode_api_relocate_progress(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiSearchFilters sse_decode_api_search_filters(SseDeserializer deserializer);
 
@@ -402,11 +687,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiSeriesVideo sse_decode_api_series_video(SseDeserializer deserializer);
 
+  @protected
+  ApiSmartFolderRule sse_decode_api_smart_folder_rule(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiSubscriptionsPage sse_decode_api_subscriptions_page(
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiSubtitleTrack sse_decode_api_subtitle_track(SseDeserializer deserializer);
+
   @protected
   ApiSyncStatus sse_decode_api_sync_status(SseDeserializer deserializer);
 
@@ -425,6 +718,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiVideoQuality sse_decode_api_video_quality(SseDeserializer deserializer);
 
+  @protected
+  ApiWatchNext sse_decode_api_watch_next(SseDeserializer deserializer);
+
   @protected
   ApiWebDavSettings sse_decode_api_web_dav_settings(
     SseDeserializer deserializer,
@@ -451,6 +747,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiDownloadBandwidthSettings
+  sse_decode_box_autoadd_api_download_bandwidth_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadQuery sse_decode_box_autoadd_api_download_query(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadQuotaPolicy sse_decode_box_autoadd_api_download_quota_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiDownloadRule sse_decode_box_autoadd_api_download_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiExportOptions sse_decode_box_autoadd_api_export_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiMyListInfo sse_decode_box_autoadd_api_my_list_info(
     SseDeserializer deserializer,
@@ -476,6 +798,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiSmartFolderRule sse_decode_box_autoadd_api_smart_folder_rule(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiUserInfo sse_decode_box_autoadd_api_user_info(
     SseDeserializer deserializer,
@@ -486,12 +813,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -521,19 +854,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ApiByteRange> sse_decode_list_api_byte_range(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiCleanupItem> sse_decode_list_api_cleanup_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiComment> sse_decode_list_api_comment(SseDeserializer deserializer);
 
+  @protected
+  List<ApiContinueWatching> sse_decode_list_api_continue_watching(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiDownloadEvent> sse_decode_list_api_download_event(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiDownloadFolder> sse_decode_list_api_download_folder(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ApiDownloadRule> sse_decode_list_api_download_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiDownloadSubtitle> sse_decode_list_api_download_subtitle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiDownloadTask> sse_decode_list_api_download_task(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ApiDownloadVariant> sse_decode_list_api_download_variant(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiFilterOption> sse_decode_list_api_filter_option(
     SseDeserializer deserializer,
@@ -544,11 +912,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ApiMissingDownload> sse_decode_list_api_missing_download(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiMyListItem> sse_decode_list_api_my_list_item(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ApiNextEpisode> sse_decode_list_api_next_episode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiOrphanFile> sse_decode_list_api_orphan_file(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiPlayHistory> sse_decode_list_api_play_history(
     SseDeserializer deserializer,
@@ -559,6 +942,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ApiSubtitleTrack> sse_decode_list_api_subtitle_track(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiTagGroup> sse_decode_list_api_tag_group(SseDeserializer deserializer);
 
@@ -623,12 +1011,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -640,6 +1034,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -658,18 +1055,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_api_bundle_progress_Sse(
+    RustStreamSink<ApiBundleProgress> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_api_download_task_Sse(
     RustStreamSink<ApiDownloadTask> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_api_download_variant_Sse(
+    RustStreamSink<ApiDownloadVariant> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_api_export_progress_Sse(
     RustStreamSink<ApiExportProgress> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_api_quota_blocked_event_Sse(
+    RustStreamSink<ApiQuotaBlockedEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_api_relocate_progress_Sse(
+    RustStreamSink<ApiRelocateProgress> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -685,6 +1106,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_api_banner(ApiBanner self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_api_batch_enqueue_result(
+    ApiBatchEnqueueResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_bundle_progress(
+    ApiBundleProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_byte_range(ApiByteRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_cleanup_item(
+    ApiCleanupItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_cleanup_plan(
+    ApiCleanupPlan self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_cloudflare_challenge(
     ApiCloudflareChallenge self,
@@ -700,24 +1148,90 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_continue_watching(
+    ApiContinueWatching self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_download_bandwidth_settings(
+    ApiDownloadBandwidthSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_download_event(
+    ApiDownloadEvent self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_download_folder(
     ApiDownloadFolder self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_download_page(
+    ApiDownloadPage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_download_query(
+    ApiDownloadQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_download_quota_policy(
+    ApiDownloadQuotaPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_download_rule(
+    ApiDownloadRule self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_download_status(
     ApiDownloadStatus self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_download_storage_info(
+    ApiDownloadStorageInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_download_subtitle(
+    ApiDownloadSubtitle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_download_task(
     ApiDownloadTask self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_download_variant(
+    ApiDownloadVariant self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_export_options(
+    ApiExportOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_export_progress(
     ApiExportProgress self,
@@ -751,6 +1265,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_missing_download(
+    ApiMissingDownload self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_my_list_info(
     ApiMyListInfo self,
@@ -763,6 +1283,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_next_episode(
+    ApiNextEpisode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_orphan_file(ApiOrphanFile self, SseSerializer serializer);
+
   @protected
   void sse_encode_api_play_history(
     ApiPlayHistory self,
@@ -781,6 +1310,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_quota_blocked_event(
+    ApiQuotaBlockedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_reconcile_report(
+    ApiReconcileReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_relocate_progress(
+    ApiRelocateProgress self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_search_filters(
     ApiSearchFilters self,
@@ -802,12 +1349,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_smart_folder_rule(
+    ApiSmartFolderRule self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_subscriptions_page(
     ApiSubscriptionsPage self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_subtitle_track(
+    ApiSubtitleTrack self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_sync_status(ApiSyncStatus self, SseSerializer serializer);
 
@@ -832,6 +1391,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_watch_next(ApiWatchNext self, SseSerializer serializer);
+
   @protected
   void sse_encode_api_web_dav_settings(
     ApiWebDavSettings self,
@@ -865,6 +1427,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_api_download_bandwidth_settings(
+    ApiDownloadBandwidthSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_download_query(
+    ApiDownloadQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_download_quota_policy(
+    ApiDownloadQuotaPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_download_rule(
+    ApiDownloadRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_export_options(
+    ApiExportOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_api_my_list_info(
     ApiMyListInfo self,
@@ -895,6 +1487,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_api_smart_folder_rule(
+    ApiSmartFolderRule self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_api_user_info(
     ApiUserInfo self,
@@ -907,6 +1505,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
@@ -916,6 +1517,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
@@ -946,24 +1550,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_byte_range(
+    List<ApiByteRange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_cleanup_item(
+    List<ApiCleanupItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_comment(
     List<ApiComment> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_continue_watching(
+    List<ApiContinueWatching> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_download_event(
+    List<ApiDownloadEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_download_folder(
     List<ApiDownloadFolder> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_download_rule(
+    List<ApiDownloadRule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_download_subtitle(
+    List<ApiDownloadSubtitle> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_download_task(
     List<ApiDownloadTask> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_download_variant(
+    List<ApiDownloadVariant> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_filter_option(
     List<ApiFilterOption> self,
@@ -976,12 +1622,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_missing_download(
+    List<ApiMissingDownload> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_my_list_item(
     List<ApiMyListItem> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_next_episode(
+    List<ApiNextEpisode> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_orphan_file(
+    List<ApiOrphanFile> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_play_history(
     List<ApiPlayHistory> self,
@@ -994,6 +1658,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_api_subtitle_track(
+    List<ApiSubtitleTrack> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_tag_group(
     List<ApiTagGroup> self,
@@ -1075,6 +1745,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
@@ -1084,6 +1757,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -1096,6 +1772,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
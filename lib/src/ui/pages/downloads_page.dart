@@ -223,7 +223,14 @@ class _DownloadsPageState extends State<DownloadsPage> {
     );
     if (result != null && result.isNotEmpty) {
       try {
-        await folder_api.createDownloadFolder(name: result);
+        // 在当前打开的普通文件夹下创建子文件夹；智能文件夹不能包含子文件夹
+        final current = _folders.value
+            .where((f) => f.id == _currentFolderId.value)
+            .firstOrNull;
+        await folder_api.createDownloadFolder(
+          name: result,
+          parentId: current != null && !current.isSmart ? current.id : null,
+        );
         await _loadFolders();
       } catch (e) {
         if (!mounted) return;
@@ -0,0 +1,9 @@
+-- Download retry bookkeeping
+-- error_kind: classified failure (network / source_expired / http_client / http_server / disk_full / challenge_required / unknown)
+-- retry_count / max_retries: automatic retry attempts for transient failures
+-- next_retry_at: scheduled time of the next automatic retry (NULL when none is pending)
+
+ALTER TABLE downloads ADD COLUMN error_kind TEXT;
+ALTER TABLE downloads ADD COLUMN retry_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE downloads ADD COLUMN max_retries INTEGER NOT NULL DEFAULT 3;
+ALTER TABLE downloads ADD COLUMN next_retry_at INTEGER;
@@ -0,0 +1,6 @@
+-- Media source actually used by a download.
+-- source_url: last resolved (signed) media URL; refreshed when the CDN reports it expired
+-- source_quality: quality picked from the watch page, reused on resume/refresh so the file stays the same
+
+ALTER TABLE downloads ADD COLUMN source_url TEXT;
+ALTER TABLE downloads ADD COLUMN source_quality TEXT;
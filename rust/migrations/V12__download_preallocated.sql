@@ -0,0 +1,4 @@
+-- Whether the target file was preallocated to its full size.
+-- When set, the on-disk length no longer reflects progress, so resume trusts downloaded_bytes.
+
+ALTER TABLE downloads ADD COLUMN preallocated INTEGER NOT NULL DEFAULT 0;
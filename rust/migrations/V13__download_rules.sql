@@ -0,0 +1,26 @@
+-- Subscription-driven auto-download rules.
+-- match_type: artist | tag | genre; match_value compared case-insensitively
+-- max_age_days / daily_cap: NULL means unlimited
+
+CREATE TABLE IF NOT EXISTS download_rules (
+  id TEXT PRIMARY KEY,
+  name TEXT NOT NULL,
+  match_type TEXT NOT NULL,
+  match_value TEXT NOT NULL,
+  quality TEXT NOT NULL,
+  folder_id TEXT,
+  max_age_days INTEGER,
+  daily_cap INTEGER,
+  enabled INTEGER NOT NULL DEFAULT 1,
+  created_at INTEGER NOT NULL,
+  last_checked_at INTEGER
+);
+
+-- Videos the rule checker has already evaluated; rule_id is set when a rule enqueued the video
+CREATE TABLE IF NOT EXISTS download_rule_seen (
+  video_id TEXT PRIMARY KEY,
+  rule_id TEXT,
+  seen_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_download_rule_seen_rule ON download_rule_seen(rule_id, seen_at);
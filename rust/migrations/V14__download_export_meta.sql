@@ -0,0 +1,7 @@
+-- Metadata captured from the watch page for media-server exports (NFO sidecars).
+-- upload_date: YYYY-MM-DD as shown on the watch page
+-- series_name / series_episode: playlist the video belongs to and its 1-based position
+
+ALTER TABLE downloads ADD COLUMN upload_date TEXT;
+ALTER TABLE downloads ADD COLUMN series_name TEXT;
+ALTER TABLE downloads ADD COLUMN series_episode INTEGER;
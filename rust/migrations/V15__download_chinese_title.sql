@@ -0,0 +1,3 @@
+-- Chinese title from the watch page (used by export filename templates)
+
+ALTER TABLE downloads ADD COLUMN chinese_title TEXT;
@@ -0,0 +1,12 @@
+-- Nested / smart download folders
+-- parent_id: NULL for top-level folders
+-- sort_order: manual ordering among siblings
+-- cover_video_id: download whose cover represents the folder
+-- rule_json: smart folder rule (DownloadFilter JSON); NULL for regular folders
+
+ALTER TABLE download_folders ADD COLUMN parent_id TEXT;
+ALTER TABLE download_folders ADD COLUMN sort_order INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE download_folders ADD COLUMN cover_video_id TEXT;
+ALTER TABLE download_folders ADD COLUMN rule_json TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_download_folders_parent ON download_folders(parent_id, sort_order);
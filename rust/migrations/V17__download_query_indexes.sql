@@ -0,0 +1,10 @@
+-- Indexes backing the paged downloads query (filters + sort keys)
+
+CREATE INDEX IF NOT EXISTS idx_downloads_created_at ON downloads(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_downloads_status_created_at ON downloads(status, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_downloads_folder_created_at ON downloads(folder_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_downloads_completed_at ON downloads(completed_at);
+CREATE INDEX IF NOT EXISTS idx_downloads_title ON downloads(title COLLATE NOCASE);
+CREATE INDEX IF NOT EXISTS idx_downloads_total_bytes ON downloads(total_bytes);
+CREATE INDEX IF NOT EXISTS idx_downloads_upload_date ON downloads(upload_date);
+CREATE INDEX IF NOT EXISTS idx_downloads_author_name ON downloads(author_name);
@@ -0,0 +1,25 @@
+-- Additional quality variants of a downloaded video.
+-- The primary copy stays in downloads; variants are either kept alongside it
+-- (replace_primary = 0) or swapped in when they complete (replace_primary = 1, quality upgrade).
+-- keep_previous: on swap, keep the replaced primary file as a variant instead of deleting it
+-- status / bytes / source_* follow the same meaning as in downloads
+
+CREATE TABLE IF NOT EXISTS download_variants (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  video_id TEXT NOT NULL,
+  quality TEXT NOT NULL,
+  source_quality TEXT,
+  source_url TEXT,
+  save_path TEXT,
+  total_bytes INTEGER DEFAULT 0,
+  downloaded_bytes INTEGER DEFAULT 0,
+  status INTEGER DEFAULT 0,
+  error_message TEXT,
+  replace_primary INTEGER NOT NULL DEFAULT 0,
+  keep_previous INTEGER NOT NULL DEFAULT 0,
+  created_at INTEGER NOT NULL,
+  completed_at INTEGER,
+  UNIQUE(video_id, quality)
+);
+
+CREATE INDEX IF NOT EXISTS idx_download_variants_status ON download_variants(status);
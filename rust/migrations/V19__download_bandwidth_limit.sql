@@ -0,0 +1,3 @@
+-- Per-download bandwidth cap in KB/s (NULL = use the default per-task limit, 0 = unlimited).
+
+ALTER TABLE downloads ADD COLUMN bandwidth_limit_kb INTEGER;
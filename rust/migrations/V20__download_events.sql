@@ -0,0 +1,21 @@
+-- Per-download event journal for troubleshooting: state transitions, picked source,
+-- ranged requests / HTTP statuses and errors. created_at is in unix milliseconds.
+
+CREATE TABLE IF NOT EXISTS download_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    video_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    status TEXT,
+    message TEXT,
+    source_host TEXT,
+    quality TEXT,
+    format TEXT,
+    range_start INTEGER,
+    range_end INTEGER,
+    http_status INTEGER,
+    error_kind TEXT,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_download_events_video ON download_events(video_id, id);
+CREATE INDEX IF NOT EXISTS idx_download_events_created ON download_events(created_at);
@@ -0,0 +1,17 @@
+-- Subtitle tracks downloaded alongside a video. file_path is a basename under
+-- <downloads root>/download_subtitles (NULL until the file has been fetched).
+-- WebVTT tracks are stored converted to SRT, so format reflects the stored file.
+
+CREATE TABLE IF NOT EXISTS download_subtitles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    video_id TEXT NOT NULL,
+    language TEXT NOT NULL DEFAULT '',
+    label TEXT NOT NULL DEFAULT '',
+    url TEXT NOT NULL,
+    format TEXT NOT NULL,
+    file_path TEXT,
+    created_at INTEGER NOT NULL,
+    UNIQUE(video_id, language, label)
+);
+
+CREATE INDEX IF NOT EXISTS idx_download_subtitles_video ON download_subtitles(video_id);
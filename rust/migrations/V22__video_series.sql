@@ -0,0 +1,17 @@
+-- Series membership remembered from parsed video details, so "next episode"
+-- suggestions work offline. One row per episode; position is the index in the
+-- series ordered by episode number.
+
+CREATE TABLE IF NOT EXISTS video_series (
+    series_id TEXT NOT NULL,
+    series_title TEXT NOT NULL,
+    video_id TEXT NOT NULL,
+    title TEXT NOT NULL DEFAULT '',
+    cover_url TEXT NOT NULL DEFAULT '',
+    episode INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    PRIMARY KEY (series_id, video_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_video_series_video ON video_series(video_id);
@@ -0,0 +1,12 @@
+-- Resume point of an HLS download. Segments are appended to the file in order,
+-- so after segments_done segments the file is exactly bytes_done long
+-- (including the fMP4 initialization segment). segment_count guards against
+-- resuming with a playlist that changed in the meantime.
+
+CREATE TABLE IF NOT EXISTS download_hls_progress (
+    video_id TEXT PRIMARY KEY,
+    segment_count INTEGER NOT NULL,
+    segments_done INTEGER NOT NULL,
+    bytes_done INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
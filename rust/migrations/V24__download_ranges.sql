@@ -0,0 +1,11 @@
+-- Byte ranges of an in-progress download written ahead of the sequential
+-- download position, because the player seeked there while the task was
+-- running. Ranges are half-open [range_start, range_end) and merged. Rows are
+-- dropped once the sequential download passes them or the download finishes.
+
+CREATE TABLE IF NOT EXISTS download_ranges (
+    video_id TEXT NOT NULL,
+    range_start INTEGER NOT NULL,
+    range_end INTEGER NOT NULL,
+    PRIMARY KEY (video_id, range_start)
+);
@@ -0,0 +1,11 @@
+-- Resume point of an HLS quality variant, same layout as download_hls_progress.
+-- video_id is kept so the rows can be removed together with the download.
+
+CREATE TABLE IF NOT EXISTS download_variant_hls_progress (
+    variant_id INTEGER PRIMARY KEY,
+    video_id TEXT NOT NULL,
+    segment_count INTEGER NOT NULL,
+    segments_done INTEGER NOT NULL,
+    bytes_done INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
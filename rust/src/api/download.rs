@@ -1,19 +1,31 @@
 // 下载管理 API
 
-use crate::api::models::{ApiDownloadStatus, ApiDownloadTask, ApiExportProgress};
-use crate::core::{network, parser, runtime, storage};
+use crate::api::download_folders::folder_scope;
+use crate::api::models::{
+    ApiByteRange, ApiDownloadPage, ApiDownloadQuery, ApiDownloadStatus, ApiDownloadStorageInfo,
+    ApiDownloadTask, ApiExportOptions, ApiExportProgress,
+};
+use crate::api::{
+    download_events, download_export, download_hls, download_quota, download_seek,
+    download_subtitles, download_variants, video, watch_next,
+};
+use crate::core::byte_ranges::RangeSet;
+use crate::core::media_verify::{self, MediaCheck};
+use crate::core::{library_use, network, parser, runtime, storage, throttle};
 use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::OnceLock;
+use std::time::Duration;
 use tokio::sync::broadcast;
-use tokio::sync::Semaphore;
 use tokio::sync::{watch, Mutex};
+use tokio::sync::{Semaphore, SemaphorePermit};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum DownloadControl {
+pub(crate) enum DownloadControl {
     Running,
     Paused,
     Canceled,
@@ -24,6 +36,53 @@ fn task_controls() -> &'static Mutex<HashMap<String, watch::Sender<DownloadContr
     MAP.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+/// 暂停调度新下载（迁移下载目录时使用）
+static DOWNLOADS_SUSPENDED: AtomicBool = AtomicBool::new(false);
+
+/// 暂停所有进行中的下载并阻止新任务启动，等待进行中的任务退出
+///
+/// 返回被暂停的任务 ID（用于之后恢复）；已在暂停中时返回错误
+pub(crate) async fn suspend_downloads() -> anyhow::Result<Vec<String>> {
+    if DOWNLOADS_SUSPENDED.swap(true, Ordering::SeqCst) {
+        anyhow::bail!("Downloads are already suspended");
+    }
+    let active = task_controls()
+        .lock()
+        .await
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    for video_id in &active {
+        pause_download(video_id.clone()).await?;
+    }
+    download_variants::stop_variant_tasks().await;
+    // 等待下载循环响应暂停并释放文件句柄
+    for _ in 0..150 {
+        if task_controls().lock().await.is_empty() && !download_variants::has_variant_tasks().await
+        {
+            return Ok(active);
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    DOWNLOADS_SUSPENDED.store(false, Ordering::SeqCst);
+    for video_id in &active {
+        let _ = resume_download(video_id.clone()).await;
+    }
+    let _ = download_variants::resume_variant_downloads().await;
+    anyhow::bail!("Timed out waiting for active downloads to stop")
+}
+
+/// 恢复调度：被暂停的任务重新排队，并启动所有排队中的任务
+pub(crate) async fn resume_suspended_downloads(paused: &[String]) {
+    DOWNLOADS_SUSPENDED.store(false, Ordering::SeqCst);
+    for video_id in paused {
+        let _ = storage::update_download_status(video_id, storage::DownloadStatus::Queued, None);
+        let _ = storage::reset_download_retry(video_id);
+    }
+    let _ = resume_queued_downloads().await;
+    let _ = download_variants::resume_variant_downloads().await;
+}
+
 fn download_semaphore() -> &'static Semaphore {
     static SEM: OnceLock<Semaphore> = OnceLock::new();
     SEM.get_or_init(|| {
@@ -32,6 +91,184 @@ fn download_semaphore() -> &'static Semaphore {
     })
 }
 
+/// 获取下载并发许可（主下载与画质变体共用）
+pub(crate) async fn acquire_download_permit() -> Option<SemaphorePermit<'static>> {
+    let permits_needed = if current_download_concurrency() <= 1 {
+        2
+    } else {
+        1
+    };
+    download_semaphore().acquire_many(permits_needed).await.ok()
+}
+
+/// 是否处于暂停调度状态（迁移下载目录中）
+pub(crate) fn downloads_suspended() -> bool {
+    DOWNLOADS_SUSPENDED.load(Ordering::SeqCst)
+}
+
+/// 新任务默认的最大自动重试次数
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// 自动重试退避：首次 30 秒，之后翻倍，最长 30 分钟
+const RETRY_BASE_DELAY_SECS: i64 = 30;
+const RETRY_MAX_DELAY_SECS: i64 = 30 * 60;
+/// 下载目录所在磁盘默认保留的空闲空间（MB）
+const DEFAULT_SPACE_RESERVE_MB: u64 = 500;
+
+/// 下载失败分类（持久化到 downloads.error_kind）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DownloadErrorKind {
+    /// 连接失败、超时、连接中断
+    Network,
+    /// 媒体签名链接过期（CDN 返回 403/410）
+    SourceExpired,
+    /// 其他 4xx
+    HttpClient,
+    /// 5xx
+    HttpServer,
+    /// 磁盘已满
+    DiskFull,
+    /// 需要 Cloudflare 验证（需用户在 WebView 中处理）
+    ChallengeRequired,
+    /// 下载完成但文件校验失败
+    Corrupt,
+    /// 开始/继续前预检发现剩余空间不足（扣除预留空间后）
+    InsufficientSpace,
+    /// 超出下载存储配额（自动清理后仍不足）
+    QuotaExceeded,
+    Unknown,
+}
+
+impl DownloadErrorKind {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DownloadErrorKind::Network => "network",
+            DownloadErrorKind::SourceExpired => "source_expired",
+            DownloadErrorKind::HttpClient => "http_client",
+            DownloadErrorKind::HttpServer => "http_server",
+            DownloadErrorKind::DiskFull => "disk_full",
+            DownloadErrorKind::ChallengeRequired => "challenge_required",
+            DownloadErrorKind::Corrupt => "corrupt",
+            DownloadErrorKind::InsufficientSpace => "insufficient_space",
+            DownloadErrorKind::QuotaExceeded => "quota_exceeded",
+            DownloadErrorKind::Unknown => "unknown",
+        }
+    }
+
+    /// 可自动重试的临时性错误
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            DownloadErrorKind::Network
+                | DownloadErrorKind::SourceExpired
+                | DownloadErrorKind::HttpServer
+        )
+    }
+
+    fn from_http_status(status: u16) -> Self {
+        match status {
+            403 | 410 => DownloadErrorKind::SourceExpired,
+            408 | 429 => DownloadErrorKind::Network,
+            400..=499 => DownloadErrorKind::HttpClient,
+            500..=599 => DownloadErrorKind::HttpServer,
+            _ => DownloadErrorKind::Unknown,
+        }
+    }
+
+    pub(crate) fn classify(error: &anyhow::Error) -> Self {
+        for cause in error.chain() {
+            if cause.is::<InsufficientSpaceError>() {
+                return DownloadErrorKind::InsufficientSpace;
+            }
+            if cause.is::<QuotaExceededError>() {
+                return DownloadErrorKind::QuotaExceeded;
+            }
+            if let Some(e) = cause.downcast_ref::<MediaHttpError>() {
+                return Self::from_http_status(e.status);
+            }
+            if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+                if let Some(status) = e.status() {
+                    return Self::from_http_status(status.as_u16());
+                }
+                if e.is_builder() {
+                    return DownloadErrorKind::Unknown;
+                }
+                return DownloadErrorKind::Network;
+            }
+            if let Some(e) = cause.downcast_ref::<std::io::Error>() {
+                match e.kind() {
+                    std::io::ErrorKind::StorageFull => return DownloadErrorKind::DiskFull,
+                    std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::UnexpectedEof
+                    | std::io::ErrorKind::BrokenPipe => return DownloadErrorKind::Network,
+                    _ => {}
+                }
+            }
+        }
+        if error.to_string().contains("CLOUDFLARE_CHALLENGE") {
+            DownloadErrorKind::ChallengeRequired
+        } else {
+            DownloadErrorKind::Unknown
+        }
+    }
+}
+
+/// 媒体地址返回的非成功 HTTP 状态
+#[derive(Debug, thiserror::Error)]
+#[error("Media request failed with HTTP {status}")]
+struct MediaHttpError {
+    status: u16,
+}
+
+/// 磁盘剩余空间不足以完成下载（含预留空间）
+#[derive(Debug, thiserror::Error)]
+#[error("Insufficient disk space: {required} bytes required, {available} bytes available")]
+struct InsufficientSpaceError {
+    required: u64,
+    available: u64,
+}
+
+/// 完成下载后将超出存储配额
+#[derive(Debug, thiserror::Error)]
+#[error("Download quota exceeded: {required} bytes required, quota is {quota} bytes")]
+pub(crate) struct QuotaExceededError {
+    pub required: u64,
+    pub quota: u64,
+}
+
+fn default_max_retries() -> u32 {
+    storage::get_setting("download_max_retries")
+        .ok()
+        .flatten()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(DEFAULT_MAX_RETRIES)
+}
+
+fn space_reserve_bytes() -> u64 {
+    storage::get_setting("download_space_reserve_mb")
+        .ok()
+        .flatten()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SPACE_RESERVE_MB)
+        .saturating_mul(1024 * 1024)
+}
+
+fn preallocate_enabled() -> bool {
+    storage::get_setting("download_preallocate")
+        .ok()
+        .flatten()
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false)
+}
+
+/// 单次下载过程中连接中断后的最大续连次数
+pub(crate) const MAX_STREAM_RECONNECTS: u32 = 3;
+
+fn retry_delay_secs(attempt: u32) -> i64 {
+    (RETRY_BASE_DELAY_SECS << attempt.min(10)).min(RETRY_MAX_DELAY_SECS)
+}
+
 fn current_download_concurrency() -> u32 {
     storage::get_setting("download_concurrent")
         .ok()
@@ -72,6 +309,7 @@ pub async fn add_download(
                     storage::DownloadStatus::Queued,
                     None,
                 )?;
+                storage::reset_download_retry(&record.video_id)?;
                 let hint = basename(&save_path)
                     .and_then(|name| resolve_download_path(&name).ok())
                     .unwrap_or_else(PathBuf::new);
@@ -81,6 +319,21 @@ pub async fn add_download(
         return Ok(task);
     }
 
+    enqueue_new_download(video_id, title, cover_url, quality, description, tags, None).await
+}
+
+/// 新建下载任务并排队（调用方需确认任务尚不存在），可直接归入下载文件夹
+pub(crate) async fn enqueue_new_download(
+    video_id: String,
+    title: String,
+    cover_url: String,
+    quality: String,
+    description: Option<String>,
+    tags: Vec<String>,
+    folder_id: Option<String>,
+) -> anyhow::Result<ApiDownloadTask> {
+    // 封面写入下载目录：迁移期间拒绝新建下载，迁移结束后再试
+    let _library = library_use::acquire()?;
     let watch_url = format!("{}/watch?v={}", network::base_url(), video_id);
     let cover_path = download_cover(&video_id, &cover_url).await.ok().flatten();
     storage::add_download(
@@ -98,6 +351,14 @@ pub async fn add_download(
         None,
     )?;
     storage::update_download_status(&video_id, storage::DownloadStatus::Queued, None)?;
+    storage::update_download_max_retries(&video_id, default_max_retries() as i32)?;
+    download_events::record(storage::DownloadEventRecord {
+        quality: Some(quality.clone()),
+        ..storage::DownloadEventRecord::new(&video_id, "created")
+    });
+    if let Some(folder_id) = folder_id.as_deref() {
+        storage::update_downloads_folder(std::slice::from_ref(&video_id), Some(folder_id))?;
+    }
 
     spawn_download(video_id.clone(), PathBuf::new());
     if let Ok(Some(record)) = storage::get_download_by_video_id(&video_id) {
@@ -111,7 +372,7 @@ pub async fn add_download(
         cover_url,
         cover_path: cover_path.as_deref().and_then(|p| {
             basename(p).and_then(|name| {
-                resolve_under_data_dir("download_covers", &name)
+                resolve_under_downloads_root("download_covers", &name)
                     .ok()
                     .map(|p| p.to_string_lossy().into_owned())
             })
@@ -130,7 +391,11 @@ pub async fn add_download(
         speed: 0,
         created_at: chrono::Utc::now().timestamp(),
         file_path: None,
-        folder_id: None,
+        folder_id,
+        error_kind: None,
+        retry_count: 0,
+        max_retries: default_max_retries(),
+        next_retry_at: None,
     })
 }
 
@@ -144,20 +409,76 @@ pub async fn get_all_downloads() -> anyhow::Result<Vec<ApiDownloadTask>> {
 /// 获取指定状态的下载任务
 #[frb]
 pub async fn get_downloads_by_status(status: String) -> anyhow::Result<Vec<ApiDownloadTask>> {
-    let records = storage::get_downloads()?;
-    let filtered = records
-        .into_iter()
-        .filter(|task| match (task.status, status.as_str()) {
-            (storage::DownloadStatus::Queued, "pending") => true,
-            (storage::DownloadStatus::Downloading, "downloading") => true,
-            (storage::DownloadStatus::Paused, "paused") => true,
-            (storage::DownloadStatus::Completed, "completed") => true,
-            (storage::DownloadStatus::Failed, "failed") => true,
-            _ => false,
-        })
-        .map(map_record)
-        .collect::<Vec<_>>();
-    Ok(filtered)
+    let Some(status) = parse_status(&status) else {
+        return Ok(vec![]);
+    };
+    let query = storage::DownloadQuery {
+        statuses: vec![status],
+        descending: true,
+        ..Default::default()
+    };
+    let (records, _) = storage::query_downloads(&query)?;
+    Ok(records.into_iter().map(map_record).collect())
+}
+
+/// 按条件分页查询下载任务
+#[frb]
+pub async fn query_downloads(query: ApiDownloadQuery) -> anyhow::Result<ApiDownloadPage> {
+    let statuses = query
+        .statuses
+        .iter()
+        .map(|s| parse_status(s).ok_or_else(|| anyhow::anyhow!("Unknown download status: {s}")))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let sort = match query.sort_by.as_deref().unwrap_or("created_at") {
+        "created_at" => storage::DownloadSort::CreatedAt,
+        "completed_at" => storage::DownloadSort::CompletedAt,
+        "title" => storage::DownloadSort::Title,
+        "size" => storage::DownloadSort::Size,
+        "upload_date" => storage::DownloadSort::UploadDate,
+        "author" => storage::DownloadSort::Author,
+        other => anyhow::bail!("Unknown sort key: {other}"),
+    };
+    let (folder_ids, rule) = match query.folder_id.as_deref().filter(|id| !id.is_empty()) {
+        Some(folder_id) => folder_scope(folder_id, query.include_subfolders)?,
+        None => (vec![], None),
+    };
+    let query = storage::DownloadQuery {
+        filter: storage::DownloadFilter {
+            authors: query.authors,
+            tags: query.tags,
+            downloaded_from: query.downloaded_from,
+            downloaded_to: query.downloaded_to,
+            ..Default::default()
+        },
+        rule,
+        statuses,
+        folder_ids,
+        text: query.text.map(|t| t.trim().to_string()),
+        min_bytes: query.min_bytes.map(|b| b as i64),
+        max_bytes: query.max_bytes.map(|b| b as i64),
+        sort,
+        descending: query.descending,
+        offset: query.offset,
+        limit: query.limit,
+    };
+    let (records, total) = storage::query_downloads(&query)?;
+    Ok(ApiDownloadPage {
+        items: records.into_iter().map(map_record).collect(),
+        total,
+    })
+}
+
+fn parse_status(status: &str) -> Option<storage::DownloadStatus> {
+    match status {
+        "pending" => Some(storage::DownloadStatus::Queued),
+        "downloading" => Some(storage::DownloadStatus::Downloading),
+        "paused" => Some(storage::DownloadStatus::Paused),
+        "completed" => Some(storage::DownloadStatus::Completed),
+        "failed" => Some(storage::DownloadStatus::Failed),
+        "corrupt" => Some(storage::DownloadStatus::Corrupt),
+        "missing" => Some(storage::DownloadStatus::Missing),
+        _ => None,
+    }
 }
 
 /// 暂停下载
@@ -174,6 +495,7 @@ pub async fn pause_download(task_id: String) -> anyhow::Result<bool> {
 #[frb]
 pub async fn resume_download(task_id: String) -> anyhow::Result<bool> {
     storage::update_download_status(&task_id, storage::DownloadStatus::Queued, None)?;
+    storage::reset_download_retry(&task_id)?;
     if let Ok(Some(record)) = storage::get_download_by_video_id(&task_id) {
         if let Some(save_path) = record.save_path.clone() {
             let hint = basename(&save_path)
@@ -187,226 +509,167 @@ pub async fn resume_download(task_id: String) -> anyhow::Result<bool> {
     Ok(true)
 }
 
+/// 重新校验下载文件（已完成、已标记损坏或文件缺失的任务）
+///
+/// - 校验通过：损坏 / 缺失任务恢复为已完成
+/// - 文件缺失、不完整或结构损坏：标记为 `Corrupt`
+#[frb]
+pub async fn verify_download(task_id: String) -> anyhow::Result<ApiDownloadTask> {
+    let record = storage::get_download_by_video_id(&task_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download task not found"))?;
+    if !matches!(
+        record.status,
+        storage::DownloadStatus::Completed
+            | storage::DownloadStatus::Corrupt
+            | storage::DownloadStatus::Missing
+    ) {
+        return Ok(map_record(record));
+    }
+
+    let path = download_path_hint(&record);
+    let check = if path.as_os_str().is_empty() || !path.exists() {
+        MediaCheck::Invalid("File not found".to_string())
+    } else {
+        verify_downloaded_file(path, record.total_bytes.max(0) as u64).await?
+    };
+    match check {
+        MediaCheck::Ok => {
+            if record.status != storage::DownloadStatus::Completed {
+                storage::update_download_status(
+                    &task_id,
+                    storage::DownloadStatus::Completed,
+                    None,
+                )?;
+                storage::reset_download_retry(&task_id)?;
+            }
+        }
+        MediaCheck::Truncated { actual, expected } => {
+            let reason = format!("Truncated: {} of {} bytes", actual, expected);
+            mark_download_corrupt(&task_id, &reason)?;
+        }
+        MediaCheck::Invalid(reason) => mark_download_corrupt(&task_id, &reason)?,
+    }
+
+    let record = storage::get_download_by_video_id(&task_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download task not found"))?;
+    let task = map_record(record);
+    let _ = progress_sender().send(task.clone());
+    Ok(task)
+}
+
+/// 修复损坏的下载
+///
+/// - 文件仅是不完整：保留已下载部分，Range 续传补齐
+/// - 结构损坏或文件缺失：删除文件后重新下载
+#[frb]
+pub async fn repair_download(task_id: String) -> anyhow::Result<bool> {
+    let Some(record) = storage::get_download_by_video_id(&task_id)? else {
+        return Ok(false);
+    };
+    if task_controls().lock().await.contains_key(&task_id) {
+        return Ok(false);
+    }
+
+    let path = download_path_hint(&record);
+    let expected = record.total_bytes.max(0) as u64;
+    let check = if !path.as_os_str().is_empty() && path.exists() {
+        verify_downloaded_file(path.clone(), expected).await.ok()
+    } else {
+        None
+    };
+    match check {
+        Some(MediaCheck::Truncated { actual, .. }) => {
+            tracing::info!(
+                "download repair_resume video_id={} from_bytes={}",
+                task_id,
+                actual
+            );
+            storage::update_download_progress(&task_id, actual as i64, expected as i64)?;
+        }
+        _ => {
+            tracing::info!("download repair_redownload video_id={}", task_id);
+            if !path.as_os_str().is_empty() {
+                let _ = std::fs::remove_file(&path);
+            }
+            storage::reset_download_progress(&task_id)?;
+        }
+    }
+
+    storage::update_download_status(&task_id, storage::DownloadStatus::Queued, None)?;
+    storage::reset_download_retry(&task_id)?;
+    spawn_download(task_id, path);
+    Ok(true)
+}
+
 /// 取消/删除下载
 #[frb]
 pub async fn delete_download(task_id: String, delete_file: bool) -> anyhow::Result<bool> {
-    if let Some(tx) = task_controls().lock().await.remove(&task_id) {
-        let _ = tx.send(DownloadControl::Canceled);
-    }
+    // 删除文件时登记使用下载目录，迁移期间拒绝
+    let _library = if delete_file {
+        Some(library_use::acquire()?)
+    } else {
+        None
+    };
+    stop_download_tasks(&task_id).await;
     if delete_file {
         if let Ok(Some(record)) = storage::get_download_by_video_id(&task_id) {
-            if let Some(name) = record.save_path.as_deref().and_then(basename) {
-                if let Ok(path) = resolve_download_path(&name) {
-                    let _ = std::fs::remove_file(path);
-                }
-            }
-            if let Some(name) = record.cover_path.as_deref().and_then(basename) {
-                if let Ok(path) = resolve_under_data_dir("download_covers", &name) {
-                    let _ = std::fs::remove_file(path);
-                }
-            }
-            if let Some(name) = record.author_avatar_path.as_deref().and_then(basename) {
-                if let Ok(path) = resolve_under_data_dir("download_avatars", &name) {
-                    let _ = std::fs::remove_file(path);
-                }
+            for path in download_files(&record) {
+                let _ = std::fs::remove_file(path);
             }
         }
+        download_subtitles::delete_subtitle_files(&task_id);
     }
     storage::delete_download(&task_id)?;
+    throttle::set_task_limit(&task_id, None);
     Ok(true)
 }
 
+/// 停止下载任务及其变体任务（不删除记录与文件）
+pub(crate) async fn stop_download_tasks(task_id: &str) {
+    if let Some(tx) = task_controls().lock().await.remove(task_id) {
+        let _ = tx.send(DownloadControl::Canceled);
+    }
+    download_variants::delete_variants_of(task_id, false).await;
+}
+
+/// 下载占用的本地文件：主文件、封面、头像、字幕与变体文件
+pub(crate) fn download_files(record: &storage::DownloadRecord) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    if let Some(name) = record.save_path.as_deref().and_then(basename) {
+        files.extend(resolve_download_path(&name).ok());
+    }
+    if let Some(name) = record.cover_path.as_deref().and_then(basename) {
+        files.extend(resolve_under_downloads_root("download_covers", &name).ok());
+    }
+    if let Some(name) = record.author_avatar_path.as_deref().and_then(basename) {
+        files.extend(resolve_under_downloads_root("download_avatars", &name).ok());
+    }
+    files.extend(
+        download_subtitles::local_subtitles(&record.video_id)
+            .into_iter()
+            .map(|(_, path)| path),
+    );
+    files.extend(download_variants::variant_files(&record.video_id));
+    files
+}
+
 /// 导出下载文件到指定目录（通过 StreamSink 发送进度）
 ///
 /// - 仅导出已下载完成且存在本地文件的任务
 /// - 文件名格式：`[{作者}]{标题}.{ext}`，非法字符替换为 `_`
+/// - 进度事件中的 `job_id` 可用于暂停 / 取消（见 download_export）
 #[frb]
 pub fn export_downloads_to_dir(
     task_ids: Vec<String>,
     dest_dir: String,
     sink: StreamSink<ApiExportProgress>,
 ) {
-    runtime::spawn(async move {
-        let total_files = task_ids.len() as u32;
-        let mut done_files: u32 = 0;
-
-        let dest_dir = PathBuf::from(dest_dir);
-        if let Err(e) = tokio::fs::create_dir_all(&dest_dir).await {
-            let _ = sink.add(ApiExportProgress {
-                total_files,
-                done_files,
-                current_file: None,
-                current_bytes: 0,
-                current_total_bytes: 0,
-                done: true,
-                error: Some(format!("Create dir failed: {e}")),
-            });
-            return;
-        }
-
-        for task_id in task_ids {
-            let record = match storage::get_download_by_video_id(&task_id) {
-                Ok(Some(r)) => r,
-                _ => {
-                    done_files += 1;
-                    let _ = sink.add(ApiExportProgress {
-                        total_files,
-                        done_files,
-                        current_file: Some(task_id),
-                        current_bytes: 0,
-                        current_total_bytes: 0,
-                        done: false,
-                        error: Some("Download record not found".to_string()),
-                    });
-                    continue;
-                }
-            };
-
-            if record.status != storage::DownloadStatus::Completed {
-                done_files += 1;
-                let _ = sink.add(ApiExportProgress {
-                    total_files,
-                    done_files,
-                    current_file: Some(record.title),
-                    current_bytes: 0,
-                    current_total_bytes: 0,
-                    done: false,
-                    error: Some("Not completed".to_string()),
-                });
-                continue;
-            }
-
-            let Some(src_name) = record.save_path.as_deref().and_then(basename) else {
-                done_files += 1;
-                let _ = sink.add(ApiExportProgress {
-                    total_files,
-                    done_files,
-                    current_file: Some(record.title),
-                    current_bytes: 0,
-                    current_total_bytes: 0,
-                    done: false,
-                    error: Some("Missing local file".to_string()),
-                });
-                continue;
-            };
-            let src_path = match resolve_download_path(&src_name) {
-                Ok(p) => p,
-                Err(e) => {
-                    done_files += 1;
-                    let _ = sink.add(ApiExportProgress {
-                        total_files,
-                        done_files,
-                        current_file: Some(record.title),
-                        current_bytes: 0,
-                        current_total_bytes: 0,
-                        done: false,
-                        error: Some(format!("Resolve path failed: {e}")),
-                    });
-                    continue;
-                }
-            };
-            let meta = match tokio::fs::metadata(&src_path).await {
-                Ok(m) => m,
-                Err(e) => {
-                    done_files += 1;
-                    let _ = sink.add(ApiExportProgress {
-                        total_files,
-                        done_files,
-                        current_file: Some(record.title),
-                        current_bytes: 0,
-                        current_total_bytes: 0,
-                        done: false,
-                        error: Some(format!("Stat failed: {e}")),
-                    });
-                    continue;
-                }
-            };
-            let total_bytes = meta.len();
-
-            let ext = src_path
-                .extension()
-                .and_then(|s| s.to_str())
-                .unwrap_or("mp4");
-
-            let author = record
-                .author_name
-                .clone()
-                .unwrap_or_else(|| "Unknown".to_string());
-            let filename = format!(
-                "[{}]{}.{}",
-                sanitize_filename(&author),
-                sanitize_filename(&record.title),
-                sanitize_filename(ext),
-            );
-            let mut dst_path = dest_dir.join(filename);
-            dst_path = uniquify_path(dst_path);
-
-            let display_name = dst_path
-                .file_name()
-                .and_then(|s| s.to_str())
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| record.title.clone());
-
-            let _ = sink.add(ApiExportProgress {
-                total_files,
-                done_files,
-                current_file: Some(display_name.clone()),
-                current_bytes: 0,
-                current_total_bytes: total_bytes,
-                done: false,
-                error: None,
-            });
-
-            match copy_file_with_progress(&src_path, &dst_path, total_bytes, |copied| {
-                let _ = sink.add(ApiExportProgress {
-                    total_files,
-                    done_files,
-                    current_file: Some(display_name.clone()),
-                    current_bytes: copied,
-                    current_total_bytes: total_bytes,
-                    done: false,
-                    error: None,
-                });
-            })
-            .await
-            {
-                Ok(_) => {
-                    done_files += 1;
-                    let _ = sink.add(ApiExportProgress {
-                        total_files,
-                        done_files,
-                        current_file: Some(display_name),
-                        current_bytes: total_bytes,
-                        current_total_bytes: total_bytes,
-                        done: false,
-                        error: None,
-                    });
-                }
-                Err(e) => {
-                    done_files += 1;
-                    let _ = sink.add(ApiExportProgress {
-                        total_files,
-                        done_files,
-                        current_file: Some(display_name),
-                        current_bytes: 0,
-                        current_total_bytes: total_bytes,
-                        done: false,
-                        error: Some(e.to_string()),
-                    });
-                }
-            }
-        }
-
-        let _ = sink.add(ApiExportProgress {
-            total_files,
-            done_files,
-            current_file: None,
-            current_bytes: 0,
-            current_total_bytes: 0,
-            done: true,
-            error: None,
-        });
-    });
+    download_export::run_export(
+        task_ids,
+        PathBuf::from(dest_dir),
+        ApiExportOptions::default(),
+        sink,
+    );
 }
 
 /// 批量暂停下载
@@ -442,6 +705,7 @@ pub async fn resume_all_downloads() -> anyhow::Result<bool> {
                 storage::DownloadStatus::Queued,
                 None,
             )?;
+            storage::reset_download_retry(&record.video_id)?;
             if let Some(save_path) = record.save_path.clone() {
                 spawn_download(record.video_id, PathBuf::from(save_path));
             } else {
@@ -452,6 +716,48 @@ pub async fn resume_all_downloads() -> anyhow::Result<bool> {
     Ok(true)
 }
 
+/// 设置任务的最大自动重试次数（0 表示失败后不自动重试）
+#[frb]
+pub async fn set_download_max_retries(task_id: String, max_retries: u32) -> anyhow::Result<bool> {
+    storage::update_download_max_retries(&task_id, max_retries.min(i32::MAX as u32) as i32)?;
+    Ok(true)
+}
+
+/// 获取下载目录空间使用情况（已占用 / 待下载 / 磁盘剩余）
+#[frb]
+pub async fn get_download_storage_info() -> anyhow::Result<ApiDownloadStorageInfo> {
+    let dir = storage::get_downloads_root()?.join("downloads");
+    std::fs::create_dir_all(&dir)?;
+    let pending_bytes = storage::get_downloads()?
+        .into_iter()
+        .filter(|r| {
+            matches!(
+                r.status,
+                storage::DownloadStatus::Queued
+                    | storage::DownloadStatus::Downloading
+                    | storage::DownloadStatus::Paused
+            ) && !r.preallocated
+        })
+        .map(|r| (r.total_bytes - r.downloaded_bytes).max(0) as u64)
+        .sum();
+    let (free_bytes, disk_total_bytes) = match fs4::statvfs(&dir) {
+        Ok(stats) => (stats.available_space(), stats.total_space()),
+        Err(_) => (0, 0),
+    };
+    let used_dir = dir.clone();
+    let used_bytes =
+        tokio::task::spawn_blocking(move || crate::api::settings::dir_size(used_dir)).await?;
+    Ok(ApiDownloadStorageInfo {
+        download_dir: dir.to_string_lossy().into_owned(),
+        used_bytes,
+        pending_bytes,
+        free_bytes,
+        disk_total_bytes,
+        reserve_bytes: space_reserve_bytes(),
+        preallocate: preallocate_enabled(),
+    })
+}
+
 /// 监听下载进度更新
 #[frb]
 pub fn subscribe_download_progress(sink: StreamSink<ApiDownloadTask>) {
@@ -492,6 +798,50 @@ pub async fn get_local_video_path(video_id: String) -> anyhow::Result<Option<Str
     }))
 }
 
+/// 获取下载任务已写入磁盘的字节区间（`end` 不包含），用于边下边播时显示缓冲进度
+///
+/// 顺序下载的开头部分之外，还包括播放器拖动后提前下载的区间
+#[frb]
+pub async fn get_download_available_ranges(task_id: String) -> anyhow::Result<Vec<ApiByteRange>> {
+    let Some(record) = storage::get_download_by_video_id(&task_id)? else {
+        return Ok(vec![]);
+    };
+    let Ok(meta) = download_path_hint(&record).metadata() else {
+        return Ok(vec![]);
+    };
+    Ok(available_ranges(&record, meta.len())?
+        .ranges()
+        .iter()
+        .map(|&(start, end)| ApiByteRange { start, end })
+        .collect())
+}
+
+/// 文件中已写入的全部区间：从开头起顺序下载的部分 + 拖动预取的区间
+pub(crate) fn available_ranges(
+    record: &storage::DownloadRecord,
+    file_len: u64,
+) -> anyhow::Result<RangeSet> {
+    let prefix = on_disk_bytes(record, file_len);
+    let mut ranges = if record.status == storage::DownloadStatus::Completed {
+        RangeSet::default()
+    } else {
+        download_seek::written_ranges(&record.video_id)?
+    };
+    ranges.insert(0, prefix);
+    Ok(ranges)
+}
+
+/// 文件中从开头起已下载完成的字节数（预分配的文件长度恒为总大小，以下载进度为准）
+pub(crate) fn on_disk_bytes(record: &storage::DownloadRecord, file_len: u64) -> u64 {
+    if record.status == storage::DownloadStatus::Completed {
+        file_len
+    } else if record.preallocated {
+        (record.downloaded_bytes.max(0) as u64).min(file_len)
+    } else {
+        file_len
+    }
+}
+
 pub(crate) async fn resume_queued_downloads() -> anyhow::Result<()> {
     let records = storage::get_downloads()?;
     for record in records {
@@ -499,6 +849,15 @@ pub(crate) async fn resume_queued_downloads() -> anyhow::Result<()> {
             continue;
         }
 
+        // 等待自动重试的任务按保存的重试时间继续退避
+        if let Some(next_retry_at) = record.next_retry_at {
+            let delay = next_retry_at - chrono::Utc::now().timestamp();
+            if delay > 0 {
+                schedule_retry(record.video_id, next_retry_at, delay);
+                continue;
+            }
+        }
+
         if let Some(name) = record.save_path.as_deref().and_then(basename) {
             if let Ok(path) = resolve_download_path(&name) {
                 spawn_download(record.video_id, path);
@@ -512,7 +871,7 @@ pub(crate) async fn resume_queued_downloads() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn map_record(record: storage::DownloadRecord) -> ApiDownloadTask {
+pub(crate) fn map_record(record: storage::DownloadRecord) -> ApiDownloadTask {
     let status = match record.status {
         storage::DownloadStatus::Queued => ApiDownloadStatus::Pending,
         storage::DownloadStatus::Downloading => ApiDownloadStatus::Downloading,
@@ -521,6 +880,10 @@ fn map_record(record: storage::DownloadRecord) -> ApiDownloadTask {
         storage::DownloadStatus::Failed => ApiDownloadStatus::Failed {
             error: record.error_message.unwrap_or_default(),
         },
+        storage::DownloadStatus::Corrupt => ApiDownloadStatus::Corrupt {
+            reason: record.error_message.unwrap_or_default(),
+        },
+        storage::DownloadStatus::Missing => ApiDownloadStatus::Missing,
     };
 
     let progress = if record.total_bytes > 0 {
@@ -537,13 +900,13 @@ fn map_record(record: storage::DownloadRecord) -> ApiDownloadTask {
     });
     let cover_path = record.cover_path.as_deref().and_then(|p| {
         let name = basename(p)?;
-        resolve_under_data_dir("download_covers", &name)
+        resolve_under_downloads_root("download_covers", &name)
             .ok()
             .map(|p| p.to_string_lossy().into_owned())
     });
     let author_avatar_path = record.author_avatar_path.as_deref().and_then(|p| {
         let name = basename(p)?;
-        resolve_under_data_dir("download_avatars", &name)
+        resolve_under_downloads_root("download_avatars", &name)
             .ok()
             .map(|p| p.to_string_lossy().into_owned())
     });
@@ -569,30 +932,46 @@ fn map_record(record: storage::DownloadRecord) -> ApiDownloadTask {
         created_at: record.created_at,
         file_path,
         folder_id: record.folder_id,
+        error_kind: record.error_kind,
+        retry_count: record.retry_count.max(0) as u32,
+        max_retries: record.max_retries.max(0) as u32,
+        next_retry_at: record.next_retry_at,
     }
 }
 
-fn build_download_filename(video_id: &str, quality: &str, ext: &str) -> String {
+pub(crate) fn build_download_filename(video_id: &str, quality: &str, ext: &str) -> String {
     format!("{}_{}.{}", video_id, quality.replace(' ', ""), ext)
 }
 
-fn resolve_download_path(file_name: &str) -> anyhow::Result<PathBuf> {
-    let mut base = storage::get_data_dir()?;
+pub(crate) fn resolve_download_path(file_name: &str) -> anyhow::Result<PathBuf> {
+    let mut base = storage::get_downloads_root()?;
     base.push("downloads");
     std::fs::create_dir_all(&base)?;
     base.push(file_name);
     Ok(base)
 }
 
-fn resolve_under_data_dir(subdir: &str, file_name: &str) -> anyhow::Result<PathBuf> {
-    let mut base = storage::get_data_dir()?;
+pub(crate) fn resolve_under_downloads_root(
+    subdir: &str,
+    file_name: &str,
+) -> anyhow::Result<PathBuf> {
+    let mut base = storage::get_downloads_root()?;
     base.push(subdir);
     std::fs::create_dir_all(&base)?;
     base.push(file_name);
     Ok(base)
 }
 
-fn basename(path: &str) -> Option<String> {
+pub(crate) fn download_path_hint(record: &storage::DownloadRecord) -> PathBuf {
+    record
+        .save_path
+        .as_deref()
+        .and_then(basename)
+        .and_then(|name| resolve_download_path(&name).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn basename(path: &str) -> Option<String> {
     Path::new(path)
         .file_name()
         .and_then(|s| s.to_str())
@@ -607,7 +986,7 @@ async fn download_author_avatar(
     if avatar_url.trim().is_empty() || author_id.trim().is_empty() || video_id.trim().is_empty() {
         return Ok(None);
     }
-    let mut dir = storage::get_data_dir()?;
+    let mut dir = storage::get_downloads_root()?;
     dir.push("download_avatars");
     std::fs::create_dir_all(&dir)?;
 
@@ -641,7 +1020,7 @@ async fn download_cover(video_id: &str, cover_url: &str) -> anyhow::Result<Optio
     if cover_url.trim().is_empty() {
         return Ok(None);
     }
-    let mut dir = storage::get_data_dir()?;
+    let mut dir = storage::get_downloads_root()?;
     dir.push("download_covers");
     std::fs::create_dir_all(&dir)?;
 
@@ -673,18 +1052,13 @@ async fn download_cover(video_id: &str, cover_url: &str) -> anyhow::Result<Optio
 fn spawn_download(video_id: String, save_path_hint: PathBuf) {
     runtime::spawn(async move {
         // 全局并发控制（最多同时下载不同视频）
-        let permits_needed = if current_download_concurrency() <= 1 {
-            2
-        } else {
-            1
-        };
-        let permit = match download_semaphore().acquire_many(permits_needed).await {
-            Ok(p) => p,
-            Err(_) => return,
+        let Some(permit) = acquire_download_permit().await else {
+            return;
         };
 
         let mut map = task_controls().lock().await;
-        if map.contains_key(&video_id) {
+        // 下载目录迁移期间不启动新任务（保持排队，迁移结束后统一恢复）
+        if map.contains_key(&video_id) || DOWNLOADS_SUSPENDED.load(Ordering::SeqCst) {
             drop(permit);
             return;
         }
@@ -696,21 +1070,83 @@ fn spawn_download(video_id: String, save_path_hint: PathBuf) {
         let _ = task_controls().lock().await.remove(&video_id);
         drop(permit);
 
+        if let Err(e) = result {
+            handle_download_failure(&video_id, &e);
+        }
         if let Ok(Some(record)) = storage::get_download_by_video_id(&video_id) {
             let task = map_record(record);
             let _ = progress_sender().send(task);
         }
-        if let Err(e) = result {
-            let _ = storage::update_download_status(
-                &video_id,
-                storage::DownloadStatus::Failed,
-                Some(&e.to_string()),
-            );
-            if let Ok(Some(record)) = storage::get_download_by_video_id(&video_id) {
-                let task = map_record(record);
-                let _ = progress_sender().send(task);
+    });
+}
+
+/// 任务失败处理：临时性错误按退避策略自动重试，其余直接标记失败
+fn handle_download_failure(video_id: &str, error: &anyhow::Error) {
+    let kind = DownloadErrorKind::classify(error);
+    let message = error.to_string();
+    tracing::warn!(
+        "download failed video_id={} kind={} error={}",
+        video_id,
+        kind.as_str(),
+        message
+    );
+    download_events::record(storage::DownloadEventRecord {
+        message: Some(message.clone()),
+        error_kind: Some(kind.as_str().to_string()),
+        ..storage::DownloadEventRecord::new(video_id, "error")
+    });
+
+    if let Ok(Some(record)) = storage::get_download_by_video_id(video_id) {
+        let attempt = record.retry_count.max(0);
+        if kind.is_transient() && attempt < record.max_retries {
+            let delay = retry_delay_secs(attempt as u32);
+            let next_retry_at = chrono::Utc::now().timestamp() + delay;
+            if storage::schedule_download_retry(
+                video_id,
+                attempt + 1,
+                kind.as_str(),
+                &message,
+                next_retry_at,
+            )
+            .is_ok()
+            {
+                tracing::info!(
+                    "download retry_scheduled video_id={} attempt={} delay_secs={}",
+                    video_id,
+                    attempt + 1,
+                    delay
+                );
+                download_events::record(storage::DownloadEventRecord {
+                    status: Some(storage::DownloadStatus::Queued.as_str().to_string()),
+                    message: Some(format!("Attempt {} in {}s", attempt + 1, delay)),
+                    error_kind: Some(kind.as_str().to_string()),
+                    ..storage::DownloadEventRecord::new(video_id, "retry_scheduled")
+                });
+                schedule_retry(video_id.to_string(), next_retry_at, delay);
+                return;
             }
         }
+    }
+
+    let _ =
+        storage::update_download_status(video_id, storage::DownloadStatus::Failed, Some(&message));
+    let _ = storage::update_download_error_kind(video_id, Some(kind.as_str()));
+}
+
+fn schedule_retry(video_id: String, next_retry_at: i64, delay_secs: i64) {
+    runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(delay_secs.max(0) as u64)).await;
+        let Ok(Some(record)) = storage::get_download_by_video_id(&video_id) else {
+            return;
+        };
+        // 等待期间被暂停、删除或手动继续时，放弃本次重试
+        if record.status != storage::DownloadStatus::Queued
+            || record.next_retry_at != Some(next_retry_at)
+        {
+            return;
+        }
+        let hint = download_path_hint(&record);
+        spawn_download(record.video_id, hint);
     });
 }
 
@@ -756,6 +1192,24 @@ async fn run_download(
     if record.tags.is_empty() && !detail.tags.is_empty() {
         let _ = storage::update_download_description_and_tags(&video_id, None, Some(&detail.tags));
     }
+    if let Some(series) = detail.series.as_ref() {
+        watch_next::remember_series(series);
+    }
+    let series = detail.series.as_ref().map(|series| {
+        let episode = &series.episodes[series.current_index];
+        (series.title.clone(), episode.number as i32)
+    });
+    let _ = storage::update_download_export_meta(
+        &video_id,
+        Some(detail.upload_date.trim()).filter(|d| !d.is_empty()),
+        series.as_ref().map(|(name, _)| name.as_str()),
+        series.as_ref().map(|(_, episode)| *episode),
+        detail
+            .chinese_title
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty()),
+    );
 
     // 尝试补齐作者信息（用于离线展示）
     if let Some(creator) = detail.creator.clone() {
@@ -783,37 +1237,63 @@ async fn run_download(
         }
     }
 
-    let q_norm = quality.to_ascii_lowercase();
-    let pick = detail
-        .video_sources
+    // 字幕随视频一起下载（已下载的跳过）
+    if !detail.subtitles.is_empty() {
+        download_subtitles::fetch_subtitles(&video_id, &detail.subtitles).await;
+    }
+
+    // 续传时优先沿用上次实际选中的清晰度，避免回退到 auto 后拼接出不同的文件
+    let mut pick = None;
+    for wanted in record
+        .source_quality
         .iter()
-        .find(|s| s.quality.to_ascii_lowercase() == q_norm)
-        .or_else(|| {
-            detail
-                .video_sources
-                .iter()
-                .find(|s| s.quality.to_ascii_lowercase() == "auto")
-        })
-        .or_else(|| detail.video_sources.first())
+        .chain(std::iter::once(&quality))
+    {
+        pick = find_source_or_variant(&detail.video_sources, wanted).await;
+        if pick.is_some() {
+            break;
+        }
+    }
+    let pick = pick
+        .or_else(|| find_source(&detail.video_sources, "auto").cloned())
+        .or_else(|| detail.video_sources.first().cloned())
         .ok_or_else(|| anyhow::anyhow!("No playable source"))?;
-    let url = pick.url.clone();
+    let mut source = MediaSource {
+        url: pick.url.clone(),
+        quality: pick.quality.clone(),
+    };
     let format = pick.format.clone();
+    storage::update_download_source(&video_id, &source.url, &source.quality)?;
+    download_events::record(storage::DownloadEventRecord {
+        message: Some(format!("Requested quality {}", quality)),
+        source_host: download_events::source_host(&source.url),
+        quality: Some(source.quality.clone()),
+        format: Some(format.clone()),
+        ..storage::DownloadEventRecord::new(&video_id, "source_picked")
+    });
     tracing::info!(
         "download picked_source video_id={} quality_req={} quality_pick={} format={} url_len={}",
         video_id,
         quality,
-        pick.quality,
+        source.quality,
         format,
-        url.len()
+        source.url.len()
     );
-    let ext = if format.to_ascii_lowercase().contains("m3u8") || url.contains(".m3u8") {
-        "m3u8"
-    } else {
-        "mp4"
-    };
+    // HLS 播放源按分片下载
+    if download_hls::is_hls_source(&format, &source.url) {
+        return download_hls::run_hls_download(
+            download_hls::HlsTarget::Download(&video_id),
+            &source.url,
+            &quality,
+            record.bandwidth_limit_kb,
+            save_path_hint,
+            &mut ctrl_rx,
+        )
+        .await;
+    }
 
     let save_path = if save_path_hint.as_os_str().is_empty() {
-        let file_name = build_download_filename(&video_id, &quality, ext);
+        let file_name = build_download_filename(&video_id, &quality, "mp4");
         storage::update_download_save_path(&video_id, &file_name)?;
         resolve_download_path(&file_name)?
     } else if save_path_hint.is_absolute() {
@@ -825,58 +1305,66 @@ async fn run_download(
 
     storage::update_download_status(&video_id, storage::DownloadStatus::Downloading, None)?;
 
-    let mut downloaded: u64 = if save_path.exists() {
+    let file_len = if save_path.exists() {
         std::fs::metadata(&save_path).map(|m| m.len()).unwrap_or(0)
     } else {
         0
     };
-
-    // 如果数据库里记录的进度比文件大，信任文件
     let db_downloaded = record.downloaded_bytes.max(0) as u64;
-    if db_downloaded > downloaded {
-        downloaded = db_downloaded;
-    }
+    let mut downloaded = if record.preallocated {
+        // 预分配的文件长度恒为总大小，只能以数据库进度为准
+        db_downloaded.min(file_len)
+    } else {
+        // 如果数据库里记录的进度比文件大，信任文件
+        file_len.max(db_downloaded)
+    };
 
-    let client = network::get_client();
-    let mut req = client.get(&url);
     if downloaded > 0 {
         tracing::info!(
             "download resume_range video_id={} from_bytes={}",
             video_id,
             downloaded
         );
-        req = req.header(reqwest::header::RANGE, format!("bytes={}-", downloaded));
     }
-    let resp = req.send().await?;
-
-    // range 可能不被支持，回退为重新下载
-    let status = resp.status();
-    if downloaded > 0 && status != reqwest::StatusCode::PARTIAL_CONTENT {
-        downloaded = 0;
+    let expected_total = record.total_bytes.max(0) as u64;
+    let (resp, start, total) =
+        open_media_stream(&video_id, &mut source, downloaded, expected_total).await?;
+    // range 不被支持或文件已变化时，回退为重新下载
+    if start != downloaded {
+        downloaded = start;
         let _ = tokio::fs::remove_file(&save_path).await;
     }
+    if downloaded == 0 {
+        download_seek::clear(&video_id)?;
+    }
 
-    let total_size = resp.content_length().unwrap_or(0);
-    let total = if downloaded > 0 {
-        downloaded + total_size
-    } else {
-        total_size
-    };
+    // 预分配的文件续传时空间已占用，无需再次预检
+    if !(record.preallocated && downloaded > 0) {
+        ensure_free_space(&save_path, total.saturating_sub(downloaded), &video_id)?;
+        download_quota::ensure_quota(&video_id, total.saturating_sub(downloaded)).await?;
+    }
 
     use tokio::io::AsyncWriteExt;
-    let mut file = if downloaded > 0 {
-        tokio::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&save_path)
-            .await?
-    } else {
-        tokio::fs::File::create(&save_path).await?
-    };
+    let mut file = open_download_file(
+        &video_id,
+        &save_path,
+        downloaded,
+        total,
+        record.preallocated,
+    )
+    .await?;
+    // 播放器拖动到尚未下载的位置时从该位置预取（见 download_seek）
+    let _seek = download_seek::register(&video_id, &save_path, downloaded, total)?;
 
     let mut stream = resp.bytes_stream();
+    let mut reconnects: u32 = 0;
     use futures_util::StreamExt;
 
+    // 限速：读取一块数据后按令牌桶计算等待时长，等待期间仍响应暂停/取消
+    let mut throttle = throttle::TaskThrottle::new(&video_id, record.bandwidth_limit_kb);
+    let throttle_wait = tokio::time::sleep(Duration::ZERO);
+    tokio::pin!(throttle_wait);
+
     loop {
         tokio::select! {
             changed = ctrl_rx.changed() => {
@@ -892,18 +1380,70 @@ async fn run_download(
                     DownloadControl::Running => {}
                 }
             }
-            chunk = stream.next() => {
+            _ = &mut throttle_wait, if !throttle_wait.is_elapsed() => {}
+            chunk = stream.next(), if throttle_wait.is_elapsed() => {
                 match chunk {
                     Some(Ok(bytes)) => {
                         file.write_all(&bytes).await?;
                         downloaded += bytes.len() as u64;
+                        reconnects = 0;
+                        let wait = throttle.reserve(bytes.len());
+                        if !wait.is_zero() {
+                            throttle_wait.as_mut().reset(tokio::time::Instant::now() + wait);
+                        }
                         let _ = storage::update_download_progress(&video_id, downloaded as i64, total as i64);
                         if let Ok(Some(record)) = storage::get_download_by_video_id(&video_id) {
                             let task = map_record(record);
                             let _ = progress_sender().send(task);
                         }
+                        // 后面的部分已由拖动预取写入：跳到该区间末尾继续
+                        if let Some(end) = download_seek::skip_covered(&video_id, downloaded)? {
+                            downloaded = end;
+                            let _ = storage::update_download_progress(&video_id, downloaded as i64, total as i64);
+                            if downloaded >= total {
+                                break;
+                            }
+                            file.flush().await?;
+                            let (resp, start, _) =
+                                open_media_stream(&video_id, &mut source, downloaded, total).await?;
+                            if start != downloaded {
+                                downloaded = start;
+                                download_seek::clear(&video_id)?;
+                                file = open_download_file(&video_id, &save_path, 0, total, false).await?;
+                            } else {
+                                file = open_download_file(&video_id, &save_path, downloaded, total, true).await?;
+                            }
+                            stream = resp.bytes_stream();
+                        }
+                    }
+                    Some(Err(e)) => {
+                        // 连接中断：从当前位置重新发起 Range 请求（期间签名过期会自动刷新地址）
+                        if reconnects >= MAX_STREAM_RECONNECTS {
+                            return Err(e.into());
+                        }
+                        reconnects += 1;
+                        download_events::record(storage::DownloadEventRecord {
+                            message: Some(e.to_string()),
+                            range_start: Some(downloaded as i64),
+                            ..storage::DownloadEventRecord::new(&video_id, "stream_interrupted")
+                        });
+                        tracing::warn!(
+                            "download stream_interrupted video_id={} at_bytes={} attempt={} error={}",
+                            video_id,
+                            downloaded,
+                            reconnects,
+                            e
+                        );
+                        file.flush().await?;
+                        let (resp, start, _) =
+                            open_media_stream(&video_id, &mut source, downloaded, total).await?;
+                        if start != downloaded {
+                            downloaded = start;
+                            download_seek::clear(&video_id)?;
+                            file = open_download_file(&video_id, &save_path, 0, total, false).await?;
+                        }
+                        stream = resp.bytes_stream();
                     }
-                    Some(Err(e)) => return Err(e.into()),
                     None => break,
                 }
             }
@@ -911,89 +1451,404 @@ async fn run_download(
     }
 
     file.flush().await?;
+    drop(file);
+
+    // 连接提前关闭：按网络错误处理，重试时从当前位置续传
+    if total > 0 && downloaded < total {
+        return Err(stream_ended_early(downloaded, total).into());
+    }
+
+    match verify_downloaded_file(save_path, total).await? {
+        MediaCheck::Ok => {}
+        MediaCheck::Truncated { actual, expected } => {
+            let _ = storage::update_download_progress(&video_id, actual as i64, expected as i64);
+            return Err(stream_ended_early(actual, expected).into());
+        }
+        MediaCheck::Invalid(reason) => {
+            tracing::warn!(
+                "download verify_failed video_id={} reason={}",
+                video_id,
+                reason
+            );
+            mark_download_corrupt(&video_id, &reason)?;
+            return Ok(());
+        }
+    }
+
+    download_seek::clear(&video_id)?;
     storage::update_download_status(&video_id, storage::DownloadStatus::Completed, None)?;
+    storage::reset_download_retry(&video_id)?;
     Ok(())
 }
 
-fn progress_sender() -> &'static broadcast::Sender<ApiDownloadTask> {
-    static CHANNEL: OnceLock<broadcast::Sender<ApiDownloadTask>> = OnceLock::new();
-    CHANNEL.get_or_init(|| {
-        let (tx, _) = broadcast::channel(100);
-        tx
-    })
+/// 检查下载目录所在磁盘能否容纳剩余数据
+///
+/// 需要的空间 = 本任务剩余字节 + 其他进行中任务（未预分配）的剩余字节 + 预留空间
+pub(crate) fn ensure_free_space(
+    save_path: &Path,
+    remaining: u64,
+    video_id: &str,
+) -> anyhow::Result<()> {
+    if remaining == 0 {
+        return Ok(());
+    }
+    let dir = save_path.parent().unwrap_or(save_path);
+    let available = match fs4::available_space(dir) {
+        Ok(v) => v,
+        Err(e) => {
+            // 部分平台/文件系统无法获取可用空间，跳过预检
+            tracing::warn!("download space_check_unavailable error={}", e);
+            return Ok(());
+        }
+    };
+    let others = storage::get_downloads()?
+        .into_iter()
+        .filter(|r| {
+            r.video_id != video_id
+                && r.status == storage::DownloadStatus::Downloading
+                && !r.preallocated
+        })
+        .map(|r| (r.total_bytes - r.downloaded_bytes).max(0) as u64)
+        .sum::<u64>();
+    let required = remaining
+        .saturating_add(others)
+        .saturating_add(space_reserve_bytes());
+    if available < required {
+        return Err(InsufficientSpaceError {
+            required,
+            available,
+        }
+        .into());
+    }
+    Ok(())
 }
 
-fn sanitize_filename(input: &str) -> String {
-    // Windows + common filesystem forbidden: <>:"/\|?* and control chars
-    let mut out = String::with_capacity(input.len());
-    for ch in input.chars() {
-        let invalid =
-            matches!(ch, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') || ch.is_control();
-        if invalid {
-            out.push('_');
-        } else {
-            out.push(ch);
+/// 打开目标文件并定位到写入位置
+///
+/// 从头下载时重建文件；开启预分配且已知总大小时一次性占满磁盘空间，
+/// 之后按偏移写入，续传以数据库记录的进度为准。
+async fn open_download_file(
+    video_id: &str,
+    save_path: &Path,
+    offset: u64,
+    total: u64,
+    preallocated: bool,
+) -> anyhow::Result<tokio::fs::File> {
+    use tokio::io::AsyncSeekExt;
+
+    if offset > 0 {
+        if preallocated {
+            let mut file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .open(save_path)
+                .await?;
+            file.seek(std::io::SeekFrom::Start(offset)).await?;
+            return Ok(file);
         }
+        // 不用追加模式：拖动预取可能已把文件扩展到完整大小
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(save_path)
+            .await?;
+        file.seek(std::io::SeekFrom::Start(offset)).await?;
+        return Ok(file);
     }
-    let out = out.trim().trim_matches('.').trim().to_string();
-    if out.is_empty() {
-        "_".to_string()
-    } else {
-        out
+
+    let file = tokio::fs::File::create(save_path).await?;
+    if total == 0 || !preallocate_enabled() {
+        storage::update_download_preallocated(video_id, false)?;
+        return Ok(file);
+    }
+    let std_file = file.into_std().await;
+    let (std_file, result) = tokio::task::spawn_blocking(move || {
+        let result = fs4::fs_std::FileExt::allocate(&std_file, total);
+        (std_file, result)
+    })
+    .await?;
+    match result {
+        Ok(()) => {
+            storage::update_download_preallocated(video_id, true)?;
+            tracing::info!(
+                "download preallocated video_id={} bytes={}",
+                video_id,
+                total
+            );
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::StorageFull => return Err(e.into()),
+        Err(e) => {
+            // 文件系统不支持预分配时退回普通追加写入
+            tracing::warn!(
+                "download preallocate_failed video_id={} error={}",
+                video_id,
+                e
+            );
+            std_file.set_len(0)?;
+            storage::update_download_preallocated(video_id, false)?;
+        }
     }
+    Ok(tokio::fs::File::from_std(std_file))
 }
 
-fn uniquify_path(path: PathBuf) -> PathBuf {
-    if !path.exists() {
-        return path;
+pub(crate) fn stream_ended_early(downloaded: u64, total: u64) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::UnexpectedEof,
+        format!("Stream ended at {} of {} bytes", downloaded, total),
+    )
+}
+
+pub(crate) async fn verify_downloaded_file(
+    path: PathBuf,
+    expected_total: u64,
+) -> anyhow::Result<MediaCheck> {
+    let check =
+        tokio::task::spawn_blocking(move || media_verify::verify_media_file(&path, expected_total))
+            .await??;
+    Ok(check)
+}
+
+pub(crate) fn mark_download_corrupt(video_id: &str, reason: &str) -> anyhow::Result<()> {
+    storage::update_download_status(video_id, storage::DownloadStatus::Corrupt, Some(reason))?;
+    storage::reset_download_retry(video_id)?;
+    storage::update_download_error_kind(video_id, Some(DownloadErrorKind::Corrupt.as_str()))?;
+    Ok(())
+}
+
+/// 当前使用的媒体地址及其清晰度（签名过期后按同一清晰度刷新）
+struct MediaSource {
+    url: String,
+    quality: String,
+}
+
+pub(crate) fn find_source<'a>(
+    sources: &'a [parser::VideoSource],
+    quality: &str,
+) -> Option<&'a parser::VideoSource> {
+    sources
+        .iter()
+        .find(|s| s.quality.eq_ignore_ascii_case(quality))
+}
+
+/// 查找指定清晰度的播放源：网页未直接提供时，展开 HLS master 播放列表查找同名画质变体
+pub(crate) async fn find_source_or_variant(
+    sources: &[parser::VideoSource],
+    quality: &str,
+) -> Option<parser::VideoSource> {
+    if let Some(source) = find_source(sources, quality) {
+        return Some(source.clone());
     }
-    let stem = path
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("file")
-        .to_string();
-    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
-    let dir = path
-        .parent()
-        .map(|p| p.to_path_buf())
-        .unwrap_or_else(|| PathBuf::new());
-
-    for i in 1..10_000 {
-        let name = if ext.is_empty() {
-            format!("{stem} ({i})")
-        } else {
-            format!("{stem} ({i}).{ext}")
+    for master in sources.iter().filter(|s| s.format == "m3u8") {
+        let variants = match video::fetch_master_variants(&master.url).await {
+            Ok(variants) => variants,
+            Err(e) => {
+                tracing::warn!("download expand_master_failed error={}", e);
+                continue;
+            }
         };
-        let candidate = dir.join(name);
-        if !candidate.exists() {
-            return candidate;
+        let found = variants.into_iter().find_map(|variant| {
+            let label = variant.quality()?;
+            label
+                .eq_ignore_ascii_case(quality)
+                .then_some(parser::VideoSource {
+                    quality: label,
+                    url: variant.url,
+                    format: "m3u8".to_string(),
+                })
+        });
+        if found.is_some() {
+            return found;
         }
     }
-    path
+    None
 }
 
-async fn copy_file_with_progress(
-    src: &PathBuf,
-    dst: &PathBuf,
-    total: u64,
-    mut on_progress: impl FnMut(u64),
-) -> anyhow::Result<()> {
-    use tokio::io::{AsyncReadExt, AsyncWriteExt};
-    let mut in_f = tokio::fs::File::open(src).await?;
-    let mut out_f = tokio::fs::File::create(dst).await?;
-    let mut buf = vec![0u8; 1024 * 1024];
-    let mut copied: u64 = 0;
+/// 打开媒体流，返回 `(响应, 实际起始偏移, 文件总长度)`。
+///
+/// - 地址签名过期（403/410）时重新抓取 watch 页，按同一清晰度刷新一次地址
+/// - 续传时用 `Content-Range` 中的总长度校验是否仍是同一个文件；不一致或服务端不支持
+///   Range 时，起始偏移回退为 0（调用方需要重新写入文件）
+async fn open_media_stream(
+    video_id: &str,
+    source: &mut MediaSource,
+    offset: u64,
+    expected_total: u64,
+) -> anyhow::Result<(reqwest::Response, u64, u64)> {
+    let mut offset = offset;
+    let mut refreshed = false;
     loop {
-        let n = in_f.read(&mut buf).await?;
-        if n == 0 {
-            break;
+        let resp = match request_media(&source.url, offset).await {
+            Ok(resp) => resp,
+            Err(e) => {
+                download_events::record(storage::DownloadEventRecord {
+                    message: Some(e.to_string()),
+                    source_host: download_events::source_host(&source.url),
+                    quality: Some(source.quality.clone()),
+                    range_start: Some(offset as i64),
+                    http_status: e
+                        .downcast_ref::<MediaHttpError>()
+                        .map(|http| i32::from(http.status)),
+                    ..storage::DownloadEventRecord::new(video_id, "http_response")
+                });
+                if refreshed || DownloadErrorKind::classify(&e) != DownloadErrorKind::SourceExpired
+                {
+                    return Err(e);
+                }
+                tracing::info!(
+                    "download source_expired video_id={} quality={} error={}",
+                    video_id,
+                    source.quality,
+                    e
+                );
+                download_events::record(storage::DownloadEventRecord {
+                    quality: Some(source.quality.clone()),
+                    ..storage::DownloadEventRecord::new(video_id, "source_expired")
+                });
+                source.url = refresh_source_url(video_id, &source.quality).await?;
+                refreshed = true;
+                continue;
+            }
+        };
+        let total = if offset > 0 && resp.status() == reqwest::StatusCode::PARTIAL_CONTENT {
+            content_range_total(&resp)
+        } else {
+            resp.content_length()
+        };
+        download_events::record(storage::DownloadEventRecord {
+            source_host: download_events::source_host(&source.url),
+            quality: Some(source.quality.clone()),
+            range_start: Some(offset as i64),
+            range_end: total.filter(|t| *t > 0).map(|t| t as i64 - 1),
+            http_status: Some(i32::from(resp.status().as_u16())),
+            ..storage::DownloadEventRecord::new(video_id, "http_response")
+        });
+
+        if offset == 0 {
+            let total = resp.content_length().unwrap_or(0);
+            return Ok((resp, 0, total));
+        }
+        if resp.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+            let total = resp.content_length().unwrap_or(0);
+            return Ok((resp, 0, total));
         }
-        out_f.write_all(&buf[..n]).await?;
-        copied += n as u64;
-        if total == 0 || copied == total || (copied % (4 * 1024 * 1024) < n as u64) {
-            on_progress(copied);
+        let total = content_range_total(&resp)
+            .unwrap_or_else(|| offset + resp.content_length().unwrap_or(0));
+        if expected_total > 0 && total != expected_total {
+            download_events::record(storage::DownloadEventRecord {
+                message: Some(format!(
+                    "Expected {} bytes, got {} bytes; restarting from 0",
+                    expected_total, total
+                )),
+                ..storage::DownloadEventRecord::new(video_id, "source_changed")
+            });
+            tracing::warn!(
+                "download source_changed video_id={} expected_total={} actual_total={}",
+                video_id,
+                expected_total,
+                total
+            );
+            offset = 0;
+            continue;
         }
+        return Ok((resp, offset, total));
+    }
+}
+
+pub(crate) async fn request_media(url: &str, offset: u64) -> anyhow::Result<reqwest::Response> {
+    let mut req = network::get_client().get(url);
+    if offset > 0 {
+        req = req.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+    }
+    let resp = req.send().await?;
+    let status = resp.status();
+    if !status.is_success() {
+        return Err(MediaHttpError {
+            status: status.as_u16(),
+        }
+        .into());
+    }
+    Ok(resp)
+}
+
+/// 重新抓取 watch 页，获取同一清晰度的新地址
+async fn refresh_source_url(video_id: &str, quality: &str) -> anyhow::Result<String> {
+    let watch_url = format!("{}/watch?v={}", network::base_url(), video_id);
+    let html = network::get(&watch_url).await?;
+    let detail = parser::parse_video_detail(&html)?;
+    let pick = find_source_or_variant(&detail.video_sources, quality)
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Quality {} is no longer available", quality))?;
+    storage::update_download_source(video_id, &pick.url, &pick.quality)?;
+    download_events::record(storage::DownloadEventRecord {
+        source_host: download_events::source_host(&pick.url),
+        quality: Some(pick.quality.clone()),
+        format: Some(pick.format.clone()),
+        ..storage::DownloadEventRecord::new(video_id, "source_refreshed")
+    });
+    tracing::info!(
+        "download source_refreshed video_id={} quality={} url_len={}",
+        video_id,
+        pick.quality,
+        pick.url.len()
+    );
+    Ok(pick.url.clone())
+}
+
+/// 解析 `Content-Range: bytes start-end/total` 中的总长度
+pub(crate) fn content_range_total(resp: &reqwest::Response) -> Option<u64> {
+    resp.headers()
+        .get(reqwest::header::CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .rsplit_once('/')
+        .and_then(|(_, total)| total.trim().parse::<u64>().ok())
+}
+
+/// 记录在下载流程之外被修改时，向订阅者推送最新状态
+pub(crate) fn notify_download_changed(video_id: &str) {
+    if let Ok(Some(record)) = storage::get_download_by_video_id(video_id) {
+        let _ = progress_sender().send(map_record(record));
+    }
+}
+
+fn progress_sender() -> &'static broadcast::Sender<ApiDownloadTask> {
+    static CHANNEL: OnceLock<broadcast::Sender<ApiDownloadTask>> = OnceLock::new();
+    CHANNEL.get_or_init(|| {
+        let (tx, _) = broadcast::channel(100);
+        tx
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enqueue_rejected_during_relocation() {
+        let _lock = library_use::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let relocation = library_use::begin_relocation().unwrap();
+        let enqueued = rt.block_on(enqueue_new_download(
+            "relocating_v1".to_string(),
+            "title".to_string(),
+            "http://127.0.0.1:9/cover.jpg".to_string(),
+            "480P".to_string(),
+            None,
+            vec![],
+            None,
+        ));
+        assert!(enqueued.is_err());
+        let deleted = rt.block_on(delete_download("relocating_v1".to_string(), true));
+        assert!(deleted.is_err());
+        drop(relocation);
+
+        // 迁移结束后不再拒绝
+        assert!(library_use::acquire().is_ok());
     }
-    out_f.flush().await?;
-    Ok(())
 }
@@ -0,0 +1,179 @@
+// 批量下载 API（整部系列 / 播放列表 / 搜索结果）
+
+use crate::api::download::enqueue_new_download;
+use crate::api::download_folders::ensure_manual_folder;
+use crate::api::models::{ApiBatchEnqueueResult, ApiSearchFilters};
+use crate::api::search::build_search_url;
+use crate::core::{network, parser, storage};
+use flutter_rust_bridge::frb;
+use std::collections::HashSet;
+
+/// 播放列表最多翻页数
+const MAX_PLAYLIST_PAGES: u32 = 100;
+/// 搜索最多翻页数
+const MAX_SEARCH_PAGES: u32 = 50;
+/// 播放列表每页条数（不足一页视为最后一页）
+const PLAYLIST_PAGE_SIZE: usize = 20;
+
+/// 下载整部系列（按识别出的集数顺序，无法识别时按观看页播放列表顺序）
+///
+/// 观看页没有播放列表时仅下载该视频本身
+#[frb]
+pub async fn download_series(
+    video_id: String,
+    quality: String,
+    folder_id: Option<String>,
+) -> anyhow::Result<ApiBatchEnqueueResult> {
+    let watch_url = format!("{}/watch?v={}", network::base_url(), video_id);
+    let html = network::get(&watch_url).await?;
+    let detail = parser::parse_video_detail(&html)?;
+
+    let videos = match (detail.series, detail.playlist) {
+        (Some(series), Some(playlist)) => series
+            .episodes
+            .iter()
+            .filter_map(|e| playlist.videos.iter().find(|v| v.id == e.id).cloned())
+            .collect(),
+        (None, Some(playlist)) if !playlist.videos.is_empty() => playlist.videos,
+        _ => vec![parser::VideoCard {
+            id: detail.id.clone(),
+            title: detail.title.clone(),
+            cover_url: detail.cover_url.clone(),
+            duration: detail.duration.clone().unwrap_or_default(),
+            views: detail.views.clone(),
+            artist: detail.creator.as_ref().map(|c| c.name.clone()),
+            upload_time: None,
+            tags: detail.tags.clone(),
+            upload_date: Some(detail.upload_date.clone()),
+        }],
+    };
+    tracing::info!(
+        "download_series video_id={} episodes={}",
+        video_id,
+        videos.len()
+    );
+    enqueue_cards(videos, &quality, folder_id).await
+}
+
+/// 下载播放列表（`/playlist?list=...`）中的全部视频
+#[frb]
+pub async fn download_playlist(
+    list_id: String,
+    quality: String,
+    folder_id: Option<String>,
+) -> anyhow::Result<ApiBatchEnqueueResult> {
+    let mut videos = Vec::new();
+    for page in 1..=MAX_PLAYLIST_PAGES {
+        let url = format!(
+            "{}/playlist?list={}&page={}",
+            network::base_url(),
+            urlencoding::encode(&list_id),
+            page
+        );
+        let html = network::get(&url).await?;
+        let items = parser::parse_my_list_items(&html)?;
+        let count = items.videos.len();
+        videos.extend(items.videos);
+        if count < PLAYLIST_PAGE_SIZE {
+            break;
+        }
+    }
+    tracing::info!(
+        "download_playlist list_id={} videos={}",
+        list_id,
+        videos.len()
+    );
+    enqueue_cards(videos, &quality, folder_id).await
+}
+
+/// 下载搜索结果（最多 `max_pages` 页），可按作者名过滤以下载整个作者目录
+#[frb]
+pub async fn download_search(
+    filters: ApiSearchFilters,
+    max_pages: u32,
+    artist: Option<String>,
+    quality: String,
+    folder_id: Option<String>,
+) -> anyhow::Result<ApiBatchEnqueueResult> {
+    let artist = artist
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty());
+    let first_page = filters.page.max(1);
+    let max_pages = max_pages.clamp(1, MAX_SEARCH_PAGES);
+
+    let mut videos = Vec::new();
+    for page in first_page..first_page + max_pages {
+        let url = build_search_url(&ApiSearchFilters {
+            page,
+            ..filters.clone()
+        });
+        tracing::info!("download_search GET {}", url);
+        let html = network::get(&url).await?;
+        let result = parser::parse_search_page(&html)?;
+        videos.extend(result.videos.into_iter().filter(|v| {
+            match &artist {
+                Some(artist) => v
+                    .artist
+                    .as_deref()
+                    .is_some_and(|a| a.trim().eq_ignore_ascii_case(artist)),
+                None => true,
+            }
+        }));
+        if !result.has_next {
+            break;
+        }
+    }
+    enqueue_cards(videos, &quality, folder_id).await
+}
+
+/// 按顺序加入下载队列：已存在的任务跳过，重复的视频只保留第一次出现
+async fn enqueue_cards(
+    videos: Vec<parser::VideoCard>,
+    quality: &str,
+    folder_id: Option<String>,
+) -> anyhow::Result<ApiBatchEnqueueResult> {
+    if let Some(folder_id) = folder_id.as_deref() {
+        ensure_manual_folder(folder_id)?;
+    }
+
+    let mut seen = HashSet::new();
+    let mut total = 0u32;
+    let mut enqueued = Vec::new();
+    let mut skipped = Vec::new();
+    let mut failed = Vec::new();
+    for video in videos {
+        if !seen.insert(video.id.clone()) {
+            continue;
+        }
+        total += 1;
+        if storage::get_download_by_video_id(&video.id)?.is_some() {
+            skipped.push(video.id);
+            continue;
+        }
+        let id = video.id.clone();
+        match enqueue_new_download(
+            video.id,
+            video.title,
+            video.cover_url,
+            quality.to_string(),
+            None,
+            video.tags,
+            folder_id.clone(),
+        )
+        .await
+        {
+            Ok(_) => enqueued.push(id),
+            Err(e) => {
+                tracing::warn!("download batch_enqueue_failed video_id={} error={}", id, e);
+                failed.push(id);
+            }
+        }
+    }
+
+    Ok(ApiBatchEnqueueResult {
+        total,
+        enqueued,
+        skipped,
+        failed,
+    })
+}
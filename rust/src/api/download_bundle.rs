@@ -0,0 +1,895 @@
+// 下载库打包 API（跨设备迁移）
+// - 打包格式：目录或 zip，包含 manifest.json、media/、covers/、avatars/、subtitles/
+// - manifest 记录 downloads 与 download_folders 的元数据（标题、作者、标签、文件夹等）
+// - 导入时重建文件夹与下载记录，video_id 冲突按 `skip` / `replace` 处理
+
+use crate::api::download::{
+    basename, download_files, resolve_download_path, resolve_under_downloads_root,
+    stop_download_tasks,
+};
+use crate::api::download_subtitles::{self, SUBTITLES_DIR as DATA_SUBTITLES_DIR};
+use crate::api::models::ApiBundleProgress;
+use crate::core::{library_use, runtime, storage, throttle};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+const MANIFEST_NAME: &str = "manifest.json";
+const MANIFEST_VERSION: u32 = 1;
+const MEDIA_DIR: &str = "media";
+const COVERS_DIR: &str = "covers";
+const AVATARS_DIR: &str = "avatars";
+const SUBTITLES_DIR: &str = "subtitles";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BundleManifest {
+    version: u32,
+    exported_at: i64,
+    #[serde(default)]
+    folders: Vec<BundleFolder>,
+    #[serde(default)]
+    downloads: Vec<BundleDownload>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BundleFolder {
+    id: String,
+    name: String,
+    created_at: i64,
+    #[serde(default)]
+    parent_id: Option<String>,
+    #[serde(default)]
+    sort_order: i64,
+    #[serde(default)]
+    cover_video_id: Option<String>,
+    /// 智能文件夹规则，普通文件夹为空
+    #[serde(default)]
+    rule_json: Option<String>,
+}
+
+/// manifest 中的下载记录（文件名相对于 media/、covers/、avatars/、subtitles/）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BundleDownload {
+    video_id: String,
+    title: String,
+    #[serde(default)]
+    cover_url: String,
+    #[serde(default)]
+    video_url: String,
+    #[serde(default)]
+    quality: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    author_id: Option<String>,
+    #[serde(default)]
+    author_name: Option<String>,
+    #[serde(default)]
+    author_avatar_url: Option<String>,
+    #[serde(default)]
+    folder_id: Option<String>,
+    total_bytes: i64,
+    created_at: i64,
+    #[serde(default)]
+    completed_at: Option<i64>,
+    #[serde(default)]
+    source_url: Option<String>,
+    #[serde(default)]
+    source_quality: Option<String>,
+    #[serde(default)]
+    upload_date: Option<String>,
+    #[serde(default)]
+    series_name: Option<String>,
+    #[serde(default)]
+    series_episode: Option<i32>,
+    #[serde(default)]
+    chinese_title: Option<String>,
+    media_file: String,
+    #[serde(default)]
+    cover_file: Option<String>,
+    #[serde(default)]
+    avatar_file: Option<String>,
+    #[serde(default)]
+    subtitles: Vec<BundleSubtitle>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BundleSubtitle {
+    #[serde(default)]
+    language: String,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    url: String,
+    format: String,
+    file: String,
+}
+
+/// 导入冲突策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConflictPolicy {
+    Skip,
+    Replace,
+}
+
+impl ConflictPolicy {
+    fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "replace" => ConflictPolicy::Replace,
+            _ => ConflictPolicy::Skip,
+        }
+    }
+}
+
+/// 导出已完成的下载为可迁移的打包（通过 StreamSink 发送进度）
+///
+/// - `task_ids` 为空时导出全部已完成的下载
+/// - `as_zip` 为 true 时 `dest_path` 为 zip 文件路径，否则为目录
+#[frb]
+pub fn export_download_bundle(
+    task_ids: Vec<String>,
+    dest_path: String,
+    as_zip: bool,
+    sink: StreamSink<ApiBundleProgress>,
+) {
+    let reporter = BundleReporter::new(sink, "export");
+    runtime::spawn(async move {
+        let reporter = Arc::new(Mutex::new(reporter));
+        let job_reporter = reporter.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let _library = library_use::acquire()?;
+            write_bundle(task_ids, Path::new(&dest_path), as_zip, &job_reporter)
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|r| r);
+        lock(&reporter).done(result.err().map(|e| e.to_string()));
+    });
+}
+
+/// 从打包（目录或 zip 文件）导入下载（通过 StreamSink 发送进度）
+///
+/// - `conflict_policy`：`skip`（默认，保留本地记录）| `replace`（删除本地记录与文件后导入）
+/// - 文件夹按名称合并，不存在时新建
+#[frb]
+pub fn import_download_bundle(
+    src_path: String,
+    conflict_policy: String,
+    sink: StreamSink<ApiBundleProgress>,
+) {
+    let reporter = BundleReporter::new(sink, "import");
+    let policy = ConflictPolicy::parse(&conflict_policy);
+    runtime::spawn(async move {
+        let reporter = Arc::new(Mutex::new(reporter));
+        let result = match library_use::acquire() {
+            Ok(_library) => import_bundle(PathBuf::from(src_path), policy, reporter.clone()).await,
+            Err(e) => Err(e),
+        };
+        lock(&reporter).done(result.err().map(|e| e.to_string()));
+    });
+}
+
+// ============================================================================
+// 进度
+// ============================================================================
+
+struct BundleReporter {
+    sink: StreamSink<ApiBundleProgress>,
+    progress: ApiBundleProgress,
+}
+
+impl BundleReporter {
+    fn new(sink: StreamSink<ApiBundleProgress>, stage: &str) -> Self {
+        Self {
+            sink,
+            progress: ApiBundleProgress {
+                stage: stage.to_string(),
+                total_items: 0,
+                done_items: 0,
+                exported: 0,
+                imported: 0,
+                skipped: 0,
+                failed: 0,
+                current_item: None,
+                current_bytes: 0,
+                current_total_bytes: 0,
+                done: false,
+                error: None,
+            },
+        }
+    }
+
+    fn send(&self) {
+        let _ = self.sink.add(self.progress.clone());
+    }
+
+    fn start_item(&mut self, name: &str, total_bytes: u64) {
+        self.progress.current_item = Some(name.to_string());
+        self.progress.current_bytes = 0;
+        self.progress.current_total_bytes = total_bytes;
+        self.progress.error = None;
+        self.send();
+    }
+
+    fn bytes(&mut self, current: u64) {
+        self.progress.current_bytes = current;
+        self.send();
+    }
+
+    fn finish_item(&mut self, error: Option<String>) {
+        self.progress.done_items += 1;
+        if error.is_some() {
+            self.progress.failed += 1;
+        }
+        self.progress.error = error;
+        self.send();
+    }
+
+    fn done(&mut self, error: Option<String>) {
+        self.progress.done = true;
+        self.progress.current_item = None;
+        self.progress.error = error;
+        self.send();
+    }
+}
+
+type SharedReporter = Arc<Mutex<BundleReporter>>;
+
+fn lock<T>(m: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// ============================================================================
+// 导出
+// ============================================================================
+
+enum BundleWriter {
+    Dir(PathBuf),
+    Zip(zip::ZipWriter<File>),
+}
+
+impl BundleWriter {
+    fn add_file(
+        &mut self,
+        entry: &str,
+        src: &Path,
+        mut on_progress: impl FnMut(u64),
+    ) -> anyhow::Result<()> {
+        let mut input = File::open(src)?;
+        match self {
+            BundleWriter::Dir(root) => {
+                let dst = root.join(entry);
+                if let Some(parent) = dst.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let part = part_path(&dst);
+                let mut out = File::create(&part)?;
+                copy_with_progress(&mut input, &mut out, &mut on_progress)?;
+                out.sync_all()?;
+                std::fs::rename(&part, &dst)?;
+            }
+            BundleWriter::Zip(zip) => {
+                // 媒体与图片本身已压缩，直接存储
+                let options = zip::write::FileOptions::default()
+                    .compression_method(zip::CompressionMethod::Stored)
+                    .large_file(true);
+                zip.start_file(entry, options)?;
+                copy_with_progress(&mut input, zip, &mut on_progress)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn add_bytes(&mut self, entry: &str, data: &[u8]) -> anyhow::Result<()> {
+        match self {
+            BundleWriter::Dir(root) => std::fs::write(root.join(entry), data)?,
+            BundleWriter::Zip(zip) => {
+                zip.start_file(entry, zip::write::FileOptions::default())?;
+                zip.write_all(data)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        if let BundleWriter::Zip(mut zip) = self {
+            zip.finish()?;
+        }
+        Ok(())
+    }
+}
+
+fn write_bundle(
+    task_ids: Vec<String>,
+    dest: &Path,
+    as_zip: bool,
+    reporter: &SharedReporter,
+) -> anyhow::Result<()> {
+    let records = if task_ids.is_empty() {
+        storage::get_downloads()?
+    } else {
+        task_ids
+            .iter()
+            .filter_map(|id| storage::get_download_by_video_id(id).ok().flatten())
+            .collect()
+    };
+    let records = records
+        .into_iter()
+        .filter(|r| r.status == storage::DownloadStatus::Completed)
+        .collect::<Vec<_>>();
+    {
+        let mut reporter = lock(reporter);
+        reporter.progress.total_items = records.len() as u32;
+        reporter.send();
+    }
+
+    let mut writer = if as_zip {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        BundleWriter::Zip(zip::ZipWriter::new(File::create(dest)?))
+    } else {
+        std::fs::create_dir_all(dest)?;
+        BundleWriter::Dir(dest.to_path_buf())
+    };
+
+    let mut downloads = Vec::with_capacity(records.len());
+    for record in records {
+        let media = record
+            .save_path
+            .as_deref()
+            .and_then(basename)
+            .and_then(|name| Some((resolve_download_path(&name).ok()?, name)))
+            .filter(|(path, _)| path.is_file());
+        let Some((media_path, media_name)) = media else {
+            let mut reporter = lock(reporter);
+            reporter.start_item(&record.title, 0);
+            reporter.finish_item(Some("Missing local file".to_string()));
+            continue;
+        };
+        let size = std::fs::metadata(&media_path)?.len();
+        lock(reporter).start_item(&record.title, size);
+
+        let copied = writer.add_file(&format!("{MEDIA_DIR}/{media_name}"), &media_path, |n| {
+            lock(reporter).bytes(n)
+        });
+        if let Err(e) = copied {
+            // zip 写入失败后无法继续
+            if as_zip {
+                return Err(e);
+            }
+            lock(reporter).finish_item(Some(e.to_string()));
+            continue;
+        }
+
+        let cover_file = add_image(
+            &mut writer,
+            "download_covers",
+            COVERS_DIR,
+            &record.cover_path,
+        )?;
+        let avatar_file = add_image(
+            &mut writer,
+            "download_avatars",
+            AVATARS_DIR,
+            &record.author_avatar_path,
+        )?;
+        let mut subtitles = Vec::new();
+        for (subtitle, _) in download_subtitles::local_subtitles(&record.video_id) {
+            let file = add_image(
+                &mut writer,
+                DATA_SUBTITLES_DIR,
+                SUBTITLES_DIR,
+                &subtitle.file_path,
+            )?;
+            if let Some(file) = file {
+                subtitles.push(BundleSubtitle {
+                    language: subtitle.language,
+                    label: subtitle.label,
+                    url: subtitle.url,
+                    format: subtitle.format,
+                    file,
+                });
+            }
+        }
+        downloads.push(BundleDownload {
+            video_id: record.video_id,
+            title: record.title,
+            cover_url: record.cover_url,
+            video_url: record.video_url,
+            quality: record.quality,
+            description: record.description,
+            tags: record.tags,
+            author_id: record.author_id,
+            author_name: record.author_name,
+            author_avatar_url: record.author_avatar_url,
+            folder_id: record.folder_id,
+            total_bytes: size as i64,
+            created_at: record.created_at,
+            completed_at: record.completed_at,
+            source_url: record.source_url,
+            source_quality: record.source_quality,
+            upload_date: record.upload_date,
+            series_name: record.series_name,
+            series_episode: record.series_episode,
+            chinese_title: record.chinese_title,
+            media_file: media_name,
+            cover_file,
+            avatar_file,
+            subtitles,
+        });
+        let mut reporter = lock(reporter);
+        reporter.progress.exported += 1;
+        reporter.finish_item(None);
+    }
+
+    // 导出用到的文件夹及其上级，导入时重建层级
+    let all_folders = storage::get_download_folders()?;
+    let mut used_folders = HashSet::new();
+    for folder_id in downloads.iter().filter_map(|d| d.folder_id.as_deref()) {
+        let mut next = Some(folder_id);
+        while let Some(id) = next {
+            if !used_folders.insert(id) {
+                break;
+            }
+            next = all_folders
+                .iter()
+                .find(|f| f.id == id)
+                .and_then(|f| f.parent_id.as_deref());
+        }
+    }
+    let folders = all_folders
+        .iter()
+        .filter(|f| used_folders.contains(f.id.as_str()))
+        .map(|f| BundleFolder {
+            id: f.id.clone(),
+            name: f.name.clone(),
+            created_at: f.created_at,
+            parent_id: f.parent_id.clone(),
+            sort_order: f.sort_order,
+            cover_video_id: f.cover_video_id.clone(),
+            rule_json: f.rule_json.clone(),
+        })
+        .collect();
+    let manifest = BundleManifest {
+        version: MANIFEST_VERSION,
+        exported_at: chrono::Utc::now().timestamp(),
+        folders,
+        downloads,
+    };
+
+    // manifest 最后写入：没有 manifest 的打包视为不完整
+    writer.add_bytes(MANIFEST_NAME, &serde_json::to_vec_pretty(&manifest)?)?;
+    writer.finish()
+}
+
+/// 写入封面 / 头像 / 字幕（文件不存在时忽略）
+fn add_image(
+    writer: &mut BundleWriter,
+    data_subdir: &str,
+    bundle_dir: &str,
+    stored: &Option<String>,
+) -> anyhow::Result<Option<String>> {
+    let Some(name) = stored.as_deref().and_then(basename) else {
+        return Ok(None);
+    };
+    let path = resolve_under_downloads_root(data_subdir, &name)?;
+    if !path.is_file() {
+        return Ok(None);
+    }
+    writer.add_file(&format!("{bundle_dir}/{name}"), &path, |_| {})?;
+    Ok(Some(name))
+}
+
+// ============================================================================
+// 导入
+// ============================================================================
+
+enum BundleReader {
+    Dir(PathBuf),
+    Zip(zip::ZipArchive<File>),
+}
+
+impl BundleReader {
+    fn open(path: &Path) -> anyhow::Result<Self> {
+        if path.is_dir() {
+            Ok(BundleReader::Dir(path.to_path_buf()))
+        } else {
+            Ok(BundleReader::Zip(zip::ZipArchive::new(File::open(path)?)?))
+        }
+    }
+
+    fn read_manifest(&mut self) -> anyhow::Result<BundleManifest> {
+        let bytes = match self {
+            BundleReader::Dir(root) => std::fs::read(root.join(MANIFEST_NAME))?,
+            BundleReader::Zip(zip) => {
+                let mut entry = zip.by_name(MANIFEST_NAME)?;
+                let mut bytes = Vec::with_capacity(entry.size() as usize);
+                entry.read_to_end(&mut bytes)?;
+                bytes
+            }
+        };
+        let manifest: BundleManifest = serde_json::from_slice(&bytes)?;
+        if manifest.version > MANIFEST_VERSION {
+            anyhow::bail!("Unsupported bundle version: {}", manifest.version);
+        }
+        Ok(manifest)
+    }
+
+    fn size_of(&mut self, dir: &str, name: &str) -> Option<u64> {
+        match self {
+            BundleReader::Dir(root) => std::fs::metadata(root.join(dir).join(name))
+                .ok()
+                .map(|m| m.len()),
+            BundleReader::Zip(zip) => zip.by_name(&format!("{dir}/{name}")).ok().map(|e| e.size()),
+        }
+    }
+
+    fn extract(
+        &mut self,
+        dir: &str,
+        name: &str,
+        dst: &Path,
+        mut on_progress: impl FnMut(u64),
+    ) -> anyhow::Result<()> {
+        let mut out = File::create(dst)?;
+        match self {
+            BundleReader::Dir(root) => {
+                let mut input = File::open(root.join(dir).join(name))?;
+                copy_with_progress(&mut input, &mut out, &mut on_progress)?;
+            }
+            BundleReader::Zip(zip) => {
+                let mut input = zip.by_name(&format!("{dir}/{name}"))?;
+                copy_with_progress(&mut input, &mut out, &mut on_progress)?;
+            }
+        }
+        out.sync_all()?;
+        Ok(())
+    }
+}
+
+async fn import_bundle(
+    src: PathBuf,
+    policy: ConflictPolicy,
+    reporter: SharedReporter,
+) -> anyhow::Result<()> {
+    let (reader, manifest) = tokio::task::spawn_blocking(move || {
+        let mut reader = BundleReader::open(&src)?;
+        let manifest = reader.read_manifest()?;
+        anyhow::Ok((reader, manifest))
+    })
+    .await??;
+    let reader = Arc::new(Mutex::new(reader));
+    {
+        let mut reporter = lock(&reporter);
+        reporter.progress.total_items = manifest.downloads.len() as u32;
+        reporter.send();
+    }
+
+    let folder_map = import_folders(&manifest.folders)?;
+
+    for item in manifest.downloads {
+        lock(&reporter).start_item(&item.title, item.total_bytes.max(0) as u64);
+        let result = import_one(&item, policy, &folder_map, &reader, &reporter).await;
+        let mut reporter = lock(&reporter);
+        match result {
+            Ok(true) => {
+                reporter.progress.imported += 1;
+                reporter.finish_item(None);
+            }
+            Ok(false) => {
+                reporter.progress.skipped += 1;
+                reporter.finish_item(None);
+            }
+            Err(e) => reporter.finish_item(Some(e.to_string())),
+        }
+    }
+    Ok(())
+}
+
+/// 重建文件夹层级（同一上级下按名称合并），返回 打包内 ID → 本地 ID
+///
+/// 智能文件夹不能放入视频，不出现在返回结果中
+fn import_folders(folders: &[BundleFolder]) -> anyhow::Result<HashMap<String, String>> {
+    let mut existing = storage::get_download_folders()?;
+    let depth = |folder: &BundleFolder| {
+        let mut depth = 0;
+        let mut parent = folder.parent_id.as_deref();
+        while let Some(id) = parent {
+            if depth > folders.len() {
+                break;
+            }
+            depth += 1;
+            parent = folders
+                .iter()
+                .find(|f| f.id == id)
+                .and_then(|f| f.parent_id.as_deref());
+        }
+        depth
+    };
+    // 上级在前，同级保持原顺序
+    let mut ordered = folders
+        .iter()
+        .filter(|f| !f.name.trim().is_empty())
+        .collect::<Vec<_>>();
+    ordered.sort_by_key(|f| (depth(f), f.sort_order, f.created_at));
+
+    let mut local_ids = HashMap::new();
+    let mut map = HashMap::new();
+    for folder in ordered {
+        let name = folder.name.trim();
+        // 上级不在打包中时放在顶层
+        let parent_id = folder
+            .parent_id
+            .as_ref()
+            .and_then(|id| local_ids.get(id))
+            .cloned();
+        let smart = folder.rule_json.is_some();
+        let local = existing
+            .iter()
+            .find(|f| f.name == name && f.parent_id == parent_id && f.is_smart() == smart);
+        let local_id = match local {
+            Some(local) => local.id.clone(),
+            None => {
+                let id = if existing.iter().any(|f| f.id == folder.id) {
+                    Uuid::new_v4().to_string()
+                } else {
+                    folder.id.clone()
+                };
+                storage::create_download_folder(
+                    &id,
+                    name,
+                    parent_id.as_deref(),
+                    folder.rule_json.as_deref(),
+                )?;
+                if folder.cover_video_id.is_some() {
+                    storage::update_download_folder_cover(&id, folder.cover_video_id.as_deref())?;
+                }
+                existing = storage::get_download_folders()?;
+                id
+            }
+        };
+        if !smart {
+            map.insert(folder.id.clone(), local_id.clone());
+        }
+        local_ids.insert(folder.id.clone(), local_id);
+    }
+    Ok(map)
+}
+
+/// 导入单个下载；返回 false 表示因冲突跳过
+async fn import_one(
+    item: &BundleDownload,
+    policy: ConflictPolicy,
+    folder_map: &HashMap<String, String>,
+    reader: &Arc<Mutex<BundleReader>>,
+    reporter: &SharedReporter,
+) -> anyhow::Result<bool> {
+    let existing = storage::get_download_by_video_id(&item.video_id)?;
+    if existing.is_some() && policy == ConflictPolicy::Skip {
+        return Ok(false);
+    }
+    let media_name = safe_name(&item.media_file)?;
+
+    // 先解压到临时文件，成功后再替换本地记录（旧文件保留到替换成功）
+    let part = part_path(&resolve_download_path(&media_name)?);
+    {
+        let (reader, reporter, tmp) = (reader.clone(), reporter.clone(), part.clone());
+        let name = media_name.clone();
+        let extracted = tokio::task::spawn_blocking(move || {
+            lock(&reader).extract(MEDIA_DIR, &name, &tmp, |n| lock(&reporter).bytes(n))
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|r| r);
+        if let Err(e) = extracted {
+            let _ = std::fs::remove_file(&part);
+            return Err(e);
+        }
+    }
+    let size = std::fs::metadata(&part)?.len();
+
+    let media_path = unique_path(resolve_download_path(&media_name)?);
+    std::fs::rename(&part, &media_path)?;
+    let cover_path = import_image(reader, COVERS_DIR, "download_covers", &item.cover_file).await;
+    let avatar_path =
+        import_image(reader, AVATARS_DIR, "download_avatars", &item.avatar_file).await;
+
+    let now = chrono::Utc::now().timestamp();
+    let record = storage::DownloadRecord {
+        id: 0,
+        video_id: item.video_id.clone(),
+        title: item.title.clone(),
+        cover_url: item.cover_url.clone(),
+        video_url: item.video_url.clone(),
+        quality: item.quality.clone(),
+        description: item.description.clone(),
+        tags: item.tags.clone(),
+        cover_path: cover_path.as_deref().and_then(file_name_of),
+        author_id: item.author_id.clone(),
+        author_name: item.author_name.clone(),
+        author_avatar_url: item.author_avatar_url.clone(),
+        author_avatar_path: avatar_path.as_deref().and_then(file_name_of),
+        folder_id: item
+            .folder_id
+            .as_ref()
+            .and_then(|id| folder_map.get(id))
+            .cloned(),
+        save_path: file_name_of(&media_path),
+        total_bytes: size as i64,
+        downloaded_bytes: size as i64,
+        status: storage::DownloadStatus::Completed,
+        error_message: None,
+        created_at: item.created_at,
+        completed_at: item.completed_at.or(Some(now)),
+        error_kind: None,
+        retry_count: 0,
+        max_retries: 0,
+        next_retry_at: None,
+        source_url: item.source_url.clone(),
+        source_quality: item.source_quality.clone(),
+        preallocated: false,
+        upload_date: item.upload_date.clone(),
+        series_name: item.series_name.clone(),
+        series_episode: item.series_episode,
+        chinese_title: item.chinese_title.clone(),
+        bandwidth_limit_kb: None,
+    };
+    // 替换时旧下载的记录与新记录在同一事务内交换，成功后才删除旧文件
+    let swapped = match &existing {
+        Some(old) => {
+            stop_download_tasks(&item.video_id).await;
+            let old_files = download_files(old);
+            storage::replace_imported_download(&record).map(|()| old_files)
+        }
+        None => storage::insert_imported_download(&record).map(|()| vec![]),
+    };
+    match swapped {
+        Ok(old_files) => {
+            // 旧文件缺失时新文件可能使用了相同的文件名
+            let new_files = [Some(&media_path), cover_path.as_ref(), avatar_path.as_ref()];
+            for path in old_files {
+                if !new_files.contains(&Some(&path)) {
+                    let _ = std::fs::remove_file(path);
+                }
+            }
+            if existing.is_some() {
+                throttle::set_task_limit(&item.video_id, None);
+            }
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&media_path);
+            for path in [cover_path, avatar_path].into_iter().flatten() {
+                let _ = std::fs::remove_file(path);
+            }
+            return Err(e);
+        }
+    }
+    for subtitle in &item.subtitles {
+        let file = Some(subtitle.file.clone());
+        let Some(path) = import_image(reader, SUBTITLES_DIR, DATA_SUBTITLES_DIR, &file).await
+        else {
+            continue;
+        };
+        let saved = storage::upsert_download_subtitle(&storage::DownloadSubtitleRecord {
+            video_id: item.video_id.clone(),
+            language: subtitle.language.clone(),
+            label: subtitle.label.clone(),
+            url: subtitle.url.clone(),
+            format: subtitle.format.clone(),
+            file_path: file_name_of(&path),
+        });
+        if saved.is_err() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+    Ok(true)
+}
+
+/// 解压封面 / 头像 / 字幕（失败时忽略，仅影响离线展示）
+async fn import_image(
+    reader: &Arc<Mutex<BundleReader>>,
+    bundle_dir: &'static str,
+    data_subdir: &'static str,
+    name: &Option<String>,
+) -> Option<PathBuf> {
+    let name = safe_name(name.as_deref()?).ok()?;
+    let reader = reader.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut reader = lock(&reader);
+        reader.size_of(bundle_dir, &name)?;
+        let dst = unique_path(resolve_under_downloads_root(data_subdir, &name).ok()?);
+        match reader.extract(bundle_dir, &name, &dst, |_| {}) {
+            Ok(()) => Some(dst),
+            Err(_) => {
+                let _ = std::fs::remove_file(&dst);
+                None
+            }
+        }
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+// ============================================================================
+// 工具函数
+// ============================================================================
+
+/// manifest 中的文件名只能是单个文件名（防止路径穿越）
+fn safe_name(name: &str) -> anyhow::Result<String> {
+    let base = basename(name).unwrap_or_default();
+    if base.is_empty() || base != name || base == "." || base == ".." || base.contains('\\') {
+        anyhow::bail!("Invalid file name in bundle: {name}");
+    }
+    Ok(base)
+}
+
+fn file_name_of(path: &Path) -> Option<String> {
+    path.file_name()
+        .and_then(|s| s.to_str())
+        .map(|s| s.to_string())
+}
+
+fn part_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// 文件已存在时追加 `_1`、`_2`……
+fn unique_path(path: PathBuf) -> PathBuf {
+    if !path.exists() {
+        return path;
+    }
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("file")
+        .to_string();
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let dir = path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+    for i in 1..10_000 {
+        let name = if ext.is_empty() {
+            format!("{stem}_{i}")
+        } else {
+            format!("{stem}_{i}.{ext}")
+        };
+        let candidate = dir.join(name);
+        if !candidate.exists() {
+            return candidate;
+        }
+    }
+    path
+}
+
+fn copy_with_progress<R: Read, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    on_progress: &mut impl FnMut(u64),
+) -> std::io::Result<u64> {
+    let mut buf = vec![0u8; 1024 * 1024];
+    let mut copied = 0u64;
+    loop {
+        let n = input.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        output.write_all(&buf[..n])?;
+        copied += n as u64;
+        if copied % (4 * 1024 * 1024) < n as u64 {
+            on_progress(copied);
+        }
+    }
+    output.flush()?;
+    on_progress(copied);
+    Ok(copied)
+}
@@ -0,0 +1,66 @@
+// 下载事件日志 API
+// - 状态变化由 storage 在更新状态时自动记录；选中的源、Range 请求、HTTP 状态与错误由下载流程记录
+// - 只记录媒体地址的主机名，避免把带签名的地址写入日志导出
+
+use crate::api::models::ApiDownloadEvent;
+use crate::core::storage::{self, DownloadEventRecord};
+use flutter_rust_bridge::frb;
+
+/// 日志导出中包含最近多少天的下载事件
+const EXPORT_EVENT_DAYS: i64 = 7;
+
+/// 获取下载任务的事件时间线（按发生顺序）
+#[frb]
+pub async fn get_download_timeline(task_id: String) -> anyhow::Result<Vec<ApiDownloadEvent>> {
+    Ok(storage::get_download_events(&task_id)?
+        .into_iter()
+        .map(map_event)
+        .collect())
+}
+
+/// 记录事件；失败只写日志，不影响下载流程
+pub(crate) fn record(event: DownloadEventRecord) {
+    if let Err(e) = storage::insert_download_event(&event) {
+        tracing::debug!(
+            "download event_record_failed video_id={} kind={} error={}",
+            event.video_id,
+            event.kind,
+            e
+        );
+    }
+}
+
+/// 媒体地址的主机名
+pub(crate) fn source_host(url: &str) -> Option<String> {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+}
+
+/// 最近的下载事件（JSON Lines），用于日志导出
+pub(crate) fn export_recent_events() -> anyhow::Result<String> {
+    let since = chrono::Utc::now().timestamp_millis() - EXPORT_EVENT_DAYS * 24 * 60 * 60 * 1000;
+    let mut out = String::new();
+    for event in storage::get_download_events_since(since)? {
+        out.push_str(&serde_json::to_string(&event)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn map_event(event: DownloadEventRecord) -> ApiDownloadEvent {
+    ApiDownloadEvent {
+        id: event.id,
+        kind: event.kind,
+        status: event.status,
+        message: event.message,
+        source_host: event.source_host,
+        quality: event.quality,
+        format: event.format,
+        range_start: event.range_start.map(|v| v.max(0) as u64),
+        range_end: event.range_end.map(|v| v.max(0) as u64),
+        http_status: event.http_status.map(|v| v as u16),
+        error_kind: event.error_kind,
+        created_at: event.created_at,
+    }
+}
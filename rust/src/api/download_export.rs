@@ -0,0 +1,1226 @@
+// 下载导出 API
+// - 普通模式：仅复制媒体文件，文件名默认 `[{作者}]{标题}.{ext}`
+// - 媒体库模式：按 Kodi/Jellyfin 约定生成目录、NFO、poster 与作者头像（.actors）
+// - 文件名/子目录可通过模板自定义（见 core::filename_template）
+// - 已下载的字幕与视频同名复制到同一目录
+// - 导出任务可暂停 / 继续 / 取消；先写 `.part` 再重命名，重复导出时跳过相同文件
+
+use crate::api::download::{
+    basename, notify_download_changed, resolve_download_path, resolve_under_downloads_root,
+};
+use crate::api::download_subtitles;
+use crate::api::models::{ApiExportOptions, ApiExportProgress};
+use crate::core::filename_template::{
+    self, numbered_name, render_template, sanitize_filename, sanitize_segment, validate_template,
+};
+use crate::core::{library_use, runtime, storage};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::sync::{watch, Mutex};
+use uuid::Uuid;
+
+/// 按选项导出下载文件到指定目录（通过 StreamSink 发送进度）
+#[frb]
+pub fn export_downloads_with_options(
+    task_ids: Vec<String>,
+    dest_dir: String,
+    options: ApiExportOptions,
+    sink: StreamSink<ApiExportProgress>,
+) {
+    run_export(task_ids, PathBuf::from(dest_dir), options, sink);
+}
+
+/// 获取文件名模板支持的占位符
+#[frb]
+pub fn get_export_template_tokens() -> Vec<String> {
+    filename_template::TEMPLATE_TOKENS
+        .iter()
+        .map(|t| format!("{{{t}}}"))
+        .collect()
+}
+
+/// 校验文件名模板（不合法时返回错误信息）
+#[frb]
+pub fn validate_export_template(template: String) -> anyhow::Result<bool> {
+    validate_template(&template).map_err(anyhow::Error::msg)?;
+    Ok(true)
+}
+
+/// 预览文件名模板的渲染结果（相对导出目录的路径，`/` 分隔）
+///
+/// `task_ids` 为空时使用示例数据
+#[frb]
+pub fn preview_export_template(
+    template: String,
+    task_ids: Vec<String>,
+    max_name_bytes: Option<u32>,
+) -> anyhow::Result<Vec<String>> {
+    let naming = NameTemplate::new(Some(template), max_name_bytes)?;
+    let folders = folder_names();
+
+    if task_ids.is_empty() {
+        let values = HashMap::from([
+            ("author", "作者".to_string()),
+            ("title", "標題".to_string()),
+            ("chinese_title", "标题".to_string()),
+            ("video_id", "12345".to_string()),
+            ("quality", "1080P".to_string()),
+            ("upload_date", "2024-01-01".to_string()),
+            ("folder", "收藏".to_string()),
+            ("series", "系列".to_string()),
+            ("episode", "1".to_string()),
+        ]);
+        let segments = naming.render(&values, "mp4", "12345")?;
+        return Ok(vec![format!("{}.mp4", segments.join("/"))]);
+    }
+
+    let mut out = Vec::with_capacity(task_ids.len());
+    for task_id in task_ids {
+        let Some(record) = storage::get_download_by_video_id(&task_id)? else {
+            continue;
+        };
+        let ext = record
+            .save_path
+            .as_deref()
+            .and_then(|p| Path::new(p).extension().and_then(|s| s.to_str()))
+            .map(sanitize_filename)
+            .unwrap_or_else(|| "mp4".to_string());
+        let values = template_values(&record, &folders);
+        let segments = naming.render(&values, &ext, &record.video_id)?;
+        out.push(format!("{}.{ext}", segments.join("/")));
+    }
+    Ok(out)
+}
+
+/// 分组方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupBy {
+    None,
+    Artist,
+    Series,
+}
+
+impl GroupBy {
+    fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "artist" => GroupBy::Artist,
+            "series" => GroupBy::Series,
+            _ => GroupBy::None,
+        }
+    }
+}
+
+/// 导出文件名模板与长度限制
+struct NameTemplate {
+    template: String,
+    max_name_bytes: usize,
+    /// 是否为用户自定义模板（影响剧集布局下的文件名）
+    custom: bool,
+}
+
+impl NameTemplate {
+    fn new(template: Option<String>, max_name_bytes: Option<u32>) -> anyhow::Result<Self> {
+        let template = template
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        if let Some(template) = template.as_deref() {
+            validate_template(template).map_err(anyhow::Error::msg)?;
+        }
+        let max_name_bytes = max_name_bytes
+            .map(|n| n as usize)
+            .unwrap_or(filename_template::DEFAULT_MAX_NAME_BYTES)
+            .clamp(
+                filename_template::MIN_MAX_NAME_BYTES,
+                filename_template::DEFAULT_MAX_NAME_BYTES,
+            );
+        Ok(Self {
+            custom: template.is_some(),
+            template: template.unwrap_or_else(|| filename_template::DEFAULT_TEMPLATE.to_string()),
+            max_name_bytes,
+        })
+    }
+
+    /// 渲染为路径各段（最后一段为文件名，不含扩展名）
+    fn render(
+        &self,
+        values: &HashMap<&str, String>,
+        ext: &str,
+        fallback: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        render_template(&self.template, values, ext, self.max_name_bytes, fallback)
+            .map_err(anyhow::Error::msg)
+    }
+
+    /// 清理单段目录名（分组目录等）
+    fn segment(&self, input: &str) -> String {
+        sanitize_segment(input, self.max_name_bytes).unwrap_or_else(|| "_".to_string())
+    }
+}
+
+/// 文件夹 ID → 名称
+fn folder_names() -> HashMap<String, String> {
+    storage::get_download_folders()
+        .map(|folders| folders.into_iter().map(|f| (f.id, f.name)).collect())
+        .unwrap_or_default()
+}
+
+/// 模板占位符取值
+fn template_values(
+    record: &storage::DownloadRecord,
+    folders: &HashMap<String, String>,
+) -> HashMap<&'static str, String> {
+    let quality = record
+        .source_quality
+        .clone()
+        .or_else(|| record.quality.clone())
+        .filter(|q| !q.trim().is_empty())
+        .unwrap_or_default();
+    HashMap::from([
+        (
+            "author",
+            record
+                .author_name
+                .clone()
+                .filter(|a| !a.trim().is_empty())
+                .unwrap_or_else(|| "Unknown".to_string()),
+        ),
+        ("title", record.title.clone()),
+        (
+            "chinese_title",
+            record.chinese_title.clone().unwrap_or_default(),
+        ),
+        ("video_id", record.video_id.clone()),
+        ("quality", quality),
+        (
+            "upload_date",
+            record.upload_date.clone().unwrap_or_default(),
+        ),
+        (
+            "folder",
+            record
+                .folder_id
+                .as_ref()
+                .and_then(|id| folders.get(id))
+                .cloned()
+                .unwrap_or_default(),
+        ),
+        ("series", record.series_name.clone().unwrap_or_default()),
+        (
+            "episode",
+            record
+                .series_episode
+                .map(|e| e.to_string())
+                .unwrap_or_default(),
+        ),
+    ])
+}
+
+/// 单个视频的导出目标
+struct ExportTarget {
+    media_path: PathBuf,
+    /// 目标位置已有相同文件（重复导出时跳过复制）
+    identical_exists: bool,
+    /// 媒体库布局下的 sidecar；普通模式为空
+    sidecars: Option<Sidecars>,
+}
+
+struct Sidecars {
+    nfo_path: PathBuf,
+    /// 剧集（episodedetails）或电影（movie）
+    episode: Option<i32>,
+    /// 不含扩展名（写入为 JPEG，见 `write_jpeg`）
+    poster_path: PathBuf,
+    /// 作者头像目录（Kodi 约定的 `.actors`）
+    actors_dir: PathBuf,
+    /// 系列目录（需要生成 tvshow.nfo / poster）
+    show_dir: Option<PathBuf>,
+    /// 作者目录（需要生成 folder.jpg）
+    artist_dir: Option<PathBuf>,
+}
+
+/// 进度上报（每个任务结束时 `done_files` 加一）
+struct ExportReporter {
+    sink: StreamSink<ApiExportProgress>,
+    job_id: String,
+    total_files: u32,
+    done_files: u32,
+    skipped_files: u32,
+}
+
+impl ExportReporter {
+    fn progress(
+        &self,
+        current_file: Option<String>,
+        current: u64,
+        total: u64,
+    ) -> ApiExportProgress {
+        ApiExportProgress {
+            job_id: self.job_id.clone(),
+            total_files: self.total_files,
+            done_files: self.done_files,
+            skipped_files: self.skipped_files,
+            current_file,
+            current_bytes: current,
+            current_total_bytes: total,
+            done: false,
+            error: None,
+        }
+    }
+
+    fn send(&self, current_file: Option<String>, current: u64, total: u64, error: Option<String>) {
+        let _ = self.sink.add(ApiExportProgress {
+            error,
+            ..self.progress(current_file, current, total)
+        });
+    }
+
+    fn finish_file(&mut self, name: String, current: u64, total: u64, error: Option<String>) {
+        self.done_files += 1;
+        self.send(Some(name), current, total, error);
+    }
+
+    fn skip_file(&mut self, name: String, total: u64) {
+        self.skipped_files += 1;
+        self.finish_file(name, total, total, None);
+    }
+
+    fn done(&self, error: Option<String>) {
+        let _ = self.sink.add(ApiExportProgress {
+            done: true,
+            error,
+            ..self.progress(None, 0, 0)
+        });
+    }
+}
+
+/// 导出任务控制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportControl {
+    Running,
+    Paused,
+    Canceled,
+}
+
+fn export_jobs() -> &'static Mutex<HashMap<String, watch::Sender<ExportControl>>> {
+    static MAP: OnceLock<Mutex<HashMap<String, watch::Sender<ExportControl>>>> = OnceLock::new();
+    MAP.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+async fn send_export_control(job_id: &str, control: ExportControl) -> bool {
+    match export_jobs().lock().await.get(job_id) {
+        Some(tx) => tx.send(control).is_ok(),
+        None => false,
+    }
+}
+
+/// 暂停导出任务（当前文件写入在下一个数据块处暂停，`.part` 保留）
+#[frb]
+pub async fn pause_export_job(job_id: String) -> anyhow::Result<bool> {
+    Ok(send_export_control(&job_id, ExportControl::Paused).await)
+}
+
+/// 继续导出任务
+#[frb]
+pub async fn resume_export_job(job_id: String) -> anyhow::Result<bool> {
+    Ok(send_export_control(&job_id, ExportControl::Running).await)
+}
+
+/// 取消导出任务（删除当前文件的 `.part`，已完成的文件保留）
+#[frb]
+pub async fn cancel_export_job(job_id: String) -> anyhow::Result<bool> {
+    Ok(send_export_control(&job_id, ExportControl::Canceled).await)
+}
+
+/// 获取进行中的导出任务 ID
+#[frb]
+pub async fn get_active_export_jobs() -> anyhow::Result<Vec<String>> {
+    Ok(export_jobs().lock().await.keys().cloned().collect())
+}
+
+/// 暂停时等待继续；返回 false 表示已取消
+async fn wait_while_paused(ctrl_rx: &mut watch::Receiver<ExportControl>) -> bool {
+    loop {
+        let control = *ctrl_rx.borrow_and_update();
+        match control {
+            ExportControl::Running => return true,
+            ExportControl::Canceled => return false,
+            ExportControl::Paused => {}
+        }
+        if ctrl_rx.changed().await.is_err() {
+            return *ctrl_rx.borrow() != ExportControl::Canceled;
+        }
+    }
+}
+
+/// 导出布局参数
+struct ExportPlan {
+    media_server_layout: bool,
+    group_by: GroupBy,
+    naming: NameTemplate,
+}
+
+/// 启动导出任务，任务 ID 通过第一条进度事件返回
+pub(crate) fn run_export(
+    task_ids: Vec<String>,
+    dest_dir: PathBuf,
+    options: ApiExportOptions,
+    sink: StreamSink<ApiExportProgress>,
+) {
+    runtime::spawn(async move {
+        let job_id = Uuid::new_v4().to_string();
+        let mut reporter = ExportReporter {
+            sink,
+            job_id: job_id.clone(),
+            total_files: task_ids.len() as u32,
+            done_files: 0,
+            skipped_files: 0,
+        };
+        let naming = match NameTemplate::new(options.filename_template, options.max_name_bytes) {
+            Ok(n) => n,
+            Err(e) => {
+                reporter.done(Some(format!("Invalid filename template: {e}")));
+                return;
+            }
+        };
+        let plan = ExportPlan {
+            media_server_layout: options.media_server_layout,
+            group_by: GroupBy::parse(&options.group_by),
+            naming,
+        };
+
+        let _library = match library_use::acquire() {
+            Ok(library) => library,
+            Err(e) => {
+                reporter.done(Some(e.to_string()));
+                return;
+            }
+        };
+
+        if let Err(e) = tokio::fs::create_dir_all(&dest_dir).await {
+            reporter.done(Some(format!("Create dir failed: {e}")));
+            return;
+        }
+
+        let (tx, mut ctrl_rx) = watch::channel(ExportControl::Running);
+        export_jobs().lock().await.insert(job_id.clone(), tx);
+        reporter.send(None, 0, 0, None);
+
+        let canceled = run_export_job(
+            task_ids,
+            &dest_dir,
+            &plan,
+            options.move_files,
+            &mut reporter,
+            &mut ctrl_rx,
+        )
+        .await;
+
+        let _ = export_jobs().lock().await.remove(&job_id);
+        reporter.done(canceled.then(|| "Canceled".to_string()));
+    });
+}
+
+/// 逐个导出；返回 true 表示被取消
+async fn run_export_job(
+    task_ids: Vec<String>,
+    dest_dir: &Path,
+    plan: &ExportPlan,
+    move_files: bool,
+    reporter: &mut ExportReporter,
+    ctrl_rx: &mut watch::Receiver<ExportControl>,
+) -> bool {
+    let folders = folder_names();
+
+    for task_id in task_ids {
+        if !wait_while_paused(ctrl_rx).await {
+            return true;
+        }
+
+        let record = match storage::get_download_by_video_id(&task_id) {
+            Ok(Some(r)) => r,
+            _ => {
+                reporter.finish_file(task_id, 0, 0, Some("Download record not found".into()));
+                continue;
+            }
+        };
+
+        if record.status != storage::DownloadStatus::Completed {
+            reporter.finish_file(record.title, 0, 0, Some("Not completed".to_string()));
+            continue;
+        }
+
+        let Some(src_name) = record.save_path.as_deref().and_then(basename) else {
+            reporter.finish_file(record.title, 0, 0, Some("Missing local file".to_string()));
+            continue;
+        };
+        let src_path = match resolve_download_path(&src_name) {
+            Ok(p) => p,
+            Err(e) => {
+                let error = format!("Resolve path failed: {e}");
+                reporter.finish_file(record.title, 0, 0, Some(error));
+                continue;
+            }
+        };
+        let total_bytes = match tokio::fs::metadata(&src_path).await {
+            Ok(m) => m.len(),
+            Err(e) => {
+                reporter.finish_file(record.title, 0, 0, Some(format!("Stat failed: {e}")));
+                continue;
+            }
+        };
+
+        let ext = src_path
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("mp4")
+            .to_string();
+        let values = template_values(&record, &folders);
+        let target = match plan
+            .plan_target(dest_dir, &record, &src_path, total_bytes, &ext, &values)
+            .await
+        {
+            Ok(t) => t,
+            Err(e) => {
+                let error = format!("Prepare target failed: {e}");
+                reporter.finish_file(record.title, 0, 0, Some(error));
+                continue;
+            }
+        };
+
+        let display_name = target
+            .media_path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| record.title.clone());
+
+        if !target.identical_exists {
+            reporter.send(Some(display_name.clone()), 0, total_bytes, None);
+            let copied = copy_file_resumable(
+                &src_path,
+                &target.media_path,
+                total_bytes,
+                ctrl_rx,
+                |copied| reporter.send(Some(display_name.clone()), copied, total_bytes, None),
+            )
+            .await;
+            match copied {
+                Ok(true) => {}
+                Ok(false) => {
+                    let _ = tokio::fs::remove_file(part_path(&target.media_path)).await;
+                    return true;
+                }
+                Err(e) => {
+                    reporter.finish_file(display_name, 0, total_bytes, Some(e.to_string()));
+                    continue;
+                }
+            }
+        }
+
+        let error = match target.sidecars.as_ref() {
+            Some(sidecars) => write_sidecars(&record, &target.media_path, sidecars)
+                .await
+                .err()
+                .map(|e| format!("Write metadata failed: {e}")),
+            None => None,
+        };
+        let (error, copied_subtitles) = match error {
+            None => match copy_subtitles(&record.video_id, &target.media_path).await {
+                Ok(copied) => (None, copied),
+                Err(e) => (Some(format!("Copy subtitles failed: {e}")), vec![]),
+            },
+            other => (other, vec![]),
+        };
+
+        // 移动模式：目标文件与元数据都已写好后再删除已导出的本地文件
+        let error = match error {
+            None if move_files => finish_move(
+                &record.video_id,
+                &src_path,
+                &copied_subtitles,
+                &target.media_path,
+            )
+            .await
+            .err()
+            .map(|e| format!("Remove local copy failed: {e}")),
+            other => other,
+        };
+
+        if target.identical_exists && error.is_none() {
+            reporter.skip_file(display_name, total_bytes);
+        } else {
+            reporter.finish_file(display_name, total_bytes, total_bytes, error);
+        }
+    }
+    false
+}
+
+impl ExportPlan {
+    /// 计算导出路径并创建所需目录；目标已存在相同文件时复用该路径
+    async fn plan_target(
+        &self,
+        dest_dir: &Path,
+        record: &storage::DownloadRecord,
+        src: &Path,
+        src_size: u64,
+        ext: &str,
+        values: &HashMap<&str, String>,
+    ) -> anyhow::Result<ExportTarget> {
+        let (group_by, naming) = (self.group_by, &self.naming);
+        let ext = sanitize_filename(ext);
+        let series = record
+            .series_name
+            .as_deref()
+            .filter(|s| !s.trim().is_empty());
+
+        let group_dir = match group_by {
+            GroupBy::None => dest_dir.to_path_buf(),
+            GroupBy::Artist => dest_dir.join(naming.segment(&values["author"])),
+            GroupBy::Series => match series {
+                Some(series) => dest_dir.join(naming.segment(series)),
+                None => dest_dir.to_path_buf(),
+            },
+        };
+
+        // 模板中 `/` 之前的部分作为子目录，最后一段为文件名
+        let mut segments = naming.render(values, &ext, &record.video_id)?;
+        let stem = segments.pop().unwrap_or_else(|| record.video_id.clone());
+        let base_dir = segments
+            .iter()
+            .fold(group_dir.clone(), |dir, segment| dir.join(segment));
+        tokio::fs::create_dir_all(&base_dir).await?;
+
+        if !self.media_server_layout {
+            let (media_path, identical_exists) = choose_media_path(
+                base_dir.join(format!("{stem}.{ext}")),
+                src,
+                src_size,
+                naming.max_name_bytes,
+            )
+            .await;
+            return Ok(ExportTarget {
+                media_path,
+                identical_exists,
+                sidecars: None,
+            });
+        }
+
+        // 剧集：系列目录下平铺，`{名称}.nfo` / `{名称}-thumb.jpg` 与视频同名
+        if group_by == GroupBy::Series && series.is_some() {
+            let episode_stem = if naming.custom {
+                stem
+            } else {
+                naming.segment(&record.title)
+            };
+            tokio::fs::create_dir_all(&group_dir).await?;
+            let (media_path, identical_exists) = choose_media_path(
+                group_dir.join(format!("{episode_stem}.{ext}")),
+                src,
+                src_size,
+                naming.max_name_bytes,
+            )
+            .await;
+            let file_stem = media_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or(&episode_stem)
+                .to_string();
+            return Ok(ExportTarget {
+                sidecars: Some(Sidecars {
+                    nfo_path: group_dir.join(format!("{file_stem}.nfo")),
+                    episode: Some(record.series_episode.unwrap_or(0)),
+                    poster_path: group_dir.join(format!("{file_stem}-thumb")),
+                    actors_dir: group_dir.join(".actors"),
+                    show_dir: Some(group_dir.clone()),
+                    artist_dir: None,
+                }),
+                media_path,
+                identical_exists,
+            });
+        }
+
+        // 电影：每个视频一个目录，目录中已有其他视频时追加视频 ID 区分
+        // （只有 `.part` 的目录视为上次中断的导出，继续使用）
+        let file_name = format!("{stem}.{ext}");
+        let mut movie_dir = base_dir.join(&stem);
+        let existing = movie_dir.join(&file_name);
+        if existing.exists() && !files_identical(src, &existing, src_size).await {
+            movie_dir = base_dir.join(naming.segment(&format!("{stem} [{}]", record.video_id)));
+        }
+        tokio::fs::create_dir_all(&movie_dir).await?;
+        let (media_path, identical_exists) = choose_media_path(
+            movie_dir.join(&file_name),
+            src,
+            src_size,
+            naming.max_name_bytes,
+        )
+        .await;
+        Ok(ExportTarget {
+            media_path,
+            identical_exists,
+            sidecars: Some(Sidecars {
+                nfo_path: movie_dir.join("movie.nfo"),
+                episode: None,
+                poster_path: movie_dir.join("poster"),
+                actors_dir: movie_dir.join(".actors"),
+                show_dir: None,
+                artist_dir: (group_by == GroupBy::Artist).then_some(group_dir),
+            }),
+        })
+    }
+}
+
+/// 目标不存在时直接使用；已存在相同文件时复用（跳过复制）；否则追加序号
+async fn choose_media_path(
+    desired: PathBuf,
+    src: &Path,
+    src_size: u64,
+    max_name_bytes: usize,
+) -> (PathBuf, bool) {
+    if !desired.exists() {
+        return (desired, false);
+    }
+    if files_identical(src, &desired, src_size).await {
+        return (desired, true);
+    }
+    (uniquify_path(desired, max_name_bytes), false)
+}
+
+/// 判断两个文件是否相同：大小一致，且首 / 中 / 尾各 1MB 内容一致
+async fn files_identical(src: &Path, dst: &Path, size: u64) -> bool {
+    let (src, dst) = (src.to_path_buf(), dst.to_path_buf());
+    tokio::task::spawn_blocking(move || compare_samples(&src, &dst, size).unwrap_or(false))
+        .await
+        .unwrap_or(false)
+}
+
+pub(crate) fn compare_samples(src: &Path, dst: &Path, size: u64) -> std::io::Result<bool> {
+    if !dst.is_file() || std::fs::metadata(dst)?.len() != size {
+        return Ok(false);
+    }
+    prefix_samples_equal(src, dst, size)
+}
+
+/// 比较两个文件前 `len` 字节的首 / 中 / 尾各 1MB 内容
+fn prefix_samples_equal(src: &Path, dst: &Path, len: u64) -> std::io::Result<bool> {
+    use std::io::{Read, Seek, SeekFrom};
+    const SAMPLE: u64 = 1024 * 1024;
+    let mut a = std::fs::File::open(src)?;
+    let mut b = std::fs::File::open(dst)?;
+    let sample = SAMPLE.min(len) as usize;
+    let (mut buf_a, mut buf_b) = (vec![0u8; sample], vec![0u8; sample]);
+    for offset in [
+        0,
+        len.saturating_sub(SAMPLE) / 2,
+        len.saturating_sub(SAMPLE),
+    ] {
+        a.seek(SeekFrom::Start(offset))?;
+        b.seek(SeekFrom::Start(offset))?;
+        a.read_exact(&mut buf_a)?;
+        b.read_exact(&mut buf_b)?;
+        if buf_a != buf_b {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// 写入 NFO 与图片（poster / folder 统一为 `.jpg`，作者头像沿用原扩展名）
+async fn write_sidecars(
+    record: &storage::DownloadRecord,
+    media_path: &Path,
+    sidecars: &Sidecars,
+) -> anyhow::Result<()> {
+    let cover = record
+        .cover_path
+        .as_deref()
+        .and_then(basename)
+        .and_then(|name| resolve_under_downloads_root("download_covers", &name).ok())
+        .filter(|p| p.exists());
+    let avatar = record
+        .author_avatar_path
+        .as_deref()
+        .and_then(basename)
+        .and_then(|name| resolve_under_downloads_root("download_avatars", &name).ok())
+        .filter(|p| p.exists());
+
+    let poster_name = match cover.as_deref() {
+        Some(cover) => write_jpeg(cover, &sidecars.poster_path)
+            .await?
+            .file_name()
+            .map(|s| s.to_string_lossy().into_owned()),
+        None => None,
+    };
+
+    let actor_thumb = match (avatar.as_deref(), record.author_name.as_deref()) {
+        (Some(avatar), Some(author)) => {
+            tokio::fs::create_dir_all(&sidecars.actors_dir).await?;
+            let dst = with_image_ext(
+                &sidecars
+                    .actors_dir
+                    .join(sanitize_filename(author).replace(' ', "_")),
+                avatar,
+            );
+            tokio::fs::copy(avatar, &dst).await?;
+            dst.file_name()
+                .map(|s| format!(".actors/{}", s.to_string_lossy()))
+        }
+        _ => None,
+    };
+
+    let media_name = media_path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let nfo = build_nfo(
+        record,
+        sidecars.episode,
+        poster_name.as_deref(),
+        actor_thumb.as_deref(),
+        &media_name,
+    );
+    tokio::fs::write(&sidecars.nfo_path, nfo).await?;
+
+    if let Some(show_dir) = sidecars.show_dir.as_deref() {
+        let show_poster = match cover.as_deref() {
+            Some(cover) => {
+                let poster = with_ext(&show_dir.join("poster"), "jpg");
+                if poster.exists() {
+                    Some(poster)
+                } else {
+                    Some(write_jpeg(cover, &show_dir.join("poster")).await?)
+                }
+            }
+            None => None,
+        };
+        let tvshow_nfo = show_dir.join("tvshow.nfo");
+        if !tvshow_nfo.exists() {
+            let poster_name = show_poster
+                .as_deref()
+                .and_then(|p| p.file_name())
+                .map(|s| s.to_string_lossy().into_owned());
+            tokio::fs::write(
+                &tvshow_nfo,
+                build_tvshow_nfo(record, poster_name.as_deref()),
+            )
+            .await?;
+        }
+    }
+    if let (Some(artist_dir), Some(avatar)) = (sidecars.artist_dir.as_deref(), avatar.as_deref()) {
+        if !with_ext(&artist_dir.join("folder"), "jpg").exists() {
+            write_jpeg(avatar, &artist_dir.join("folder")).await?;
+        }
+    }
+    Ok(())
+}
+
+/// 移动完成：只删除已写到目标的媒体与字幕源文件，记录标记为 `Missing` 并注明去向；
+/// 封面、变体文件与记录（文件夹、历史关联）保留
+async fn finish_move(
+    video_id: &str,
+    media_path: &Path,
+    subtitles: &[PathBuf],
+    moved_to: &Path,
+) -> anyhow::Result<()> {
+    tokio::fs::remove_file(media_path).await?;
+    for path in subtitles {
+        let _ = tokio::fs::remove_file(path).await;
+    }
+    let note = format!("Moved to {}", moved_to.display());
+    storage::update_download_status(video_id, storage::DownloadStatus::Missing, Some(&note))?;
+    notify_download_changed(video_id);
+    Ok(())
+}
+
+/// 字幕与视频同名放在同一目录：`{视频名}.{语言}.srt`（媒体服务器可自动识别）
+///
+/// 返回已复制的字幕源文件
+async fn copy_subtitles(video_id: &str, media_path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let subtitles = download_subtitles::local_subtitles(video_id);
+    if subtitles.is_empty() {
+        return Ok(vec![]);
+    }
+    let dir = media_path.parent().unwrap_or(Path::new("."));
+    let stem = media_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| video_id.to_string());
+    let mut used = HashSet::new();
+    let mut copied = Vec::new();
+    for (index, (subtitle, src)) in subtitles.into_iter().enumerate() {
+        let language = sanitize_filename(&subtitle.language);
+        let base = if language.is_empty() {
+            stem.clone()
+        } else {
+            format!("{stem}.{language}")
+        };
+        let mut name = format!("{base}.{}", subtitle.format);
+        // 同一语言有多条字幕时追加序号
+        if !used.insert(name.clone()) {
+            name = format!("{base}.{}.{}", index + 1, subtitle.format);
+        }
+        tokio::fs::copy(&src, dir.join(name)).await?;
+        copied.push(src);
+    }
+    Ok(copied)
+}
+
+/// 将图片写为 `{base}.jpg`（媒体服务器只识别 `poster.jpg` / `folder.jpg`），返回实际路径
+///
+/// JPEG 直接复制，PNG / WebP 转码；无法解码时退回按原扩展名复制
+async fn write_jpeg(src: &Path, base: &Path) -> anyhow::Result<PathBuf> {
+    let dst = with_ext(base, "jpg");
+    let (from, to) = (src.to_path_buf(), dst.clone());
+    let written = tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+        let reader = image::ImageReader::open(&from)?.with_guessed_format()?;
+        if reader.format() == Some(image::ImageFormat::Jpeg) {
+            std::fs::copy(&from, &to)?;
+            return Ok(());
+        }
+        // JPEG 不支持透明通道
+        let rgb = reader.decode()?.to_rgb8();
+        rgb.save_with_format(&to, image::ImageFormat::Jpeg)?;
+        Ok(())
+    })
+    .await?;
+    match written {
+        Ok(()) => Ok(dst),
+        Err(e) => {
+            tracing::warn!(
+                "export image_transcode_failed src={} error={}",
+                src.display(),
+                e
+            );
+            let _ = tokio::fs::remove_file(&dst).await;
+            let fallback = with_image_ext(base, src);
+            tokio::fs::copy(src, &fallback).await?;
+            Ok(fallback)
+        }
+    }
+}
+
+fn with_image_ext(path: &Path, src: &Path) -> PathBuf {
+    with_ext(
+        path,
+        src.extension().and_then(|s| s.to_str()).unwrap_or("jpg"),
+    )
+}
+
+fn with_ext(path: &Path, ext: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(ext);
+    PathBuf::from(name)
+}
+
+/// 电影（movie）或剧集（episodedetails）NFO
+fn build_nfo(
+    record: &storage::DownloadRecord,
+    episode: Option<i32>,
+    poster: Option<&str>,
+    actor_thumb: Option<&str>,
+    media_name: &str,
+) -> String {
+    let root = if episode.is_some() {
+        "episodedetails"
+    } else {
+        "movie"
+    };
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\n");
+    xml.push_str(&format!("<{root}>\n"));
+    push_tag(&mut xml, "title", &record.title);
+    if let Some(episode) = episode {
+        if let Some(series) = record.series_name.as_deref() {
+            push_tag(&mut xml, "showtitle", series);
+        }
+        push_tag(&mut xml, "season", "1");
+        push_tag(&mut xml, "episode", &episode.to_string());
+    }
+    if let Some(plot) = record
+        .description
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+    {
+        push_tag(&mut xml, "plot", plot.trim());
+    }
+    if let Some(date) = record.upload_date.as_deref().filter(|s| !s.is_empty()) {
+        push_tag(&mut xml, "premiered", date);
+        if episode.is_some() {
+            push_tag(&mut xml, "aired", date);
+        }
+        if let Some(year) = date.get(..4) {
+            push_tag(&mut xml, "year", year);
+        }
+    }
+    if let Some(author) = record.author_name.as_deref() {
+        push_tag(&mut xml, "studio", author);
+        xml.push_str("  <actor>\n");
+        xml.push_str(&format!("    <name>{}</name>\n", xml_escape(author)));
+        xml.push_str("    <role>Artist</role>\n");
+        if let Some(thumb) = actor_thumb {
+            xml.push_str(&format!("    <thumb>{}</thumb>\n", xml_escape(thumb)));
+        }
+        xml.push_str("  </actor>\n");
+    }
+    for tag in &record.tags {
+        push_tag(&mut xml, "tag", tag);
+    }
+    if let Some(poster) = poster {
+        xml.push_str(&format!(
+            "  <thumb aspect=\"poster\">{}</thumb>\n",
+            xml_escape(poster)
+        ));
+    }
+    xml.push_str(&format!(
+        "  <uniqueid type=\"hanime1\" default=\"true\">{}</uniqueid>\n",
+        xml_escape(&record.video_id)
+    ));
+    xml.push_str(&format!(
+        "  <original_filename>{}</original_filename>\n",
+        xml_escape(media_name)
+    ));
+    xml.push_str(&format!("</{root}>\n"));
+    xml
+}
+
+fn build_tvshow_nfo(record: &storage::DownloadRecord, poster: Option<&str>) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\n");
+    xml.push_str("<tvshow>\n");
+    push_tag(
+        &mut xml,
+        "title",
+        record.series_name.as_deref().unwrap_or(&record.title),
+    );
+    if let Some(author) = record.author_name.as_deref() {
+        push_tag(&mut xml, "studio", author);
+    }
+    if let Some(poster) = poster {
+        xml.push_str(&format!(
+            "  <thumb aspect=\"poster\">{}</thumb>\n",
+            xml_escape(poster)
+        ));
+    }
+    xml.push_str("</tvshow>\n");
+    xml
+}
+
+fn push_tag(xml: &mut String, name: &str, value: &str) {
+    xml.push_str(&format!("  <{name}>{}</{name}>\n", xml_escape(value)));
+}
+
+fn xml_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // XML 1.0 不允许的控制字符
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn uniquify_path(path: PathBuf, max_name_bytes: usize) -> PathBuf {
+    if !path.exists() {
+        return path;
+    }
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("file")
+        .to_string();
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let dir = path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+
+    for i in 1..10_000 {
+        let candidate = dir.join(numbered_name(&stem, ext, i, max_name_bytes));
+        if !candidate.exists() {
+            return candidate;
+        }
+    }
+    path
+}
+
+/// 未完成文件的临时路径（`{name}.part`）
+fn part_path(dst: &Path) -> PathBuf {
+    let mut name = dst.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// 先写入 `.part`，完成后重命名；已有 `.part` 且内容与源文件开头一致时从其末尾续写
+///
+/// 返回 false 表示被取消（`.part` 由调用方处理）
+async fn copy_file_resumable(
+    src: &Path,
+    dst: &Path,
+    total: u64,
+    ctrl_rx: &mut watch::Receiver<ExportControl>,
+    mut on_progress: impl FnMut(u64),
+) -> anyhow::Result<bool> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+    let part = part_path(dst);
+    let resume_from = match tokio::fs::metadata(&part).await {
+        Ok(m) if m.len() <= total => m.len(),
+        _ => 0,
+    };
+    // 同名 `.part` 可能来自其他视频（例如同名导出）：抽样比对后才续写
+    let resume_from = if resume_from > 0 {
+        let (src_path, part_path) = (src.to_path_buf(), part.clone());
+        let same = tokio::task::spawn_blocking(move || {
+            prefix_samples_equal(&src_path, &part_path, resume_from).unwrap_or(false)
+        })
+        .await
+        .unwrap_or(false);
+        if same {
+            resume_from
+        } else {
+            0
+        }
+    } else {
+        0
+    };
+
+    let mut in_f = tokio::fs::File::open(src).await?;
+    let mut out_f = if resume_from > 0 {
+        in_f.seek(std::io::SeekFrom::Start(resume_from)).await?;
+        tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(&part)
+            .await?
+    } else {
+        tokio::fs::File::create(&part).await?
+    };
+
+    let mut buf = vec![0u8; 1024 * 1024];
+    let mut copied = resume_from;
+    if copied > 0 {
+        on_progress(copied);
+    }
+    loop {
+        if *ctrl_rx.borrow() != ExportControl::Running {
+            out_f.flush().await?;
+            if !wait_while_paused(ctrl_rx).await {
+                return Ok(false);
+            }
+        }
+        let n = in_f.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        out_f.write_all(&buf[..n]).await?;
+        copied += n as u64;
+        if total == 0 || copied == total || (copied % (4 * 1024 * 1024) < n as u64) {
+            on_progress(copied);
+        }
+    }
+    out_f.flush().await?;
+    out_f.sync_all().await?;
+    drop(out_f);
+
+    if copied != total {
+        anyhow::bail!("Copied {} bytes, expected {}", copied, total);
+    }
+    tokio::fs::rename(&part, dst).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_move_keeps_variant_files() {
+        let root = std::env::temp_dir().join(format!("hibiscus_move_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&root).unwrap();
+        storage::init_db(Some(root.join("data.db").to_str().unwrap())).unwrap();
+        storage::save_setting(storage::DOWNLOADS_ROOT_KEY, root.to_str().unwrap()).unwrap();
+
+        let video_id = "move_v1";
+        storage::add_download(
+            video_id,
+            video_id,
+            "",
+            "",
+            "480P",
+            None,
+            &[],
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        storage::update_download_save_path(video_id, "move_v1_480P.mp4").unwrap();
+        storage::update_download_status(video_id, storage::DownloadStatus::Completed, None)
+            .unwrap();
+        let variant = storage::create_download_variant(video_id, "720P", true, true).unwrap();
+        storage::update_download_variant_save_path(variant, "move_v1_720P.mp4").unwrap();
+
+        let media = resolve_download_path("move_v1_480P.mp4").unwrap();
+        let variant_file = resolve_download_path("move_v1_720P.mp4").unwrap();
+        let subtitle =
+            resolve_under_downloads_root("download_subtitles", "move_v1.en.srt").unwrap();
+        for path in [&media, &variant_file, &subtitle] {
+            std::fs::write(path, b"data").unwrap();
+        }
+
+        let dest = root.join("export").join("move_v1.mp4");
+        finish_move(video_id, &media, std::slice::from_ref(&subtitle), &dest)
+            .await
+            .unwrap();
+
+        assert!(!media.exists());
+        assert!(!subtitle.exists());
+        assert!(variant_file.exists());
+        let record = storage::get_download_by_video_id(video_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.status, storage::DownloadStatus::Missing);
+        assert_eq!(
+            record.error_message,
+            Some(format!("Moved to {}", dest.display()))
+        );
+        assert_eq!(
+            storage::get_download_variants(Some(video_id))
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // 全局连接可能指向该目录下的数据库，只清理文件目录
+        for dir in ["downloads", "download_subtitles"] {
+            let _ = std::fs::remove_dir_all(root.join(dir));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poster_written_as_jpeg() {
+        let dir = std::env::temp_dir().join(format!("hibiscus_poster_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cover = dir.join("cover.png");
+        image::RgbaImage::from_pixel(4, 4, image::Rgba([200, 100, 50, 128]))
+            .save(&cover)
+            .unwrap();
+
+        let poster = write_jpeg(&cover, &dir.join("poster")).await.unwrap();
+        assert_eq!(poster, dir.join("poster.jpg"));
+        let format = image::ImageReader::open(&poster)
+            .unwrap()
+            .with_guessed_format()
+            .unwrap()
+            .format();
+        assert_eq!(format, Some(image::ImageFormat::Jpeg));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,36 +1,75 @@
 // 下载文件夹管理 API
 // 文件夹仅用于过滤分类，删除文件夹不影响已下载的视频
+// - 文件夹可嵌套（parent_id），同级之间可手动排序，可指定封面视频
+// - 智能文件夹由保存的规则在 SQL 中求值，不能手动移入视频，也不能包含子文件夹
 
-use crate::api::models::ApiDownloadFolder;
-use crate::core::storage;
+use crate::api::download::map_record;
+use crate::api::models::{ApiDownloadFolder, ApiDownloadTask, ApiSmartFolderRule};
+use crate::core::storage::{self, DownloadFilter, DownloadFolderRecord};
 use flutter_rust_bridge::frb;
+use std::collections::HashSet;
 use uuid::Uuid;
 
-/// 获取所有下载文件夹
+/// 获取所有下载文件夹（按同级排序，界面根据 parent_id 组装树）
 #[frb]
 pub async fn get_download_folders() -> anyhow::Result<Vec<ApiDownloadFolder>> {
     let records = storage::get_download_folders()?;
-    Ok(records
-        .into_iter()
-        .map(|r| ApiDownloadFolder {
-            id: r.id,
-            name: r.name,
-            created_at: r.created_at,
-        })
-        .collect())
+    let counts = storage::count_downloads_by_folder()?;
+    let mut folders = Vec::with_capacity(records.len());
+    for record in records {
+        let video_count = match parse_rule(&record)? {
+            Some(filter) => storage::count_downloads_by_filter(&filter)?,
+            None => counts.get(&record.id).copied().unwrap_or(0),
+        };
+        folders.push(map_folder(record, video_count));
+    }
+    Ok(folders)
 }
 
-/// 创建下载文件夹
+/// 创建下载文件夹（`parent_id` 为空时创建在顶层）
 #[frb]
-pub async fn create_download_folder(name: String) -> anyhow::Result<ApiDownloadFolder> {
-    let id = Uuid::new_v4().to_string();
-    let now = chrono::Utc::now().timestamp();
-    storage::create_download_folder(&id, &name)?;
-    Ok(ApiDownloadFolder {
-        id,
-        name,
-        created_at: now,
-    })
+pub async fn create_download_folder(
+    name: String,
+    parent_id: Option<String>,
+) -> anyhow::Result<ApiDownloadFolder> {
+    create_folder(name, parent_id, None)
+}
+
+/// 创建智能文件夹
+#[frb]
+pub async fn create_smart_folder(
+    name: String,
+    parent_id: Option<String>,
+    rule: ApiSmartFolderRule,
+) -> anyhow::Result<ApiDownloadFolder> {
+    let filter = to_filter(rule);
+    let rule_json = serde_json::to_string(&filter)?;
+    let mut folder = create_folder(name, parent_id, Some(&rule_json))?;
+    folder.video_count = storage::count_downloads_by_filter(&filter)?;
+    Ok(folder)
+}
+
+/// 获取智能文件夹规则
+#[frb]
+pub async fn get_smart_folder_rule(folder_id: String) -> anyhow::Result<ApiSmartFolderRule> {
+    let folder = find_folder(&folder_id)?;
+    let filter = parse_rule(&folder)?.ok_or_else(|| anyhow::anyhow!("Not a smart folder"))?;
+    Ok(from_filter(filter))
+}
+
+/// 更新智能文件夹规则
+#[frb]
+pub async fn update_smart_folder_rule(
+    folder_id: String,
+    rule: ApiSmartFolderRule,
+) -> anyhow::Result<bool> {
+    let folder = find_folder(&folder_id)?;
+    if !folder.is_smart() {
+        anyhow::bail!("Not a smart folder");
+    }
+    let rule_json = serde_json::to_string(&to_filter(rule))?;
+    storage::update_download_folder_rule(&folder_id, &rule_json)?;
+    Ok(true)
 }
 
 /// 重命名下载文件夹
@@ -40,19 +79,264 @@ pub async fn rename_download_folder(folder_id: String, name: String) -> anyhow::
     Ok(true)
 }
 
-/// 删除下载文件夹（视频不会被删除，仅清除视频的文件夹关联）
+/// 删除下载文件夹（视频不会被删除，仅清除视频的文件夹关联；子文件夹移到上一级）
 #[frb]
 pub async fn delete_download_folder(folder_id: String) -> anyhow::Result<bool> {
     storage::delete_download_folder(&folder_id)?;
     Ok(true)
 }
 
-/// 将视频移动到文件夹
+/// 移动文件夹到新的上级（`parent_id` 为空时移到顶层），排在新同级的末尾
+#[frb]
+pub async fn move_download_folder(
+    folder_id: String,
+    parent_id: Option<String>,
+) -> anyhow::Result<bool> {
+    let folders = storage::get_download_folders()?;
+    if !folders.iter().any(|f| f.id == folder_id) {
+        anyhow::bail!("Download folder not found");
+    }
+    let parent_id = parent_id.filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id.as_deref() {
+        check_parent(&folders, parent_id)?;
+        // 新上级不能是自身或自身的子孙
+        if descendant_ids(&folders, &folder_id).contains(parent_id) {
+            anyhow::bail!("Cannot move a folder into itself");
+        }
+    }
+    storage::update_download_folder_parent(&folder_id, parent_id.as_deref())?;
+    Ok(true)
+}
+
+/// 调整同级文件夹顺序
+///
+/// `folder_ids` 中的文件夹按给定顺序排在前面，未列出的同级文件夹保持原有相对顺序排在后面
+#[frb]
+pub async fn reorder_download_folders(
+    parent_id: Option<String>,
+    folder_ids: Vec<String>,
+) -> anyhow::Result<bool> {
+    let parent_id = parent_id.filter(|id| !id.is_empty());
+    let siblings = storage::get_download_folders()?
+        .into_iter()
+        .filter(|f| f.parent_id == parent_id)
+        .map(|f| f.id)
+        .collect::<Vec<_>>();
+    if let Some(id) = folder_ids.iter().find(|id| !siblings.contains(id)) {
+        anyhow::bail!("Folder {} is not under the given parent", id);
+    }
+
+    let mut seen = HashSet::new();
+    let ordered = folder_ids
+        .into_iter()
+        .chain(siblings)
+        .filter(|id| seen.insert(id.clone()))
+        .collect::<Vec<_>>();
+    storage::update_download_folders_order(&ordered)?;
+    Ok(true)
+}
+
+/// 设置文件夹封面（`video_id` 为空时恢复默认）
+#[frb]
+pub async fn set_download_folder_cover(
+    folder_id: String,
+    video_id: Option<String>,
+) -> anyhow::Result<bool> {
+    find_folder(&folder_id)?;
+    let video_id = video_id.filter(|id| !id.is_empty());
+    if let Some(video_id) = video_id.as_deref() {
+        if storage::get_download_by_video_id(video_id)?.is_none() {
+            anyhow::bail!("Download task not found");
+        }
+    }
+    storage::update_download_folder_cover(&folder_id, video_id.as_deref())?;
+    Ok(true)
+}
+
+/// 获取文件夹中的视频（智能文件夹按规则求值）
+///
+/// `include_subfolders` 为 true 时同时包含所有子孙文件夹中的视频（去重）
+#[frb]
+pub async fn get_folder_downloads(
+    folder_id: String,
+    include_subfolders: bool,
+) -> anyhow::Result<Vec<ApiDownloadTask>> {
+    let folders = storage::get_download_folders()?;
+    if !folders.iter().any(|f| f.id == folder_id) {
+        anyhow::bail!("Download folder not found");
+    }
+    let ids = if include_subfolders {
+        descendant_ids(&folders, &folder_id)
+    } else {
+        HashSet::from([folder_id.as_str()])
+    };
+
+    let mut manual = Vec::new();
+    let mut records = Vec::new();
+    for folder in folders.iter().filter(|f| ids.contains(f.id.as_str())) {
+        match parse_rule(folder)? {
+            Some(filter) => records.extend(storage::get_downloads_by_filter(&filter)?),
+            None => manual.push(folder.id.clone()),
+        }
+    }
+    records.extend(storage::get_downloads_in_folders(&manual)?);
+
+    let mut seen = HashSet::new();
+    records.retain(|r| seen.insert(r.video_id.clone()));
+    records.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(records.into_iter().map(map_record).collect())
+}
+
+/// 将视频移动到文件夹（智能文件夹不能手动移入）
 #[frb]
 pub async fn move_downloads_to_folder(
     video_ids: Vec<String>,
     folder_id: Option<String>,
 ) -> anyhow::Result<bool> {
+    if let Some(folder_id) = folder_id.as_deref() {
+        ensure_manual_folder(folder_id)?;
+    }
     storage::update_downloads_folder(&video_ids, folder_id.as_deref())?;
     Ok(true)
 }
+
+/// 确认文件夹存在且可以手动放入视频（非智能文件夹）
+pub(crate) fn ensure_manual_folder(folder_id: &str) -> anyhow::Result<()> {
+    if find_folder(folder_id)?.is_smart() {
+        anyhow::bail!("Cannot move videos into a smart folder");
+    }
+    Ok(())
+}
+
+/// 查询范围：普通文件夹为自身（及普通子孙文件夹）ID，智能文件夹为其规则
+pub(crate) fn folder_scope(
+    folder_id: &str,
+    include_subfolders: bool,
+) -> anyhow::Result<(Vec<String>, Option<DownloadFilter>)> {
+    let folders = storage::get_download_folders()?;
+    let folder = folders
+        .iter()
+        .find(|f| f.id == folder_id)
+        .ok_or_else(|| anyhow::anyhow!("Download folder not found"))?;
+    if let Some(filter) = parse_rule(folder)? {
+        return Ok((vec![], Some(filter)));
+    }
+    let ids = if include_subfolders {
+        let scope = descendant_ids(&folders, folder_id);
+        folders
+            .iter()
+            .filter(|f| scope.contains(f.id.as_str()) && !f.is_smart())
+            .map(|f| f.id.clone())
+            .collect()
+    } else {
+        vec![folder_id.to_string()]
+    };
+    Ok((ids, None))
+}
+
+fn create_folder(
+    name: String,
+    parent_id: Option<String>,
+    rule_json: Option<&str>,
+) -> anyhow::Result<ApiDownloadFolder> {
+    let parent_id = parent_id.filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id.as_deref() {
+        check_parent(&storage::get_download_folders()?, parent_id)?;
+    }
+    let id = Uuid::new_v4().to_string();
+    storage::create_download_folder(&id, &name, parent_id.as_deref(), rule_json)?;
+    let record = find_folder(&id)?;
+    Ok(map_folder(record, 0))
+}
+
+fn find_folder(folder_id: &str) -> anyhow::Result<DownloadFolderRecord> {
+    storage::get_download_folders()?
+        .into_iter()
+        .find(|f| f.id == folder_id)
+        .ok_or_else(|| anyhow::anyhow!("Download folder not found"))
+}
+
+/// 上级必须存在且为普通文件夹
+fn check_parent(folders: &[DownloadFolderRecord], parent_id: &str) -> anyhow::Result<()> {
+    match folders.iter().find(|f| f.id == parent_id) {
+        None => anyhow::bail!("Parent folder not found"),
+        Some(parent) if parent.is_smart() => {
+            anyhow::bail!("Smart folders cannot contain subfolders")
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// 文件夹自身及其所有子孙的 ID
+fn descendant_ids<'a>(folders: &'a [DownloadFolderRecord], root: &'a str) -> HashSet<&'a str> {
+    let mut ids = HashSet::from([root]);
+    let mut stack = vec![root];
+    while let Some(current) = stack.pop() {
+        for folder in folders {
+            if folder.parent_id.as_deref() == Some(current) && ids.insert(folder.id.as_str()) {
+                stack.push(folder.id.as_str());
+            }
+        }
+    }
+    ids
+}
+
+fn parse_rule(folder: &DownloadFolderRecord) -> anyhow::Result<Option<DownloadFilter>> {
+    folder
+        .rule_json
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("Invalid smart folder rule: {}", e))
+}
+
+fn map_folder(record: DownloadFolderRecord, video_count: u32) -> ApiDownloadFolder {
+    ApiDownloadFolder {
+        is_smart: record.is_smart(),
+        id: record.id,
+        name: record.name,
+        created_at: record.created_at,
+        parent_id: record.parent_id,
+        sort_order: record.sort_order as i32,
+        cover_video_id: record.cover_video_id,
+        video_count,
+    }
+}
+
+fn to_filter(rule: ApiSmartFolderRule) -> DownloadFilter {
+    let clean = |values: Vec<String>| {
+        values
+            .into_iter()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>()
+    };
+    let date = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    DownloadFilter {
+        authors: clean(rule.authors),
+        tags: clean(rule.tags),
+        qualities: clean(rule.qualities),
+        downloaded_from: rule.downloaded_from,
+        downloaded_to: rule.downloaded_to,
+        uploaded_from: date(rule.uploaded_from),
+        uploaded_to: date(rule.uploaded_to),
+        watched: rule.watched,
+    }
+}
+
+fn from_filter(filter: DownloadFilter) -> ApiSmartFolderRule {
+    ApiSmartFolderRule {
+        authors: filter.authors,
+        tags: filter.tags,
+        qualities: filter.qualities,
+        downloaded_from: filter.downloaded_from,
+        downloaded_to: filter.downloaded_to,
+        uploaded_from: filter.uploaded_from,
+        uploaded_to: filter.uploaded_to,
+        watched: filter.watched,
+    }
+}
@@ -0,0 +1,387 @@
+// HLS 下载
+// - 播放源为 m3u8 时按分片下载并拼接：TS 分片拼接为 .ts；fMP4 为初始化分片 + 各分片，保存为 .mp4
+// - master 播放列表按请求的清晰度选择变体（规则同 hls::pick_quality）
+// - AES-128 加密的分片按 EXT-X-KEY 获取密钥解密；直播流、字节范围分片与其他加密方式返回明确错误
+// - 分片数据与普通下载共用限速（全局 + 单任务令牌桶）
+// - 每完成一个分片记录续传位置（download_hls_progress），续传时截断到最后完成的分片末尾继续
+// - 画质变体同样按分片下载，进度与状态写入变体记录（download_variant_hls_progress）
+
+use crate::api::download::{
+    build_download_filename, ensure_free_space, mark_download_corrupt, notify_download_changed,
+    resolve_download_path, verify_downloaded_file, DownloadControl, MAX_STREAM_RECONNECTS,
+};
+use crate::api::{download_quota, download_variants};
+use crate::core::hls::{self, HlsSegment, MediaPlaylist};
+use crate::core::media_verify::MediaCheck;
+use crate::core::throttle::TaskThrottle;
+use crate::core::{network, storage};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::watch;
+
+/// 播放源是否为 HLS 播放列表
+pub(crate) fn is_hls_source(format: &str, url: &str) -> bool {
+    format.to_ascii_lowercase().contains("m3u8") || url.contains(".m3u8")
+}
+
+/// HLS 下载写入的记录
+pub(crate) enum HlsTarget<'a> {
+    /// 主下载（downloads 表，按 video_id）
+    Download(&'a str),
+    /// 画质变体（download_variants 表）
+    Variant(&'a storage::DownloadVariantRecord),
+}
+
+impl HlsTarget<'_> {
+    fn video_id(&self) -> &str {
+        match self {
+            HlsTarget::Download(video_id) => video_id,
+            HlsTarget::Variant(variant) => &variant.video_id,
+        }
+    }
+
+    /// 生成并保存新的文件名，同时清除旧的续传位置
+    fn assign_file_name(&self, quality: &str, ext: &str) -> anyhow::Result<String> {
+        match self {
+            HlsTarget::Download(video_id) => {
+                let name = build_download_filename(video_id, quality, ext);
+                storage::update_download_save_path(video_id, &name)?;
+                storage::delete_download_hls_progress(video_id)?;
+                Ok(name)
+            }
+            HlsTarget::Variant(variant) => {
+                let name = download_variants::variant_file_name(variant, ext)?;
+                storage::update_download_variant_save_path(variant.id, &name)?;
+                storage::delete_variant_hls_progress(variant.id)?;
+                Ok(name)
+            }
+        }
+    }
+
+    fn load_progress(&self) -> anyhow::Result<Option<storage::HlsProgressRecord>> {
+        match self {
+            HlsTarget::Download(video_id) => storage::get_download_hls_progress(video_id),
+            HlsTarget::Variant(variant) => storage::get_variant_hls_progress(variant.id),
+        }
+    }
+
+    fn save_progress(&self, progress: &storage::HlsProgressRecord) -> anyhow::Result<()> {
+        match self {
+            HlsTarget::Download(video_id) => {
+                storage::save_download_hls_progress(video_id, progress)
+            }
+            HlsTarget::Variant(variant) => {
+                storage::save_variant_hls_progress(variant.id, &variant.video_id, progress)
+            }
+        }
+    }
+
+    fn clear_progress(&self) -> anyhow::Result<()> {
+        match self {
+            HlsTarget::Download(video_id) => storage::delete_download_hls_progress(video_id),
+            HlsTarget::Variant(variant) => storage::delete_variant_hls_progress(variant.id),
+        }
+    }
+
+    fn set_status(&self, status: storage::DownloadStatus) -> anyhow::Result<()> {
+        match self {
+            HlsTarget::Download(video_id) => {
+                storage::update_download_status(video_id, status, None)
+            }
+            HlsTarget::Variant(variant) => {
+                storage::update_download_variant_status(variant.id, status, None)
+            }
+        }
+    }
+
+    fn report_progress(&self, downloaded: u64, total: u64) -> anyhow::Result<()> {
+        match self {
+            HlsTarget::Download(video_id) => {
+                storage::update_download_progress(video_id, downloaded as i64, total as i64)?;
+                notify_download_changed(video_id);
+            }
+            HlsTarget::Variant(variant) => {
+                storage::update_download_variant_progress(
+                    variant.id,
+                    downloaded as i64,
+                    total as i64,
+                )?;
+                download_variants::notify_variant(variant.id);
+            }
+        }
+        Ok(())
+    }
+
+    fn mark_corrupt(&self, reason: &str) -> anyhow::Result<()> {
+        match self {
+            HlsTarget::Download(video_id) => mark_download_corrupt(video_id, reason),
+            HlsTarget::Variant(variant) => storage::update_download_variant_status(
+                variant.id,
+                storage::DownloadStatus::Corrupt,
+                Some(reason),
+            ),
+        }
+    }
+
+    fn complete(&self) -> anyhow::Result<()> {
+        match self {
+            HlsTarget::Download(video_id) => {
+                storage::update_download_status(
+                    video_id,
+                    storage::DownloadStatus::Completed,
+                    None,
+                )?;
+                storage::reset_download_retry(video_id)?;
+                Ok(())
+            }
+            HlsTarget::Variant(variant) => download_variants::complete_variant(variant),
+        }
+    }
+}
+
+/// 下载 HLS 播放源（`playlist_url` 为 master 或媒体播放列表）
+pub(crate) async fn run_hls_download(
+    target: HlsTarget<'_>,
+    playlist_url: &str,
+    quality: &str,
+    bandwidth_limit_kb: Option<u32>,
+    save_path_hint: PathBuf,
+    ctrl_rx: &mut watch::Receiver<DownloadControl>,
+) -> anyhow::Result<()> {
+    let video_id = target.video_id();
+    let playlist = load_media_playlist(playlist_url, quality).await?;
+    let ext = if playlist.init_url.is_some() {
+        "mp4"
+    } else {
+        "ts"
+    };
+
+    // 沿用已有文件名；扩展名不同（例如旧版本保存的 .m3u8）时改用新文件名
+    let hint_name = save_path_hint
+        .file_name()
+        .and_then(|n| n.to_str())
+        .map(str::to_string);
+    let file_name = match hint_name {
+        Some(name) if name.to_ascii_lowercase().ends_with(&format!(".{ext}")) => name,
+        hint => {
+            if let Some(old) = hint.and_then(|name| resolve_download_path(&name).ok()) {
+                let _ = tokio::fs::remove_file(old).await;
+            }
+            target.assign_file_name(quality, ext)?
+        }
+    };
+    let save_path = resolve_download_path(&file_name)?;
+
+    target.set_status(storage::DownloadStatus::Downloading)?;
+    if let HlsTarget::Download(id) = &target {
+        storage::update_download_preallocated(id, false)?;
+    }
+
+    let segment_count = playlist.segments.len() as u32;
+    let file_len = tokio::fs::metadata(&save_path)
+        .await
+        .map(|m| m.len())
+        .unwrap_or(0);
+    // 续传：分片数量一致且文件不短于记录的长度时，截断到最后完成的分片末尾
+    let resume = target
+        .load_progress()?
+        .filter(|p| p.segment_count == segment_count && p.bytes_done <= file_len);
+    let mut progress = match resume {
+        Some(progress) => {
+            tracing::info!(
+                "download hls_resume video_id={} segment={}/{}",
+                video_id,
+                progress.segments_done,
+                segment_count
+            );
+            progress
+        }
+        None => storage::HlsProgressRecord {
+            segment_count,
+            segments_done: 0,
+            bytes_done: 0,
+        },
+    };
+    {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(&save_path)
+            .await?;
+        file.set_len(progress.bytes_done).await?;
+    }
+    let mut file = tokio::fs::OpenOptions::new()
+        .append(true)
+        .open(&save_path)
+        .await?;
+
+    let mut keys = HashMap::new();
+    let mut throttle = TaskThrottle::new(video_id, bandwidth_limit_kb);
+    if progress.bytes_done == 0 {
+        if let Some(init_url) = playlist.init_url.as_deref() {
+            let data = fetch_throttled(init_url, &mut throttle).await?;
+            file.write_all(&data).await?;
+            file.flush().await?;
+            progress.bytes_done = data.len() as u64;
+            target.save_progress(&progress)?;
+        }
+    }
+
+    let mut checked_space = false;
+    for segment in playlist
+        .segments
+        .iter()
+        .skip(progress.segments_done as usize)
+    {
+        let data = loop {
+            tokio::select! {
+                changed = ctrl_rx.changed() => {
+                    let _ = changed;
+                    match *ctrl_rx.borrow() {
+                        DownloadControl::Paused => {
+                            target.set_status(storage::DownloadStatus::Paused)?;
+                            return Ok(());
+                        }
+                        DownloadControl::Canceled => return Ok(()),
+                        DownloadControl::Running => {}
+                    }
+                }
+                data = fetch_segment(video_id, segment, &mut keys, &mut throttle) => break data?,
+            }
+        };
+        file.write_all(&data).await?;
+        file.flush().await?;
+        progress.segments_done += 1;
+        progress.bytes_done += data.len() as u64;
+        target.save_progress(&progress)?;
+
+        // 总大小按已下载分片的平均大小估算
+        let estimated =
+            progress.bytes_done / u64::from(progress.segments_done) * u64::from(segment_count);
+        let estimated = estimated.max(progress.bytes_done);
+        if !checked_space {
+            checked_space = true;
+            let remaining = estimated - progress.bytes_done;
+            ensure_free_space(&save_path, remaining, video_id)?;
+            download_quota::ensure_quota(video_id, remaining).await?;
+        }
+        target.report_progress(progress.bytes_done, estimated)?;
+    }
+    file.sync_all().await?;
+    drop(file);
+
+    let total = progress.bytes_done;
+    target.report_progress(total, total)?;
+    let reason = match verify_downloaded_file(save_path, total).await? {
+        MediaCheck::Ok => None,
+        MediaCheck::Truncated { actual, expected } => {
+            Some(format!("File is {} bytes, expected {}", actual, expected))
+        }
+        MediaCheck::Invalid(reason) => Some(reason),
+    };
+    if let Some(reason) = reason {
+        // 拼接结果无效：清除续传位置，重新下载时从头开始
+        target.clear_progress()?;
+        tracing::warn!(
+            "download verify_failed video_id={} reason={}",
+            video_id,
+            reason
+        );
+        target.mark_corrupt(&reason)?;
+        return Ok(());
+    }
+    target.clear_progress()?;
+    target.complete()
+}
+
+/// 获取媒体播放列表；`url` 为 master 播放列表时按清晰度选择变体
+async fn load_media_playlist(url: &str, quality: &str) -> anyhow::Result<MediaPlaylist> {
+    let (body, playlist_url) = fetch_playlist(url).await?;
+    let variants = hls::parse_master_playlist(&body, &playlist_url);
+    let (body, playlist_url) = if variants.is_empty() {
+        (body, playlist_url)
+    } else {
+        let qualities = variants
+            .iter()
+            .map(|v| v.quality().unwrap_or_else(|| "auto".to_string()))
+            .collect::<Vec<_>>();
+        let index = hls::pick_quality(&qualities, quality).unwrap_or(0);
+        fetch_playlist(&variants[index].url).await?
+    };
+    hls::parse_media_playlist(&body, &playlist_url).map_err(anyhow::Error::msg)
+}
+
+async fn fetch_playlist(url: &str) -> anyhow::Result<(String, url::Url)> {
+    let resp = network::media_request(url)?
+        .send()
+        .await?
+        .error_for_status()?;
+    let playlist_url = resp.url().clone();
+    Ok((resp.text().await?, playlist_url))
+}
+
+async fn fetch_bytes(url: &str) -> anyhow::Result<Vec<u8>> {
+    let resp = network::media_request(url)?
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(resp.bytes().await?.to_vec())
+}
+
+/// 按限速读取响应（每读取一块数据后按令牌桶等待）
+async fn fetch_throttled(url: &str, throttle: &mut TaskThrottle) -> anyhow::Result<Vec<u8>> {
+    use futures_util::StreamExt;
+    let resp = network::media_request(url)?
+        .send()
+        .await?
+        .error_for_status()?;
+    let mut data = Vec::with_capacity(resp.content_length().unwrap_or(0) as usize);
+    let mut stream = resp.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        data.extend_from_slice(&chunk);
+        let wait = throttle.reserve(chunk.len());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+    Ok(data)
+}
+
+/// 下载并解密一个分片；网络错误时重试
+async fn fetch_segment(
+    video_id: &str,
+    segment: &HlsSegment,
+    keys: &mut HashMap<String, Vec<u8>>,
+    throttle: &mut TaskThrottle,
+) -> anyhow::Result<Vec<u8>> {
+    let mut attempt = 0;
+    let data = loop {
+        match fetch_throttled(&segment.url, throttle).await {
+            Ok(data) => break data,
+            Err(e) if attempt < MAX_STREAM_RECONNECTS => {
+                attempt += 1;
+                tracing::warn!(
+                    "download hls_segment_retry video_id={} sequence={} attempt={} error={}",
+                    video_id,
+                    segment.sequence,
+                    attempt,
+                    e
+                );
+                tokio::time::sleep(Duration::from_secs(u64::from(attempt))).await;
+            }
+            Err(e) => return Err(e),
+        }
+    };
+    let Some(key) = segment.key.as_ref() else {
+        return Ok(data);
+    };
+    if !keys.contains_key(&key.url) {
+        let bytes = fetch_bytes(&key.url).await?;
+        keys.insert(key.url.clone(), bytes);
+    }
+    hls::decrypt_segment(&keys[&key.url], &segment.iv(), &data).map_err(anyhow::Error::msg)
+}
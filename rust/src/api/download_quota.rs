@@ -0,0 +1,350 @@
+// 下载存储配额 API
+// - 配额覆盖下载根目录下的视频、封面与头像
+// - 清理策略：已看完且超过保留天数的下载、超出配额时按时间从旧到新；固定文件夹中的下载不清理
+// - 新下载开始前检查配额：开启自动清理时先按策略释放空间，仍不足则阻止下载并发送事件
+
+use crate::api::download::{delete_download, QuotaExceededError};
+use crate::api::download_folders::folder_scope;
+use crate::api::models::{
+    ApiCleanupItem, ApiCleanupPlan, ApiDownloadQuotaPolicy, ApiQuotaBlockedEvent,
+};
+use crate::api::settings::dir_size;
+use crate::core::cleanup_plan::{self, CleanupCandidate, CleanupRules};
+use crate::core::{library_use, runtime, storage};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::OnceLock;
+use tokio::sync::{broadcast, Mutex};
+
+const QUOTA_POLICY_KEY: &str = "download_quota_policy";
+const LIBRARY_DIRS: [&str; 4] = [
+    "downloads",
+    "download_covers",
+    "download_avatars",
+    "download_subtitles",
+];
+
+/// 持久化的配额策略（JSON 保存在 settings 表）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct QuotaPolicy {
+    quota_bytes: Option<u64>,
+    auto_cleanup: bool,
+    watched_older_than_days: Option<u32>,
+    oldest_first: bool,
+    pinned_folder_ids: Vec<String>,
+}
+
+impl QuotaPolicy {
+    fn load() -> Self {
+        storage::get_setting(QUOTA_POLICY_KEY)
+            .ok()
+            .flatten()
+            .and_then(|v| serde_json::from_str(&v).ok())
+            .unwrap_or_default()
+    }
+
+    fn rules(&self) -> CleanupRules {
+        CleanupRules {
+            watched_older_than_days: self.watched_older_than_days,
+            oldest_first: self.oldest_first,
+        }
+    }
+}
+
+fn blocked_sender() -> &'static broadcast::Sender<ApiQuotaBlockedEvent> {
+    static TX: OnceLock<broadcast::Sender<ApiQuotaBlockedEvent>> = OnceLock::new();
+    TX.get_or_init(|| broadcast::channel(32).0)
+}
+
+/// 清理串行执行，避免并发下载同时触发重复删除
+fn cleanup_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// 获取配额与清理策略
+#[frb]
+pub async fn get_download_quota_policy() -> anyhow::Result<ApiDownloadQuotaPolicy> {
+    let policy = QuotaPolicy::load();
+    Ok(ApiDownloadQuotaPolicy {
+        quota_bytes: policy.quota_bytes,
+        auto_cleanup: policy.auto_cleanup,
+        watched_older_than_days: policy.watched_older_than_days,
+        oldest_first: policy.oldest_first,
+        pinned_folder_ids: policy.pinned_folder_ids,
+    })
+}
+
+/// 保存配额与清理策略
+#[frb]
+pub async fn set_download_quota_policy(policy: ApiDownloadQuotaPolicy) -> anyhow::Result<bool> {
+    let folders = storage::get_download_folders()?;
+    let policy = QuotaPolicy {
+        quota_bytes: policy.quota_bytes.filter(|b| *b > 0),
+        auto_cleanup: policy.auto_cleanup,
+        watched_older_than_days: policy.watched_older_than_days,
+        oldest_first: policy.oldest_first,
+        pinned_folder_ids: policy
+            .pinned_folder_ids
+            .into_iter()
+            .filter(|id| folders.iter().any(|f| &f.id == id))
+            .collect(),
+    };
+    storage::save_setting(QUOTA_POLICY_KEY, &serde_json::to_string(&policy)?)?;
+    Ok(true)
+}
+
+/// 预览清理：按当前策略列出将被删除的下载（不做任何修改）
+#[frb]
+pub async fn preview_download_cleanup() -> anyhow::Result<ApiCleanupPlan> {
+    let policy = QuotaPolicy::load();
+    let used = library_size().await?;
+    build_plan(&policy, used, 0, None)
+}
+
+/// 立即按当前策略清理，返回实际删除的下载
+#[frb]
+pub async fn run_download_cleanup() -> anyhow::Result<ApiCleanupPlan> {
+    let _guard = cleanup_lock().lock().await;
+    // 统计与删除文件期间登记使用下载目录，迁移期间拒绝
+    let _library = library_use::acquire()?;
+    let policy = QuotaPolicy::load();
+    let used = library_size().await?;
+    let plan = build_plan(&policy, used, 0, None)?;
+    execute_plan(plan).await
+}
+
+/// 监听配额阻止下载事件
+#[frb]
+pub fn subscribe_quota_blocked_events(sink: StreamSink<ApiQuotaBlockedEvent>) {
+    let mut rx = blocked_sender().subscribe();
+    std::thread::spawn(move || loop {
+        match rx.blocking_recv() {
+            Ok(item) => {
+                let _ = sink.add(item);
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+        }
+    });
+}
+
+/// 启动时按策略执行一次自动清理（仅开启自动清理时）
+pub(crate) fn start_auto_cleanup() {
+    runtime::spawn(async {
+        if !QuotaPolicy::load().auto_cleanup {
+            return;
+        }
+        match run_download_cleanup().await {
+            Ok(plan) if !plan.items.is_empty() => tracing::info!(
+                "download quota_cleanup removed={} freed_bytes={}",
+                plan.items.len(),
+                plan.freed_bytes
+            ),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("download quota_cleanup failed error={}", e),
+        }
+    });
+}
+
+/// 下载开始前检查配额
+///
+/// - `remaining`：本任务剩余字节数（已写入部分已计入目录占用）
+/// - 超出时若开启自动清理则先清理（不会删除本任务）；仍超出则发送事件并返回 `QuotaExceededError`
+pub(crate) async fn ensure_quota(video_id: &str, remaining: u64) -> anyhow::Result<()> {
+    let policy = QuotaPolicy::load();
+    let Some(quota) = policy.quota_bytes else {
+        return Ok(());
+    };
+    let _guard = cleanup_lock().lock().await;
+    let others = storage::get_downloads()?
+        .into_iter()
+        .filter(|r| {
+            r.video_id != video_id
+                && r.status == storage::DownloadStatus::Downloading
+                && !r.preallocated
+        })
+        .map(|r| (r.total_bytes - r.downloaded_bytes).max(0) as u64)
+        .sum::<u64>();
+    let needed = remaining.saturating_add(others);
+    let mut used = library_size().await?;
+    if used.saturating_add(needed) <= quota {
+        return Ok(());
+    }
+
+    let mut freed = 0;
+    if policy.auto_cleanup {
+        let plan = build_plan(&policy, used, needed, Some(video_id))?;
+        if !plan.items.is_empty() {
+            let result = execute_plan(plan).await?;
+            freed = result.freed_bytes;
+            tracing::info!(
+                "download quota_cleanup video_id={} removed={} freed_bytes={}",
+                video_id,
+                result.items.len(),
+                freed
+            );
+            used = library_size().await?;
+        }
+    }
+    let required = used.saturating_add(needed);
+    if required <= quota {
+        return Ok(());
+    }
+
+    let title = storage::get_download_by_video_id(video_id)?
+        .map(|r| r.title)
+        .unwrap_or_default();
+    let _ = blocked_sender().send(ApiQuotaBlockedEvent {
+        video_id: video_id.to_string(),
+        title,
+        required_bytes: required,
+        used_bytes: used,
+        quota_bytes: quota,
+        freed_bytes: freed,
+        blocked_at: chrono::Utc::now().timestamp(),
+    });
+    Err(QuotaExceededError { required, quota }.into())
+}
+
+/// 下载根目录（视频、封面、头像）的总占用
+async fn library_size() -> anyhow::Result<u64> {
+    let root = storage::get_downloads_root()?;
+    let size = tokio::task::spawn_blocking(move || {
+        LIBRARY_DIRS
+            .iter()
+            .map(|dir| dir_size(root.join(dir)))
+            .sum::<u64>()
+    })
+    .await?;
+    Ok(size)
+}
+
+/// 生成清理计划；`exclude` 为正在准备下载的任务
+fn build_plan(
+    policy: &QuotaPolicy,
+    used: u64,
+    needed: u64,
+    exclude: Option<&str>,
+) -> anyhow::Result<ApiCleanupPlan> {
+    let pinned = pinned_videos(&policy.pinned_folder_ids)?;
+    let watch_states = storage::get_history_watch_states()?;
+    let mut variant_sizes = std::collections::HashMap::<String, u64>::new();
+    for variant in storage::get_download_variants(None)? {
+        *variant_sizes.entry(variant.video_id).or_default() += variant.total_bytes.max(0) as u64;
+    }
+
+    let records = storage::get_downloads()?
+        .into_iter()
+        .filter(|r| {
+            r.status == storage::DownloadStatus::Completed
+                && Some(r.video_id.as_str()) != exclude
+                && !pinned.contains(&r.video_id)
+        })
+        .collect::<Vec<_>>();
+    let candidates = records
+        .iter()
+        .map(|r| {
+            let (watch_progress, total_duration, watched_at) = watch_states
+                .get(&r.video_id)
+                .map(|(p, d, w)| (*p, *d, Some(*w)))
+                .unwrap_or((0, 0, None));
+            CleanupCandidate {
+                video_id: r.video_id.clone(),
+                size: (r.total_bytes.max(0) as u64)
+                    .saturating_add(variant_sizes.get(&r.video_id).copied().unwrap_or(0)),
+                completed_at: r.completed_at.unwrap_or(r.created_at),
+                watched_at,
+                watch_progress,
+                total_duration,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let plan = cleanup_plan::plan_cleanup(
+        &candidates,
+        &policy.rules(),
+        used,
+        policy.quota_bytes,
+        needed,
+        chrono::Utc::now().timestamp(),
+    );
+    let items = plan
+        .into_iter()
+        .map(|(index, reason)| {
+            let candidate = &candidates[index];
+            ApiCleanupItem {
+                video_id: candidate.video_id.clone(),
+                title: records[index].title.clone(),
+                reason: reason.as_str().to_string(),
+                size: candidate.size,
+                completed_at: candidate.completed_at,
+                watched_at: candidate.watched_at,
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(ApiCleanupPlan {
+        used_bytes: used,
+        quota_bytes: policy.quota_bytes,
+        freed_bytes: items.iter().map(|i| i.size).sum(),
+        items,
+    })
+}
+
+/// 执行清理计划，返回实际删除的项目
+async fn execute_plan(plan: ApiCleanupPlan) -> anyhow::Result<ApiCleanupPlan> {
+    let mut removed = Vec::with_capacity(plan.items.len());
+    for item in plan.items {
+        match delete_download(item.video_id.clone(), true).await {
+            Ok(_) => {
+                tracing::info!(
+                    "download quota_removed video_id={} reason={} size={}",
+                    item.video_id,
+                    item.reason,
+                    item.size
+                );
+                removed.push(item);
+            }
+            Err(e) => tracing::warn!(
+                "download quota_remove_failed video_id={} error={}",
+                item.video_id,
+                e
+            ),
+        }
+    }
+    Ok(ApiCleanupPlan {
+        used_bytes: plan.used_bytes,
+        quota_bytes: plan.quota_bytes,
+        freed_bytes: removed.iter().map(|i| i.size).sum(),
+        items: removed,
+    })
+}
+
+/// 固定文件夹（含子文件夹、智能文件夹规则）中的下载
+fn pinned_videos(pinned: &[String]) -> anyhow::Result<HashSet<String>> {
+    let mut folder_ids = Vec::new();
+    let mut result = HashSet::new();
+    for folder_id in pinned {
+        // 文件夹已被删除时忽略
+        let Ok((ids, rule)) = folder_scope(folder_id, true) else {
+            continue;
+        };
+        folder_ids.extend(ids);
+        if let Some(rule) = rule {
+            result.extend(
+                storage::get_downloads_by_filter(&rule)?
+                    .into_iter()
+                    .map(|r| r.video_id),
+            );
+        }
+    }
+    result.extend(
+        storage::get_downloads_in_folders(&folder_ids)?
+            .into_iter()
+            .map(|r| r.video_id),
+    );
+    Ok(result)
+}
@@ -0,0 +1,324 @@
+// 下载库核对 API
+// - 扫描 downloads / download_covers / download_avatars / download_subtitles 目录，与 downloads 表双向比对
+// - 报告：记录存在但文件缺失、文件存在但无记录引用（孤立文件）
+// - 修复：重新关联文件、标记缺失、重新下载、删除孤立文件
+
+use crate::api::download::{
+    basename, map_record, notify_download_changed, repair_download, resolve_under_downloads_root,
+    verify_download,
+};
+use crate::api::download_subtitles::SUBTITLES_DIR;
+use crate::api::models::{
+    ApiDownloadStatus, ApiDownloadTask, ApiMissingDownload, ApiOrphanFile, ApiReconcileReport,
+};
+use crate::core::{library_use, storage};
+use flutter_rust_bridge::frb;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+const MEDIA_DIR: &str = "downloads";
+const COVERS_DIR: &str = "download_covers";
+const AVATARS_DIR: &str = "download_avatars";
+const SCAN_DIRS: [&str; 4] = [MEDIA_DIR, COVERS_DIR, AVATARS_DIR, SUBTITLES_DIR];
+
+/// 最近修改的文件可能正在写入（下载 / 导入中），不视为孤立文件
+const ORPHAN_MIN_AGE: Duration = Duration::from_secs(5 * 60);
+
+/// 扫描下载目录与数据库，生成核对报告（只读，不做任何修改）
+#[frb]
+pub async fn scan_download_library() -> anyhow::Result<ApiReconcileReport> {
+    let _library = library_use::acquire()?;
+    let records = storage::get_downloads()?;
+    let mut referenced = referenced_files(&records);
+    referenced.extend(variant_files()?);
+    referenced.extend(subtitle_files()?);
+
+    let mut orphans = Vec::new();
+    for dir in SCAN_DIRS {
+        let root = resolve_under_downloads_root(dir, "")?;
+        for entry in std::fs::read_dir(&root)? {
+            let entry = entry?;
+            let meta = entry.metadata()?;
+            if !meta.is_file() {
+                continue;
+            }
+            let Some(name) = entry.file_name().to_str().map(|s| s.to_string()) else {
+                continue;
+            };
+            if referenced.contains(&(dir, name.clone())) || is_recent(&meta) {
+                continue;
+            }
+            orphans.push(ApiOrphanFile {
+                dir: dir.to_string(),
+                video_id: guess_video_id(&name),
+                file_name: name,
+                size: meta.len(),
+                modified_at: meta
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs() as i64),
+            });
+        }
+    }
+    orphans.sort_by(|a, b| (&a.dir, &a.file_name).cmp(&(&b.dir, &b.file_name)));
+
+    let mut missing = Vec::new();
+    for record in records {
+        let missing_media = matches!(
+            record.status,
+            storage::DownloadStatus::Completed
+                | storage::DownloadStatus::Corrupt
+                | storage::DownloadStatus::Missing
+        ) && !file_exists(MEDIA_DIR, record.save_path.as_deref());
+        let missing_cover =
+            record.cover_path.is_some() && !file_exists(COVERS_DIR, record.cover_path.as_deref());
+        let missing_avatar = record.author_avatar_path.is_some()
+            && !file_exists(AVATARS_DIR, record.author_avatar_path.as_deref());
+        if !missing_media && !missing_cover && !missing_avatar {
+            continue;
+        }
+        let candidate_files = if missing_media {
+            orphans
+                .iter()
+                .filter(|o| o.dir == MEDIA_DIR && o.video_id.as_deref() == Some(&record.video_id))
+                .map(|o| o.file_name.clone())
+                .collect()
+        } else {
+            vec![]
+        };
+        missing.push(ApiMissingDownload {
+            status: map_status(&record),
+            video_id: record.video_id,
+            title: record.title,
+            missing_media,
+            missing_cover,
+            missing_avatar,
+            expected_bytes: record.total_bytes.max(0) as u64,
+            candidate_files,
+        });
+    }
+
+    Ok(ApiReconcileReport {
+        missing_bytes: missing
+            .iter()
+            .filter(|m| m.missing_media)
+            .map(|m| m.expected_bytes)
+            .sum(),
+        orphan_bytes: orphans.iter().map(|o| o.size).sum(),
+        missing,
+        orphans,
+        scanned_at: chrono::Utc::now().timestamp(),
+    })
+}
+
+/// 将下载记录重新关联到目录中已有的文件
+///
+/// - `kind`：`media` | `cover` | `avatar`
+/// - 文件必须位于对应目录且未被其他记录引用；媒体文件关联后会重新校验
+#[frb]
+pub async fn relink_download(
+    video_id: String,
+    kind: String,
+    file_name: String,
+) -> anyhow::Result<ApiDownloadTask> {
+    let _library = library_use::acquire()?;
+    let record = storage::get_download_by_video_id(&video_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download task not found"))?;
+    let dir = match kind.as_str() {
+        "media" => MEDIA_DIR,
+        "cover" => COVERS_DIR,
+        "avatar" => AVATARS_DIR,
+        other => anyhow::bail!("Unknown file kind: {other}"),
+    };
+    let name = plain_file_name(&file_name)?;
+    let path = resolve_under_downloads_root(dir, &name)?;
+    if !path.is_file() {
+        anyhow::bail!("File not found: {name}");
+    }
+    let owner = storage::get_downloads()?.into_iter().find(|r| {
+        r.video_id != video_id
+            && referenced_files(std::slice::from_ref(r)).contains(&(dir, name.clone()))
+    });
+    if let Some(owner) = owner {
+        anyhow::bail!("File is used by another download: {}", owner.video_id);
+    }
+    if variant_files()?.contains(&(dir, name.clone())) {
+        anyhow::bail!("File is used by a quality variant");
+    }
+
+    match dir {
+        MEDIA_DIR => {
+            let size = std::fs::metadata(&path)?.len() as i64;
+            storage::relink_download_file(&record.video_id, &name, size)?;
+            // 校验失败时 verify_download 会标记为 Corrupt
+            return verify_download(video_id).await;
+        }
+        COVERS_DIR => storage::update_download_cover_path(&video_id, &name)?,
+        _ => storage::update_download_author(
+            &video_id,
+            record.author_id.as_deref(),
+            record.author_name.as_deref(),
+            record.author_avatar_url.as_deref(),
+            Some(&name),
+        )?,
+    }
+    notify_download_changed(&video_id);
+    let record = storage::get_download_by_video_id(&video_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download task not found"))?;
+    Ok(map_record(record))
+}
+
+/// 将媒体文件缺失的已完成任务标记为 `Missing`，返回实际标记的数量
+#[frb]
+pub async fn mark_downloads_missing(video_ids: Vec<String>) -> anyhow::Result<u32> {
+    let mut marked = 0;
+    for video_id in video_ids {
+        let Some(record) = storage::get_download_by_video_id(&video_id)? else {
+            continue;
+        };
+        let eligible = matches!(
+            record.status,
+            storage::DownloadStatus::Completed | storage::DownloadStatus::Corrupt
+        ) && !file_exists(MEDIA_DIR, record.save_path.as_deref());
+        if !eligible {
+            continue;
+        }
+        storage::update_download_status(
+            &video_id,
+            storage::DownloadStatus::Missing,
+            Some("File not found"),
+        )?;
+        notify_download_changed(&video_id);
+        marked += 1;
+    }
+    Ok(marked)
+}
+
+/// 重新下载文件缺失的任务，返回成功加入队列的数量
+#[frb]
+pub async fn redownload_missing(video_ids: Vec<String>) -> anyhow::Result<u32> {
+    let mut queued = 0;
+    for video_id in video_ids {
+        let Some(record) = storage::get_download_by_video_id(&video_id)? else {
+            continue;
+        };
+        if file_exists(MEDIA_DIR, record.save_path.as_deref()) {
+            continue;
+        }
+        if repair_download(video_id).await? {
+            queued += 1;
+        }
+    }
+    Ok(queued)
+}
+
+/// 删除孤立文件（`files` 为 `{dir}/{file_name}`），删除前会再次确认无记录引用
+///
+/// 返回释放的字节数
+#[frb]
+pub async fn delete_orphan_files(files: Vec<String>) -> anyhow::Result<u64> {
+    let _library = library_use::acquire()?;
+    let mut referenced = referenced_files(&storage::get_downloads()?);
+    referenced.extend(variant_files()?);
+    referenced.extend(subtitle_files()?);
+    let mut freed = 0u64;
+    for file in files {
+        let Some((dir, name)) = file.split_once('/') else {
+            continue;
+        };
+        let Some(dir) = SCAN_DIRS.into_iter().find(|d| *d == dir) else {
+            continue;
+        };
+        let Ok(name) = plain_file_name(name) else {
+            continue;
+        };
+        if referenced.contains(&(dir, name.clone())) {
+            continue;
+        }
+        let path = resolve_under_downloads_root(dir, &name)?;
+        let Ok(meta) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if !meta.is_file() || is_recent(&meta) {
+            continue;
+        }
+        match std::fs::remove_file(&path) {
+            Ok(()) => freed += meta.len(),
+            Err(e) => tracing::warn!("reconcile delete_orphan failed file={} err={}", file, e),
+        }
+    }
+    Ok(freed)
+}
+
+/// 所有记录引用的文件（目录, 文件名）
+fn referenced_files(records: &[storage::DownloadRecord]) -> HashSet<(&'static str, String)> {
+    let mut set = HashSet::new();
+    for record in records {
+        let entries = [
+            (MEDIA_DIR, record.save_path.as_deref()),
+            (COVERS_DIR, record.cover_path.as_deref()),
+            (AVATARS_DIR, record.author_avatar_path.as_deref()),
+        ];
+        for (dir, path) in entries {
+            if let Some(name) = path.and_then(basename) {
+                set.insert((dir, name));
+            }
+        }
+    }
+    set
+}
+
+/// 画质变体引用的媒体文件
+fn variant_files() -> anyhow::Result<HashSet<(&'static str, String)>> {
+    Ok(storage::get_download_variants(None)?
+        .into_iter()
+        .filter_map(|v| v.save_path.as_deref().and_then(basename))
+        .map(|name| (MEDIA_DIR, name))
+        .collect())
+}
+
+/// 字幕记录引用的文件
+fn subtitle_files() -> anyhow::Result<HashSet<(&'static str, String)>> {
+    Ok(storage::get_download_subtitle_files()?
+        .iter()
+        .filter_map(|path| basename(path))
+        .map(|name| (SUBTITLES_DIR, name))
+        .collect())
+}
+
+fn file_exists(dir: &str, stored: Option<&str>) -> bool {
+    stored
+        .and_then(basename)
+        .and_then(|name| resolve_under_downloads_root(dir, &name).ok())
+        .map(|p: PathBuf| p.is_file())
+        .unwrap_or(false)
+}
+
+fn is_recent(meta: &std::fs::Metadata) -> bool {
+    meta.modified()
+        .ok()
+        .and_then(|t| t.elapsed().ok())
+        .is_some_and(|age| age < ORPHAN_MIN_AGE)
+}
+
+/// 下载文件名以视频 ID 开头：`{id}_{quality}.mp4`、`{id}.jpg`、`{id}_{author}.jpg`
+fn guess_video_id(file_name: &str) -> Option<String> {
+    let id = file_name
+        .split(['_', '.'])
+        .next()
+        .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()))?;
+    Some(id.to_string())
+}
+
+fn plain_file_name(name: &str) -> anyhow::Result<String> {
+    match basename(name) {
+        Some(base) if base == name && base != "." && base != ".." => Ok(base),
+        _ => anyhow::bail!("Invalid file name: {name}"),
+    }
+}
+
+fn map_status(record: &storage::DownloadRecord) -> ApiDownloadStatus {
+    map_record(record.clone()).status
+}
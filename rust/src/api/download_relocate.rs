@@ -0,0 +1,367 @@
+// 下载目录迁移 API
+// - 下载根目录可配置（默认数据目录），其下为 downloads / download_covers / download_avatars / download_subtitles
+// - 导出、打包、核对扫描、本地播放、新建下载或删除文件进行中时拒绝迁移（见 core::library_use）
+// - 迁移：暂停调度 → 同盘 rename / 跨盘复制并校验 → 保存新根目录 → 清理旧文件 → 恢复下载
+// - 任一文件失败时回滚已迁移的文件，根目录设置保持不变
+
+use crate::api::download::{resume_suspended_downloads, suspend_downloads};
+use crate::api::download_export::compare_samples;
+use crate::api::models::ApiRelocateProgress;
+use crate::core::{library_use, runtime, storage};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+const LIBRARY_DIRS: [&str; 4] = [
+    "downloads",
+    "download_covers",
+    "download_avatars",
+    "download_subtitles",
+];
+
+/// 获取当前下载根目录
+#[frb]
+pub async fn get_downloads_root_path() -> anyhow::Result<String> {
+    Ok(storage::get_downloads_root()?
+        .to_string_lossy()
+        .into_owned())
+}
+
+/// 迁移下载目录到新的根目录（通过 StreamSink 发送进度）
+///
+/// - `new_root` 为空时迁回数据目录
+/// - 迁移期间进行中的下载会被暂停，结束后自动恢复
+/// - 导出、打包、核对扫描、本地播放、新建下载或删除文件进行中时返回错误，结束后再试
+#[frb]
+pub fn relocate_downloads(new_root: String, sink: StreamSink<ApiRelocateProgress>) {
+    runtime::spawn(async move {
+        let mut reporter = RelocateReporter::new(sink);
+        let result = relocate(new_root, &mut reporter).await;
+        reporter.done(result.err().map(|e| e.to_string()));
+    });
+}
+
+#[derive(Clone)]
+struct RelocateReporter {
+    sink: StreamSink<ApiRelocateProgress>,
+    progress: ApiRelocateProgress,
+}
+
+impl RelocateReporter {
+    fn new(sink: StreamSink<ApiRelocateProgress>) -> Self {
+        Self {
+            sink,
+            progress: ApiRelocateProgress {
+                stage: "preparing".to_string(),
+                total_files: 0,
+                done_files: 0,
+                total_bytes: 0,
+                done_bytes: 0,
+                current_file: None,
+                done: false,
+                error: None,
+            },
+        }
+    }
+
+    fn stage(&mut self, stage: &str) {
+        self.progress.stage = stage.to_string();
+        self.send();
+    }
+
+    fn send(&self) {
+        let _ = self.sink.add(self.progress.clone());
+    }
+
+    fn done(&mut self, error: Option<String>) {
+        self.progress.done = true;
+        self.progress.current_file = None;
+        self.progress.error = error;
+        self.send();
+    }
+}
+
+/// 单个待迁移文件
+struct PlannedFile {
+    src: PathBuf,
+    dst: PathBuf,
+    size: u64,
+}
+
+/// 已迁移的文件（用于回滚 / 清理）
+struct MovedFile {
+    src: PathBuf,
+    dst: PathBuf,
+    /// true：同盘 rename（源文件已不存在）；false：复制（源文件仍在）
+    renamed: bool,
+}
+
+async fn relocate(new_root: String, reporter: &mut RelocateReporter) -> anyhow::Result<()> {
+    reporter.send();
+    let Some((old_root, new_root)) = prepare(new_root)? else {
+        return Ok(());
+    };
+
+    // 导出、打包、核对扫描、本地播放、新建下载或删除文件进行中时拒绝迁移；迁移期间这些任务无法开始
+    let _relocation = library_use::begin_relocation()?;
+    let paused = suspend_downloads().await?;
+    tracing::info!(
+        "relocate start from={} to={} paused={}",
+        old_root.display(),
+        new_root.display(),
+        paused.len()
+    );
+
+    let (old, new, mut job_reporter) = (old_root.clone(), new_root.clone(), reporter.clone());
+    let moved = tokio::task::spawn_blocking(move || {
+        let result = move_library(&old, &new, &mut job_reporter);
+        (job_reporter.progress, result)
+    })
+    .await;
+
+    let result = match moved {
+        Ok((progress, Ok(moved))) => {
+            reporter.progress = progress;
+            match save_root(&new_root) {
+                Ok(()) => {
+                    reporter.stage("cleanup");
+                    let _ = tokio::task::spawn_blocking(move || cleanup_sources(&old_root, &moved))
+                        .await;
+                    Ok(())
+                }
+                Err(e) => {
+                    reporter.stage("rolling_back");
+                    let _ = tokio::task::spawn_blocking(move || rollback(&moved)).await;
+                    Err(e)
+                }
+            }
+        }
+        Ok((progress, Err(e))) => {
+            reporter.progress = progress;
+            Err(e)
+        }
+        Err(e) => Err(e.into()),
+    };
+
+    resume_suspended_downloads(&paused).await;
+    tracing::info!("relocate finished ok={}", result.is_ok());
+    result
+}
+
+/// 校验目标目录；返回 None 表示目标与当前目录相同，无需迁移
+fn prepare(new_root: String) -> anyhow::Result<Option<(PathBuf, PathBuf)>> {
+    let old_root = storage::get_downloads_root()?;
+    let new_root = match new_root.trim() {
+        "" => storage::get_data_dir()?,
+        root => PathBuf::from(root),
+    };
+    if !new_root.is_absolute() {
+        anyhow::bail!("Target directory must be an absolute path");
+    }
+    std::fs::create_dir_all(&new_root)?;
+    std::fs::create_dir_all(&old_root)?;
+    let old_canon = old_root.canonicalize()?;
+    let new_canon = new_root.canonicalize()?;
+    if old_canon == new_canon {
+        return Ok(None);
+    }
+    for dir in LIBRARY_DIRS {
+        if new_canon.starts_with(old_canon.join(dir)) {
+            anyhow::bail!("Target directory is inside the current library");
+        }
+    }
+
+    // 写入探测文件，确认目标目录可写
+    let probe = new_root.join(".hibiscus_write_test");
+    std::fs::write(&probe, b"ok")?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(Some((old_root, new_root)))
+}
+
+fn save_root(new_root: &Path) -> anyhow::Result<()> {
+    let data_dir = storage::get_data_dir()?;
+    let is_default = data_dir
+        .canonicalize()
+        .ok()
+        .zip(new_root.canonicalize().ok())
+        .is_some_and(|(a, b)| a == b);
+    if is_default {
+        storage::delete_setting(storage::DOWNLOADS_ROOT_KEY)
+    } else {
+        storage::save_setting(storage::DOWNLOADS_ROOT_KEY, &new_root.to_string_lossy())
+    }
+}
+
+fn plan_files(old_root: &Path, new_root: &Path) -> anyhow::Result<Vec<PlannedFile>> {
+    let mut planned = Vec::new();
+    let mut conflicts = 0;
+    for dir in LIBRARY_DIRS {
+        let src_dir = old_root.join(dir);
+        let Ok(entries) = std::fs::read_dir(&src_dir) else {
+            continue;
+        };
+        let dst_dir = new_root.join(dir);
+        for entry in entries {
+            let entry = entry?;
+            let meta = entry.metadata()?;
+            if !meta.is_file() {
+                continue;
+            }
+            let dst = dst_dir.join(entry.file_name());
+            if dst.exists() {
+                conflicts += 1;
+            }
+            planned.push(PlannedFile {
+                src: entry.path(),
+                dst,
+                size: meta.len(),
+            });
+        }
+    }
+    if conflicts > 0 {
+        anyhow::bail!(
+            "Target directory already contains {} file(s) with the same names",
+            conflicts
+        );
+    }
+    Ok(planned)
+}
+
+/// 迁移全部文件；失败时回滚已迁移的部分
+fn move_library(
+    old_root: &Path,
+    new_root: &Path,
+    reporter: &mut RelocateReporter,
+) -> anyhow::Result<Vec<MovedFile>> {
+    let planned = plan_files(old_root, new_root)?;
+    for dir in LIBRARY_DIRS {
+        std::fs::create_dir_all(new_root.join(dir))?;
+    }
+    reporter.progress.total_files = planned.len() as u32;
+    reporter.progress.total_bytes = planned.iter().map(|f| f.size).sum();
+    reporter.stage("moving");
+
+    let mut moved = Vec::with_capacity(planned.len());
+    let mut space_checked = false;
+    for (index, file) in planned.iter().enumerate() {
+        reporter.progress.current_file = file
+            .src
+            .file_name()
+            .map(|s| s.to_string_lossy().into_owned());
+        reporter.send();
+
+        let result = if std::fs::rename(&file.src, &file.dst).is_ok() {
+            Ok(true)
+        } else {
+            // 跨文件系统：复制前确认剩余文件放得下
+            let remaining: u64 = planned[index..].iter().map(|f| f.size).sum();
+            let enough_space = space_checked
+                || fs4::available_space(new_root)
+                    .map(|free| free >= remaining)
+                    .unwrap_or(true);
+            space_checked = true;
+            if enough_space {
+                copy_verified(file, reporter).map(|()| false)
+            } else {
+                Err(anyhow::anyhow!(
+                    "Insufficient disk space: {} bytes required",
+                    remaining
+                ))
+            }
+        };
+
+        match result {
+            Ok(renamed) => {
+                moved.push(MovedFile {
+                    src: file.src.clone(),
+                    dst: file.dst.clone(),
+                    renamed,
+                });
+                reporter.progress.done_files += 1;
+                reporter.progress.done_bytes = planned[..=index].iter().map(|f| f.size).sum();
+                reporter.send();
+            }
+            Err(e) => {
+                tracing::warn!("relocate failed file={} err={}", file.src.display(), e);
+                reporter.stage("rolling_back");
+                rollback(&moved);
+                return Err(e);
+            }
+        }
+    }
+    Ok(moved)
+}
+
+/// 复制到 `.part`，校验大小与抽样内容后重命名
+fn copy_verified(file: &PlannedFile, reporter: &mut RelocateReporter) -> anyhow::Result<()> {
+    let mut part_name = file.dst.as_os_str().to_owned();
+    part_name.push(".part");
+    let part = PathBuf::from(part_name);
+
+    let copied = (|| -> anyhow::Result<()> {
+        let mut input = File::open(&file.src)?;
+        let mut output = File::create(&part)?;
+        let base = reporter.progress.done_bytes;
+        let mut buf = vec![0u8; 1024 * 1024];
+        let mut copied = 0u64;
+        loop {
+            let n = input.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            output.write_all(&buf[..n])?;
+            copied += n as u64;
+            if copied % (8 * 1024 * 1024) < n as u64 {
+                reporter.progress.done_bytes = base + copied;
+                reporter.send();
+            }
+        }
+        output.sync_all()?;
+        drop(output);
+        if !compare_samples(&file.src, &part, file.size)? {
+            anyhow::bail!("Verification failed for {}", file.src.display());
+        }
+        std::fs::rename(&part, &file.dst)?;
+        Ok(())
+    })();
+    if copied.is_err() {
+        let _ = std::fs::remove_file(&part);
+    }
+    copied
+}
+
+/// 撤销已迁移的文件：rename 的移回原处，复制的删除副本
+fn rollback(moved: &[MovedFile]) {
+    for file in moved.iter().rev() {
+        let result = if file.renamed {
+            std::fs::rename(&file.dst, &file.src)
+        } else {
+            std::fs::remove_file(&file.dst)
+        };
+        if let Err(e) = result {
+            tracing::error!(
+                "relocate rollback failed file={} err={}",
+                file.dst.display(),
+                e
+            );
+        }
+    }
+}
+
+/// 迁移成功后删除复制模式下保留的源文件，并移除空的旧目录
+fn cleanup_sources(old_root: &Path, moved: &[MovedFile]) {
+    for file in moved.iter().filter(|f| !f.renamed) {
+        if let Err(e) = std::fs::remove_file(&file.src) {
+            tracing::warn!(
+                "relocate cleanup failed file={} err={}",
+                file.src.display(),
+                e
+            );
+        }
+    }
+    for dir in LIBRARY_DIRS {
+        let _ = std::fs::remove_dir(old_root.join(dir));
+    }
+}
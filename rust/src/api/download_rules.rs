@@ -0,0 +1,434 @@
+// 订阅自动下载规则 API
+// 后台定时拉取订阅更新，按规则（作者 / 标签 / 类型）将新视频加入下载队列
+
+use crate::api::download::enqueue_new_download;
+use crate::api::download_folders::ensure_manual_folder;
+use crate::api::models::ApiDownloadRule;
+use crate::core::{network, parser, runtime, storage};
+use flutter_rust_bridge::frb;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// 默认检查间隔（分钟）
+const DEFAULT_CHECK_INTERVAL_MIN: u64 = 60;
+/// 最短检查间隔（分钟），避免频繁请求订阅页
+const MIN_CHECK_INTERVAL_MIN: u64 = 10;
+/// 启动后首次检查的延迟，避开启动时的请求高峰
+const INITIAL_CHECK_DELAY_SECS: u64 = 60;
+
+/// 每次检查最多翻阅的订阅页数（首次检查或长时间未检查时避免拉取全部历史）
+const MAX_SUBSCRIPTION_PAGES: u32 = 10;
+
+const MATCH_TYPES: [&str; 3] = ["artist", "tag", "genre"];
+
+/// 已建立基线（订阅中的现有视频已标记为已处理）的时间
+const BASELINE_KEY: &str = "download_rules_baseline_at";
+
+fn check_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// 视频 ID → 匹配信息与简介
+type PendingDetails = HashMap<String, (Candidate, Option<String>)>;
+
+/// 因当日上限暂未入队的视频，次日重试时不再请求观看页
+fn pending_details() -> &'static Mutex<PendingDetails> {
+    static PENDING: OnceLock<Mutex<PendingDetails>> = OnceLock::new();
+    PENDING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn check_interval() -> Duration {
+    let minutes = storage::get_setting("download_rules_interval_min")
+        .ok()
+        .flatten()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_CHECK_INTERVAL_MIN)
+        .max(MIN_CHECK_INTERVAL_MIN);
+    Duration::from_secs(minutes * 60)
+}
+
+/// 获取所有自动下载规则
+#[frb]
+pub async fn get_download_rules() -> anyhow::Result<Vec<ApiDownloadRule>> {
+    Ok(storage::get_download_rules()?
+        .into_iter()
+        .map(map_rule)
+        .collect())
+}
+
+/// 创建自动下载规则（忽略传入的 id / created_at）
+#[frb]
+pub async fn create_download_rule(rule: ApiDownloadRule) -> anyhow::Result<ApiDownloadRule> {
+    let record = storage::DownloadRuleRecord {
+        id: Uuid::new_v4().to_string(),
+        created_at: chrono::Utc::now().timestamp(),
+        last_checked_at: None,
+        ..to_record(&rule)?
+    };
+    storage::create_download_rule(&record)?;
+    if record.enabled {
+        ensure_baseline_logged().await;
+    }
+    Ok(map_rule(record))
+}
+
+/// 更新自动下载规则
+#[frb]
+pub async fn update_download_rule(rule: ApiDownloadRule) -> anyhow::Result<bool> {
+    storage::update_download_rule(&to_record(&rule)?)?;
+    Ok(true)
+}
+
+/// 启用/停用自动下载规则
+#[frb]
+pub async fn set_download_rule_enabled(rule_id: String, enabled: bool) -> anyhow::Result<bool> {
+    storage::set_download_rule_enabled(&rule_id, enabled)?;
+    if enabled {
+        ensure_baseline_logged().await;
+    }
+    Ok(true)
+}
+
+/// 删除自动下载规则
+#[frb]
+pub async fn delete_download_rule(rule_id: String) -> anyhow::Result<bool> {
+    storage::delete_download_rule(&rule_id)?;
+    Ok(true)
+}
+
+/// 设置后台检查间隔（分钟，最少 10 分钟）
+#[frb]
+pub async fn set_download_rules_interval(minutes: u32) -> anyhow::Result<bool> {
+    storage::save_setting("download_rules_interval_min", &minutes.to_string())?;
+    Ok(true)
+}
+
+/// 立即检查一次订阅更新，返回本次加入队列的视频数
+#[frb]
+pub async fn check_download_rules_now() -> anyhow::Result<u32> {
+    run_rule_check().await
+}
+
+/// 启动后台规则检查（应用初始化时调用，重复调用无效）
+pub(crate) fn start_rule_checker() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    if STARTED.set(()).is_err() {
+        return;
+    }
+    runtime::spawn(async {
+        tokio::time::sleep(Duration::from_secs(INITIAL_CHECK_DELAY_SECS)).await;
+        loop {
+            match run_rule_check().await {
+                Ok(count) if count > 0 => {
+                    tracing::info!("download_rules enqueued={}", count);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("download_rules check_failed error={}", e),
+            }
+            tokio::time::sleep(check_interval()).await;
+        }
+    });
+}
+
+/// 首条规则创建/启用时将订阅首页的现有视频标记为已处理，只有之后的上传才算新视频
+async fn ensure_baseline_logged() {
+    let _guard = check_lock().lock().await;
+    if let Err(e) = ensure_baseline().await {
+        // 下次检查时重试
+        tracing::warn!("download_rules baseline_failed error={}", e);
+    }
+}
+
+/// 建立基线；需持有 check_lock
+async fn ensure_baseline() -> anyhow::Result<()> {
+    if storage::get_setting(BASELINE_KEY)?.is_some() {
+        return Ok(());
+    }
+    // 旧版本已检查过的订阅无需补建
+    if !storage::has_rule_seen_videos()? {
+        let url = format!("{}/subscriptions?page=1", network::base_url());
+        tracing::info!("download_rules baseline GET {}", url);
+        let html = network::get(&url).await?;
+        let (_authors, videos, _max_page) = parser::parse_subscriptions_page(&html)?;
+        for card in &videos {
+            storage::mark_rule_video_seen(&card.id, None)?;
+        }
+        tracing::info!("download_rules baseline videos={}", videos.len());
+    }
+    storage::save_setting(BASELINE_KEY, &chrono::Utc::now().timestamp().to_string())?;
+    Ok(())
+}
+
+/// 用于规则匹配的视频信息
+#[derive(Clone)]
+struct Candidate {
+    artist: Option<String>,
+    genre: Option<String>,
+    tags: Vec<String>,
+    /// 上传时间（秒级时间戳），无法解析时为空
+    uploaded_at: Option<i64>,
+}
+
+async fn run_rule_check() -> anyhow::Result<u32> {
+    let _guard = check_lock().lock().await;
+
+    let rules = storage::get_download_rules()?
+        .into_iter()
+        .filter(|r| r.enabled)
+        .collect::<Vec<_>>();
+    if rules.is_empty() {
+        return Ok(0);
+    }
+    ensure_baseline().await?;
+
+    // 翻页直到遇到已处理过的视频，避免一次上传超过一页时遗漏
+    let mut videos = Vec::new();
+    let mut ids = HashSet::new();
+    for page in 1..=MAX_SUBSCRIPTION_PAGES {
+        let url = format!("{}/subscriptions?page={}", network::base_url(), page);
+        tracing::info!("download_rules GET {}", url);
+        let html = network::get(&url).await?;
+        let (_authors, page_videos, max_page) = parser::parse_subscriptions_page(&html)?;
+        let mut reached_seen = page_videos.is_empty();
+        for card in page_videos {
+            if storage::is_rule_video_seen(&card.id)? {
+                reached_seen = true;
+            }
+            if ids.insert(card.id.clone()) {
+                videos.push(card);
+            }
+        }
+        if reached_seen || page >= max_page {
+            break;
+        }
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let day_start = chrono::Local::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|t| t.and_local_timezone(chrono::Local).earliest())
+        .map(|t| t.timestamp())
+        .unwrap_or(now - 24 * 60 * 60);
+    // 仅作者匹配且不限上传时间时，无需请求观看页
+    let needs_detail = rules
+        .iter()
+        .any(|r| r.match_type != "artist" || r.max_age_days.is_some());
+    let folders = storage::get_download_folders()?;
+
+    let mut enqueued = 0u32;
+    // 订阅页按时间倒序，反向遍历使下载顺序与上传顺序一致
+    for card in videos.into_iter().rev() {
+        if storage::is_rule_video_seen(&card.id)? {
+            continue;
+        }
+        if storage::get_download_by_video_id(&card.id)?.is_some() {
+            storage::mark_rule_video_seen(&card.id, None)?;
+            continue;
+        }
+
+        // 所有规则都达到当日上限时不再请求观看页，剩余视频次日再处理
+        let mut has_room = false;
+        for rule in &rules {
+            if !rule_capped(rule, day_start)? {
+                has_room = true;
+                break;
+            }
+        }
+        if !has_room {
+            break;
+        }
+
+        let pending = pending_details().lock().await.remove(&card.id);
+        let (candidate, description) = match pending {
+            Some(pending) => pending,
+            None if needs_detail => match fetch_candidate(&card.id, card.artist.clone()).await {
+                Ok(fetched) => fetched,
+                Err(e) => {
+                    // 下次检查时重试
+                    tracing::warn!(
+                        "download_rules detail_failed video_id={} error={}",
+                        card.id,
+                        e
+                    );
+                    continue;
+                }
+            },
+            None => (
+                Candidate {
+                    artist: card.artist.clone(),
+                    genre: None,
+                    tags: card.tags.clone(),
+                    uploaded_at: None,
+                },
+                None,
+            ),
+        };
+
+        let matching = rules
+            .iter()
+            .filter(|r| rule_matches(r, &candidate, now))
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            storage::mark_rule_video_seen(&card.id, None)?;
+            continue;
+        }
+        // 使用第一条未达到当日上限的匹配规则
+        let mut picked = None;
+        for rule in matching {
+            if !rule_capped(rule, day_start)? {
+                picked = Some(rule);
+                break;
+            }
+        }
+        let Some(rule) = picked else {
+            // 所有匹配规则都达到当日上限：不标记为已处理，次日再尝试
+            if needs_detail {
+                pending_details()
+                    .lock()
+                    .await
+                    .insert(card.id.clone(), (candidate, description));
+            }
+            continue;
+        };
+
+        let folder_id = rule
+            .folder_id
+            .clone()
+            .filter(|id| folders.iter().any(|f| &f.id == id && !f.is_smart()));
+        match enqueue_new_download(
+            card.id.clone(),
+            card.title,
+            card.cover_url,
+            rule.quality.clone(),
+            description,
+            candidate.tags,
+            folder_id,
+        )
+        .await
+        {
+            Ok(_) => {
+                storage::mark_rule_video_seen(&card.id, Some(&rule.id))?;
+                enqueued += 1;
+                tracing::info!(
+                    "download_rules enqueue video_id={} rule_id={}",
+                    card.id,
+                    rule.id
+                );
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "download_rules enqueue_failed video_id={} error={}",
+                    card.id,
+                    e
+                );
+            }
+        }
+    }
+
+    for rule in &rules {
+        let _ = storage::update_download_rule_checked_at(&rule.id, now);
+    }
+    Ok(enqueued)
+}
+
+/// 请求观看页获取作者、类型、标签与上传时间，同时返回简介；
+/// 观看页缺少作者信息时沿用订阅卡片上的作者
+async fn fetch_candidate(
+    video_id: &str,
+    card_artist: Option<String>,
+) -> anyhow::Result<(Candidate, Option<String>)> {
+    let watch_url = format!("{}/watch?v={}", network::base_url(), video_id);
+    let detail = parser::parse_video_detail(&network::get(&watch_url).await?)?;
+    let (artist, genre) = match detail.creator {
+        Some(creator) => (Some(creator.name), creator.genre),
+        None => (card_artist, None),
+    };
+    let candidate = Candidate {
+        artist,
+        genre,
+        tags: detail.tags,
+        uploaded_at: chrono::NaiveDate::parse_from_str(detail.upload_date.trim(), "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|t| t.and_utc().timestamp()),
+    };
+    let description = Some(detail.description).filter(|d| !d.trim().is_empty());
+    Ok((candidate, description))
+}
+
+fn rule_capped(rule: &storage::DownloadRuleRecord, day_start: i64) -> anyhow::Result<bool> {
+    Ok(match rule.daily_cap {
+        Some(cap) => storage::count_rule_downloads_since(&rule.id, day_start)? >= cap,
+        None => false,
+    })
+}
+
+fn rule_matches(rule: &storage::DownloadRuleRecord, candidate: &Candidate, now: i64) -> bool {
+    if let Some(days) = rule.max_age_days {
+        // 上传时间未知时无法确认在期限内，视为不匹配
+        let Some(uploaded_at) = candidate.uploaded_at else {
+            return false;
+        };
+        if now - uploaded_at > days * 24 * 60 * 60 {
+            return false;
+        }
+    }
+    let value = rule.match_value.trim();
+    let eq = |s: &str| s.trim().eq_ignore_ascii_case(value);
+    match rule.match_type.as_str() {
+        "artist" => candidate.artist.as_deref().is_some_and(eq),
+        "tag" => candidate.tags.iter().any(|t| eq(t)),
+        // 类型信息缺失时退化为按标签匹配
+        "genre" => {
+            candidate.genre.as_deref().is_some_and(eq) || candidate.tags.iter().any(|t| eq(t))
+        }
+        _ => false,
+    }
+}
+
+fn to_record(rule: &ApiDownloadRule) -> anyhow::Result<storage::DownloadRuleRecord> {
+    let match_type = rule.match_type.trim().to_ascii_lowercase();
+    if !MATCH_TYPES.contains(&match_type.as_str()) {
+        anyhow::bail!("Unsupported match type: {}", rule.match_type);
+    }
+    if rule.match_value.trim().is_empty() {
+        anyhow::bail!("Match value is empty");
+    }
+    if let Some(folder_id) = rule.folder_id.as_deref().filter(|id| !id.is_empty()) {
+        ensure_manual_folder(folder_id)?;
+    }
+    Ok(storage::DownloadRuleRecord {
+        id: rule.id.clone(),
+        name: rule.name.trim().to_string(),
+        match_type,
+        match_value: rule.match_value.trim().to_string(),
+        quality: rule.quality.clone(),
+        folder_id: rule.folder_id.clone().filter(|id| !id.is_empty()),
+        max_age_days: rule.max_age_days.map(i64::from),
+        daily_cap: rule.daily_cap.map(i64::from),
+        enabled: rule.enabled,
+        created_at: rule.created_at,
+        last_checked_at: rule.last_checked_at,
+    })
+}
+
+fn map_rule(record: storage::DownloadRuleRecord) -> ApiDownloadRule {
+    ApiDownloadRule {
+        id: record.id,
+        name: record.name,
+        match_type: record.match_type,
+        match_value: record.match_value,
+        quality: record.quality,
+        folder_id: record.folder_id,
+        max_age_days: record.max_age_days.map(|v| v.max(0) as u32),
+        daily_cap: record.daily_cap.map(|v| v.max(0) as u32),
+        enabled: record.enabled,
+        created_at: record.created_at,
+        last_checked_at: record.last_checked_at,
+    }
+}
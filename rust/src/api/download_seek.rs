@@ -0,0 +1,270 @@
+// 边下边播时的拖动预取
+// - 播放器拖动到尚未下载的位置时，本地播放服务发出提示，下载任务从该位置起另开一个 Range 请求写入文件
+// - 提前写入的区间记录在 download_ranges（半开区间，合并相邻部分），重启后续传仍可使用
+// - 顺序下载到达提前写入的区间时停止该区间的预取并直接跳到区间末尾继续
+// - 同一任务同时只有一个预取；新的拖动位置替换旧的预取
+// - 仅在下载进行中生效；任务暂停、取消或结束时预取随之停止
+
+use crate::api::download::{content_range_total, request_media};
+use crate::core::byte_ranges::RangeSet;
+use crate::core::throttle::TaskThrottle;
+use crate::core::{runtime, storage};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tokio::task::JoinHandle;
+
+/// 新的拖动位置在进行中的预取写入位置之后不超过该值时，沿用该预取
+const FETCH_AHEAD_SLACK_BYTES: u64 = 4 * 1024 * 1024;
+
+struct SeekTask {
+    save_path: PathBuf,
+    total: u64,
+    /// 顺序下载的当前位置
+    prefix: u64,
+    /// 提前写入的区间（不含顺序下载的开头部分）
+    ranges: RangeSet,
+    /// 进行中的预取：(起始位置, 任务)
+    fetch: Option<(u64, JoinHandle<()>)>,
+    /// 播放源不支持预取（不支持 Range 或文件已变化），之后的提示直接拒绝
+    unsupported: bool,
+}
+
+fn seek_tasks() -> &'static Mutex<HashMap<String, SeekTask>> {
+    static MAP: OnceLock<Mutex<HashMap<String, SeekTask>>> = OnceLock::new();
+    MAP.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 预取登记（顺序下载结束时 drop，停止预取并注销）
+pub(crate) struct SeekRegistration {
+    video_id: String,
+}
+
+impl Drop for SeekRegistration {
+    fn drop(&mut self) {
+        let task = seek_tasks().lock().unwrap().remove(&self.video_id);
+        if let Some((_, handle)) = task.and_then(|t| t.fetch) {
+            handle.abort();
+        }
+    }
+}
+
+/// 顺序下载开始写入文件时登记；载入上次提前写入、位于 `prefix` 之后的区间
+pub(crate) fn register(
+    video_id: &str,
+    save_path: &Path,
+    prefix: u64,
+    total: u64,
+) -> anyhow::Result<SeekRegistration> {
+    let saved = RangeSet::from_ranges(storage::get_download_ranges(video_id)?);
+    let mut ranges = saved.clone();
+    ranges.remove_before(prefix);
+    if ranges != saved {
+        storage::save_download_ranges(video_id, ranges.ranges())?;
+    }
+    let task = SeekTask {
+        save_path: save_path.to_path_buf(),
+        total,
+        prefix,
+        ranges,
+        fetch: None,
+        unsupported: false,
+    };
+    let old = seek_tasks()
+        .lock()
+        .unwrap()
+        .insert(video_id.to_string(), task);
+    if let Some((_, handle)) = old.and_then(|t| t.fetch) {
+        handle.abort();
+    }
+    Ok(SeekRegistration {
+        video_id: video_id.to_string(),
+    })
+}
+
+/// 清除提前写入的区间（从头重新下载或下载完成时）
+pub(crate) fn clear(video_id: &str) -> anyhow::Result<()> {
+    if let Some(task) = seek_tasks().lock().unwrap().get_mut(video_id) {
+        if let Some((_, handle)) = task.fetch.take() {
+            handle.abort();
+        }
+        task.ranges = RangeSet::default();
+        task.prefix = 0;
+    }
+    storage::save_download_ranges(video_id, &[])?;
+    Ok(())
+}
+
+/// 播放器请求的位置 `offset` 尚未下载：从该位置起预取
+///
+/// 返回 `false` 表示无法预取（下载未在进行、总大小未知或播放源不支持），调用方应改用其他方式获取
+pub(crate) fn seek_hint(video_id: &str, offset: u64) -> bool {
+    let mut tasks = seek_tasks().lock().unwrap();
+    let Some(task) = tasks.get_mut(video_id) else {
+        return false;
+    };
+    if task.unsupported || task.total == 0 || offset >= task.total {
+        return false;
+    }
+    if offset < task.prefix || task.ranges.covered_end(offset).is_some() {
+        return true;
+    }
+    if let Some((start, handle)) = task.fetch.as_ref() {
+        let written = task.ranges.covered_end(*start).unwrap_or(*start);
+        if !handle.is_finished()
+            && offset >= *start
+            && offset <= written.saturating_add(FETCH_AHEAD_SLACK_BYTES)
+        {
+            return true;
+        }
+        handle.abort();
+    }
+    tracing::info!(
+        "download seek_prefetch video_id={} offset={}",
+        video_id,
+        offset
+    );
+    let id = video_id.to_string();
+    let handle = runtime::spawn(async move {
+        if let Err(e) = fetch_ahead(&id, offset).await {
+            tracing::warn!(
+                "download seek_prefetch_failed video_id={} offset={} error={}",
+                id,
+                offset,
+                e
+            );
+        }
+    });
+    task.fetch = Some((offset, handle));
+    true
+}
+
+/// 顺序下载写到 `pos`：`pos` 已提前写入时停止该区间的预取，返回顺序下载应跳到的位置
+pub(crate) fn skip_covered(video_id: &str, pos: u64) -> anyhow::Result<Option<u64>> {
+    let mut tasks = seek_tasks().lock().unwrap();
+    let Some(task) = tasks.get_mut(video_id) else {
+        return Ok(None);
+    };
+    task.prefix = pos;
+    let first_start = match task.ranges.ranges().first() {
+        Some(&(start, _)) => start,
+        None => return Ok(None),
+    };
+    let skip_to = task.ranges.covered_end(pos);
+    if let Some(end) = skip_to {
+        // 预取正在写这个区间：停止，剩余部分由顺序下载继续
+        if let Some((start, handle)) = task.fetch.as_ref() {
+            if task.ranges.covered_end(*start) == Some(end) {
+                handle.abort();
+                task.fetch = None;
+            }
+        }
+        task.ranges.remove_before(end);
+        task.prefix = end;
+    } else if first_start < pos {
+        task.ranges.remove_before(pos);
+    } else {
+        return Ok(None);
+    }
+    storage::save_download_ranges(video_id, task.ranges.ranges())?;
+    Ok(skip_to)
+}
+
+/// 下载进行中时提前写入的区间；未在下载时读取上次保存的区间
+pub(crate) fn written_ranges(video_id: &str) -> anyhow::Result<RangeSet> {
+    if let Some(task) = seek_tasks().lock().unwrap().get(video_id) {
+        return Ok(task.ranges.clone());
+    }
+    Ok(RangeSet::from_ranges(storage::get_download_ranges(
+        video_id,
+    )?))
+}
+
+/// 从 `offset` 起下载并写入文件，到达文件末尾、已写入的区间或顺序下载位置时停止
+async fn fetch_ahead(video_id: &str, offset: u64) -> anyhow::Result<()> {
+    use futures_util::StreamExt;
+    use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+    let Some((save_path, total)) = seek_tasks()
+        .lock()
+        .unwrap()
+        .get(video_id)
+        .map(|t| (t.save_path.clone(), t.total))
+    else {
+        return Ok(());
+    };
+    let record = storage::get_download_by_video_id(video_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download not found"))?;
+    let url = record
+        .source_url
+        .filter(|u| !u.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Download has no source url"))?;
+    let resp = request_media(&url, offset).await?;
+    if resp.status() != reqwest::StatusCode::PARTIAL_CONTENT
+        || content_range_total(&resp) != Some(total)
+    {
+        if let Some(task) = seek_tasks().lock().unwrap().get_mut(video_id) {
+            task.unsupported = true;
+        }
+        anyhow::bail!("Source does not support ranged requests");
+    }
+
+    // 文件扩展到完整大小后按偏移写入；之后续传以数据库记录的进度为准（同预分配的文件）
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .open(&save_path)
+        .await?;
+    if file.metadata().await?.len() < total {
+        file.set_len(total).await?;
+        storage::update_download_preallocated(video_id, true)?;
+    }
+    file.seek(std::io::SeekFrom::Start(offset)).await?;
+
+    let mut throttle = TaskThrottle::new(video_id, None);
+    let mut pos = offset;
+    let mut stream = resp.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        let mut data = &chunk[..];
+        while !data.is_empty() {
+            // 写到下一个已写入区间或顺序下载位置为止
+            let limit = {
+                let tasks = seek_tasks().lock().unwrap();
+                let Some(task) = tasks.get(video_id) else {
+                    return Ok(());
+                };
+                if pos < task.prefix || task.ranges.covered_end(pos).is_some() {
+                    return Ok(());
+                }
+                task.ranges
+                    .ranges()
+                    .iter()
+                    .map(|&(start, _)| start)
+                    .find(|&start| start > pos)
+                    .unwrap_or(total)
+                    .min(total)
+            };
+            let n = data.len().min((limit - pos) as usize);
+            file.write_all(&data[..n]).await?;
+            file.flush().await?;
+            {
+                let mut tasks = seek_tasks().lock().unwrap();
+                let Some(task) = tasks.get_mut(video_id) else {
+                    return Ok(());
+                };
+                task.ranges.insert(pos, pos + n as u64);
+                storage::save_download_ranges(video_id, task.ranges.ranges())?;
+            }
+            pos += n as u64;
+            data = &data[n..];
+            if pos >= limit {
+                return Ok(());
+            }
+        }
+        let wait = throttle.reserve(chunk.len());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+    Ok(())
+}
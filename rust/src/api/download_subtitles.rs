@@ -0,0 +1,145 @@
+// 下载字幕 API
+// - 下载视频时一并下载播放器提供的字幕轨道，保存到下载根目录的 download_subtitles 下
+// - WebVTT 字幕转换为 SRT 保存（多数本地播放器与媒体服务器都支持 SRT）
+// - 字幕记录保存在 download_subtitles 表，删除下载时一并删除
+
+use crate::api::download::{basename, resolve_under_downloads_root};
+use crate::api::download_events;
+use crate::api::models::ApiDownloadSubtitle;
+use crate::core::parser::SubtitleTrack;
+use crate::core::{network, storage, subtitle};
+use flutter_rust_bridge::frb;
+use std::path::PathBuf;
+
+pub(crate) const SUBTITLES_DIR: &str = "download_subtitles";
+
+/// 获取下载任务的字幕（`file_path` 为空表示尚未下载）
+#[frb]
+pub async fn get_download_subtitles(task_id: String) -> anyhow::Result<Vec<ApiDownloadSubtitle>> {
+    Ok(storage::get_download_subtitles(&task_id)?
+        .into_iter()
+        .map(|s| ApiDownloadSubtitle {
+            file_path: s
+                .file_path
+                .as_deref()
+                .and_then(subtitle_path)
+                .filter(|p| p.is_file())
+                .map(|p| p.to_string_lossy().into_owned()),
+            language: s.language,
+            label: s.label,
+            url: s.url,
+            format: s.format,
+        })
+        .collect())
+}
+
+/// 下载字幕轨道；已下载的轨道跳过，失败只记录事件，不影响视频下载
+pub(crate) async fn fetch_subtitles(video_id: &str, tracks: &[SubtitleTrack]) {
+    let saved = storage::get_download_subtitles(video_id).unwrap_or_default();
+    for (index, track) in tracks.iter().enumerate() {
+        let done = saved.iter().any(|s| {
+            s.language == track.language
+                && s.label == track.label
+                && s.file_path
+                    .as_deref()
+                    .and_then(subtitle_path)
+                    .is_some_and(|p| p.is_file())
+        });
+        if done {
+            continue;
+        }
+        match fetch_subtitle(video_id, index + 1, track).await {
+            Ok(record) => {
+                if let Err(e) = storage::upsert_download_subtitle(&record) {
+                    tracing::warn!(
+                        "download subtitle_save_failed video_id={} err={}",
+                        video_id,
+                        e
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "download subtitle_failed video_id={} language={} err={}",
+                    video_id,
+                    track.language,
+                    e
+                );
+                download_events::record(storage::DownloadEventRecord {
+                    message: Some(format!("Subtitle {}: {}", track.label, e)),
+                    source_host: download_events::source_host(&track.url),
+                    format: Some(track.format.clone()),
+                    ..storage::DownloadEventRecord::new(video_id, "subtitle_failed")
+                });
+            }
+        }
+    }
+}
+
+async fn fetch_subtitle(
+    video_id: &str,
+    index: usize,
+    track: &SubtitleTrack,
+) -> anyhow::Result<storage::DownloadSubtitleRecord> {
+    let resp = network::media_request(&track.url)?.send().await?;
+    if !resp.status().is_success() {
+        anyhow::bail!("HTTP {}", resp.status().as_u16());
+    }
+    let bytes = resp.bytes().await?;
+    let (format, data) = if track.format == "vtt" {
+        let text = String::from_utf8_lossy(&bytes);
+        ("srt".to_string(), subtitle::vtt_to_srt(&text).into_bytes())
+    } else {
+        (track.format.clone(), bytes.to_vec())
+    };
+    let file_name = subtitle_file_name(video_id, index, &track.language, &format);
+    let path = resolve_under_downloads_root(SUBTITLES_DIR, &file_name)?;
+    tokio::fs::write(&path, &data).await?;
+    Ok(storage::DownloadSubtitleRecord {
+        video_id: video_id.to_string(),
+        language: track.language.clone(),
+        label: track.label.clone(),
+        url: track.url.clone(),
+        format,
+        file_path: Some(file_name),
+    })
+}
+
+/// `{video_id}_{序号}.{语言}.{格式}`，语言未标注时为 `und`
+fn subtitle_file_name(video_id: &str, index: usize, language: &str, format: &str) -> String {
+    let language = language
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect::<String>();
+    let language = if language.is_empty() {
+        "und"
+    } else {
+        &language
+    };
+    format!("{}_{}.{}.{}", video_id, index, language, format)
+}
+
+/// 数据库中保存的字幕文件名 → 本地路径
+pub(crate) fn subtitle_path(stored: &str) -> Option<PathBuf> {
+    let name = basename(stored)?;
+    resolve_under_downloads_root(SUBTITLES_DIR, &name).ok()
+}
+
+/// 已下载到本地的字幕（记录, 本地路径）
+pub(crate) fn local_subtitles(video_id: &str) -> Vec<(storage::DownloadSubtitleRecord, PathBuf)> {
+    storage::get_download_subtitles(video_id)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|s| {
+            let path = s.file_path.as_deref().and_then(subtitle_path)?;
+            path.is_file().then_some((s, path))
+        })
+        .collect()
+}
+
+/// 删除下载的字幕文件（记录由 storage::delete_download 删除）
+pub(crate) fn delete_subtitle_files(video_id: &str) {
+    for (_, path) in local_subtitles(video_id) {
+        let _ = std::fs::remove_file(path);
+    }
+}
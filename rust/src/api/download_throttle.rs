@@ -0,0 +1,101 @@
+// 下载限速 API
+// - 全局限速与单任务默认限速保存在 settings 表，修改后立即作用于正在进行的下载
+// - 任务单独限速保存在 downloads 表（画质变体沿用所属视频的设置）
+// - 「仅在播放时限速」依赖播放器通过 `set_player_streaming` 上报播放状态
+
+use crate::api::models::ApiDownloadBandwidthSettings;
+use crate::core::{storage, throttle};
+use flutter_rust_bridge::frb;
+
+const GLOBAL_LIMIT_KEY: &str = "download_bandwidth_global_kb";
+const TASK_LIMIT_KEY: &str = "download_bandwidth_task_kb";
+const ONLY_WHILE_STREAMING_KEY: &str = "download_bandwidth_only_streaming";
+
+fn load_settings() -> anyhow::Result<ApiDownloadBandwidthSettings> {
+    let read_u32 = |key: &str| -> anyhow::Result<u32> {
+        Ok(storage::get_setting(key)?
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(0))
+    };
+    Ok(ApiDownloadBandwidthSettings {
+        global_kb_per_sec: read_u32(GLOBAL_LIMIT_KEY)?,
+        task_kb_per_sec: read_u32(TASK_LIMIT_KEY)?,
+        only_while_streaming: storage::get_setting(ONLY_WHILE_STREAMING_KEY)?.as_deref()
+            == Some("true"),
+        player_streaming: throttle::player_streaming(),
+    })
+}
+
+/// 获取下载限速设置
+#[frb]
+pub async fn get_download_bandwidth_settings() -> anyhow::Result<ApiDownloadBandwidthSettings> {
+    load_settings()
+}
+
+/// 保存下载限速设置（立即生效）
+#[frb]
+pub async fn set_download_bandwidth_settings(
+    settings: ApiDownloadBandwidthSettings,
+) -> anyhow::Result<bool> {
+    storage::save_setting(GLOBAL_LIMIT_KEY, &settings.global_kb_per_sec.to_string())?;
+    storage::save_setting(TASK_LIMIT_KEY, &settings.task_kb_per_sec.to_string())?;
+    storage::save_setting(
+        ONLY_WHILE_STREAMING_KEY,
+        if settings.only_while_streaming {
+            "true"
+        } else {
+            "false"
+        },
+    )?;
+    throttle::set_limits(
+        settings.global_kb_per_sec,
+        settings.task_kb_per_sec,
+        settings.only_while_streaming,
+    );
+    tracing::info!(
+        "download bandwidth_settings global_kb={} task_kb={} only_while_streaming={}",
+        settings.global_kb_per_sec,
+        settings.task_kb_per_sec,
+        settings.only_while_streaming
+    );
+    Ok(true)
+}
+
+/// 获取任务单独设置的限速（KB/s，0 表示不限），为空表示使用默认值
+#[frb]
+pub async fn get_download_task_bandwidth_limit(task_id: String) -> anyhow::Result<Option<u32>> {
+    let record = storage::get_download_by_video_id(&task_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download not found"))?;
+    Ok(record.bandwidth_limit_kb)
+}
+
+/// 设置任务单独的限速（KB/s，0 表示不限），为空时恢复使用默认值（立即生效）
+#[frb]
+pub async fn set_download_task_bandwidth_limit(
+    task_id: String,
+    kb_per_sec: Option<u32>,
+) -> anyhow::Result<bool> {
+    if storage::get_download_by_video_id(&task_id)?.is_none() {
+        anyhow::bail!("Download not found");
+    }
+    storage::update_download_bandwidth_limit(&task_id, kb_per_sec)?;
+    throttle::set_task_limit(&task_id, kb_per_sec);
+    Ok(true)
+}
+
+/// 播放器开始/停止播放在线视频（播放本地文件时无需上报）
+#[frb]
+pub fn set_player_streaming(active: bool) {
+    throttle::set_player_streaming(active);
+}
+
+/// 启动时从设置恢复限速
+pub(crate) fn load_bandwidth_settings() -> anyhow::Result<()> {
+    let settings = load_settings()?;
+    throttle::set_limits(
+        settings.global_kb_per_sec,
+        settings.task_kb_per_sec,
+        settings.only_while_streaming,
+    );
+    Ok(())
+}
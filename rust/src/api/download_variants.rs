@@ -0,0 +1,583 @@
+// 下载画质变体 API
+// - 主下载保存在 downloads 表，其他画质保存在 download_variants 表
+// - 画质升级：新画质与旧文件并存下载，完成后在单个事务内替换主下载
+//   （文件夹、封面、作者信息保留在主记录上，观看进度按 video_id 关联不受影响）
+// - 变体必须精确匹配请求的画质，不做回退；失败后不自动重试，可手动继续
+
+use crate::api::download::{
+    acquire_download_permit, basename, build_download_filename, content_range_total,
+    downloads_suspended, ensure_free_space, find_source_or_variant, notify_download_changed,
+    request_media, resolve_download_path, stream_ended_early, verify_downloaded_file,
+    DownloadControl, DownloadErrorKind,
+};
+use crate::api::models::{ApiDownloadStatus, ApiDownloadVariant};
+use crate::api::{download_hls, download_quota};
+use crate::core::media_verify::MediaCheck;
+use crate::core::{network, parser, runtime, storage, throttle};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::sync::{broadcast, watch, Mutex};
+
+fn variant_controls() -> &'static Mutex<HashMap<i64, watch::Sender<DownloadControl>>> {
+    static MAP: OnceLock<Mutex<HashMap<i64, watch::Sender<DownloadControl>>>> = OnceLock::new();
+    MAP.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn variant_sender() -> &'static broadcast::Sender<ApiDownloadVariant> {
+    static TX: OnceLock<broadcast::Sender<ApiDownloadVariant>> = OnceLock::new();
+    TX.get_or_init(|| broadcast::channel(256).0)
+}
+
+/// 升级已下载视频的画质
+///
+/// - 新画质在后台下载，期间旧文件仍可播放；完成后自动替换主下载
+/// - `keep_previous` 为 true 时保留旧画质为变体，否则删除旧文件
+/// - 该画质已作为变体下载完成时立即替换
+#[frb]
+pub async fn upgrade_download(
+    video_id: String,
+    quality: String,
+    keep_previous: bool,
+) -> anyhow::Result<ApiDownloadVariant> {
+    let record = storage::get_download_by_video_id(&video_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download task not found"))?;
+    if record.status != storage::DownloadStatus::Completed {
+        anyhow::bail!("Only completed downloads can be upgraded");
+    }
+    let quality = check_new_quality(&record, &quality)?;
+
+    let existing = storage::get_download_variants(Some(&video_id))?
+        .into_iter()
+        .find(|v| v.quality.eq_ignore_ascii_case(&quality));
+    if let Some(variant) = existing {
+        if variant.status != storage::DownloadStatus::Completed {
+            anyhow::bail!("Quality {} is already being downloaded", variant.quality);
+        }
+        promote(&variant, keep_previous)?;
+        let variant = storage::get_download_variant(variant.id)?.unwrap_or(variant);
+        return Ok(map_variant(variant));
+    }
+
+    let id = storage::create_download_variant(&video_id, &quality, true, keep_previous)?;
+    start_variant(id)
+}
+
+/// 额外下载一个画质，与主下载并存
+#[frb]
+pub async fn add_download_variant(
+    video_id: String,
+    quality: String,
+) -> anyhow::Result<ApiDownloadVariant> {
+    let record = storage::get_download_by_video_id(&video_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download task not found"))?;
+    let quality = check_new_quality(&record, &quality)?;
+    if storage::get_download_variants(Some(&video_id))?
+        .iter()
+        .any(|v| v.quality.eq_ignore_ascii_case(&quality))
+    {
+        anyhow::bail!("Quality {} already exists", quality);
+    }
+
+    let id = storage::create_download_variant(&video_id, &quality, false, false)?;
+    start_variant(id)
+}
+
+/// 获取视频的画质变体（不含主下载）
+#[frb]
+pub async fn get_download_variants(video_id: String) -> anyhow::Result<Vec<ApiDownloadVariant>> {
+    Ok(storage::get_download_variants(Some(&video_id))?
+        .into_iter()
+        .map(map_variant)
+        .collect())
+}
+
+/// 将已下载完成的变体设为主下载
+///
+/// `keep_previous` 为 true 时原主下载转为变体，否则删除原文件
+#[frb]
+pub async fn set_primary_download_variant(
+    variant_id: i64,
+    keep_previous: bool,
+) -> anyhow::Result<bool> {
+    let variant = storage::get_download_variant(variant_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download variant not found"))?;
+    if variant.status != storage::DownloadStatus::Completed {
+        anyhow::bail!("Variant is not completed");
+    }
+    promote(&variant, keep_previous)?;
+    Ok(true)
+}
+
+/// 暂停变体下载
+#[frb]
+pub async fn pause_download_variant(variant_id: i64) -> anyhow::Result<bool> {
+    storage::update_download_variant_status(variant_id, storage::DownloadStatus::Paused, None)?;
+    if let Some(tx) = variant_controls().lock().await.get(&variant_id).cloned() {
+        let _ = tx.send(DownloadControl::Paused);
+    }
+    notify_variant(variant_id);
+    Ok(true)
+}
+
+/// 继续变体下载（暂停或失败后）
+#[frb]
+pub async fn resume_download_variant(variant_id: i64) -> anyhow::Result<bool> {
+    let variant = storage::get_download_variant(variant_id)?
+        .ok_or_else(|| anyhow::anyhow!("Download variant not found"))?;
+    if variant.status == storage::DownloadStatus::Completed {
+        return Ok(false);
+    }
+    storage::update_download_variant_status(variant_id, storage::DownloadStatus::Queued, None)?;
+    spawn_variant(variant_id);
+    notify_variant(variant_id);
+    Ok(true)
+}
+
+/// 取消 / 删除变体（进行中的升级会被放弃，主下载不受影响）
+#[frb]
+pub async fn delete_download_variant(variant_id: i64, delete_file: bool) -> anyhow::Result<bool> {
+    let Some(variant) = storage::get_download_variant(variant_id)? else {
+        return Ok(false);
+    };
+    cancel_task(variant_id).await;
+    if delete_file {
+        remove_variant_file(&variant);
+    }
+    storage::delete_download_variant(variant_id)?;
+    Ok(true)
+}
+
+/// 监听变体下载进度
+#[frb]
+pub fn subscribe_download_variant_progress(sink: StreamSink<ApiDownloadVariant>) {
+    let mut rx = variant_sender().subscribe();
+    std::thread::spawn(move || loop {
+        match rx.blocking_recv() {
+            Ok(item) => {
+                let _ = sink.add(item);
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+        }
+    });
+}
+
+/// 启动所有排队中的变体（应用启动、下载目录迁移结束时调用）
+pub(crate) async fn resume_variant_downloads() -> anyhow::Result<()> {
+    for variant in storage::get_download_variants(None)? {
+        if matches!(
+            variant.status,
+            storage::DownloadStatus::Queued | storage::DownloadStatus::Downloading
+        ) {
+            spawn_variant(variant.id);
+        }
+    }
+    Ok(())
+}
+
+/// 停止所有进行中的变体任务（状态保持不变，之后由 `resume_variant_downloads` 恢复）
+pub(crate) async fn stop_variant_tasks() {
+    for tx in variant_controls().lock().await.values() {
+        let _ = tx.send(DownloadControl::Canceled);
+    }
+}
+
+pub(crate) async fn has_variant_tasks() -> bool {
+    !variant_controls().lock().await.is_empty()
+}
+
+/// 删除主下载时一并删除其变体
+pub(crate) async fn delete_variants_of(video_id: &str, delete_file: bool) {
+    let Ok(variants) = storage::get_download_variants(Some(video_id)) else {
+        return;
+    };
+    for variant in variants {
+        cancel_task(variant.id).await;
+        if delete_file {
+            remove_variant_file(&variant);
+        }
+    }
+}
+
+/// 变体必须与主下载的实际画质不同
+fn check_new_quality(record: &storage::DownloadRecord, quality: &str) -> anyhow::Result<String> {
+    let quality = quality.trim();
+    if quality.is_empty() || quality.eq_ignore_ascii_case("auto") {
+        anyhow::bail!("A specific quality is required");
+    }
+    let current = record
+        .source_quality
+        .as_deref()
+        .or(record.quality.as_deref())
+        .unwrap_or("");
+    if current.eq_ignore_ascii_case(quality) {
+        anyhow::bail!("Download is already in {}", quality);
+    }
+    Ok(quality.to_string())
+}
+
+fn start_variant(id: i64) -> anyhow::Result<ApiDownloadVariant> {
+    spawn_variant(id);
+    let variant = storage::get_download_variant(id)?
+        .ok_or_else(|| anyhow::anyhow!("Download variant not found"))?;
+    Ok(map_variant(variant))
+}
+
+/// 替换主下载并清理旧文件
+fn promote(variant: &storage::DownloadVariantRecord, keep_previous: bool) -> anyhow::Result<()> {
+    let replaced = storage::promote_download_variant(variant.id, keep_previous)?;
+    // 旧文件名与新文件相同时（同名覆盖下载）不能删除
+    if let Some(name) = replaced.as_deref().and_then(basename) {
+        if variant.save_path.as_deref().and_then(basename).as_deref() != Some(name.as_str()) {
+            if let Ok(path) = resolve_download_path(&name) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+    tracing::info!(
+        "download variant_promoted video_id={} quality={} keep_previous={}",
+        variant.video_id,
+        variant.quality,
+        keep_previous
+    );
+    notify_download_changed(&variant.video_id);
+    notify_variant(variant.id);
+    Ok(())
+}
+
+async fn cancel_task(id: i64) {
+    if let Some(tx) = variant_controls().lock().await.remove(&id) {
+        let _ = tx.send(DownloadControl::Canceled);
+    }
+}
+
+/// 变体的本地文件
+pub(crate) fn variant_files(video_id: &str) -> Vec<PathBuf> {
+    storage::get_download_variants(Some(video_id))
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|v| v.save_path.as_deref().and_then(basename))
+        .filter_map(|name| resolve_download_path(&name).ok())
+        .collect()
+}
+
+fn remove_variant_file(variant: &storage::DownloadVariantRecord) {
+    if let Some(name) = variant.save_path.as_deref().and_then(basename) {
+        if let Ok(path) = resolve_download_path(&name) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn spawn_variant(id: i64) {
+    runtime::spawn(async move {
+        let Some(permit) = acquire_download_permit().await else {
+            return;
+        };
+        let mut map = variant_controls().lock().await;
+        if map.contains_key(&id) || downloads_suspended() {
+            drop(permit);
+            return;
+        }
+        let (tx, rx) = watch::channel(DownloadControl::Running);
+        map.insert(id, tx);
+        drop(map);
+
+        let result = run_variant(id, rx).await;
+        let _ = variant_controls().lock().await.remove(&id);
+        drop(permit);
+
+        if let Err(e) = result {
+            let kind = DownloadErrorKind::classify(&e);
+            tracing::warn!(
+                "download variant_failed id={} kind={} error={}",
+                id,
+                kind.as_str(),
+                e
+            );
+            let _ = storage::update_download_variant_status(
+                id,
+                storage::DownloadStatus::Failed,
+                Some(&e.to_string()),
+            );
+        }
+        notify_variant(id);
+    });
+}
+
+async fn run_variant(id: i64, mut ctrl_rx: watch::Receiver<DownloadControl>) -> anyhow::Result<()> {
+    let Some(variant) = storage::get_download_variant(id)? else {
+        return Ok(());
+    };
+    if matches!(
+        variant.status,
+        storage::DownloadStatus::Paused | storage::DownloadStatus::Completed
+    ) {
+        return Ok(());
+    }
+    let video_id = variant.video_id.clone();
+
+    let (mut url, hls) = fetch_source(&variant).await?;
+    // 与主下载共用限速设置（任务单独限速沿用所属视频）
+    let limit = storage::get_download_by_video_id(&video_id)?.and_then(|r| r.bandwidth_limit_kb);
+    // HLS 播放源与主下载一样按分片下载
+    if hls {
+        let hint = variant
+            .save_path
+            .as_deref()
+            .and_then(basename)
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        return download_hls::run_hls_download(
+            download_hls::HlsTarget::Variant(&variant),
+            &url,
+            &variant.quality,
+            limit,
+            hint,
+            &mut ctrl_rx,
+        )
+        .await;
+    }
+    let file_name = match variant.save_path.as_deref().and_then(basename) {
+        Some(name) => name,
+        None => {
+            let name = variant_file_name(&variant, "mp4")?;
+            storage::update_download_variant_save_path(id, &name)?;
+            name
+        }
+    };
+    let save_path = resolve_download_path(&file_name)?;
+    storage::update_download_variant_status(id, storage::DownloadStatus::Downloading, None)?;
+    notify_variant(id);
+
+    let offset = std::fs::metadata(&save_path).map(|m| m.len()).unwrap_or(0);
+    let (resp, mut downloaded, total) = open_variant_stream(
+        &variant,
+        &mut url,
+        offset,
+        variant.total_bytes.max(0) as u64,
+    )
+    .await?;
+    ensure_free_space(&save_path, total.saturating_sub(downloaded), &video_id)?;
+    download_quota::ensure_quota(&video_id, total.saturating_sub(downloaded)).await?;
+
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+    let mut file = if downloaded > 0 {
+        tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(&save_path)
+            .await?
+    } else {
+        tokio::fs::File::create(&save_path).await?
+    };
+    let mut stream = resp.bytes_stream();
+    let mut throttle = throttle::TaskThrottle::new(&video_id, limit);
+    let throttle_wait = tokio::time::sleep(std::time::Duration::ZERO);
+    tokio::pin!(throttle_wait);
+    loop {
+        tokio::select! {
+            changed = ctrl_rx.changed() => {
+                let _ = changed;
+                let control = *ctrl_rx.borrow();
+                match control {
+                    DownloadControl::Paused => {
+                        file.flush().await?;
+                        storage::update_download_variant_status(id, storage::DownloadStatus::Paused, None)?;
+                        return Ok(());
+                    }
+                    DownloadControl::Canceled => {
+                        file.flush().await?;
+                        return Ok(());
+                    }
+                    DownloadControl::Running => {}
+                }
+            }
+            _ = &mut throttle_wait, if !throttle_wait.is_elapsed() => {}
+            chunk = stream.next(), if throttle_wait.is_elapsed() => {
+                match chunk {
+                    Some(Ok(bytes)) => {
+                        file.write_all(&bytes).await?;
+                        downloaded += bytes.len() as u64;
+                        let wait = throttle.reserve(bytes.len());
+                        if !wait.is_zero() {
+                            throttle_wait.as_mut().reset(tokio::time::Instant::now() + wait);
+                        }
+                        let _ = storage::update_download_variant_progress(id, downloaded as i64, total as i64);
+                        notify_variant(id);
+                    }
+                    Some(Err(e)) => {
+                        file.flush().await?;
+                        return Err(e.into());
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+    file.flush().await?;
+    drop(file);
+
+    if total > 0 && downloaded < total {
+        return Err(stream_ended_early(downloaded, total).into());
+    }
+    match verify_downloaded_file(save_path, total).await? {
+        MediaCheck::Ok => {}
+        MediaCheck::Truncated { actual, expected } => {
+            let _ = storage::update_download_variant_progress(id, actual as i64, expected as i64);
+            return Err(stream_ended_early(actual, expected).into());
+        }
+        MediaCheck::Invalid(reason) => {
+            storage::update_download_variant_status(
+                id,
+                storage::DownloadStatus::Corrupt,
+                Some(&reason),
+            )?;
+            return Ok(());
+        }
+    }
+
+    complete_variant(&variant)
+}
+
+/// 变体下载完成；需要替换主下载时立即替换
+pub(crate) fn complete_variant(variant: &storage::DownloadVariantRecord) -> anyhow::Result<()> {
+    storage::update_download_variant_status(variant.id, storage::DownloadStatus::Completed, None)?;
+    if variant.replace_primary {
+        if let Some(variant) = storage::get_download_variant(variant.id)? {
+            promote(&variant, variant.keep_previous)?;
+        }
+    }
+    Ok(())
+}
+
+/// 打开媒体流，返回 `(响应, 实际起始偏移, 文件总长度)`
+///
+/// 地址过期时刷新一次；服务端不支持 Range 或文件已变化时从头下载
+async fn open_variant_stream(
+    variant: &storage::DownloadVariantRecord,
+    url: &mut String,
+    offset: u64,
+    expected_total: u64,
+) -> anyhow::Result<(reqwest::Response, u64, u64)> {
+    let mut offset = offset;
+    let mut refreshed = false;
+    loop {
+        let resp = match request_media(url, offset).await {
+            Ok(resp) => resp,
+            Err(e)
+                if !refreshed
+                    && DownloadErrorKind::classify(&e) == DownloadErrorKind::SourceExpired =>
+            {
+                *url = fetch_source(variant).await?.0;
+                refreshed = true;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        if offset == 0 || resp.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+            let total = resp.content_length().unwrap_or(0);
+            return Ok((resp, 0, total));
+        }
+        let total = content_range_total(&resp)
+            .unwrap_or_else(|| offset + resp.content_length().unwrap_or(0));
+        if expected_total > 0 && total != expected_total {
+            offset = 0;
+            continue;
+        }
+        return Ok((resp, offset, total));
+    }
+}
+
+/// 访问 watch 页获取精确匹配画质的地址，返回 `(地址, 是否为 HLS 播放源)`
+async fn fetch_source(variant: &storage::DownloadVariantRecord) -> anyhow::Result<(String, bool)> {
+    let watch_url = format!("{}/watch?v={}", network::base_url(), variant.video_id);
+    let html = network::get(&watch_url).await?;
+    let detail = parser::parse_video_detail(&html)?;
+    let pick = find_source_or_variant(&detail.video_sources, &variant.quality)
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Quality {} is not available", variant.quality))?;
+    storage::update_download_variant_source(variant.id, &pick.url, &pick.quality)?;
+    tracing::info!(
+        "download variant_source id={} video_id={} quality={} url_len={}",
+        variant.id,
+        variant.video_id,
+        pick.quality,
+        pick.url.len()
+    );
+    Ok((
+        pick.url.clone(),
+        download_hls::is_hls_source(&pick.format, &pick.url),
+    ))
+}
+
+/// 变体文件名：`{id}_{quality}.{ext}`，与主下载或其他变体重名时追加变体 ID
+pub(crate) fn variant_file_name(
+    variant: &storage::DownloadVariantRecord,
+    ext: &str,
+) -> anyhow::Result<String> {
+    let name = build_download_filename(&variant.video_id, &variant.quality, ext);
+    let primary = storage::get_download_by_video_id(&variant.video_id)?
+        .and_then(|r| r.save_path.as_deref().and_then(basename));
+    let taken = primary.as_deref() == Some(name.as_str())
+        || storage::get_download_variants(Some(&variant.video_id))?
+            .iter()
+            .any(|v| v.id != variant.id && v.save_path.as_deref() == Some(name.as_str()));
+    if taken {
+        let quality = format!("{}_{}", variant.quality, variant.id);
+        return Ok(build_download_filename(&variant.video_id, &quality, ext));
+    }
+    Ok(name)
+}
+
+pub(crate) fn notify_variant(id: i64) {
+    if let Ok(Some(variant)) = storage::get_download_variant(id) {
+        let _ = variant_sender().send(map_variant(variant));
+    }
+}
+
+fn map_variant(variant: storage::DownloadVariantRecord) -> ApiDownloadVariant {
+    let status = match variant.status {
+        storage::DownloadStatus::Queued => ApiDownloadStatus::Pending,
+        storage::DownloadStatus::Downloading => ApiDownloadStatus::Downloading,
+        storage::DownloadStatus::Paused => ApiDownloadStatus::Paused,
+        storage::DownloadStatus::Completed => ApiDownloadStatus::Completed,
+        storage::DownloadStatus::Failed => ApiDownloadStatus::Failed {
+            error: variant.error_message.clone().unwrap_or_default(),
+        },
+        storage::DownloadStatus::Corrupt => ApiDownloadStatus::Corrupt {
+            reason: variant.error_message.clone().unwrap_or_default(),
+        },
+        storage::DownloadStatus::Missing => ApiDownloadStatus::Missing,
+    };
+    let progress = if variant.total_bytes > 0 {
+        (variant.downloaded_bytes as f32 / variant.total_bytes as f32).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let file_path = (variant.status == storage::DownloadStatus::Completed)
+        .then(|| variant.save_path.as_deref().and_then(basename))
+        .flatten()
+        .and_then(|name| resolve_download_path(&name).ok())
+        .filter(|p| Path::new(p).is_file())
+        .map(|p| p.to_string_lossy().into_owned());
+    ApiDownloadVariant {
+        id: variant.id,
+        video_id: variant.video_id,
+        quality: variant.quality,
+        source_quality: variant.source_quality,
+        status,
+        progress,
+        downloaded_bytes: variant.downloaded_bytes.max(0) as u64,
+        total_bytes: variant.total_bytes.max(0) as u64,
+        file_path,
+        replace_primary: variant.replace_primary,
+        keep_previous: variant.keep_previous,
+        error_message: variant.error_message,
+        created_at: variant.created_at,
+        completed_at: variant.completed_at,
+    }
+}
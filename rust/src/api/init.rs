@@ -1,6 +1,9 @@
 // 初始化和系统相关 API
 
-use crate::api::{cache, download};
+use crate::api::{
+    cache, download, download_events, download_quota, download_rules, download_throttle,
+    download_variants,
+};
 use crate::core::{network, otlp, storage};
 use flutter_rust_bridge::frb;
 use std::fs;
@@ -48,7 +51,13 @@ pub async fn init_app(data_path: String) -> anyhow::Result<()> {
     let db_path = format!("{}/data.db", data_path);
     storage::init_db(Some(&db_path))?;
     storage::reset_running_downloads()?;
+    if let Err(e) = download_throttle::load_bandwidth_settings() {
+        tracing::warn!("Load bandwidth settings failed: {e:?}");
+    }
     download::resume_queued_downloads().await?;
+    download_variants::resume_variant_downloads().await?;
+    download_rules::start_rule_checker();
+    download_quota::start_auto_cleanup();
 
     if let Err(e) = otlp::init().await {
         tracing::debug!("OTLP init failed: {e:?}");
@@ -139,7 +148,7 @@ async fn load_saved_cookies() -> anyhow::Result<()> {
 pub async fn set_cookies(cookie_string: String, domain: Option<String>) -> anyhow::Result<()> {
     // 使用传入的域名或当前活跃域名
     let host = domain.unwrap_or_else(|| network::get_active_domain().host.clone());
-    
+
     // 设置到网络模块
     network::set_cookies(&cookie_string, Some(&host))?;
 
@@ -346,6 +355,15 @@ pub fn export_logs_zip() -> anyhow::Result<String> {
             io::copy(&mut f, &mut zip)?;
         }
     }
+    // 附带最近的下载事件时间线，便于排查下载问题（数据库不可用时跳过）
+    match download_events::export_recent_events() {
+        Ok(events) if !events.is_empty() => {
+            zip.start_file("download_events.jsonl", FileOptions::default())?;
+            zip.write_all(events.as_bytes())?;
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Export download events failed: {e:?}"),
+    }
     zip.finish()?;
 
     // 打包完成后再清理日志，避免“刚轮转的第一条”在打包前被删掉。
@@ -3,13 +3,28 @@
 
 pub mod cache;
 pub mod download;
+pub mod download_batch;
+pub mod download_bundle;
+pub mod download_events;
+pub mod download_export;
 pub mod download_folders;
+pub mod download_hls;
+pub mod download_quota;
+pub mod download_reconcile;
+pub mod download_relocate;
+pub mod download_rules;
+pub mod download_seek;
+pub mod download_subtitles;
+pub mod download_throttle;
+pub mod download_variants;
 pub mod init;
 pub mod models;
 pub mod search;
 pub mod settings;
+pub mod stream;
 pub mod sync;
 pub mod user;
 pub mod video;
+pub mod watch_next;
 // 保留原有的 simple 模块用于测试
 pub mod simple;
@@ -44,6 +44,8 @@ pub struct ApiVideoDetail {
     pub author: Option<ApiAuthorInfo>,
     pub tags: Vec<String>,
     pub qualities: Vec<ApiVideoQuality>,
+    /// 播放器提供的字幕轨道
+    pub subtitles: Vec<ApiSubtitleTrack>,
     pub series: Option<ApiSeriesInfo>,
     pub related_videos: Vec<ApiVideoCard>,
     /// HTML 表单 hidden input `_token`（Laravel 表单 token）
@@ -61,6 +63,37 @@ pub struct ApiVideoDetail {
 pub struct ApiVideoQuality {
     pub quality: String, // "1080p", "720p", "480p", "360p"
     pub url: String,
+    /// 分辨率（HLS 变体或已展开的 master 播放列表提供）
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// 峰值带宽（bit/s）
+    pub bandwidth: Option<u64>,
+    pub codecs: Option<String>,
+}
+
+/// 字幕轨道
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiSubtitleTrack {
+    /// 语言代码（例如 `zh-Hant`），未标注时为空
+    pub language: String,
+    pub label: String,
+    pub url: String,
+    /// `vtt` | `srt` | `ass`
+    pub format: String,
+}
+
+/// 随下载保存的字幕
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiDownloadSubtitle {
+    pub language: String,
+    pub label: String,
+    pub url: String,
+    /// 本地文件格式（WebVTT 字幕转换为 `srt` 保存）
+    pub format: String,
+    /// 本地文件路径，尚未下载或文件缺失时为空
+    pub file_path: Option<String>,
 }
 
 /// 作者信息
@@ -319,6 +352,16 @@ pub struct ApiDownloadTask {
     pub created_at: i64,
     pub file_path: Option<String>,
     pub folder_id: Option<String>,
+    /// 最近一次失败的分类：`network` | `source_expired` | `http_client` | `http_server` |
+    /// `disk_full` | `insufficient_space` | `quota_exceeded` | `challenge_required` | `corrupt` |
+    /// `unknown`
+    pub error_kind: Option<String>,
+    /// 已自动重试次数
+    pub retry_count: u32,
+    /// 最大自动重试次数
+    pub max_retries: u32,
+    /// 下一次自动重试时间（秒级时间戳），无待执行的重试时为空
+    pub next_retry_at: Option<i64>,
 }
 
 /// 下载文件夹（仅用于过滤分类，删除文件夹不影响视频）
@@ -328,6 +371,327 @@ pub struct ApiDownloadFolder {
     pub id: String,
     pub name: String,
     pub created_at: i64,
+    /// 上级文件夹，顶层为空
+    pub parent_id: Option<String>,
+    /// 同级文件夹中的排序
+    pub sort_order: i32,
+    /// 作为封面的下载视频 ID，为空时由界面自行选择
+    pub cover_video_id: Option<String>,
+    /// 智能文件夹：内容由规则决定，不能手动移入视频
+    pub is_smart: bool,
+    /// 文件夹中的视频数量（不含子文件夹）
+    pub video_count: u32,
+}
+
+/// 智能文件夹规则
+///
+/// - 不同条件之间为“且”；`authors` / `qualities` 满足任一即可，`tags` 需全部包含
+/// - 列表为空、字段为空表示不限
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Default)]
+pub struct ApiSmartFolderRule {
+    /// 作者名或作者 ID
+    pub authors: Vec<String>,
+    pub tags: Vec<String>,
+    /// 画质，如 `1080P`
+    pub qualities: Vec<String>,
+    /// 加入下载的时间范围（秒级时间戳，闭区间）
+    pub downloaded_from: Option<i64>,
+    pub downloaded_to: Option<i64>,
+    /// 上传日期范围（`YYYY-MM-DD`，闭区间）
+    pub uploaded_from: Option<String>,
+    pub uploaded_to: Option<String>,
+    /// true：仅已观看；false：仅未观看
+    pub watched: Option<bool>,
+}
+
+/// 下载列表查询条件（过滤、排序、分页均在数据库中完成）
+///
+/// 列表为空、字段为空表示不限
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Default)]
+pub struct ApiDownloadQuery {
+    /// `pending` | `downloading` | `paused` | `completed` | `failed` | `corrupt` | `missing`，满足任一即可
+    pub statuses: Vec<String>,
+    /// 文件夹；智能文件夹按其规则过滤
+    pub folder_id: Option<String>,
+    /// 是否包含子文件夹中的视频（仅普通子文件夹）
+    pub include_subfolders: bool,
+    /// 作者名或作者 ID，满足任一即可
+    pub authors: Vec<String>,
+    /// 需全部包含
+    pub tags: Vec<String>,
+    /// 标题 / 中文标题 / 简介包含的文本
+    pub text: Option<String>,
+    /// 加入下载的时间范围（秒级时间戳，闭区间）
+    pub downloaded_from: Option<i64>,
+    pub downloaded_to: Option<i64>,
+    /// 文件大小范围（字节，闭区间）
+    pub min_bytes: Option<u64>,
+    pub max_bytes: Option<u64>,
+    /// `created_at`（默认） | `completed_at` | `title` | `size` | `upload_date` | `author`
+    pub sort_by: Option<String>,
+    pub descending: bool,
+    pub offset: u32,
+    /// 每页数量，0 表示不限
+    pub limit: u32,
+}
+
+/// 下载列表分页结果
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiDownloadPage {
+    pub items: Vec<ApiDownloadTask>,
+    /// 满足条件的总数（不受分页影响）
+    pub total: u32,
+}
+
+/// 下载存储配额与自动清理策略
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Default)]
+pub struct ApiDownloadQuotaPolicy {
+    /// 下载根目录（视频、封面、头像）可占用的最大字节数，为空表示不限
+    pub quota_bytes: Option<u64>,
+    /// 新下载超出配额时自动按策略清理；关闭时直接阻止下载
+    pub auto_cleanup: bool,
+    /// 已看完的下载在最后观看多少天后清理，为空表示不按观看状态清理
+    pub watched_older_than_days: Option<u32>,
+    /// 超出配额时按完成时间从旧到新清理
+    pub oldest_first: bool,
+    /// 固定文件夹（含子文件夹）中的下载不会被清理
+    pub pinned_folder_ids: Vec<String>,
+}
+
+/// 字节区间 [start, end)
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// 下载事件（用于查看任务时间线排查问题）
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiDownloadEvent {
+    pub id: i64,
+    /// 事件类型：created / status / source_picked / source_expired / source_refreshed /
+    /// source_changed / http_response / stream_interrupted / error / retry_scheduled
+    pub kind: String,
+    /// 状态变化后的状态（kind 为 status 时）
+    pub status: Option<String>,
+    pub message: Option<String>,
+    /// 媒体地址主机名
+    pub source_host: Option<String>,
+    pub quality: Option<String>,
+    pub format: Option<String>,
+    /// 请求的字节范围（结束位置包含在内）
+    pub range_start: Option<u64>,
+    pub range_end: Option<u64>,
+    pub http_status: Option<u16>,
+    pub error_kind: Option<String>,
+    /// 毫秒时间戳
+    pub created_at: i64,
+}
+
+/// 下载限速设置
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Default)]
+pub struct ApiDownloadBandwidthSettings {
+    /// 所有下载合计的限速（KB/s），0 表示不限
+    pub global_kb_per_sec: u32,
+    /// 单个任务的默认限速（KB/s），0 表示不限；任务可单独设置
+    pub task_kb_per_sec: u32,
+    /// 仅在播放器播放在线视频时限速
+    pub only_while_streaming: bool,
+    /// 播放器当前是否正在播放在线视频（只读，保存时忽略）
+    pub player_streaming: bool,
+}
+
+/// 清理计划中的单个下载
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiCleanupItem {
+    pub video_id: String,
+    pub title: String,
+    /// `watched`：已看完且超过保留天数；`oldest`：超出配额按时间清理
+    pub reason: String,
+    /// 释放的字节数（含画质变体）
+    pub size: u64,
+    pub completed_at: i64,
+    pub watched_at: Option<i64>,
+}
+
+/// 清理计划 / 结果
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiCleanupPlan {
+    /// 清理前已占用字节数
+    pub used_bytes: u64,
+    pub quota_bytes: Option<u64>,
+    pub items: Vec<ApiCleanupItem>,
+    /// 释放（或预计释放）的字节数
+    pub freed_bytes: u64,
+}
+
+/// 配额阻止下载事件
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiQuotaBlockedEvent {
+    pub video_id: String,
+    pub title: String,
+    /// 完成下载所需的总占用（已占用 + 进行中任务剩余 + 本任务剩余）
+    pub required_bytes: u64,
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+    /// 自动清理已释放的字节数
+    pub freed_bytes: u64,
+    pub blocked_at: i64,
+}
+
+/// 下载视频的画质变体（主下载之外的其他画质）
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiDownloadVariant {
+    pub id: i64,
+    pub video_id: String,
+    /// 请求的画质
+    pub quality: String,
+    /// 实际下载的画质
+    pub source_quality: Option<String>,
+    pub status: ApiDownloadStatus,
+    pub progress: f32,
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    /// 已完成时的本地文件路径
+    pub file_path: Option<String>,
+    /// 画质升级：完成后替换主下载
+    pub replace_primary: bool,
+    /// 替换时保留原主下载为变体
+    pub keep_previous: bool,
+    pub error_message: Option<String>,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+/// 下载目录空间使用情况
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiDownloadStorageInfo {
+    /// 下载目录
+    pub download_dir: String,
+    /// 下载目录已占用
+    pub used_bytes: u64,
+    /// 未完成任务仍需下载的字节数
+    pub pending_bytes: u64,
+    /// 所在磁盘可用空间（获取失败时为 0）
+    pub free_bytes: u64,
+    /// 所在磁盘总空间（获取失败时为 0）
+    pub disk_total_bytes: u64,
+    /// 预留空间（开始下载前需保证剩余空间不低于该值）
+    pub reserve_bytes: u64,
+    /// 是否开启文件预分配
+    pub preallocate: bool,
+}
+
+/// 批量添加下载的结果
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiBatchEnqueueResult {
+    /// 解析到的视频总数（去重后）
+    pub total: u32,
+    /// 本次新加入队列的视频 ID（按集数/列表顺序）
+    pub enqueued: Vec<String>,
+    /// 已存在下载任务而跳过的视频 ID
+    pub skipped: Vec<String>,
+    /// 添加失败的视频 ID
+    pub failed: Vec<String>,
+}
+
+/// 订阅自动下载规则
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiDownloadRule {
+    /// 新建时可留空，由后端生成
+    pub id: String,
+    pub name: String,
+    /// 匹配方式：`artist` | `tag` | `genre`
+    pub match_type: String,
+    /// 匹配值（忽略大小写）
+    pub match_value: String,
+    pub quality: String,
+    /// 下载后归入的文件夹
+    pub folder_id: Option<String>,
+    /// 仅下载上传时间在该天数以内的视频，为空不限
+    pub max_age_days: Option<u32>,
+    /// 每天最多自动下载数，为空不限
+    pub daily_cap: Option<u32>,
+    pub enabled: bool,
+    pub created_at: i64,
+    pub last_checked_at: Option<i64>,
+}
+
+/// 下载目录迁移进度
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiRelocateProgress {
+    /// `preparing` | `moving` | `rolling_back` | `cleanup`
+    pub stage: String,
+    pub total_files: u32,
+    pub done_files: u32,
+    pub total_bytes: u64,
+    pub done_bytes: u64,
+    pub current_file: Option<String>,
+    pub done: bool,
+    pub error: Option<String>,
+}
+
+// ============================================================================
+// 下载库核对模型
+// ============================================================================
+
+/// 下载库核对报告（数据库记录 ↔ 本地文件）
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiReconcileReport {
+    /// 记录存在但文件缺失
+    pub missing: Vec<ApiMissingDownload>,
+    /// 文件存在但没有记录引用
+    pub orphans: Vec<ApiOrphanFile>,
+    /// 缺失媒体文件的预期总大小
+    pub missing_bytes: u64,
+    /// 孤立文件总大小
+    pub orphan_bytes: u64,
+    pub scanned_at: i64,
+}
+
+/// 文件缺失的下载记录
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiMissingDownload {
+    pub video_id: String,
+    pub title: String,
+    pub status: ApiDownloadStatus,
+    pub missing_media: bool,
+    pub missing_cover: bool,
+    pub missing_avatar: bool,
+    /// 记录中的媒体文件大小
+    pub expected_bytes: u64,
+    /// 文件名与该视频匹配的孤立媒体文件（可用于重新关联）
+    pub candidate_files: Vec<String>,
+}
+
+/// 没有记录引用的文件
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiOrphanFile {
+    /// `downloads` | `download_covers` | `download_avatars` | `download_subtitles`
+    pub dir: String,
+    pub file_name: String,
+    pub size: u64,
+    pub modified_at: Option<i64>,
+    /// 从文件名推测的视频 ID
+    pub video_id: Option<String>,
 }
 
 // ============================================================================
@@ -338,8 +702,12 @@ pub struct ApiDownloadFolder {
 #[frb(dart_metadata=("freezed"))]
 #[derive(Debug, Clone)]
 pub struct ApiExportProgress {
+    /// 导出任务 ID（用于暂停 / 继续 / 取消）
+    pub job_id: String,
     pub total_files: u32,
     pub done_files: u32,
+    /// 目标已存在相同文件而跳过的数量（计入 done_files）
+    pub skipped_files: u32,
     pub current_file: Option<String>,
     pub current_bytes: u64,
     pub current_total_bytes: u64,
@@ -347,6 +715,59 @@ pub struct ApiExportProgress {
     pub error: Option<String>,
 }
 
+/// 下载库打包（导出 / 导入）进度
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiBundleProgress {
+    /// `export` | `import`
+    pub stage: String,
+    pub total_items: u32,
+    pub done_items: u32,
+    /// 导出：写入的视频数
+    pub exported: u32,
+    /// 导入：新建记录数
+    pub imported: u32,
+    /// 导入时因 video_id 已存在而跳过的数量
+    pub skipped: u32,
+    pub failed: u32,
+    pub current_item: Option<String>,
+    pub current_bytes: u64,
+    pub current_total_bytes: u64,
+    pub done: bool,
+    pub error: Option<String>,
+}
+
+/// 导出选项
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiExportOptions {
+    /// 媒体库布局：每个视频独立目录，附带 NFO（Kodi/Jellyfin）、poster.jpg 与作者头像
+    pub media_server_layout: bool,
+    /// 分组目录：`none` | `artist`（按作者）| `series`（按系列，媒体库布局下生成剧集 NFO）
+    pub group_by: String,
+    /// 文件名模板，`/` 分隔子目录，为空时使用 `[{author}]{title}`
+    ///
+    /// 占位符：`{author}` `{title}` `{chinese_title}` `{video_id}` `{quality}`
+    /// `{upload_date}` `{folder}` `{series}` `{episode}`
+    pub filename_template: Option<String>,
+    /// 单个文件/目录名的最大字节数（默认 255，最小 32）
+    pub max_name_bytes: Option<u32>,
+    /// 移动而非复制：导出成功后删除本地媒体与字幕文件，记录标记为 `Missing`（封面与变体保留）
+    pub move_files: bool,
+}
+
+impl Default for ApiExportOptions {
+    fn default() -> Self {
+        Self {
+            media_server_layout: false,
+            group_by: "none".to_string(),
+            filename_template: None,
+            max_name_bytes: None,
+            move_files: false,
+        }
+    }
+}
+
 /// 下载状态
 #[derive(Debug, Clone)]
 pub enum ApiDownloadStatus {
@@ -354,7 +775,15 @@ pub enum ApiDownloadStatus {
     Downloading,
     Paused,
     Completed,
-    Failed { error: String },
+    Failed {
+        error: String,
+    },
+    /// 文件完整性校验失败
+    Corrupt {
+        reason: String,
+    },
+    /// 本地文件缺失（可重新关联或重新下载）
+    Missing,
 }
 
 // ============================================================================
@@ -383,6 +812,50 @@ pub struct ApiPlayHistoryList {
     pub has_next: bool,
 }
 
+/// 继续观看（已开始但未看完）
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiContinueWatching {
+    pub video_id: String,
+    pub title: String,
+    pub cover_url: String,
+    pub progress: f32, // 0.0 - 1.0
+    /// 已观看秒数
+    pub position: u32,
+    pub duration: u32,
+    pub last_played_at: i64,
+    /// 所属系列（已保存系列信息时）
+    pub series_title: Option<String>,
+    pub episode: Option<u32>,
+    /// 已下载完成，可离线播放
+    pub is_downloaded: bool,
+}
+
+/// 下一集推荐（系列中看完一集后的下一集）
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiNextEpisode {
+    pub video_id: String,
+    pub title: String,
+    pub cover_url: String,
+    pub series_id: String,
+    pub series_title: String,
+    pub episode: u32,
+    /// 最近看完的上一集
+    pub previous_video_id: String,
+    pub previous_played_at: i64,
+    /// 已下载完成，可离线播放
+    pub is_downloaded: bool,
+}
+
+/// 「接下来看什么」
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ApiWatchNext {
+    pub continue_watching: Vec<ApiContinueWatching>,
+    pub next_episodes: Vec<ApiNextEpisode>,
+}
+
 // ============================================================================
 // 设置相关模型
 // ============================================================================
@@ -12,7 +12,7 @@ use flutter_rust_bridge::frb;
 use std::time::Duration;
 
 /// 构建搜索 URL
-fn build_search_url(filters: &ApiSearchFilters) -> String {
+pub(crate) fn build_search_url(filters: &ApiSearchFilters) -> String {
     let base = network::base_url();
     let mut url = format!("{}/search?", base);
     let mut params = vec![];
@@ -59,6 +59,23 @@ pub async fn set_download_concurrent(count: u32) -> anyhow::Result<bool> {
     Ok(true)
 }
 
+/// 设置下载预留空间（MB）：剩余空间低于「待下载大小 + 预留」时不开始下载
+#[frb]
+pub async fn set_download_space_reserve(reserve_mb: u32) -> anyhow::Result<bool> {
+    storage::save_setting("download_space_reserve_mb", &reserve_mb.to_string())?;
+    Ok(true)
+}
+
+/// 设置是否在开始下载时预分配完整文件大小
+#[frb]
+pub async fn set_download_preallocate(enabled: bool) -> anyhow::Result<bool> {
+    storage::save_setting(
+        "download_preallocate",
+        if enabled { "true" } else { "false" },
+    )?;
+    Ok(true)
+}
+
 /// 设置代理
 #[frb]
 pub async fn set_proxy(proxy_url: Option<String>) -> anyhow::Result<bool> {
@@ -106,8 +123,9 @@ pub async fn delete_kv(key: String) -> anyhow::Result<bool> {
 /// 获取缓存大小
 #[frb]
 pub async fn get_cache_size() -> anyhow::Result<CacheInfo> {
-    let cover_cache_size = dir_size(storage::get_data_dir()?.join("download_covers"));
-    let avatar_cache_size = dir_size(storage::get_data_dir()?.join("download_avatars"));
+    let root = storage::get_downloads_root()?;
+    let cover_cache_size = dir_size(root.join("download_covers"));
+    let avatar_cache_size = dir_size(root.join("download_avatars"));
     let total_size = cover_cache_size.saturating_add(avatar_cache_size);
     Ok(CacheInfo {
         cover_cache_size: cover_cache_size.saturating_add(avatar_cache_size),
@@ -154,7 +172,7 @@ pub fn get_app_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
-fn dir_size(path: PathBuf) -> u64 {
+pub(crate) fn dir_size(path: PathBuf) -> u64 {
     fn walk(p: &std::path::Path) -> u64 {
         let mut total = 0u64;
         let Ok(rd) = std::fs::read_dir(p) else {
@@ -0,0 +1,77 @@
+// 本地播放代理 API
+// - 播放器统一拿到 127.0.0.1 上的地址：本地文件支持 Range，远程媒体带上应用的请求头
+// - 代理服务在第一次获取地址时启动
+
+use crate::api::download::{available_ranges, download_path_hint, on_disk_bytes};
+use crate::api::download_seek;
+use crate::core::byte_ranges::RangeSet;
+use crate::core::storage;
+use crate::core::stream_server::{self, LocalMedia};
+use flutter_rust_bridge::frb;
+
+/// 获取已下载或下载中视频的本地播放地址（边下边播），没有本地文件时返回空
+#[frb]
+pub async fn get_local_stream_url(video_id: String) -> anyhow::Result<Option<String>> {
+    if resolve_local(&video_id)?.is_none() {
+        return Ok(None);
+    }
+    let base = stream_server::start(resolve_local, download_seek::seek_hint)?;
+    Ok(Some(stream_server::local_url(&base, &video_id)))
+}
+
+/// 获取远程媒体（mp4 / m3u8）经本地代理后的播放地址
+#[frb]
+pub async fn get_proxied_stream_url(url: String) -> anyhow::Result<String> {
+    let base = stream_server::start(resolve_local, download_seek::seek_hint)?;
+    Ok(stream_server::remote_url(&base, &url))
+}
+
+/// 下载记录对应的本地文件
+///
+/// - 已完成：整个文件可读
+/// - 下载中：已下载部分可读并会继续增长，尚未下载的位置由下载任务预取
+/// - 排队/暂停/失败：已下载部分可读，其余从远程地址获取
+fn resolve_local(video_id: &str) -> anyhow::Result<Option<LocalMedia>> {
+    let Some(record) = storage::get_download_by_video_id(video_id)? else {
+        return Ok(None);
+    };
+    let path = download_path_hint(&record);
+    if path.as_os_str().is_empty() {
+        return Ok(None);
+    }
+    let file_len = std::fs::metadata(&path).map(|m| m.len()).ok();
+    let media = match record.status {
+        storage::DownloadStatus::Completed => {
+            let Some(file_len) = file_len else {
+                return Ok(None);
+            };
+            LocalMedia {
+                path,
+                total: file_len,
+                available: file_len,
+                ranges: RangeSet::from_ranges([(0, file_len)]),
+                growing: false,
+                source_url: None,
+            }
+        }
+        storage::DownloadStatus::Queued
+        | storage::DownloadStatus::Downloading
+        | storage::DownloadStatus::Paused
+        | storage::DownloadStatus::Failed => LocalMedia {
+            available: file_len.map(|len| on_disk_bytes(&record, len)).unwrap_or(0),
+            ranges: match file_len {
+                Some(len) => available_ranges(&record, len)?,
+                None => RangeSet::default(),
+            },
+            total: record.total_bytes.max(0) as u64,
+            growing: record.status == storage::DownloadStatus::Downloading,
+            source_url: record.source_url.clone().filter(|u| !u.is_empty()),
+            path,
+        },
+        storage::DownloadStatus::Corrupt | storage::DownloadStatus::Missing => return Ok(None),
+    };
+    if media.ranges.is_empty() && media.source_url.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(media))
+}
@@ -2,15 +2,39 @@
 
 use crate::api::models::{
     ApiAuthorInfo, ApiComment, ApiCommentList, ApiMyListInfo, ApiMyListItem, ApiPlaylistInfo,
-    ApiVideoCard, ApiVideoDetail, ApiVideoQuality,
+    ApiSeriesInfo, ApiSeriesVideo, ApiSubtitleTrack, ApiVideoCard, ApiVideoDetail, ApiVideoQuality,
 };
-use crate::core::{network, parser};
+use crate::api::stream;
+use crate::api::watch_next;
+use crate::core::cache::web_cache;
+use crate::core::{hls, network, parser, storage};
 use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use urlencoding::encode;
 
+/// 播放源缓存键前缀
+const VIDEO_SOURCES_CACHE_PREFIX: &str = "VIDEO_SOURCES$";
+/// 播放源缓存的最长时间（地址自身的过期时间更早时以其为准）
+const VIDEO_SOURCES_MAX_AGE: Duration = Duration::from_secs(30 * 60);
+/// 地址过期前提前失效，避免播放器拿到即将过期的地址
+const URL_EXPIRY_MARGIN_SECS: i64 = 60;
+
 /// 获取视频详情
 #[frb]
 pub async fn get_video_detail(video_id: String) -> anyhow::Result<ApiVideoDetail> {
+    get_video_detail_with_options(video_id, false).await
+}
+
+/// 获取视频详情
+///
+/// - `expand_hls_variants`：请求并解析 HLS master 播放列表，把各画质变体（分辨率、带宽、编码）
+///   加入 `qualities`；网页已直接提供的清晰度保持不变
+#[frb]
+pub async fn get_video_detail_with_options(
+    video_id: String,
+    expand_hls_variants: bool,
+) -> anyhow::Result<ApiVideoDetail> {
     let base = network::base_url();
     let url = format!("{}/watch?v={}", base, video_id);
     tracing::info!("Getting video detail: {}", url);
@@ -20,6 +44,14 @@ pub async fn get_video_detail(video_id: String) -> anyhow::Result<ApiVideoDetail
         Ok(html) => {
             // 解析 HTML
             let detail = parser::parse_video_detail(&html)?;
+            if let Some(series) = detail.series.as_ref() {
+                watch_next::remember_series(series);
+            }
+            let sources = if expand_hls_variants {
+                expand_video_sources(&video_id, &detail.video_sources).await
+            } else {
+                plain_sources(&detail.video_sources)
+            };
 
             // 转换为 API 模型
             Ok(ApiVideoDetail {
@@ -42,15 +74,42 @@ pub async fn get_video_detail(video_id: String) -> anyhow::Result<ApiVideoDetail
                     is_subscribed: c.is_subscribed,
                 }),
                 tags: detail.tags,
-                qualities: detail
-                    .video_sources
+                qualities: sources
                     .into_iter()
                     .map(|s| ApiVideoQuality {
                         quality: s.quality,
                         url: s.url,
+                        width: s.resolution.map(|(w, _)| w),
+                        height: s.resolution.map(|(_, h)| h),
+                        bandwidth: s.bandwidth,
+                        codecs: s.codecs,
+                    })
+                    .collect(),
+                subtitles: detail
+                    .subtitles
+                    .into_iter()
+                    .map(|t| ApiSubtitleTrack {
+                        language: t.language,
+                        label: t.label,
+                        url: t.url,
+                        format: t.format,
                     })
                     .collect(),
-                series: None,
+                series: detail.series.map(|series| ApiSeriesInfo {
+                    id: series.id,
+                    title: series.title,
+                    current_index: series.current_index as u32,
+                    videos: series
+                        .episodes
+                        .into_iter()
+                        .map(|e| ApiSeriesVideo {
+                            id: e.id,
+                            title: e.title,
+                            cover_url: e.cover_url,
+                            episode: e.number.to_string(),
+                        })
+                        .collect(),
+                }),
                 related_videos: detail
                     .related_videos
                     .into_iter()
@@ -172,13 +231,159 @@ pub async fn get_comment_replies(comment_id: String) -> anyhow::Result<Vec<ApiCo
 }
 
 /// 获取视频播放地址
+///
+/// - 已下载完成时优先返回本地播放地址
+/// - 否则按清晰度选择：完全匹配 → 低于目标的最接近清晰度 → auto（HLS master 播放列表会展开为各画质）
+/// - 解析结果连同地址过期时间一起缓存，重复调用不会再次抓取 watch 页
 #[frb]
 pub async fn get_video_url(video_id: String, quality: String) -> anyhow::Result<String> {
-    // TODO: 实现实际的播放地址获取逻辑
-    Ok(format!(
-        "https://example.com/video/{}/{}.m3u8",
-        video_id, quality
-    ))
+    let completed = storage::get_download_by_video_id(&video_id)?
+        .is_some_and(|r| r.status == storage::DownloadStatus::Completed);
+    if completed {
+        if let Some(url) = stream::get_local_stream_url(video_id.clone()).await? {
+            return Ok(url);
+        }
+    }
+    let resolved = resolve_video_sources(&video_id).await?;
+    let qualities = resolved
+        .sources
+        .iter()
+        .map(|s| s.quality.as_str())
+        .collect::<Vec<_>>();
+    let index = hls::pick_quality(&qualities, &quality)
+        .ok_or_else(|| anyhow::anyhow!("No playable source"))?;
+    let source = &resolved.sources[index];
+    tracing::info!(
+        "video_url video_id={} quality_req={} quality_pick={} format={}",
+        video_id,
+        quality,
+        source.quality,
+        source.format
+    );
+    Ok(source.url.clone())
+}
+
+/// 解析后的播放源（HLS master 播放列表已展开为各画质）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ResolvedSource {
+    pub quality: String,
+    pub url: String,
+    pub format: String,
+    pub bandwidth: Option<u64>,
+    pub resolution: Option<(u32, u32)>,
+    pub codecs: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ResolvedSources {
+    pub sources: Vec<ResolvedSource>,
+    /// 地址失效时间（秒级时间戳，已预留余量）
+    pub expires_at: i64,
+}
+
+/// 获取视频的播放源（带缓存，地址过期后重新解析）
+pub(crate) async fn resolve_video_sources(video_id: &str) -> anyhow::Result<ResolvedSources> {
+    let cache_key = format!("{}{}", VIDEO_SOURCES_CACHE_PREFIX, video_id);
+    let resolved = web_cache::cache_first(&cache_key, VIDEO_SOURCES_MAX_AGE, || {
+        fetch_video_sources(video_id)
+    })
+    .await?;
+    if resolved.expires_at > chrono::Utc::now().timestamp() {
+        return Ok(resolved);
+    }
+    web_cache::clean_by_key_prefix(&cache_key)?;
+    web_cache::cache_first(&cache_key, VIDEO_SOURCES_MAX_AGE, || {
+        fetch_video_sources(video_id)
+    })
+    .await
+}
+
+async fn fetch_video_sources(video_id: &str) -> anyhow::Result<ResolvedSources> {
+    let watch_url = format!("{}/watch?v={}", network::base_url(), video_id);
+    let html = network::get(&watch_url).await?;
+    let detail = parser::parse_video_detail(&html)?;
+    let sources = expand_video_sources(video_id, &detail.video_sources).await;
+    if sources.is_empty() {
+        anyhow::bail!("No playable source");
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let expires_at = sources
+        .iter()
+        .filter_map(|s| hls::url_expiry(&s.url))
+        .min()
+        .map(|t| t - URL_EXPIRY_MARGIN_SECS)
+        .unwrap_or(now + VIDEO_SOURCES_MAX_AGE.as_secs() as i64);
+    Ok(ResolvedSources {
+        sources,
+        expires_at,
+    })
+}
+
+fn plain_sources(sources: &[parser::VideoSource]) -> Vec<ResolvedSource> {
+    sources
+        .iter()
+        .map(|s| ResolvedSource {
+            quality: s.quality.clone(),
+            url: s.url.clone(),
+            format: s.format.clone(),
+            bandwidth: None,
+            resolution: None,
+            codecs: None,
+        })
+        .collect()
+}
+
+/// 网页提供的播放源 + HLS master 播放列表中的画质变体（网页已提供的清晰度保持不变）
+async fn expand_video_sources(
+    video_id: &str,
+    page_sources: &[parser::VideoSource],
+) -> Vec<ResolvedSource> {
+    let mut sources = plain_sources(page_sources);
+    for source in page_sources.iter().filter(|s| s.format == "m3u8") {
+        let variants = match fetch_master_variants(&source.url).await {
+            Ok(variants) => variants,
+            Err(e) => {
+                tracing::warn!(
+                    "video expand_master_failed video_id={} error={}",
+                    video_id,
+                    e
+                );
+                continue;
+            }
+        };
+        for variant in variants {
+            let Some(quality) = variant.quality() else {
+                continue;
+            };
+            if sources
+                .iter()
+                .any(|s| s.quality.eq_ignore_ascii_case(&quality))
+            {
+                continue;
+            }
+            sources.push(ResolvedSource {
+                quality,
+                url: variant.url,
+                format: "m3u8".to_string(),
+                bandwidth: variant.bandwidth,
+                resolution: variant.resolution,
+                codecs: variant.codecs,
+            });
+        }
+    }
+    sources
+}
+
+/// 获取 master 播放列表中的画质变体（不是 master 播放列表时为空）
+pub(crate) async fn fetch_master_variants(url: &str) -> anyhow::Result<Vec<hls::HlsVariant>> {
+    let resp = network::media_request(url)?
+        .send()
+        .await?
+        .error_for_status()?;
+    let playlist_url = resp.url().clone();
+    let body = resp.text().await?;
+    Ok(hls::parse_master_playlist(&body, &playlist_url))
 }
 
 /// 添加视频到收藏
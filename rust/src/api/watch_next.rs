@@ -0,0 +1,130 @@
+// 继续观看 / 下一集 API
+// - 继续观看来自播放历史；下一集来自历史与已保存的系列信息（见 core::watch_next）
+// - 系列信息在解析视频详情（打开详情页、下载）时保存到 video_series 表，离线时也能推荐下一集
+
+use crate::api::models::{ApiContinueWatching, ApiNextEpisode, ApiWatchNext};
+use crate::core::parser::Series;
+use crate::core::storage;
+use crate::core::watch_next::{self, WatchState};
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+
+/// 参与计算的最近历史记录条数
+const HISTORY_SCAN_LIMIT: i32 = 500;
+
+/// 获取继续观看与下一集推荐（每类最多 `limit` 条）
+#[frb]
+pub async fn get_watch_next(limit: u32) -> anyhow::Result<ApiWatchNext> {
+    let limit = limit.clamp(1, 100) as usize;
+    let history = storage::get_history(HISTORY_SCAN_LIMIT, 0)?;
+    let states = history
+        .iter()
+        .map(|r| WatchState {
+            video_id: r.video_id.clone(),
+            watch_progress: i64::from(r.watch_progress),
+            total_duration: i64::from(r.total_duration),
+            watched_at: r.watched_at,
+        })
+        .collect::<Vec<_>>();
+
+    // 系列 ID → 按位置排序的剧集
+    let video_ids = history
+        .iter()
+        .map(|r| r.video_id.clone())
+        .collect::<Vec<_>>();
+    let mut series: Vec<Vec<storage::VideoSeriesRecord>> = Vec::new();
+    for record in storage::get_video_series_containing(&video_ids)? {
+        match series.last_mut() {
+            Some(last) if last[0].series_id == record.series_id => last.push(record),
+            _ => series.push(vec![record]),
+        }
+    }
+    let membership = series
+        .iter()
+        .flatten()
+        .map(|e| (e.video_id.as_str(), e))
+        .collect::<HashMap<_, _>>();
+
+    let continue_watching = watch_next::continue_watching(&states)
+        .into_iter()
+        .take(limit)
+        .map(|index| {
+            let r = &history[index];
+            let duration = r.total_duration.max(0) as u32;
+            let position = r.watch_progress.max(0) as u32;
+            let episode = membership.get(r.video_id.as_str());
+            ApiContinueWatching {
+                video_id: r.video_id.clone(),
+                title: r.title.clone(),
+                cover_url: r.cover_url.clone(),
+                progress: if duration > 0 {
+                    (position as f32 / duration as f32).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                },
+                position,
+                duration,
+                last_played_at: r.watched_at,
+                series_title: episode.map(|e| e.series_title.clone()),
+                episode: episode.map(|e| e.episode),
+                is_downloaded: is_downloaded(&r.video_id),
+            }
+        })
+        .collect();
+
+    let episode_ids = series
+        .iter()
+        .map(|episodes| episodes.iter().map(|e| e.video_id.clone()).collect())
+        .collect::<Vec<Vec<String>>>();
+    let next_episodes = watch_next::next_episodes(&states, &episode_ids)
+        .into_iter()
+        .take(limit)
+        .map(|next| {
+            let e = &series[next.series_index][next.episode_index];
+            ApiNextEpisode {
+                video_id: e.video_id.clone(),
+                title: e.title.clone(),
+                cover_url: e.cover_url.clone(),
+                series_id: e.series_id.clone(),
+                series_title: e.series_title.clone(),
+                episode: e.episode,
+                previous_video_id: next.previous_video_id,
+                previous_played_at: next.watched_at,
+                is_downloaded: is_downloaded(&e.video_id),
+            }
+        })
+        .collect();
+
+    Ok(ApiWatchNext {
+        continue_watching,
+        next_episodes,
+    })
+}
+
+/// 保存解析到的系列信息；失败只写日志
+pub(crate) fn remember_series(series: &Series) {
+    let episodes = series
+        .episodes
+        .iter()
+        .enumerate()
+        .map(|(position, e)| storage::VideoSeriesRecord {
+            series_id: series.id.clone(),
+            series_title: series.title.clone(),
+            video_id: e.id.clone(),
+            title: e.title.clone(),
+            cover_url: e.cover_url.clone(),
+            episode: e.number,
+            position: position as u32,
+        })
+        .collect::<Vec<_>>();
+    if let Err(e) = storage::save_video_series(&series.id, &episodes) {
+        tracing::warn!("watch_next save_series_failed id={} err={}", series.id, e);
+    }
+}
+
+fn is_downloaded(video_id: &str) -> bool {
+    storage::get_download_by_video_id(video_id)
+        .ok()
+        .flatten()
+        .is_some_and(|r| r.status == storage::DownloadStatus::Completed)
+}
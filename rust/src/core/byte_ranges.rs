@@ -0,0 +1,90 @@
+// 字节区间集合（纯计算）
+// - 半开区间 [start, end)，按起点排序；插入时合并重叠与相邻的区间
+// - 用于记录边下边播时已写入文件的区间（顺序下载的开头部分 + 拖动后提前下载的部分）
+
+/// 互不重叠、不相邻的字节区间
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct RangeSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl RangeSet {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u64, u64)>) -> Self {
+        let mut set = Self::default();
+        for (start, end) in ranges {
+            set.insert(start, end);
+        }
+        set
+    }
+
+    pub fn ranges(&self) -> &[(u64, u64)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// 加入区间 [start, end)，与重叠或相邻的区间合并
+    pub fn insert(&mut self, start: u64, end: u64) {
+        if start >= end {
+            return;
+        }
+        let (mut start, mut end) = (start, end);
+        self.ranges.retain(|&(s, e)| {
+            if s <= end && e >= start {
+                start = start.min(s);
+                end = end.max(e);
+                false
+            } else {
+                true
+            }
+        });
+        let index = self.ranges.partition_point(|&(s, _)| s < start);
+        self.ranges.insert(index, (start, end));
+    }
+
+    /// 包含 `pos` 的区间的末尾；`pos` 未被覆盖时为空
+    pub fn covered_end(&self, pos: u64) -> Option<u64> {
+        self.ranges
+            .iter()
+            .find(|&&(s, e)| s <= pos && pos < e)
+            .map(|&(_, e)| e)
+    }
+
+    /// 去掉 `pos` 之前的部分
+    pub fn remove_before(&mut self, pos: u64) {
+        self.ranges.retain(|&(_, e)| e > pos);
+        if let Some(first) = self.ranges.first_mut() {
+            first.0 = first.0.max(pos);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_set() {
+        let mut set = RangeSet::from_ranges([(100, 200), (0, 10), (300, 400)]);
+        assert_eq!(set.ranges(), &[(0, 10), (100, 200), (300, 400)]);
+
+        // 相邻与重叠的区间合并
+        set.insert(200, 250);
+        set.insert(240, 310);
+        assert_eq!(set.ranges(), &[(0, 10), (100, 400)]);
+        set.insert(5, 5);
+        assert_eq!(set.ranges(), &[(0, 10), (100, 400)]);
+
+        assert_eq!(set.covered_end(0), Some(10));
+        assert_eq!(set.covered_end(10), None);
+        assert_eq!(set.covered_end(399), Some(400));
+        assert_eq!(set.covered_end(400), None);
+
+        set.remove_before(150);
+        assert_eq!(set.ranges(), &[(150, 400)]);
+        set.remove_before(400);
+        assert!(set.is_empty());
+    }
+}
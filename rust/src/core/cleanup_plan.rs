@@ -0,0 +1,212 @@
+// 下载清理计划（纯计算，不访问数据库与文件系统）
+// - 规则一：已看完且最后观看早于 N 天的下载，无论是否超出配额都会清理
+// - 规则二：仍超出配额时，按完成时间从旧到新继续清理，直到满足所需空间；
+//   全部清理也无法满足时不执行规则二
+// - 固定文件夹中的下载由调用方在生成候选时排除
+
+/// 观看进度达到时长的该比例视为已看完
+pub(crate) const WATCHED_RATIO: f64 = 0.9;
+
+/// 清理候选（已完成的下载）
+#[derive(Debug, Clone)]
+pub(crate) struct CleanupCandidate {
+    pub video_id: String,
+    /// 占用字节数（含画质变体）
+    pub size: u64,
+    /// 完成时间，缺失时使用创建时间
+    pub completed_at: i64,
+    /// 最后观看时间；未观看或历史已删除时为空
+    pub watched_at: Option<i64>,
+    /// 观看进度（秒）
+    pub watch_progress: i64,
+    /// 视频总时长（秒），未知时为 0
+    pub total_duration: i64,
+}
+
+impl CleanupCandidate {
+    pub fn is_watched(&self) -> bool {
+        self.watched_at.is_some()
+            && self.total_duration > 0
+            && self.watch_progress as f64 >= self.total_duration as f64 * WATCHED_RATIO
+    }
+}
+
+/// 清理原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CleanupReason {
+    /// 已看完且超过保留天数
+    Watched,
+    /// 超出配额，按时间从旧到新清理
+    Oldest,
+}
+
+impl CleanupReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CleanupReason::Watched => "watched",
+            CleanupReason::Oldest => "oldest",
+        }
+    }
+}
+
+/// 清理规则
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CleanupRules {
+    /// 已看完的下载在最后观看多少天后清理
+    pub watched_older_than_days: Option<u32>,
+    /// 超出配额时按时间从旧到新清理
+    pub oldest_first: bool,
+}
+
+/// 生成清理计划，返回（候选下标, 原因），按清理顺序排列
+///
+/// - `used`：当前已占用字节数
+/// - `quota`：配额，为空表示不限（只执行规则一）
+/// - `needed`：额外需要的空间（例如即将开始的下载剩余字节数）
+pub(crate) fn plan_cleanup(
+    candidates: &[CleanupCandidate],
+    rules: &CleanupRules,
+    used: u64,
+    quota: Option<u64>,
+    needed: u64,
+    now: i64,
+) -> Vec<(usize, CleanupReason)> {
+    let mut plan = Vec::new();
+    let mut freed = 0u64;
+
+    if let Some(days) = rules.watched_older_than_days {
+        let cutoff = now - i64::from(days) * 24 * 60 * 60;
+        let mut watched = candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_watched() && c.watched_at.is_some_and(|t| t <= cutoff))
+            .collect::<Vec<_>>();
+        watched.sort_by_key(|(_, c)| c.watched_at);
+        for (index, candidate) in watched {
+            freed = freed.saturating_add(candidate.size);
+            plan.push((index, CleanupReason::Watched));
+        }
+    }
+
+    let Some(quota) = quota else {
+        return plan;
+    };
+    if !rules.oldest_first {
+        return plan;
+    }
+    let target = used.saturating_add(needed);
+    if target.saturating_sub(freed) <= quota {
+        return plan;
+    }
+    let mut oldest = candidates
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !plan.iter().any(|(i, _)| i == index))
+        .collect::<Vec<_>>();
+    // 全部删除也无法满足配额时不按时间清理（只会白白删除下载）
+    let available = oldest
+        .iter()
+        .fold(freed, |sum, (_, c)| sum.saturating_add(c.size));
+    if target.saturating_sub(available) > quota {
+        return plan;
+    }
+    oldest.sort_by_key(|(_, c)| c.completed_at);
+    for (index, candidate) in oldest {
+        if target.saturating_sub(freed) <= quota {
+            break;
+        }
+        freed = freed.saturating_add(candidate.size);
+        plan.push((index, CleanupReason::Oldest));
+    }
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn candidate(
+        id: &str,
+        size: u64,
+        completed_at: i64,
+        watched_at: Option<i64>,
+    ) -> CleanupCandidate {
+        CleanupCandidate {
+            video_id: id.to_string(),
+            size,
+            completed_at,
+            watched_at,
+            watch_progress: if watched_at.is_some() { 95 } else { 0 },
+            total_duration: 100,
+        }
+    }
+
+    #[test]
+    fn test_plan_cleanup() {
+        let now = 100 * DAY;
+        let candidates = vec![
+            candidate("a", 10, DAY, Some(90 * DAY)),
+            candidate("b", 20, 2 * DAY, None),
+            candidate("c", 30, 3 * DAY, Some(50 * DAY)),
+            candidate("d", 40, 4 * DAY, None),
+        ];
+        let ids = |plan: Vec<(usize, CleanupReason)>| {
+            plan.into_iter()
+                .map(|(i, r)| (candidates[i].video_id.as_str(), r))
+                .collect::<Vec<_>>()
+        };
+
+        // 仅规则一：最后观看早于 30 天的已看完下载
+        let rules = CleanupRules {
+            watched_older_than_days: Some(30),
+            oldest_first: false,
+        };
+        assert_eq!(
+            ids(plan_cleanup(&candidates, &rules, 100, None, 0, now)),
+            vec![("c", CleanupReason::Watched)]
+        );
+
+        // 规则一释放后仍超出配额：从最旧的开始补充
+        let rules = CleanupRules {
+            watched_older_than_days: Some(30),
+            oldest_first: true,
+        };
+        assert_eq!(
+            ids(plan_cleanup(&candidates, &rules, 100, Some(50), 5, now)),
+            vec![
+                ("c", CleanupReason::Watched),
+                ("a", CleanupReason::Oldest),
+                ("b", CleanupReason::Oldest),
+            ]
+        );
+
+        // 未超出配额时不按时间清理
+        let rules = CleanupRules {
+            watched_older_than_days: None,
+            oldest_first: true,
+        };
+        assert!(plan_cleanup(&candidates, &rules, 100, Some(200), 50, now).is_empty());
+
+        // 全部删除也无法满足配额：只执行规则一
+        let rules = CleanupRules {
+            watched_older_than_days: Some(30),
+            oldest_first: true,
+        };
+        assert_eq!(
+            ids(plan_cleanup(&candidates, &rules, 100, Some(50), 1000, now)),
+            vec![("c", CleanupReason::Watched)]
+        );
+        // 刚好能满足时仍按时间清理
+        assert_eq!(
+            ids(plan_cleanup(&candidates, &rules, 100, Some(0), 0, now)).len(),
+            4
+        );
+
+        // 看了一半不算已看完
+        let mut partial = candidate("e", 10, DAY, Some(DAY));
+        partial.watch_progress = 50;
+        assert!(!partial.is_watched());
+    }
+}
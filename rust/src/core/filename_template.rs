@@ -0,0 +1,321 @@
+// 导出文件名模板
+// - 语法：`{token}` 占位，`/` 分隔子目录，`{{` / `}}` 转义花括号
+// - 渲染后逐段清理非法字符、避开 Windows 保留名，并按字节数截断（兼容 FAT32/exFAT/NTFS/ext4）
+
+use std::collections::HashMap;
+
+/// 支持的占位符
+pub(crate) const TEMPLATE_TOKENS: [&str; 9] = [
+    "author",
+    "title",
+    "chinese_title",
+    "video_id",
+    "quality",
+    "upload_date",
+    "folder",
+    "series",
+    "episode",
+];
+
+/// 默认模板（与旧版导出文件名一致）
+pub(crate) const DEFAULT_TEMPLATE: &str = "[{author}]{title}";
+
+/// 单段名称的默认最大字节数（常见文件系统单个名称上限为 255）
+pub(crate) const DEFAULT_MAX_NAME_BYTES: usize = 255;
+/// 允许设置的最小字节数，需容纳扩展名与去重后缀
+pub(crate) const MIN_MAX_NAME_BYTES: usize = 32;
+
+/// 模板中的片段
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Text(String),
+    Token(String),
+    Separator,
+}
+
+fn parse(template: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some('{') | None => {
+                            return Err("Unclosed '{' in template".to_string());
+                        }
+                        Some(c) => name.push(c),
+                    }
+                }
+                let name = name.trim().to_string();
+                if !TEMPLATE_TOKENS.contains(&name.as_str()) {
+                    return Err(format!("Unknown token: {{{}}}", name));
+                }
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                pieces.push(Piece::Token(name));
+            }
+            '}' => return Err("Unmatched '}' in template".to_string()),
+            '/' | '\\' => {
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                pieces.push(Piece::Separator);
+            }
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    Ok(pieces)
+}
+
+/// 校验模板：语法正确、占位符已知、不含空段或 `.` / `..` 段、至少包含一个占位符
+pub(crate) fn validate_template(template: &str) -> Result<(), String> {
+    let template = template.trim();
+    if template.is_empty() {
+        return Err("Template is empty".to_string());
+    }
+    let pieces = parse(template)?;
+    if !pieces.iter().any(|p| matches!(p, Piece::Token(_))) {
+        return Err("Template must contain at least one token".to_string());
+    }
+    for segment in pieces.split(|p| *p == Piece::Separator) {
+        if segment.is_empty() {
+            return Err("Template contains an empty path segment".to_string());
+        }
+        if let [Piece::Text(text)] = segment {
+            if matches!(text.trim(), "." | "..") {
+                return Err("Template must not contain '.' or '..' segments".to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 渲染模板为相对路径的各段（最后一段为不含扩展名的文件名）
+///
+/// - 值为空的目录段会被省略，文件名段为空时回退为 `fallback_name`
+/// - `ext` 仅用于预留最后一段的长度（文件名 + `.` + 扩展名 不超过 `max_name_bytes`）
+pub(crate) fn render_template(
+    template: &str,
+    values: &HashMap<&str, String>,
+    ext: &str,
+    max_name_bytes: usize,
+    fallback_name: &str,
+) -> Result<Vec<String>, String> {
+    validate_template(template)?;
+    let pieces = parse(template.trim())?;
+    let max_name_bytes = max_name_bytes.max(MIN_MAX_NAME_BYTES);
+
+    let raw_segments = pieces
+        .split(|p| *p == Piece::Separator)
+        .map(|segment| {
+            let mut has_value = false;
+            let mut out = String::new();
+            for piece in segment {
+                match piece {
+                    Piece::Text(text) => out.push_str(text),
+                    Piece::Token(name) => {
+                        let value = values.get(name.as_str()).map(|v| v.trim()).unwrap_or("");
+                        has_value |= !value.is_empty();
+                        out.push_str(value);
+                    }
+                    Piece::Separator => {}
+                }
+            }
+            let only_text = segment.iter().all(|p| matches!(p, Piece::Text(_)));
+            (out, has_value || only_text)
+        })
+        .collect::<Vec<_>>();
+
+    let last = raw_segments.len() - 1;
+    let mut segments = Vec::with_capacity(raw_segments.len());
+    for (index, (raw, has_value)) in raw_segments.into_iter().enumerate() {
+        if index == last {
+            let budget = max_name_bytes.saturating_sub(ext.len() + 1).max(1);
+            let name = if has_value {
+                sanitize_segment(&raw, budget)
+            } else {
+                None
+            };
+            let name = name
+                .or_else(|| sanitize_segment(fallback_name, budget))
+                .unwrap_or_else(|| "_".to_string());
+            segments.push(name);
+        } else if has_value {
+            if let Some(dir) = sanitize_segment(&raw, max_name_bytes) {
+                segments.push(dir);
+            }
+        }
+    }
+    Ok(segments)
+}
+
+/// 清理单段名称；结果为空时返回 None
+pub(crate) fn sanitize_segment(input: &str, max_bytes: usize) -> Option<String> {
+    let trimmed = input.trim().trim_matches('.').trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let cleaned = sanitize_filename(trimmed);
+    let mut out = truncate_bytes(&cleaned, max_bytes)
+        .trim()
+        .trim_end_matches('.')
+        .trim()
+        .to_string();
+    if out.is_empty() {
+        return None;
+    }
+    if is_reserved_name(&out) {
+        out.push('_');
+    }
+    Some(out)
+}
+
+/// 重名时的候选文件名 `{stem} ({n}).{ext}`
+///
+/// 先截断 `stem` 为序号与扩展名预留空间，结果不超过 `max_name_bytes`
+pub(crate) fn numbered_name(stem: &str, ext: &str, n: u32, max_name_bytes: usize) -> String {
+    let suffix = format!(" ({n})");
+    let ext_len = if ext.is_empty() { 0 } else { ext.len() + 1 };
+    let budget = max_name_bytes.saturating_sub(suffix.len() + ext_len).max(1);
+    let stem = truncate_bytes(stem, budget).trim_end();
+    if ext.is_empty() {
+        format!("{stem}{suffix}")
+    } else {
+        format!("{stem}{suffix}.{ext}")
+    }
+}
+
+/// 替换文件系统非法字符（Windows + 常见文件系统），结果为空时返回 `_`
+pub(crate) fn sanitize_filename(input: &str) -> String {
+    // Windows + common filesystem forbidden: <>:"/\|?* and control chars
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        let invalid =
+            matches!(ch, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') || ch.is_control();
+        if invalid {
+            out.push('_');
+        } else {
+            out.push(ch);
+        }
+    }
+    let out = out.trim().trim_matches('.').trim().to_string();
+    if out.is_empty() {
+        "_".to_string()
+    } else {
+        out
+    }
+}
+
+/// 按 UTF-8 字节数截断（不切断字符）
+fn truncate_bytes(input: &str, max_bytes: usize) -> &str {
+    if input.len() <= max_bytes {
+        return input;
+    }
+    let mut end = max_bytes;
+    while end > 0 && !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    &input[..end]
+}
+
+/// Windows 保留设备名（不区分大小写，忽略扩展名）
+fn is_reserved_name(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name).trim();
+    let upper = stem.to_ascii_uppercase();
+    matches!(upper.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+        || ((upper.starts_with("COM") || upper.starts_with("LPT"))
+            && upper.len() == 4
+            && upper.as_bytes()[3].is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> HashMap<&'static str, String> {
+        HashMap::from([
+            ("author", "Studio/A".to_string()),
+            ("title", "Episode: 1?".to_string()),
+            ("video_id", "12345".to_string()),
+            ("quality", "1080P".to_string()),
+        ])
+    }
+
+    #[test]
+    fn validate_rejects_bad_templates() {
+        assert!(validate_template("[{author}]{title}").is_ok());
+        assert!(validate_template("{author}/{{raw}}{title}").is_ok());
+        assert!(validate_template("").is_err());
+        assert!(validate_template("plain").is_err());
+        assert!(validate_template("{unknown}").is_err());
+        assert!(validate_template("{title").is_err());
+        assert!(validate_template("{author}//{title}").is_err());
+        assert!(validate_template("../{title}").is_err());
+    }
+
+    #[test]
+    fn render_sanitizes_each_segment() {
+        let segments = render_template(
+            "{author}/{folder}/[{quality}]{title}",
+            &values(),
+            "mp4",
+            255,
+            "x",
+        )
+        .unwrap();
+        // 空的 {folder} 段被省略，`/` 出现在值中时不会产生子目录
+        assert_eq!(segments, vec!["Studio_A", "[1080P]Episode_ 1_"]);
+    }
+
+    #[test]
+    fn render_truncates_by_bytes() {
+        let mut v = values();
+        v.insert("title", "测".repeat(200));
+        let segments = render_template("{title}", &v, "mp4", 64, "x").unwrap();
+        assert!(segments[0].len() + 4 <= 64);
+        assert!(segments[0].chars().all(|c| c == '测'));
+    }
+
+    #[test]
+    fn numbered_name_reserves_suffix() {
+        assert_eq!(numbered_name("a", "mp4", 2, 255), "a (2).mp4");
+        assert_eq!(numbered_name("a", "", 2, 255), "a (2)");
+        // 已达到最大长度的文件名：截断后再追加序号
+        let mut v = values();
+        v.insert("title", "测".repeat(200));
+        let stem = render_template("{title}", &v, "mp4", 64, "x")
+            .unwrap()
+            .remove(0);
+        let name = numbered_name(&stem, "mp4", 12, 64);
+        assert!(name.len() <= 64);
+        assert!(name.ends_with(" (12).mp4"));
+        assert!(name
+            .trim_end_matches(" (12).mp4")
+            .chars()
+            .all(|c| c == '测'));
+    }
+
+    #[test]
+    fn render_avoids_reserved_names() {
+        let mut v = values();
+        v.insert("title", "con".to_string());
+        let segments = render_template("{title}", &v, "mp4", 255, "x").unwrap();
+        assert_eq!(segments, vec!["con_"]);
+    }
+}
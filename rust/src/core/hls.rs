@@ -0,0 +1,457 @@
+// HLS 播放列表与播放地址工具（纯计算，不发起网络请求）
+// - 解析 master 播放列表中的各画质变体（带宽、分辨率、编码）
+// - 按清晰度选择播放源：完全匹配 → 低于目标的最接近清晰度 → auto
+// - 从带签名的地址中读取过期时间
+// - 解析点播媒体播放列表的分片（EXT-X-MAP 初始化分片、AES-128 密钥），解密 AES-128 分片
+
+/// master 播放列表中的一个画质变体
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HlsVariant {
+    /// 变体播放列表的绝对地址
+    pub url: String,
+    /// 峰值带宽（bit/s）
+    pub bandwidth: Option<u64>,
+    /// 分辨率（宽, 高）
+    pub resolution: Option<(u32, u32)>,
+    pub codecs: Option<String>,
+}
+
+impl HlsVariant {
+    /// 清晰度名称（与网页 `<source size>` 一致，例如 `720P`）
+    pub fn quality(&self) -> Option<String> {
+        self.resolution.map(|(_, height)| format!("{}P", height))
+    }
+}
+
+/// 解析 master 播放列表；不是 master 播放列表（没有 `#EXT-X-STREAM-INF`）时返回空
+pub(crate) fn parse_master_playlist(body: &str, playlist_url: &url::Url) -> Vec<HlsVariant> {
+    let mut variants = Vec::new();
+    let mut pending: Option<HlsVariant> = None;
+    for line in body.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(attrs) = line.strip_prefix("#EXT-X-STREAM-INF:") {
+            let attrs = parse_attributes(attrs);
+            let get = |name: &str| {
+                attrs
+                    .iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                    .map(|(_, v)| v.as_str())
+            };
+            pending = Some(HlsVariant {
+                url: String::new(),
+                bandwidth: get("BANDWIDTH").and_then(|v| v.parse().ok()),
+                resolution: get("RESOLUTION").and_then(|v| {
+                    let (w, h) = v.split_once(['x', 'X'])?;
+                    Some((w.parse().ok()?, h.parse().ok()?))
+                }),
+                codecs: get("CODECS").map(str::to_string),
+            });
+        } else if line.starts_with('#') {
+            continue;
+        } else if let Some(mut variant) = pending.take() {
+            let Ok(url) = playlist_url.join(line) else {
+                continue;
+            };
+            variant.url = url.to_string();
+            variants.push(variant);
+        }
+    }
+    variants
+}
+
+/// 解析属性列表 `A=1,B="x,y"`（引号内的逗号不分隔）
+fn parse_attributes(attrs: &str) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    let mut rest = attrs.trim();
+    while !rest.is_empty() {
+        let Some((name, after)) = rest.split_once('=') else {
+            break;
+        };
+        let (value, remaining) = if let Some(quoted) = after.strip_prefix('"') {
+            match quoted.find('"') {
+                Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                None => (quoted, ""),
+            }
+        } else {
+            match after.find(',') {
+                Some(end) => (&after[..end], &after[end..]),
+                None => (after, ""),
+            }
+        };
+        result.push((name.trim().to_string(), value.to_string()));
+        rest = remaining.trim_start_matches(',').trim();
+    }
+    result
+}
+
+/// 清晰度名称中的高度（`1080P` → 1080）；`auto` 等返回空
+pub(crate) fn quality_height(quality: &str) -> Option<u32> {
+    quality
+        .trim()
+        .trim_end_matches(['p', 'P'])
+        .parse::<u32>()
+        .ok()
+}
+
+/// 按清晰度选择：完全匹配 → 低于目标的最接近清晰度 → auto → 高于目标的最接近清晰度
+pub(crate) fn pick_quality<S: AsRef<str>>(qualities: &[S], wanted: &str) -> Option<usize> {
+    let exact = qualities
+        .iter()
+        .position(|q| q.as_ref().eq_ignore_ascii_case(wanted.trim()));
+    if exact.is_some() {
+        return exact;
+    }
+    let heights = qualities
+        .iter()
+        .map(|q| quality_height(q.as_ref()))
+        .collect::<Vec<_>>();
+    if let Some(target) = quality_height(wanted) {
+        let lower = heights
+            .iter()
+            .enumerate()
+            .filter_map(|(i, h)| h.filter(|h| *h <= target).map(|h| (i, h)))
+            .max_by_key(|(_, h)| *h);
+        if let Some((index, _)) = lower {
+            return Some(index);
+        }
+    }
+    let auto = qualities
+        .iter()
+        .position(|q| q.as_ref().eq_ignore_ascii_case("auto"));
+    if auto.is_some() {
+        return auto;
+    }
+    heights
+        .iter()
+        .enumerate()
+        .filter_map(|(i, h)| h.map(|h| (i, h)))
+        .min_by_key(|(_, h)| *h)
+        .map(|(i, _)| i)
+        .or(if qualities.is_empty() { None } else { Some(0) })
+}
+
+/// 媒体播放列表中的一个分片
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HlsSegment {
+    pub url: String,
+    /// 媒体序号（未指定 IV 时作为解密 IV）
+    pub sequence: u64,
+    /// AES-128 密钥，未加密时为空
+    pub key: Option<HlsKey>,
+}
+
+/// AES-128 密钥（EXT-X-KEY）
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HlsKey {
+    pub url: String,
+    pub iv: Option<[u8; 16]>,
+}
+
+impl HlsSegment {
+    /// 解密使用的 IV：未指定时为大端序的媒体序号
+    pub fn iv(&self) -> [u8; 16] {
+        if let Some(iv) = self.key.as_ref().and_then(|k| k.iv) {
+            return iv;
+        }
+        let mut iv = [0u8; 16];
+        iv[8..].copy_from_slice(&self.sequence.to_be_bytes());
+        iv
+    }
+}
+
+/// 点播媒体播放列表
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MediaPlaylist {
+    /// fMP4 初始化分片（EXT-X-MAP），TS 分片时为空
+    pub init_url: Option<String>,
+    pub segments: Vec<HlsSegment>,
+}
+
+/// 解析点播媒体播放列表
+///
+/// 直播流、字节范围分片、多个初始化分片以及 AES-128 以外的加密方式不支持下载，返回错误原因
+pub(crate) fn parse_media_playlist(
+    body: &str,
+    playlist_url: &url::Url,
+) -> Result<MediaPlaylist, String> {
+    let mut lines = body.lines().map(str::trim).filter(|l| !l.is_empty());
+    if lines.next() != Some("#EXTM3U") {
+        return Err("Not an HLS playlist".to_string());
+    }
+    let join = |uri: &str| {
+        playlist_url
+            .join(uri)
+            .map(|u| u.to_string())
+            .map_err(|e| format!("Invalid URI {uri}: {e}"))
+    };
+    let mut sequence = 0u64;
+    let mut key = None;
+    let mut init_url: Option<String> = None;
+    let mut ended = false;
+    let mut segments = Vec::new();
+    for line in lines {
+        if let Some(value) = line.strip_prefix("#EXT-X-MEDIA-SEQUENCE:") {
+            sequence = value.trim().parse().unwrap_or(0);
+        } else if let Some(attrs) = line.strip_prefix("#EXT-X-KEY:") {
+            let attrs = parse_attributes(attrs);
+            let get = |name: &str| {
+                attrs
+                    .iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                    .map(|(_, v)| v.as_str())
+            };
+            key = match get("METHOD").unwrap_or("NONE") {
+                "NONE" => None,
+                "AES-128" => {
+                    if get("KEYFORMAT").is_some_and(|f| f != "identity") {
+                        return Err("Unsupported HLS key format".to_string());
+                    }
+                    let uri = get("URI").ok_or("HLS key without URI")?;
+                    let iv = match get("IV") {
+                        Some(iv) => Some(parse_iv(iv).ok_or("Invalid HLS key IV")?),
+                        None => None,
+                    };
+                    Some(HlsKey {
+                        url: join(uri)?,
+                        iv,
+                    })
+                }
+                method => return Err(format!("Unsupported HLS encryption {method}")),
+            };
+        } else if let Some(attrs) = line.strip_prefix("#EXT-X-MAP:") {
+            let attrs = parse_attributes(attrs);
+            if attrs
+                .iter()
+                .any(|(k, _)| k.eq_ignore_ascii_case("BYTERANGE"))
+            {
+                return Err("HLS byte-range segments are not supported".to_string());
+            }
+            let uri = attrs
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case("URI"))
+                .map(|(_, v)| join(v))
+                .ok_or("HLS map without URI")??;
+            if init_url.as_ref().is_some_and(|u| *u != uri) {
+                return Err("Multiple HLS initialization segments are not supported".to_string());
+            }
+            init_url = Some(uri);
+        } else if line.starts_with("#EXT-X-BYTERANGE") {
+            return Err("HLS byte-range segments are not supported".to_string());
+        } else if line.starts_with("#EXT-X-STREAM-INF") {
+            return Err("Expected a media playlist, got a master playlist".to_string());
+        } else if line == "#EXT-X-ENDLIST" {
+            ended = true;
+        } else if !line.starts_with('#') {
+            segments.push(HlsSegment {
+                url: join(line)?,
+                sequence,
+                key: key.clone(),
+            });
+            sequence += 1;
+        }
+    }
+    if !ended {
+        return Err("Live HLS streams cannot be downloaded".to_string());
+    }
+    if segments.is_empty() {
+        return Err("HLS playlist has no segments".to_string());
+    }
+    Ok(MediaPlaylist { init_url, segments })
+}
+
+/// `0x` 开头的 16 字节十六进制 IV
+fn parse_iv(value: &str) -> Option<[u8; 16]> {
+    let hex = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))?;
+    if hex.is_empty() || hex.len() > 32 {
+        return None;
+    }
+    let value = u128::from_str_radix(hex, 16).ok()?;
+    Some(value.to_be_bytes())
+}
+
+/// AES-128-CBC 解密分片并去掉 PKCS#7 填充
+pub(crate) fn decrypt_segment(key: &[u8], iv: &[u8; 16], data: &[u8]) -> Result<Vec<u8>, String> {
+    use aes::cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit};
+    if key.len() != 16 {
+        return Err(format!("Invalid AES-128 key length {}", key.len()));
+    }
+    if data.is_empty() || !data.len().is_multiple_of(16) {
+        return Err(format!(
+            "Encrypted segment length {} is invalid",
+            data.len()
+        ));
+    }
+    let cipher = aes::Aes128::new(GenericArray::from_slice(key));
+    let mut out = Vec::with_capacity(data.len());
+    let mut previous = *iv;
+    for chunk in data.chunks_exact(16) {
+        let mut block = GenericArray::clone_from_slice(chunk);
+        cipher.decrypt_block(&mut block);
+        out.extend(block.iter().zip(previous).map(|(b, p)| b ^ p));
+        previous.copy_from_slice(chunk);
+    }
+    let pad = usize::from(out[out.len() - 1]);
+    if pad == 0
+        || pad > 16
+        || !out[out.len() - pad..]
+            .iter()
+            .all(|b| usize::from(*b) == pad)
+    {
+        return Err("Invalid padding in decrypted segment (wrong key?)".to_string());
+    }
+    out.truncate(out.len() - pad);
+    Ok(out)
+}
+
+/// 从带签名的地址中读取过期时间（秒级时间戳）
+pub(crate) fn url_expiry(url: &str) -> Option<i64> {
+    let url = url::Url::parse(url).ok()?;
+    url.query_pairs()
+        .filter(|(k, _)| {
+            ["expires", "expire", "exp", "e"]
+                .iter()
+                .any(|name| k.eq_ignore_ascii_case(name))
+        })
+        .filter_map(|(_, v)| v.parse::<i64>().ok())
+        // 毫秒时间戳换算为秒
+        .map(|v| if v > 100_000_000_000 { v / 1000 } else { v })
+        .find(|v| *v > 1_000_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_master_playlist() {
+        let url = url::Url::parse("https://cdn.example.com/v/master.m3u8?sig=1").unwrap();
+        let body = "#EXTM3U\n\
+            #EXT-X-STREAM-INF:BANDWIDTH=2500000,RESOLUTION=1280x720,CODECS=\"avc1.4d401f,mp4a.40.2\"\n\
+            720/index.m3u8\n\
+            #EXT-X-STREAM-INF:BANDWIDTH=800000,RESOLUTION=640x360\n\
+            https://other.example.com/360.m3u8\n";
+        let variants = parse_master_playlist(body, &url);
+        assert_eq!(
+            variants,
+            vec![
+                HlsVariant {
+                    url: "https://cdn.example.com/v/720/index.m3u8".to_string(),
+                    bandwidth: Some(2_500_000),
+                    resolution: Some((1280, 720)),
+                    codecs: Some("avc1.4d401f,mp4a.40.2".to_string()),
+                },
+                HlsVariant {
+                    url: "https://other.example.com/360.m3u8".to_string(),
+                    bandwidth: Some(800_000),
+                    resolution: Some((640, 360)),
+                    codecs: None,
+                },
+            ]
+        );
+        assert_eq!(variants[0].quality().as_deref(), Some("720P"));
+
+        // 媒体播放列表不是 master
+        let media = "#EXTM3U\n#EXTINF:4.0,\nseg0.ts\n";
+        assert!(parse_master_playlist(media, &url).is_empty());
+    }
+
+    #[test]
+    fn test_parse_media_playlist() {
+        let url = url::Url::parse("https://cdn.example.com/v/720/index.m3u8").unwrap();
+        let body = "#EXTM3U\n\
+            #EXT-X-TARGETDURATION:4\n\
+            #EXT-X-MEDIA-SEQUENCE:7\n\
+            #EXT-X-MAP:URI=\"init.mp4\"\n\
+            #EXTINF:4.0,\n\
+            seg0.m4s\n\
+            #EXT-X-KEY:METHOD=AES-128,URI=\"/keys/k1\",IV=0x000102030405060708090a0b0c0d0e0f\n\
+            #EXTINF:4.0,\n\
+            seg1.m4s\n\
+            #EXT-X-KEY:METHOD=AES-128,URI=\"https://keys.example.com/k2\"\n\
+            #EXTINF:2.5,\n\
+            seg2.m4s\n\
+            #EXT-X-ENDLIST\n";
+        let playlist = parse_media_playlist(body, &url).unwrap();
+        assert_eq!(
+            playlist.init_url.as_deref(),
+            Some("https://cdn.example.com/v/720/init.mp4")
+        );
+        let segments = &playlist.segments;
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].url, "https://cdn.example.com/v/720/seg0.m4s");
+        assert_eq!(segments[0].key, None);
+        assert_eq!(
+            segments[1].key.as_ref().unwrap().url,
+            "https://cdn.example.com/keys/k1"
+        );
+        assert_eq!(segments[1].iv(), core::array::from_fn(|i| i as u8));
+        // 未指定 IV 时使用媒体序号
+        assert_eq!(segments[2].sequence, 9);
+        assert_eq!(segments[2].iv()[15], 9);
+        assert!(segments[2].iv()[..15].iter().all(|b| *b == 0));
+
+        // 不支持的播放列表
+        let live = "#EXTM3U\n#EXTINF:4.0,\nseg0.ts\n";
+        assert!(parse_media_playlist(live, &url).is_err());
+        let sample_aes = "#EXTM3U\n#EXT-X-KEY:METHOD=SAMPLE-AES,URI=\"k\"\n#EXTINF:4.0,\nseg0.ts\n#EXT-X-ENDLIST\n";
+        assert!(parse_media_playlist(sample_aes, &url).is_err());
+        let byte_range = "#EXTM3U\n#EXTINF:4.0,\n#EXT-X-BYTERANGE:100@0\nall.ts\n#EXT-X-ENDLIST\n";
+        assert!(parse_media_playlist(byte_range, &url).is_err());
+        assert!(parse_media_playlist("seg0.ts\n", &url).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_segment() {
+        use aes::cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit};
+        let key = [7u8; 16];
+        let iv = [9u8; 16];
+        let plain = b"0123456789abcdefHLS".to_vec();
+        // AES-128-CBC + PKCS#7 加密
+        let mut padded = plain.clone();
+        let pad = 16 - plain.len() % 16;
+        padded.extend(std::iter::repeat_n(pad as u8, pad));
+        let cipher = aes::Aes128::new(GenericArray::from_slice(&key));
+        let mut previous = iv;
+        let mut encrypted = Vec::new();
+        for chunk in padded.chunks_exact(16) {
+            let mut block = GenericArray::clone_from_slice(chunk);
+            block.iter_mut().zip(previous).for_each(|(b, p)| *b ^= p);
+            cipher.encrypt_block(&mut block);
+            previous.copy_from_slice(&block);
+            encrypted.extend_from_slice(&block);
+        }
+        assert_eq!(decrypt_segment(&key, &iv, &encrypted).unwrap(), plain);
+        assert!(decrypt_segment(&[1u8; 16], &iv, &encrypted).is_err());
+        assert!(decrypt_segment(&key, &iv, &encrypted[..20]).is_err());
+        assert!(decrypt_segment(&key[..8], &iv, &encrypted).is_err());
+    }
+
+    #[test]
+    fn test_pick_quality() {
+        let qualities = ["1080P", "720P", "480P", "auto"];
+        assert_eq!(pick_quality(&qualities, "720p"), Some(1));
+        // 没有完全匹配时选择低于目标的最接近清晰度
+        assert_eq!(pick_quality(&qualities, "900P"), Some(1));
+        // 低于所有清晰度时回退到 auto
+        assert_eq!(pick_quality(&qualities, "240P"), Some(3));
+        assert_eq!(pick_quality(&qualities, "auto"), Some(3));
+        // 没有 auto 时选择最接近的更高清晰度
+        assert_eq!(pick_quality(&["1080P", "720P"], "480P"), Some(1));
+        assert_eq!(pick_quality::<&str>(&[], "720P"), None);
+    }
+
+    #[test]
+    fn test_url_expiry() {
+        assert_eq!(
+            url_expiry("https://cdn.example.com/a.mp4?token=x&expires=1700000000"),
+            Some(1_700_000_000)
+        );
+        assert_eq!(
+            url_expiry("https://cdn.example.com/a.mp4?Expires=1700000000000"),
+            Some(1_700_000_000)
+        );
+        assert_eq!(url_expiry("https://cdn.example.com/a.mp4?e=12"), None);
+        assert_eq!(url_expiry("https://cdn.example.com/a.mp4"), None);
+    }
+}
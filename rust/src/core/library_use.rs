@@ -0,0 +1,79 @@
+// 下载目录使用登记
+// - 导出、打包、核对扫描、本地播放、新建下载（封面）、删除与清理等直接读写下载目录文件的任务，在使用期间持有 `LibraryUse`
+// - 迁移下载目录前检查：有任务正在使用时拒绝迁移；迁移期间新任务无法开始
+// - 先登记再检查对方标志（双方均为 SeqCst），不会出现两边都认为对方不存在的情况
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// 正在使用下载目录的任务数
+static USERS: AtomicUsize = AtomicUsize::new(0);
+/// 是否正在迁移下载目录
+static RELOCATING: AtomicBool = AtomicBool::new(false);
+
+/// 下载目录使用登记（drop 时注销）
+#[derive(Debug)]
+pub(crate) struct LibraryUse(());
+
+impl Drop for LibraryUse {
+    fn drop(&mut self) {
+        USERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 开始使用下载目录；迁移进行中时返回错误
+pub(crate) fn acquire() -> anyhow::Result<LibraryUse> {
+    USERS.fetch_add(1, Ordering::SeqCst);
+    let guard = LibraryUse(());
+    if RELOCATING.load(Ordering::SeqCst) {
+        anyhow::bail!("Download directory is being relocated");
+    }
+    Ok(guard)
+}
+
+/// 迁移登记（drop 时结束迁移）
+#[derive(Debug)]
+pub(crate) struct Relocation(());
+
+impl Drop for Relocation {
+    fn drop(&mut self) {
+        RELOCATING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// 开始迁移下载目录；已在迁移或有任务正在使用下载目录时返回错误
+pub(crate) fn begin_relocation() -> anyhow::Result<Relocation> {
+    if RELOCATING.swap(true, Ordering::SeqCst) {
+        anyhow::bail!("Download directory is already being relocated");
+    }
+    let guard = Relocation(());
+    if USERS.load(Ordering::SeqCst) > 0 {
+        anyhow::bail!(
+            "Download files are in use (export, bundle, library scan, playback, new download or deletion); try again later"
+        );
+    }
+    Ok(guard)
+}
+
+/// 登记状态为全局变量，相关测试需串行执行
+#[cfg(test)]
+pub(crate) static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relocation_excludes_users() {
+        let _lock = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let user = acquire().unwrap();
+        assert!(begin_relocation().is_err());
+        drop(user);
+
+        let relocation = begin_relocation().unwrap();
+        assert!(acquire().is_err());
+        assert!(begin_relocation().is_err());
+        drop(relocation);
+
+        assert!(acquire().is_ok());
+    }
+}
@@ -0,0 +1,345 @@
+// 下载文件完整性校验
+// - 文件大小与预期总长度比对
+// - MP4：顶层 box 结构完整（ftyp / moov / mdat 均存在且不越界），moov 可解析（含 mvhd / trak）
+// - TS：188 字节包的同步字节 0x47
+// - M3U8：以 #EXTM3U 开头
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+const TS_PACKET_SIZE: u64 = 188;
+const TS_SYNC_BYTE: u8 = 0x47;
+/// TS 抽查的包数量（文件头/尾各取一段）
+const TS_SAMPLE_PACKETS: u64 = 1000;
+/// moov 超过该大小时不再整体读入，仅校验顶层结构
+const MOOV_PARSE_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// 校验结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MediaCheck {
+    Ok,
+    /// 文件比预期短（连接提前关闭），可以 Range 续传补齐
+    Truncated {
+        actual: u64,
+        expected: u64,
+    },
+    /// 结构损坏，需要重新下载
+    Invalid(String),
+}
+
+/// 校验下载完成的文件；`expected_size` 为 0 时跳过大小比对
+pub(crate) fn verify_media_file(path: &Path, expected_size: u64) -> std::io::Result<MediaCheck> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    if expected_size > 0 && len < expected_size {
+        return Ok(MediaCheck::Truncated {
+            actual: len,
+            expected: expected_size,
+        });
+    }
+    if expected_size > 0 && len > expected_size {
+        return Ok(MediaCheck::Invalid(format!(
+            "File size {} exceeds expected {}",
+            len, expected_size
+        )));
+    }
+    if len == 0 {
+        return Ok(MediaCheck::Invalid("Empty file".to_string()));
+    }
+
+    let ext = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .map(|s| s.to_ascii_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "mp4" | "m4v" | "mov" => verify_mp4(&mut file, len),
+        "ts" => verify_ts(&mut file, len),
+        "m3u8" => verify_m3u8(&mut file),
+        _ => Ok(MediaCheck::Ok),
+    }
+}
+
+/// 顶层 box 头
+struct BoxHeader {
+    kind: [u8; 4],
+    /// box 总长度（含头）
+    size: u64,
+    header_len: u64,
+}
+
+fn read_box_header<R: Read>(r: &mut R, remaining: u64) -> std::io::Result<Option<BoxHeader>> {
+    if remaining < 8 {
+        return Ok(None);
+    }
+    let mut head = [0u8; 8];
+    r.read_exact(&mut head)?;
+    let size32 = u32::from_be_bytes([head[0], head[1], head[2], head[3]]) as u64;
+    let kind = [head[4], head[5], head[6], head[7]];
+    let (size, header_len) = match size32 {
+        // size == 0：box 延伸到文件末尾
+        0 => (remaining, 8),
+        // size == 1：64 位 largesize
+        1 => {
+            if remaining < 16 {
+                return Ok(None);
+            }
+            let mut large = [0u8; 8];
+            r.read_exact(&mut large)?;
+            (u64::from_be_bytes(large), 16)
+        }
+        n => (n, 8),
+    };
+    Ok(Some(BoxHeader {
+        kind,
+        size,
+        header_len,
+    }))
+}
+
+fn box_name(kind: &[u8; 4]) -> String {
+    String::from_utf8_lossy(kind).into_owned()
+}
+
+fn is_valid_box_kind(kind: &[u8; 4]) -> bool {
+    // 类型为 4 个可打印字符（允许 © 等 Latin-1 字符，如 `©xyz`）
+    kind.iter()
+        .all(|b| b.is_ascii_alphanumeric() || *b == b' ' || *b >= 0xA9)
+}
+
+pub(crate) fn verify_mp4<R: Read + Seek>(r: &mut R, len: u64) -> std::io::Result<MediaCheck> {
+    let mut offset = 0u64;
+    let mut has_ftyp = false;
+    let mut has_mdat = false;
+    let mut moov: Option<(u64, u64)> = None;
+
+    r.seek(SeekFrom::Start(0))?;
+    while offset < len {
+        let Some(header) = read_box_header(r, len - offset)? else {
+            return Ok(MediaCheck::Invalid(format!(
+                "Trailing {} bytes at offset {}",
+                len - offset,
+                offset
+            )));
+        };
+        if !is_valid_box_kind(&header.kind) {
+            return Ok(MediaCheck::Invalid(format!(
+                "Invalid box type at offset {}",
+                offset
+            )));
+        }
+        if header.size < header.header_len {
+            return Ok(MediaCheck::Invalid(format!(
+                "Invalid size for box {} at offset {}",
+                box_name(&header.kind),
+                offset
+            )));
+        }
+        // 损坏的 largesize 可能接近 u64::MAX，相加需检查溢出
+        if offset.checked_add(header.size).is_none_or(|end| end > len) {
+            return Ok(MediaCheck::Invalid(format!(
+                "Box {} at offset {} exceeds file size",
+                box_name(&header.kind),
+                offset
+            )));
+        }
+        match &header.kind {
+            b"ftyp" => has_ftyp = true,
+            b"mdat" => has_mdat = true,
+            b"moov" => moov = Some((offset + header.header_len, header.size - header.header_len)),
+            _ => {}
+        }
+        offset += header.size;
+        r.seek(SeekFrom::Start(offset))?;
+    }
+
+    if !has_ftyp {
+        return Ok(MediaCheck::Invalid("Missing ftyp box".to_string()));
+    }
+    if !has_mdat {
+        return Ok(MediaCheck::Invalid("Missing mdat box".to_string()));
+    }
+    let Some((moov_start, moov_len)) = moov else {
+        return Ok(MediaCheck::Invalid("Missing moov box".to_string()));
+    };
+    if moov_len > MOOV_PARSE_LIMIT {
+        return Ok(MediaCheck::Ok);
+    }
+
+    // 解析 moov 子 box：必须恰好填满 moov，且包含 mvhd 与至少一个 trak
+    r.seek(SeekFrom::Start(moov_start))?;
+    let mut payload = vec![0u8; moov_len as usize];
+    r.read_exact(&mut payload)?;
+    let mut cursor = std::io::Cursor::new(payload.as_slice());
+    let mut child_offset = 0u64;
+    let mut has_mvhd = false;
+    let mut has_trak = false;
+    while child_offset < moov_len {
+        let Some(child) = read_box_header(&mut cursor, moov_len - child_offset)? else {
+            return Ok(MediaCheck::Invalid("Malformed moov box".to_string()));
+        };
+        if !is_valid_box_kind(&child.kind)
+            || child.size < child.header_len
+            || child_offset
+                .checked_add(child.size)
+                .is_none_or(|end| end > moov_len)
+        {
+            return Ok(MediaCheck::Invalid("Malformed moov box".to_string()));
+        }
+        match &child.kind {
+            b"mvhd" => has_mvhd = true,
+            b"trak" => has_trak = true,
+            _ => {}
+        }
+        child_offset += child.size;
+        cursor.seek(SeekFrom::Start(child_offset))?;
+    }
+    if !has_mvhd || !has_trak {
+        return Ok(MediaCheck::Invalid("moov box lacks mvhd/trak".to_string()));
+    }
+
+    Ok(MediaCheck::Ok)
+}
+
+pub(crate) fn verify_ts<R: Read + Seek>(r: &mut R, len: u64) -> std::io::Result<MediaCheck> {
+    if len < TS_PACKET_SIZE {
+        return Ok(MediaCheck::Invalid(
+            "File shorter than one TS packet".to_string(),
+        ));
+    }
+    if !len.is_multiple_of(TS_PACKET_SIZE) {
+        return Ok(MediaCheck::Invalid(format!(
+            "Size {} is not a multiple of {}",
+            len, TS_PACKET_SIZE
+        )));
+    }
+    let packets = len / TS_PACKET_SIZE;
+    let head = packets.min(TS_SAMPLE_PACKETS);
+    let tail_start = packets.saturating_sub(TS_SAMPLE_PACKETS).max(head);
+    let mut byte = [0u8; 1];
+    for index in (0..head).chain(tail_start..packets) {
+        r.seek(SeekFrom::Start(index * TS_PACKET_SIZE))?;
+        r.read_exact(&mut byte)?;
+        if byte[0] != TS_SYNC_BYTE {
+            return Ok(MediaCheck::Invalid(format!(
+                "Lost TS sync at packet {}",
+                index
+            )));
+        }
+    }
+    Ok(MediaCheck::Ok)
+}
+
+fn verify_m3u8<R: Read>(r: &mut R) -> std::io::Result<MediaCheck> {
+    let mut head = [0u8; 7];
+    match r.read_exact(&mut head) {
+        Ok(()) if &head == b"#EXTM3U" => Ok(MediaCheck::Ok),
+        Ok(()) => Ok(MediaCheck::Invalid("Missing #EXTM3U header".to_string())),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+            Ok(MediaCheck::Invalid("Missing #EXTM3U header".to_string()))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn mp4_box(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut out = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(payload);
+        out
+    }
+
+    fn sample_mp4() -> Vec<u8> {
+        let mut moov_payload = mp4_box(b"mvhd", &[0u8; 100]);
+        moov_payload.extend(mp4_box(b"trak", &mp4_box(b"tkhd", &[0u8; 84])));
+        let mut data = mp4_box(b"ftyp", b"isom\0\0\x02\0isomiso2");
+        data.extend(mp4_box(b"moov", &moov_payload));
+        data.extend(mp4_box(b"mdat", &[1u8; 512]));
+        data
+    }
+
+    #[test]
+    fn mp4_valid_structure() {
+        let data = sample_mp4();
+        let len = data.len() as u64;
+        assert_eq!(
+            verify_mp4(&mut Cursor::new(data), len).unwrap(),
+            MediaCheck::Ok
+        );
+    }
+
+    #[test]
+    fn mp4_truncated_mdat() {
+        let mut data = sample_mp4();
+        data.truncate(data.len() - 100);
+        let len = data.len() as u64;
+        assert!(matches!(
+            verify_mp4(&mut Cursor::new(data), len).unwrap(),
+            MediaCheck::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn mp4_largesize_overflow() {
+        let mut data = mp4_box(b"ftyp", b"isom\0\0\x02\0");
+        data.extend(1u32.to_be_bytes());
+        data.extend(b"mdat");
+        data.extend(u64::MAX.to_be_bytes());
+        data.extend([0u8; 32]);
+        let len = data.len() as u64;
+        assert!(matches!(
+            verify_mp4(&mut Cursor::new(data), len).unwrap(),
+            MediaCheck::Invalid(_)
+        ));
+
+        // moov 子 box 的 largesize 溢出
+        let mut moov = 1u32.to_be_bytes().to_vec();
+        moov.extend(b"mvhd");
+        moov.extend(u64::MAX.to_be_bytes());
+        let mut data = mp4_box(b"ftyp", b"isom\0\0\x02\0");
+        data.extend(mp4_box(b"moov", &moov));
+        data.extend(mp4_box(b"mdat", &[1u8; 64]));
+        let len = data.len() as u64;
+        assert!(matches!(
+            verify_mp4(&mut Cursor::new(data), len).unwrap(),
+            MediaCheck::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn mp4_missing_moov() {
+        let mut data = mp4_box(b"ftyp", b"isom\0\0\x02\0");
+        data.extend(mp4_box(b"mdat", &[1u8; 64]));
+        let len = data.len() as u64;
+        assert_eq!(
+            verify_mp4(&mut Cursor::new(data), len).unwrap(),
+            MediaCheck::Invalid("Missing moov box".to_string())
+        );
+    }
+
+    #[test]
+    fn ts_sync_bytes() {
+        let mut data = vec![0u8; (TS_PACKET_SIZE * 4) as usize];
+        for i in 0..4 {
+            data[i * TS_PACKET_SIZE as usize] = TS_SYNC_BYTE;
+        }
+        let len = data.len() as u64;
+        assert_eq!(
+            verify_ts(&mut Cursor::new(data.clone()), len).unwrap(),
+            MediaCheck::Ok
+        );
+
+        data[2 * TS_PACKET_SIZE as usize] = 0;
+        assert!(matches!(
+            verify_ts(&mut Cursor::new(data), len).unwrap(),
+            MediaCheck::Invalid(_)
+        ));
+    }
+}
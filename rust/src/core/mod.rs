@@ -1,9 +1,19 @@
 // 核心模块
 
+pub mod byte_ranges;
 pub mod cache;
+pub mod cleanup_plan;
+pub mod filename_template;
+pub mod hls;
+pub mod library_use;
+pub mod media_verify;
 pub mod network;
 pub mod otlp;
 pub mod parser;
 pub mod runtime;
 pub mod storage;
+pub mod stream_server;
+pub mod subtitle;
+pub mod throttle;
+pub mod watch_next;
 pub mod webdav;
@@ -367,6 +367,18 @@ fn apply_default_headers(builder: RequestBuilder, active: &ActiveDomain) -> Resu
     Ok(builder.headers(headers))
 }
 
+/// 构造媒体请求（视频、播放列表、分片）：带 UA、Referer 与 Cookie，不带页面导航相关请求头
+pub fn media_request(url: &str) -> Result<RequestBuilder> {
+    let active = get_active_domain();
+    ensure_cookies_loaded(active.cookie_domain());
+    let user_agent = get_or_init_user_agent()?;
+    Ok(get_client()
+        .get(url)
+        .header(USER_AGENT, user_agent)
+        .header(REFERER, active.referer())
+        .header("origin", active.base_url()))
+}
+
 /// 获取 Cookie Jar
 pub fn get_cookie_jar() -> Arc<Jar> {
     COOKIE_JAR.get_or_init(|| Arc::new(Jar::default())).clone()
@@ -21,6 +21,18 @@ static VIDEO_CODE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#"watch\?v=(\d+)
 
 static DIGITS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\d+"#).unwrap());
 
+/// 标题中的集数：`第3話` / `第三集` / `EP3` / `#3` / 结尾的数字
+static EPISODE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)第\s*([0-9一二三四五六七八九十百]+)\s*[話话集卷章部]|(?:\bep|\bepisode|\bvol)\.?\s*(\d{1,3})|#\s*(\d{1,3})|(?:^|[^\d])(\d{1,3})\s*$"#,
+    )
+    .unwrap()
+});
+
+/// 标题末尾的括号内容（字幕组、画质等标注）
+static TRAILING_BRACKET_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\s*(\[[^\]]*\]|【[^】]*】|\([^)]*\)|（[^）]*）)\s*$"#).unwrap());
+
 // ============================================================================
 // 内部数据结构
 // ============================================================================
@@ -65,6 +77,7 @@ pub(crate) struct VideoDetail {
     pub like_percent: Option<u32>,
     pub upload_date: String,
     pub video_sources: Vec<VideoSource>,
+    pub subtitles: Vec<SubtitleTrack>,
     pub related_videos: Vec<VideoCard>,
     pub creator: Option<Creator>,
     pub form_token: Option<String>,
@@ -72,6 +85,8 @@ pub(crate) struct VideoDetail {
     pub is_fav: bool,
     pub fav_times: Option<i32>,
     pub playlist: Option<Playlist>,
+    /// 当前视频所属系列（播放列表中包含当前视频时）
+    pub series: Option<Series>,
     pub my_list: Option<MyListInfo>,
 }
 
@@ -94,6 +109,17 @@ pub(crate) struct VideoSource {
     pub format: String,
 }
 
+/// 字幕轨道（内部使用）
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SubtitleTrack {
+    /// 语言代码（`srclang`），未标注时为空
+    pub language: String,
+    pub label: String,
+    pub url: String,
+    /// `vtt` | `srt` | `ass`
+    pub format: String,
+}
+
 /// 创作者信息（内部使用）
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -109,9 +135,32 @@ pub(crate) struct Creator {
 #[derive(Debug, Clone)]
 pub(crate) struct Playlist {
     pub name: Option<String>,
+    /// 播放列表 ID（`/playlist?list=...`），页面没有链接时为空
+    pub list_id: Option<String>,
     pub videos: Vec<VideoCard>,
 }
 
+/// 系列信息（由观看页播放列表识别）
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Series {
+    /// 播放列表 ID，没有时使用第一集的视频 ID
+    pub id: String,
+    pub title: String,
+    /// 按集数排序
+    pub episodes: Vec<SeriesEpisode>,
+    /// 当前视频在 `episodes` 中的位置
+    pub current_index: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SeriesEpisode {
+    pub id: String,
+    pub title: String,
+    pub cover_url: String,
+    /// 集数（从标题识别，无法识别时为列表中的顺序）
+    pub number: u32,
+}
+
 /// 我的列表信息
 #[derive(Debug, Clone)]
 pub(crate) struct MyListInfo {
@@ -467,6 +516,7 @@ pub(crate) fn parse_video_detail(html: &str) -> Result<VideoDetail> {
 
     // 视频源
     let video_sources = parse_video_sources(&document, html);
+    let subtitles = parse_subtitle_tracks(&document);
 
     // 创作者
     let creator = parse_creator(&document);
@@ -489,6 +539,10 @@ pub(crate) fn parse_video_detail(html: &str) -> Result<VideoDetail> {
         .and_then(|url| extract_video_code(url))
         .unwrap_or_default();
 
+    let series = playlist
+        .as_ref()
+        .and_then(|playlist| series_from_playlist(playlist, &id));
+
     Ok(VideoDetail {
         id,
         title,
@@ -503,6 +557,7 @@ pub(crate) fn parse_video_detail(html: &str) -> Result<VideoDetail> {
         like_percent,
         upload_date,
         video_sources,
+        subtitles,
         related_videos,
         creator,
         form_token,
@@ -510,6 +565,7 @@ pub(crate) fn parse_video_detail(html: &str) -> Result<VideoDetail> {
         is_fav,
         fav_times,
         playlist,
+        series,
         my_list,
     })
 }
@@ -833,6 +889,61 @@ fn parse_video_sources(document: &Html, html: &str) -> Vec<VideoSource> {
     sources
 }
 
+/// 解析播放器的字幕轨道（`<track kind="subtitles|captions">`）
+fn parse_subtitle_tracks(document: &Html) -> Vec<SubtitleTrack> {
+    let track_selector = Selector::parse("video#player track").unwrap();
+    let mut tracks: Vec<SubtitleTrack> = Vec::new();
+    for track in document.select(&track_selector) {
+        let kind = track.value().attr("kind").unwrap_or("subtitles");
+        if !kind.eq_ignore_ascii_case("subtitles") && !kind.eq_ignore_ascii_case("captions") {
+            continue;
+        }
+        let src = track.value().attr("src").unwrap_or("").trim();
+        if src.is_empty() {
+            continue;
+        }
+        let url = make_absolute_url(src);
+        if tracks.iter().any(|t| t.url == url) {
+            continue;
+        }
+        let language = track
+            .value()
+            .attr("srclang")
+            .unwrap_or("")
+            .trim()
+            .to_string();
+        let label = track
+            .value()
+            .attr("label")
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| language.clone());
+        tracks.push(SubtitleTrack {
+            language,
+            label,
+            format: subtitle_format(&url).to_string(),
+            url,
+        });
+    }
+    tracks
+}
+
+/// 根据地址扩展名判断字幕格式，无法判断时按 WebVTT 处理（`<track>` 的标准格式）
+fn subtitle_format(url: &str) -> &'static str {
+    let path = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    if path.ends_with(".srt") {
+        "srt"
+    } else if path.ends_with(".ass") || path.ends_with(".ssa") {
+        "ass"
+    } else {
+        "vtt"
+    }
+}
+
 /// 解析创作者信息
 fn parse_creator(document: &Html) -> Option<Creator> {
     let name_selector = Selector::parse("#video-artist-name").unwrap();
@@ -1013,7 +1124,134 @@ fn parse_playlist(document: &Html) -> Option<Playlist> {
         }
     }
 
-    Some(Playlist { name, videos })
+    let list_selector = Selector::parse("a[href*='list=']").unwrap();
+    let list_id = wrapper
+        .select(&list_selector)
+        .filter_map(|a| a.value().attr("href"))
+        .find_map(|href| {
+            let (_, query) = href.split_once('?')?;
+            query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("list="))
+                .map(|id| id.to_string())
+        })
+        .filter(|id| !id.is_empty());
+
+    Some(Playlist {
+        name,
+        list_id,
+        videos,
+    })
+}
+
+/// 从播放列表识别系列：列表中包含当前视频时视为系列
+///
+/// 所有集数都能从标题识别且互不相同时按集数排序，否则保持页面顺序并按顺序编号
+pub(crate) fn series_from_playlist(playlist: &Playlist, current_id: &str) -> Option<Series> {
+    let title = playlist
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())?;
+    if !playlist.videos.iter().any(|v| v.id == current_id) {
+        return None;
+    }
+    let derived = playlist
+        .videos
+        .iter()
+        .map(|v| episode_number(&v.title))
+        .collect::<Vec<_>>();
+    let numbers = derived
+        .iter()
+        .flatten()
+        .collect::<std::collections::HashSet<_>>();
+    let numbered = numbers.len() == derived.len();
+
+    let mut episodes = playlist
+        .videos
+        .iter()
+        .zip(&derived)
+        .enumerate()
+        .map(|(index, (video, number))| SeriesEpisode {
+            id: video.id.clone(),
+            title: video.title.clone(),
+            cover_url: video.cover_url.clone(),
+            number: if numbered {
+                number.unwrap_or_default()
+            } else {
+                index as u32 + 1
+            },
+        })
+        .collect::<Vec<_>>();
+    episodes.sort_by_key(|e| e.number);
+    let current_index = episodes.iter().position(|e| e.id == current_id)?;
+    Some(Series {
+        id: playlist
+            .list_id
+            .clone()
+            .unwrap_or_else(|| episodes[0].id.clone()),
+        title: title.to_string(),
+        episodes,
+        current_index,
+    })
+}
+
+/// 从标题识别集数
+pub(crate) fn episode_number(title: &str) -> Option<u32> {
+    // 全角数字转半角
+    let mut title = title
+        .chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap_or(c),
+            _ => c,
+        })
+        .collect::<String>();
+    while let Some(m) = TRAILING_BRACKET_REGEX.find(&title) {
+        if m.start() == 0 {
+            break;
+        }
+        title.truncate(m.start());
+    }
+    let caps = EPISODE_REGEX.captures(title.trim())?;
+    if let Some(value) = caps.get(1) {
+        return value
+            .as_str()
+            .parse()
+            .ok()
+            .or_else(|| chinese_number(value.as_str()));
+    }
+    caps.iter()
+        .skip(2)
+        .flatten()
+        .next()
+        .and_then(|m| m.as_str().parse().ok())
+        .filter(|n| *n > 0)
+}
+
+/// 中文数字（一 ~ 九百九十九）
+fn chinese_number(value: &str) -> Option<u32> {
+    let digit = |c: char| {
+        "一二三四五六七八九"
+            .chars()
+            .position(|d| d == c)
+            .map(|i| i as u32 + 1)
+    };
+    let mut total = 0;
+    let mut current = 0;
+    for c in value.chars() {
+        match c {
+            '百' => {
+                total += current.max(1) * 100;
+                current = 0;
+            }
+            '十' => {
+                total += current.max(1) * 10;
+                current = 0;
+            }
+            _ => current = digit(c)?,
+        }
+    }
+    Some(total + current).filter(|n| *n > 0)
 }
 
 /// 解析我的列表信息
@@ -1521,6 +1759,107 @@ mod tests {
         assert_eq!(sources[0].format, "m3u8");
     }
 
+    #[test]
+    fn parse_subtitle_tracks_from_video_tag() {
+        let html = r#"
+                <html>
+                    <body>
+                        <video id="player">
+                            <source size="720" type="video/mp4" src="https://cdn.example.com/video720.mp4" />
+                            <track kind="subtitles" srclang="zh-Hant" label="繁體中文" src="https://cdn.example.com/sub/zh.vtt?t=1" />
+                            <track kind="captions" srclang="en" src="https://cdn.example.com/sub/en.srt" />
+                            <track kind="chapters" srclang="en" src="https://cdn.example.com/sub/chapters.vtt" />
+                            <track kind="subtitles" srclang="ja" label="日本語" src="" />
+                        </video>
+                    </body>
+                </html>
+                "#;
+
+        let doc = Html::parse_document(html);
+        let tracks = parse_subtitle_tracks(&doc);
+        assert_eq!(
+            tracks,
+            vec![
+                SubtitleTrack {
+                    language: "zh-Hant".to_string(),
+                    label: "繁體中文".to_string(),
+                    url: "https://cdn.example.com/sub/zh.vtt?t=1".to_string(),
+                    format: "vtt".to_string(),
+                },
+                SubtitleTrack {
+                    language: "en".to_string(),
+                    label: "en".to_string(),
+                    url: "https://cdn.example.com/sub/en.srt".to_string(),
+                    format: "srt".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn episode_number_from_title() {
+        assert_eq!(episode_number("某某物語 第3話"), Some(3));
+        assert_eq!(episode_number("某某物語 第十二集 [中文字幕]"), Some(12));
+        assert_eq!(episode_number("Some Story EP.2"), Some(2));
+        assert_eq!(episode_number("Some Story episode 10"), Some(10));
+        assert_eq!(episode_number("某某物語 ＃４"), Some(4));
+        assert_eq!(episode_number("某某物語 #4"), Some(4));
+        assert_eq!(episode_number("某某物語２ 【中文字幕】"), Some(2));
+        // 年份等长数字不视为集数
+        assert_eq!(episode_number("某某物語 2023"), None);
+        assert_eq!(episode_number("某某物語 前編"), None);
+    }
+
+    #[test]
+    fn series_from_playlist_orders_episodes() {
+        let card = |id: &str, title: &str| VideoCard {
+            id: id.to_string(),
+            title: title.to_string(),
+            cover_url: format!("https://cdn.example.com/{id}.jpg"),
+            duration: String::new(),
+            views: String::new(),
+            artist: None,
+            upload_time: None,
+            tags: vec![],
+            upload_date: None,
+        };
+        let playlist = Playlist {
+            name: Some(" 某某物語 ".to_string()),
+            list_id: None,
+            videos: vec![
+                card("300", "某某物語 第3話"),
+                card("100", "某某物語 第1話"),
+                card("200", "某某物語 第2話"),
+            ],
+        };
+        let series = series_from_playlist(&playlist, "200").unwrap();
+        assert_eq!(series.id, "100");
+        assert_eq!(series.title, "某某物語");
+        assert_eq!(
+            series
+                .episodes
+                .iter()
+                .map(|e| (e.id.as_str(), e.number))
+                .collect::<Vec<_>>(),
+            vec![("100", 1), ("200", 2), ("300", 3)]
+        );
+        assert_eq!(series.current_index, 1);
+
+        // 集数无法全部识别时保持页面顺序
+        let playlist = Playlist {
+            list_id: Some("abc".to_string()),
+            videos: vec![card("1", "前編"), card("2", "後編")],
+            ..playlist
+        };
+        let series = series_from_playlist(&playlist, "2").unwrap();
+        assert_eq!(series.id, "abc");
+        assert_eq!(series.episodes[1].number, 2);
+        assert_eq!(series.current_index, 1);
+
+        // 不包含当前视频的列表不是系列
+        assert!(series_from_playlist(&playlist, "9").is_none());
+    }
+
     #[test]
     fn parse_video_detail_basic() {
         let html = r#"
@@ -2,7 +2,10 @@
 
 use anyhow::Result;
 use refinery::embed_migrations;
-use rusqlite::{params, Connection};
+use rusqlite::types::Value;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use std::sync::OnceLock;
@@ -46,6 +49,20 @@ pub fn get_data_dir() -> Result<PathBuf> {
         .ok_or_else(|| anyhow::anyhow!("Data dir not initialized"))
 }
 
+/// 下载根目录设置（为空时使用数据目录）
+pub(crate) const DOWNLOADS_ROOT_KEY: &str = "downloads_root";
+
+/// 获取下载根目录（其下为 downloads / download_covers / download_avatars / download_subtitles）
+pub fn get_downloads_root() -> Result<PathBuf> {
+    match get_setting(DOWNLOADS_ROOT_KEY)?
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+    {
+        Some(root) => Ok(PathBuf::from(root)),
+        None => get_data_dir(),
+    }
+}
+
 /// 获取数据库连接
 pub fn get_db() -> Result<std::sync::MutexGuard<'static, Connection>> {
     DB.get()
@@ -200,6 +217,33 @@ pub(crate) fn get_history_count() -> Result<i64> {
     Ok(count)
 }
 
+/// 观看状态：video_id → (观看进度, 总时长, 最后观看时间)（不包含已删除的）
+pub(crate) fn get_history_watch_states() -> Result<HashMap<String, (i64, i64, i64)>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(
+        "SELECT video_id, watch_progress, total_duration, watched_at FROM history
+         WHERE deleted_at IS NULL OR deleted_at < watched_at",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            (
+                row.get::<_, Option<i64>>(1)?.unwrap_or(0),
+                row.get::<_, Option<i64>>(2)?.unwrap_or(0),
+                row.get::<_, i64>(3)?,
+            ),
+        ))
+    })?;
+
+    let mut result = HashMap::new();
+    for row in rows {
+        let (video_id, state) = row?;
+        result.insert(video_id, state);
+    }
+
+    Ok(result)
+}
+
 /// 获取单条历史记录（不包含已删除的）
 pub(crate) fn get_history_by_video_id(video_id: &str) -> Result<Option<HistoryRecord>> {
     let db = get_db()?;
@@ -349,6 +393,25 @@ pub(crate) enum DownloadStatus {
     Paused = 2,
     Completed = 3,
     Failed = 4,
+    /// 下载结束但完整性校验未通过
+    Corrupt = 5,
+    /// 已完成但本地文件缺失（例如用户清理了存储）
+    Missing = 6,
+}
+
+impl DownloadStatus {
+    /// 与 API 状态名保持一致（用于事件日志）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DownloadStatus::Queued => "pending",
+            DownloadStatus::Downloading => "downloading",
+            DownloadStatus::Paused => "paused",
+            DownloadStatus::Completed => "completed",
+            DownloadStatus::Failed => "failed",
+            DownloadStatus::Corrupt => "corrupt",
+            DownloadStatus::Missing => "missing",
+        }
+    }
 }
 
 impl From<i32> for DownloadStatus {
@@ -359,6 +422,8 @@ impl From<i32> for DownloadStatus {
             2 => DownloadStatus::Paused,
             3 => DownloadStatus::Completed,
             4 => DownloadStatus::Failed,
+            5 => DownloadStatus::Corrupt,
+            6 => DownloadStatus::Missing,
             _ => DownloadStatus::Queued,
         }
     }
@@ -389,6 +454,74 @@ pub(crate) struct DownloadRecord {
     pub error_message: Option<String>,
     pub created_at: i64,
     pub completed_at: Option<i64>,
+    pub error_kind: Option<String>,
+    pub retry_count: i32,
+    pub max_retries: i32,
+    pub next_retry_at: Option<i64>,
+    pub source_url: Option<String>,
+    pub source_quality: Option<String>,
+    /// 文件已预分配到完整大小（续传时以 downloaded_bytes 为准）
+    pub preallocated: bool,
+    /// 上传日期（YYYY-MM-DD）
+    pub upload_date: Option<String>,
+    /// 所属系列（观看页播放列表名）及集数（从 1 开始）
+    pub series_name: Option<String>,
+    pub series_episode: Option<i32>,
+    pub chinese_title: Option<String>,
+    /// 任务单独设置的限速（KB/s，0 表示不限），为空时使用默认值
+    pub bandwidth_limit_kb: Option<u32>,
+}
+
+/// downloads 表查询列（与 `map_download_row` 的下标一一对应）
+const DOWNLOAD_COLUMNS: &str =
+    "id, video_id, title, cover_url, video_url, quality, description, tags, cover_path,
+        author_id, author_name, author_avatar_url, author_avatar_path, folder_id,
+        save_path, total_bytes, downloaded_bytes, status, error_message, created_at, completed_at,
+        error_kind, retry_count, max_retries, next_retry_at, source_url, source_quality,
+        preallocated, upload_date, series_name, series_episode,
+        chinese_title, bandwidth_limit_kb";
+
+fn map_download_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<DownloadRecord> {
+    let tags_json: Option<String> = row.get(7)?;
+    let tags = tags_json
+        .as_deref()
+        .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
+        .unwrap_or_default();
+    Ok(DownloadRecord {
+        id: row.get(0)?,
+        video_id: row.get(1)?,
+        title: row.get(2)?,
+        cover_url: row.get(3)?,
+        video_url: row.get(4)?,
+        quality: row.get(5)?,
+        description: row.get(6)?,
+        tags,
+        cover_path: row.get(8)?,
+        author_id: row.get(9)?,
+        author_name: row.get(10)?,
+        author_avatar_url: row.get(11)?,
+        author_avatar_path: row.get(12)?,
+        folder_id: row.get(13)?,
+        save_path: row.get(14)?,
+        total_bytes: row.get(15)?,
+        downloaded_bytes: row.get(16)?,
+        status: DownloadStatus::from(row.get::<_, i32>(17)?),
+        error_message: row.get(18)?,
+        created_at: row.get(19)?,
+        completed_at: row.get(20)?,
+        error_kind: row.get(21)?,
+        retry_count: row.get(22)?,
+        max_retries: row.get(23)?,
+        next_retry_at: row.get(24)?,
+        source_url: row.get(25)?,
+        source_quality: row.get(26)?,
+        preallocated: row.get(27)?,
+        upload_date: row.get(28)?,
+        series_name: row.get(29)?,
+        series_episode: row.get(30)?,
+        chinese_title: row.get(31)?,
+        bandwidth_limit_kb: row.get(32)?,
+    })
 }
 
 /// 下载文件夹记录（内部使用）
@@ -398,6 +531,20 @@ pub(crate) struct DownloadFolderRecord {
     pub id: String,
     pub name: String,
     pub created_at: i64,
+    /// 上级文件夹，顶层为空
+    pub parent_id: Option<String>,
+    /// 同级文件夹中的排序
+    pub sort_order: i64,
+    /// 作为文件夹封面的下载
+    pub cover_video_id: Option<String>,
+    /// 智能文件夹规则（`DownloadFilter` JSON），普通文件夹为空
+    pub rule_json: Option<String>,
+}
+
+impl DownloadFolderRecord {
+    pub fn is_smart(&self) -> bool {
+        self.rule_json.is_some()
+    }
 }
 
 /// 添加下载任务
@@ -448,46 +595,90 @@ pub fn add_download(
     Ok(db.last_insert_rowid())
 }
 
+/// 写入导入的下载记录（包含完整元数据与状态，video_id 已存在时失败）
+pub(crate) fn insert_imported_download(record: &DownloadRecord) -> Result<()> {
+    let db = get_db()?;
+    insert_imported_on(&db, record)
+}
+
+/// 在单个事务内用导入的记录替换同一 video_id 的下载（连同变体、事件、字幕记录）
+pub(crate) fn replace_imported_download(record: &DownloadRecord) -> Result<()> {
+    let mut db = get_db()?;
+    let tx = db.transaction()?;
+    for table in [
+        "downloads",
+        "download_variants",
+        "download_events",
+        "download_subtitles",
+        "download_hls_progress",
+        "download_variant_hls_progress",
+        "download_ranges",
+    ] {
+        tx.execute(
+            &format!("DELETE FROM {} WHERE video_id = ?1", table),
+            params![record.video_id],
+        )?;
+    }
+    insert_imported_on(&tx, record)?;
+    tx.commit()?;
+    Ok(())
+}
+
+fn insert_imported_on(db: &Connection, record: &DownloadRecord) -> Result<()> {
+    let tags_json = serde_json::to_string(&record.tags).unwrap_or_else(|_| "[]".to_string());
+    db.execute(
+        r#"
+        INSERT INTO downloads (
+            video_id, title, cover_url, video_url, quality, description, tags, cover_path,
+            author_id, author_name, author_avatar_url, author_avatar_path, folder_id,
+            save_path, total_bytes, downloaded_bytes, status, created_at, completed_at,
+            source_url, source_quality, upload_date, series_name, series_episode, chinese_title
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
+        "#,
+        params![
+            record.video_id,
+            record.title,
+            record.cover_url,
+            record.video_url,
+            record.quality,
+            record.description,
+            tags_json,
+            record.cover_path,
+            record.author_id,
+            record.author_name,
+            record.author_avatar_url,
+            record.author_avatar_path,
+            record.folder_id,
+            record.save_path,
+            record.total_bytes,
+            record.downloaded_bytes,
+            record.status as i32,
+            record.created_at,
+            record.completed_at,
+            record.source_url,
+            record.source_quality,
+            record.upload_date,
+            record.series_name,
+            record.series_episode,
+            record.chinese_title
+        ],
+    )?;
+    Ok(())
+}
+
 /// 获取单个下载任务（按 video_id）
 pub(crate) fn get_download_by_video_id(video_id: &str) -> Result<Option<DownloadRecord>> {
     let db = get_db()?;
-    let mut stmt = db.prepare(
-        "SELECT id, video_id, title, cover_url, video_url, quality, description, tags, cover_path,
-                author_id, author_name, author_avatar_url, author_avatar_path, folder_id,
-                save_path, total_bytes, downloaded_bytes, status, error_message, created_at, completed_at
-         FROM downloads WHERE video_id = ?1 LIMIT 1"
-    )?;
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM downloads WHERE video_id = ?1 LIMIT 1",
+        DOWNLOAD_COLUMNS
+    ))?;
 
     let mut rows = stmt.query(params![video_id])?;
     if let Some(row) = rows.next()? {
-        let tags_json: Option<String> = row.get(7)?;
-        let tags = tags_json
-            .as_deref()
-            .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
-            .unwrap_or_default();
-        Ok(Some(DownloadRecord {
-            id: row.get(0)?,
-            video_id: row.get(1)?,
-            title: row.get(2)?,
-            cover_url: row.get(3)?,
-            video_url: row.get(4)?,
-            quality: row.get(5)?,
-            description: row.get(6)?,
-            tags,
-            cover_path: row.get(8)?,
-            author_id: row.get(9)?,
-            author_name: row.get(10)?,
-            author_avatar_url: row.get(11)?,
-            author_avatar_path: row.get(12)?,
-            folder_id: row.get(13)?,
-            save_path: row.get(14)?,
-            total_bytes: row.get(15)?,
-            downloaded_bytes: row.get(16)?,
-            status: DownloadStatus::from(row.get::<_, i32>(17)?),
-            error_message: row.get(18)?,
-            created_at: row.get(19)?,
-            completed_at: row.get(20)?,
-        }))
+        Ok(Some(map_download_row(row)?))
     } else {
         Ok(None)
     }
@@ -503,6 +694,88 @@ pub fn update_download_save_path(video_id: &str, save_path: &str) -> Result<()>
     Ok(())
 }
 
+/// 重新关联本地文件并标记为已完成（大小以实际文件为准）
+pub fn relink_download_file(video_id: &str, save_path: &str, size: i64) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        r#"
+        UPDATE downloads
+        SET save_path = ?1, downloaded_bytes = ?2, total_bytes = ?2, preallocated = 0,
+            status = ?3, error_message = NULL, error_kind = NULL, next_retry_at = NULL,
+            completed_at = COALESCE(completed_at, ?4)
+        WHERE video_id = ?5
+        "#,
+        params![
+            save_path,
+            size,
+            DownloadStatus::Completed as i32,
+            chrono::Utc::now().timestamp(),
+            video_id
+        ],
+    )?;
+    Ok(())
+}
+
+/// 更新当前使用的媒体地址（签名地址会过期，续传/刷新时覆盖）
+pub fn update_download_source(
+    video_id: &str,
+    source_url: &str,
+    source_quality: &str,
+) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET source_url = ?1, source_quality = ?2 WHERE video_id = ?3",
+        params![source_url, source_quality, video_id],
+    )?;
+    Ok(())
+}
+
+/// 更新导出用元数据（上传日期、所属系列、中文标题）
+pub fn update_download_export_meta(
+    video_id: &str,
+    upload_date: Option<&str>,
+    series_name: Option<&str>,
+    series_episode: Option<i32>,
+    chinese_title: Option<&str>,
+) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        r#"
+        UPDATE downloads
+        SET upload_date = ?1, series_name = ?2, series_episode = ?3, chinese_title = ?4
+        WHERE video_id = ?5
+        "#,
+        params![
+            upload_date,
+            series_name,
+            series_episode,
+            chinese_title,
+            video_id
+        ],
+    )?;
+    Ok(())
+}
+
+/// 标记目标文件是否已预分配
+pub fn update_download_preallocated(video_id: &str, preallocated: bool) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET preallocated = ?1 WHERE video_id = ?2",
+        params![preallocated, video_id],
+    )?;
+    Ok(())
+}
+
+/// 更新任务单独设置的限速（KB/s），为空时使用默认值
+pub fn update_download_bandwidth_limit(video_id: &str, limit_kb: Option<u32>) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET bandwidth_limit_kb = ?1 WHERE video_id = ?2",
+        params![limit_kb, video_id],
+    )?;
+    Ok(())
+}
+
 /// 更新下载封面本地路径
 pub fn update_download_cover_path(video_id: &str, cover_path: &str) -> Result<()> {
     let db = get_db()?;
@@ -545,17 +818,103 @@ pub(crate) fn update_download_status(
     status: DownloadStatus,
     error: Option<&str>,
 ) -> Result<()> {
-    let db = get_db()?;
+    let mut db = get_db()?;
     let completed_at = if status == DownloadStatus::Completed {
         Some(chrono::Utc::now().timestamp())
     } else {
         None
     };
 
-    db.execute(
-        "UPDATE downloads SET status = ?1, error_message = ?2, completed_at = ?3 WHERE video_id = ?4",
+    let tx = db.transaction()?;
+    let previous = tx
+        .query_row(
+            "SELECT status FROM downloads WHERE video_id = ?1",
+            params![video_id],
+            |row| row.get::<_, i32>(0),
+        )
+        .optional()?;
+    tx.execute(
+        "UPDATE downloads SET status = ?1, error_message = ?2, completed_at = ?3, next_retry_at = NULL
+         WHERE video_id = ?4",
         params![status as i32, error, completed_at, video_id],
     )?;
+    // 仅在状态实际变化时记录事件
+    if previous.is_some_and(|p| DownloadStatus::from(p) != status) {
+        insert_download_event_on(
+            &tx,
+            &DownloadEventRecord {
+                status: Some(status.as_str().to_string()),
+                message: error.map(str::to_string),
+                ..DownloadEventRecord::new(video_id, "status")
+            },
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// 安排自动重试：状态回到排队，记录失败分类与下一次重试时间
+pub fn schedule_download_retry(
+    video_id: &str,
+    retry_count: i32,
+    error_kind: &str,
+    error: &str,
+    next_retry_at: i64,
+) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET status = ?1, error_message = ?2, error_kind = ?3, retry_count = ?4, next_retry_at = ?5
+         WHERE video_id = ?6",
+        params![
+            DownloadStatus::Queued as i32,
+            error,
+            error_kind,
+            retry_count,
+            next_retry_at,
+            video_id
+        ],
+    )?;
+    Ok(())
+}
+
+/// 更新下载失败分类
+pub fn update_download_error_kind(video_id: &str, error_kind: Option<&str>) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET error_kind = ?1 WHERE video_id = ?2",
+        params![error_kind, video_id],
+    )?;
+    Ok(())
+}
+
+/// 清除重试状态（手动继续或下载成功后调用）
+pub fn reset_download_retry(video_id: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET retry_count = 0, error_kind = NULL, next_retry_at = NULL WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    Ok(())
+}
+
+/// 清空下载进度（损坏文件重新下载前使用）
+pub fn reset_download_progress(video_id: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET downloaded_bytes = 0, total_bytes = 0, error_message = NULL, preallocated = 0
+         WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    Ok(())
+}
+
+/// 更新单个任务的最大自动重试次数
+pub fn update_download_max_retries(video_id: &str, max_retries: i32) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE downloads SET max_retries = ?1 WHERE video_id = ?2",
+        params![max_retries, video_id],
+    )?;
     Ok(())
 }
 
@@ -576,43 +935,12 @@ pub fn update_download_description_and_tags(
 /// 获取下载列表
 pub(crate) fn get_downloads() -> Result<Vec<DownloadRecord>> {
     let db = get_db()?;
-    let mut stmt = db.prepare(
-        "SELECT id, video_id, title, cover_url, video_url, quality, description, tags, cover_path,
-        author_id, author_name, author_avatar_url, author_avatar_path, folder_id,
-        save_path, total_bytes, downloaded_bytes, status, error_message, created_at, completed_at
-         FROM downloads ORDER BY created_at DESC",
-    )?;
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM downloads ORDER BY created_at DESC",
+        DOWNLOAD_COLUMNS
+    ))?;
 
-    let records = stmt.query_map([], |row| {
-        let tags_json: Option<String> = row.get(7)?;
-        let tags = tags_json
-            .as_deref()
-            .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
-            .unwrap_or_default();
-        Ok(DownloadRecord {
-            id: row.get(0)?,
-            video_id: row.get(1)?,
-            title: row.get(2)?,
-            cover_url: row.get(3)?,
-            video_url: row.get(4)?,
-            quality: row.get(5)?,
-            description: row.get(6)?,
-            tags,
-            cover_path: row.get(8)?,
-            author_id: row.get(9)?,
-            author_name: row.get(10)?,
-            author_avatar_url: row.get(11)?,
-            author_avatar_path: row.get(12)?,
-            folder_id: row.get(13)?,
-            save_path: row.get(14)?,
-            total_bytes: row.get(15)?,
-            downloaded_bytes: row.get(16)?,
-            status: DownloadStatus::from(row.get::<_, i32>(17)?),
-            error_message: row.get(18)?,
-            created_at: row.get(19)?,
-            completed_at: row.get(20)?,
-        })
-    })?;
+    let records = stmt.query_map([], map_download_row)?;
 
     let mut result = Vec::new();
     for record in records {
@@ -632,6 +960,13 @@ pub fn reset_running_downloads() -> Result<()> {
             DownloadStatus::Downloading as i32
         ],
     )?;
+    db.execute(
+        "UPDATE download_variants SET status = ?1 WHERE status = ?2",
+        params![
+            DownloadStatus::Queued as i32,
+            DownloadStatus::Downloading as i32
+        ],
+    )?;
     Ok(())
 }
 
@@ -642,6 +977,30 @@ pub fn delete_download(video_id: &str) -> Result<()> {
         "DELETE FROM downloads WHERE video_id = ?1",
         params![video_id],
     )?;
+    db.execute(
+        "DELETE FROM download_variants WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    db.execute(
+        "DELETE FROM download_events WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    db.execute(
+        "DELETE FROM download_subtitles WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    db.execute(
+        "DELETE FROM download_hls_progress WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    db.execute(
+        "DELETE FROM download_variant_hls_progress WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    db.execute(
+        "DELETE FROM download_ranges WHERE video_id = ?1",
+        params![video_id],
+    )?;
     Ok(())
 }
 
@@ -667,32 +1026,103 @@ pub fn update_downloads_folder(video_ids: &[String], folder_id: Option<&str>) ->
     Ok(())
 }
 
-// ========== 下载文件夹 ==========
+// ========== 下载画质变体 ==========
+
+/// 下载画质变体记录（内部使用）
+#[derive(Debug, Clone)]
+pub(crate) struct DownloadVariantRecord {
+    pub id: i64,
+    pub video_id: String,
+    /// 请求的画质（变体必须精确匹配该画质）
+    pub quality: String,
+    pub source_quality: Option<String>,
+    pub source_url: Option<String>,
+    pub save_path: Option<String>,
+    pub total_bytes: i64,
+    pub downloaded_bytes: i64,
+    pub status: DownloadStatus,
+    pub error_message: Option<String>,
+    /// 完成后替换主下载（画质升级）
+    pub replace_primary: bool,
+    /// 替换时保留原主下载为变体
+    pub keep_previous: bool,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+const VARIANT_COLUMNS: &str = "id, video_id, quality, source_quality, source_url, save_path,
+        total_bytes, downloaded_bytes, status, error_message, replace_primary, keep_previous,
+        created_at, completed_at";
+
+fn map_variant_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<DownloadVariantRecord> {
+    Ok(DownloadVariantRecord {
+        id: row.get(0)?,
+        video_id: row.get(1)?,
+        quality: row.get(2)?,
+        source_quality: row.get(3)?,
+        source_url: row.get(4)?,
+        save_path: row.get(5)?,
+        total_bytes: row.get::<_, Option<i64>>(6)?.unwrap_or(0),
+        downloaded_bytes: row.get::<_, Option<i64>>(7)?.unwrap_or(0),
+        status: DownloadStatus::from(row.get::<_, Option<i32>>(8)?.unwrap_or(0)),
+        error_message: row.get(9)?,
+        replace_primary: row.get(10)?,
+        keep_previous: row.get(11)?,
+        created_at: row.get(12)?,
+        completed_at: row.get(13)?,
+    })
+}
 
-/// 创建下载文件夹
-pub fn create_download_folder(id: &str, name: &str) -> Result<()> {
+/// 新增画质变体（排队状态），返回变体 ID
+pub(crate) fn create_download_variant(
+    video_id: &str,
+    quality: &str,
+    replace_primary: bool,
+    keep_previous: bool,
+) -> Result<i64> {
     let db = get_db()?;
     let now = chrono::Utc::now().timestamp();
     db.execute(
-        "INSERT INTO download_folders (id, name, created_at) VALUES (?1, ?2, ?3)",
-        params![id, name, now],
+        r#"
+        INSERT INTO download_variants (
+            video_id, quality, status, replace_primary, keep_previous, created_at
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "#,
+        params![
+            video_id,
+            quality,
+            DownloadStatus::Queued as i32,
+            replace_primary,
+            keep_previous,
+            now
+        ],
     )?;
-    Ok(())
+    Ok(db.last_insert_rowid())
 }
 
-/// 获取所有下载文件夹
-pub(crate) fn get_download_folders() -> Result<Vec<DownloadFolderRecord>> {
+/// 获取单个画质变体
+pub(crate) fn get_download_variant(id: i64) -> Result<Option<DownloadVariantRecord>> {
     let db = get_db()?;
-    let mut stmt =
-        db.prepare("SELECT id, name, created_at FROM download_folders ORDER BY created_at ASC")?;
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM download_variants WHERE id = ?1",
+        VARIANT_COLUMNS
+    ))?;
+    let mut rows = stmt.query(params![id])?;
+    if let Some(row) = rows.next()? {
+        return Ok(Some(map_variant_row(row)?));
+    }
+    Ok(None)
+}
 
-    let records = stmt.query_map([], |row| {
-        Ok(DownloadFolderRecord {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            created_at: row.get(2)?,
-        })
-    })?;
+/// 获取画质变体（`video_id` 为空时返回全部）
+pub(crate) fn get_download_variants(video_id: Option<&str>) -> Result<Vec<DownloadVariantRecord>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM download_variants WHERE ?1 IS NULL OR video_id = ?1 ORDER BY created_at ASC",
+        VARIANT_COLUMNS
+    ))?;
+    let records = stmt.query_map(params![video_id], map_variant_row)?;
 
     let mut result = Vec::new();
     for record in records {
@@ -702,66 +1132,1245 @@ pub(crate) fn get_download_folders() -> Result<Vec<DownloadFolderRecord>> {
     Ok(result)
 }
 
-/// 更新下载文件夹名称
-pub fn update_download_folder_name(id: &str, name: &str) -> Result<()> {
+/// 记录变体实际选中的地址与画质
+pub fn update_download_variant_source(
+    id: i64,
+    source_url: &str,
+    source_quality: &str,
+) -> Result<()> {
     let db = get_db()?;
     db.execute(
-        "UPDATE download_folders SET name = ?1 WHERE id = ?2",
-        params![name, id],
+        "UPDATE download_variants SET source_url = ?1, source_quality = ?2 WHERE id = ?3",
+        params![source_url, source_quality, id],
     )?;
     Ok(())
 }
 
-/// 删除下载文件夹（不影响视频，仅清除视频的 folder_id）
-pub fn delete_download_folder(id: &str) -> Result<()> {
+/// 更新变体保存路径（仅文件名）
+pub fn update_download_variant_save_path(id: i64, save_path: &str) -> Result<()> {
     let db = get_db()?;
-    // 先清除所有关联视频的 folder_id
     db.execute(
-        "UPDATE downloads SET folder_id = NULL WHERE folder_id = ?1",
-        params![id],
+        "UPDATE download_variants SET save_path = ?1 WHERE id = ?2",
+        params![save_path, id],
     )?;
-    // 再删除文件夹
-    db.execute("DELETE FROM download_folders WHERE id = ?1", params![id])?;
     Ok(())
 }
 
-// ========== 设置 ==========
-
-/// 保存设置
-pub fn save_setting(key: &str, value: &str) -> Result<()> {
+/// 更新变体下载进度
+pub fn update_download_variant_progress(id: i64, downloaded: i64, total: i64) -> Result<()> {
     let db = get_db()?;
     db.execute(
-        "INSERT OR REPLACE INTO settings (key, value) VALUES (?1, ?2)",
-        params![key, value],
+        "UPDATE download_variants SET downloaded_bytes = ?1, total_bytes = ?2 WHERE id = ?3",
+        params![downloaded, total, id],
     )?;
     Ok(())
 }
 
-/// 获取设置
-pub fn get_setting(key: &str) -> Result<Option<String>> {
+/// 更新变体状态
+pub(crate) fn update_download_variant_status(
+    id: i64,
+    status: DownloadStatus,
+    error: Option<&str>,
+) -> Result<()> {
     let db = get_db()?;
-    let mut stmt = db.prepare("SELECT value FROM settings WHERE key = ?1")?;
-    let mut rows = stmt.query(params![key])?;
-
-    if let Some(row) = rows.next()? {
-        Ok(Some(row.get(0)?))
+    let completed_at = if status == DownloadStatus::Completed {
+        Some(chrono::Utc::now().timestamp())
     } else {
-        Ok(None)
-    }
+        None
+    };
+    db.execute(
+        "UPDATE download_variants SET status = ?1, error_message = ?2, completed_at = ?3 WHERE id = ?4",
+        params![status as i32, error, completed_at, id],
+    )?;
+    Ok(())
 }
 
-/// 删除设置
-pub fn delete_setting(key: &str) -> Result<()> {
+/// 删除画质变体记录
+pub fn delete_download_variant(id: i64) -> Result<()> {
     let db = get_db()?;
-    db.execute("DELETE FROM settings WHERE key = ?1", params![key])?;
+    db.execute("DELETE FROM download_variants WHERE id = ?1", params![id])?;
+    db.execute(
+        "DELETE FROM download_variant_hls_progress WHERE variant_id = ?1",
+        params![id],
+    )?;
     Ok(())
 }
 
-// ========== Telemetry Queue (OTLP) ==========
+/// 将已完成的变体设为主下载（单个事务内交换）
+///
+/// - 文件夹、封面、作者等元数据保留在主记录上，观看记录按 video_id 关联，均不受影响
+/// - `keep_previous` 且原主下载已完成时，原文件转为该变体行；否则删除变体行
+///
+/// 返回需要删除的原主下载文件名（保留或不存在时为空）
+pub(crate) fn promote_download_variant(id: i64, keep_previous: bool) -> Result<Option<String>> {
+    let mut db = get_db()?;
+    promote_variant_on(&mut db, id, keep_previous)
+}
 
-#[derive(Debug, Clone)]
-pub(crate) struct TelemetryRecord {
-    pub id: i64,
+fn promote_variant_on(db: &mut Connection, id: i64, keep_previous: bool) -> Result<Option<String>> {
+    let tx = db.transaction()?;
+    let variant = tx.query_row(
+        &format!(
+            "SELECT {} FROM download_variants WHERE id = ?1",
+            VARIANT_COLUMNS
+        ),
+        params![id],
+        map_variant_row,
+    )?;
+    if variant.status != DownloadStatus::Completed {
+        anyhow::bail!("Variant is not completed");
+    }
+    let primary = tx.query_row(
+        &format!(
+            "SELECT {} FROM downloads WHERE video_id = ?1",
+            DOWNLOAD_COLUMNS
+        ),
+        params![variant.video_id],
+        map_download_row,
+    )?;
+
+    tx.execute(
+        r#"
+        UPDATE downloads
+        SET quality = ?1, source_quality = ?2, source_url = ?3, save_path = ?4,
+            total_bytes = ?5, downloaded_bytes = ?6, status = ?7, error_message = NULL,
+            error_kind = NULL, completed_at = ?8, next_retry_at = NULL, retry_count = 0,
+            preallocated = 0
+        WHERE video_id = ?9
+        "#,
+        params![
+            variant.quality,
+            variant.source_quality,
+            variant.source_url,
+            variant.save_path,
+            variant.total_bytes,
+            variant.downloaded_bytes,
+            DownloadStatus::Completed as i32,
+            variant.completed_at,
+            variant.video_id
+        ],
+    )?;
+
+    let keep =
+        keep_previous && primary.status == DownloadStatus::Completed && primary.save_path.is_some();
+    let replaced = if keep {
+        tx.execute(
+            r#"
+            UPDATE download_variants
+            SET quality = ?1, source_quality = ?2, source_url = ?3, save_path = ?4,
+                total_bytes = ?5, downloaded_bytes = ?6, status = ?7, error_message = NULL,
+                replace_primary = 0, keep_previous = 0, completed_at = ?8
+            WHERE id = ?9
+            "#,
+            params![
+                primary
+                    .source_quality
+                    .clone()
+                    .or_else(|| primary.quality.clone())
+                    .unwrap_or_default(),
+                primary.source_quality,
+                primary.source_url,
+                primary.save_path,
+                primary.total_bytes,
+                primary.downloaded_bytes,
+                DownloadStatus::Completed as i32,
+                primary.completed_at,
+                id
+            ],
+        )?;
+        None
+    } else {
+        tx.execute("DELETE FROM download_variants WHERE id = ?1", params![id])?;
+        primary.save_path
+    };
+    tx.commit()?;
+    Ok(replaced)
+}
+
+// ========== 下载事件日志 ==========
+
+/// 每个下载最多保留的事件数（超出时删除最旧的）
+const MAX_EVENTS_PER_DOWNLOAD: i64 = 300;
+
+/// 下载事件（状态变化、选中的源、Range 请求与 HTTP 状态、错误）
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct DownloadEventRecord {
+    pub id: i64,
+    pub video_id: String,
+    /// 事件类型，例如 status / source_picked / http_response / error
+    pub kind: String,
+    /// 状态变化后的状态（与 API 状态名一致）
+    pub status: Option<String>,
+    pub message: Option<String>,
+    /// 媒体地址的主机名（不记录带签名的完整地址）
+    pub source_host: Option<String>,
+    pub quality: Option<String>,
+    pub format: Option<String>,
+    /// 请求的字节范围
+    pub range_start: Option<i64>,
+    pub range_end: Option<i64>,
+    pub http_status: Option<i32>,
+    pub error_kind: Option<String>,
+    /// 毫秒时间戳
+    pub created_at: i64,
+}
+
+impl DownloadEventRecord {
+    pub fn new(video_id: &str, kind: &str) -> Self {
+        Self {
+            video_id: video_id.to_string(),
+            kind: kind.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+const EVENT_COLUMNS: &str = "id, video_id, kind, status, message, source_host, quality, format,
+        range_start, range_end, http_status, error_kind, created_at";
+
+fn map_event_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<DownloadEventRecord> {
+    Ok(DownloadEventRecord {
+        id: row.get(0)?,
+        video_id: row.get(1)?,
+        kind: row.get(2)?,
+        status: row.get(3)?,
+        message: row.get(4)?,
+        source_host: row.get(5)?,
+        quality: row.get(6)?,
+        format: row.get(7)?,
+        range_start: row.get(8)?,
+        range_end: row.get(9)?,
+        http_status: row.get(10)?,
+        error_kind: row.get(11)?,
+        created_at: row.get(12)?,
+    })
+}
+
+/// 记录下载事件（时间由数据库写入时生成）
+pub(crate) fn insert_download_event(event: &DownloadEventRecord) -> Result<()> {
+    let db = get_db()?;
+    insert_download_event_on(&db, event)
+}
+
+fn insert_download_event_on(db: &Connection, event: &DownloadEventRecord) -> Result<()> {
+    db.execute(
+        "INSERT INTO download_events (video_id, kind, status, message, source_host, quality, format,
+            range_start, range_end, http_status, error_kind, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            event.video_id,
+            event.kind,
+            event.status,
+            event.message,
+            event.source_host,
+            event.quality,
+            event.format,
+            event.range_start,
+            event.range_end,
+            event.http_status,
+            event.error_kind,
+            chrono::Utc::now().timestamp_millis()
+        ],
+    )?;
+    db.execute(
+        "DELETE FROM download_events WHERE video_id = ?1 AND id <= (
+            SELECT id FROM download_events WHERE video_id = ?1 ORDER BY id DESC LIMIT 1 OFFSET ?2
+         )",
+        params![event.video_id, MAX_EVENTS_PER_DOWNLOAD],
+    )?;
+    Ok(())
+}
+
+/// 获取下载的事件时间线（按发生顺序）
+pub(crate) fn get_download_events(video_id: &str) -> Result<Vec<DownloadEventRecord>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM download_events WHERE video_id = ?1 ORDER BY id",
+        EVENT_COLUMNS
+    ))?;
+    let events = stmt
+        .query_map(params![video_id], map_event_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(events)
+}
+
+/// 获取某时间（毫秒）之后所有下载的事件（用于日志导出）
+pub(crate) fn get_download_events_since(since_ms: i64) -> Result<Vec<DownloadEventRecord>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM download_events WHERE created_at >= ?1 ORDER BY id",
+        EVENT_COLUMNS
+    ))?;
+    let events = stmt
+        .query_map(params![since_ms], map_event_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(events)
+}
+
+// ========== 下载字幕 ==========
+
+/// 随视频下载的字幕轨道
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DownloadSubtitleRecord {
+    pub video_id: String,
+    pub language: String,
+    pub label: String,
+    pub url: String,
+    /// 本地文件的格式（WebVTT 转换后为 `srt`）
+    pub format: String,
+    /// 文件名（位于下载根目录的 download_subtitles 下），尚未下载时为空
+    pub file_path: Option<String>,
+}
+
+fn map_subtitle_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<DownloadSubtitleRecord> {
+    Ok(DownloadSubtitleRecord {
+        video_id: row.get(0)?,
+        language: row.get(1)?,
+        label: row.get(2)?,
+        url: row.get(3)?,
+        format: row.get(4)?,
+        file_path: row.get(5)?,
+    })
+}
+
+/// 保存字幕轨道（同一视频的同一语言 + 名称只保留一条）
+pub(crate) fn upsert_download_subtitle(subtitle: &DownloadSubtitleRecord) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "INSERT INTO download_subtitles (video_id, language, label, url, format, file_path, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(video_id, language, label) DO UPDATE SET
+            url = excluded.url,
+            format = excluded.format,
+            file_path = excluded.file_path",
+        params![
+            subtitle.video_id,
+            subtitle.language,
+            subtitle.label,
+            subtitle.url,
+            subtitle.format,
+            subtitle.file_path,
+            chrono::Utc::now().timestamp()
+        ],
+    )?;
+    Ok(())
+}
+
+/// 获取下载的字幕轨道
+pub(crate) fn get_download_subtitles(video_id: &str) -> Result<Vec<DownloadSubtitleRecord>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(
+        "SELECT video_id, language, label, url, format, file_path
+         FROM download_subtitles WHERE video_id = ?1 ORDER BY id",
+    )?;
+    let subtitles = stmt
+        .query_map(params![video_id], map_subtitle_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(subtitles)
+}
+
+/// 获取所有已下载的字幕文件名（用于目录比对）
+pub(crate) fn get_download_subtitle_files() -> Result<Vec<String>> {
+    let db = get_db()?;
+    let mut stmt =
+        db.prepare("SELECT file_path FROM download_subtitles WHERE file_path IS NOT NULL")?;
+    let files = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    Ok(files)
+}
+
+// ========== HLS 下载进度 ==========
+
+/// HLS 下载的续传位置：前 `segments_done` 个分片已写入，文件长度为 `bytes_done`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct HlsProgressRecord {
+    pub segment_count: u32,
+    pub segments_done: u32,
+    pub bytes_done: u64,
+}
+
+pub(crate) fn get_download_hls_progress(video_id: &str) -> Result<Option<HlsProgressRecord>> {
+    let db = get_db()?;
+    let record = db
+        .query_row(
+            "SELECT segment_count, segments_done, bytes_done FROM download_hls_progress
+             WHERE video_id = ?1",
+            params![video_id],
+            |row| {
+                Ok(HlsProgressRecord {
+                    segment_count: row.get(0)?,
+                    segments_done: row.get(1)?,
+                    bytes_done: row.get::<_, i64>(2)?.max(0) as u64,
+                })
+            },
+        )
+        .optional()?;
+    Ok(record)
+}
+
+pub(crate) fn save_download_hls_progress(
+    video_id: &str,
+    progress: &HlsProgressRecord,
+) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "INSERT INTO download_hls_progress
+            (video_id, segment_count, segments_done, bytes_done, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(video_id) DO UPDATE SET
+            segment_count = excluded.segment_count,
+            segments_done = excluded.segments_done,
+            bytes_done = excluded.bytes_done,
+            updated_at = excluded.updated_at",
+        params![
+            video_id,
+            progress.segment_count,
+            progress.segments_done,
+            progress.bytes_done as i64,
+            chrono::Utc::now().timestamp()
+        ],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn delete_download_hls_progress(video_id: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "DELETE FROM download_hls_progress WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    Ok(())
+}
+
+/// 画质变体的 HLS 续传位置
+pub(crate) fn get_variant_hls_progress(id: i64) -> Result<Option<HlsProgressRecord>> {
+    let db = get_db()?;
+    let record = db
+        .query_row(
+            "SELECT segment_count, segments_done, bytes_done FROM download_variant_hls_progress
+             WHERE variant_id = ?1",
+            params![id],
+            |row| {
+                Ok(HlsProgressRecord {
+                    segment_count: row.get(0)?,
+                    segments_done: row.get(1)?,
+                    bytes_done: row.get::<_, i64>(2)?.max(0) as u64,
+                })
+            },
+        )
+        .optional()?;
+    Ok(record)
+}
+
+pub(crate) fn save_variant_hls_progress(
+    id: i64,
+    video_id: &str,
+    progress: &HlsProgressRecord,
+) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "INSERT INTO download_variant_hls_progress
+            (variant_id, video_id, segment_count, segments_done, bytes_done, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(variant_id) DO UPDATE SET
+            segment_count = excluded.segment_count,
+            segments_done = excluded.segments_done,
+            bytes_done = excluded.bytes_done,
+            updated_at = excluded.updated_at",
+        params![
+            id,
+            video_id,
+            progress.segment_count,
+            progress.segments_done,
+            progress.bytes_done as i64,
+            chrono::Utc::now().timestamp()
+        ],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn delete_variant_hls_progress(id: i64) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "DELETE FROM download_variant_hls_progress WHERE variant_id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
+// ========== 提前下载的区间 ==========
+
+/// 拖动后提前下载到文件中的区间 [start, end)（按起点排序）
+pub(crate) fn get_download_ranges(video_id: &str) -> Result<Vec<(u64, u64)>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(
+        "SELECT range_start, range_end FROM download_ranges
+         WHERE video_id = ?1 ORDER BY range_start",
+    )?;
+    let ranges = stmt
+        .query_map(params![video_id], |row| {
+            Ok((
+                row.get::<_, i64>(0)?.max(0) as u64,
+                row.get::<_, i64>(1)?.max(0) as u64,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(ranges)
+}
+
+/// 替换下载的全部提前下载区间
+pub(crate) fn save_download_ranges(video_id: &str, ranges: &[(u64, u64)]) -> Result<()> {
+    let mut db = get_db()?;
+    let tx = db.transaction()?;
+    tx.execute(
+        "DELETE FROM download_ranges WHERE video_id = ?1",
+        params![video_id],
+    )?;
+    for (start, end) in ranges {
+        tx.execute(
+            "INSERT INTO download_ranges (video_id, range_start, range_end) VALUES (?1, ?2, ?3)",
+            params![video_id, *start as i64, *end as i64],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+// ========== 视频系列 ==========
+
+/// 系列中的一集（解析视频详情时保存，用于离线计算下一集）
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VideoSeriesRecord {
+    pub series_id: String,
+    pub series_title: String,
+    pub video_id: String,
+    pub title: String,
+    pub cover_url: String,
+    pub episode: u32,
+    /// 按集数排序后的位置
+    pub position: u32,
+}
+
+/// 保存系列的剧集列表（替换该系列之前保存的全部剧集）
+pub(crate) fn save_video_series(series_id: &str, episodes: &[VideoSeriesRecord]) -> Result<()> {
+    let mut db = get_db()?;
+    let tx = db.transaction()?;
+    tx.execute(
+        "DELETE FROM video_series WHERE series_id = ?1",
+        params![series_id],
+    )?;
+    let now = chrono::Utc::now().timestamp();
+    for episode in episodes {
+        tx.execute(
+            "INSERT OR REPLACE INTO video_series
+                (series_id, series_title, video_id, title, cover_url, episode, position, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                series_id,
+                episode.series_title,
+                episode.video_id,
+                episode.title,
+                episode.cover_url,
+                episode.episode,
+                episode.position,
+                now
+            ],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// 获取包含任一给定视频的系列的全部剧集（按系列、位置排序）
+pub(crate) fn get_video_series_containing(video_ids: &[String]) -> Result<Vec<VideoSeriesRecord>> {
+    if video_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let db = get_db()?;
+    let placeholders = vec!["?"; video_ids.len()].join(", ");
+    let mut stmt = db.prepare(&format!(
+        "SELECT series_id, series_title, video_id, title, cover_url, episode, position
+         FROM video_series
+         WHERE series_id IN (SELECT series_id FROM video_series WHERE video_id IN ({}))
+         ORDER BY series_id, position",
+        placeholders
+    ))?;
+    let records = stmt
+        .query_map(rusqlite::params_from_iter(video_ids), |row| {
+            Ok(VideoSeriesRecord {
+                series_id: row.get(0)?,
+                series_title: row.get(1)?,
+                video_id: row.get(2)?,
+                title: row.get(3)?,
+                cover_url: row.get(4)?,
+                episode: row.get(5)?,
+                position: row.get(6)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(records)
+}
+
+// ========== 下载文件夹 ==========
+
+/// 创建下载文件夹（排在同级文件夹末尾）
+///
+/// `rule_json` 非空时为智能文件夹
+pub fn create_download_folder(
+    id: &str,
+    name: &str,
+    parent_id: Option<&str>,
+    rule_json: Option<&str>,
+) -> Result<()> {
+    let db = get_db()?;
+    let now = chrono::Utc::now().timestamp();
+    db.execute(
+        r#"
+        INSERT INTO download_folders (id, name, created_at, parent_id, sort_order, rule_json)
+        VALUES (
+            ?1, ?2, ?3, ?4,
+            (SELECT COALESCE(MAX(sort_order), -1) + 1 FROM download_folders WHERE parent_id IS ?4),
+            ?5
+        )
+        "#,
+        params![id, name, now, parent_id, rule_json],
+    )?;
+    Ok(())
+}
+
+/// 获取所有下载文件夹（按同级排序、创建时间）
+pub(crate) fn get_download_folders() -> Result<Vec<DownloadFolderRecord>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(
+        r#"
+        SELECT id, name, created_at, parent_id, sort_order, cover_video_id, rule_json
+        FROM download_folders
+        ORDER BY sort_order ASC, created_at ASC
+        "#,
+    )?;
+
+    let records = stmt.query_map([], |row| {
+        Ok(DownloadFolderRecord {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            created_at: row.get(2)?,
+            parent_id: row.get(3)?,
+            sort_order: row.get(4)?,
+            cover_video_id: row.get(5)?,
+            rule_json: row.get(6)?,
+        })
+    })?;
+
+    let mut result = Vec::new();
+    for record in records {
+        result.push(record?);
+    }
+
+    Ok(result)
+}
+
+/// 更新下载文件夹名称
+pub fn update_download_folder_name(id: &str, name: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE download_folders SET name = ?1 WHERE id = ?2",
+        params![name, id],
+    )?;
+    Ok(())
+}
+
+/// 移动文件夹到新的上级（排在新同级文件夹末尾）；循环引用由调用方检查
+pub fn update_download_folder_parent(id: &str, parent_id: Option<&str>) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        r#"
+        UPDATE download_folders
+        SET parent_id = ?1,
+            sort_order = (
+                SELECT COALESCE(MAX(sort_order), -1) + 1 FROM download_folders
+                WHERE parent_id IS ?1 AND id != ?2
+            )
+        WHERE id = ?2
+        "#,
+        params![parent_id, id],
+    )?;
+    Ok(())
+}
+
+/// 按给定顺序重写文件夹排序
+pub fn update_download_folders_order(ids: &[String]) -> Result<()> {
+    let mut db = get_db()?;
+    let tx = db.transaction()?;
+    for (index, id) in ids.iter().enumerate() {
+        tx.execute(
+            "UPDATE download_folders SET sort_order = ?1 WHERE id = ?2",
+            params![index as i64, id],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// 设置文件夹封面（为空时使用默认封面）
+pub fn update_download_folder_cover(id: &str, cover_video_id: Option<&str>) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE download_folders SET cover_video_id = ?1 WHERE id = ?2",
+        params![cover_video_id, id],
+    )?;
+    Ok(())
+}
+
+/// 更新智能文件夹规则
+pub fn update_download_folder_rule(id: &str, rule_json: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE download_folders SET rule_json = ?1 WHERE id = ?2 AND rule_json IS NOT NULL",
+        params![rule_json, id],
+    )?;
+    Ok(())
+}
+
+/// 删除下载文件夹（不影响视频，仅清除视频的 folder_id；子文件夹移到被删除文件夹的上级）
+pub fn delete_download_folder(id: &str) -> Result<()> {
+    let mut db = get_db()?;
+    let tx = db.transaction()?;
+    // 先清除所有关联视频的 folder_id
+    tx.execute(
+        "UPDATE downloads SET folder_id = NULL WHERE folder_id = ?1",
+        params![id],
+    )?;
+    // 子文件夹上移一级
+    tx.execute(
+        r#"
+        UPDATE download_folders
+        SET parent_id = (SELECT parent_id FROM download_folders WHERE id = ?1)
+        WHERE parent_id = ?1
+        "#,
+        params![id],
+    )?;
+    // 再删除文件夹
+    tx.execute("DELETE FROM download_folders WHERE id = ?1", params![id])?;
+    tx.commit()?;
+    Ok(())
+}
+
+/// 获取位于指定文件夹中的下载
+pub(crate) fn get_downloads_in_folders(folder_ids: &[String]) -> Result<Vec<DownloadRecord>> {
+    if folder_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let db = get_db()?;
+    let placeholders = vec!["?"; folder_ids.len()].join(", ");
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM downloads WHERE folder_id IN ({}) ORDER BY created_at DESC",
+        DOWNLOAD_COLUMNS, placeholders
+    ))?;
+    let records = stmt.query_map(rusqlite::params_from_iter(folder_ids), map_download_row)?;
+
+    let mut result = Vec::new();
+    for record in records {
+        result.push(record?);
+    }
+
+    Ok(result)
+}
+
+/// 各文件夹中的下载数量（folder_id → 数量）
+pub(crate) fn count_downloads_by_folder() -> Result<HashMap<String, u32>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(
+        "SELECT folder_id, COUNT(*) FROM downloads WHERE folder_id IS NOT NULL GROUP BY folder_id",
+    )?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+    let mut result = HashMap::new();
+    for row in rows {
+        let (folder_id, count) = row?;
+        result.insert(folder_id, count);
+    }
+
+    Ok(result)
+}
+
+// ========== 下载过滤（智能文件夹） ==========
+
+/// 下载过滤条件，在 SQL 中求值；智能文件夹规则以 JSON 形式保存
+///
+/// - 不同条件之间为“且”；`authors` / `qualities` 满足任一即可，`tags` 需全部包含
+/// - 时间范围均为闭区间；上传日期为 `YYYY-MM-DD`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct DownloadFilter {
+    /// 作者名或作者 ID
+    pub authors: Vec<String>,
+    pub tags: Vec<String>,
+    /// 请求画质或实际画质
+    pub qualities: Vec<String>,
+    /// 加入下载的时间（秒级时间戳）
+    pub downloaded_from: Option<i64>,
+    pub downloaded_to: Option<i64>,
+    pub uploaded_from: Option<String>,
+    pub uploaded_to: Option<String>,
+    /// true：仅已观看；false：仅未观看
+    pub watched: Option<bool>,
+}
+
+impl DownloadFilter {
+    /// 生成 WHERE 子句（不含 `WHERE`）与参数；无条件时为 `1`
+    pub(crate) fn to_where(&self) -> (String, Vec<Value>) {
+        let mut clauses = Vec::new();
+        let mut args = Vec::new();
+
+        if !self.authors.is_empty() {
+            let placeholders = vec!["?"; self.authors.len()].join(", ");
+            clauses.push(format!(
+                "(author_name IN ({0}) OR author_id IN ({0}))",
+                placeholders
+            ));
+            for _ in 0..2 {
+                args.extend(self.authors.iter().cloned().map(Value::Text));
+            }
+        }
+        for tag in &self.tags {
+            clauses.push(
+                "EXISTS (SELECT 1 FROM json_each(CASE WHEN json_valid(downloads.tags) \
+                 THEN downloads.tags ELSE '[]' END) WHERE value = ?)"
+                    .to_string(),
+            );
+            args.push(Value::Text(tag.clone()));
+        }
+        if !self.qualities.is_empty() {
+            let placeholders = vec!["?"; self.qualities.len()].join(", ");
+            clauses.push(format!(
+                "(quality IN ({0}) OR source_quality IN ({0}))",
+                placeholders
+            ));
+            for _ in 0..2 {
+                args.extend(self.qualities.iter().cloned().map(Value::Text));
+            }
+        }
+        if let Some(from) = self.downloaded_from {
+            clauses.push("created_at >= ?".to_string());
+            args.push(Value::Integer(from));
+        }
+        if let Some(to) = self.downloaded_to {
+            clauses.push("created_at <= ?".to_string());
+            args.push(Value::Integer(to));
+        }
+        if let Some(from) = &self.uploaded_from {
+            clauses.push("upload_date >= ?".to_string());
+            args.push(Value::Text(from.clone()));
+        }
+        if let Some(to) = &self.uploaded_to {
+            clauses.push("upload_date <= ?".to_string());
+            args.push(Value::Text(to.clone()));
+        }
+        if let Some(watched) = self.watched {
+            // 与历史记录软删除规则一致：deleted_at 为空或早于 watched_at 视为存在
+            clauses.push(format!(
+                "{} EXISTS (SELECT 1 FROM history h WHERE h.video_id = downloads.video_id \
+                 AND (h.deleted_at IS NULL OR h.deleted_at < h.watched_at))",
+                if watched { "" } else { "NOT" }
+            ));
+        }
+
+        if clauses.is_empty() {
+            ("1".to_string(), args)
+        } else {
+            (clauses.join(" AND "), args)
+        }
+    }
+}
+
+/// 下载列表排序字段
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) enum DownloadSort {
+    #[default]
+    CreatedAt,
+    CompletedAt,
+    Title,
+    Size,
+    UploadDate,
+    Author,
+}
+
+impl DownloadSort {
+    fn column(self) -> &'static str {
+        match self {
+            DownloadSort::CreatedAt => "created_at",
+            DownloadSort::CompletedAt => "completed_at",
+            DownloadSort::Title => "title COLLATE NOCASE",
+            DownloadSort::Size => "total_bytes",
+            DownloadSort::UploadDate => "upload_date",
+            DownloadSort::Author => "author_name COLLATE NOCASE",
+        }
+    }
+}
+
+/// 下载列表分页查询
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DownloadQuery {
+    pub filter: DownloadFilter,
+    /// 智能文件夹规则，需与其余条件同时满足
+    pub rule: Option<DownloadFilter>,
+    /// 满足任一状态即可，为空不限
+    pub statuses: Vec<DownloadStatus>,
+    /// 位于任一文件夹即可，为空不限
+    pub folder_ids: Vec<String>,
+    /// 标题 / 中文标题 / 简介包含的文本（不区分大小写）
+    pub text: Option<String>,
+    pub min_bytes: Option<i64>,
+    pub max_bytes: Option<i64>,
+    pub sort: DownloadSort,
+    pub descending: bool,
+    pub offset: u32,
+    /// 0 表示不限
+    pub limit: u32,
+}
+
+impl DownloadQuery {
+    fn to_where(&self) -> (String, Vec<Value>) {
+        let (clause, mut args) = self.filter.to_where();
+        let mut clauses = vec![clause];
+        if let Some(rule) = &self.rule {
+            let (clause, rule_args) = rule.to_where();
+            clauses.push(clause);
+            args.extend(rule_args);
+        }
+        if !self.statuses.is_empty() {
+            clauses.push(format!(
+                "status IN ({})",
+                vec!["?"; self.statuses.len()].join(", ")
+            ));
+            args.extend(self.statuses.iter().map(|s| Value::Integer(*s as i64)));
+        }
+        if !self.folder_ids.is_empty() {
+            clauses.push(format!(
+                "folder_id IN ({})",
+                vec!["?"; self.folder_ids.len()].join(", ")
+            ));
+            args.extend(self.folder_ids.iter().cloned().map(Value::Text));
+        }
+        if let Some(text) = self.text.as_deref().filter(|t| !t.is_empty()) {
+            let pattern = format!(
+                "%{}%",
+                text.replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_")
+            );
+            clauses.push(
+                "(title LIKE ? ESCAPE '\\' OR chinese_title LIKE ? ESCAPE '\\' \
+                 OR description LIKE ? ESCAPE '\\')"
+                    .to_string(),
+            );
+            for _ in 0..3 {
+                args.push(Value::Text(pattern.clone()));
+            }
+        }
+        if let Some(min) = self.min_bytes {
+            clauses.push("total_bytes >= ?".to_string());
+            args.push(Value::Integer(min));
+        }
+        if let Some(max) = self.max_bytes {
+            clauses.push("total_bytes <= ?".to_string());
+            args.push(Value::Integer(max));
+        }
+        (clauses.join(" AND "), args)
+    }
+}
+
+/// 分页查询下载，返回（当前页记录, 满足条件的总数）
+pub(crate) fn query_downloads(query: &DownloadQuery) -> Result<(Vec<DownloadRecord>, u32)> {
+    let db = get_db()?;
+    query_downloads_on(&db, query)
+}
+
+fn query_downloads_on(
+    db: &Connection,
+    query: &DownloadQuery,
+) -> Result<(Vec<DownloadRecord>, u32)> {
+    let (clause, args) = query.to_where();
+    let total = db.query_row(
+        &format!("SELECT COUNT(*) FROM downloads WHERE {}", clause),
+        rusqlite::params_from_iter(args.iter()),
+        |row| row.get(0),
+    )?;
+
+    let order = if query.descending { "DESC" } else { "ASC" };
+    // 空值始终排在最后；id 作为次序键保证分页稳定
+    let mut sql = format!(
+        "SELECT {} FROM downloads WHERE {} ORDER BY {col} IS NULL, {col} {order}, id {order}",
+        DOWNLOAD_COLUMNS,
+        clause,
+        col = query.sort.column(),
+        order = order
+    );
+    if query.limit > 0 {
+        sql.push_str(&format!(" LIMIT {} OFFSET {}", query.limit, query.offset));
+    } else if query.offset > 0 {
+        sql.push_str(&format!(" LIMIT -1 OFFSET {}", query.offset));
+    }
+    let mut stmt = db.prepare(&sql)?;
+    let records = stmt.query_map(rusqlite::params_from_iter(args), map_download_row)?;
+
+    let mut result = Vec::new();
+    for record in records {
+        result.push(record?);
+    }
+
+    Ok((result, total))
+}
+
+/// 按过滤条件查询下载
+pub(crate) fn get_downloads_by_filter(filter: &DownloadFilter) -> Result<Vec<DownloadRecord>> {
+    let db = get_db()?;
+    query_downloads_by_filter(&db, filter)
+}
+
+/// 按过滤条件统计下载数量
+pub(crate) fn count_downloads_by_filter(filter: &DownloadFilter) -> Result<u32> {
+    let db = get_db()?;
+    let (clause, args) = filter.to_where();
+    let count = db.query_row(
+        &format!("SELECT COUNT(*) FROM downloads WHERE {}", clause),
+        rusqlite::params_from_iter(args),
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
+fn query_downloads_by_filter(
+    db: &Connection,
+    filter: &DownloadFilter,
+) -> Result<Vec<DownloadRecord>> {
+    let (clause, args) = filter.to_where();
+    let mut stmt = db.prepare(&format!(
+        "SELECT {} FROM downloads WHERE {} ORDER BY created_at DESC",
+        DOWNLOAD_COLUMNS, clause
+    ))?;
+    let records = stmt.query_map(rusqlite::params_from_iter(args), map_download_row)?;
+
+    let mut result = Vec::new();
+    for record in records {
+        result.push(record?);
+    }
+
+    Ok(result)
+}
+
+// ========== 自动下载规则 ==========
+
+/// 自动下载规则记录（内部使用）
+#[derive(Debug, Clone)]
+pub(crate) struct DownloadRuleRecord {
+    pub id: String,
+    pub name: String,
+    /// `artist` | `tag` | `genre`
+    pub match_type: String,
+    pub match_value: String,
+    pub quality: String,
+    pub folder_id: Option<String>,
+    pub max_age_days: Option<i64>,
+    pub daily_cap: Option<i64>,
+    pub enabled: bool,
+    pub created_at: i64,
+    pub last_checked_at: Option<i64>,
+}
+
+/// 新增自动下载规则
+pub(crate) fn create_download_rule(rule: &DownloadRuleRecord) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        r#"
+        INSERT INTO download_rules (
+            id, name, match_type, match_value, quality, folder_id,
+            max_age_days, daily_cap, enabled, created_at
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+        "#,
+        params![
+            rule.id,
+            rule.name,
+            rule.match_type,
+            rule.match_value,
+            rule.quality,
+            rule.folder_id,
+            rule.max_age_days,
+            rule.daily_cap,
+            rule.enabled,
+            rule.created_at
+        ],
+    )?;
+    Ok(())
+}
+
+/// 更新自动下载规则（不修改创建时间与检查时间）
+pub(crate) fn update_download_rule(rule: &DownloadRuleRecord) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        r#"
+        UPDATE download_rules
+        SET name = ?1, match_type = ?2, match_value = ?3, quality = ?4, folder_id = ?5,
+            max_age_days = ?6, daily_cap = ?7, enabled = ?8
+        WHERE id = ?9
+        "#,
+        params![
+            rule.name,
+            rule.match_type,
+            rule.match_value,
+            rule.quality,
+            rule.folder_id,
+            rule.max_age_days,
+            rule.daily_cap,
+            rule.enabled,
+            rule.id
+        ],
+    )?;
+    Ok(())
+}
+
+/// 获取所有自动下载规则
+pub(crate) fn get_download_rules() -> Result<Vec<DownloadRuleRecord>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare(
+        r#"
+        SELECT id, name, match_type, match_value, quality, folder_id,
+               max_age_days, daily_cap, enabled, created_at, last_checked_at
+        FROM download_rules
+        ORDER BY created_at ASC
+        "#,
+    )?;
+
+    let records = stmt.query_map([], |row| {
+        Ok(DownloadRuleRecord {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            match_type: row.get(2)?,
+            match_value: row.get(3)?,
+            quality: row.get(4)?,
+            folder_id: row.get(5)?,
+            max_age_days: row.get(6)?,
+            daily_cap: row.get(7)?,
+            enabled: row.get(8)?,
+            created_at: row.get(9)?,
+            last_checked_at: row.get(10)?,
+        })
+    })?;
+
+    let mut result = Vec::new();
+    for record in records {
+        result.push(record?);
+    }
+
+    Ok(result)
+}
+
+/// 启用/停用自动下载规则
+pub fn set_download_rule_enabled(id: &str, enabled: bool) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE download_rules SET enabled = ?1 WHERE id = ?2",
+        params![enabled, id],
+    )?;
+    Ok(())
+}
+
+/// 记录规则最近一次检查时间
+pub fn update_download_rule_checked_at(id: &str, checked_at: i64) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "UPDATE download_rules SET last_checked_at = ?1 WHERE id = ?2",
+        params![checked_at, id],
+    )?;
+    Ok(())
+}
+
+/// 删除自动下载规则（已入队的下载不受影响）
+pub fn delete_download_rule(id: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute("DELETE FROM download_rules WHERE id = ?1", params![id])?;
+    db.execute(
+        "UPDATE download_rule_seen SET rule_id = NULL WHERE rule_id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
+/// 视频是否已被规则检查器处理过
+pub fn is_rule_video_seen(video_id: &str) -> Result<bool> {
+    let db = get_db()?;
+    let mut stmt = db.prepare("SELECT 1 FROM download_rule_seen WHERE video_id = ?1")?;
+    Ok(stmt.exists(params![video_id])?)
+}
+
+/// 是否已有规则检查器处理过的视频
+pub fn has_rule_seen_videos() -> Result<bool> {
+    let db = get_db()?;
+    let mut stmt = db.prepare("SELECT 1 FROM download_rule_seen LIMIT 1")?;
+    Ok(stmt.exists([])?)
+}
+
+/// 标记视频已处理；`rule_id` 为触发下载的规则（未命中任何规则时为空）
+pub fn mark_rule_video_seen(video_id: &str, rule_id: Option<&str>) -> Result<()> {
+    let db = get_db()?;
+    let now = chrono::Utc::now().timestamp();
+    db.execute(
+        "INSERT OR REPLACE INTO download_rule_seen (video_id, rule_id, seen_at) VALUES (?1, ?2, ?3)",
+        params![video_id, rule_id, now],
+    )?;
+    Ok(())
+}
+
+/// 统计规则自指定时间以来触发的下载数（用于每日上限）
+pub fn count_rule_downloads_since(rule_id: &str, since: i64) -> Result<i64> {
+    let db = get_db()?;
+    let count = db.query_row(
+        "SELECT COUNT(*) FROM download_rule_seen WHERE rule_id = ?1 AND seen_at >= ?2",
+        params![rule_id, since],
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
+// ========== 设置 ==========
+
+/// 保存设置
+pub fn save_setting(key: &str, value: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute(
+        "INSERT OR REPLACE INTO settings (key, value) VALUES (?1, ?2)",
+        params![key, value],
+    )?;
+    Ok(())
+}
+
+/// 获取设置
+pub fn get_setting(key: &str) -> Result<Option<String>> {
+    let db = get_db()?;
+    let mut stmt = db.prepare("SELECT value FROM settings WHERE key = ?1")?;
+    let mut rows = stmt.query(params![key])?;
+
+    if let Some(row) = rows.next()? {
+        Ok(Some(row.get(0)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// 删除设置
+pub fn delete_setting(key: &str) -> Result<()> {
+    let db = get_db()?;
+    db.execute("DELETE FROM settings WHERE key = ?1", params![key])?;
+    Ok(())
+}
+
+// ========== Telemetry Queue (OTLP) ==========
+
+#[derive(Debug, Clone)]
+pub(crate) struct TelemetryRecord {
+    pub id: i64,
     pub payload_base64: String,
     pub _retry_count: i32,
 }
@@ -947,8 +2556,251 @@ mod tests {
         let history = get_history(10, 0).unwrap();
         assert_eq!(history.len(), 1);
         assert_eq!(history[0].video_id, "video1");
+        // 全局连接可能被其他测试复用，数据库文件不在此处删除
+    }
 
-        // 清理
-        let _ = std::fs::remove_file(&db_path);
+    #[test]
+    fn test_download_filter() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::runner().run(&mut conn).unwrap();
+        let rows = [
+            ("v1", "alice", r#"["a","b"]"#, "1080", "2024-01-05", 100),
+            ("v2", "bob", r#"["a"]"#, "720", "2024-03-01", 200),
+            ("v3", "alice", "[]", "720", "2023-12-31", 300),
+        ];
+        for (id, author, tags, quality, upload_date, created_at) in rows {
+            conn.execute(
+                r#"
+                INSERT INTO downloads (video_id, title, cover_url, video_url, quality, tags,
+                    author_name, status, created_at, upload_date)
+                VALUES (?1, ?1, '', '', ?2, ?3, ?4, 2, ?5, ?6)
+                "#,
+                params![id, quality, tags, author, created_at, upload_date],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "INSERT INTO history (video_id, title, watched_at) VALUES ('v2', 'v2', 10)",
+            [],
+        )
+        .unwrap();
+
+        let ids = |filter: DownloadFilter| -> Vec<String> {
+            query_downloads_by_filter(&conn, &filter)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.video_id)
+                .collect()
+        };
+
+        assert_eq!(ids(DownloadFilter::default()), vec!["v3", "v2", "v1"]);
+        assert_eq!(
+            ids(DownloadFilter {
+                authors: vec!["alice".into()],
+                qualities: vec!["720".into()],
+                ..Default::default()
+            }),
+            vec!["v3"]
+        );
+        assert_eq!(
+            ids(DownloadFilter {
+                tags: vec!["a".into(), "b".into()],
+                ..Default::default()
+            }),
+            vec!["v1"]
+        );
+        assert_eq!(
+            ids(DownloadFilter {
+                uploaded_from: Some("2024-01-01".into()),
+                downloaded_to: Some(150),
+                ..Default::default()
+            }),
+            vec!["v1"]
+        );
+        assert_eq!(
+            ids(DownloadFilter {
+                watched: Some(true),
+                ..Default::default()
+            }),
+            vec!["v2"]
+        );
+        assert_eq!(
+            ids(DownloadFilter {
+                watched: Some(false),
+                ..Default::default()
+            }),
+            vec!["v3", "v1"]
+        );
+    }
+
+    #[test]
+    fn test_download_query() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::runner().run(&mut conn).unwrap();
+        let rows = [
+            ("v1", "Alpha 100%", 3, 500, None),
+            ("v2", "beta", 3, 100, Some("f1")),
+            ("v3", "Gamma", 4, 300, Some("f1")),
+            ("v4", "delta", 3, 200, None),
+        ];
+        for (index, (id, title, status, bytes, folder)) in rows.into_iter().enumerate() {
+            conn.execute(
+                r#"
+                INSERT INTO downloads (video_id, title, cover_url, video_url, status, total_bytes,
+                    folder_id, created_at)
+                VALUES (?1, ?2, '', '', ?3, ?4, ?5, ?6)
+                "#,
+                params![id, title, status, bytes, folder, index as i64],
+            )
+            .unwrap();
+        }
+
+        let run = |query: DownloadQuery| -> (Vec<String>, u32) {
+            let (records, total) = query_downloads_on(&conn, &query).unwrap();
+            (records.into_iter().map(|r| r.video_id).collect(), total)
+        };
+
+        let (ids, total) = run(DownloadQuery {
+            statuses: vec![DownloadStatus::Completed],
+            sort: DownloadSort::Title,
+            limit: 2,
+            ..Default::default()
+        });
+        assert_eq!((ids, total), (vec!["v1".into(), "v2".into()], 3));
+
+        let (ids, total) = run(DownloadQuery {
+            statuses: vec![DownloadStatus::Completed],
+            sort: DownloadSort::Title,
+            offset: 2,
+            limit: 2,
+            ..Default::default()
+        });
+        assert_eq!((ids, total), (vec!["v4".into()], 3));
+
+        let (ids, _) = run(DownloadQuery {
+            sort: DownloadSort::Size,
+            descending: true,
+            min_bytes: Some(200),
+            ..Default::default()
+        });
+        assert_eq!(ids, vec!["v1", "v3", "v4"]);
+
+        let (ids, _) = run(DownloadQuery {
+            folder_ids: vec!["f1".into()],
+            text: Some("AM".into()),
+            ..Default::default()
+        });
+        assert_eq!(ids, vec!["v3"]);
+
+        // LIKE 通配符按字面匹配
+        let (ids, _) = run(DownloadQuery {
+            text: Some("0%".into()),
+            ..Default::default()
+        });
+        assert_eq!(ids, vec!["v1"]);
+    }
+
+    #[test]
+    fn test_promote_download_variant() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::runner().run(&mut conn).unwrap();
+        conn.execute(
+            r#"
+            INSERT INTO downloads (video_id, title, cover_url, video_url, quality, source_quality,
+                save_path, total_bytes, downloaded_bytes, status, folder_id, created_at)
+            VALUES ('v1', 'v1', '', '', '480P', '480P', 'v1_480P.mp4', 10, 10, 3, 'f1', 1)
+            "#,
+            [],
+        )
+        .unwrap();
+        let insert_variant = |conn: &Connection, quality: &str, keep_previous: bool| -> i64 {
+            conn.execute(
+                r#"
+                INSERT INTO download_variants (video_id, quality, source_quality, save_path,
+                    total_bytes, downloaded_bytes, status, replace_primary, keep_previous,
+                    created_at, completed_at)
+                VALUES ('v1', ?1, ?1, 'v1_' || ?1 || '.mp4', 20, 20, 3, 1, ?2, 2, 3)
+                "#,
+                params![quality, keep_previous],
+            )
+            .unwrap();
+            conn.last_insert_rowid()
+        };
+        let primary = |conn: &Connection| -> (Option<String>, Option<String>, Option<String>) {
+            conn.query_row(
+                "SELECT quality, save_path, folder_id FROM downloads WHERE video_id = 'v1'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap()
+        };
+
+        // 保留原文件：原主下载转为变体
+        let id = insert_variant(&conn, "720P", true);
+        assert_eq!(promote_variant_on(&mut conn, id, true).unwrap(), None);
+        assert_eq!(
+            primary(&conn),
+            (
+                Some("720P".into()),
+                Some("v1_720P.mp4".into()),
+                Some("f1".into())
+            )
+        );
+        let kept: (String, String) = conn
+            .query_row(
+                "SELECT quality, save_path FROM download_variants WHERE id = ?1",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(kept, ("480P".into(), "v1_480P.mp4".into()));
+
+        // 不保留：返回需删除的旧文件，变体行删除
+        let id = insert_variant(&conn, "1080P", false);
+        assert_eq!(
+            promote_variant_on(&mut conn, id, false).unwrap(),
+            Some("v1_720P.mp4".into())
+        );
+        assert_eq!(primary(&conn).1, Some("v1_1080P.mp4".into()));
+        let remaining: i64 = conn
+            .query_row("SELECT COUNT(*) FROM download_variants", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(remaining, 1);
+    }
+
+    #[test]
+    fn test_download_events_pruned() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::runner().run(&mut conn).unwrap();
+        for i in 0..MAX_EVENTS_PER_DOWNLOAD + 5 {
+            let event = DownloadEventRecord {
+                message: Some(i.to_string()),
+                ..DownloadEventRecord::new("v1", "status")
+            };
+            insert_download_event_on(&conn, &event).unwrap();
+        }
+        insert_download_event_on(&conn, &DownloadEventRecord::new("v2", "status")).unwrap();
+
+        // 超出上限时只删除同一下载最旧的事件
+        let count = |video_id: &str| -> i64 {
+            conn.query_row(
+                "SELECT COUNT(*) FROM download_events WHERE video_id = ?1",
+                params![video_id],
+                |row| row.get(0),
+            )
+            .unwrap()
+        };
+        assert_eq!(count("v1"), MAX_EVENTS_PER_DOWNLOAD);
+        assert_eq!(count("v2"), 1);
+        let oldest: String = conn
+            .query_row(
+                "SELECT message FROM download_events WHERE video_id = 'v1' ORDER BY id LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(oldest, "5");
     }
 }
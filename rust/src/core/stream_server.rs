@@ -0,0 +1,742 @@
+// 本地播放代理服务（仅监听 127.0.0.1）
+// - /{token}/local/{video_id}：播放已完成或下载中的文件，支持 Range / 206
+//   - 下载中的文件：已下载部分直接读取，读到末尾时跟随下载继续输出
+//   - 下载进行中拖动到尚未下载的位置时，通知下载任务从该位置预取写入文件，读取跟随预取进度
+//   - 下载未在进行（或播放源不支持预取）时，直接从远程获取该区间
+// - /{token}/remote?url=...：以应用的 UA、Referer、Cookie 与 DNS 设置代理远程媒体，Range 透传
+// - m3u8 播放列表中的分片、密钥等地址改写为经过本服务的地址
+// - 路径中的随机 token 避免本机其他应用借用代理
+// - 每个连接只处理一个请求（Connection: close）
+
+use crate::core::byte_ranges::RangeSet;
+use crate::core::{library_use, network, runtime};
+use anyhow::{anyhow, Result};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// 请求头最大长度
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+const PLAYLIST_CONTENT_TYPE: &str = "application/vnd.apple.mpegurl";
+/// 请求位置距已下载末尾不超过该值且下载仍在进行时，等待顺序下载而不是预取
+const TAIL_WAIT_BYTES: u64 = 4 * 1024 * 1024;
+/// 跟随下载时的轮询间隔
+const TAIL_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// 跟随下载时超过该时间没有新数据则结束响应（播放器会重新发起 Range 请求）
+const TAIL_STALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 本地媒体（由 API 层根据下载记录解析）
+#[derive(Debug, Clone)]
+pub(crate) struct LocalMedia {
+    pub path: PathBuf,
+    /// 完整大小；下载中的任务为预期总大小，未知时为 0
+    pub total: u64,
+    /// 从文件开头起已可读取的字节数
+    pub available: u64,
+    /// 已可读取的全部区间（含从开头起的 `available` 部分与拖动预取的区间）
+    pub ranges: RangeSet,
+    /// 下载仍在进行，`ranges` 会继续增长
+    pub growing: bool,
+    /// 远程地址：请求位置尚未下载时从这里获取
+    pub source_url: Option<String>,
+}
+
+/// 根据 video_id 解析本地媒体，没有可播放的本地文件时返回 `None`
+pub(crate) type LocalResolver = fn(&str) -> Result<Option<LocalMedia>>;
+
+/// 通知下载任务从指定位置预取；返回 `false` 表示无法预取
+pub(crate) type SeekHint = fn(&str, u64) -> bool;
+
+struct Server {
+    port: u16,
+    token: String,
+    resolver: LocalResolver,
+    seek_hint: SeekHint,
+}
+
+static SERVER: OnceLock<Server> = OnceLock::new();
+
+/// 启动代理服务（已启动时直接返回），返回服务根地址
+pub(crate) fn start(resolver: LocalResolver, seek_hint: SeekHint) -> Result<String> {
+    if let Some(server) = SERVER.get() {
+        return Ok(base_url(server));
+    }
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0))?;
+    listener.set_nonblocking(true)?;
+    let server = Server {
+        port: listener.local_addr()?.port(),
+        token: uuid::Uuid::new_v4().simple().to_string(),
+        resolver,
+        seek_hint,
+    };
+    if SERVER.set(server).is_err() {
+        // 并发启动时保留先启动的实例
+        return Ok(base_url(SERVER.get().unwrap()));
+    }
+    let server = SERVER.get().unwrap();
+    tracing::info!("stream_server started port={}", server.port);
+    runtime::spawn(async move {
+        let listener = match TcpListener::from_std(listener) {
+            Ok(listener) => listener,
+            Err(e) => {
+                tracing::error!("stream_server listen_failed error={}", e);
+                return;
+            }
+        };
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    runtime::spawn(async move {
+                        if let Err(e) = handle_connection(stream, server).await {
+                            tracing::debug!("stream_server request_failed error={}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!("stream_server accept_failed error={}", e);
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                }
+            }
+        }
+    });
+    Ok(base_url(server))
+}
+
+fn base_url(server: &Server) -> String {
+    format!("http://127.0.0.1:{}/{}", server.port, server.token)
+}
+
+/// 本地媒体的播放地址
+pub(crate) fn local_url(base: &str, video_id: &str) -> String {
+    format!("{}/local/{}", base, urlencoding::encode(video_id))
+}
+
+/// 远程媒体经代理后的播放地址
+pub(crate) fn remote_url(base: &str, url: &str) -> String {
+    format!("{}/remote?url={}", base, urlencoding::encode(url))
+}
+
+/// 解析后的请求（只关心方法、路径、查询串与 Range）
+#[derive(Debug, PartialEq)]
+struct Request {
+    method: String,
+    path: String,
+    query: Option<String>,
+    range: Option<String>,
+}
+
+fn parse_request(head: &str) -> Result<Request> {
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().ok_or_else(|| anyhow!("Empty request"))?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().ok_or_else(|| anyhow!("Missing method"))?;
+    let target = parts.next().ok_or_else(|| anyhow!("Missing target"))?;
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path, Some(query.to_string())),
+        None => (target, None),
+    };
+    let range = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("range"))
+        .map(|(_, value)| value.trim().to_string());
+    Ok(Request {
+        method: method.to_ascii_uppercase(),
+        path: path.to_string(),
+        query,
+        range,
+    })
+}
+
+/// 请求的字节范围
+#[derive(Debug, PartialEq)]
+enum ByteRange {
+    /// 未指定或无法解析：返回完整内容
+    Full,
+    /// 闭区间 [start, end]
+    Partial(u64, u64),
+    /// 起始位置超出内容长度
+    Unsatisfiable,
+}
+
+/// 解析 `Range: bytes=...`（只支持单个范围，多个范围时返回完整内容）
+fn parse_range(header: Option<&str>, total: u64) -> ByteRange {
+    let Some(spec) = header
+        .and_then(|h| h.trim().strip_prefix("bytes="))
+        .filter(|spec| !spec.contains(','))
+    else {
+        return ByteRange::Full;
+    };
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return ByteRange::Full;
+    };
+    let (start, end) = (start.trim(), end.trim());
+    if start.is_empty() {
+        // 后缀范围：最后 N 个字节
+        let Ok(suffix) = end.parse::<u64>() else {
+            return ByteRange::Full;
+        };
+        if suffix == 0 || total == 0 {
+            return ByteRange::Unsatisfiable;
+        }
+        return ByteRange::Partial(total.saturating_sub(suffix), total - 1);
+    }
+    let Ok(start) = start.parse::<u64>() else {
+        return ByteRange::Full;
+    };
+    if start >= total {
+        return ByteRange::Unsatisfiable;
+    }
+    let end = match end {
+        "" => total - 1,
+        end => match end.parse::<u64>() {
+            Ok(end) if end >= start => end.min(total - 1),
+            _ => return ByteRange::Full,
+        },
+    };
+    ByteRange::Partial(start, end)
+}
+
+/// 改写 m3u8：分片、子播放列表以及标签中的 `URI="..."` 均改为经过代理的绝对地址
+fn rewrite_playlist(body: &str, playlist_url: &url::Url, base: &str) -> String {
+    let proxied = |uri: &str| -> String {
+        match playlist_url.join(uri) {
+            Ok(absolute) => remote_url(base, absolute.as_str()),
+            Err(_) => uri.to_string(),
+        }
+    };
+    let mut out = String::with_capacity(body.len() * 2);
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            out.push_str(line);
+        } else if trimmed.starts_with('#') {
+            match trimmed.find("URI=\"") {
+                Some(index) => {
+                    let value_start = index + "URI=\"".len();
+                    match trimmed[value_start..].find('"') {
+                        Some(len) => {
+                            let uri = &trimmed[value_start..value_start + len];
+                            out.push_str(&trimmed[..value_start]);
+                            out.push_str(&proxied(uri));
+                            out.push_str(&trimmed[value_start + len..]);
+                        }
+                        None => out.push_str(trimmed),
+                    }
+                }
+                None => out.push_str(trimmed),
+            }
+        } else {
+            out.push_str(&proxied(trimmed));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn is_playlist(url: &url::Url, content_type: Option<&str>) -> bool {
+    content_type.is_some_and(|t| t.to_ascii_lowercase().contains("mpegurl"))
+        || url.path().to_ascii_lowercase().ends_with(".m3u8")
+}
+
+fn content_type_for(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("mp4") | Some("m4v") => "video/mp4",
+        Some("webm") => "video/webm",
+        Some("m3u8") => PLAYLIST_CONTENT_TYPE,
+        Some("ts") => "video/mp2t",
+        _ => "application/octet-stream",
+    }
+}
+
+async fn read_head(stream: &mut TcpStream) -> Result<String> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Err(anyhow!("Connection closed before request head"));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            buf.truncate(end);
+            return Ok(String::from_utf8_lossy(&buf).into_owned());
+        }
+        if buf.len() > MAX_HEAD_BYTES {
+            return Err(anyhow!("Request head too large"));
+        }
+    }
+}
+
+async fn write_head(
+    stream: &mut TcpStream,
+    status: u16,
+    reason: &str,
+    headers: &[(&str, String)],
+) -> Result<()> {
+    let mut head = format!("HTTP/1.1 {} {}\r\n", status, reason);
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("Connection: close\r\n\r\n");
+    stream.write_all(head.as_bytes()).await?;
+    Ok(())
+}
+
+async fn write_status(stream: &mut TcpStream, status: u16, reason: &str) -> Result<()> {
+    write_head(
+        stream,
+        status,
+        reason,
+        &[("Content-Length", "0".to_string())],
+    )
+    .await
+}
+
+async fn write_unsatisfiable(stream: &mut TcpStream, total: u64) -> Result<()> {
+    write_head(
+        stream,
+        416,
+        "Range Not Satisfiable",
+        &[
+            ("Content-Range", format!("bytes */{}", total)),
+            ("Content-Length", "0".to_string()),
+        ],
+    )
+    .await
+}
+
+async fn handle_connection(mut stream: TcpStream, server: &'static Server) -> Result<()> {
+    let head = read_head(&mut stream).await?;
+    let request = parse_request(&head)?;
+    if request.method != "GET" && request.method != "HEAD" {
+        return write_status(&mut stream, 405, "Method Not Allowed").await;
+    }
+    let Some(route) = request
+        .path
+        .strip_prefix('/')
+        .and_then(|p| p.strip_prefix(server.token.as_str()))
+    else {
+        return write_status(&mut stream, 404, "Not Found").await;
+    };
+    let base = base_url(server);
+    if let Some(video_id) = route.strip_prefix("/local/") {
+        let video_id = urlencoding::decode(video_id)?.into_owned();
+        serve_local(&mut stream, server, &request, &video_id, &base).await
+    } else if route == "/remote" {
+        let url = request
+            .query
+            .as_deref()
+            .and_then(|q| url::form_urlencoded::parse(q.as_bytes()).find(|(k, _)| k == "url"))
+            .map(|(_, v)| v.into_owned());
+        match url {
+            Some(url) => serve_remote(&mut stream, &request, &url, &base).await,
+            None => write_status(&mut stream, 400, "Bad Request").await,
+        }
+    } else {
+        write_status(&mut stream, 404, "Not Found").await
+    }
+}
+
+/// 本地媒体请求的读取方式
+#[derive(Debug, PartialEq)]
+enum LocalPlan {
+    /// 起始位置已下载
+    Disk,
+    /// 起始位置即将下载到：等待下载
+    WaitForDownload,
+    /// 起始位置离已下载部分较远且下载进行中：通知下载任务预取并等待
+    Prefetch,
+    /// 下载未在进行：从远程获取
+    Remote,
+    /// 无法满足
+    Unavailable,
+}
+
+fn plan_local(
+    start: u64,
+    readable: bool,
+    available: u64,
+    growing: bool,
+    has_remote: bool,
+) -> LocalPlan {
+    if readable {
+        LocalPlan::Disk
+    } else if growing && start < available.saturating_add(TAIL_WAIT_BYTES) {
+        LocalPlan::WaitForDownload
+    } else if growing {
+        LocalPlan::Prefetch
+    } else if has_remote {
+        LocalPlan::Remote
+    } else {
+        LocalPlan::Unavailable
+    }
+}
+
+async fn serve_local(
+    stream: &mut TcpStream,
+    server: &Server,
+    request: &Request,
+    video_id: &str,
+    base: &str,
+) -> Result<()> {
+    let resolve = |video_id: &str| match (server.resolver)(video_id) {
+        Ok(media) => media,
+        Err(e) => {
+            tracing::warn!(
+                "stream_server resolve_failed video_id={} error={}",
+                video_id,
+                e
+            );
+            None
+        }
+    };
+    // 读取期间登记使用下载目录，迁移下载目录时拒绝新的本地读取
+    let Ok(_library) = library_use::acquire() else {
+        return write_status(stream, 503, "Service Unavailable").await;
+    };
+    let Some(mut media) = resolve(video_id) else {
+        return write_status(stream, 404, "Not Found").await;
+    };
+    let total = media.total.max(media.available);
+    if total == 0 {
+        // 尚未获取到文件大小：整体代理远程地址
+        return match media.source_url.clone() {
+            Some(url) => serve_remote(stream, request, &url, base).await,
+            None => write_status(stream, 404, "Not Found").await,
+        };
+    }
+    let (status, reason, start, end) = match parse_range(request.range.as_deref(), total) {
+        ByteRange::Full => (200, "OK", 0, total - 1),
+        ByteRange::Partial(start, end) => (206, "Partial Content", start, end),
+        ByteRange::Unsatisfiable => return write_unsatisfiable(stream, total).await,
+    };
+    let remote = match plan_local(
+        start,
+        media.ranges.covered_end(start).is_some(),
+        media.available,
+        media.growing,
+        media.source_url.is_some(),
+    ) {
+        LocalPlan::Disk | LocalPlan::WaitForDownload => false,
+        // 无法预取（例如播放源不支持 Range）时退回从远程获取
+        LocalPlan::Prefetch => !(server.seek_hint)(video_id, start) && media.source_url.is_some(),
+        LocalPlan::Remote => true,
+        LocalPlan::Unavailable => return write_unsatisfiable(stream, total).await,
+    };
+    if remote {
+        let url = media.source_url.clone().unwrap_or_default();
+        return serve_source_range(stream, request, &media, &url, start, end, total).await;
+    }
+
+    let len = end - start + 1;
+    let mut headers = vec![
+        ("Content-Type", content_type_for(&media.path).to_string()),
+        ("Accept-Ranges", "bytes".to_string()),
+        ("Content-Length", len.to_string()),
+    ];
+    if status == 206 {
+        headers.push((
+            "Content-Range",
+            format!("bytes {}-{}/{}", start, end, total),
+        ));
+    }
+    write_head(stream, status, reason, &headers).await?;
+    if request.method == "HEAD" {
+        return Ok(());
+    }
+
+    // 读到已写入区间的末尾时跟随下载；长时间没有新数据则提前结束，由播放器重新请求
+    let mut file: Option<tokio::fs::File> = None;
+    let mut pos = start;
+    let mut last_progress = Instant::now();
+    while pos <= end {
+        let Some(readable_end) = media.ranges.covered_end(pos) else {
+            if !media.growing || last_progress.elapsed() > TAIL_STALL_TIMEOUT {
+                break;
+            }
+            // 离顺序下载位置较远：提示下载任务从这里预取（进行中的预取会沿用）
+            if pos >= media.available.saturating_add(TAIL_WAIT_BYTES)
+                && !(server.seek_hint)(video_id, pos)
+            {
+                break;
+            }
+            tokio::time::sleep(TAIL_POLL_INTERVAL).await;
+            let Some(latest) = resolve(video_id) else {
+                break;
+            };
+            if latest.ranges != media.ranges {
+                last_progress = Instant::now();
+            }
+            media = latest;
+            continue;
+        };
+        let file = match file.as_mut() {
+            Some(file) => file,
+            None => {
+                let mut opened = tokio::fs::File::open(&media.path).await?;
+                opened.seek(std::io::SeekFrom::Start(pos)).await?;
+                file.insert(opened)
+            }
+        };
+        let n = (end + 1).min(readable_end) - pos;
+        let copied = tokio::io::copy(&mut file.take(n), stream).await?;
+        if copied == 0 {
+            break;
+        }
+        pos += copied;
+    }
+    stream.flush().await?;
+    Ok(())
+}
+
+/// 下载未在进行时拖动到尚未下载的位置：从远程获取请求区间并直接返回给播放器
+async fn serve_source_range(
+    stream: &mut TcpStream,
+    request: &Request,
+    media: &LocalMedia,
+    url: &str,
+    start: u64,
+    end: u64,
+    total: u64,
+) -> Result<()> {
+    let resp = network::media_request(url)?
+        .header(reqwest::header::RANGE, format!("bytes={}-{}", start, end))
+        .send()
+        .await;
+    let resp = match resp {
+        Ok(resp) if resp.status() == reqwest::StatusCode::PARTIAL_CONTENT => resp,
+        Ok(resp) => {
+            tracing::warn!("stream_server source_range_failed status={}", resp.status());
+            return write_status(stream, 502, "Bad Gateway").await;
+        }
+        Err(e) => {
+            tracing::warn!("stream_server source_range_failed error={}", e);
+            return write_status(stream, 502, "Bad Gateway").await;
+        }
+    };
+    write_head(
+        stream,
+        206,
+        "Partial Content",
+        &[
+            ("Content-Type", content_type_for(&media.path).to_string()),
+            ("Accept-Ranges", "bytes".to_string()),
+            ("Content-Length", (end - start + 1).to_string()),
+            (
+                "Content-Range",
+                format!("bytes {}-{}/{}", start, end, total),
+            ),
+        ],
+    )
+    .await?;
+    if request.method == "HEAD" {
+        return Ok(());
+    }
+    use futures_util::StreamExt;
+    let mut body = resp.bytes_stream();
+    while let Some(chunk) = body.next().await {
+        stream.write_all(&chunk?).await?;
+    }
+    stream.flush().await?;
+    Ok(())
+}
+
+async fn serve_remote(
+    stream: &mut TcpStream,
+    request: &Request,
+    url: &str,
+    base: &str,
+) -> Result<()> {
+    if !url::Url::parse(url).is_ok_and(|u| matches!(u.scheme(), "http" | "https")) {
+        return write_status(stream, 400, "Bad Request").await;
+    }
+    let mut builder = network::media_request(url)?;
+    if let Some(range) = request.range.as_deref() {
+        builder = builder.header(reqwest::header::RANGE, range);
+    }
+    let resp = match builder.send().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            tracing::warn!("stream_server upstream_failed error={}", e);
+            return write_status(stream, 502, "Bad Gateway").await;
+        }
+    };
+    let status = resp.status();
+    let header = |name: reqwest::header::HeaderName| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let content_type = header(reqwest::header::CONTENT_TYPE);
+
+    if status.is_success() && is_playlist(resp.url(), content_type.as_deref()) {
+        let playlist_url = resp.url().clone();
+        let body = resp.text().await?;
+        let body = rewrite_playlist(&body, &playlist_url, base);
+        write_head(
+            stream,
+            200,
+            "OK",
+            &[
+                ("Content-Type", PLAYLIST_CONTENT_TYPE.to_string()),
+                ("Content-Length", body.len().to_string()),
+                ("Cache-Control", "no-cache".to_string()),
+            ],
+        )
+        .await?;
+        if request.method != "HEAD" {
+            stream.write_all(body.as_bytes()).await?;
+        }
+        return Ok(());
+    }
+
+    let mut headers = Vec::new();
+    for (name, key) in [
+        ("Content-Type", reqwest::header::CONTENT_TYPE),
+        ("Content-Length", reqwest::header::CONTENT_LENGTH),
+        ("Content-Range", reqwest::header::CONTENT_RANGE),
+        ("Accept-Ranges", reqwest::header::ACCEPT_RANGES),
+    ] {
+        if let Some(value) = header(key) {
+            headers.push((name, value));
+        }
+    }
+    write_head(
+        stream,
+        status.as_u16(),
+        status.canonical_reason().unwrap_or(""),
+        &headers,
+    )
+    .await?;
+    if request.method == "HEAD" {
+        return Ok(());
+    }
+    use futures_util::StreamExt;
+    let mut body = resp.bytes_stream();
+    while let Some(chunk) = body.next().await {
+        stream.write_all(&chunk?).await?;
+    }
+    stream.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request() {
+        let head = "GET /t/remote?url=a%20b HTTP/1.1\r\nHost: 127.0.0.1\r\nrange: bytes=10-\r\n";
+        assert_eq!(
+            parse_request(head).unwrap(),
+            Request {
+                method: "GET".to_string(),
+                path: "/t/remote".to_string(),
+                query: Some("url=a%20b".to_string()),
+                range: Some("bytes=10-".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range(None, 100), ByteRange::Full);
+        assert_eq!(
+            parse_range(Some("bytes=0-"), 100),
+            ByteRange::Partial(0, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=10-19"), 100),
+            ByteRange::Partial(10, 19)
+        );
+        // 结束位置超出时截断
+        assert_eq!(
+            parse_range(Some("bytes=90-200"), 100),
+            ByteRange::Partial(90, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=-10"), 100),
+            ByteRange::Partial(90, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=100-"), 100),
+            ByteRange::Unsatisfiable
+        );
+        // 多个范围或无法解析时返回完整内容
+        assert_eq!(parse_range(Some("bytes=0-1,5-6"), 100), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=abc"), 100), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=20-10"), 100), ByteRange::Full);
+    }
+
+    #[test]
+    fn test_plan_local() {
+        let mb = 1024 * 1024;
+        assert_eq!(plan_local(0, true, 10 * mb, false, false), LocalPlan::Disk);
+        // 位于已预取的区间
+        assert_eq!(
+            plan_local(50 * mb, true, 10 * mb, true, true),
+            LocalPlan::Disk
+        );
+        // 刚好在已下载末尾附近：等待下载
+        assert_eq!(
+            plan_local(11 * mb, false, 10 * mb, true, true),
+            LocalPlan::WaitForDownload
+        );
+        // 下载进行中拖动到较远位置：通知下载任务预取
+        assert_eq!(
+            plan_local(50 * mb, false, 10 * mb, true, true),
+            LocalPlan::Prefetch
+        );
+        assert_eq!(
+            plan_local(50 * mb, false, 10 * mb, true, false),
+            LocalPlan::Prefetch
+        );
+        // 下载已暂停：从远程获取，没有远程地址时无法满足
+        assert_eq!(
+            plan_local(11 * mb, false, 10 * mb, false, true),
+            LocalPlan::Remote
+        );
+        assert_eq!(
+            plan_local(11 * mb, false, 10 * mb, false, false),
+            LocalPlan::Unavailable
+        );
+    }
+
+    #[test]
+    fn test_rewrite_playlist() {
+        let playlist_url =
+            url::Url::parse("https://cdn.example.com/v/720/index.m3u8?sig=1").unwrap();
+        let body = "#EXTM3U\n#EXT-X-KEY:METHOD=AES-128,URI=\"key.bin\"\n#EXTINF:4.0,\nseg0.ts\n\n#EXTINF:4.0,\nhttps://other.example.com/seg1.ts\n#EXT-X-ENDLIST";
+        let out = rewrite_playlist(body, &playlist_url, "http://127.0.0.1:1/t");
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "#EXTM3U");
+        assert_eq!(
+            lines[1],
+            format!(
+                "#EXT-X-KEY:METHOD=AES-128,URI=\"http://127.0.0.1:1/t/remote?url={}\"",
+                urlencoding::encode("https://cdn.example.com/v/720/key.bin")
+            )
+        );
+        assert_eq!(
+            lines[3],
+            remote_url(
+                "http://127.0.0.1:1/t",
+                "https://cdn.example.com/v/720/seg0.ts"
+            )
+        );
+        assert_eq!(lines[4], "");
+        assert_eq!(
+            lines[6],
+            remote_url("http://127.0.0.1:1/t", "https://other.example.com/seg1.ts")
+        );
+        assert_eq!(lines[7], "#EXT-X-ENDLIST");
+    }
+}
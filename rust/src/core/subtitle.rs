@@ -0,0 +1,119 @@
+// 字幕格式转换（纯计算）
+// - WebVTT → SRT：跳过文件头与 NOTE / STYLE / REGION 块，时间戳补齐小时并改用逗号分隔毫秒
+// - 去掉 SRT 不支持的提示设置（`align:start` 等）与标签（保留 <b> <i> <u>）
+
+/// 将 WebVTT 字幕转换为 SRT
+pub(crate) fn vtt_to_srt(vtt: &str) -> String {
+    let text = vtt.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut srt = String::new();
+    let mut index = 0;
+    for block in text.split("\n\n") {
+        let lines = block
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .collect::<Vec<_>>();
+        // 提示块：可选的标识行 + 时间行 + 文本
+        let Some(timing_at) = lines.iter().position(|l| l.contains("-->")) else {
+            continue;
+        };
+        if timing_at > 1 {
+            continue;
+        }
+        let Some((start, end)) = parse_timing(lines[timing_at]) else {
+            continue;
+        };
+        index += 1;
+        srt.push_str(&format!("{index}\n{start} --> {end}\n"));
+        for line in &lines[timing_at + 1..] {
+            srt.push_str(&clean_cue_text(line));
+            srt.push('\n');
+        }
+        srt.push('\n');
+    }
+    srt
+}
+
+/// 解析时间行 `00:01.000 --> 00:04.000 align:start`，返回 SRT 格式的起止时间
+fn parse_timing(line: &str) -> Option<(String, String)> {
+    let (start, rest) = line.split_once("-->")?;
+    let end = rest.split_whitespace().next()?;
+    Some((srt_timestamp(start.trim())?, srt_timestamp(end)?))
+}
+
+/// `mm:ss.ttt` / `hh:mm:ss.ttt` → `hh:mm:ss,ttt`
+fn srt_timestamp(value: &str) -> Option<String> {
+    let (clock, millis) = value.split_once('.')?;
+    let parts = clock
+        .split(':')
+        .map(|p| p.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let (h, m, s) = match parts.as_slice() {
+        [m, s] => (0, *m, *s),
+        [h, m, s] => (*h, *m, *s),
+        _ => return None,
+    };
+    let millis = millis.parse::<u64>().ok()?;
+    Some(format!("{h:02}:{m:02}:{s:02},{millis:03}"))
+}
+
+/// 去掉 WebVTT 专用标签（声音、类名、卡拉 OK 时间戳）并还原实体
+fn clean_cue_text(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(open) = rest.find('<') {
+        out.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('>') else {
+            out.push_str(&rest[open..]);
+            rest = "";
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        let name = tag
+            .trim_start_matches('/')
+            .split(['.', ' '])
+            .next()
+            .unwrap_or("");
+        if matches!(name, "b" | "i" | "u") {
+            out.push('<');
+            out.push_str(tag.split(['.', ' ']).next().unwrap_or(""));
+            out.push('>');
+        }
+        rest = &rest[open + close + 1..];
+    }
+    out.push_str(rest);
+    out.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vtt_to_srt() {
+        let vtt = "\u{feff}WEBVTT - demo\r\n\
+            Kind: captions\r\n\
+            \r\n\
+            NOTE 这是注释\r\n\
+            -- 不是提示块\r\n\
+            \r\n\
+            STYLE\r\n\
+            ::cue { color: yellow }\r\n\
+            \r\n\
+            intro\r\n\
+            00:01.000 --> 00:04.500 align:start position:10%\r\n\
+            <v 旁白>你好 &amp; <i>欢迎</i></v>\r\n\
+            <c.yellow>第二行</c>\r\n\
+            \r\n\
+            01:02:03.040 --> 01:02:05.000\r\n\
+            <00:00:01.000><b>再见</b>\r\n";
+        assert_eq!(
+            vtt_to_srt(vtt),
+            "1\n00:00:01,000 --> 00:00:04,500\n你好 & <i>欢迎</i>\n第二行\n\n\
+             2\n01:02:03,040 --> 01:02:05,000\n<b>再见</b>\n\n"
+        );
+        assert_eq!(vtt_to_srt("WEBVTT\n\n"), "");
+    }
+}
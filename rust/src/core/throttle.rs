@@ -0,0 +1,179 @@
+// 下载限速（令牌桶）
+// - 全局限速：所有下载任务共享一个令牌桶
+// - 单任务限速：每个任务一个令牌桶，优先使用任务单独设置，否则使用默认值
+// - 「仅在播放时限速」：开启后只有播放器正在播放在线视频时才限速
+// - 限速值保存在原子变量中，修改后对正在进行的下载立即生效
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// 全局限速（KB/s，0 表示不限）
+static GLOBAL_KB_PER_SEC: AtomicU32 = AtomicU32::new(0);
+/// 单任务默认限速（KB/s，0 表示不限）
+static TASK_KB_PER_SEC: AtomicU32 = AtomicU32::new(0);
+/// 仅在播放器播放在线视频时限速
+static ONLY_WHILE_STREAMING: AtomicBool = AtomicBool::new(false);
+/// 播放器是否正在播放在线视频
+static PLAYER_STREAMING: AtomicBool = AtomicBool::new(false);
+
+/// 令牌桶：容量为 1 秒的流量，允许透支，透支部分换算为等待时间
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    tokens: f64,
+    last: Option<Instant>,
+}
+
+impl TokenBucket {
+    pub fn new() -> Self {
+        Self {
+            tokens: 0.0,
+            last: None,
+        }
+    }
+
+    /// 取走 `bytes` 个令牌，返回需要等待的时长；`rate`（字节/秒）为 0 表示不限速
+    pub fn take(&mut self, rate: u64, bytes: u64, now: Instant) -> Duration {
+        let elapsed = self
+            .last
+            .map(|last| now.saturating_duration_since(last).as_secs_f64())
+            .unwrap_or(0.0);
+        self.last = Some(now);
+        if rate == 0 {
+            self.tokens = 0.0;
+            return Duration::ZERO;
+        }
+        let rate = rate as f64;
+        // 限速调低时丢弃超出新容量的积累
+        self.tokens = (self.tokens + elapsed * rate).min(rate);
+        self.tokens -= bytes as f64;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / rate)
+        }
+    }
+}
+
+fn global_bucket() -> &'static Mutex<TokenBucket> {
+    static BUCKET: OnceLock<Mutex<TokenBucket>> = OnceLock::new();
+    BUCKET.get_or_init(|| Mutex::new(TokenBucket::new()))
+}
+
+/// 任务单独设置的限速（video_id -> KB/s，0 表示该任务不限速）
+fn task_limits() -> &'static Mutex<HashMap<String, u32>> {
+    static LIMITS: OnceLock<Mutex<HashMap<String, u32>>> = OnceLock::new();
+    LIMITS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 更新全局与单任务默认限速
+pub(crate) fn set_limits(global_kb_per_sec: u32, task_kb_per_sec: u32, only_while_streaming: bool) {
+    GLOBAL_KB_PER_SEC.store(global_kb_per_sec, Ordering::Relaxed);
+    TASK_KB_PER_SEC.store(task_kb_per_sec, Ordering::Relaxed);
+    ONLY_WHILE_STREAMING.store(only_while_streaming, Ordering::Relaxed);
+}
+
+/// 更新任务单独设置的限速；为空时恢复使用默认值
+pub(crate) fn set_task_limit(video_id: &str, kb_per_sec: Option<u32>) {
+    let mut limits = task_limits().lock().unwrap();
+    match kb_per_sec {
+        Some(limit) => {
+            limits.insert(video_id.to_string(), limit);
+        }
+        None => {
+            limits.remove(video_id);
+        }
+    }
+}
+
+/// 播放器开始/停止播放在线视频
+pub(crate) fn set_player_streaming(active: bool) {
+    PLAYER_STREAMING.store(active, Ordering::Relaxed);
+}
+
+pub(crate) fn player_streaming() -> bool {
+    PLAYER_STREAMING.load(Ordering::Relaxed)
+}
+
+fn throttling_active() -> bool {
+    !ONLY_WHILE_STREAMING.load(Ordering::Relaxed) || player_streaming()
+}
+
+fn bytes_per_sec(kb_per_sec: u32) -> u64 {
+    u64::from(kb_per_sec) * 1024
+}
+
+/// 单个下载任务的限速器
+#[derive(Debug)]
+pub(crate) struct TaskThrottle {
+    video_id: String,
+    bucket: TokenBucket,
+}
+
+impl TaskThrottle {
+    /// `saved`：数据库中保存的任务单独限速
+    pub fn new(video_id: &str, saved: Option<u32>) -> Self {
+        if saved.is_some() {
+            set_task_limit(video_id, saved);
+        }
+        Self {
+            video_id: video_id.to_string(),
+            bucket: TokenBucket::new(),
+        }
+    }
+
+    /// 记录已读取的字节数，返回读取下一块数据前需要等待的时长
+    pub fn reserve(&mut self, bytes: usize) -> Duration {
+        let now = Instant::now();
+        if !throttling_active() {
+            // 不限速期间也推进时间，避免恢复限速时一次性积累过多令牌
+            self.bucket.take(0, 0, now);
+            global_bucket().lock().unwrap().take(0, 0, now);
+            return Duration::ZERO;
+        }
+        let task_limit = task_limits()
+            .lock()
+            .unwrap()
+            .get(&self.video_id)
+            .copied()
+            .unwrap_or_else(|| TASK_KB_PER_SEC.load(Ordering::Relaxed));
+        let global_limit = GLOBAL_KB_PER_SEC.load(Ordering::Relaxed);
+        let bytes = bytes as u64;
+        let task_wait = self.bucket.take(bytes_per_sec(task_limit), bytes, now);
+        let global_wait =
+            global_bucket()
+                .lock()
+                .unwrap()
+                .take(bytes_per_sec(global_limit), bytes, now);
+        task_wait.max(global_wait)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new();
+
+        // 不限速
+        assert_eq!(bucket.take(0, 1_000_000, start), Duration::ZERO);
+
+        // 1000 B/s：首次取 500 字节需等待 0.5 秒
+        assert_eq!(bucket.take(1000, 500, start), Duration::from_millis(500));
+        // 透支累积：再取 500 字节共需等待 1 秒
+        assert_eq!(bucket.take(1000, 500, start), Duration::from_secs(1));
+        // 2 秒后透支已还清并积累 1 秒（封顶容量），可直接取 1000 字节
+        let later = start + Duration::from_secs(3);
+        assert_eq!(bucket.take(1000, 1000, later), Duration::ZERO);
+
+        // 调低限速时积累的令牌按新容量截断
+        let mut bucket = TokenBucket::new();
+        bucket.take(1000, 0, start);
+        let later = start + Duration::from_secs(10);
+        assert_eq!(bucket.take(100, 200, later), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,172 @@
+// 继续观看与下一集推荐（纯计算，不访问数据库）
+// - 继续观看：已开始观看（超过最短进度）但未看完的视频，按最后观看时间从新到旧
+// - 下一集：系列中最近看完的一集之后第一集未看完的视频；该集已开始观看时归入继续观看
+// - 「看完」与下载清理使用同一比例（cleanup_plan::WATCHED_RATIO）
+
+use crate::core::cleanup_plan::WATCHED_RATIO;
+use std::collections::HashMap;
+
+/// 观看进度低于该值（秒）视为未开始
+pub(crate) const MIN_PROGRESS_SECS: i64 = 10;
+
+/// 观看状态（来自历史记录）
+#[derive(Debug, Clone)]
+pub(crate) struct WatchState {
+    pub video_id: String,
+    /// 观看进度（秒）
+    pub watch_progress: i64,
+    /// 视频总时长（秒），未知时为 0
+    pub total_duration: i64,
+    pub watched_at: i64,
+}
+
+impl WatchState {
+    pub fn is_finished(&self) -> bool {
+        self.total_duration > 0
+            && self.watch_progress as f64 >= self.total_duration as f64 * WATCHED_RATIO
+    }
+
+    /// 已开始但未看完（时长未知时只要开始观看即算）
+    pub fn is_in_progress(&self) -> bool {
+        self.watch_progress >= MIN_PROGRESS_SECS && !self.is_finished()
+    }
+}
+
+/// 下一集推荐
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NextEpisode {
+    /// `series` 中的下标
+    pub series_index: usize,
+    /// 该系列剧集列表中的下标
+    pub episode_index: usize,
+    /// 最近看完的上一集
+    pub previous_video_id: String,
+    /// 上一集的最后观看时间
+    pub watched_at: i64,
+}
+
+/// 继续观看：返回 `history` 中的下标，按最后观看时间从新到旧
+pub(crate) fn continue_watching(history: &[WatchState]) -> Vec<usize> {
+    let mut picked = history
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.is_in_progress())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    picked.sort_by_key(|i| std::cmp::Reverse(history[*i].watched_at));
+    picked
+}
+
+/// 下一集推荐（每个系列最多一条），按上一集的观看时间从新到旧
+///
+/// - `series`：每个系列按集数排序的视频 ID
+pub(crate) fn next_episodes(history: &[WatchState], series: &[Vec<String>]) -> Vec<NextEpisode> {
+    let states = history
+        .iter()
+        .map(|s| (s.video_id.as_str(), s))
+        .collect::<HashMap<_, _>>();
+    let mut result = Vec::new();
+    for (series_index, episodes) in series.iter().enumerate() {
+        let last_finished = episodes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, id)| Some((i, *states.get(id.as_str())?)))
+            .filter(|(_, s)| s.is_finished())
+            .max_by_key(|(_, s)| s.watched_at);
+        let Some((finished_index, finished)) = last_finished else {
+            continue;
+        };
+        let next = episodes
+            .iter()
+            .enumerate()
+            .skip(finished_index + 1)
+            .find(|(_, id)| !states.get(id.as_str()).is_some_and(|s| s.is_finished()));
+        let Some((episode_index, next_id)) = next else {
+            continue;
+        };
+        if states
+            .get(next_id.as_str())
+            .is_some_and(|s| s.is_in_progress())
+        {
+            continue;
+        }
+        result.push(NextEpisode {
+            series_index,
+            episode_index,
+            previous_video_id: finished.video_id.clone(),
+            watched_at: finished.watched_at,
+        });
+    }
+    result.sort_by_key(|n| std::cmp::Reverse(n.watched_at));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(video_id: &str, progress: i64, total: i64, watched_at: i64) -> WatchState {
+        WatchState {
+            video_id: video_id.to_string(),
+            watch_progress: progress,
+            total_duration: total,
+            watched_at,
+        }
+    }
+
+    #[test]
+    fn test_continue_watching() {
+        let history = [
+            state("a", 300, 600, 10),
+            // 接近结尾视为看完
+            state("b", 590, 600, 20),
+            // 刚打开未观看
+            state("c", 3, 600, 30),
+            state("d", 60, 0, 40),
+        ];
+        assert_eq!(continue_watching(&history), vec![3, 0]);
+    }
+
+    #[test]
+    fn test_next_episodes() {
+        let ids = |ids: &[&str]| ids.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let series = [
+            ids(&["s1e1", "s1e2", "s1e3"]),
+            // 最新一集已看完，没有下一集
+            ids(&["s2e1", "s2e2"]),
+            // 下一集已开始观看，归入继续观看
+            ids(&["s3e1", "s3e2"]),
+            // 跳过已看完的集
+            ids(&["s4e1", "s4e2", "s4e3"]),
+            // 没有看完任何一集
+            ids(&["s5e1"]),
+        ];
+        let history = [
+            state("s1e1", 600, 600, 10),
+            state("s1e2", 5, 600, 11),
+            state("s2e2", 600, 600, 20),
+            state("s3e1", 600, 600, 30),
+            state("s3e2", 100, 600, 31),
+            state("s4e1", 600, 600, 40),
+            state("s4e2", 600, 600, 35),
+            state("s5e1", 100, 600, 50),
+        ];
+        assert_eq!(
+            next_episodes(&history, &series),
+            vec![
+                NextEpisode {
+                    series_index: 3,
+                    episode_index: 2,
+                    previous_video_id: "s4e1".to_string(),
+                    watched_at: 40,
+                },
+                NextEpisode {
+                    series_index: 0,
+                    episode_index: 1,
+                    previous_video_id: "s1e1".to_string(),
+                    watched_at: 10,
+                },
+            ]
+        );
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -304094941;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1749195758;
 
 // Section: executor
 
@@ -94,6 +94,47 @@ fn wire__crate__api__download__add_download_impl(
         },
     )
 }
+fn wire__crate__api__download_variants__add_download_variant_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_download_variant",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_variants::add_download_variant(
+                            api_video_id,
+                            api_quality,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__user__add_to_favorites_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -208,7 +249,7 @@ fn wire__crate__api__models__api_app_settings_default_impl(
         },
     )
 }
-fn wire__crate__api__models__api_search_filters_default_impl(
+fn wire__crate__api__models__api_download_bandwidth_settings_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -216,7 +257,7 @@ fn wire__crate__api__models__api_search_filters_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_search_filters_default",
+            debug_name: "api_download_bandwidth_settings_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -233,15 +274,16 @@ fn wire__crate__api__models__api_search_filters_default_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::models::ApiSearchFilters::default())?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::ApiDownloadBandwidthSettings::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__sync__api_web_dav_settings_default_impl(
+fn wire__crate__api__models__api_download_query_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -249,7 +291,7 @@ fn wire__crate__api__sync__api_web_dav_settings_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_web_dav_settings_default",
+            debug_name: "api_download_query_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -267,22 +309,22 @@ fn wire__crate__api__sync__api_web_dav_settings_default_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::sync::ApiWebDavSettings::default())?;
+                        Result::<_, ()>::Ok(crate::api::models::ApiDownloadQuery::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__cache__auto_clean_cache_impl(
+fn wire__crate__api__models__api_download_quota_policy_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "auto_clean_cache",
+            debug_name: "api_download_quota_policy_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -296,26 +338,18 @@ fn wire__crate__api__cache__auto_clean_cache_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_web_expire_ms = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_image_expire_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::cache::auto_clean_cache(
-                            api_web_expire_ms,
-                            api_image_expire_ms,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::ApiDownloadQuotaPolicy::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__cache__cache_size_default_impl(
+fn wire__crate__api__models__api_export_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -323,7 +357,7 @@ fn wire__crate__api__cache__cache_size_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cache_size_default",
+            debug_name: "api_export_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -340,22 +374,23 @@ fn wire__crate__api__cache__cache_size_default_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::cache::CacheSize::default())?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::ApiExportOptions::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__init__check_cloudflare_impl(
+fn wire__crate__api__models__api_search_filters_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_cloudflare",
+            debug_name: "api_search_filters_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -370,27 +405,25 @@ fn wire__crate__api__init__check_cloudflare_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::init::check_cloudflare().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::ApiSearchFilters::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__init__check_network_impl(
+fn wire__crate__api__models__api_smart_folder_rule_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_network",
+            debug_name: "api_smart_folder_rule_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -405,19 +438,50 @@ fn wire__crate__api__init__check_network_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::init::check_network().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::ApiSmartFolderRule::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync__api_web_dav_settings_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_web_dav_settings_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::sync::ApiWebDavSettings::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__sync__cleanup_expired_history_impl(
+fn wire__crate__api__cache__auto_clean_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -425,7 +489,7 @@ fn wire__crate__api__sync__cleanup_expired_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cleanup_expired_history",
+            debug_name: "auto_clean_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -439,11 +503,17 @@ fn wire__crate__api__sync__cleanup_expired_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_web_expire_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_image_expire_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::cleanup_expired_history().await?;
+                        let output_ok = crate::api::cache::auto_clean_cache(
+                            api_web_expire_ms,
+                            api_image_expire_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -452,7 +522,7 @@ fn wire__crate__api__sync__cleanup_expired_history_impl(
         },
     )
 }
-fn wire__crate__api__init__cleanup_logs_impl(
+fn wire__crate__api__cache__cache_size_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -460,7 +530,7 @@ fn wire__crate__api__init__cleanup_logs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cleanup_logs",
+            debug_name: "cache_size_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -474,28 +544,17 @@ fn wire__crate__api__init__cleanup_logs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_path = <String>::sse_decode(&mut deserializer);
-            let api_max_total_bytes = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_max_files = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_max_age_days = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::init::cleanup_logs(
-                            api_data_path,
-                            api_max_total_bytes,
-                            api_max_files,
-                            api_max_age_days,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cache::CacheSize::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__cache__clear_all_cache_impl(
+fn wire__crate__api__download_export__cancel_export_job_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -503,7 +562,7 @@ fn wire__crate__api__cache__clear_all_cache_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_all_cache",
+            debug_name: "cancel_export_job",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -517,11 +576,13 @@ fn wire__crate__api__cache__clear_all_cache_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::cache::clear_all_cache().await?;
+                        let output_ok =
+                            crate::api::download_export::cancel_export_job(api_job_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -530,7 +591,7 @@ fn wire__crate__api__cache__clear_all_cache_impl(
         },
     )
 }
-fn wire__crate__api__init__clear_cookies_impl(
+fn wire__crate__api__init__check_cloudflare_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -538,7 +599,7 @@ fn wire__crate__api__init__clear_cookies_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_cookies",
+            debug_name: "check_cloudflare",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -556,7 +617,7 @@ fn wire__crate__api__init__clear_cookies_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::init::clear_cookies().await?;
+                        let output_ok = crate::api::init::check_cloudflare().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -565,7 +626,7 @@ fn wire__crate__api__init__clear_cookies_impl(
         },
     )
 }
-fn wire__crate__api__cache__clear_image_cache_impl(
+fn wire__crate__api__download_rules__check_download_rules_now_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -573,7 +634,7 @@ fn wire__crate__api__cache__clear_image_cache_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_image_cache",
+            debug_name: "check_download_rules_now",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -591,7 +652,8 @@ fn wire__crate__api__cache__clear_image_cache_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::cache::clear_image_cache().await?;
+                        let output_ok =
+                            crate::api::download_rules::check_download_rules_now().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -600,7 +662,7 @@ fn wire__crate__api__cache__clear_image_cache_impl(
         },
     )
 }
-fn wire__crate__api__user__clear_play_history_impl(
+fn wire__crate__api__init__check_network_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -608,7 +670,7 @@ fn wire__crate__api__user__clear_play_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_play_history",
+            debug_name: "check_network",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -626,7 +688,7 @@ fn wire__crate__api__user__clear_play_history_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::clear_play_history().await?;
+                        let output_ok = crate::api::init::check_network().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -635,7 +697,7 @@ fn wire__crate__api__user__clear_play_history_impl(
         },
     )
 }
-fn wire__crate__api__cache__clear_web_cache_impl(
+fn wire__crate__api__sync__cleanup_expired_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -643,7 +705,7 @@ fn wire__crate__api__cache__clear_web_cache_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_web_cache",
+            debug_name: "cleanup_expired_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -661,7 +723,7 @@ fn wire__crate__api__cache__clear_web_cache_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::cache::clear_web_cache().await?;
+                        let output_ok = crate::api::sync::cleanup_expired_history().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -670,15 +732,15 @@ fn wire__crate__api__cache__clear_web_cache_impl(
         },
     )
 }
-fn wire__crate__api__download_folders__create_download_folder_impl(
+fn wire__crate__api__init__cleanup_logs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_download_folder",
+            debug_name: "cleanup_logs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -692,22 +754,28 @@ fn wire__crate__api__download_folders__create_download_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_data_path = <String>::sse_decode(&mut deserializer);
+            let api_max_total_bytes = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_max_files = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_max_age_days = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::download_folders::create_download_folder(api_name).await?;
+                    (move || {
+                        let output_ok = crate::api::init::cleanup_logs(
+                            api_data_path,
+                            api_max_total_bytes,
+                            api_max_files,
+                            api_max_age_days,
+                        )?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__download__delete_download_impl(
+fn wire__crate__api__cache__clear_all_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -715,7 +783,7 @@ fn wire__crate__api__download__delete_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_download",
+            debug_name: "clear_all_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -729,15 +797,11 @@ fn wire__crate__api__download__delete_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
-            let api_delete_file = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download::delete_download(api_task_id, api_delete_file)
-                                .await?;
+                        let output_ok = crate::api::cache::clear_all_cache().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -746,7 +810,7 @@ fn wire__crate__api__download__delete_download_impl(
         },
     )
 }
-fn wire__crate__api__download_folders__delete_download_folder_impl(
+fn wire__crate__api__init__clear_cookies_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -754,7 +818,7 @@ fn wire__crate__api__download_folders__delete_download_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_download_folder",
+            debug_name: "clear_cookies",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -768,14 +832,11 @@ fn wire__crate__api__download_folders__delete_download_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_folder_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download_folders::delete_download_folder(api_folder_id)
-                                .await?;
+                        let output_ok = crate::api::init::clear_cookies().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -784,7 +845,7 @@ fn wire__crate__api__download_folders__delete_download_folder_impl(
         },
     )
 }
-fn wire__crate__api__user__delete_from_list_impl(
+fn wire__crate__api__cache__clear_image_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -792,7 +853,7 @@ fn wire__crate__api__user__delete_from_list_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_from_list",
+            debug_name: "clear_image_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -806,21 +867,11 @@ fn wire__crate__api__user__delete_from_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_list_type = <String>::sse_decode(&mut deserializer);
-            let api_video_code = <String>::sse_decode(&mut deserializer);
-            let api__form_token = <String>::sse_decode(&mut deserializer);
-            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::delete_from_list(
-                            api_list_type,
-                            api_video_code,
-                            api__form_token,
-                            api_x_csrf_token,
-                        )
-                        .await?;
+                        let output_ok = crate::api::cache::clear_image_cache().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -829,7 +880,7 @@ fn wire__crate__api__user__delete_from_list_impl(
         },
     )
 }
-fn wire__crate__api__settings__delete_kv_impl(
+fn wire__crate__api__user__clear_play_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -837,7 +888,7 @@ fn wire__crate__api__settings__delete_kv_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_kv",
+            debug_name: "clear_play_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -851,12 +902,11 @@ fn wire__crate__api__settings__delete_kv_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::delete_kv(api_key).await?;
+                        let output_ok = crate::api::user::clear_play_history().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -865,7 +915,7 @@ fn wire__crate__api__settings__delete_kv_impl(
         },
     )
 }
-fn wire__crate__api__user__delete_play_history_impl(
+fn wire__crate__api__cache__clear_web_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -873,7 +923,7 @@ fn wire__crate__api__user__delete_play_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_play_history",
+            debug_name: "clear_web_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -887,12 +937,11 @@ fn wire__crate__api__user__delete_play_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::delete_play_history(api_video_id).await?;
+                        let output_ok = crate::api::cache::clear_web_cache().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -901,15 +950,15 @@ fn wire__crate__api__user__delete_play_history_impl(
         },
     )
 }
-fn wire__crate__api__download__export_downloads_to_dir_impl(
+fn wire__crate__api__download_folders__create_download_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_downloads_to_dir",
+            debug_name: "create_download_folder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -923,37 +972,34 @@ fn wire__crate__api__download__export_downloads_to_dir_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_ids = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_dest_dir = <String>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::models::ApiExportProgress,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::download::export_downloads_to_dir(
-                            api_task_ids,
-                            api_dest_dir,
-                            api_sink,
-                        );
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::create_download_folder(
+                            api_name,
+                            api_parent_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__init__export_logs_zip_impl(
+fn wire__crate__api__download_rules__create_download_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_logs_zip",
+            debug_name: "create_download_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -967,19 +1013,22 @@ fn wire__crate__api__init__export_logs_zip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule = <crate::api::models::ApiDownloadRule>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::init::export_logs_zip()?;
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_rules::create_download_rule(api_rule).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__sync__force_upload_history_impl(
+fn wire__crate__api__download_folders__create_smart_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -987,7 +1036,7 @@ fn wire__crate__api__sync__force_upload_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "force_upload_history",
+            debug_name: "create_smart_folder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1001,11 +1050,19 @@ fn wire__crate__api__sync__force_upload_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_rule = <crate::api::models::ApiSmartFolderRule>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::force_upload_history().await?;
+                        let output_ok = crate::api::download_folders::create_smart_folder(
+                            api_name,
+                            api_parent_id,
+                            api_rule,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1014,7 +1071,7 @@ fn wire__crate__api__sync__force_upload_history_impl(
         },
     )
 }
-fn wire__crate__api__download__get_all_downloads_impl(
+fn wire__crate__api__download__delete_download_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1022,7 +1079,7 @@ fn wire__crate__api__download__get_all_downloads_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_downloads",
+            debug_name: "delete_download",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1036,11 +1093,15 @@ fn wire__crate__api__download__get_all_downloads_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_delete_file = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download::get_all_downloads().await?;
+                        let output_ok =
+                            crate::api::download::delete_download(api_task_id, api_delete_file)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1049,15 +1110,15 @@ fn wire__crate__api__download__get_all_downloads_impl(
         },
     )
 }
-fn wire__crate__api__settings__get_app_version_impl(
+fn wire__crate__api__download_folders__delete_download_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_app_version",
+            debug_name: "delete_download_folder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1071,25 +1132,31 @@ fn wire__crate__api__settings__get_app_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::settings::get_app_version())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_folders::delete_download_folder(api_folder_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__cache__get_cache_size_impl(
+fn wire__crate__api__download_rules__delete_download_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cache_size",
+            debug_name: "delete_download_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1103,19 +1170,22 @@ fn wire__crate__api__cache__get_cache_size_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::cache::get_cache_size()?;
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_rules::delete_download_rule(api_rule_id).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__settings__get_cache_size_impl(
+fn wire__crate__api__download_variants__delete_download_variant_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1123,7 +1193,7 @@ fn wire__crate__api__settings__get_cache_size_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cache_size",
+            debug_name: "delete_download_variant",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1137,11 +1207,17 @@ fn wire__crate__api__settings__get_cache_size_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_variant_id = <i64>::sse_decode(&mut deserializer);
+            let api_delete_file = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::get_cache_size().await?;
+                        let output_ok = crate::api::download_variants::delete_download_variant(
+                            api_variant_id,
+                            api_delete_file,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1150,7 +1226,7 @@ fn wire__crate__api__settings__get_cache_size_impl(
         },
     )
 }
-fn wire__crate__api__user__get_cloudflare_challenge_info_impl(
+fn wire__crate__api__user__delete_from_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1158,7 +1234,7 @@ fn wire__crate__api__user__get_cloudflare_challenge_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cloudflare_challenge_info",
+            debug_name: "delete_from_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1172,11 +1248,21 @@ fn wire__crate__api__user__get_cloudflare_challenge_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_type = <String>::sse_decode(&mut deserializer);
+            let api_video_code = <String>::sse_decode(&mut deserializer);
+            let api__form_token = <String>::sse_decode(&mut deserializer);
+            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::get_cloudflare_challenge_info().await?;
+                        let output_ok = crate::api::user::delete_from_list(
+                            api_list_type,
+                            api_video_code,
+                            api__form_token,
+                            api_x_csrf_token,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1185,7 +1271,7 @@ fn wire__crate__api__user__get_cloudflare_challenge_info_impl(
         },
     )
 }
-fn wire__crate__api__video__get_comment_replies_impl(
+fn wire__crate__api__settings__delete_kv_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1193,7 +1279,7 @@ fn wire__crate__api__video__get_comment_replies_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_comment_replies",
+            debug_name: "delete_kv",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1207,13 +1293,12 @@ fn wire__crate__api__video__get_comment_replies_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_comment_id = <String>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::video::get_comment_replies(api_comment_id).await?;
+                        let output_ok = crate::api::settings::delete_kv(api_key).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1222,7 +1307,7 @@ fn wire__crate__api__video__get_comment_replies_impl(
         },
     )
 }
-fn wire__crate__api__user__get_current_user_impl(
+fn wire__crate__api__download_reconcile__delete_orphan_files_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1230,7 +1315,7 @@ fn wire__crate__api__user__get_current_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_current_user",
+            debug_name: "delete_orphan_files",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1244,11 +1329,13 @@ fn wire__crate__api__user__get_current_user_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_files = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::get_current_user().await?;
+                        let output_ok =
+                            crate::api::download_reconcile::delete_orphan_files(api_files).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1257,7 +1344,7 @@ fn wire__crate__api__user__get_current_user_impl(
         },
     )
 }
-fn wire__crate__api__settings__get_data_dir_path_impl(
+fn wire__crate__api__user__delete_play_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1265,7 +1352,7 @@ fn wire__crate__api__settings__get_data_dir_path_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_data_dir_path",
+            debug_name: "delete_play_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1279,11 +1366,12 @@ fn wire__crate__api__settings__get_data_dir_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::get_data_dir_path().await?;
+                        let output_ok = crate::api::user::delete_play_history(api_video_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1292,7 +1380,7 @@ fn wire__crate__api__settings__get_data_dir_path_impl(
         },
     )
 }
-fn wire__crate__api__download_folders__get_download_folders_impl(
+fn wire__crate__api__download_batch__download_playlist_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1300,7 +1388,7 @@ fn wire__crate__api__download_folders__get_download_folders_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_download_folders",
+            debug_name: "download_playlist",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1314,12 +1402,19 @@ fn wire__crate__api__download_folders__get_download_folders_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <String>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
+            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download_folders::get_download_folders().await?;
+                        let output_ok = crate::api::download_batch::download_playlist(
+                            api_list_id,
+                            api_quality,
+                            api_folder_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1328,7 +1423,7 @@ fn wire__crate__api__download_folders__get_download_folders_impl(
         },
     )
 }
-fn wire__crate__api__download__get_downloads_by_status_impl(
+fn wire__crate__api__download_batch__download_search_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1336,7 +1431,7 @@ fn wire__crate__api__download__get_downloads_by_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_downloads_by_status",
+            debug_name: "download_search",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1350,13 +1445,23 @@ fn wire__crate__api__download__get_downloads_by_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_status = <String>::sse_decode(&mut deserializer);
+            let api_filters = <crate::api::models::ApiSearchFilters>::sse_decode(&mut deserializer);
+            let api_max_pages = <u32>::sse_decode(&mut deserializer);
+            let api_artist = <Option<String>>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
+            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download::get_downloads_by_status(api_status).await?;
+                        let output_ok = crate::api::download_batch::download_search(
+                            api_filters,
+                            api_max_pages,
+                            api_artist,
+                            api_quality,
+                            api_folder_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1365,7 +1470,7 @@ fn wire__crate__api__download__get_downloads_by_status_impl(
         },
     )
 }
-fn wire__crate__api__user__get_favorites_impl(
+fn wire__crate__api__download_batch__download_series_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1373,7 +1478,7 @@ fn wire__crate__api__user__get_favorites_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_favorites",
+            debug_name: "download_series",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1387,12 +1492,19 @@ fn wire__crate__api__user__get_favorites_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
+            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::get_favorites(api_page).await?;
+                        let output_ok = crate::api::download_batch::download_series(
+                            api_video_id,
+                            api_quality,
+                            api_folder_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1401,15 +1513,15 @@ fn wire__crate__api__user__get_favorites_impl(
         },
     )
 }
-fn wire__crate__api__search__get_filter_options_impl(
+fn wire__crate__api__download_bundle__export_download_bundle_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_filter_options",
+            debug_name: "export_download_bundle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1423,28 +1535,39 @@ fn wire__crate__api__search__get_filter_options_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_dest_path = <String>::sse_decode(&mut deserializer);
+            let api_as_zip = <bool>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiBundleProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::search::get_filter_options().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download_bundle::export_download_bundle(
+                            api_task_ids,
+                            api_dest_path,
+                            api_as_zip,
+                            api_sink,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__settings__get_flutter_settings_impl(
+fn wire__crate__api__download__export_downloads_to_dir_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_flutter_settings",
+            debug_name: "export_downloads_to_dir",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1458,28 +1581,37 @@ fn wire__crate__api__settings__get_flutter_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_dest_dir = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiExportProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::settings::get_flutter_settings().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download::export_downloads_to_dir(
+                            api_task_ids,
+                            api_dest_dir,
+                            api_sink,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__search__get_home_videos_impl(
+fn wire__crate__api__download_export__export_downloads_with_options_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_home_videos",
+            debug_name: "export_downloads_with_options",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1493,29 +1625,39 @@ fn wire__crate__api__search__get_home_videos_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_page = <u32>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::search::get_home_videos(api_page).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            let api_task_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_dest_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::models::ApiExportOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiExportProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download_export::export_downloads_with_options(
+                            api_task_ids,
+                            api_dest_dir,
+                            api_options,
+                            api_sink,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__search__get_homepage_impl(
+fn wire__crate__api__init__export_logs_zip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_homepage",
+            debug_name: "export_logs_zip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1530,19 +1672,18 @@ fn wire__crate__api__search__get_homepage_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::search::get_homepage().await?;
+                    (move || {
+                        let output_ok = crate::api::init::export_logs_zip()?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__settings__get_kv_impl(
+fn wire__crate__api__sync__force_upload_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1550,7 +1691,7 @@ fn wire__crate__api__settings__get_kv_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_kv",
+            debug_name: "force_upload_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1564,12 +1705,11 @@ fn wire__crate__api__settings__get_kv_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::get_kv(api_key).await?;
+                        let output_ok = crate::api::sync::force_upload_history().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1578,7 +1718,7 @@ fn wire__crate__api__settings__get_kv_impl(
         },
     )
 }
-fn wire__crate__api__sync__get_last_sync_time_impl(
+fn wire__crate__api__download_export__get_active_export_jobs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1586,7 +1726,7 @@ fn wire__crate__api__sync__get_last_sync_time_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_last_sync_time",
+            debug_name: "get_active_export_jobs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1604,7 +1744,8 @@ fn wire__crate__api__sync__get_last_sync_time_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::get_last_sync_time().await?;
+                        let output_ok =
+                            crate::api::download_export::get_active_export_jobs().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1613,7 +1754,7 @@ fn wire__crate__api__sync__get_last_sync_time_impl(
         },
     )
 }
-fn wire__crate__api__download__get_local_video_path_impl(
+fn wire__crate__api__download__get_all_downloads_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1621,7 +1762,7 @@ fn wire__crate__api__download__get_local_video_path_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_local_video_path",
+            debug_name: "get_all_downloads",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1635,13 +1776,11 @@ fn wire__crate__api__download__get_local_video_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download::get_local_video_path(api_video_id).await?;
+                        let output_ok = crate::api::download::get_all_downloads().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1650,15 +1789,15 @@ fn wire__crate__api__download__get_local_video_path_impl(
         },
     )
 }
-fn wire__crate__api__user__get_login_form_token_impl(
+fn wire__crate__api__settings__get_app_version_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_login_form_token",
+            debug_name: "get_app_version",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1673,27 +1812,24 @@ fn wire__crate__api__user__get_login_form_token_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::user::get_login_form_token().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::settings::get_app_version())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__user__get_my_list_impl(
+fn wire__crate__api__cache__get_cache_size_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_my_list",
+            debug_name: "get_cache_size",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1707,23 +1843,19 @@ fn wire__crate__api__user__get_my_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_list_type = <String>::sse_decode(&mut deserializer);
-            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::user::get_my_list(api_list_type, api_page).await?;
+                    (move || {
+                        let output_ok = crate::api::cache::get_cache_size()?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__user__get_my_subscriptions_impl(
+fn wire__crate__api__settings__get_cache_size_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1731,7 +1863,7 @@ fn wire__crate__api__user__get_my_subscriptions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_my_subscriptions",
+            debug_name: "get_cache_size",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1745,14 +1877,11 @@ fn wire__crate__api__user__get_my_subscriptions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_page = <u32>::sse_decode(&mut deserializer);
-            let api_query = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::user::get_my_subscriptions(api_page, api_query).await?;
+                        let output_ok = crate::api::settings::get_cache_size().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1761,7 +1890,7 @@ fn wire__crate__api__user__get_my_subscriptions_impl(
         },
     )
 }
-fn wire__crate__api__user__get_play_history_impl(
+fn wire__crate__api__user__get_cloudflare_challenge_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1769,7 +1898,7 @@ fn wire__crate__api__user__get_play_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_play_history",
+            debug_name: "get_cloudflare_challenge_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1783,14 +1912,11 @@ fn wire__crate__api__user__get_play_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_page = <u32>::sse_decode(&mut deserializer);
-            let api_page_size = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::user::get_play_history(api_page, api_page_size).await?;
+                        let output_ok = crate::api::user::get_cloudflare_challenge_info().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1799,7 +1925,7 @@ fn wire__crate__api__user__get_play_history_impl(
         },
     )
 }
-fn wire__crate__api__settings__get_settings_impl(
+fn wire__crate__api__video__get_comment_replies_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1807,7 +1933,7 @@ fn wire__crate__api__settings__get_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_settings",
+            debug_name: "get_comment_replies",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1821,11 +1947,13 @@ fn wire__crate__api__settings__get_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_comment_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::get_settings().await?;
+                        let output_ok =
+                            crate::api::video::get_comment_replies(api_comment_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1834,7 +1962,7 @@ fn wire__crate__api__settings__get_settings_impl(
         },
     )
 }
-fn wire__crate__api__user__get_subscribed_authors_impl(
+fn wire__crate__api__user__get_current_user_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1842,7 +1970,7 @@ fn wire__crate__api__user__get_subscribed_authors_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_subscribed_authors",
+            debug_name: "get_current_user",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1856,12 +1984,11 @@ fn wire__crate__api__user__get_subscribed_authors_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::get_subscribed_authors(api_page).await?;
+                        let output_ok = crate::api::user::get_current_user().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1870,15 +1997,15 @@ fn wire__crate__api__user__get_subscribed_authors_impl(
         },
     )
 }
-fn wire__crate__api__init__get_version_impl(
+fn wire__crate__api__settings__get_data_dir_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_version",
+            debug_name: "get_data_dir_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1893,16 +2020,19 @@ fn wire__crate__api__init__get_version_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::init::get_version())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::settings::get_data_dir_path().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__video__get_video_comments_impl(
+fn wire__crate__api__download__get_download_available_ranges_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1910,7 +2040,7 @@ fn wire__crate__api__video__get_video_comments_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_comments",
+            debug_name: "get_download_available_ranges",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1924,14 +2054,14 @@ fn wire__crate__api__video__get_video_comments_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
-            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::video::get_video_comments(api_video_id, api_page).await?;
+                            crate::api::download::get_download_available_ranges(api_task_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1940,7 +2070,7 @@ fn wire__crate__api__video__get_video_comments_impl(
         },
     )
 }
-fn wire__crate__api__video__get_video_detail_impl(
+fn wire__crate__api__download_throttle__get_download_bandwidth_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1948,7 +2078,7 @@ fn wire__crate__api__video__get_video_detail_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_detail",
+            debug_name: "get_download_bandwidth_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1962,12 +2092,13 @@ fn wire__crate__api__video__get_video_detail_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::video::get_video_detail(api_video_id).await?;
+                        let output_ok =
+                            crate::api::download_throttle::get_download_bandwidth_settings()
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1976,7 +2107,7 @@ fn wire__crate__api__video__get_video_detail_impl(
         },
     )
 }
-fn wire__crate__api__user__get_video_progress_impl(
+fn wire__crate__api__download_folders__get_download_folders_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1984,7 +2115,7 @@ fn wire__crate__api__user__get_video_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_progress",
+            debug_name: "get_download_folders",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1998,12 +2129,12 @@ fn wire__crate__api__user__get_video_progress_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::get_video_progress(api_video_id).await?;
+                        let output_ok =
+                            crate::api::download_folders::get_download_folders().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2012,7 +2143,7 @@ fn wire__crate__api__user__get_video_progress_impl(
         },
     )
 }
-fn wire__crate__api__video__get_video_url_impl(
+fn wire__crate__api__download_quota__get_download_quota_policy_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2020,7 +2151,7 @@ fn wire__crate__api__video__get_video_url_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_url",
+            debug_name: "get_download_quota_policy",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2034,14 +2165,12 @@ fn wire__crate__api__video__get_video_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
-            let api_quality = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::video::get_video_url(api_video_id, api_quality).await?;
+                            crate::api::download_quota::get_download_quota_policy().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2050,7 +2179,7 @@ fn wire__crate__api__video__get_video_url_impl(
         },
     )
 }
-fn wire__crate__api__sync__get_webdav_settings_impl(
+fn wire__crate__api__download_rules__get_download_rules_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2058,7 +2187,7 @@ fn wire__crate__api__sync__get_webdav_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_webdav_settings",
+            debug_name: "get_download_rules",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2076,7 +2205,7 @@ fn wire__crate__api__sync__get_webdav_settings_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::get_webdav_settings().await?;
+                        let output_ok = crate::api::download_rules::get_download_rules().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2085,16 +2214,17 @@ fn wire__crate__api__sync__get_webdav_settings_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__download__get_download_storage_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_download_storage_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2106,16 +2236,20 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::get_download_storage_info().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__init__init_app_impl(
+fn wire__crate__api__download_subtitles__get_download_subtitles_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2123,7 +2257,7 @@ fn wire__crate__api__init__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_download_subtitles",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2137,12 +2271,14 @@ fn wire__crate__api__init__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_path = <String>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::init::init_app(api_data_path).await?;
+                        let output_ok =
+                            crate::api::download_subtitles::get_download_subtitles(api_task_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2151,7 +2287,7 @@ fn wire__crate__api__init__init_app_impl(
         },
     )
 }
-fn wire__crate__api__settings__init_app_impl(
+fn wire__crate__api__download_throttle__get_download_task_bandwidth_limit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2159,7 +2295,7 @@ fn wire__crate__api__settings__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_download_task_bandwidth_limit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2173,14 +2309,16 @@ fn wire__crate__api__settings__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_cache_dir = <String>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::settings::init_app(api_data_dir, api_cache_dir).await?;
+                            crate::api::download_throttle::get_download_task_bandwidth_limit(
+                                api_task_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2189,15 +2327,15 @@ fn wire__crate__api__settings__init_app_impl(
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__download_events__get_download_timeline_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_download_timeline",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2211,19 +2349,22 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_events::get_download_timeline(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__user__is_logged_in_impl(
+fn wire__crate__api__download_variants__get_download_variants_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2231,7 +2372,7 @@ fn wire__crate__api__user__is_logged_in_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_logged_in",
+            debug_name: "get_download_variants",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2245,11 +2386,14 @@ fn wire__crate__api__user__is_logged_in_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::is_logged_in().await?;
+                        let output_ok =
+                            crate::api::download_variants::get_download_variants(api_video_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2258,7 +2402,7 @@ fn wire__crate__api__user__is_logged_in_impl(
         },
     )
 }
-fn wire__crate__api__video__like_comment_impl(
+fn wire__crate__api__download__get_downloads_by_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2266,7 +2410,7 @@ fn wire__crate__api__video__like_comment_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "like_comment",
+            debug_name: "get_downloads_by_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2280,12 +2424,13 @@ fn wire__crate__api__video__like_comment_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api__comment_id = <String>::sse_decode(&mut deserializer);
+            let api_status = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::video::like_comment(api__comment_id).await?;
+                        let output_ok =
+                            crate::api::download::get_downloads_by_status(api_status).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2294,7 +2439,7 @@ fn wire__crate__api__video__like_comment_impl(
         },
     )
 }
-fn wire__crate__api__cache__load_cached_image_impl(
+fn wire__crate__api__download_relocate__get_downloads_root_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2302,7 +2447,7 @@ fn wire__crate__api__cache__load_cached_image_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_cached_image",
+            debug_name: "get_downloads_root_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2316,12 +2461,12 @@ fn wire__crate__api__cache__load_cached_image_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::cache::load_cached_image(api_url).await?;
+                        let output_ok =
+                            crate::api::download_relocate::get_downloads_root_path().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2330,15 +2475,15 @@ fn wire__crate__api__cache__load_cached_image_impl(
         },
     )
 }
-fn wire__crate__api__user__login_impl(
+fn wire__crate__api__download_export__get_export_template_tokens_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "login",
+            debug_name: "get_export_template_tokens",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2352,30 +2497,19 @@ fn wire__crate__api__user__login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_email = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
-            let api_form_token = <String>::sse_decode(&mut deserializer);
-            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::user::login(
-                            api_email,
-                            api_password,
-                            api_form_token,
-                            api_x_csrf_token,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::download_export::get_export_template_tokens(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__user__logout_impl(
+fn wire__crate__api__user__get_favorites_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2383,7 +2517,7 @@ fn wire__crate__api__user__logout_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "logout",
+            debug_name: "get_favorites",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2397,11 +2531,12 @@ fn wire__crate__api__user__logout_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::logout().await?;
+                        let output_ok = crate::api::user::get_favorites(api_page).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2410,7 +2545,7 @@ fn wire__crate__api__user__logout_impl(
         },
     )
 }
-fn wire__crate__api__download_folders__move_downloads_to_folder_impl(
+fn wire__crate__api__search__get_filter_options_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2418,7 +2553,7 @@ fn wire__crate__api__download_folders__move_downloads_to_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "move_downloads_to_folder",
+            debug_name: "get_filter_options",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2432,17 +2567,11 @@ fn wire__crate__api__download_folders__move_downloads_to_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_ids = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download_folders::move_downloads_to_folder(
-                            api_video_ids,
-                            api_folder_id,
-                        )
-                        .await?;
+                        let output_ok = crate::api::search::get_filter_options().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2451,7 +2580,7 @@ fn wire__crate__api__download_folders__move_downloads_to_folder_impl(
         },
     )
 }
-fn wire__crate__api__download__pause_all_downloads_impl(
+fn wire__crate__api__settings__get_flutter_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2459,7 +2588,7 @@ fn wire__crate__api__download__pause_all_downloads_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pause_all_downloads",
+            debug_name: "get_flutter_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2477,7 +2606,7 @@ fn wire__crate__api__download__pause_all_downloads_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download::pause_all_downloads().await?;
+                        let output_ok = crate::api::settings::get_flutter_settings().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2486,7 +2615,7 @@ fn wire__crate__api__download__pause_all_downloads_impl(
         },
     )
 }
-fn wire__crate__api__download__pause_download_impl(
+fn wire__crate__api__download_folders__get_folder_downloads_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2494,7 +2623,7 @@ fn wire__crate__api__download__pause_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pause_download",
+            debug_name: "get_folder_downloads",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2508,12 +2637,17 @@ fn wire__crate__api__download__pause_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
+            let api_include_subfolders = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download::pause_download(api_task_id).await?;
+                        let output_ok = crate::api::download_folders::get_folder_downloads(
+                            api_folder_id,
+                            api_include_subfolders,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2522,7 +2656,7 @@ fn wire__crate__api__download__pause_download_impl(
         },
     )
 }
-fn wire__crate__api__video__post_comment_impl(
+fn wire__crate__api__search__get_home_videos_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2530,7 +2664,7 @@ fn wire__crate__api__video__post_comment_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "post_comment",
+            debug_name: "get_home_videos",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2544,19 +2678,12 @@ fn wire__crate__api__video__post_comment_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_reply_to = <Option<String>>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::video::post_comment(
-                            api_video_id,
-                            api_content,
-                            api_reply_to,
-                        )
-                        .await?;
+                        let output_ok = crate::api::search::get_home_videos(api_page).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2565,15 +2692,15 @@ fn wire__crate__api__video__post_comment_impl(
         },
     )
 }
-fn wire__crate__api__init__prepare_logs_for_sharing_impl(
+fn wire__crate__api__search__get_homepage_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "prepare_logs_for_sharing",
+            debug_name: "get_homepage",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2588,18 +2715,19 @@ fn wire__crate__api__init__prepare_logs_for_sharing_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::init::prepare_logs_for_sharing()?;
+                    (move || async move {
+                        let output_ok = crate::api::search::get_homepage().await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__user__reload_user_agent_impl(
+fn wire__crate__api__settings__get_kv_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2607,7 +2735,7 @@ fn wire__crate__api__user__reload_user_agent_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reload_user_agent",
+            debug_name: "get_kv",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2621,11 +2749,12 @@ fn wire__crate__api__user__reload_user_agent_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::reload_user_agent().await?;
+                        let output_ok = crate::api::settings::get_kv(api_key).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2634,7 +2763,7 @@ fn wire__crate__api__user__reload_user_agent_impl(
         },
     )
 }
-fn wire__crate__api__user__remove_from_favorites_impl(
+fn wire__crate__api__sync__get_last_sync_time_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2642,7 +2771,7 @@ fn wire__crate__api__user__remove_from_favorites_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_from_favorites",
+            debug_name: "get_last_sync_time",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2656,21 +2785,11 @@ fn wire__crate__api__user__remove_from_favorites_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_code = <String>::sse_decode(&mut deserializer);
-            let api_form_token = <String>::sse_decode(&mut deserializer);
-            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::remove_from_favorites(
-                            api_video_code,
-                            api_form_token,
-                            api_x_csrf_token,
-                            api_user_id,
-                        )
-                        .await?;
+                        let output_ok = crate::api::sync::get_last_sync_time().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2679,7 +2798,7 @@ fn wire__crate__api__user__remove_from_favorites_impl(
         },
     )
 }
-fn wire__crate__api__video__remove_from_favorites_impl(
+fn wire__crate__api__stream__get_local_stream_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2687,7 +2806,7 @@ fn wire__crate__api__video__remove_from_favorites_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_from_favorites",
+            debug_name: "get_local_stream_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2701,13 +2820,13 @@ fn wire__crate__api__video__remove_from_favorites_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api__video_id = <String>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::video::remove_from_favorites(api__video_id).await?;
+                            crate::api::stream::get_local_stream_url(api_video_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2716,7 +2835,7 @@ fn wire__crate__api__video__remove_from_favorites_impl(
         },
     )
 }
-fn wire__crate__api__download_folders__rename_download_folder_impl(
+fn wire__crate__api__download__get_local_video_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2724,7 +2843,7 @@ fn wire__crate__api__download_folders__rename_download_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rename_download_folder",
+            debug_name: "get_local_video_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2738,17 +2857,13 @@ fn wire__crate__api__download_folders__rename_download_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_folder_id = <String>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download_folders::rename_download_folder(
-                            api_folder_id,
-                            api_name,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::download::get_local_video_path(api_video_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2757,15 +2872,15 @@ fn wire__crate__api__download_folders__rename_download_folder_impl(
         },
     )
 }
-fn wire__crate__api__init__report_flutter_error_impl(
+fn wire__crate__api__user__get_login_form_token_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "report_flutter_error",
+            debug_name: "get_login_form_token",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2779,29 +2894,28 @@ fn wire__crate__api__init__report_flutter_error_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_stack = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init::report_flutter_error(api_message, api_stack);
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::get_login_form_token().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__init__report_flutter_log_impl(
+fn wire__crate__api__user__get_my_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "report_flutter_log",
+            debug_name: "get_my_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2815,28 +2929,23 @@ fn wire__crate__api__init__report_flutter_log_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
-            let api_stack = <Option<String>>::sse_decode(&mut deserializer);
+            let api_list_type = <String>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init::report_flutter_log(
-                            api_level,
-                            api_message,
-                            api_tag,
-                            api_stack,
-                        );
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::user::get_my_list(api_list_type, api_page).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__download__resume_all_downloads_impl(
+fn wire__crate__api__user__get_my_subscriptions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2844,7 +2953,7 @@ fn wire__crate__api__download__resume_all_downloads_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resume_all_downloads",
+            debug_name: "get_my_subscriptions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2858,11 +2967,14 @@ fn wire__crate__api__download__resume_all_downloads_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_query = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download::resume_all_downloads().await?;
+                        let output_ok =
+                            crate::api::user::get_my_subscriptions(api_page, api_query).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2871,7 +2983,7 @@ fn wire__crate__api__download__resume_all_downloads_impl(
         },
     )
 }
-fn wire__crate__api__download__resume_download_impl(
+fn wire__crate__api__user__get_play_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2879,7 +2991,7 @@ fn wire__crate__api__download__resume_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resume_download",
+            debug_name: "get_play_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2893,12 +3005,14 @@ fn wire__crate__api__download__resume_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::download::resume_download(api_task_id).await?;
+                        let output_ok =
+                            crate::api::user::get_play_history(api_page, api_page_size).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2907,7 +3021,7 @@ fn wire__crate__api__download__resume_download_impl(
         },
     )
 }
-fn wire__crate__api__settings__save_flutter_settings_impl(
+fn wire__crate__api__stream__get_proxied_stream_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2915,7 +3029,7 @@ fn wire__crate__api__settings__save_flutter_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_flutter_settings",
+            debug_name: "get_proxied_stream_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2929,13 +3043,12 @@ fn wire__crate__api__settings__save_flutter_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_json = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::settings::save_flutter_settings(api_json).await?;
+                        let output_ok = crate::api::stream::get_proxied_stream_url(api_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2944,7 +3057,7 @@ fn wire__crate__api__settings__save_flutter_settings_impl(
         },
     )
 }
-fn wire__crate__api__settings__save_settings_impl(
+fn wire__crate__api__settings__get_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2952,7 +3065,7 @@ fn wire__crate__api__settings__save_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_settings",
+            debug_name: "get_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2966,12 +3079,11 @@ fn wire__crate__api__settings__save_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_settings = <crate::api::models::ApiAppSettings>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::save_settings(api_settings).await?;
+                        let output_ok = crate::api::settings::get_settings().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2980,7 +3092,7 @@ fn wire__crate__api__settings__save_settings_impl(
         },
     )
 }
-fn wire__crate__api__sync__save_webdav_settings_impl(
+fn wire__crate__api__download_folders__get_smart_folder_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2988,7 +3100,7 @@ fn wire__crate__api__sync__save_webdav_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_webdav_settings",
+            debug_name: "get_smart_folder_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3002,13 +3114,14 @@ fn wire__crate__api__sync__save_webdav_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_settings = <crate::api::sync::ApiWebDavSettings>::sse_decode(&mut deserializer);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::sync::save_webdav_settings(api_settings).await?;
+                            crate::api::download_folders::get_smart_folder_rule(api_folder_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3017,7 +3130,7 @@ fn wire__crate__api__sync__save_webdav_settings_impl(
         },
     )
 }
-fn wire__crate__api__search__search_impl(
+fn wire__crate__api__user__get_subscribed_authors_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3025,7 +3138,7 @@ fn wire__crate__api__search__search_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search",
+            debug_name: "get_subscribed_authors",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3039,12 +3152,12 @@ fn wire__crate__api__search__search_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_filters = <crate::api::models::ApiSearchFilters>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::search::search(api_filters).await?;
+                        let output_ok = crate::api::user::get_subscribed_authors(api_page).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3053,15 +3166,15 @@ fn wire__crate__api__search__search_impl(
         },
     )
 }
-fn wire__crate__api__user__set_cf_clearance_impl(
+fn wire__crate__api__init__get_version_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_cf_clearance",
+            debug_name: "get_version",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3075,22 +3188,17 @@ fn wire__crate__api__user__set_cf_clearance_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_cookie_value = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::user::set_cf_clearance(api_cookie_value).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::init::get_version())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__init__set_cookies_impl(
+fn wire__crate__api__video__get_video_comments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3098,7 +3206,7 @@ fn wire__crate__api__init__set_cookies_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_cookies",
+            debug_name: "get_video_comments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3112,14 +3220,14 @@ fn wire__crate__api__init__set_cookies_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_cookie_string = <String>::sse_decode(&mut deserializer);
-            let api_domain = <Option<String>>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::init::set_cookies(api_cookie_string, api_domain).await?;
+                            crate::api::video::get_video_comments(api_video_id, api_page).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3128,7 +3236,7 @@ fn wire__crate__api__init__set_cookies_impl(
         },
     )
 }
-fn wire__crate__api__user__set_cookies_impl(
+fn wire__crate__api__video__get_video_detail_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3136,7 +3244,7 @@ fn wire__crate__api__user__set_cookies_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_cookies",
+            debug_name: "get_video_detail",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3150,12 +3258,12 @@ fn wire__crate__api__user__set_cookies_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api__cookies = <Vec<(String, String)>>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::set_cookies(api__cookies).await?;
+                        let output_ok = crate::api::video::get_video_detail(api_video_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3164,7 +3272,7 @@ fn wire__crate__api__user__set_cookies_impl(
         },
     )
 }
-fn wire__crate__api__settings__set_default_quality_impl(
+fn wire__crate__api__video__get_video_detail_with_options_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3172,7 +3280,7 @@ fn wire__crate__api__settings__set_default_quality_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_default_quality",
+            debug_name: "get_video_detail_with_options",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3186,13 +3294,17 @@ fn wire__crate__api__settings__set_default_quality_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_quality = <String>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_expand_hls_variants = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::settings::set_default_quality(api_quality).await?;
+                        let output_ok = crate::api::video::get_video_detail_with_options(
+                            api_video_id,
+                            api_expand_hls_variants,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3201,7 +3313,7 @@ fn wire__crate__api__settings__set_default_quality_impl(
         },
     )
 }
-fn wire__crate__api__settings__set_download_concurrent_impl(
+fn wire__crate__api__user__get_video_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3209,7 +3321,7 @@ fn wire__crate__api__settings__set_download_concurrent_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_download_concurrent",
+            debug_name: "get_video_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3223,13 +3335,12 @@ fn wire__crate__api__settings__set_download_concurrent_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_count = <u32>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::settings::set_download_concurrent(api_count).await?;
+                        let output_ok = crate::api::user::get_video_progress(api_video_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3238,7 +3349,7 @@ fn wire__crate__api__settings__set_download_concurrent_impl(
         },
     )
 }
-fn wire__crate__api__settings__set_kv_impl(
+fn wire__crate__api__video__get_video_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3246,7 +3357,7 @@ fn wire__crate__api__settings__set_kv_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_kv",
+            debug_name: "get_video_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3260,13 +3371,14 @@ fn wire__crate__api__settings__set_kv_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <String>::sse_decode(&mut deserializer);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::set_kv(api_key, api_value).await?;
+                        let output_ok =
+                            crate::api::video::get_video_url(api_video_id, api_quality).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3275,7 +3387,7 @@ fn wire__crate__api__settings__set_kv_impl(
         },
     )
 }
-fn wire__crate__api__settings__set_proxy_impl(
+fn wire__crate__api__watch_next__get_watch_next_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3283,7 +3395,7 @@ fn wire__crate__api__settings__set_proxy_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_proxy",
+            debug_name: "get_watch_next",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3297,12 +3409,12 @@ fn wire__crate__api__settings__set_proxy_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_proxy_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::settings::set_proxy(api_proxy_url).await?;
+                        let output_ok = crate::api::watch_next::get_watch_next(api_limit).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3311,7 +3423,7 @@ fn wire__crate__api__settings__set_proxy_impl(
         },
     )
 }
-fn wire__crate__api__sync__should_auto_sync_impl(
+fn wire__crate__api__sync__get_webdav_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3319,7 +3431,7 @@ fn wire__crate__api__sync__should_auto_sync_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "should_auto_sync",
+            debug_name: "get_webdav_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3337,7 +3449,7 @@ fn wire__crate__api__sync__should_auto_sync_impl(
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::should_auto_sync().await?;
+                        let output_ok = crate::api::sync::get_webdav_settings().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3346,17 +3458,16 @@ fn wire__crate__api__sync__should_auto_sync_impl(
         },
     )
 }
-fn wire__crate__api__user__subscribe_author_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__greet_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_author",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3368,30 +3479,16 @@ fn wire__crate__api__user__subscribe_author_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_artist_id = <String>::sse_decode(&mut deserializer);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_form_token = <String>::sse_decode(&mut deserializer);
-            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::user::subscribe_author(
-                            api_artist_id,
-                            api_user_id,
-                            api_form_token,
-                            api_x_csrf_token,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__download__subscribe_download_progress_impl(
+fn wire__crate__api__download_bundle__import_download_bundle_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3399,7 +3496,7 @@ fn wire__crate__api__download__subscribe_download_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_download_progress",
+            debug_name: "import_download_bundle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3413,15 +3510,21 @@ fn wire__crate__api__download__subscribe_download_progress_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_src_path = <String>::sse_decode(&mut deserializer);
+            let api_conflict_policy = <String>::sse_decode(&mut deserializer);
             let api_sink = <StreamSink<
-                crate::api::models::ApiDownloadTask,
+                crate::api::models::ApiBundleProgress,
                 flutter_rust_bridge::for_generated::SseCodec,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::download::subscribe_download_progress(api_sink);
+                        crate::api::download_bundle::import_download_bundle(
+                            api_src_path,
+                            api_conflict_policy,
+                            api_sink,
+                        );
                     })?;
                     Ok(output_ok)
                 })())
@@ -3429,7 +3532,7 @@ fn wire__crate__api__download__subscribe_download_progress_impl(
         },
     )
 }
-fn wire__crate__api__sync__sync_history_impl(
+fn wire__crate__api__init__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3437,7 +3540,7 @@ fn wire__crate__api__sync__sync_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_history",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3451,12 +3554,12 @@ fn wire__crate__api__sync__sync_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_force_upload = <bool>::sse_decode(&mut deserializer);
+            let api_data_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::sync_history(api_force_upload).await?;
+                        let output_ok = crate::api::init::init_app(api_data_path).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3465,7 +3568,7 @@ fn wire__crate__api__sync__sync_history_impl(
         },
     )
 }
-fn wire__crate__api__sync__test_webdav_connection_impl(
+fn wire__crate__api__settings__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3473,7 +3576,7 @@ fn wire__crate__api__sync__test_webdav_connection_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_webdav_connection",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3487,19 +3590,14 @@ fn wire__crate__api__sync__test_webdav_connection_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_cache_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::test_webdav_connection(
-                            api_url,
-                            api_username,
-                            api_password,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::settings::init_app(api_data_dir, api_cache_dir).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3508,7 +3606,41 @@ fn wire__crate__api__sync__test_webdav_connection_impl(
         },
     )
 }
-fn wire__crate__api__user__unsubscribe_author_impl(
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__user__is_logged_in_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3516,7 +3648,7 @@ fn wire__crate__api__user__unsubscribe_author_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "unsubscribe_author",
+            debug_name: "is_logged_in",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3530,21 +3662,11 @@ fn wire__crate__api__user__unsubscribe_author_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_artist_id = <String>::sse_decode(&mut deserializer);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_form_token = <String>::sse_decode(&mut deserializer);
-            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::unsubscribe_author(
-                            api_artist_id,
-                            api_user_id,
-                            api_form_token,
-                            api_x_csrf_token,
-                        )
-                        .await?;
+                        let output_ok = crate::api::user::is_logged_in().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3553,7 +3675,7 @@ fn wire__crate__api__user__unsubscribe_author_impl(
         },
     )
 }
-fn wire__crate__api__sync__update_last_sync_time_impl(
+fn wire__crate__api__video__like_comment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3561,7 +3683,7 @@ fn wire__crate__api__sync__update_last_sync_time_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_last_sync_time",
+            debug_name: "like_comment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3575,11 +3697,12 @@ fn wire__crate__api__sync__update_last_sync_time_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__comment_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::sync::update_last_sync_time().await?;
+                        let output_ok = crate::api::video::like_comment(api__comment_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3588,7 +3711,7 @@ fn wire__crate__api__sync__update_last_sync_time_impl(
         },
     )
 }
-fn wire__crate__api__user__update_play_history_impl(
+fn wire__crate__api__cache__load_cached_image_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3596,7 +3719,7 @@ fn wire__crate__api__user__update_play_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_play_history",
+            debug_name: "load_cached_image",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3610,21 +3733,55 @@ fn wire__crate__api__user__update_play_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_video_id = <String>::sse_decode(&mut deserializer);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_cover_url = <String>::sse_decode(&mut deserializer);
-            let api_progress = <f32>::sse_decode(&mut deserializer);
-            let api_duration = <u32>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::user::update_play_history(
-                            api_video_id,
-                            api_title,
-                            api_cover_url,
-                            api_progress,
-                            api_duration,
+                        let output_ok = crate::api::cache::load_cached_image(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_email = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_form_token = <String>::sse_decode(&mut deserializer);
+            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::login(
+                            api_email,
+                            api_password,
+                            api_form_token,
+                            api_x_csrf_token,
                         )
                         .await?;
                         Ok(output_ok)
@@ -3635,15 +3792,15 @@ fn wire__crate__api__user__update_play_history_impl(
         },
     )
 }
-fn wire__crate__api__cache__vacuum_database_impl(
+fn wire__crate__api__user__logout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "vacuum_database",
+            debug_name: "logout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3658,20 +3815,2596 @@ fn wire__crate__api__cache__vacuum_database_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::cache::vacuum_database()?;
+                    (move || async move {
+                        let output_ok = crate::api::user::logout().await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-
-// Section: dart2rust
-
+fn wire__crate__api__download_reconcile__mark_downloads_missing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_downloads_missing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_reconcile::mark_downloads_missing(api_video_ids)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_folders__move_download_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_download_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::move_download_folder(
+                            api_folder_id,
+                            api_parent_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_folders__move_downloads_to_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_downloads_to_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::move_downloads_to_folder(
+                            api_video_ids,
+                            api_folder_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__pause_all_downloads_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_all_downloads",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::pause_all_downloads().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__pause_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::pause_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_variants__pause_download_variant_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_download_variant",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_variant_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_variants::pause_download_variant(api_variant_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_export__pause_export_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_export_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_export::pause_export_job(api_job_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__video__post_comment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "post_comment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_reply_to = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::video::post_comment(
+                            api_video_id,
+                            api_content,
+                            api_reply_to,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init__prepare_logs_for_sharing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prepare_logs_for_sharing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::init::prepare_logs_for_sharing()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_quota__preview_download_cleanup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_download_cleanup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_quota::preview_download_cleanup().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_export__preview_export_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_export_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_template = <String>::sse_decode(&mut deserializer);
+            let api_task_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_max_name_bytes = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::download_export::preview_export_template(
+                            api_template,
+                            api_task_ids,
+                            api_max_name_bytes,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__query_downloads_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_downloads",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::models::ApiDownloadQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::query_downloads(api_query).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_reconcile__redownload_missing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "redownload_missing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_reconcile::redownload_missing(api_video_ids)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_reconcile__relink_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relink_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            let api_file_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_reconcile::relink_download(
+                            api_video_id,
+                            api_kind,
+                            api_file_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__reload_user_agent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reload_user_agent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::reload_user_agent().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_relocate__relocate_downloads_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relocate_downloads",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_new_root = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiRelocateProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download_relocate::relocate_downloads(api_new_root, api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__user__remove_from_favorites_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_from_favorites",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_code = <String>::sse_decode(&mut deserializer);
+            let api_form_token = <String>::sse_decode(&mut deserializer);
+            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::remove_from_favorites(
+                            api_video_code,
+                            api_form_token,
+                            api_x_csrf_token,
+                            api_user_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__video__remove_from_favorites_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_from_favorites",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__video_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::video::remove_from_favorites(api__video_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_folders__rename_download_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rename_download_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::rename_download_folder(
+                            api_folder_id,
+                            api_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_folders__reorder_download_folders_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reorder_download_folders",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_folder_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::reorder_download_folders(
+                            api_parent_id,
+                            api_folder_ids,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__repair_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "repair_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::repair_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init__report_flutter_error_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_flutter_error",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_stack = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init::report_flutter_error(api_message, api_stack);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init__report_flutter_log_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_flutter_log",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
+            let api_stack = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init::report_flutter_log(
+                            api_level,
+                            api_message,
+                            api_tag,
+                            api_stack,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__download__resume_all_downloads_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_all_downloads",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::resume_all_downloads().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__resume_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::resume_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_variants__resume_download_variant_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_download_variant",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_variant_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_variants::resume_download_variant(api_variant_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_export__resume_export_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_export_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_export::resume_export_job(api_job_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_quota__run_download_cleanup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_download_cleanup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_quota::run_download_cleanup().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__save_flutter_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_flutter_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::settings::save_flutter_settings(api_json).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__save_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::api::models::ApiAppSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::settings::save_settings(api_settings).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__sync__save_webdav_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_webdav_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::api::sync::ApiWebDavSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::sync::save_webdav_settings(api_settings).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_reconcile__scan_download_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_download_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_reconcile::scan_download_library().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search__search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filters = <crate::api::models::ApiSearchFilters>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::search::search(api_filters).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__set_cf_clearance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cf_clearance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cookie_value = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::user::set_cf_clearance(api_cookie_value).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init__set_cookies_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cookies",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cookie_string = <String>::sse_decode(&mut deserializer);
+            let api_domain = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::init::set_cookies(api_cookie_string, api_domain).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__set_cookies_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cookies",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__cookies = <Vec<(String, String)>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::set_cookies(api__cookies).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__set_default_quality_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_default_quality",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_quality = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::settings::set_default_quality(api_quality).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_throttle__set_download_bandwidth_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_bandwidth_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::models::ApiDownloadBandwidthSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_throttle::set_download_bandwidth_settings(
+                                api_settings,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__set_download_concurrent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_concurrent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::settings::set_download_concurrent(api_count).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_folders__set_download_folder_cover_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_folder_cover",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
+            let api_video_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::set_download_folder_cover(
+                            api_folder_id,
+                            api_video_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__set_download_max_retries_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_max_retries",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_max_retries = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::set_download_max_retries(
+                            api_task_id,
+                            api_max_retries,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__set_download_preallocate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_preallocate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::settings::set_download_preallocate(api_enabled).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_quota__set_download_quota_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_quota_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy =
+                <crate::api::models::ApiDownloadQuotaPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_quota::set_download_quota_policy(api_policy)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_rules__set_download_rule_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_rule_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule_id = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_rules::set_download_rule_enabled(
+                            api_rule_id,
+                            api_enabled,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_rules__set_download_rules_interval_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_rules_interval",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_minutes = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_rules::set_download_rules_interval(api_minutes)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__set_download_space_reserve_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_space_reserve",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_reserve_mb = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::settings::set_download_space_reserve(api_reserve_mb)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_throttle__set_download_task_bandwidth_limit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_task_bandwidth_limit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_kb_per_sec = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_throttle::set_download_task_bandwidth_limit(
+                                api_task_id,
+                                api_kb_per_sec,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__set_kv_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_kv",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_value = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::settings::set_kv(api_key, api_value).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_throttle__set_player_streaming_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_player_streaming",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_active = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download_throttle::set_player_streaming(api_active);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__download_variants__set_primary_download_variant_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_primary_download_variant",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_variant_id = <i64>::sse_decode(&mut deserializer);
+            let api_keep_previous = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_variants::set_primary_download_variant(
+                                api_variant_id,
+                                api_keep_previous,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__set_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_proxy_url = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::settings::set_proxy(api_proxy_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__sync__should_auto_sync_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "should_auto_sync",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::sync::should_auto_sync().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__subscribe_author_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_author",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_artist_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_form_token = <String>::sse_decode(&mut deserializer);
+            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::subscribe_author(
+                            api_artist_id,
+                            api_user_id,
+                            api_form_token,
+                            api_x_csrf_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__subscribe_download_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_download_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiDownloadTask,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download::subscribe_download_progress(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__download_variants__subscribe_download_variant_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_download_variant_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiDownloadVariant,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download_variants::subscribe_download_variant_progress(
+                            api_sink,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__download_quota__subscribe_quota_blocked_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_quota_blocked_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::models::ApiQuotaBlockedEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::download_quota::subscribe_quota_blocked_events(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync__sync_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_force_upload = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::sync::sync_history(api_force_upload).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__sync__test_webdav_connection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_webdav_connection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::sync::test_webdav_connection(
+                            api_url,
+                            api_username,
+                            api_password,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__unsubscribe_author_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_author",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_artist_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_form_token = <String>::sse_decode(&mut deserializer);
+            let api_x_csrf_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::unsubscribe_author(
+                            api_artist_id,
+                            api_user_id,
+                            api_form_token,
+                            api_x_csrf_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_rules__update_download_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_download_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule = <crate::api::models::ApiDownloadRule>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download_rules::update_download_rule(api_rule).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__sync__update_last_sync_time_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_last_sync_time",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::sync::update_last_sync_time().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__user__update_play_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_play_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_cover_url = <String>::sse_decode(&mut deserializer);
+            let api_progress = <f32>::sse_decode(&mut deserializer);
+            let api_duration = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::user::update_play_history(
+                            api_video_id,
+                            api_title,
+                            api_cover_url,
+                            api_progress,
+                            api_duration,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_folders__update_smart_folder_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_smart_folder_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_id = <String>::sse_decode(&mut deserializer);
+            let api_rule = <crate::api::models::ApiSmartFolderRule>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_folders::update_smart_folder_rule(
+                            api_folder_id,
+                            api_rule,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_variants__upgrade_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upgrade_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_video_id = <String>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
+            let api_keep_previous = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download_variants::upgrade_download(
+                            api_video_id,
+                            api_quality,
+                            api_keep_previous,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cache__vacuum_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vacuum_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::cache::vacuum_database()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download_export__validate_export_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_export_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_template = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::download_export::validate_export_template(api_template)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__verify_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::download::verify_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3680,6 +6413,19 @@ impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::api::models::ApiBundleProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::api::models::ApiDownloadTask,
@@ -3693,6 +6439,19 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::api::models::ApiDownloadVariant,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::api::models::ApiExportProgress,
@@ -3706,6 +6465,32 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::api::models::ApiQuotaBlockedEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::models::ApiRelocateProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3764,6 +6549,102 @@ impl SseDecode for crate::api::models::ApiBanner {
     }
 }
 
+impl SseDecode for crate::api::models::ApiBatchEnqueueResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_enqueued = <Vec<String>>::sse_decode(deserializer);
+        let mut var_skipped = <Vec<String>>::sse_decode(deserializer);
+        let mut var_failed = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiBatchEnqueueResult {
+            total: var_total,
+            enqueued: var_enqueued,
+            skipped: var_skipped,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiBundleProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage = <String>::sse_decode(deserializer);
+        let mut var_totalItems = <u32>::sse_decode(deserializer);
+        let mut var_doneItems = <u32>::sse_decode(deserializer);
+        let mut var_exported = <u32>::sse_decode(deserializer);
+        let mut var_imported = <u32>::sse_decode(deserializer);
+        let mut var_skipped = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        let mut var_currentItem = <Option<String>>::sse_decode(deserializer);
+        let mut var_currentBytes = <u64>::sse_decode(deserializer);
+        let mut var_currentTotalBytes = <u64>::sse_decode(deserializer);
+        let mut var_done = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiBundleProgress {
+            stage: var_stage,
+            total_items: var_totalItems,
+            done_items: var_doneItems,
+            exported: var_exported,
+            imported: var_imported,
+            skipped: var_skipped,
+            failed: var_failed,
+            current_item: var_currentItem,
+            current_bytes: var_currentBytes,
+            current_total_bytes: var_currentTotalBytes,
+            done: var_done,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiByteRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u64>::sse_decode(deserializer);
+        let mut var_end = <u64>::sse_decode(deserializer);
+        return crate::api::models::ApiByteRange {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiCleanupItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_videoId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_completedAt = <i64>::sse_decode(deserializer);
+        let mut var_watchedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::models::ApiCleanupItem {
+            video_id: var_videoId,
+            title: var_title,
+            reason: var_reason,
+            size: var_size,
+            completed_at: var_completedAt,
+            watched_at: var_watchedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiCleanupPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_usedBytes = <u64>::sse_decode(deserializer);
+        let mut var_quotaBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::models::ApiCleanupItem>>::sse_decode(deserializer);
+        let mut var_freedBytes = <u64>::sse_decode(deserializer);
+        return crate::api::models::ApiCleanupPlan {
+            used_bytes: var_usedBytes,
+            quota_bytes: var_quotaBytes,
+            items: var_items,
+            freed_bytes: var_freedBytes,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiCloudflareChallenge {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3818,16 +6699,198 @@ impl SseDecode for crate::api::models::ApiCommentList {
     }
 }
 
+impl SseDecode for crate::api::models::ApiContinueWatching {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_videoId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_coverUrl = <String>::sse_decode(deserializer);
+        let mut var_progress = <f32>::sse_decode(deserializer);
+        let mut var_position = <u32>::sse_decode(deserializer);
+        let mut var_duration = <u32>::sse_decode(deserializer);
+        let mut var_lastPlayedAt = <i64>::sse_decode(deserializer);
+        let mut var_seriesTitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_episode = <Option<u32>>::sse_decode(deserializer);
+        let mut var_isDownloaded = <bool>::sse_decode(deserializer);
+        return crate::api::models::ApiContinueWatching {
+            video_id: var_videoId,
+            title: var_title,
+            cover_url: var_coverUrl,
+            progress: var_progress,
+            position: var_position,
+            duration: var_duration,
+            last_played_at: var_lastPlayedAt,
+            series_title: var_seriesTitle,
+            episode: var_episode,
+            is_downloaded: var_isDownloaded,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadBandwidthSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_globalKbPerSec = <u32>::sse_decode(deserializer);
+        let mut var_taskKbPerSec = <u32>::sse_decode(deserializer);
+        let mut var_onlyWhileStreaming = <bool>::sse_decode(deserializer);
+        let mut var_playerStreaming = <bool>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadBandwidthSettings {
+            global_kb_per_sec: var_globalKbPerSec,
+            task_kb_per_sec: var_taskKbPerSec,
+            only_while_streaming: var_onlyWhileStreaming,
+            player_streaming: var_playerStreaming,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_status = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        let mut var_sourceHost = <Option<String>>::sse_decode(deserializer);
+        let mut var_quality = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_rangeStart = <Option<u64>>::sse_decode(deserializer);
+        let mut var_rangeEnd = <Option<u64>>::sse_decode(deserializer);
+        let mut var_httpStatus = <Option<u16>>::sse_decode(deserializer);
+        let mut var_errorKind = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadEvent {
+            id: var_id,
+            kind: var_kind,
+            status: var_status,
+            message: var_message,
+            source_host: var_sourceHost,
+            quality: var_quality,
+            format: var_format,
+            range_start: var_rangeStart,
+            range_end: var_rangeEnd,
+            http_status: var_httpStatus,
+            error_kind: var_errorKind,
+            created_at: var_createdAt,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiDownloadFolder {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_id = <String>::sse_decode(deserializer);
         let mut var_name = <String>::sse_decode(deserializer);
         let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_parentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_sortOrder = <i32>::sse_decode(deserializer);
+        let mut var_coverVideoId = <Option<String>>::sse_decode(deserializer);
+        let mut var_isSmart = <bool>::sse_decode(deserializer);
+        let mut var_videoCount = <u32>::sse_decode(deserializer);
         return crate::api::models::ApiDownloadFolder {
             id: var_id,
             name: var_name,
             created_at: var_createdAt,
+            parent_id: var_parentId,
+            sort_order: var_sortOrder,
+            cover_video_id: var_coverVideoId,
+            is_smart: var_isSmart,
+            video_count: var_videoCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_items = <Vec<crate::api::models::ApiDownloadTask>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadPage {
+            items: var_items,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_statuses = <Vec<String>>::sse_decode(deserializer);
+        let mut var_folderId = <Option<String>>::sse_decode(deserializer);
+        let mut var_includeSubfolders = <bool>::sse_decode(deserializer);
+        let mut var_authors = <Vec<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        let mut var_downloadedFrom = <Option<i64>>::sse_decode(deserializer);
+        let mut var_downloadedTo = <Option<i64>>::sse_decode(deserializer);
+        let mut var_minBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_sortBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        let mut var_limit = <u32>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadQuery {
+            statuses: var_statuses,
+            folder_id: var_folderId,
+            include_subfolders: var_includeSubfolders,
+            authors: var_authors,
+            tags: var_tags,
+            text: var_text,
+            downloaded_from: var_downloadedFrom,
+            downloaded_to: var_downloadedTo,
+            min_bytes: var_minBytes,
+            max_bytes: var_maxBytes,
+            sort_by: var_sortBy,
+            descending: var_descending,
+            offset: var_offset,
+            limit: var_limit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadQuotaPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_quotaBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_autoCleanup = <bool>::sse_decode(deserializer);
+        let mut var_watchedOlderThanDays = <Option<u32>>::sse_decode(deserializer);
+        let mut var_oldestFirst = <bool>::sse_decode(deserializer);
+        let mut var_pinnedFolderIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadQuotaPolicy {
+            quota_bytes: var_quotaBytes,
+            auto_cleanup: var_autoCleanup,
+            watched_older_than_days: var_watchedOlderThanDays,
+            oldest_first: var_oldestFirst,
+            pinned_folder_ids: var_pinnedFolderIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_matchType = <String>::sse_decode(deserializer);
+        let mut var_matchValue = <String>::sse_decode(deserializer);
+        let mut var_quality = <String>::sse_decode(deserializer);
+        let mut var_folderId = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxAgeDays = <Option<u32>>::sse_decode(deserializer);
+        let mut var_dailyCap = <Option<u32>>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_lastCheckedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadRule {
+            id: var_id,
+            name: var_name,
+            match_type: var_matchType,
+            match_value: var_matchValue,
+            quality: var_quality,
+            folder_id: var_folderId,
+            max_age_days: var_maxAgeDays,
+            daily_cap: var_dailyCap,
+            enabled: var_enabled,
+            created_at: var_createdAt,
+            last_checked_at: var_lastCheckedAt,
         };
     }
 }
@@ -3853,6 +6916,13 @@ impl SseDecode for crate::api::models::ApiDownloadStatus {
                 let mut var_error = <String>::sse_decode(deserializer);
                 return crate::api::models::ApiDownloadStatus::Failed { error: var_error };
             }
+            5 => {
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::models::ApiDownloadStatus::Corrupt { reason: var_reason };
+            }
+            6 => {
+                return crate::api::models::ApiDownloadStatus::Missing;
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3860,6 +6930,46 @@ impl SseDecode for crate::api::models::ApiDownloadStatus {
     }
 }
 
+impl SseDecode for crate::api::models::ApiDownloadStorageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_downloadDir = <String>::sse_decode(deserializer);
+        let mut var_usedBytes = <u64>::sse_decode(deserializer);
+        let mut var_pendingBytes = <u64>::sse_decode(deserializer);
+        let mut var_freeBytes = <u64>::sse_decode(deserializer);
+        let mut var_diskTotalBytes = <u64>::sse_decode(deserializer);
+        let mut var_reserveBytes = <u64>::sse_decode(deserializer);
+        let mut var_preallocate = <bool>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadStorageInfo {
+            download_dir: var_downloadDir,
+            used_bytes: var_usedBytes,
+            pending_bytes: var_pendingBytes,
+            free_bytes: var_freeBytes,
+            disk_total_bytes: var_diskTotalBytes,
+            reserve_bytes: var_reserveBytes,
+            preallocate: var_preallocate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadSubtitle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_language = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_format = <String>::sse_decode(deserializer);
+        let mut var_filePath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadSubtitle {
+            language: var_language,
+            label: var_label,
+            url: var_url,
+            format: var_format,
+            file_path: var_filePath,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiDownloadTask {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3883,6 +6993,10 @@ impl SseDecode for crate::api::models::ApiDownloadTask {
         let mut var_createdAt = <i64>::sse_decode(deserializer);
         let mut var_filePath = <Option<String>>::sse_decode(deserializer);
         let mut var_folderId = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorKind = <Option<String>>::sse_decode(deserializer);
+        let mut var_retryCount = <u32>::sse_decode(deserializer);
+        let mut var_maxRetries = <u32>::sse_decode(deserializer);
+        let mut var_nextRetryAt = <Option<i64>>::sse_decode(deserializer);
         return crate::api::models::ApiDownloadTask {
             id: var_id,
             video_id: var_videoId,
@@ -3904,6 +7018,64 @@ impl SseDecode for crate::api::models::ApiDownloadTask {
             created_at: var_createdAt,
             file_path: var_filePath,
             folder_id: var_folderId,
+            error_kind: var_errorKind,
+            retry_count: var_retryCount,
+            max_retries: var_maxRetries,
+            next_retry_at: var_nextRetryAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiDownloadVariant {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_videoId = <String>::sse_decode(deserializer);
+        let mut var_quality = <String>::sse_decode(deserializer);
+        let mut var_sourceQuality = <Option<String>>::sse_decode(deserializer);
+        let mut var_status = <crate::api::models::ApiDownloadStatus>::sse_decode(deserializer);
+        let mut var_progress = <f32>::sse_decode(deserializer);
+        let mut var_downloadedBytes = <u64>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_filePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_replacePrimary = <bool>::sse_decode(deserializer);
+        let mut var_keepPrevious = <bool>::sse_decode(deserializer);
+        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_completedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::models::ApiDownloadVariant {
+            id: var_id,
+            video_id: var_videoId,
+            quality: var_quality,
+            source_quality: var_sourceQuality,
+            status: var_status,
+            progress: var_progress,
+            downloaded_bytes: var_downloadedBytes,
+            total_bytes: var_totalBytes,
+            file_path: var_filePath,
+            replace_primary: var_replacePrimary,
+            keep_previous: var_keepPrevious,
+            error_message: var_errorMessage,
+            created_at: var_createdAt,
+            completed_at: var_completedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiExportOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mediaServerLayout = <bool>::sse_decode(deserializer);
+        let mut var_groupBy = <String>::sse_decode(deserializer);
+        let mut var_filenameTemplate = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxNameBytes = <Option<u32>>::sse_decode(deserializer);
+        let mut var_moveFiles = <bool>::sse_decode(deserializer);
+        return crate::api::models::ApiExportOptions {
+            media_server_layout: var_mediaServerLayout,
+            group_by: var_groupBy,
+            filename_template: var_filenameTemplate,
+            max_name_bytes: var_maxNameBytes,
+            move_files: var_moveFiles,
         };
     }
 }
@@ -3911,16 +7083,20 @@ impl SseDecode for crate::api::models::ApiDownloadTask {
 impl SseDecode for crate::api::models::ApiExportProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_jobId = <String>::sse_decode(deserializer);
         let mut var_totalFiles = <u32>::sse_decode(deserializer);
         let mut var_doneFiles = <u32>::sse_decode(deserializer);
+        let mut var_skippedFiles = <u32>::sse_decode(deserializer);
         let mut var_currentFile = <Option<String>>::sse_decode(deserializer);
         let mut var_currentBytes = <u64>::sse_decode(deserializer);
         let mut var_currentTotalBytes = <u64>::sse_decode(deserializer);
         let mut var_done = <bool>::sse_decode(deserializer);
         let mut var_error = <Option<String>>::sse_decode(deserializer);
         return crate::api::models::ApiExportProgress {
+            job_id: var_jobId,
             total_files: var_totalFiles,
             done_files: var_doneFiles,
+            skipped_files: var_skippedFiles,
             current_file: var_currentFile,
             current_bytes: var_currentBytes,
             current_total_bytes: var_currentTotalBytes,
@@ -4013,6 +7189,30 @@ impl SseDecode for crate::api::models::ApiHomeSection {
     }
 }
 
+impl SseDecode for crate::api::models::ApiMissingDownload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_videoId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_status = <crate::api::models::ApiDownloadStatus>::sse_decode(deserializer);
+        let mut var_missingMedia = <bool>::sse_decode(deserializer);
+        let mut var_missingCover = <bool>::sse_decode(deserializer);
+        let mut var_missingAvatar = <bool>::sse_decode(deserializer);
+        let mut var_expectedBytes = <u64>::sse_decode(deserializer);
+        let mut var_candidateFiles = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiMissingDownload {
+            video_id: var_videoId,
+            title: var_title,
+            status: var_status,
+            missing_media: var_missingMedia,
+            missing_cover: var_missingCover,
+            missing_avatar: var_missingAvatar,
+            expected_bytes: var_expectedBytes,
+            candidate_files: var_candidateFiles,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiMyListInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4039,6 +7239,50 @@ impl SseDecode for crate::api::models::ApiMyListItem {
     }
 }
 
+impl SseDecode for crate::api::models::ApiNextEpisode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_videoId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_coverUrl = <String>::sse_decode(deserializer);
+        let mut var_seriesId = <String>::sse_decode(deserializer);
+        let mut var_seriesTitle = <String>::sse_decode(deserializer);
+        let mut var_episode = <u32>::sse_decode(deserializer);
+        let mut var_previousVideoId = <String>::sse_decode(deserializer);
+        let mut var_previousPlayedAt = <i64>::sse_decode(deserializer);
+        let mut var_isDownloaded = <bool>::sse_decode(deserializer);
+        return crate::api::models::ApiNextEpisode {
+            video_id: var_videoId,
+            title: var_title,
+            cover_url: var_coverUrl,
+            series_id: var_seriesId,
+            series_title: var_seriesTitle,
+            episode: var_episode,
+            previous_video_id: var_previousVideoId,
+            previous_played_at: var_previousPlayedAt,
+            is_downloaded: var_isDownloaded,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiOrphanFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dir = <String>::sse_decode(deserializer);
+        let mut var_fileName = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_modifiedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_videoId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiOrphanFile {
+            dir: var_dir,
+            file_name: var_fileName,
+            size: var_size,
+            modified_at: var_modifiedAt,
+            video_id: var_videoId,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiPlayHistory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4087,6 +7331,71 @@ impl SseDecode for crate::api::models::ApiPlaylistInfo {
     }
 }
 
+impl SseDecode for crate::api::models::ApiQuotaBlockedEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_videoId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_requiredBytes = <u64>::sse_decode(deserializer);
+        let mut var_usedBytes = <u64>::sse_decode(deserializer);
+        let mut var_quotaBytes = <u64>::sse_decode(deserializer);
+        let mut var_freedBytes = <u64>::sse_decode(deserializer);
+        let mut var_blockedAt = <i64>::sse_decode(deserializer);
+        return crate::api::models::ApiQuotaBlockedEvent {
+            video_id: var_videoId,
+            title: var_title,
+            required_bytes: var_requiredBytes,
+            used_bytes: var_usedBytes,
+            quota_bytes: var_quotaBytes,
+            freed_bytes: var_freedBytes,
+            blocked_at: var_blockedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiReconcileReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_missing =
+            <Vec<crate::api::models::ApiMissingDownload>>::sse_decode(deserializer);
+        let mut var_orphans = <Vec<crate::api::models::ApiOrphanFile>>::sse_decode(deserializer);
+        let mut var_missingBytes = <u64>::sse_decode(deserializer);
+        let mut var_orphanBytes = <u64>::sse_decode(deserializer);
+        let mut var_scannedAt = <i64>::sse_decode(deserializer);
+        return crate::api::models::ApiReconcileReport {
+            missing: var_missing,
+            orphans: var_orphans,
+            missing_bytes: var_missingBytes,
+            orphan_bytes: var_orphanBytes,
+            scanned_at: var_scannedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiRelocateProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage = <String>::sse_decode(deserializer);
+        let mut var_totalFiles = <u32>::sse_decode(deserializer);
+        let mut var_doneFiles = <u32>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_doneBytes = <u64>::sse_decode(deserializer);
+        let mut var_currentFile = <Option<String>>::sse_decode(deserializer);
+        let mut var_done = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::ApiRelocateProgress {
+            stage: var_stage,
+            total_files: var_totalFiles,
+            done_files: var_doneFiles,
+            total_bytes: var_totalBytes,
+            done_bytes: var_doneBytes,
+            current_file: var_currentFile,
+            done: var_done,
+            error: var_error,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiSearchFilters {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4163,6 +7472,30 @@ impl SseDecode for crate::api::models::ApiSeriesVideo {
     }
 }
 
+impl SseDecode for crate::api::models::ApiSmartFolderRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_authors = <Vec<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_qualities = <Vec<String>>::sse_decode(deserializer);
+        let mut var_downloadedFrom = <Option<i64>>::sse_decode(deserializer);
+        let mut var_downloadedTo = <Option<i64>>::sse_decode(deserializer);
+        let mut var_uploadedFrom = <Option<String>>::sse_decode(deserializer);
+        let mut var_uploadedTo = <Option<String>>::sse_decode(deserializer);
+        let mut var_watched = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::models::ApiSmartFolderRule {
+            authors: var_authors,
+            tags: var_tags,
+            qualities: var_qualities,
+            downloaded_from: var_downloadedFrom,
+            downloaded_to: var_downloadedTo,
+            uploaded_from: var_uploadedFrom,
+            uploaded_to: var_uploadedTo,
+            watched: var_watched,
+        };
+    }
+}
+
 impl SseDecode for crate::api::models::ApiSubscriptionsPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4179,6 +7512,22 @@ impl SseDecode for crate::api::models::ApiSubscriptionsPage {
     }
 }
 
+impl SseDecode for crate::api::models::ApiSubtitleTrack {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_language = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_format = <String>::sse_decode(deserializer);
+        return crate::api::models::ApiSubtitleTrack {
+            language: var_language,
+            label: var_label,
+            url: var_url,
+            format: var_format,
+        };
+    }
+}
+
 impl SseDecode for crate::api::sync::ApiSyncStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4285,6 +7634,8 @@ impl SseDecode for crate::api::models::ApiVideoDetail {
         let mut var_tags = <Vec<String>>::sse_decode(deserializer);
         let mut var_qualities =
             <Vec<crate::api::models::ApiVideoQuality>>::sse_decode(deserializer);
+        let mut var_subtitles =
+            <Vec<crate::api::models::ApiSubtitleTrack>>::sse_decode(deserializer);
         let mut var_series = <Option<crate::api::models::ApiSeriesInfo>>::sse_decode(deserializer);
         let mut var_relatedVideos =
             <Vec<crate::api::models::ApiVideoCard>>::sse_decode(deserializer);
@@ -4311,6 +7662,7 @@ impl SseDecode for crate::api::models::ApiVideoDetail {
             author: var_author,
             tags: var_tags,
             qualities: var_qualities,
+            subtitles: var_subtitles,
             series: var_series,
             related_videos: var_relatedVideos,
             form_token: var_formToken,
@@ -4328,9 +7680,31 @@ impl SseDecode for crate::api::models::ApiVideoQuality {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_quality = <String>::sse_decode(deserializer);
         let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bandwidth = <Option<u64>>::sse_decode(deserializer);
+        let mut var_codecs = <Option<String>>::sse_decode(deserializer);
         return crate::api::models::ApiVideoQuality {
             quality: var_quality,
             url: var_url,
+            width: var_width,
+            height: var_height,
+            bandwidth: var_bandwidth,
+            codecs: var_codecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ApiWatchNext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_continueWatching =
+            <Vec<crate::api::models::ApiContinueWatching>>::sse_decode(deserializer);
+        let mut var_nextEpisodes =
+            <Vec<crate::api::models::ApiNextEpisode>>::sse_decode(deserializer);
+        return crate::api::models::ApiWatchNext {
+            continue_watching: var_continueWatching,
+            next_episodes: var_nextEpisodes,
         };
     }
 }
@@ -4404,32 +7778,168 @@ impl SseDecode for i32 {
     }
 }
 
-impl SseDecode for i64 {
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiAuthorInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiAuthorInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiByteRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiByteRange>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiCleanupItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiCleanupItem>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiComment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiComment>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiContinueWatching> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiContinueWatching>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiDownloadEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiDownloadEvent>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiDownloadFolder> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiDownloadFolder>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiDownloadRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiDownloadRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::ApiDownloadSubtitle> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiDownloadSubtitle>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for Vec<crate::api::models::ApiDownloadTask> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+            ans_.push(<crate::api::models::ApiDownloadTask>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiAuthorInfo> {
+impl SseDecode for Vec<crate::api::models::ApiDownloadVariant> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiAuthorInfo>::sse_decode(
+            ans_.push(<crate::api::models::ApiDownloadVariant>::sse_decode(
                 deserializer,
             ));
         }
@@ -4437,25 +7947,27 @@ impl SseDecode for Vec<crate::api::models::ApiAuthorInfo> {
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiComment> {
+impl SseDecode for Vec<crate::api::models::ApiFilterOption> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiComment>::sse_decode(deserializer));
+            ans_.push(<crate::api::models::ApiFilterOption>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiDownloadFolder> {
+impl SseDecode for Vec<crate::api::models::ApiHomeSection> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiDownloadFolder>::sse_decode(
+            ans_.push(<crate::api::models::ApiHomeSection>::sse_decode(
                 deserializer,
             ));
         }
@@ -4463,13 +7975,13 @@ impl SseDecode for Vec<crate::api::models::ApiDownloadFolder> {
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiDownloadTask> {
+impl SseDecode for Vec<crate::api::models::ApiMissingDownload> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiDownloadTask>::sse_decode(
+            ans_.push(<crate::api::models::ApiMissingDownload>::sse_decode(
                 deserializer,
             ));
         }
@@ -4477,13 +7989,13 @@ impl SseDecode for Vec<crate::api::models::ApiDownloadTask> {
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiFilterOption> {
+impl SseDecode for Vec<crate::api::models::ApiMyListItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiFilterOption>::sse_decode(
+            ans_.push(<crate::api::models::ApiMyListItem>::sse_decode(
                 deserializer,
             ));
         }
@@ -4491,13 +8003,13 @@ impl SseDecode for Vec<crate::api::models::ApiFilterOption> {
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiHomeSection> {
+impl SseDecode for Vec<crate::api::models::ApiNextEpisode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiHomeSection>::sse_decode(
+            ans_.push(<crate::api::models::ApiNextEpisode>::sse_decode(
                 deserializer,
             ));
         }
@@ -4505,13 +8017,13 @@ impl SseDecode for Vec<crate::api::models::ApiHomeSection> {
     }
 }
 
-impl SseDecode for Vec<crate::api::models::ApiMyListItem> {
+impl SseDecode for Vec<crate::api::models::ApiOrphanFile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::ApiMyListItem>::sse_decode(
+            ans_.push(<crate::api::models::ApiOrphanFile>::sse_decode(
                 deserializer,
             ));
         }
@@ -4547,6 +8059,20 @@ impl SseDecode for Vec<crate::api::models::ApiSeriesVideo> {
     }
 }
 
+impl SseDecode for Vec<crate::api::models::ApiSubtitleTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ApiSubtitleTrack>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::models::ApiTagGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4720,6 +8246,17 @@ impl SseDecode for Option<crate::api::models::ApiUserInfo> {
     }
 }
 
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4742,6 +8279,17 @@ impl SseDecode for Option<i64> {
     }
 }
 
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4773,6 +8321,13 @@ impl SseDecode for (String, String) {
     }
 }
 
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4809,275 +8364,802 @@ fn pde_ffi_dispatcher_primary_impl(
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
         1 => wire__crate__api__download__add_download_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__user__add_to_favorites_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__video__add_to_favorites_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__models__api_app_settings_default_impl(
+        2 => wire__crate__api__download_variants__add_download_variant_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__user__add_to_favorites_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__video__add_to_favorites_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__models__api_app_settings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__models__api_download_bandwidth_settings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__models__api_download_query_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__models__api_download_quota_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__models__api_export_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__models__api_search_filters_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__models__api_smart_folder_rule_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__sync__api_web_dav_settings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__cache__auto_clean_cache_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__cache__cache_size_default_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__download_export__cancel_export_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__init__check_cloudflare_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__download_rules__check_download_rules_now_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__init__check_network_impl(port, ptr, rust_vec_len, data_len),
+        19 => {
+            wire__crate__api__sync__cleanup_expired_history_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__init__cleanup_logs_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__cache__clear_all_cache_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__init__clear_cookies_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__cache__clear_image_cache_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__user__clear_play_history_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__cache__clear_web_cache_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__download_folders__create_download_folder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__download_rules__create_download_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__download_folders__create_smart_folder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__download__delete_download_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__download_folders__delete_download_folder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__download_rules__delete_download_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__download_variants__delete_download_variant_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__user__delete_from_list_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__settings__delete_kv_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__download_reconcile__delete_orphan_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__user__delete_play_history_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__download_batch__download_playlist_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__download_batch__download_search_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__download_batch__download_series_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__download_bundle__export_download_bundle_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__download__export_downloads_to_dir_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__download_export__export_downloads_with_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__init__export_logs_zip_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__sync__force_upload_history_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__download_export__get_active_export_jobs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__download__get_all_downloads_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__settings__get_app_version_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__cache__get_cache_size_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__settings__get_cache_size_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__user__get_cloudflare_challenge_info_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__video__get_comment_replies_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__user__get_current_user_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__settings__get_data_dir_path_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__download__get_download_available_ranges_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        55 => wire__crate__api__download_throttle__get_download_bandwidth_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__download_folders__get_download_folders_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__download_quota__get_download_quota_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__download_rules__get_download_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        59 => wire__crate__api__download__get_download_storage_info_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        60 => wire__crate__api__download_subtitles__get_download_subtitles_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => wire__crate__api__download_throttle__get_download_task_bandwidth_limit_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__download_events__get_download_timeline_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__api__download_variants__get_download_variants_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__download__get_downloads_by_status_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        5 => wire__crate__api__models__api_search_filters_default_impl(
+        65 => wire__crate__api__download_relocate__get_downloads_root_path_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        6 => wire__crate__api__sync__api_web_dav_settings_default_impl(
+        66 => wire__crate__api__download_export__get_export_template_tokens_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        7 => wire__crate__api__cache__auto_clean_cache_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__cache__cache_size_default_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__init__check_cloudflare_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__init__check_network_impl(port, ptr, rust_vec_len, data_len),
-        11 => {
-            wire__crate__api__sync__cleanup_expired_history_impl(port, ptr, rust_vec_len, data_len)
+        67 => wire__crate__api__user__get_favorites_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__search__get_filter_options_impl(port, ptr, rust_vec_len, data_len),
+        69 => {
+            wire__crate__api__settings__get_flutter_settings_impl(port, ptr, rust_vec_len, data_len)
         }
-        12 => wire__crate__api__init__cleanup_logs_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__cache__clear_all_cache_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__init__clear_cookies_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__cache__clear_image_cache_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__user__clear_play_history_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__cache__clear_web_cache_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__download_folders__create_download_folder_impl(
+        70 => wire__crate__api__download_folders__get_folder_downloads_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        19 => wire__crate__api__download__delete_download_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__download_folders__delete_download_folder_impl(
+        71 => wire__crate__api__search__get_home_videos_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__search__get_homepage_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__settings__get_kv_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__sync__get_last_sync_time_impl(port, ptr, rust_vec_len, data_len),
+        75 => {
+            wire__crate__api__stream__get_local_stream_url_impl(port, ptr, rust_vec_len, data_len)
+        }
+        76 => {
+            wire__crate__api__download__get_local_video_path_impl(port, ptr, rust_vec_len, data_len)
+        }
+        77 => wire__crate__api__user__get_login_form_token_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__user__get_my_list_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__user__get_my_subscriptions_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__user__get_play_history_impl(port, ptr, rust_vec_len, data_len),
+        81 => {
+            wire__crate__api__stream__get_proxied_stream_url_impl(port, ptr, rust_vec_len, data_len)
+        }
+        82 => wire__crate__api__settings__get_settings_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__download_folders__get_smart_folder_rule_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        21 => wire__crate__api__user__delete_from_list_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__settings__delete_kv_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__user__delete_play_history_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__download__export_downloads_to_dir_impl(
+        84 => {
+            wire__crate__api__user__get_subscribed_authors_impl(port, ptr, rust_vec_len, data_len)
+        }
+        85 => wire__crate__api__init__get_version_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__video__get_video_comments_impl(port, ptr, rust_vec_len, data_len),
+        87 => wire__crate__api__video__get_video_detail_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__video__get_video_detail_with_options_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        25 => wire__crate__api__init__export_logs_zip_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__sync__force_upload_history_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__download__get_all_downloads_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__settings__get_app_version_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__cache__get_cache_size_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__settings__get_cache_size_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__user__get_cloudflare_challenge_info_impl(
+        89 => wire__crate__api__user__get_video_progress_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__video__get_video_url_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__watch_next__get_watch_next_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__sync__get_webdav_settings_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__download_bundle__import_download_bundle_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => wire__crate__api__video__get_comment_replies_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__user__get_current_user_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__settings__get_data_dir_path_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__download_folders__get_download_folders_impl(
+        95 => wire__crate__api__init__init_app_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__settings__init_app_impl(port, ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__user__is_logged_in_impl(port, ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__video__like_comment_impl(port, ptr, rust_vec_len, data_len),
+        100 => wire__crate__api__cache__load_cached_image_impl(port, ptr, rust_vec_len, data_len),
+        101 => wire__crate__api__user__login_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__api__user__logout_impl(port, ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__download_reconcile__mark_downloads_missing_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => wire__crate__api__download__get_downloads_by_status_impl(
+        104 => wire__crate__api__download_folders__move_download_folder_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__user__get_favorites_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__search__get_filter_options_impl(port, ptr, rust_vec_len, data_len),
-        39 => {
-            wire__crate__api__settings__get_flutter_settings_impl(port, ptr, rust_vec_len, data_len)
-        }
-        40 => wire__crate__api__search__get_home_videos_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__search__get_homepage_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__settings__get_kv_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__sync__get_last_sync_time_impl(port, ptr, rust_vec_len, data_len),
-        44 => {
-            wire__crate__api__download__get_local_video_path_impl(port, ptr, rust_vec_len, data_len)
-        }
-        45 => wire__crate__api__user__get_login_form_token_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__user__get_my_list_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__user__get_my_subscriptions_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__user__get_play_history_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__settings__get_settings_impl(port, ptr, rust_vec_len, data_len),
-        50 => {
-            wire__crate__api__user__get_subscribed_authors_impl(port, ptr, rust_vec_len, data_len)
-        }
-        51 => wire__crate__api__init__get_version_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__video__get_video_comments_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__video__get_video_detail_impl(port, ptr, rust_vec_len, data_len),
-        54 => wire__crate__api__user__get_video_progress_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__video__get_video_url_impl(port, ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__sync__get_webdav_settings_impl(port, ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__init__init_app_impl(port, ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__settings__init_app_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__user__is_logged_in_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__video__like_comment_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__cache__load_cached_image_impl(port, ptr, rust_vec_len, data_len),
-        64 => wire__crate__api__user__login_impl(port, ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__user__logout_impl(port, ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__download_folders__move_downloads_to_folder_impl(
+        105 => wire__crate__api__download_folders__move_downloads_to_folder_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        67 => {
+        106 => {
             wire__crate__api__download__pause_all_downloads_impl(port, ptr, rust_vec_len, data_len)
         }
-        68 => wire__crate__api__download__pause_download_impl(port, ptr, rust_vec_len, data_len),
-        69 => wire__crate__api__video__post_comment_impl(port, ptr, rust_vec_len, data_len),
-        70 => {
+        107 => wire__crate__api__download__pause_download_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__download_variants__pause_download_variant_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        109 => wire__crate__api__download_export__pause_export_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__video__post_comment_impl(port, ptr, rust_vec_len, data_len),
+        111 => {
             wire__crate__api__init__prepare_logs_for_sharing_impl(port, ptr, rust_vec_len, data_len)
         }
-        71 => wire__crate__api__user__reload_user_agent_impl(port, ptr, rust_vec_len, data_len),
-        72 => wire__crate__api__user__remove_from_favorites_impl(port, ptr, rust_vec_len, data_len),
-        73 => {
+        112 => wire__crate__api__download_quota__preview_download_cleanup_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__api__download_export__preview_export_template_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => wire__crate__api__download__query_downloads_impl(port, ptr, rust_vec_len, data_len),
+        115 => wire__crate__api__download_reconcile__redownload_missing_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__download_reconcile__relink_download_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__crate__api__user__reload_user_agent_impl(port, ptr, rust_vec_len, data_len),
+        118 => wire__crate__api__download_relocate__relocate_downloads_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => {
+            wire__crate__api__user__remove_from_favorites_impl(port, ptr, rust_vec_len, data_len)
+        }
+        120 => {
             wire__crate__api__video__remove_from_favorites_impl(port, ptr, rust_vec_len, data_len)
         }
-        74 => wire__crate__api__download_folders__rename_download_folder_impl(
+        121 => wire__crate__api__download_folders__rename_download_folder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        122 => wire__crate__api__download_folders__reorder_download_folders_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        75 => wire__crate__api__init__report_flutter_error_impl(port, ptr, rust_vec_len, data_len),
-        76 => wire__crate__api__init__report_flutter_log_impl(port, ptr, rust_vec_len, data_len),
-        77 => {
+        123 => wire__crate__api__download__repair_download_impl(port, ptr, rust_vec_len, data_len),
+        124 => wire__crate__api__init__report_flutter_error_impl(port, ptr, rust_vec_len, data_len),
+        125 => wire__crate__api__init__report_flutter_log_impl(port, ptr, rust_vec_len, data_len),
+        126 => {
             wire__crate__api__download__resume_all_downloads_impl(port, ptr, rust_vec_len, data_len)
         }
-        78 => wire__crate__api__download__resume_download_impl(port, ptr, rust_vec_len, data_len),
-        79 => wire__crate__api__settings__save_flutter_settings_impl(
+        127 => wire__crate__api__download__resume_download_impl(port, ptr, rust_vec_len, data_len),
+        128 => wire__crate__api__download_variants__resume_download_variant_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        129 => wire__crate__api__download_export__resume_export_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        130 => wire__crate__api__download_quota__run_download_cleanup_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        131 => wire__crate__api__settings__save_flutter_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => wire__crate__api__settings__save_settings_impl(port, ptr, rust_vec_len, data_len),
-        81 => wire__crate__api__sync__save_webdav_settings_impl(port, ptr, rust_vec_len, data_len),
-        82 => wire__crate__api__search__search_impl(port, ptr, rust_vec_len, data_len),
-        83 => wire__crate__api__user__set_cf_clearance_impl(port, ptr, rust_vec_len, data_len),
-        84 => wire__crate__api__init__set_cookies_impl(port, ptr, rust_vec_len, data_len),
-        85 => wire__crate__api__user__set_cookies_impl(port, ptr, rust_vec_len, data_len),
-        86 => {
+        132 => wire__crate__api__settings__save_settings_impl(port, ptr, rust_vec_len, data_len),
+        133 => wire__crate__api__sync__save_webdav_settings_impl(port, ptr, rust_vec_len, data_len),
+        134 => wire__crate__api__download_reconcile__scan_download_library_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        135 => wire__crate__api__search__search_impl(port, ptr, rust_vec_len, data_len),
+        136 => wire__crate__api__user__set_cf_clearance_impl(port, ptr, rust_vec_len, data_len),
+        137 => wire__crate__api__init__set_cookies_impl(port, ptr, rust_vec_len, data_len),
+        138 => wire__crate__api__user__set_cookies_impl(port, ptr, rust_vec_len, data_len),
+        139 => {
             wire__crate__api__settings__set_default_quality_impl(port, ptr, rust_vec_len, data_len)
         }
-        87 => wire__crate__api__settings__set_download_concurrent_impl(
+        140 => wire__crate__api__download_throttle__set_download_bandwidth_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        141 => wire__crate__api__settings__set_download_concurrent_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        142 => wire__crate__api__download_folders__set_download_folder_cover_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        143 => wire__crate__api__download__set_download_max_retries_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        144 => wire__crate__api__settings__set_download_preallocate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        145 => wire__crate__api__download_quota__set_download_quota_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        146 => wire__crate__api__download_rules__set_download_rule_enabled_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        147 => wire__crate__api__download_rules__set_download_rules_interval_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        148 => wire__crate__api__settings__set_download_space_reserve_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        149 => wire__crate__api__download_throttle__set_download_task_bandwidth_limit_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        150 => wire__crate__api__settings__set_kv_impl(port, ptr, rust_vec_len, data_len),
+        151 => wire__crate__api__download_throttle__set_player_streaming_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        152 => wire__crate__api__download_variants__set_primary_download_variant_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        153 => wire__crate__api__settings__set_proxy_impl(port, ptr, rust_vec_len, data_len),
+        154 => wire__crate__api__sync__should_auto_sync_impl(port, ptr, rust_vec_len, data_len),
+        155 => wire__crate__api__user__subscribe_author_impl(port, ptr, rust_vec_len, data_len),
+        156 => wire__crate__api__download__subscribe_download_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        157 => wire__crate__api__download_variants__subscribe_download_variant_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        88 => wire__crate__api__settings__set_kv_impl(port, ptr, rust_vec_len, data_len),
-        89 => wire__crate__api__settings__set_proxy_impl(port, ptr, rust_vec_len, data_len),
-        90 => wire__crate__api__sync__should_auto_sync_impl(port, ptr, rust_vec_len, data_len),
-        91 => wire__crate__api__user__subscribe_author_impl(port, ptr, rust_vec_len, data_len),
-        92 => wire__crate__api__download__subscribe_download_progress_impl(
+        158 => wire__crate__api__download_quota__subscribe_quota_blocked_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__sync__sync_history_impl(port, ptr, rust_vec_len, data_len),
-        94 => {
+        159 => wire__crate__api__sync__sync_history_impl(port, ptr, rust_vec_len, data_len),
+        160 => {
             wire__crate__api__sync__test_webdav_connection_impl(port, ptr, rust_vec_len, data_len)
         }
-        95 => wire__crate__api__user__unsubscribe_author_impl(port, ptr, rust_vec_len, data_len),
-        96 => wire__crate__api__sync__update_last_sync_time_impl(port, ptr, rust_vec_len, data_len),
-        97 => wire__crate__api__user__update_play_history_impl(port, ptr, rust_vec_len, data_len),
-        98 => wire__crate__api__cache__vacuum_database_impl(port, ptr, rust_vec_len, data_len),
+        161 => wire__crate__api__user__unsubscribe_author_impl(port, ptr, rust_vec_len, data_len),
+        162 => wire__crate__api__download_rules__update_download_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => {
+            wire__crate__api__sync__update_last_sync_time_impl(port, ptr, rust_vec_len, data_len)
+        }
+        164 => wire__crate__api__user__update_play_history_impl(port, ptr, rust_vec_len, data_len),
+        165 => wire__crate__api__download_folders__update_smart_folder_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        166 => wire__crate__api__download_variants__upgrade_download_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        167 => wire__crate__api__cache__vacuum_database_impl(port, ptr, rust_vec_len, data_len),
+        168 => wire__crate__api__download_export__validate_export_template_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        169 => wire__crate__api__download__verify_download_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        93 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        57 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiAppSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.default_quality.into_into_dart().into_dart(),
+            self.download_concurrent.into_into_dart().into_dart(),
+            self.proxy_url.into_into_dart().into_dart(),
+            self.theme_mode.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiAppSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiAppSettings>
+    for crate::api::models::ApiAppSettings
+{
+    fn into_into_dart(self) -> crate::api::models::ApiAppSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiAuthorInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.is_subscribed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiAuthorInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiAuthorInfo>
+    for crate::api::models::ApiAuthorInfo
+{
+    fn into_into_dart(self) -> crate::api::models::ApiAuthorInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiBanner {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.pic_url.into_into_dart().into_dart(),
+            self.video_code.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::models::ApiBanner {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiBanner>
+    for crate::api::models::ApiBanner
+{
+    fn into_into_dart(self) -> crate::api::models::ApiBanner {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiBatchEnqueueResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.enqueued.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiBatchEnqueueResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiBatchEnqueueResult>
+    for crate::api::models::ApiBatchEnqueueResult
+{
+    fn into_into_dart(self) -> crate::api::models::ApiBatchEnqueueResult {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::ApiAppSettings {
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiBundleProgress {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.default_quality.into_into_dart().into_dart(),
-            self.download_concurrent.into_into_dart().into_dart(),
-            self.proxy_url.into_into_dart().into_dart(),
-            self.theme_mode.into_into_dart().into_dart(),
-            self.language.into_into_dart().into_dart(),
+            self.stage.into_into_dart().into_dart(),
+            self.total_items.into_into_dart().into_dart(),
+            self.done_items.into_into_dart().into_dart(),
+            self.exported.into_into_dart().into_dart(),
+            self.imported.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+            self.current_item.into_into_dart().into_dart(),
+            self.current_bytes.into_into_dart().into_dart(),
+            self.current_total_bytes.into_into_dart().into_dart(),
+            self.done.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::ApiAppSettings
+    for crate::api::models::ApiBundleProgress
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiAppSettings>
-    for crate::api::models::ApiAppSettings
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiBundleProgress>
+    for crate::api::models::ApiBundleProgress
 {
-    fn into_into_dart(self) -> crate::api::models::ApiAppSettings {
+    fn into_into_dart(self) -> crate::api::models::ApiBundleProgress {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::ApiAuthorInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiByteRange {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.avatar_url.into_into_dart().into_dart(),
-            self.is_subscribed.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::ApiAuthorInfo
+    for crate::api::models::ApiByteRange
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiAuthorInfo>
-    for crate::api::models::ApiAuthorInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiByteRange>
+    for crate::api::models::ApiByteRange
 {
-    fn into_into_dart(self) -> crate::api::models::ApiAuthorInfo {
+    fn into_into_dart(self) -> crate::api::models::ApiByteRange {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::ApiBanner {
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiCleanupItem {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
+            self.video_id.into_into_dart().into_dart(),
             self.title.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.pic_url.into_into_dart().into_dart(),
-            self.video_code.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.completed_at.into_into_dart().into_dart(),
+            self.watched_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::models::ApiBanner {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiBanner>
-    for crate::api::models::ApiBanner
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiCleanupItem
 {
-    fn into_into_dart(self) -> crate::api::models::ApiBanner {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiCleanupItem>
+    for crate::api::models::ApiCleanupItem
+{
+    fn into_into_dart(self) -> crate::api::models::ApiCleanupItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiCleanupPlan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.used_bytes.into_into_dart().into_dart(),
+            self.quota_bytes.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.freed_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiCleanupPlan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiCleanupPlan>
+    for crate::api::models::ApiCleanupPlan
+{
+    fn into_into_dart(self) -> crate::api::models::ApiCleanupPlan {
         self
     }
 }
@@ -5154,12 +9236,100 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiCommentList>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiContinueWatching {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.video_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.cover_url.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.last_played_at.into_into_dart().into_dart(),
+            self.series_title.into_into_dart().into_dart(),
+            self.episode.into_into_dart().into_dart(),
+            self.is_downloaded.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiContinueWatching
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiContinueWatching>
+    for crate::api::models::ApiContinueWatching
+{
+    fn into_into_dart(self) -> crate::api::models::ApiContinueWatching {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadBandwidthSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.global_kb_per_sec.into_into_dart().into_dart(),
+            self.task_kb_per_sec.into_into_dart().into_dart(),
+            self.only_while_streaming.into_into_dart().into_dart(),
+            self.player_streaming.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadBandwidthSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadBandwidthSettings>
+    for crate::api::models::ApiDownloadBandwidthSettings
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadBandwidthSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.source_host.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.range_start.into_into_dart().into_dart(),
+            self.range_end.into_into_dart().into_dart(),
+            self.http_status.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadEvent>
+    for crate::api::models::ApiDownloadEvent
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadFolder {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
             self.name.into_into_dart().into_dart(),
             self.created_at.into_into_dart().into_dart(),
+            self.parent_id.into_into_dart().into_dart(),
+            self.sort_order.into_into_dart().into_dart(),
+            self.cover_video_id.into_into_dart().into_dart(),
+            self.is_smart.into_into_dart().into_dart(),
+            self.video_count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5176,6 +9346,114 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadFolder>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.items.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadPage>
+    for crate::api::models::ApiDownloadPage
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.statuses.into_into_dart().into_dart(),
+            self.folder_id.into_into_dart().into_dart(),
+            self.include_subfolders.into_into_dart().into_dart(),
+            self.authors.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.downloaded_from.into_into_dart().into_dart(),
+            self.downloaded_to.into_into_dart().into_dart(),
+            self.min_bytes.into_into_dart().into_dart(),
+            self.max_bytes.into_into_dart().into_dart(),
+            self.sort_by.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadQuery>
+    for crate::api::models::ApiDownloadQuery
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadQuotaPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.quota_bytes.into_into_dart().into_dart(),
+            self.auto_cleanup.into_into_dart().into_dart(),
+            self.watched_older_than_days.into_into_dart().into_dart(),
+            self.oldest_first.into_into_dart().into_dart(),
+            self.pinned_folder_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadQuotaPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadQuotaPolicy>
+    for crate::api::models::ApiDownloadQuotaPolicy
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadQuotaPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.match_type.into_into_dart().into_dart(),
+            self.match_value.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.folder_id.into_into_dart().into_dart(),
+            self.max_age_days.into_into_dart().into_dart(),
+            self.daily_cap.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.last_checked_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadRule>
+    for crate::api::models::ApiDownloadRule
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -5186,6 +9464,10 @@ impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadStatus {
             crate::api::models::ApiDownloadStatus::Failed { error } => {
                 [4.into_dart(), error.into_into_dart().into_dart()].into_dart()
             }
+            crate::api::models::ApiDownloadStatus::Corrupt { reason } => {
+                [5.into_dart(), reason.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::models::ApiDownloadStatus::Missing => [6.into_dart()].into_dart(),
             _ => {
                 unimplemented!("");
             }
@@ -5204,41 +9486,152 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadStatus>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadTask {
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadStorageInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.download_dir.into_into_dart().into_dart(),
+            self.used_bytes.into_into_dart().into_dart(),
+            self.pending_bytes.into_into_dart().into_dart(),
+            self.free_bytes.into_into_dart().into_dart(),
+            self.disk_total_bytes.into_into_dart().into_dart(),
+            self.reserve_bytes.into_into_dart().into_dart(),
+            self.preallocate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadStorageInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadStorageInfo>
+    for crate::api::models::ApiDownloadStorageInfo
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadStorageInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadSubtitle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.language.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadSubtitle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadSubtitle>
+    for crate::api::models::ApiDownloadSubtitle
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadSubtitle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadTask {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.video_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.cover_url.into_into_dart().into_dart(),
+            self.cover_path.into_into_dart().into_dart(),
+            self.author_id.into_into_dart().into_dart(),
+            self.author_name.into_into_dart().into_dart(),
+            self.author_avatar_url.into_into_dart().into_dart(),
+            self.author_avatar_path.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.downloaded_bytes.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.speed.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.folder_id.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+            self.retry_count.into_into_dart().into_dart(),
+            self.max_retries.into_into_dart().into_dart(),
+            self.next_retry_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiDownloadTask
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadTask>
+    for crate::api::models::ApiDownloadTask
+{
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadTask {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiDownloadVariant {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
             self.video_id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.cover_url.into_into_dart().into_dart(),
-            self.cover_path.into_into_dart().into_dart(),
-            self.author_id.into_into_dart().into_dart(),
-            self.author_name.into_into_dart().into_dart(),
-            self.author_avatar_url.into_into_dart().into_dart(),
-            self.author_avatar_path.into_into_dart().into_dart(),
             self.quality.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
+            self.source_quality.into_into_dart().into_dart(),
             self.status.into_into_dart().into_dart(),
             self.progress.into_into_dart().into_dart(),
             self.downloaded_bytes.into_into_dart().into_dart(),
             self.total_bytes.into_into_dart().into_dart(),
-            self.speed.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
             self.file_path.into_into_dart().into_dart(),
-            self.folder_id.into_into_dart().into_dart(),
+            self.replace_primary.into_into_dart().into_dart(),
+            self.keep_previous.into_into_dart().into_dart(),
+            self.error_message.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.completed_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::ApiDownloadTask
+    for crate::api::models::ApiDownloadVariant
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadTask>
-    for crate::api::models::ApiDownloadTask
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadVariant>
+    for crate::api::models::ApiDownloadVariant
 {
-    fn into_into_dart(self) -> crate::api::models::ApiDownloadTask {
+    fn into_into_dart(self) -> crate::api::models::ApiDownloadVariant {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiExportOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.media_server_layout.into_into_dart().into_dart(),
+            self.group_by.into_into_dart().into_dart(),
+            self.filename_template.into_into_dart().into_dart(),
+            self.max_name_bytes.into_into_dart().into_dart(),
+            self.move_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiExportOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiExportOptions>
+    for crate::api::models::ApiExportOptions
+{
+    fn into_into_dart(self) -> crate::api::models::ApiExportOptions {
         self
     }
 }
@@ -5246,8 +9639,10 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiDownloadTask>
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiExportProgress {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
+            self.job_id.into_into_dart().into_dart(),
             self.total_files.into_into_dart().into_dart(),
             self.done_files.into_into_dart().into_dart(),
+            self.skipped_files.into_into_dart().into_dart(),
             self.current_file.into_into_dart().into_dart(),
             self.current_bytes.into_into_dart().into_dart(),
             self.current_total_bytes.into_into_dart().into_dart(),
@@ -5384,6 +9779,33 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiHomeSection>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiMissingDownload {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.video_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.missing_media.into_into_dart().into_dart(),
+            self.missing_cover.into_into_dart().into_dart(),
+            self.missing_avatar.into_into_dart().into_dart(),
+            self.expected_bytes.into_into_dart().into_dart(),
+            self.candidate_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiMissingDownload
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiMissingDownload>
+    for crate::api::models::ApiMissingDownload
+{
+    fn into_into_dart(self) -> crate::api::models::ApiMissingDownload {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiMyListInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5427,6 +9849,58 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiMyListItem>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiNextEpisode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.video_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.cover_url.into_into_dart().into_dart(),
+            self.series_id.into_into_dart().into_dart(),
+            self.series_title.into_into_dart().into_dart(),
+            self.episode.into_into_dart().into_dart(),
+            self.previous_video_id.into_into_dart().into_dart(),
+            self.previous_played_at.into_into_dart().into_dart(),
+            self.is_downloaded.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiNextEpisode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiNextEpisode>
+    for crate::api::models::ApiNextEpisode
+{
+    fn into_into_dart(self) -> crate::api::models::ApiNextEpisode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiOrphanFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dir.into_into_dart().into_dart(),
+            self.file_name.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.modified_at.into_into_dart().into_dart(),
+            self.video_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiOrphanFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiOrphanFile>
+    for crate::api::models::ApiOrphanFile
+{
+    fn into_into_dart(self) -> crate::api::models::ApiOrphanFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiPlayHistory {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5496,6 +9970,83 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiPlaylistInfo>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiQuotaBlockedEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.video_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.required_bytes.into_into_dart().into_dart(),
+            self.used_bytes.into_into_dart().into_dart(),
+            self.quota_bytes.into_into_dart().into_dart(),
+            self.freed_bytes.into_into_dart().into_dart(),
+            self.blocked_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiQuotaBlockedEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiQuotaBlockedEvent>
+    for crate::api::models::ApiQuotaBlockedEvent
+{
+    fn into_into_dart(self) -> crate::api::models::ApiQuotaBlockedEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiReconcileReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.missing.into_into_dart().into_dart(),
+            self.orphans.into_into_dart().into_dart(),
+            self.missing_bytes.into_into_dart().into_dart(),
+            self.orphan_bytes.into_into_dart().into_dart(),
+            self.scanned_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiReconcileReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiReconcileReport>
+    for crate::api::models::ApiReconcileReport
+{
+    fn into_into_dart(self) -> crate::api::models::ApiReconcileReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiRelocateProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stage.into_into_dart().into_dart(),
+            self.total_files.into_into_dart().into_dart(),
+            self.done_files.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.done_bytes.into_into_dart().into_dart(),
+            self.current_file.into_into_dart().into_dart(),
+            self.done.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiRelocateProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiRelocateProgress>
+    for crate::api::models::ApiRelocateProgress
+{
+    fn into_into_dart(self) -> crate::api::models::ApiRelocateProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiSearchFilters {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5594,6 +10145,33 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiSeriesVideo>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiSmartFolderRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.authors.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.qualities.into_into_dart().into_dart(),
+            self.downloaded_from.into_into_dart().into_dart(),
+            self.downloaded_to.into_into_dart().into_dart(),
+            self.uploaded_from.into_into_dart().into_dart(),
+            self.uploaded_to.into_into_dart().into_dart(),
+            self.watched.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiSmartFolderRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiSmartFolderRule>
+    for crate::api::models::ApiSmartFolderRule
+{
+    fn into_into_dart(self) -> crate::api::models::ApiSmartFolderRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::models::ApiSubscriptionsPage {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5617,6 +10195,29 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiSubscriptionsPage>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiSubtitleTrack {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.language.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiSubtitleTrack
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiSubtitleTrack>
+    for crate::api::models::ApiSubtitleTrack
+{
+    fn into_into_dart(self) -> crate::api::models::ApiSubtitleTrack {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::sync::ApiSyncStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -5742,6 +10343,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::models::ApiVideoDetail {
             self.author.into_into_dart().into_dart(),
             self.tags.into_into_dart().into_dart(),
             self.qualities.into_into_dart().into_dart(),
+            self.subtitles.into_into_dart().into_dart(),
             self.series.into_into_dart().into_dart(),
             self.related_videos.into_into_dart().into_dart(),
             self.form_token.into_into_dart().into_dart(),
@@ -5771,6 +10373,10 @@ impl flutter_rust_bridge::IntoDart for crate::api::models::ApiVideoQuality {
         [
             self.quality.into_into_dart().into_dart(),
             self.url.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.bandwidth.into_into_dart().into_dart(),
+            self.codecs.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5787,6 +10393,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiVideoQuality>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ApiWatchNext {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.continue_watching.into_into_dart().into_dart(),
+            self.next_episodes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::ApiWatchNext
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ApiWatchNext>
+    for crate::api::models::ApiWatchNext
+{
+    fn into_into_dart(self) -> crate::api::models::ApiWatchNext {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::sync::ApiWebDavSettings {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5860,6 +10487,18 @@ impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::models::ApiBundleProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::models::ApiDownloadTask,
@@ -5872,6 +10511,18 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::models::ApiDownloadVariant,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::models::ApiExportProgress,
@@ -5884,6 +10535,30 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::models::ApiQuotaBlockedEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::models::ApiRelocateProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5922,6 +10597,64 @@ impl SseEncode for crate::api::models::ApiBanner {
     }
 }
 
+impl SseEncode for crate::api::models::ApiBatchEnqueueResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.total, serializer);
+        <Vec<String>>::sse_encode(self.enqueued, serializer);
+        <Vec<String>>::sse_encode(self.skipped, serializer);
+        <Vec<String>>::sse_encode(self.failed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiBundleProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stage, serializer);
+        <u32>::sse_encode(self.total_items, serializer);
+        <u32>::sse_encode(self.done_items, serializer);
+        <u32>::sse_encode(self.exported, serializer);
+        <u32>::sse_encode(self.imported, serializer);
+        <u32>::sse_encode(self.skipped, serializer);
+        <u32>::sse_encode(self.failed, serializer);
+        <Option<String>>::sse_encode(self.current_item, serializer);
+        <u64>::sse_encode(self.current_bytes, serializer);
+        <u64>::sse_encode(self.current_total_bytes, serializer);
+        <bool>::sse_encode(self.done, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiByteRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start, serializer);
+        <u64>::sse_encode(self.end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiCleanupItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.video_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.reason, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <i64>::sse_encode(self.completed_at, serializer);
+        <Option<i64>>::sse_encode(self.watched_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiCleanupPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.used_bytes, serializer);
+        <Option<u64>>::sse_encode(self.quota_bytes, serializer);
+        <Vec<crate::api::models::ApiCleanupItem>>::sse_encode(self.items, serializer);
+        <u64>::sse_encode(self.freed_bytes, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiCloudflareChallenge {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5955,12 +10688,117 @@ impl SseEncode for crate::api::models::ApiCommentList {
     }
 }
 
+impl SseEncode for crate::api::models::ApiContinueWatching {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.video_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.cover_url, serializer);
+        <f32>::sse_encode(self.progress, serializer);
+        <u32>::sse_encode(self.position, serializer);
+        <u32>::sse_encode(self.duration, serializer);
+        <i64>::sse_encode(self.last_played_at, serializer);
+        <Option<String>>::sse_encode(self.series_title, serializer);
+        <Option<u32>>::sse_encode(self.episode, serializer);
+        <bool>::sse_encode(self.is_downloaded, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadBandwidthSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.global_kb_per_sec, serializer);
+        <u32>::sse_encode(self.task_kb_per_sec, serializer);
+        <bool>::sse_encode(self.only_while_streaming, serializer);
+        <bool>::sse_encode(self.player_streaming, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.source_host, serializer);
+        <Option<String>>::sse_encode(self.quality, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <Option<u64>>::sse_encode(self.range_start, serializer);
+        <Option<u64>>::sse_encode(self.range_end, serializer);
+        <Option<u16>>::sse_encode(self.http_status, serializer);
+        <Option<String>>::sse_encode(self.error_kind, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiDownloadFolder {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.name, serializer);
         <i64>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.parent_id, serializer);
+        <i32>::sse_encode(self.sort_order, serializer);
+        <Option<String>>::sse_encode(self.cover_video_id, serializer);
+        <bool>::sse_encode(self.is_smart, serializer);
+        <u32>::sse_encode(self.video_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::models::ApiDownloadTask>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.statuses, serializer);
+        <Option<String>>::sse_encode(self.folder_id, serializer);
+        <bool>::sse_encode(self.include_subfolders, serializer);
+        <Vec<String>>::sse_encode(self.authors, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.text, serializer);
+        <Option<i64>>::sse_encode(self.downloaded_from, serializer);
+        <Option<i64>>::sse_encode(self.downloaded_to, serializer);
+        <Option<u64>>::sse_encode(self.min_bytes, serializer);
+        <Option<u64>>::sse_encode(self.max_bytes, serializer);
+        <Option<String>>::sse_encode(self.sort_by, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+        <u32>::sse_encode(self.offset, serializer);
+        <u32>::sse_encode(self.limit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadQuotaPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.quota_bytes, serializer);
+        <bool>::sse_encode(self.auto_cleanup, serializer);
+        <Option<u32>>::sse_encode(self.watched_older_than_days, serializer);
+        <bool>::sse_encode(self.oldest_first, serializer);
+        <Vec<String>>::sse_encode(self.pinned_folder_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.match_type, serializer);
+        <String>::sse_encode(self.match_value, serializer);
+        <String>::sse_encode(self.quality, serializer);
+        <Option<String>>::sse_encode(self.folder_id, serializer);
+        <Option<u32>>::sse_encode(self.max_age_days, serializer);
+        <Option<u32>>::sse_encode(self.daily_cap, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Option<i64>>::sse_encode(self.last_checked_at, serializer);
     }
 }
 
@@ -5984,6 +10822,13 @@ impl SseEncode for crate::api::models::ApiDownloadStatus {
                 <i32>::sse_encode(4, serializer);
                 <String>::sse_encode(error, serializer);
             }
+            crate::api::models::ApiDownloadStatus::Corrupt { reason } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::api::models::ApiDownloadStatus::Missing => {
+                <i32>::sse_encode(6, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -5991,6 +10836,30 @@ impl SseEncode for crate::api::models::ApiDownloadStatus {
     }
 }
 
+impl SseEncode for crate::api::models::ApiDownloadStorageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.download_dir, serializer);
+        <u64>::sse_encode(self.used_bytes, serializer);
+        <u64>::sse_encode(self.pending_bytes, serializer);
+        <u64>::sse_encode(self.free_bytes, serializer);
+        <u64>::sse_encode(self.disk_total_bytes, serializer);
+        <u64>::sse_encode(self.reserve_bytes, serializer);
+        <bool>::sse_encode(self.preallocate, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadSubtitle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.language, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.file_path, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiDownloadTask {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6014,14 +10883,51 @@ impl SseEncode for crate::api::models::ApiDownloadTask {
         <i64>::sse_encode(self.created_at, serializer);
         <Option<String>>::sse_encode(self.file_path, serializer);
         <Option<String>>::sse_encode(self.folder_id, serializer);
+        <Option<String>>::sse_encode(self.error_kind, serializer);
+        <u32>::sse_encode(self.retry_count, serializer);
+        <u32>::sse_encode(self.max_retries, serializer);
+        <Option<i64>>::sse_encode(self.next_retry_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiDownloadVariant {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.video_id, serializer);
+        <String>::sse_encode(self.quality, serializer);
+        <Option<String>>::sse_encode(self.source_quality, serializer);
+        <crate::api::models::ApiDownloadStatus>::sse_encode(self.status, serializer);
+        <f32>::sse_encode(self.progress, serializer);
+        <u64>::sse_encode(self.downloaded_bytes, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <Option<String>>::sse_encode(self.file_path, serializer);
+        <bool>::sse_encode(self.replace_primary, serializer);
+        <bool>::sse_encode(self.keep_previous, serializer);
+        <Option<String>>::sse_encode(self.error_message, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Option<i64>>::sse_encode(self.completed_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiExportOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.media_server_layout, serializer);
+        <String>::sse_encode(self.group_by, serializer);
+        <Option<String>>::sse_encode(self.filename_template, serializer);
+        <Option<u32>>::sse_encode(self.max_name_bytes, serializer);
+        <bool>::sse_encode(self.move_files, serializer);
     }
 }
 
 impl SseEncode for crate::api::models::ApiExportProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.job_id, serializer);
         <u32>::sse_encode(self.total_files, serializer);
         <u32>::sse_encode(self.done_files, serializer);
+        <u32>::sse_encode(self.skipped_files, serializer);
         <Option<String>>::sse_encode(self.current_file, serializer);
         <u64>::sse_encode(self.current_bytes, serializer);
         <u64>::sse_encode(self.current_total_bytes, serializer);
@@ -6080,6 +10986,20 @@ impl SseEncode for crate::api::models::ApiHomeSection {
     }
 }
 
+impl SseEncode for crate::api::models::ApiMissingDownload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.video_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <crate::api::models::ApiDownloadStatus>::sse_encode(self.status, serializer);
+        <bool>::sse_encode(self.missing_media, serializer);
+        <bool>::sse_encode(self.missing_cover, serializer);
+        <bool>::sse_encode(self.missing_avatar, serializer);
+        <u64>::sse_encode(self.expected_bytes, serializer);
+        <Vec<String>>::sse_encode(self.candidate_files, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiMyListInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6097,6 +11017,32 @@ impl SseEncode for crate::api::models::ApiMyListItem {
     }
 }
 
+impl SseEncode for crate::api::models::ApiNextEpisode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.video_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.cover_url, serializer);
+        <String>::sse_encode(self.series_id, serializer);
+        <String>::sse_encode(self.series_title, serializer);
+        <u32>::sse_encode(self.episode, serializer);
+        <String>::sse_encode(self.previous_video_id, serializer);
+        <i64>::sse_encode(self.previous_played_at, serializer);
+        <bool>::sse_encode(self.is_downloaded, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiOrphanFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.dir, serializer);
+        <String>::sse_encode(self.file_name, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <Option<i64>>::sse_encode(self.modified_at, serializer);
+        <Option<String>>::sse_encode(self.video_id, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiPlayHistory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6127,6 +11073,44 @@ impl SseEncode for crate::api::models::ApiPlaylistInfo {
     }
 }
 
+impl SseEncode for crate::api::models::ApiQuotaBlockedEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.video_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <u64>::sse_encode(self.required_bytes, serializer);
+        <u64>::sse_encode(self.used_bytes, serializer);
+        <u64>::sse_encode(self.quota_bytes, serializer);
+        <u64>::sse_encode(self.freed_bytes, serializer);
+        <i64>::sse_encode(self.blocked_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiReconcileReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::models::ApiMissingDownload>>::sse_encode(self.missing, serializer);
+        <Vec<crate::api::models::ApiOrphanFile>>::sse_encode(self.orphans, serializer);
+        <u64>::sse_encode(self.missing_bytes, serializer);
+        <u64>::sse_encode(self.orphan_bytes, serializer);
+        <i64>::sse_encode(self.scanned_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiRelocateProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stage, serializer);
+        <u32>::sse_encode(self.total_files, serializer);
+        <u32>::sse_encode(self.done_files, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <u64>::sse_encode(self.done_bytes, serializer);
+        <Option<String>>::sse_encode(self.current_file, serializer);
+        <bool>::sse_encode(self.done, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiSearchFilters {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6173,6 +11157,20 @@ impl SseEncode for crate::api::models::ApiSeriesVideo {
     }
 }
 
+impl SseEncode for crate::api::models::ApiSmartFolderRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.authors, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Vec<String>>::sse_encode(self.qualities, serializer);
+        <Option<i64>>::sse_encode(self.downloaded_from, serializer);
+        <Option<i64>>::sse_encode(self.downloaded_to, serializer);
+        <Option<String>>::sse_encode(self.uploaded_from, serializer);
+        <Option<String>>::sse_encode(self.uploaded_to, serializer);
+        <Option<bool>>::sse_encode(self.watched, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::ApiSubscriptionsPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6183,6 +11181,16 @@ impl SseEncode for crate::api::models::ApiSubscriptionsPage {
     }
 }
 
+impl SseEncode for crate::api::models::ApiSubtitleTrack {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.language, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.format, serializer);
+    }
+}
+
 impl SseEncode for crate::api::sync::ApiSyncStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6265,6 +11273,7 @@ impl SseEncode for crate::api::models::ApiVideoDetail {
         <Option<crate::api::models::ApiAuthorInfo>>::sse_encode(self.author, serializer);
         <Vec<String>>::sse_encode(self.tags, serializer);
         <Vec<crate::api::models::ApiVideoQuality>>::sse_encode(self.qualities, serializer);
+        <Vec<crate::api::models::ApiSubtitleTrack>>::sse_encode(self.subtitles, serializer);
         <Option<crate::api::models::ApiSeriesInfo>>::sse_encode(self.series, serializer);
         <Vec<crate::api::models::ApiVideoCard>>::sse_encode(self.related_videos, serializer);
         <Option<String>>::sse_encode(self.form_token, serializer);
@@ -6281,6 +11290,21 @@ impl SseEncode for crate::api::models::ApiVideoQuality {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.quality, serializer);
         <String>::sse_encode(self.url, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<u64>>::sse_encode(self.bandwidth, serializer);
+        <Option<String>>::sse_encode(self.codecs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::ApiWatchNext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::models::ApiContinueWatching>>::sse_encode(
+            self.continue_watching,
+            serializer,
+        );
+        <Vec<crate::api::models::ApiNextEpisode>>::sse_encode(self.next_episodes, serializer);
     }
 }
 
@@ -6362,6 +11386,26 @@ impl SseEncode for Vec<crate::api::models::ApiAuthorInfo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiByteRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiByteRange>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::models::ApiCleanupItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiCleanupItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiComment> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6372,6 +11416,26 @@ impl SseEncode for Vec<crate::api::models::ApiComment> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiContinueWatching> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiContinueWatching>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::models::ApiDownloadEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiDownloadEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiDownloadFolder> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6382,6 +11446,26 @@ impl SseEncode for Vec<crate::api::models::ApiDownloadFolder> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiDownloadRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiDownloadRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::models::ApiDownloadSubtitle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiDownloadSubtitle>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiDownloadTask> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6392,6 +11476,16 @@ impl SseEncode for Vec<crate::api::models::ApiDownloadTask> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiDownloadVariant> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiDownloadVariant>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiFilterOption> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6412,6 +11506,16 @@ impl SseEncode for Vec<crate::api::models::ApiHomeSection> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiMissingDownload> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiMissingDownload>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiMyListItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6422,6 +11526,26 @@ impl SseEncode for Vec<crate::api::models::ApiMyListItem> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiNextEpisode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiNextEpisode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::models::ApiOrphanFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiOrphanFile>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiPlayHistory> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6442,6 +11566,16 @@ impl SseEncode for Vec<crate::api::models::ApiSeriesVideo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::models::ApiSubtitleTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ApiSubtitleTrack>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::models::ApiTagGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6582,6 +11716,16 @@ impl SseEncode for Option<crate::api::models::ApiUserInfo> {
     }
 }
 
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6602,6 +11746,16 @@ impl SseEncode for Option<i64> {
     }
 }
 
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6630,6 +11784,13 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {